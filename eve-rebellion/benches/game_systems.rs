@@ -3,16 +3,29 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use bevy::ecs::world::World;
 use bevy::math::Vec2;
 
-use eve_rebellion::core::{ScoreSystem, StyleGrade};
+use eve_rebellion::core::{
+    ScoreBreakdown, ScoreSource, ScoreState, ScoreSystem, StyleGrade, DEFAULT_SCORE_RULES,
+};
 use eve_rebellion::systems::collision::SpatialGrid;
-use eve_rebellion::systems::scoring_v2::{ComboHeatSystem, HeatLevel};
+use eve_rebellion::systems::heat::{HeatLevel, HeatSystem};
 
-fn bench_score_on_kill(c: &mut Criterion) {
-    c.bench_function("score_on_kill_x1000", |b| {
+fn bench_score_award(c: &mut Criterion) {
+    c.bench_function("score_award_x1000", |b| {
         b.iter(|| {
             let mut score = ScoreSystem::default();
             for i in 0..1000u64 {
-                score.on_kill(black_box(100 + i));
+                score.register_kill();
+                let state = ScoreState {
+                    chain: score.chain,
+                    chain_multiplier: score.multiplier,
+                    ..Default::default()
+                };
+                score.award(ScoreBreakdown::new(
+                    black_box(100 + i),
+                    ScoreSource::Kill,
+                    DEFAULT_SCORE_RULES,
+                    &state,
+                ));
             }
             score.score
         });
@@ -59,21 +72,18 @@ fn bench_heat_classify(c: &mut Criterion) {
     });
 }
 
-fn bench_combo_update(c: &mut Criterion) {
-    c.bench_function("combo_update_1000_frames", |b| {
+fn bench_heat_update(c: &mut Criterion) {
+    c.bench_function("heat_update_1000_frames", |b| {
         b.iter(|| {
-            let mut system = ComboHeatSystem::default();
-            // Simulate gameplay: kills, firing, frame updates
+            let mut system = HeatSystem::default();
+            // Simulate gameplay: firing, frame updates
             for i in 0..1000u32 {
-                if i % 3 == 0 {
-                    system.on_kill();
-                }
                 if i % 2 == 0 {
                     system.on_fire();
                 }
                 system.update(black_box(1.0 / 60.0));
             }
-            (system.combo_count, system.heat)
+            (system.heat_level, system.heat)
         });
     });
 }
@@ -96,10 +106,10 @@ fn bench_score_grade(c: &mut Criterion) {
 
 criterion_group!(
     benches,
-    bench_score_on_kill,
+    bench_score_award,
     bench_spatial_grid,
     bench_heat_classify,
-    bench_combo_update,
+    bench_heat_update,
     bench_score_grade,
 );
 criterion_main!(benches);
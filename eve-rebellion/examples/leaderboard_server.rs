@@ -0,0 +1,29 @@
+//! Host shared leaderboards for a group of players.
+//!
+//! Usage: cargo run --example leaderboard_server -- [addr] [data_dir]
+//!
+//! Defaults to `0.0.0.0:7878` and `leaderboard_data`. Point each game at it
+//! by entering `http://<host>:7878` as the server under Options → Online.
+
+use std::net::TcpListener;
+
+use eve_rebellion::core::leaderboard_server::serve;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Connection errors are logged with `warn!`
+    bevy::log::tracing_subscriber::fmt::init();
+
+    let mut args = std::env::args().skip(1);
+    let addr = args.next().unwrap_or_else(|| "0.0.0.0:7878".into());
+    let data_dir = args.next().unwrap_or_else(|| "leaderboard_data".into());
+
+    let listener = TcpListener::bind(&addr)?;
+    println!(
+        "Serving leaderboards on http://{} from {}",
+        listener.local_addr()?,
+        data_dir
+    );
+    serve(listener, data_dir)?;
+
+    Ok(())
+}
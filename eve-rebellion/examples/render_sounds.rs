@@ -0,0 +1,40 @@
+//! Render every procedural sound and music track to WAV files.
+//!
+//! Usage: cargo run --example render_sounds -- [out_dir] [seed] [pitch]
+//!
+//! Defaults to `target/sounds`, seed 0 and pitch 1.0, so two runs with the
+//! same arguments produce byte-identical files that can be diffed or
+//! auditioned side by side.
+
+use std::path::PathBuf;
+
+use eve_rebellion::systems::synth::{encode_wav, SynthParams, SynthSound, SAMPLE_RATE};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let out_dir = PathBuf::from(args.next().unwrap_or_else(|| "target/sounds".into()));
+    let seed: u64 = args.next().map(|s| s.parse()).transpose()?.unwrap_or(0);
+    let pitch: f32 = args.next().map(|s| s.parse()).transpose()?.unwrap_or(1.0);
+    let params = SynthParams {
+        pitch,
+        ..SynthParams::default()
+    };
+
+    std::fs::create_dir_all(&out_dir)?;
+
+    for sound in SynthSound::ALL {
+        let samples = sound.render_with(params, seed);
+        let peak = samples.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+        let path = out_dir.join(format!("{}.wav", sound.name()));
+        std::fs::write(&path, encode_wav(&samples, SAMPLE_RATE)?)?;
+        println!(
+            "{:<20} {:>6.2}s  peak {:.3}  -> {}",
+            sound.name(),
+            samples.len() as f32 / SAMPLE_RATE as f32,
+            peak,
+            path.display()
+        );
+    }
+
+    Ok(())
+}
@@ -48,6 +48,8 @@ pub struct ShipSpriteCache {
     pub ready: bool,
     /// Cache directory path
     pub cache_dir: PathBuf,
+    /// Extra ships to preload (e.g. from mod packages)
+    pub requested: Vec<u32>,
 }
 
 impl ShipSpriteCache {
@@ -55,6 +57,24 @@ impl ShipSpriteCache {
     pub fn get(&self, type_id: u32) -> Option<Handle<Image>> {
         self.sprites.get(&type_id).cloned()
     }
+
+    /// Preload more ship types during the loading screen
+    pub fn request(&mut self, type_ids: impl IntoIterator<Item = u32>) {
+        for type_id in type_ids {
+            if !SHIPS_TO_LOAD.contains(&type_id) && !self.requested.contains(&type_id) {
+                self.requested.push(type_id);
+            }
+        }
+    }
+
+    /// Built-in ships followed by requested ones
+    fn preload_list(&self) -> Vec<u32> {
+        SHIPS_TO_LOAD
+            .iter()
+            .copied()
+            .chain(self.requested.iter().copied())
+            .collect()
+    }
 }
 
 /// Ships to preload - all player and enemy ships used in game
@@ -138,13 +158,14 @@ fn start_loading_sprites(mut cache: ResMut<ShipSpriteCache>, mut images: ResMut<
         }
     }
 
-    info!("Loading {} ship sprites...", SHIPS_TO_LOAD.len());
+    let ships = cache.preload_list();
+    info!("Loading {} ship sprites...", ships.len());
 
     let bundled_dir = PathBuf::from(BUNDLED_SHIPS_DIR);
     let mut loaded_bundled = 0;
     let mut loaded_cached = 0;
 
-    for &type_id in SHIPS_TO_LOAD {
+    for type_id in ships {
         // Priority 1: Check bundled assets (fastest, works offline)
         let bundled_path = bundled_dir.join(format!("{}.png", type_id));
         if bundled_path.exists() {
@@ -200,15 +221,13 @@ fn start_loading_sprites(mut cache: ResMut<ShipSpriteCache>, mut images: ResMut<
 /// Start loading ship sprites (WASM - bundled only, no downloads)
 #[cfg(target_arch = "wasm32")]
 fn start_loading_sprites(mut cache: ResMut<ShipSpriteCache>, mut images: ResMut<Assets<Image>>) {
-    info!(
-        "Loading {} ship sprites (WASM mode)...",
-        SHIPS_TO_LOAD.len()
-    );
+    let ships = cache.preload_list();
+    info!("Loading {} ship sprites (WASM mode)...", ships.len());
 
     let bundled_dir = PathBuf::from(BUNDLED_SHIPS_DIR);
     let mut loaded = 0;
 
-    for &type_id in SHIPS_TO_LOAD {
+    for type_id in ships {
         let bundled_path = bundled_dir.join(format!("{}.png", type_id));
         if bundled_path.exists() {
             match load_image_file(&bundled_path) {
@@ -371,7 +390,7 @@ fn check_sprite_loading(
     *timer = 0.0;
 
     if cache.ready {
-        next_state.set(GameState::MainMenu);
+        next_state.set(GameState::ProfileSelect);
         return;
     }
 
@@ -414,7 +433,7 @@ fn check_sprite_loading(
 #[cfg(target_arch = "wasm32")]
 fn check_sprite_loading(cache: Res<ShipSpriteCache>, mut next_state: ResMut<NextState<GameState>>) {
     if cache.ready {
-        next_state.set(GameState::MainMenu);
+        next_state.set(GameState::ProfileSelect);
     }
 }
 
@@ -0,0 +1,498 @@
+//! Accessibility
+//!
+//! Colorblind-safe palettes, photosensitivity limits, UI text scaling,
+//! alternative fire modes, game speed, menu narration and HUD audio cues.
+//! Settings persist via `GameSettings`.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::core::*;
+use crate::systems::effects::ScreenFlash;
+use crate::systems::heat::HeatLevel;
+
+/// Text scale bounds (1.0 = authored size)
+pub const TEXT_SCALE_MIN: f32 = 0.75;
+pub const TEXT_SCALE_MAX: f32 = 1.5;
+
+/// Game speed bounds (1.0 = full speed)
+pub const GAME_SPEED_MIN: f32 = 0.5;
+pub const GAME_SPEED_MAX: f32 = 1.0;
+
+/// Brightest a screen flash may get with reduced flashing on
+pub const REDUCED_FLASH_MAX_INTENSITY: f32 = 0.25;
+/// Minimum seconds between screen flashes with reduced flashing on (< 3 per second)
+pub const REDUCED_FLASH_MIN_INTERVAL: f32 = 0.4;
+/// How much of a sprite hit flash is kept with reduced flashing on
+pub const REDUCED_HIT_FLASH_STRENGTH: f32 = 0.35;
+
+/// Accessibility plugin
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AccessibilitySettings>()
+            .init_resource::<Palette>()
+            .add_systems(
+                Update,
+                (
+                    (update_palette, update_flash_limits)
+                        .run_if(resource_changed::<AccessibilitySettings>),
+                    scale_ui_text,
+                    apply_game_speed,
+                ),
+            );
+    }
+}
+
+/// Colorblind palette variants
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorblindMode {
+    #[default]
+    Off,
+    /// Red-green (green-weak)
+    Deuteranopia,
+    /// Red-green (red-weak)
+    Protanopia,
+    /// Blue-yellow
+    Tritanopia,
+}
+
+impl ColorblindMode {
+    pub const ALL: [ColorblindMode; 4] = [
+        ColorblindMode::Off,
+        ColorblindMode::Deuteranopia,
+        ColorblindMode::Protanopia,
+        ColorblindMode::Tritanopia,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorblindMode::Off => "Off",
+            ColorblindMode::Deuteranopia => "Deuteranopia",
+            ColorblindMode::Protanopia => "Protanopia",
+            ColorblindMode::Tritanopia => "Tritanopia",
+        }
+    }
+
+    /// Step through modes, wrapping at either end
+    pub fn cycle(&self, dir: i32) -> Self {
+        let idx = Self::ALL.iter().position(|m| m == self).unwrap_or(0) as i32;
+        Self::ALL[(idx + dir).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+}
+
+/// How the fire button behaves
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FireMode {
+    /// Fire while the button is held
+    #[default]
+    Hold,
+    /// Press once to start firing, again to stop
+    Toggle,
+    /// Always firing
+    Auto,
+}
+
+impl FireMode {
+    pub const ALL: [FireMode; 3] = [FireMode::Hold, FireMode::Toggle, FireMode::Auto];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FireMode::Hold => "Hold",
+            FireMode::Toggle => "Toggle",
+            FireMode::Auto => "Auto-fire",
+        }
+    }
+
+    pub fn cycle(&self, dir: i32) -> Self {
+        let idx = Self::ALL.iter().position(|m| m == self).unwrap_or(0) as i32;
+        Self::ALL[(idx + dir).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+}
+
+/// Where menu narration goes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NarrationMode {
+    #[default]
+    Off,
+    /// Write narration lines to stdout for external screen readers
+    Text,
+    /// Speak through the platform text-to-speech command (also writes text)
+    Speech,
+}
+
+impl NarrationMode {
+    pub const ALL: [NarrationMode; 3] = [
+        NarrationMode::Off,
+        NarrationMode::Text,
+        NarrationMode::Speech,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            NarrationMode::Off => "Off",
+            NarrationMode::Text => "Text",
+            NarrationMode::Speech => "Speech",
+        }
+    }
+
+    pub fn cycle(&self, dir: i32) -> Self {
+        let idx = Self::ALL.iter().position(|m| m == self).unwrap_or(0) as i32;
+        Self::ALL[(idx + dir).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+}
+
+/// Player-facing accessibility options (persisted in `GameSettings`)
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    pub colorblind_mode: ColorblindMode,
+    /// Cap screen flash intensity/frequency and soften hit flashes
+    pub reduce_flashing: bool,
+    /// UI text scale multiplier
+    pub text_scale: f32,
+    pub fire_mode: FireMode,
+    /// Gameplay speed multiplier
+    pub game_speed: f32,
+    pub narration: NarrationMode,
+    /// Extra sounds for HUD state: ability ready, wave start, boss phase, health thresholds
+    pub audio_cues: bool,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            colorblind_mode: ColorblindMode::Off,
+            reduce_flashing: false,
+            text_scale: 1.0,
+            fire_mode: FireMode::Hold,
+            game_speed: 1.0,
+            narration: NarrationMode::Off,
+            audio_cues: false,
+        }
+    }
+}
+
+// =============================================================================
+// PALETTE
+// =============================================================================
+
+/// Central color palette for color-coded feedback.
+/// Gameplay and HUD code should read these instead of hardcoding colors.
+#[derive(Resource, Debug, Clone)]
+pub struct Palette {
+    pub shield: Color,
+    pub armor: Color,
+    pub hull: Color,
+    /// Indexed by `HeatLevel` (Cool, Warm, Hot, Overheated)
+    pub heat: [Color; 4],
+    /// Indexed by `AmmoType` (Sabot, EMP, Plasma, Fusion, Barrage, Hail, Tremor)
+    pub ammo: [Color; 7],
+    /// Indexed by `StyleGrade` (D through SSS)
+    pub grade: [Color; 7],
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::for_mode(ColorblindMode::Off)
+    }
+}
+
+impl Palette {
+    pub fn for_mode(mode: ColorblindMode) -> Self {
+        match mode {
+            ColorblindMode::Off => Self {
+                shield: Color::srgb(0.3, 0.7, 1.0),
+                armor: Color::srgb(1.0, 0.7, 0.3),
+                hull: Color::srgb(1.0, 0.4, 0.1),
+                heat: [
+                    HeatLevel::Cool.color(),
+                    HeatLevel::Warm.color(),
+                    HeatLevel::Hot.color(),
+                    HeatLevel::Overheated.color(),
+                ],
+                ammo: [
+                    AmmoType::Sabot.color(),
+                    AmmoType::EMP.color(),
+                    AmmoType::Plasma.color(),
+                    AmmoType::Fusion.color(),
+                    AmmoType::Barrage.color(),
+                    AmmoType::Hail.color(),
+                    AmmoType::Tremor.color(),
+                ],
+                grade: [
+                    StyleGrade::D.color(),
+                    StyleGrade::C.color(),
+                    StyleGrade::B.color(),
+                    StyleGrade::A.color(),
+                    StyleGrade::S.color(),
+                    StyleGrade::SS.color(),
+                    StyleGrade::SSS.color(),
+                ],
+            },
+            // Red/green confusion: lean on blue vs. yellow/orange and brightness steps
+            ColorblindMode::Deuteranopia | ColorblindMode::Protanopia => Self {
+                shield: Color::srgb(0.35, 0.7, 0.9),
+                armor: Color::srgb(0.95, 0.9, 0.25),
+                hull: Color::srgb(0.85, 0.35, 0.0),
+                heat: [
+                    Color::srgb(0.0, 0.45, 0.7),
+                    Color::srgb(0.35, 0.7, 0.9),
+                    Color::srgb(0.95, 0.9, 0.25),
+                    Color::srgb(0.85, 0.35, 0.0),
+                ],
+                ammo: [
+                    Color::srgb(0.7, 0.7, 0.7),
+                    Color::srgb(0.0, 0.45, 0.7),
+                    Color::srgb(0.9, 0.6, 0.0),
+                    Color::srgb(0.8, 0.45, 0.65),
+                    Color::srgb(0.95, 0.9, 0.25),
+                    Color::srgb(0.45, 0.3, 0.75),
+                    Color::srgb(1.0, 1.0, 1.0),
+                ],
+                grade: [
+                    Color::srgb(0.5, 0.5, 0.5),
+                    Color::srgb(0.75, 0.75, 0.75),
+                    Color::srgb(0.35, 0.7, 0.9),
+                    Color::srgb(0.0, 0.45, 0.7),
+                    Color::srgb(0.95, 0.9, 0.25),
+                    Color::srgb(0.9, 0.6, 0.0),
+                    Color::srgb(0.85, 0.35, 0.0),
+                ],
+            },
+            // Blue/yellow confusion: lean on teal vs. red/pink and brightness steps
+            ColorblindMode::Tritanopia => Self {
+                shield: Color::srgb(0.0, 0.75, 0.75),
+                armor: Color::srgb(1.0, 0.6, 0.7),
+                hull: Color::srgb(0.85, 0.1, 0.1),
+                heat: [
+                    Color::srgb(0.0, 0.6, 0.6),
+                    Color::srgb(1.0, 0.75, 0.8),
+                    Color::srgb(1.0, 0.4, 0.4),
+                    Color::srgb(0.8, 0.0, 0.0),
+                ],
+                ammo: [
+                    Color::srgb(0.7, 0.7, 0.7),
+                    Color::srgb(0.0, 0.75, 0.75),
+                    Color::srgb(1.0, 0.6, 0.7),
+                    Color::srgb(0.85, 0.1, 0.1),
+                    Color::srgb(1.0, 1.0, 1.0),
+                    Color::srgb(0.55, 0.0, 0.3),
+                    Color::srgb(0.4, 0.4, 0.4),
+                ],
+                grade: [
+                    Color::srgb(0.5, 0.5, 0.5),
+                    Color::srgb(0.75, 0.75, 0.75),
+                    Color::srgb(0.0, 0.6, 0.6),
+                    Color::srgb(0.0, 0.8, 0.8),
+                    Color::srgb(1.0, 0.75, 0.8),
+                    Color::srgb(1.0, 0.4, 0.4),
+                    Color::srgb(0.85, 0.1, 0.1),
+                ],
+            },
+        }
+    }
+
+    pub fn heat(&self, level: HeatLevel) -> Color {
+        match level {
+            HeatLevel::Cool => self.heat[0],
+            HeatLevel::Warm => self.heat[1],
+            HeatLevel::Hot => self.heat[2],
+            HeatLevel::Overheated => self.heat[3],
+        }
+    }
+
+    pub fn ammo(&self, ammo: AmmoType) -> Color {
+        match ammo {
+            AmmoType::Sabot => self.ammo[0],
+            AmmoType::EMP => self.ammo[1],
+            AmmoType::Plasma => self.ammo[2],
+            AmmoType::Fusion => self.ammo[3],
+            AmmoType::Barrage => self.ammo[4],
+            AmmoType::Hail => self.ammo[5],
+            AmmoType::Tremor => self.ammo[6],
+        }
+    }
+
+    pub fn grade(&self, grade: StyleGrade) -> Color {
+        match grade {
+            StyleGrade::D => self.grade[0],
+            StyleGrade::C => self.grade[1],
+            StyleGrade::B => self.grade[2],
+            StyleGrade::A => self.grade[3],
+            StyleGrade::S => self.grade[4],
+            StyleGrade::SS => self.grade[5],
+            StyleGrade::SSS => self.grade[6],
+        }
+    }
+
+    pub fn layer(&self, layer: DamageLayer) -> Color {
+        match layer {
+            DamageLayer::Shield => self.shield,
+            DamageLayer::Armor => self.armor,
+            DamageLayer::Hull => self.hull,
+        }
+    }
+}
+
+/// Blend a color toward white (0.0 = unchanged, 1.0 = white)
+pub fn lighten(color: Color, amount: f32) -> Color {
+    let c = color.to_srgba();
+    Color::srgba(
+        c.red + (1.0 - c.red) * amount,
+        c.green + (1.0 - c.green) * amount,
+        c.blue + (1.0 - c.blue) * amount,
+        c.alpha,
+    )
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Rebuild the palette when the colorblind mode changes
+fn update_palette(settings: Res<AccessibilitySettings>, mut palette: ResMut<Palette>) {
+    *palette = Palette::for_mode(settings.colorblind_mode);
+}
+
+/// Push photosensitivity limits into the screen flash
+fn update_flash_limits(settings: Res<AccessibilitySettings>, mut flash: ResMut<ScreenFlash>) {
+    if settings.reduce_flashing {
+        flash.max_intensity = REDUCED_FLASH_MAX_INTENSITY;
+        flash.min_interval = REDUCED_FLASH_MIN_INTERVAL;
+        flash.intensity = flash.intensity.min(REDUCED_FLASH_MAX_INTENSITY);
+    } else {
+        flash.max_intensity = 1.0;
+        flash.min_interval = 0.0;
+    }
+}
+
+/// Authored font size of a UI text node, before text scaling
+#[derive(Component)]
+pub struct BaseFontSize(pub f32);
+
+/// Apply the text scale to new UI text, and to all UI text when it changes
+fn scale_ui_text(
+    mut commands: Commands,
+    settings: Res<AccessibilitySettings>,
+    mut new_text: Query<(Entity, &mut TextFont), (With<Text>, Without<BaseFontSize>)>,
+    mut scaled_text: Query<(&mut TextFont, &BaseFontSize)>,
+) {
+    for (entity, mut font) in new_text.iter_mut() {
+        commands.entity(entity).insert(BaseFontSize(font.font_size));
+        if settings.text_scale != 1.0 {
+            font.font_size *= settings.text_scale;
+        }
+    }
+
+    if settings.is_changed() {
+        for (mut font, base) in scaled_text.iter_mut() {
+            let size = base.0 * settings.text_scale;
+            if font.font_size != size {
+                font.font_size = size;
+            }
+        }
+    }
+}
+
+/// Slow down virtual time during gameplay; menus always run at full speed
+fn apply_game_speed(
+    settings: Res<AccessibilitySettings>,
+    state: Res<State<GameState>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    let in_gameplay = matches!(
+        state.get(),
+        GameState::Playing | GameState::BossIntro | GameState::BossFight
+    );
+    let speed = if in_gameplay {
+        settings.game_speed.clamp(GAME_SPEED_MIN, GAME_SPEED_MAX)
+    } else {
+        1.0
+    };
+    if time.relative_speed() != speed {
+        time.set_relative_speed(speed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_palette_matches_legacy_colors() {
+        let palette = Palette::default();
+        assert_eq!(palette.heat(HeatLevel::Hot), HeatLevel::Hot.color());
+        assert_eq!(palette.ammo(AmmoType::EMP), AmmoType::EMP.color());
+        assert_eq!(palette.grade(StyleGrade::SS), StyleGrade::SS.color());
+    }
+
+    #[test]
+    fn colorblind_palettes_keep_entries_distinct() {
+        for mode in ColorblindMode::ALL {
+            let palette = Palette::for_mode(mode);
+            for (i, a) in palette.heat.iter().enumerate() {
+                for b in &palette.heat[i + 1..] {
+                    assert_ne!(a, b, "{:?} heat colors collide", mode);
+                }
+            }
+            for (i, a) in palette.ammo.iter().enumerate() {
+                for b in &palette.ammo[i + 1..] {
+                    assert_ne!(a, b, "{:?} ammo colors collide", mode);
+                }
+            }
+            assert_ne!(palette.shield, palette.armor);
+            assert_ne!(palette.armor, palette.hull);
+        }
+    }
+
+    #[test]
+    fn modes_cycle_both_ways() {
+        assert_eq!(ColorblindMode::Off.cycle(-1), ColorblindMode::Tritanopia);
+        assert_eq!(ColorblindMode::Tritanopia.cycle(1), ColorblindMode::Off);
+        assert_eq!(FireMode::Hold.cycle(1), FireMode::Toggle);
+        assert_eq!(FireMode::Hold.cycle(-1), FireMode::Auto);
+        assert_eq!(NarrationMode::Off.cycle(-1), NarrationMode::Speech);
+    }
+
+    #[test]
+    fn reduced_flashing_caps_intensity_and_rate() {
+        let mut flash = ScreenFlash {
+            max_intensity: REDUCED_FLASH_MAX_INTENSITY,
+            min_interval: REDUCED_FLASH_MIN_INTERVAL,
+            ..default()
+        };
+
+        flash.massive();
+        assert_eq!(flash.intensity, REDUCED_FLASH_MAX_INTENSITY);
+
+        // A second flash inside the interval is dropped
+        flash.intensity = 0.0;
+        flash.large();
+        assert_eq!(flash.intensity, 0.0);
+
+        flash.tick(REDUCED_FLASH_MIN_INTERVAL);
+        flash.large();
+        assert!(flash.intensity > 0.0);
+    }
+
+    #[test]
+    fn unlimited_flash_by_default() {
+        let mut flash = ScreenFlash::default();
+        flash.massive();
+        assert_eq!(flash.intensity, 0.8);
+        flash.large();
+        assert_eq!(flash.intensity, 0.5);
+    }
+
+    #[test]
+    fn lighten_moves_toward_white() {
+        let c = lighten(Color::srgb(0.0, 0.5, 1.0), 0.5).to_srgba();
+        assert!((c.red - 0.5).abs() < 1e-6);
+        assert!((c.green - 0.75).abs() < 1e-6);
+        assert!((c.blue - 1.0).abs() < 1e-6);
+    }
+}
@@ -1,9 +1,21 @@
 //! Achievement System
 //!
-//! Tracks player achievements and unlocks.
+//! Achievements are data. Each [`AchievementDef`] names a stat, the
+//! threshold it must reach, the scope the stat is counted over (one
+//! mission, one run, or the pilot's lifetime) and optional module and
+//! faction filters. Stats are fed by [`AchievementProgressEvent`]s - the
+//! core sends them from gameplay events and finished runs, modules send
+//! their own - and a single evaluator unlocks whatever crossed its
+//! threshold. Modules contribute sets through `GameModule::achievements`.
+
+#![allow(dead_code)]
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use AchievementScope::{Lifetime, Mission, Run};
+use AchievementTier::{Gold, Silver};
 
 /// Achievement plugin
 pub struct AchievementPlugin;
@@ -11,13 +23,20 @@ pub struct AchievementPlugin;
 impl Plugin for AchievementPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AchievementTracker>()
+            .init_resource::<AchievementRegistry>()
             .init_resource::<AchievementPopupState>()
             .add_event::<AchievementUnlockedEvent>()
+            .add_event::<AchievementProgressEvent>()
             .add_systems(
                 Update,
                 (
-                    reset_tracker_on_mission_start,
-                    check_achievements.run_if(in_state(super::GameState::Playing)),
+                    reset_achievement_scopes,
+                    feed_gameplay_stats.run_if(
+                        in_state(super::GameState::Playing)
+                            .or(in_state(super::GameState::BossFight)),
+                    ),
+                    feed_run_results,
+                    evaluate_achievements,
                     process_achievement_unlocks,
                 )
                     .chain(),
@@ -25,271 +44,272 @@ impl Plugin for AchievementPlugin {
     }
 }
 
-/// Reset achievement tracker when a new mission starts
-fn reset_tracker_on_mission_start(
-    mut tracker: ResMut<AchievementTracker>,
-    mut mission_events: EventReader<super::MissionStartEvent>,
-    time: Res<Time>,
-) {
-    for _event in mission_events.read() {
-        tracker.reset_session();
-        tracker.start_mission(time.elapsed_secs_f64());
-    }
-}
-
-fn in_state(state: super::GameState) -> impl FnMut(Option<Res<State<super::GameState>>>) -> bool {
-    move |current: Option<Res<State<super::GameState>>>| {
-        current.map(|s| *s.get() == state).unwrap_or(false)
-    }
-}
+// =============================================================================
+// DEFINITIONS
+// =============================================================================
 
-/// All available achievements
+/// What an achievement's stat is counted over
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Achievement {
-    // === Combat Achievements ===
-    /// Destroy your first enemy
-    FirstBlood,
-    /// Destroy 100 enemies in a single run
-    Centurion,
-    /// Destroy 500 enemies in a single run
-    Exterminator,
-    /// Destroy 1000 enemies in a single run
-    Annihilator,
-
-    // === Salt Miner/Combo Achievements ===
-    /// Get a 10x combo
-    ComboStarter,
-    /// Get a 25x combo
-    ComboKing,
-    /// Get a 50x combo
-    ComboMaster,
-    /// Activate salt miner mode
-    SaltMinerActivated,
-    /// Get 10 kills while salt miner is active
-    SaltMinerKiller,
-
-    // === Liberation Achievements ===
-    /// Liberate 25 souls in a single run
-    Liberator,
-    /// Liberate 100 souls in a single run
-    FreedomFighter,
-    /// Liberate 250 souls in a single run
-    Emancipator,
-
-    // === Progression Achievements ===
-    /// Complete Mission 1
-    FirstMission,
-    /// Complete Act 1 (Missions 1-4)
-    ActOneComplete,
-    /// Complete Act 2 (Missions 5-9)
-    ActTwoComplete,
-    /// Complete Act 3 and defeat Avatar (Missions 10-13)
-    CampaignVictory,
-    /// Complete a mission without taking damage
-    Flawless,
-
-    // === Boss Achievements ===
-    /// Defeat your first boss
-    BossSlayer,
-    /// Defeat the Apocalypse
-    ApocalypseFallen,
-    /// Defeat the Avatar titan
-    TitanKiller,
-
-    // === Score Achievements ===
-    /// Score 25,000 points
-    ScoreRookie,
-    /// Score 50,000 points
-    ScoreVeteran,
-    /// Score 100,000 points
-    ScoreMaster,
-    /// Score 250,000 points
-    ScoreLegend,
-
-    // === Ship Achievements ===
-    /// Complete a mission with the Rifter
-    RifterPilot,
-    /// Complete a mission with the Slasher
-    SlasherPilot,
-    /// Unlock a T2 ship
-    T2Unlocked,
-
-    // === Difficulty Achievements ===
-    /// Complete any mission on BitterVet difficulty
-    BitterVetWarrior,
-    /// Complete any mission on Triglavian (Nightmare) difficulty
-    TriglavianConqueror,
-
-    // === Special/Hidden Achievements ===
-    /// Get hit by a boss attack and survive with <10% health
-    CloseCall,
-    /// Complete a mission in under 3 minutes
-    SpeedRunner,
+pub enum AchievementScope {
+    /// Reset whenever a mission starts
+    Mission,
+    /// Reset when a new campaign or run is picked
+    Run,
+    /// Never reset; saved with the pilot
+    Lifetime,
 }
 
-impl Achievement {
-    /// Display name
+impl AchievementScope {
+    pub const ALL: [AchievementScope; 3] = [
+        AchievementScope::Mission,
+        AchievementScope::Run,
+        AchievementScope::Lifetime,
+    ];
+
     pub fn name(&self) -> &'static str {
         match self {
-            // Combat
-            Achievement::FirstBlood => "First Blood",
-            Achievement::Centurion => "Centurion",
-            Achievement::Exterminator => "Exterminator",
-            Achievement::Annihilator => "Annihilator",
-            // Salt Miner
-            Achievement::ComboStarter => "Combo Starter",
-            Achievement::ComboKing => "Combo King",
-            Achievement::ComboMaster => "Combo Master",
-            Achievement::SaltMinerActivated => "Salt Miner",
-            Achievement::SaltMinerKiller => "Salt Miner Killer",
-            // Liberation
-            Achievement::Liberator => "Liberator",
-            Achievement::FreedomFighter => "Freedom Fighter",
-            Achievement::Emancipator => "Emancipator",
-            // Progression
-            Achievement::FirstMission => "First Mission",
-            Achievement::ActOneComplete => "Act I Complete",
-            Achievement::ActTwoComplete => "Act II Complete",
-            Achievement::CampaignVictory => "Campaign Victory",
-            Achievement::Flawless => "Flawless",
-            // Boss
-            Achievement::BossSlayer => "Boss Slayer",
-            Achievement::ApocalypseFallen => "Apocalypse Fallen",
-            Achievement::TitanKiller => "Titan Killer",
-            // Score
-            Achievement::ScoreRookie => "Score Rookie",
-            Achievement::ScoreVeteran => "Score Veteran",
-            Achievement::ScoreMaster => "Score Master",
-            Achievement::ScoreLegend => "Score Legend",
-            // Ship
-            Achievement::RifterPilot => "Rifter Pilot",
-            Achievement::SlasherPilot => "Slasher Pilot",
-            Achievement::T2Unlocked => "T2 Unlocked",
-            // Difficulty
-            Achievement::BitterVetWarrior => "Bitter Vet Warrior",
-            Achievement::TriglavianConqueror => "Triglavian Conqueror",
-            // Special
-            Achievement::CloseCall => "Close Call",
-            Achievement::SpeedRunner => "Speed Runner",
+            AchievementScope::Mission => "MISSION",
+            AchievementScope::Run => "RUN",
+            AchievementScope::Lifetime => "LIFETIME",
         }
     }
 
-    /// Achievement description
-    pub fn description(&self) -> &'static str {
+    /// Key a stat is saved under: lifetime totals by name, the best
+    /// mission and run values with a scope prefix
+    pub fn saved_key(&self, stat: &str) -> String {
         match self {
-            // Combat
-            Achievement::FirstBlood => "Destroy your first enemy",
-            Achievement::Centurion => "Destroy 100 enemies in a single run",
-            Achievement::Exterminator => "Destroy 500 enemies in a single run",
-            Achievement::Annihilator => "Destroy 1000 enemies in a single run",
-            // Salt Miner
-            Achievement::ComboStarter => "Get a 10x combo",
-            Achievement::ComboKing => "Get a 25x combo",
-            Achievement::ComboMaster => "Get a 50x combo",
-            Achievement::SaltMinerActivated => "Activate salt miner mode",
-            Achievement::SaltMinerKiller => "Get 10 kills while salt miner is active",
-            // Liberation
-            Achievement::Liberator => "Liberate 25 souls in a single run",
-            Achievement::FreedomFighter => "Liberate 100 souls in a single run",
-            Achievement::Emancipator => "Liberate 250 souls in a single run",
-            // Progression
-            Achievement::FirstMission => "Complete your first mission",
-            Achievement::ActOneComplete => "Complete Act I - Liberation Begins",
-            Achievement::ActTwoComplete => "Complete Act II - Reclaiming Freedom",
-            Achievement::CampaignVictory => "Defeat the Avatar and complete the campaign",
-            Achievement::Flawless => "Complete a mission without taking damage",
-            // Boss
-            Achievement::BossSlayer => "Defeat your first boss",
-            Achievement::ApocalypseFallen => "Defeat an Apocalypse-class battleship",
-            Achievement::TitanKiller => "Defeat the Avatar titan",
-            // Score
-            Achievement::ScoreRookie => "Score 25,000 points in a single run",
-            Achievement::ScoreVeteran => "Score 50,000 points in a single run",
-            Achievement::ScoreMaster => "Score 100,000 points in a single run",
-            Achievement::ScoreLegend => "Score 250,000 points in a single run",
-            // Ship
-            Achievement::RifterPilot => "Complete a mission with the Rifter",
-            Achievement::SlasherPilot => "Complete a mission with the Slasher",
-            Achievement::T2Unlocked => "Unlock a Tech 2 assault frigate",
-            // Difficulty
-            Achievement::BitterVetWarrior => "Complete a mission on BitterVet difficulty",
-            Achievement::TriglavianConqueror => "Complete a mission on Triglavian difficulty",
-            // Special
-            Achievement::CloseCall => "Survive a boss hit with less than 10% health",
-            Achievement::SpeedRunner => "Complete a mission in under 3 minutes",
+            AchievementScope::Mission => format!("mission:{}", stat),
+            AchievementScope::Run => format!("run:{}", stat),
+            AchievementScope::Lifetime => stat.to_string(),
         }
     }
+}
 
-    /// Whether this achievement is hidden until unlocked
-    pub fn is_hidden(&self) -> bool {
-        matches!(
-            self,
-            Achievement::CloseCall
-                | Achievement::SpeedRunner
-                | Achievement::Annihilator
-                | Achievement::TitanKiller
-                | Achievement::TriglavianConqueror
-        )
+/// Medal tier, used for the popup and browser color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AchievementTier {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+/// One achievement, declared as data
+#[derive(Debug, Clone, Copy)]
+pub struct AchievementDef {
+    /// Stable id stored in saves
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Stat key (see the `STAT_*` constants and [`stat_key`])
+    pub stat: &'static str,
+    /// Value the stat must reach
+    pub threshold: u64,
+    pub scope: AchievementScope,
+    /// Module ids it can be earned in; empty for any
+    pub modules: &'static [&'static str],
+    /// Player factions (lowercase short names) it can be earned as; empty for any
+    pub factions: &'static [&'static str],
+    /// Shown as "???" until unlocked
+    pub hidden: bool,
+    pub tier: AchievementTier,
+}
+
+impl AchievementDef {
+    /// Bronze entry with no filters; see the builder methods below
+    pub const fn new(
+        id: &'static str,
+        name: &'static str,
+        description: &'static str,
+        stat: &'static str,
+        threshold: u64,
+        scope: AchievementScope,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            description,
+            stat,
+            threshold,
+            scope,
+            modules: &[],
+            factions: &[],
+            hidden: false,
+            tier: AchievementTier::Bronze,
+        }
+    }
+
+    pub const fn tier(mut self, tier: AchievementTier) -> Self {
+        self.tier = tier;
+        self
+    }
+
+    pub const fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    pub const fn modules(mut self, modules: &'static [&'static str]) -> Self {
+        self.modules = modules;
+        self
+    }
+
+    pub const fn factions(mut self, factions: &'static [&'static str]) -> Self {
+        self.factions = factions;
+        self
     }
 
     /// Achievement color for UI
     pub fn color(&self) -> Color {
-        match self {
-            // Gold for major achievements
-            Achievement::CampaignVictory
-            | Achievement::TitanKiller
-            | Achievement::ScoreLegend
-            | Achievement::ComboMaster
-            | Achievement::TriglavianConqueror => Color::srgb(1.0, 0.85, 0.2),
-
-            // Silver for mid-tier
-            Achievement::ActOneComplete
-            | Achievement::ActTwoComplete
-            | Achievement::ScoreMaster
-            | Achievement::ComboKing
-            | Achievement::Exterminator
-            | Achievement::Emancipator => Color::srgb(0.8, 0.8, 0.9),
-
-            // Bronze for entry-level
-            _ => Color::srgb(0.8, 0.5, 0.3),
+        match self.tier {
+            AchievementTier::Gold => Color::srgb(1.0, 0.85, 0.2),
+            AchievementTier::Silver => Color::srgb(0.8, 0.8, 0.9),
+            AchievementTier::Bronze => Color::srgb(0.8, 0.5, 0.3),
+        }
+    }
+
+    /// Whether it can be earned in this module as this faction
+    pub fn applies(&self, module: Option<&str>, faction: &str) -> bool {
+        let module_ok =
+            self.modules.is_empty() || module.is_some_and(|m| self.modules.contains(&m));
+        let faction_ok = self.factions.is_empty()
+            || self
+                .factions
+                .iter()
+                .any(|f| f.eq_ignore_ascii_case(faction));
+        module_ok && faction_ok
+    }
+}
+
+/// A named group of achievements (a browser tab)
+#[derive(Debug)]
+pub struct AchievementSet {
+    pub name: &'static str,
+    pub achievements: &'static [AchievementDef],
+}
+
+/// Every achievement set: the general one plus those modules register
+#[derive(Resource, Debug)]
+pub struct AchievementRegistry {
+    pub sets: Vec<&'static AchievementSet>,
+}
+
+impl Default for AchievementRegistry {
+    fn default() -> Self {
+        Self {
+            sets: vec![&GENERAL_ACHIEVEMENTS],
+        }
+    }
+}
+
+impl AchievementRegistry {
+    pub fn register(&mut self, sets: &'static [AchievementSet]) {
+        self.sets.extend(sets.iter());
+    }
+
+    pub fn all(&self) -> impl Iterator<Item = &'static AchievementDef> + '_ {
+        self.sets.iter().flat_map(|set| set.achievements.iter())
+    }
+
+    pub fn get(&self, id: &str) -> Option<&'static AchievementDef> {
+        self.all().find(|def| def.id == id)
+    }
+}
+
+// =============================================================================
+// STATS
+// =============================================================================
+
+/// Enemies destroyed (also counted per module: `kills/<module>`)
+pub const STAT_KILLS: &str = "kills";
+/// Bosses defeated (also counted per word of the boss name:
+/// `bosses_killed/apocalypse`)
+pub const STAT_BOSSES_KILLED: &str = "bosses_killed";
+/// Highest combo reached
+pub const STAT_COMBO: &str = "combo";
+/// Highest score reached
+pub const STAT_SCORE: &str = "score";
+/// Souls liberated (Elder Fleet)
+pub const STAT_SOULS: &str = "souls";
+pub const STAT_SALT_MINER_ACTIVATIONS: &str = "salt_miner_activations";
+pub const STAT_SALT_MINER_KILLS: &str = "salt_miner_kills";
+/// Boss hits survived below 10% health
+pub const STAT_CLOSE_CALLS: &str = "close_calls";
+/// Missions completed (also per module and faction:
+/// `missions_completed/caldari_gallente`, `missions_completed/caldari`)
+pub const STAT_MISSIONS_COMPLETED: &str = "missions_completed";
+/// Missions completed per difficulty: `difficulty_missions/bitter_vet`
+pub const STAT_DIFFICULTY_MISSIONS: &str = "difficulty_missions";
+/// Missions completed per ship: `ship_missions/rifter`
+pub const STAT_SHIP_MISSIONS: &str = "ship_missions";
+/// Missions completed without taking damage
+pub const STAT_FLAWLESS_MISSIONS: &str = "flawless_missions";
+/// Missions completed in under three minutes
+pub const STAT_FAST_MISSIONS: &str = "fast_missions";
+/// Campaigns or modes won (also per module and faction)
+pub const STAT_VICTORIES: &str = "victories";
+/// Highest Elder Fleet act completed
+pub const STAT_ACTS_COMPLETED: &str = "acts_completed";
+/// Tech 2 ships unlocked
+pub const STAT_T2_SHIPS: &str = "t2_ships";
+
+/// Mission length that counts as fast
+const FAST_MISSION_SECS: f32 = 180.0;
+
+/// Stat narrowed to a subject: `stat_key("difficulty_missions", "Bitter Vet")`
+/// is `difficulty_missions/bitter_vet`
+pub fn stat_key(stat: &str, subject: &str) -> String {
+    let slug: String = subject
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}/{}", stat, slug)
+}
+
+/// How a progress event changes a stat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatUpdate {
+    /// Count up
+    Add(u64),
+    /// Keep the highest value seen
+    Peak(u64),
+}
+
+impl StatUpdate {
+    fn apply(&self, value: &mut u64) {
+        match *self {
+            StatUpdate::Add(amount) => *value += amount,
+            StatUpdate::Peak(peak) => *value = (*value).max(peak),
         }
     }
+}
+
+/// Progress on a stat, from the core or a module
+#[derive(Event, Debug, Clone)]
+pub struct AchievementProgressEvent {
+    pub stat: String,
+    pub update: StatUpdate,
+}
 
-    /// Get all achievements
-    pub fn all() -> &'static [Achievement] {
-        &[
-            Achievement::FirstBlood,
-            Achievement::Centurion,
-            Achievement::Exterminator,
-            Achievement::Annihilator,
-            Achievement::ComboStarter,
-            Achievement::ComboKing,
-            Achievement::ComboMaster,
-            Achievement::SaltMinerActivated,
-            Achievement::SaltMinerKiller,
-            Achievement::Liberator,
-            Achievement::FreedomFighter,
-            Achievement::Emancipator,
-            Achievement::FirstMission,
-            Achievement::ActOneComplete,
-            Achievement::ActTwoComplete,
-            Achievement::CampaignVictory,
-            Achievement::Flawless,
-            Achievement::BossSlayer,
-            Achievement::ApocalypseFallen,
-            Achievement::TitanKiller,
-            Achievement::ScoreRookie,
-            Achievement::ScoreVeteran,
-            Achievement::ScoreMaster,
-            Achievement::ScoreLegend,
-            Achievement::RifterPilot,
-            Achievement::SlasherPilot,
-            Achievement::T2Unlocked,
-            Achievement::BitterVetWarrior,
-            Achievement::TriglavianConqueror,
-            Achievement::CloseCall,
-            Achievement::SpeedRunner,
-        ]
+impl AchievementProgressEvent {
+    pub fn add(stat: impl Into<String>, amount: u64) -> Self {
+        Self {
+            stat: stat.into(),
+            update: StatUpdate::Add(amount),
+        }
+    }
+
+    pub fn peak(stat: impl Into<String>, value: u64) -> Self {
+        Self {
+            stat: stat.into(),
+            update: StatUpdate::Peak(value),
+        }
     }
 }
 
@@ -297,41 +317,64 @@ impl Achievement {
 #[derive(Resource, Default)]
 pub struct AchievementTracker {
     /// Achievements unlocked this session (for notifications)
-    pub pending_notifications: Vec<Achievement>,
-    /// Session stats for checking achievements
-    pub session_kills: u32,
-    pub session_souls: u32,
-    pub session_max_combo: u32,
-    pub session_salt_miner_kills: u32,
-    pub session_bosses_killed: u32,
-    pub mission_start_time: Option<f64>,
-    pub took_damage_this_mission: bool,
+    pub pending_notifications: Vec<&'static AchievementDef>,
+    /// Stats of the current mission
+    pub mission: HashMap<String, u64>,
+    /// Stats of the current run
+    pub run: HashMap<String, u64>,
 }
 
 impl AchievementTracker {
-    /// Reset session stats (call at mission start)
-    pub fn reset_session(&mut self) {
-        self.session_kills = 0;
-        self.session_souls = 0;
-        self.session_max_combo = 0;
-        self.session_salt_miner_kills = 0;
-        self.session_bosses_killed = 0;
-        self.mission_start_time = None;
-        self.took_damage_this_mission = false;
-    }
-
-    /// Start mission timer
-    pub fn start_mission(&mut self, time: f64) {
-        self.mission_start_time = Some(time);
-        self.took_damage_this_mission = false;
+    /// Apply an update to every scope. `saved` holds lifetime totals and
+    /// the best mission and run values (see [`AchievementScope::saved_key`]).
+    pub fn apply(&mut self, stat: &str, update: StatUpdate, saved: &mut HashMap<String, u64>) {
+        for (scope, values) in [
+            (AchievementScope::Mission, &mut self.mission),
+            (AchievementScope::Run, &mut self.run),
+        ] {
+            let value = values.entry(stat.to_string()).or_default();
+            update.apply(value);
+            let best = saved.entry(scope.saved_key(stat)).or_default();
+            *best = (*best).max(*value);
+        }
+        update.apply(saved.entry(stat.to_string()).or_default());
+    }
+
+    /// Current value of an achievement's stat in its scope
+    pub fn value(&self, def: &AchievementDef, saved: &HashMap<String, u64>) -> u64 {
+        let values = match def.scope {
+            AchievementScope::Mission => &self.mission,
+            AchievementScope::Run => &self.run,
+            AchievementScope::Lifetime => saved,
+        };
+        values.get(def.stat).copied().unwrap_or(0)
+    }
+
+    /// Achievements that reached their threshold and are not yet unlocked
+    pub fn newly_unlocked(
+        &self,
+        registry: &AchievementRegistry,
+        saved: &HashMap<String, u64>,
+        unlocked: &HashSet<String>,
+        module: Option<&str>,
+        faction: &str,
+    ) -> Vec<&'static AchievementDef> {
+        registry
+            .all()
+            .filter(|def| !unlocked.contains(def.id))
+            .filter(|def| def.applies(module, faction))
+            .filter(|def| self.value(def, saved) >= def.threshold)
+            .collect()
     }
+}
 
-    /// Get mission duration in seconds
-    pub fn mission_duration(&self, current_time: f64) -> f64 {
-        self.mission_start_time
-            .map(|start| current_time - start)
-            .unwrap_or(0.0)
-    }
+/// Best progress ever made on an achievement (for the browser)
+pub fn best_progress(def: &AchievementDef, saved: &HashMap<String, u64>) -> u64 {
+    saved
+        .get(&def.scope.saved_key(def.stat))
+        .copied()
+        .unwrap_or(0)
+        .min(def.threshold)
 }
 
 /// Event fired when an achievement is unlocked
@@ -339,356 +382,222 @@ impl AchievementTracker {
 pub struct AchievementUnlockedEvent {
     /// The achievement that was unlocked (available for external listeners)
     #[allow(dead_code)]
-    pub achievement: Achievement,
+    pub achievement: &'static AchievementDef,
 }
 
-/// Check achievements based on game state
-fn check_achievements(
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Reset mission stats when a mission starts, run stats when a new
+/// campaign or run is picked
+fn reset_achievement_scopes(
     mut tracker: ResMut<AchievementTracker>,
-    mut save: ResMut<super::SaveData>,
+    mut transitions: EventReader<StateTransitionEvent<super::GameState>>,
+) {
+    for transition in transitions.read() {
+        let (Some(exited), Some(entered)) = (transition.exited, transition.entered) else {
+            continue;
+        };
+        if entered.in_mission() && !exited.in_mission() {
+            tracker.mission.clear();
+        }
+        if matches!(
+            entered,
+            super::GameState::MainMenu | super::GameState::ModuleSelect
+        ) {
+            tracker.mission.clear();
+            tracker.run.clear();
+        }
+    }
+}
+
+/// Turn gameplay events into stat progress
+fn feed_gameplay_stats(
     score: Res<super::ScoreSystem>,
     salt_miner: Res<super::SaltMinerSystem>,
-    heat_system: Res<crate::systems::ComboHeatSystem>,
-    mut unlock_events: EventWriter<AchievementUnlockedEvent>,
+    active: Res<crate::games::ActiveModule>,
+    state: Res<State<super::GameState>>,
+    players: Query<&crate::entities::ShipStats, With<crate::entities::Player>>,
     mut enemy_events: EventReader<super::EnemyDestroyedEvent>,
     mut boss_events: EventReader<super::BossDefeatedEvent>,
     mut salt_miner_activated: EventReader<super::SaltMinerActivatedEvent>,
-    mut stage_events: EventReader<super::StageCompleteEvent>,
     mut damage_events: EventReader<super::PlayerDamagedEvent>,
-    time: Res<Time>,
-    difficulty: Res<super::Difficulty>,
-    session: Res<super::GameSession>,
+    mut progress: EventWriter<AchievementProgressEvent>,
 ) {
-    // Track damage taken
-    for _ in damage_events.read() {
-        tracker.took_damage_this_mission = true;
-    }
-
-    // Track kills
-    for event in enemy_events.read() {
-        tracker.session_kills += 1;
-
-        // First kill
-        try_unlock(
-            Achievement::FirstBlood,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-
-        // Kill milestones
-        if tracker.session_kills >= 100 {
-            try_unlock(
-                Achievement::Centurion,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
+    for _ in enemy_events.read() {
+        progress.send(AchievementProgressEvent::add(STAT_KILLS, 1));
+        if let Some(module) = active.module_id.as_deref() {
+            progress.send(AchievementProgressEvent::add(
+                stat_key(STAT_KILLS, module),
+                1,
+            ));
         }
-        if tracker.session_kills >= 500 {
-            try_unlock(
-                Achievement::Exterminator,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
-        }
-        if tracker.session_kills >= 1000 {
-            try_unlock(
-                Achievement::Annihilator,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
-        }
-
-        // Track salt miner kills
         if salt_miner.is_active {
-            tracker.session_salt_miner_kills += 1;
-            if tracker.session_salt_miner_kills >= 10 {
-                try_unlock(
-                    Achievement::SaltMinerKiller,
-                    &mut save,
-                    &mut tracker,
-                    &mut unlock_events,
-                );
-            }
-        }
-
-        // Boss kills
-        if event.was_boss {
-            tracker.session_bosses_killed += 1;
-            try_unlock(
-                Achievement::BossSlayer,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
-
-            // Check for specific boss types
-            if event.enemy_type.contains("Apocalypse") {
-                try_unlock(
-                    Achievement::ApocalypseFallen,
-                    &mut save,
-                    &mut tracker,
-                    &mut unlock_events,
-                );
-            }
-            if event.enemy_type.contains("Avatar") {
-                try_unlock(
-                    Achievement::TitanKiller,
-                    &mut save,
-                    &mut tracker,
-                    &mut unlock_events,
-                );
-            }
+            progress.send(AchievementProgressEvent::add(STAT_SALT_MINER_KILLS, 1));
         }
     }
 
-    // Track boss defeats from BossDefeatedEvent
     for event in boss_events.read() {
-        try_unlock(
-            Achievement::BossSlayer,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-        if event.boss_type.contains("Apocalypse") {
-            try_unlock(
-                Achievement::ApocalypseFallen,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
-        }
-        if event.boss_type.contains("Avatar") {
-            try_unlock(
-                Achievement::TitanKiller,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
+        progress.send(AchievementProgressEvent::add(STAT_BOSSES_KILLED, 1));
+        for word in event
+            .boss_type
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+        {
+            progress.send(AchievementProgressEvent::add(
+                stat_key(STAT_BOSSES_KILLED, word),
+                1,
+            ));
         }
     }
 
-    // Track salt miner activation
     for _ in salt_miner_activated.read() {
-        try_unlock(
-            Achievement::SaltMinerActivated,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
+        progress.send(AchievementProgressEvent::add(
+            STAT_SALT_MINER_ACTIVATIONS,
+            1,
+        ));
     }
 
-    // Track combo
-    let current_combo = heat_system.combo_count;
-    if current_combo > tracker.session_max_combo {
-        tracker.session_max_combo = current_combo;
-    }
-    if tracker.session_max_combo >= 10 {
-        try_unlock(
-            Achievement::ComboStarter,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-    }
-    if tracker.session_max_combo >= 25 {
-        try_unlock(
-            Achievement::ComboKing,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-    }
-    if tracker.session_max_combo >= 50 {
-        try_unlock(
-            Achievement::ComboMaster,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
+    // Surviving a boss hit on a sliver of health
+    for _ in damage_events.read() {
+        if *state.get() != super::GameState::BossFight {
+            continue;
+        }
+        if let Ok(stats) = players.get_single() {
+            if stats.hull > 0.0 && stats.health_percent() < 0.1 {
+                progress.send(AchievementProgressEvent::add(STAT_CLOSE_CALLS, 1));
+            }
+        }
     }
 
-    // Track souls liberated
-    let souls = score.souls_liberated;
-    tracker.session_souls = souls;
-    if souls >= 25 {
-        try_unlock(
-            Achievement::Liberator,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-    }
-    if souls >= 100 {
-        try_unlock(
-            Achievement::FreedomFighter,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-    }
-    if souls >= 250 {
-        try_unlock(
-            Achievement::Emancipator,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-    }
+    progress.send(AchievementProgressEvent::peak(
+        STAT_COMBO,
+        score.chain as u64,
+    ));
+    progress.send(AchievementProgressEvent::peak(STAT_SCORE, score.score));
+    progress.send(AchievementProgressEvent::peak(
+        STAT_SOULS,
+        score.souls_liberated as u64,
+    ));
+}
 
-    // Track score
-    let current_score = score.score;
-    if current_score >= 25000 {
-        try_unlock(
-            Achievement::ScoreRookie,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-    }
-    if current_score >= 50000 {
-        try_unlock(
-            Achievement::ScoreVeteran,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-    }
-    if current_score >= 100000 {
-        try_unlock(
-            Achievement::ScoreMaster,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-    }
-    if current_score >= 250000 {
-        try_unlock(
-            Achievement::ScoreLegend,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
+/// Turn finished runs and act completions into stat progress
+fn feed_run_results(
+    save: Res<super::SaveData>,
+    session: Res<super::GameSession>,
+    difficulty: Res<super::Difficulty>,
+    active: Res<crate::games::ActiveModule>,
+    mut run_events: EventReader<super::RunFinishedEvent>,
+    mut act_events: EventReader<super::ActCompleteEvent>,
+    mut progress: EventWriter<AchievementProgressEvent>,
+) {
+    for event in act_events.read() {
+        progress.send(AchievementProgressEvent::peak(
+            STAT_ACTS_COMPLETED,
+            event.act.number() as u64,
+        ));
     }
 
-    // Track stage completion
-    for event in stage_events.read() {
-        // First mission
-        try_unlock(
-            Achievement::FirstMission,
-            &mut save,
-            &mut tracker,
-            &mut unlock_events,
-        );
-
-        // Act completion (based on stage number)
-        if event.stage_number >= 4 {
-            try_unlock(
-                Achievement::ActOneComplete,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
-        }
-        if event.stage_number >= 9 {
-            try_unlock(
-                Achievement::ActTwoComplete,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
-        }
-        if event.stage_number >= 13 {
-            try_unlock(
-                Achievement::CampaignVictory,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
-        }
-
-        // Flawless (no damage)
-        if !tracker.took_damage_this_mission {
-            try_unlock(
-                Achievement::Flawless,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
+    for event in run_events.read() {
+        let summary = &event.summary;
+        if summary.outcome != super::RunResult::Completed {
+            continue;
         }
-
-        // Speed runner (under 3 minutes)
-        let duration = tracker.mission_duration(time.elapsed_secs_f64());
-        if duration > 0.0 && duration < 180.0 {
-            try_unlock(
-                Achievement::SpeedRunner,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
+        let faction = player_faction(&active, &session);
+        let mut stats = vec![
+            STAT_MISSIONS_COMPLETED.to_string(),
+            stat_key(STAT_MISSIONS_COMPLETED, &faction),
+            stat_key(STAT_DIFFICULTY_MISSIONS, difficulty.name()),
+            stat_key(STAT_SHIP_MISSIONS, &summary.ship),
+        ];
+        if let Some(module) = active.module_id.as_deref() {
+            stats.push(stat_key(STAT_MISSIONS_COMPLETED, module));
         }
-
-        // Difficulty achievements
-        if *difficulty == super::Difficulty::BitterVet {
-            try_unlock(
-                Achievement::BitterVetWarrior,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
+        for stat in stats {
+            progress.send(AchievementProgressEvent::add(stat, 1));
         }
-        if *difficulty == super::Difficulty::Triglavian {
-            try_unlock(
-                Achievement::TriglavianConqueror,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
+        if summary.total_taken() <= 0.0 {
+            progress.send(AchievementProgressEvent::add(STAT_FLAWLESS_MISSIONS, 1));
         }
-
-        // Ship achievements based on ship selection
-        let ship_name = session.selected_ship().name;
-        if ship_name == "Rifter" {
-            try_unlock(
-                Achievement::RifterPilot,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
+        if summary.duration < FAST_MISSION_SECS {
+            progress.send(AchievementProgressEvent::add(STAT_FAST_MISSIONS, 1));
         }
-        if ship_name == "Slasher" {
-            try_unlock(
-                Achievement::SlasherPilot,
-                &mut save,
-                &mut tracker,
-                &mut unlock_events,
-            );
+        if event.victory {
+            progress.send(AchievementProgressEvent::add(STAT_VICTORIES, 1));
+            progress.send(AchievementProgressEvent::add(
+                stat_key(STAT_VICTORIES, &faction),
+                1,
+            ));
+            if let Some(module) = active.module_id.as_deref() {
+                progress.send(AchievementProgressEvent::add(
+                    stat_key(STAT_VICTORIES, module),
+                    1,
+                ));
+            }
         }
 
-        // Reset for next mission
-        tracker.took_damage_this_mission = false;
-        tracker.mission_start_time = Some(time.elapsed_secs_f64());
+        let t2_ships = super::Faction::all()
+            .iter()
+            .flat_map(|f| f.player_ships())
+            .filter(|ship| ship.class == super::ShipClass::AssaultFrigate)
+            .filter(|ship| save.unlocked_ships.contains(&ship.type_id))
+            .count();
+        progress.send(AchievementProgressEvent::peak(
+            STAT_T2_SHIPS,
+            t2_ships as u64,
+        ));
     }
 }
 
-/// Try to unlock an achievement (checks if already unlocked)
-fn try_unlock(
-    achievement: Achievement,
-    save: &mut ResMut<super::SaveData>,
-    tracker: &mut ResMut<AchievementTracker>,
-    unlock_events: &mut EventWriter<AchievementUnlockedEvent>,
+/// Player faction as a lowercase short name; modules with their own
+/// factions set it on `ActiveModule`
+fn player_faction(active: &crate::games::ActiveModule, session: &super::GameSession) -> String {
+    active
+        .player_faction
+        .clone()
+        .unwrap_or_else(|| session.player_faction.short_name().to_string())
+        .to_lowercase()
+}
+
+/// Apply stat progress and unlock whatever reached its threshold
+fn evaluate_achievements(
+    mut tracker: ResMut<AchievementTracker>,
+    mut save: ResMut<super::SaveData>,
+    registry: Res<AchievementRegistry>,
+    session: Res<super::GameSession>,
+    active: Res<crate::games::ActiveModule>,
+    mut progress: EventReader<AchievementProgressEvent>,
+    mut unlock_events: EventWriter<AchievementUnlockedEvent>,
 ) {
-    if !save.achievements.contains(&achievement) {
-        save.achievements.insert(achievement);
+    if progress.is_empty() {
+        return;
+    }
+
+    // Peak updates arrive every frame; only write the save when they move
+    let mut stats = save.achievement_stats.clone();
+    for event in progress.read() {
+        tracker.apply(&event.stat, event.update, &mut stats);
+    }
+    if stats != save.achievement_stats {
+        save.achievement_stats = stats;
+    }
+
+    let faction = player_faction(&active, &session);
+    let unlocked = tracker.newly_unlocked(
+        &registry,
+        &save.achievement_stats,
+        &save.achievements,
+        active.module_id.as_deref(),
+        &faction,
+    );
+    for achievement in unlocked {
+        save.achievements.insert(achievement.id.to_string());
         tracker.pending_notifications.push(achievement);
         unlock_events.send(AchievementUnlockedEvent { achievement });
         info!(
             "Achievement unlocked: {} - {}",
-            achievement.name(),
-            achievement.description()
+            achievement.name, achievement.description
         );
     }
 }
@@ -708,9 +617,9 @@ fn process_achievement_unlocks(
 #[derive(Resource, Default)]
 pub struct AchievementPopupState {
     /// Queue of achievements to show
-    pub queue: Vec<Achievement>,
+    pub queue: Vec<&'static AchievementDef>,
     /// Currently displayed achievement
-    pub current: Option<Achievement>,
+    pub current: Option<&'static AchievementDef>,
     /// Time remaining to show current popup
     pub timer: f32,
 }
@@ -720,27 +629,311 @@ impl AchievementPopupState {
     pub const DISPLAY_TIME: f32 = 3.0;
 }
 
+// =============================================================================
+// GENERAL SET
+// =============================================================================
+
+/// Achievements every module can earn. Ids match the old enum variants so
+/// existing saves keep their unlocks.
+pub static GENERAL_ACHIEVEMENTS: AchievementSet = AchievementSet {
+    name: "General",
+    achievements: &[
+        // Combat
+        AchievementDef::new(
+            "FirstBlood",
+            "First Blood",
+            "Destroy your first enemy",
+            STAT_KILLS,
+            1,
+            Lifetime,
+        ),
+        AchievementDef::new(
+            "Centurion",
+            "Centurion",
+            "Destroy 100 enemies in a single run",
+            STAT_KILLS,
+            100,
+            Run,
+        ),
+        AchievementDef::new(
+            "Exterminator",
+            "Exterminator",
+            "Destroy 500 enemies in a single run",
+            STAT_KILLS,
+            500,
+            Run,
+        )
+        .tier(Silver),
+        AchievementDef::new(
+            "Annihilator",
+            "Annihilator",
+            "Destroy 1000 enemies in a single run",
+            STAT_KILLS,
+            1000,
+            Run,
+        )
+        .hidden(),
+        AchievementDef::new(
+            "Veteran",
+            "Veteran",
+            "Destroy 5,000 enemies",
+            STAT_KILLS,
+            5000,
+            Lifetime,
+        )
+        .tier(Silver),
+        // Combo and Salt Miner
+        AchievementDef::new(
+            "ComboStarter",
+            "Combo Starter",
+            "Get a 10x combo",
+            STAT_COMBO,
+            10,
+            Mission,
+        ),
+        AchievementDef::new(
+            "ComboKing",
+            "Combo King",
+            "Get a 25x combo",
+            STAT_COMBO,
+            25,
+            Mission,
+        )
+        .tier(Silver),
+        AchievementDef::new(
+            "ComboMaster",
+            "Combo Master",
+            "Get a 50x combo",
+            STAT_COMBO,
+            50,
+            Mission,
+        )
+        .tier(Gold),
+        AchievementDef::new(
+            "SaltMinerActivated",
+            "Salt Miner",
+            "Activate salt miner mode",
+            STAT_SALT_MINER_ACTIVATIONS,
+            1,
+            Lifetime,
+        ),
+        AchievementDef::new(
+            "SaltMinerKiller",
+            "Salt Miner Killer",
+            "Get 10 kills while salt miner is active",
+            STAT_SALT_MINER_KILLS,
+            10,
+            Mission,
+        ),
+        // Progression
+        AchievementDef::new(
+            "FirstMission",
+            "First Mission",
+            "Complete your first mission",
+            STAT_MISSIONS_COMPLETED,
+            1,
+            Lifetime,
+        ),
+        AchievementDef::new(
+            "Flawless",
+            "Flawless",
+            "Complete a mission without taking damage",
+            STAT_FLAWLESS_MISSIONS,
+            1,
+            Lifetime,
+        ),
+        AchievementDef::new(
+            "Career",
+            "Career Pilot",
+            "Complete 50 missions",
+            STAT_MISSIONS_COMPLETED,
+            50,
+            Lifetime,
+        )
+        .tier(Silver),
+        // Bosses
+        AchievementDef::new(
+            "BossSlayer",
+            "Boss Slayer",
+            "Defeat your first boss",
+            STAT_BOSSES_KILLED,
+            1,
+            Lifetime,
+        ),
+        // Score
+        AchievementDef::new(
+            "ScoreRookie",
+            "Score Rookie",
+            "Score 25,000 points in a single run",
+            STAT_SCORE,
+            25_000,
+            Run,
+        ),
+        AchievementDef::new(
+            "ScoreVeteran",
+            "Score Veteran",
+            "Score 50,000 points in a single run",
+            STAT_SCORE,
+            50_000,
+            Run,
+        ),
+        AchievementDef::new(
+            "ScoreMaster",
+            "Score Master",
+            "Score 100,000 points in a single run",
+            STAT_SCORE,
+            100_000,
+            Run,
+        )
+        .tier(Silver),
+        AchievementDef::new(
+            "ScoreLegend",
+            "Score Legend",
+            "Score 250,000 points in a single run",
+            STAT_SCORE,
+            250_000,
+            Run,
+        )
+        .tier(Gold),
+        // Ships
+        AchievementDef::new(
+            "T2Unlocked",
+            "T2 Unlocked",
+            "Unlock a Tech 2 assault frigate",
+            STAT_T2_SHIPS,
+            1,
+            Lifetime,
+        ),
+        // Difficulty
+        AchievementDef::new(
+            "BitterVetWarrior",
+            "Bitter Vet Warrior",
+            "Complete a mission on BitterVet difficulty",
+            "difficulty_missions/bitter_vet",
+            1,
+            Lifetime,
+        ),
+        AchievementDef::new(
+            "TriglavianConqueror",
+            "Triglavian Conqueror",
+            "Complete a mission on Triglavian difficulty",
+            "difficulty_missions/triglavian",
+            1,
+            Lifetime,
+        )
+        .tier(Gold)
+        .hidden(),
+        // Special
+        AchievementDef::new(
+            "CloseCall",
+            "Close Call",
+            "Survive a boss hit with less than 10% health",
+            STAT_CLOSE_CALLS,
+            1,
+            Lifetime,
+        )
+        .hidden(),
+        AchievementDef::new(
+            "SpeedRunner",
+            "Speed Runner",
+            "Complete a mission in under 3 minutes",
+            STAT_FAST_MISSIONS,
+            1,
+            Lifetime,
+        )
+        .hidden(),
+    ],
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    static TEST_SET: AchievementSet = AchievementSet {
+        name: "Test",
+        achievements: &[
+            AchievementDef::new("Kills3", "Kills", "3 kills", STAT_KILLS, 3, Mission),
+            AchievementDef::new("Life5", "Life", "5 kills", STAT_KILLS, 5, Lifetime)
+                .modules(&["caldari_gallente"])
+                .factions(&["caldari"]),
+        ],
+    };
+
+    fn registry() -> AchievementRegistry {
+        AchievementRegistry {
+            sets: vec![&TEST_SET],
+        }
+    }
+
     #[test]
-    fn achievement_names_not_empty() {
-        for achievement in Achievement::all() {
-            assert!(!achievement.name().is_empty());
-            assert!(!achievement.description().is_empty());
+    fn general_set_is_complete_and_unique() {
+        let registry = AchievementRegistry::default();
+        let ids: HashSet<_> = registry.all().map(|def| def.id).collect();
+        assert_eq!(ids.len(), registry.all().count());
+        assert!(ids.len() >= 20);
+        for def in registry.all() {
+            assert!(!def.name.is_empty() && !def.description.is_empty());
+            assert!(def.threshold > 0);
         }
+        assert!(registry.all().filter(|def| def.hidden).count() >= 3);
     }
 
     #[test]
-    fn achievement_all_returns_all_variants() {
-        let all = Achievement::all();
-        assert!(all.len() >= 30);
+    fn scopes_reset_independently_and_keep_bests() {
+        let mut tracker = AchievementTracker::default();
+        let mut saved = HashMap::new();
+        tracker.apply(STAT_KILLS, StatUpdate::Add(2), &mut saved);
+        tracker.mission.clear();
+        tracker.apply(STAT_KILLS, StatUpdate::Add(1), &mut saved);
+        tracker.apply(STAT_COMBO, StatUpdate::Peak(7), &mut saved);
+        tracker.apply(STAT_COMBO, StatUpdate::Peak(4), &mut saved);
+
+        assert_eq!(tracker.mission[STAT_KILLS], 1);
+        assert_eq!(tracker.run[STAT_KILLS], 3);
+        assert_eq!(saved[STAT_KILLS], 3);
+        assert_eq!(saved["mission:kills"], 2);
+        assert_eq!(saved[STAT_COMBO], 7);
+
+        let def = &TEST_SET.achievements[0];
+        assert_eq!(best_progress(def, &saved), 2);
     }
 
     #[test]
-    fn hidden_achievements_exist() {
-        let hidden_count = Achievement::all().iter().filter(|a| a.is_hidden()).count();
-        assert!(hidden_count >= 3);
+    fn filters_gate_unlocks() {
+        let registry = registry();
+        let mut tracker = AchievementTracker::default();
+        let mut saved = HashMap::new();
+        tracker.apply(STAT_KILLS, StatUpdate::Add(5), &mut saved);
+
+        let ids = |module, faction| -> Vec<&str> {
+            tracker
+                .newly_unlocked(&registry, &saved, &HashSet::new(), module, faction)
+                .iter()
+                .map(|def| def.id)
+                .collect()
+        };
+        assert_eq!(ids(Some("elder_fleet"), "minmatar"), vec!["Kills3"]);
+        assert_eq!(ids(Some("caldari_gallente"), "GALLENTE"), vec!["Kills3"]);
+        assert_eq!(
+            ids(Some("caldari_gallente"), "CALDARI"),
+            vec!["Kills3", "Life5"]
+        );
+
+        let unlocked: HashSet<String> = ["Kills3".to_string()].into();
+        assert!(tracker
+            .newly_unlocked(&registry, &saved, &unlocked, None, "minmatar")
+            .is_empty());
+    }
+
+    #[test]
+    fn stat_keys_are_slugged() {
+        assert_eq!(
+            stat_key(STAT_DIFFICULTY_MISSIONS, "BITTER VET"),
+            "difficulty_missions/bitter_vet"
+        );
+        assert_eq!(
+            stat_key(STAT_BOSSES_KILLED, "Avatar"),
+            "bosses_killed/avatar"
+        );
     }
 }
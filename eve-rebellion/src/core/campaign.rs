@@ -6,6 +6,8 @@
 
 use bevy::prelude::*;
 
+use super::objectives::{Objective, ObjectiveKind};
+
 /// Campaign acts - progression through the story
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Act {
@@ -75,11 +77,26 @@ pub struct Mission {
     pub description: &'static str,
     pub primary_objective: &'static str,
     pub bonus_objective: Option<&'static str>,
+    /// How the bonus objective is tracked
+    pub bonus: Option<ObjectiveKind>,
     pub boss: BossType,
     pub enemy_waves: u32,
     pub souls_to_liberate: u32,
 }
 
+impl Mission {
+    /// Destroying the boss is the primary objective; the bonus objective
+    /// is optional
+    pub fn objectives(&self) -> Vec<Objective> {
+        let mut objectives =
+            vec![Objective::primary(ObjectiveKind::DestroyBoss).with_label(self.primary_objective)];
+        if let (Some(kind), Some(label)) = (self.bonus, self.bonus_objective) {
+            objectives.push(Objective::optional(kind).with_label(label));
+        }
+        objectives
+    }
+}
+
 /// Boss types for each mission
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum BossType {
@@ -187,6 +204,7 @@ const ACT1_MISSIONS: [Mission; 4] = [
         description: "Intercept a slave transport in the Arzad corridor.",
         primary_objective: "Destroy the slave transport",
         bonus_objective: Some("Liberate 10+ slaves"),
+        bonus: Some(ObjectiveKind::Collect { count: 10 }),
         boss: BossType::TransportOverseer,
         enemy_waves: 3,
         souls_to_liberate: 10,
@@ -197,6 +215,7 @@ const ACT1_MISSIONS: [Mission; 4] = [
         description: "Amarr patrols hunt our scouts. Turn the tables.",
         primary_objective: "Destroy all patrol ships",
         bonus_objective: Some("No damage taken"),
+        bonus: Some(ObjectiveKind::NoDamage),
         boss: BossType::PatrolCommander,
         enemy_waves: 4,
         souls_to_liberate: 5,
@@ -207,6 +226,7 @@ const ACT1_MISSIONS: [Mission; 4] = [
         description: "Disable orbital station defenses for extraction teams.",
         primary_objective: "Destroy defense turrets",
         bonus_objective: Some("Liberate 30+ slaves"),
+        bonus: Some(ObjectiveKind::Collect { count: 30 }),
         boss: BossType::StationBattery,
         enemy_waves: 5,
         souls_to_liberate: 30,
@@ -217,6 +237,7 @@ const ACT1_MISSIONS: [Mission; 4] = [
         description: "A slave lord flees with his 'property.' End his escape.",
         primary_objective: "Destroy the Holder's escort",
         bonus_objective: Some("Complete in under 3 minutes"),
+        bonus: Some(ObjectiveKind::TimeLimit { seconds: 180.0 }),
         boss: BossType::HolderEscort,
         enemy_waves: 4,
         souls_to_liberate: 20,
@@ -231,6 +252,7 @@ const ACT2_MISSIONS: [Mission; 5] = [
         description: "Imperial Customs bleeds our supply lines. Remove them.",
         primary_objective: "Destroy the Customs station",
         bonus_objective: Some("Destroy all cargo pods"),
+        bonus: Some(ObjectiveKind::DestroyCargo { count: 8 }),
         boss: BossType::CustomsCommandant,
         enemy_waves: 5,
         souls_to_liberate: 15,
@@ -241,6 +263,7 @@ const ACT2_MISSIONS: [Mission; 5] = [
         description: "The Inquisition sends a vessel to 'cleanse' liberated systems.",
         primary_objective: "Destroy the Inquisitor vessel",
         bonus_objective: Some("No allied losses"),
+        bonus: Some(ObjectiveKind::NoAlliedLosses),
         boss: BossType::InquisitorVessel,
         enemy_waves: 6,
         souls_to_liberate: 25,
@@ -251,6 +274,7 @@ const ACT2_MISSIONS: [Mission; 5] = [
         description: "A Navy Harbinger strike group threatens our liberation fleet.",
         primary_objective: "Destroy the strike lead",
         bonus_objective: Some("Destroy all escorts first"),
+        bonus: Some(ObjectiveKind::EscortsFirst),
         boss: BossType::HarbingerStrike,
         enemy_waves: 6,
         souls_to_liberate: 20,
@@ -261,6 +285,7 @@ const ACT2_MISSIONS: [Mission; 5] = [
         description: "The stargate to Arzad Prime is heavily fortified.",
         primary_objective: "Disable the gate defenses",
         bonus_objective: Some("Under 4 minutes"),
+        bonus: Some(ObjectiveKind::TimeLimit { seconds: 240.0 }),
         boss: BossType::StargateDefense,
         enemy_waves: 7,
         souls_to_liberate: 30,
@@ -271,6 +296,7 @@ const ACT2_MISSIONS: [Mission; 5] = [
         description: "An Amarr battlestation guards the slave processing hub.",
         primary_objective: "Destroy the battlestation core",
         bonus_objective: Some("Liberate 50+ slaves"),
+        bonus: Some(ObjectiveKind::Collect { count: 50 }),
         boss: BossType::BattlestationCore,
         enemy_waves: 8,
         souls_to_liberate: 50,
@@ -285,6 +311,7 @@ const ACT3_MISSIONS: [Mission; 4] = [
         description: "The Amarr Navy deploys Abaddon battleships to stop our advance.",
         primary_objective: "Destroy the Abaddon flagship",
         bonus_objective: Some("No damage taken in phase 1"),
+        bonus: Some(ObjectiveKind::NoDamageInPhase { phase: 1 }),
         boss: BossType::AbaddonBattleship,
         enemy_waves: 8,
         souls_to_liberate: 40,
@@ -295,6 +322,7 @@ const ACT3_MISSIONS: [Mission; 4] = [
         description: "The Avatar titan's escort fleet blocks the approach.",
         primary_objective: "Clear the escort fleet",
         bonus_objective: Some("Destroy all in one chain"),
+        bonus: Some(ObjectiveKind::SingleChain),
         boss: BossType::TitanEscort,
         enemy_waves: 9,
         souls_to_liberate: 50,
@@ -305,6 +333,7 @@ const ACT3_MISSIONS: [Mission; 4] = [
         description: "The Empress's personal champion challenges you.",
         primary_objective: "Defeat the champion",
         bonus_objective: Some("Perfect no-damage victory"),
+        bonus: Some(ObjectiveKind::NoDamage),
         boss: BossType::EmpressChampion,
         enemy_waves: 7,
         souls_to_liberate: 30,
@@ -315,12 +344,82 @@ const ACT3_MISSIONS: [Mission; 4] = [
         description: "The Avatar titan. The symbol of Amarr oppression. End it.",
         primary_objective: "Destroy the Avatar",
         bonus_objective: Some("Complete the liberation"),
+        bonus: Some(ObjectiveKind::Collect { count: 100 }),
         boss: BossType::AvatarTitan,
         enemy_waves: 10,
         souls_to_liberate: 100,
     },
 ];
 
+// Side missions - optional detours on the campaign map
+pub const SIDE_MISSIONS: [Mission; 3] = [
+    Mission {
+        id: "s1_salvage",
+        name: "SALVAGE RIGHTS",
+        description: "A wrecked Republic Fleet hangar drifts in Amarr space. Recover the hulls.",
+        primary_objective: "Destroy the salvage foreman",
+        bonus_objective: Some("Liberate 15+ slaves"),
+        bonus: Some(ObjectiveKind::Collect { count: 15 }),
+        boss: BossType::TransportOverseer,
+        enemy_waves: 4,
+        souls_to_liberate: 15,
+    },
+    Mission {
+        id: "s2_smuggler",
+        name: "SMUGGLER'S ROUTE",
+        description: "A Khanid smuggler arms the customs fleet. Take his cargo for ourselves.",
+        primary_objective: "Destroy the smuggler's escort",
+        bonus_objective: Some("Complete in under 3 minutes"),
+        bonus: Some(ObjectiveKind::TimeLimit { seconds: 180.0 }),
+        boss: BossType::HolderEscort,
+        enemy_waves: 5,
+        souls_to_liberate: 20,
+    },
+    Mission {
+        id: "s3_exodus",
+        name: "EXODUS",
+        description: "Refugees flee the battlestation in a freighter. Get them out alive.",
+        primary_objective: "Destroy the pursuit commander",
+        bonus_objective: Some("Escort the refugee freighter"),
+        bonus: Some(ObjectiveKind::Escort {
+            type_id: 20189,
+            health: 600.0,
+            speed: 18.0,
+        }),
+        boss: BossType::PatrolCommander,
+        enemy_waves: 6,
+        souls_to_liberate: 40,
+    },
+];
+
+/// Where a mission id lives: an act's story missions or the side missions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissionSlot {
+    Story(Act, usize),
+    Side(usize),
+}
+
+impl MissionSlot {
+    pub fn find(id: &str) -> Option<Self> {
+        for act in [Act::Act1, Act::Act2, Act::Act3] {
+            if let Some(index) = act.missions().iter().position(|m| m.id == id) {
+                return Some(MissionSlot::Story(act, index));
+            }
+        }
+        SIDE_MISSIONS
+            .iter()
+            .position(|m| m.id == id)
+            .map(MissionSlot::Side)
+    }
+
+    pub fn mission(&self) -> &'static Mission {
+        match *self {
+            MissionSlot::Story(act, index) => &act.missions()[index],
+            MissionSlot::Side(index) => &SIDE_MISSIONS[index],
+        }
+    }
+}
+
 /// Current campaign state
 #[derive(Debug, Clone, Resource)]
 pub struct CampaignState {
@@ -348,6 +447,9 @@ pub struct CampaignState {
     pub primary_complete: bool,
     /// Bonus objective complete
     pub bonus_complete: bool,
+    /// Side mission being flown (index into `SIDE_MISSIONS`); `act` and
+    /// `mission_index` then point at the story mission it branches from
+    pub side_mission: Option<usize>,
 }
 
 impl Default for CampaignState {
@@ -365,6 +467,7 @@ impl Default for CampaignState {
             no_damage_taken: true,
             primary_complete: false,
             bonus_complete: false,
+            side_mission: None,
         }
     }
 }
@@ -372,10 +475,37 @@ impl Default for CampaignState {
 impl CampaignState {
     /// Get current mission
     pub fn current_mission(&self) -> Option<&'static Mission> {
+        if let Some(side) = self.side_mission {
+            return SIDE_MISSIONS.get(side);
+        }
         let missions = self.act.missions();
         missions.get(self.mission_index)
     }
 
+    /// Point the campaign at a mission by id. Side missions borrow the
+    /// act and stage (boss, difficulty) of `branch_from`.
+    pub fn select_mission(&mut self, id: &str, branch_from: Option<&str>) -> bool {
+        match MissionSlot::find(id) {
+            Some(MissionSlot::Story(act, index)) => {
+                self.act = act;
+                self.mission_index = index;
+                self.side_mission = None;
+                true
+            }
+            Some(MissionSlot::Side(side)) => {
+                if let Some(MissionSlot::Story(act, index)) =
+                    branch_from.and_then(MissionSlot::find)
+                {
+                    self.act = act;
+                    self.mission_index = index;
+                }
+                self.side_mission = Some(side);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Start the current mission
     pub fn start_mission(&mut self) {
         self.in_mission = true;
@@ -395,6 +525,11 @@ impl CampaignState {
         self.in_mission = false;
         self.primary_complete = true;
 
+        // Side missions leave the story position where it was
+        if self.side_mission.take().is_some() {
+            return true;
+        }
+
         let missions = self.act.missions();
         if self.mission_index + 1 < missions.len() {
             self.mission_index += 1;
@@ -766,6 +901,20 @@ mod tests {
         assert!(!state.complete_mission()); // Campaign complete
     }
 
+    #[test]
+    fn side_missions_borrow_the_story_position() {
+        let mut state = CampaignState::default();
+        assert!(state.select_mission("s2_smuggler", Some("m5_customs_strike")));
+        assert_eq!(state.act, Act::Act2);
+        assert_eq!(state.mission_index, 0);
+        assert_eq!(state.current_mission().unwrap().id, "s2_smuggler");
+
+        assert!(state.complete_mission());
+        assert_eq!(state.side_mission, None);
+        assert_eq!(state.current_mission().unwrap().id, "m5_customs_strike");
+        assert!(!state.select_mission("nope", None));
+    }
+
     #[test]
     fn campaign_state_mission_number() {
         let mut state = CampaignState::default();
@@ -0,0 +1,450 @@
+//! Campaign Map
+//!
+//! Node graph a module's campaign is played through. Each node is a
+//! mission placed on a star map; nodes unlock once any of their required
+//! nodes is cleared, side missions hand out ships or skills, and branch
+//! nodes close their alternatives once the player commits to them.
+//! Progress lives in [`FactionProgress`] per faction pair.
+//!
+//! Core missions launch from the map directly; a module whose map holds
+//! its own missions picks them up from [`MapNodeLaunched`].
+
+use bevy::prelude::*;
+
+use super::campaign::MissionSlot;
+use super::objectives::Objective;
+use super::save::{FactionProgress, SaveData};
+use super::skills::Skill;
+
+/// What clearing a node hands out (first clear only)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NodeReward {
+    /// Ships unlocked for selection (one per playable faction)
+    Ships(&'static [u32]),
+    /// A free rank of a skill
+    Skill(Skill),
+    SkillPoints(u32),
+}
+
+impl NodeReward {
+    pub fn describe(&self) -> String {
+        match self {
+            NodeReward::Ships(_) => "New ship".into(),
+            NodeReward::Skill(skill) => format!("Free skill rank: {}", skill.name()),
+            NodeReward::SkillPoints(sp) => format!("+{} SP", sp),
+        }
+    }
+}
+
+/// A mission on the map
+#[derive(Debug, Clone, Copy)]
+pub struct MapNode {
+    /// Mission id, also the node id stored in saves
+    pub mission: &'static str,
+    /// Star system shown on the map
+    pub system: &'static str,
+    /// Map position in world units around the screen center
+    pub position: Vec2,
+    /// Optional detour off the story path
+    pub side: bool,
+    /// Unlocked once any of these is cleared; empty for the start node
+    pub requires: &'static [&'static str],
+    /// Nodes closed for good once the player commits to this one
+    pub closes: &'static [&'static str],
+    pub reward: Option<NodeReward>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeStatus {
+    Locked,
+    Available,
+    Completed,
+    /// Shut by a choice made elsewhere
+    Closed,
+}
+
+/// What the map's details panel shows for a node
+#[derive(Debug, Clone)]
+pub struct MapBriefing {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub objectives: Vec<Objective>,
+}
+
+/// A module's campaign map
+#[derive(Debug)]
+pub struct CampaignMap {
+    pub nodes: &'static [MapNode],
+    /// Clearing this node finishes the campaign
+    pub finale: &'static str,
+    /// Briefing of the mission behind a node id
+    pub briefing: fn(&str) -> Option<MapBriefing>,
+}
+
+/// A node the core campaign does not know was launched from the map
+#[derive(Event, Debug, Clone, Copy)]
+pub struct MapNodeLaunched {
+    pub mission: &'static str,
+}
+
+impl CampaignMap {
+    pub fn node(&self, mission: &str) -> Option<&MapNode> {
+        self.nodes.iter().find(|n| n.mission == mission)
+    }
+
+    pub fn status(&self, index: usize, progress: &FactionProgress) -> NodeStatus {
+        let node = &self.nodes[index];
+        if progress.completed_nodes.contains(node.mission) {
+            return NodeStatus::Completed;
+        }
+        let closed = self.nodes.iter().any(|other| {
+            other.closes.contains(&node.mission)
+                && (progress.choices.contains(other.mission)
+                    || progress.completed_nodes.contains(other.mission))
+        });
+        if closed {
+            NodeStatus::Closed
+        } else if node.requires.is_empty()
+            || node
+                .requires
+                .iter()
+                .any(|r| progress.completed_nodes.contains(*r))
+        {
+            NodeStatus::Available
+        } else {
+            NodeStatus::Locked
+        }
+    }
+
+    /// Launching this node would commit to a branch
+    pub fn needs_commitment(&self, index: usize, progress: &FactionProgress) -> bool {
+        let node = &self.nodes[index];
+        !node.closes.is_empty() && !progress.choices.contains(node.mission)
+    }
+
+    pub fn is_complete(&self, progress: &FactionProgress) -> bool {
+        progress.completed_nodes.contains(self.finale)
+    }
+
+    /// Record a cleared node and pay its reward on the first clear
+    pub fn clear_node(&self, save_data: &mut SaveData, faction: &str, enemy: &str, mission: &str) {
+        if !save_data.complete_node(faction, enemy, mission) {
+            return;
+        }
+        match self.node(mission).and_then(|n| n.reward) {
+            Some(NodeReward::Ships(ships)) => {
+                for &ship in ships {
+                    save_data.unlock_ship(ship);
+                }
+            }
+            Some(NodeReward::Skill(skill)) => {
+                save_data.skills.grant(skill);
+            }
+            Some(NodeReward::SkillPoints(sp)) => save_data.add_skill_points(sp),
+            None => {}
+        }
+    }
+
+    /// First node to put the cursor on: the first available story node
+    pub fn start_index(&self, progress: &FactionProgress) -> usize {
+        (0..self.nodes.len())
+            .find(|&i| !self.nodes[i].side && self.status(i, progress) == NodeStatus::Available)
+            .unwrap_or(0)
+    }
+
+    /// Nearest node from `from` roughly in `direction`
+    pub fn neighbor(&self, from: usize, direction: Vec2) -> Option<usize> {
+        let origin = self.nodes[from].position;
+        let direction = direction.normalize_or_zero();
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != from)
+            .filter_map(|(i, node)| {
+                let offset = node.position - origin;
+                let alignment = offset.normalize_or_zero().dot(direction);
+                // Within ~70° of the pressed direction; straighter wins
+                (alignment > 0.35).then(|| (i, offset.length() / alignment))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    }
+}
+
+/// Jump lines: one per (required, node) pair
+pub fn jump_lines(map: &CampaignMap) -> Vec<(usize, usize)> {
+    let mut lines = Vec::new();
+    for (i, node) in map.nodes.iter().enumerate() {
+        for required in node.requires {
+            if let Some(from) = map.nodes.iter().position(|n| n.mission == *required) {
+                lines.push((from, i));
+            }
+        }
+    }
+    lines
+}
+
+/// Briefing of a core campaign mission
+pub fn mission_briefing(id: &str) -> Option<MapBriefing> {
+    MissionSlot::find(id).map(|slot| {
+        let mission = slot.mission();
+        MapBriefing {
+            name: mission.name,
+            description: mission.description,
+            objectives: mission.objectives(),
+        }
+    })
+}
+
+/// Elder Fleet: three acts from Arzad to the Avatar, with a branch in
+/// acts 2 and 3 and one side mission per act
+pub static ELDER_FLEET_MAP: CampaignMap = CampaignMap {
+    finale: "m13_avatar",
+    briefing: mission_briefing,
+    nodes: &[
+        // Act 1 - The Call
+        MapNode {
+            mission: "m1_convoy_raid",
+            system: "Arzad",
+            position: Vec2::new(-340.0, 0.0),
+            side: false,
+            requires: &[],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "m2_patrol_ambush",
+            system: "Hek",
+            position: Vec2::new(-280.0, 70.0),
+            side: false,
+            requires: &["m1_convoy_raid"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "m3_station_raid",
+            system: "Amamake",
+            position: Vec2::new(-220.0, -10.0),
+            side: false,
+            requires: &["m2_patrol_ambush"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "m4_holder_escape",
+            system: "Auga",
+            position: Vec2::new(-150.0, 70.0),
+            side: false,
+            requires: &["m3_station_raid"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "s1_salvage",
+            system: "Frarn",
+            position: Vec2::new(-260.0, -120.0),
+            side: true,
+            requires: &["m2_patrol_ambush"],
+            closes: &[],
+            // Wolf / Crusader ahead of the act 1 unlock
+            reward: Some(NodeReward::Ships(&[11371, 11186])),
+        },
+        // Act 2 - The Storm
+        MapNode {
+            mission: "m5_customs_strike",
+            system: "Kamela",
+            position: Vec2::new(-70.0, 10.0),
+            side: false,
+            requires: &["m4_holder_escape"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "m6_inquisition",
+            system: "Sasta",
+            position: Vec2::new(-10.0, 90.0),
+            side: false,
+            requires: &["m5_customs_strike"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "m7_navy_battle",
+            system: "Kador Prime",
+            position: Vec2::new(50.0, 170.0),
+            side: false,
+            requires: &["m6_inquisition"],
+            closes: &["m8_stargate"],
+            reward: None,
+        },
+        MapNode {
+            mission: "m8_stargate",
+            system: "Amarr Gate",
+            position: Vec2::new(60.0, 10.0),
+            side: false,
+            requires: &["m6_inquisition"],
+            closes: &["m7_navy_battle"],
+            reward: None,
+        },
+        MapNode {
+            mission: "m9_battlestation",
+            system: "Bahromab",
+            position: Vec2::new(130.0, 90.0),
+            side: false,
+            requires: &["m7_navy_battle", "m8_stargate"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "s2_smuggler",
+            system: "Khanid",
+            position: Vec2::new(-40.0, -110.0),
+            side: true,
+            requires: &["m5_customs_strike"],
+            closes: &[],
+            reward: Some(NodeReward::Skill(Skill::SurgicalStrike)),
+        },
+        // Act 3 - Liberation
+        MapNode {
+            mission: "m10_abaddon",
+            system: "Mehatoor",
+            position: Vec2::new(210.0, 170.0),
+            side: false,
+            requires: &["m9_battlestation"],
+            closes: &["m11_titan_escort"],
+            reward: None,
+        },
+        MapNode {
+            mission: "m11_titan_escort",
+            system: "Zorast",
+            position: Vec2::new(220.0, 10.0),
+            side: false,
+            requires: &["m9_battlestation"],
+            closes: &["m10_abaddon"],
+            reward: None,
+        },
+        MapNode {
+            mission: "m12_champion",
+            system: "Dam-Torsad",
+            position: Vec2::new(290.0, 90.0),
+            side: false,
+            requires: &["m10_abaddon", "m11_titan_escort"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "m13_avatar",
+            system: "Amarr",
+            position: Vec2::new(350.0, 0.0),
+            side: false,
+            requires: &["m12_champion"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "s3_exodus",
+            system: "Sarum Prime",
+            position: Vec2::new(170.0, -110.0),
+            side: true,
+            requires: &["m9_battlestation"],
+            closes: &[],
+            reward: Some(NodeReward::SkillPoints(10)),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(completed: &[&str], choices: &[&str]) -> FactionProgress {
+        FactionProgress {
+            completed_nodes: completed.iter().map(|s| s.to_string()).collect(),
+            choices: choices.iter().map(|s| s.to_string()).collect(),
+            ..default()
+        }
+    }
+
+    fn index(mission: &str) -> usize {
+        ELDER_FLEET_MAP
+            .nodes
+            .iter()
+            .position(|n| n.mission == mission)
+            .unwrap()
+    }
+
+    #[test]
+    fn every_node_is_a_mission_and_requirements_exist() {
+        for node in ELDER_FLEET_MAP.nodes {
+            assert!(
+                (ELDER_FLEET_MAP.briefing)(node.mission).is_some(),
+                "{}",
+                node.mission
+            );
+            for id in node.requires.iter().chain(node.closes) {
+                assert!(ELDER_FLEET_MAP.node(id).is_some(), "{}", id);
+            }
+        }
+        assert!(ELDER_FLEET_MAP.node(ELDER_FLEET_MAP.finale).is_some());
+    }
+
+    #[test]
+    fn nodes_unlock_along_branches() {
+        let map = &ELDER_FLEET_MAP;
+        let fresh = progress(&[], &[]);
+        assert_eq!(map.status(0, &fresh), NodeStatus::Available);
+        assert_eq!(
+            map.status(index("m2_patrol_ambush"), &fresh),
+            NodeStatus::Locked
+        );
+        assert_eq!(map.start_index(&fresh), 0);
+
+        let p = progress(&["m1_convoy_raid", "m2_patrol_ambush"], &[]);
+        assert_eq!(map.status(0, &p), NodeStatus::Completed);
+        assert_eq!(map.status(index("s1_salvage"), &p), NodeStatus::Available);
+        assert_eq!(map.start_index(&p), index("m3_station_raid"));
+    }
+
+    #[test]
+    fn choices_close_the_other_branch() {
+        let map = &ELDER_FLEET_MAP;
+        let p = progress(
+            &[
+                "m1_convoy_raid",
+                "m2_patrol_ambush",
+                "m3_station_raid",
+                "m4_holder_escape",
+                "m5_customs_strike",
+                "m6_inquisition",
+            ],
+            &[],
+        );
+        let navy = index("m7_navy_battle");
+        let gate = index("m8_stargate");
+        assert!(map.needs_commitment(navy, &p));
+        assert_eq!(map.status(gate, &p), NodeStatus::Available);
+
+        let mut p = p;
+        p.choices.insert("m7_navy_battle".into());
+        assert!(!map.needs_commitment(navy, &p));
+        assert_eq!(map.status(gate, &p), NodeStatus::Closed);
+        assert_eq!(map.status(navy, &p), NodeStatus::Available);
+
+        p.completed_nodes.insert("m7_navy_battle".into());
+        assert_eq!(
+            map.status(index("m9_battlestation"), &p),
+            NodeStatus::Available
+        );
+        assert!(!map.is_complete(&p));
+    }
+
+    #[test]
+    fn neighbor_follows_direction() {
+        let map = &ELDER_FLEET_MAP;
+        let m1 = index("m1_convoy_raid");
+        assert_eq!(map.neighbor(m1, Vec2::X), Some(index("m3_station_raid")));
+        assert_eq!(map.neighbor(m1, Vec2::Y), Some(index("m2_patrol_ambush")));
+        assert_eq!(map.neighbor(m1, Vec2::NEG_X), None);
+        assert!(jump_lines(map).contains(&(index("m6_inquisition"), index("m8_stargate"))));
+    }
+}
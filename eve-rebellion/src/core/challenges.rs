@@ -0,0 +1,299 @@
+//! Challenge Runs
+//!
+//! Daily and weekly challenges. The date picks a seed and a set of
+//! modifiers (sometimes the ship too), so everyone flies the same Endless
+//! segment that day or week. Modifiers are applied through
+//! [`DifficultySettings`]. Only a pilot's first attempt at a challenge goes
+//! on its leaderboard; later ones are practice.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use bevy::utils::SystemTime;
+
+use super::factions::Faction;
+use super::leaderboards::format_date;
+use super::resources::DifficultySettings;
+use crate::entities::EnemyBehavior;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Behaviors of swarm waves
+const SWARM_BEHAVIORS: &[EnemyBehavior] = &[EnemyBehavior::Kamikaze, EnemyBehavior::Weaver];
+
+/// How often a challenge changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChallengePeriod {
+    Daily,
+    Weekly,
+}
+
+impl ChallengePeriod {
+    pub const ALL: [ChallengePeriod; 2] = [ChallengePeriod::Daily, ChallengePeriod::Weekly];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChallengePeriod::Daily => "DAILY",
+            ChallengePeriod::Weekly => "WEEKLY",
+        }
+    }
+
+    /// Endless waves to clear
+    pub fn segment_waves(&self) -> u32 {
+        match self {
+            ChallengePeriod::Daily => 10,
+            ChallengePeriod::Weekly => 20,
+        }
+    }
+
+    /// Modifiers drawn for each challenge
+    pub fn modifier_count(&self) -> usize {
+        match self {
+            ChallengePeriod::Daily => 2,
+            ChallengePeriod::Weekly => 3,
+        }
+    }
+
+    /// Challenge number of a day (days since 1970-01-01); weeks start on Monday
+    pub fn index_of_day(&self, day: u64) -> u64 {
+        match self {
+            ChallengePeriod::Daily => day,
+            // 1970-01-01 was a Thursday
+            ChallengePeriod::Weekly => (day + 3) / 7,
+        }
+    }
+
+    /// First day of a challenge number
+    pub fn first_day(&self, index: u64) -> u64 {
+        match self {
+            ChallengePeriod::Daily => index,
+            ChallengePeriod::Weekly => (index * 7).saturating_sub(3),
+        }
+    }
+}
+
+/// A rule change for a challenge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChallengeModifier {
+    /// Enemies fire twice as often
+    DoubleEnemyFire,
+    /// The player ship has no shields
+    NoShields,
+    /// Waves are only Kamikaze and Weaver
+    SwarmWaves,
+    /// Salt Miner is always on
+    SaltMinerAlways,
+    /// Everyone flies the same ship
+    OneShip,
+}
+
+impl ChallengeModifier {
+    pub const ALL: [ChallengeModifier; 5] = [
+        ChallengeModifier::DoubleEnemyFire,
+        ChallengeModifier::NoShields,
+        ChallengeModifier::SwarmWaves,
+        ChallengeModifier::SaltMinerAlways,
+        ChallengeModifier::OneShip,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChallengeModifier::DoubleEnemyFire => "Double Enemy Fire",
+            ChallengeModifier::NoShields => "No Shields",
+            ChallengeModifier::SwarmWaves => "Swarm Waves",
+            ChallengeModifier::SaltMinerAlways => "Salt Miner Always On",
+            ChallengeModifier::OneShip => "One Ship",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ChallengeModifier::DoubleEnemyFire => "Enemies fire twice as often",
+            ChallengeModifier::NoShields => "Your ship launches without shields",
+            ChallengeModifier::SwarmWaves => "Only Kamikaze and Weaver waves",
+            ChallengeModifier::SaltMinerAlways => "Permanent Salt Miner rage",
+            ChallengeModifier::OneShip => "Everyone flies the same hull",
+        }
+    }
+}
+
+/// One daily or weekly challenge
+#[derive(Debug, Clone, PartialEq)]
+pub struct Challenge {
+    pub period: ChallengePeriod,
+    /// Day or week number since 1970-01-01
+    pub index: u64,
+    pub seed: u32,
+    pub modifiers: Vec<ChallengeModifier>,
+    /// Minmatar hull everyone flies, with `OneShip`
+    pub ship_type_id: Option<u32>,
+}
+
+impl Challenge {
+    /// The challenge of a period containing `day` (days since 1970-01-01)
+    pub fn for_day(period: ChallengePeriod, day: u64) -> Self {
+        let index = period.index_of_day(day);
+        let seed = challenge_seed(period, index);
+        let mut rng = fastrand::Rng::with_seed(seed as u64);
+
+        let mut pool = ChallengeModifier::ALL.to_vec();
+        let mut modifiers = Vec::new();
+        for _ in 0..period.modifier_count() {
+            modifiers.push(pool.swap_remove(rng.usize(..pool.len())));
+        }
+        modifiers.sort_by_key(|m| ChallengeModifier::ALL.iter().position(|a| a == m));
+
+        let ships = Faction::Minmatar.player_ships();
+        let ship_type_id = modifiers
+            .contains(&ChallengeModifier::OneShip)
+            .then(|| ships[rng.usize(..ships.len())].type_id);
+
+        Self {
+            period,
+            index,
+            seed,
+            modifiers,
+            ship_type_id,
+        }
+    }
+
+    /// The challenge running now
+    pub fn current(period: ChallengePeriod) -> Self {
+        Self::for_day(period, today())
+    }
+
+    /// Date the challenge started on, `YYYY-MM-DD`
+    pub fn date(&self) -> String {
+        format_date(self.period.first_day(self.index) * SECONDS_PER_DAY)
+    }
+
+    /// Attempt and leaderboard key
+    pub fn key(&self) -> String {
+        format!("{}-{}", self.period.name().to_lowercase(), self.date())
+    }
+
+    /// "Daily 2026-10-19"
+    pub fn title(&self) -> String {
+        let name = self.period.name();
+        format!("{}{} {}", &name[..1], name[1..].to_lowercase(), self.date())
+    }
+
+    pub fn has(&self, modifier: ChallengeModifier) -> bool {
+        self.modifiers.contains(&modifier)
+    }
+
+    /// Layer the modifiers onto difficulty settings
+    pub fn apply(&self, settings: &mut DifficultySettings) {
+        for modifier in &self.modifiers {
+            match modifier {
+                ChallengeModifier::DoubleEnemyFire => settings.enemy.fire_rate_multiplier *= 2.0,
+                ChallengeModifier::NoShields => settings.player.shield_multiplier = 0.0,
+                ChallengeModifier::SwarmWaves => {
+                    settings.enemy.wave_behaviors = Some(SWARM_BEHAVIORS)
+                }
+                ChallengeModifier::SaltMinerAlways => settings.player.salt_miner_always = true,
+                // Picked at launch rather than applied in flight
+                ChallengeModifier::OneShip => {}
+            }
+        }
+    }
+}
+
+/// Seed of a challenge: SplitMix64 of its period and number
+fn challenge_seed(period: ChallengePeriod, index: u64) -> u32 {
+    let tag = match period {
+        ChallengePeriod::Daily => 0x0d41,
+        ChallengePeriod::Weekly => 0x3eec,
+    };
+    let mut z = (index ^ (tag << 48)).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (z ^ (z >> 31)) as u32
+}
+
+/// Days since 1970-01-01 (UTC)
+pub fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs() / SECONDS_PER_DAY)
+        .unwrap_or(0)
+}
+
+/// Challenge being flown, if any
+#[derive(Resource, Debug, Default)]
+pub struct ChallengeRun {
+    pub challenge: Option<Challenge>,
+    /// First attempt: goes on the challenge leaderboard
+    pub ranked: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2026-10-19 is a Monday
+    const MONDAY: u64 = 20_745;
+
+    #[test]
+    fn challenges_follow_the_calendar() {
+        let daily = Challenge::for_day(ChallengePeriod::Daily, MONDAY);
+        assert_eq!(daily, Challenge::for_day(ChallengePeriod::Daily, MONDAY));
+        assert_ne!(
+            daily.seed,
+            Challenge::for_day(ChallengePeriod::Daily, MONDAY + 1).seed
+        );
+        assert_eq!(daily.key(), "daily-2026-10-19");
+        assert_eq!(daily.title(), "Daily 2026-10-19");
+
+        let weekly = Challenge::for_day(ChallengePeriod::Weekly, MONDAY + 6);
+        assert_eq!(weekly, Challenge::for_day(ChallengePeriod::Weekly, MONDAY));
+        assert_ne!(
+            weekly.index,
+            Challenge::for_day(ChallengePeriod::Weekly, MONDAY + 7).index
+        );
+        assert_eq!(weekly.key(), "weekly-2026-10-19");
+
+        for day in MONDAY..MONDAY + 30 {
+            for period in ChallengePeriod::ALL {
+                let challenge = Challenge::for_day(period, day);
+                let mut modifiers = challenge.modifiers.clone();
+                modifiers.dedup();
+                assert_eq!(modifiers.len(), period.modifier_count());
+                assert_eq!(
+                    challenge.ship_type_id.is_some(),
+                    challenge.has(ChallengeModifier::OneShip)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn current_challenges_use_the_clock() {
+        // Reads the same clock the web build does
+        let day = today();
+        assert!(day >= MONDAY);
+        for period in ChallengePeriod::ALL {
+            let current = Challenge::current(period);
+            let start = period.first_day(current.index);
+            assert!(start <= day && day - start < 7);
+        }
+    }
+
+    #[test]
+    fn modifiers_apply_to_difficulty_settings() {
+        let challenge = Challenge {
+            period: ChallengePeriod::Daily,
+            index: MONDAY,
+            seed: 1,
+            modifiers: ChallengeModifier::ALL.to_vec(),
+            ship_type_id: Some(587),
+        };
+        let mut settings = DifficultySettings::default();
+        challenge.apply(&mut settings);
+        assert_eq!(settings.enemy.fire_rate_multiplier, 2.0);
+        assert_eq!(settings.player.shield_multiplier, 0.0);
+        assert_eq!(settings.enemy.wave_behaviors, Some(SWARM_BEHAVIORS));
+        assert!(settings.player.salt_miner_always);
+        assert_eq!(settings.enemy.damage_multiplier, 1.0);
+    }
+}
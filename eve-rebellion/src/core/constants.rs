@@ -95,6 +95,9 @@ pub const MULTIPLIER_PER_KILL: f32 = 0.1;
 /// Maximum score multiplier
 pub const MAX_MULTIPLIER: f32 = 99.9;
 
+/// Base points per graze
+pub const POINTS_PER_GRAZE: u64 = 25;
+
 // =============================================================================
 // SALT_MINER
 // =============================================================================
@@ -133,6 +136,9 @@ pub const CAP_BATTLECRUISER: f32 = 2000.0;
 /// Base capacitor for battleships (GJ)
 pub const CAP_BATTLESHIP: f32 = 5000.0;
 
+/// Capacitor charged per graze (fraction of max capacitor)
+pub const CAP_CHARGE_PER_GRAZE: f32 = 0.02;
+
 // =============================================================================
 // SHIP SIZES (relative to each other, in pixels)
 // =============================================================================
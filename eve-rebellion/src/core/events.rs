@@ -39,11 +39,25 @@ pub struct DamageLayerEvent {
 #[derive(Event)]
 pub struct EnemyDestroyedEvent {
     pub position: Vec2,
+    /// EVE type ID
+    pub type_id: u32,
     pub enemy_type: String,
     pub score_value: u64,
     pub was_boss: bool,
 }
 
+/// Player projectile hit an enemy
+#[derive(Event)]
+pub struct EnemyHitEvent {
+    pub position: Vec2,
+    /// Damage after resists, ammo and crits
+    pub damage: f32,
+    pub damage_type: DamageType,
+    /// Layer struck (None for enemies without a resist profile)
+    pub layer: Option<DamageLayer>,
+    pub crit: bool,
+}
+
 /// Player fired weapon
 #[derive(Event)]
 pub struct PlayerFireEvent {
@@ -93,6 +107,20 @@ pub struct BossDefeatedEvent {
     pub score_value: u64,
 }
 
+/// Boss entered a new phase
+#[derive(Event)]
+pub struct BossPhaseChangeEvent {
+    pub boss_name: String,
+    pub phase: u32,
+    pub total_phases: u32,
+}
+
+/// A wingman was shot down
+#[derive(Event)]
+pub struct WingmanLostEvent {
+    pub position: Vec2,
+}
+
 /// Collectible picked up
 #[derive(Event)]
 pub struct CollectiblePickedUpEvent {
@@ -132,11 +160,41 @@ pub struct ExplosionEvent {
     pub color: Color,
 }
 
-/// Play sound effect
+/// Play sound effect (routed through the audio mixer)
 #[derive(Event)]
 pub struct PlaySoundEvent {
     pub sound: SoundType,
     pub volume: f32,
+    /// Playback speed (pitch) multiplier
+    pub speed: f32,
+    /// World position of the emitter (None = centered, no distance falloff)
+    pub position: Option<Vec2>,
+}
+
+impl PlaySoundEvent {
+    pub fn new(sound: SoundType) -> Self {
+        Self {
+            sound,
+            volume: 1.0,
+            speed: 1.0,
+            position: None,
+        }
+    }
+
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn at(mut self, position: Vec2) -> Self {
+        self.position = Some(position);
+        self
+    }
 }
 
 // =============================================================================
@@ -152,6 +210,37 @@ pub enum DamageType {
     Explosive, // Missiles, artillery
 }
 
+impl DamageType {
+    pub fn all() -> [DamageType; 4] {
+        [
+            DamageType::EM,
+            DamageType::Thermal,
+            DamageType::Kinetic,
+            DamageType::Explosive,
+        ]
+    }
+
+    /// Index into resist arrays
+    pub fn index(&self) -> usize {
+        match self {
+            DamageType::EM => 0,
+            DamageType::Thermal => 1,
+            DamageType::Kinetic => 2,
+            DamageType::Explosive => 3,
+        }
+    }
+
+    /// Short label for resist tables
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            DamageType::EM => "EM",
+            DamageType::Thermal => "TH",
+            DamageType::Kinetic => "KI",
+            DamageType::Explosive => "EX",
+        }
+    }
+}
+
 /// Weapon types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WeaponType {
@@ -166,7 +255,7 @@ pub enum WeaponType {
 }
 
 /// Ammo types for Minmatar autocannons (affects damage and fire rate)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
 #[allow(clippy::upper_case_acronyms)] // EVE Online uses "EMP" as damage type name
 pub enum AmmoType {
     /// Balanced ammo - no bonuses or penalties
@@ -180,6 +269,10 @@ pub enum AmmoType {
     Fusion,
     /// Fast-tracking ammo - -10% damage, +40% fire rate
     Barrage,
+    /// Heavy short-range ammo - +45% damage, -40% fire rate
+    Hail,
+    /// Rapid long-range ammo - -25% damage, +70% fire rate
+    Tremor,
 }
 
 impl AmmoType {
@@ -191,6 +284,8 @@ impl AmmoType {
             AmmoType::Plasma => "PLASMA",
             AmmoType::Fusion => "FUSION",
             AmmoType::Barrage => "BARRAGE",
+            AmmoType::Hail => "HAIL",
+            AmmoType::Tremor => "TREMOR",
         }
     }
 
@@ -202,6 +297,8 @@ impl AmmoType {
             AmmoType::Plasma => "Phased Plasma",
             AmmoType::Fusion => "Fusion",
             AmmoType::Barrage => "Barrage",
+            AmmoType::Hail => "Hail",
+            AmmoType::Tremor => "Tremor",
         }
     }
 
@@ -214,6 +311,8 @@ impl AmmoType {
             AmmoType::Plasma => Color::srgb(1.0, 0.5, 0.0), // Orange
             AmmoType::Fusion => Color::srgb(1.0, 0.2, 0.2), // Red
             AmmoType::Barrage => Color::srgb(0.9, 0.8, 0.2), // Yellow
+            AmmoType::Hail => Color::srgb(0.9, 0.3, 0.6),  // Pink
+            AmmoType::Tremor => Color::srgb(0.6, 1.0, 0.6), // Pale green
         }
     }
 
@@ -225,6 +324,8 @@ impl AmmoType {
             AmmoType::Plasma => 0.7,
             AmmoType::Fusion => 1.3,
             AmmoType::Barrage => 0.9,
+            AmmoType::Hail => 1.45,
+            AmmoType::Tremor => 0.75,
         }
     }
 
@@ -236,6 +337,18 @@ impl AmmoType {
             AmmoType::Plasma => 1.5,
             AmmoType::Fusion => 1.3,
             AmmoType::Barrage => 0.9,
+            AmmoType::Hail => 1.45,
+            AmmoType::Tremor => 0.75,
+        }
+    }
+
+    /// Damage type the rounds deal
+    pub fn damage_type(&self) -> DamageType {
+        match self {
+            AmmoType::Sabot | AmmoType::Barrage | AmmoType::Tremor => DamageType::Kinetic,
+            AmmoType::EMP => DamageType::EM,
+            AmmoType::Plasma => DamageType::Thermal,
+            AmmoType::Fusion | AmmoType::Hail => DamageType::Explosive,
         }
     }
 
@@ -247,6 +360,8 @@ impl AmmoType {
             AmmoType::Plasma => 1.0,
             AmmoType::Fusion => 0.7,
             AmmoType::Barrage => 1.4,
+            AmmoType::Hail => 0.6,
+            AmmoType::Tremor => 1.7,
         }
     }
 
@@ -257,22 +372,26 @@ impl AmmoType {
             AmmoType::EMP => AmmoType::Plasma,
             AmmoType::Plasma => AmmoType::Fusion,
             AmmoType::Fusion => AmmoType::Barrage,
-            AmmoType::Barrage => AmmoType::Sabot,
+            AmmoType::Barrage => AmmoType::Hail,
+            AmmoType::Hail => AmmoType::Tremor,
+            AmmoType::Tremor => AmmoType::Sabot,
         }
     }
 
     /// Cycle to previous ammo type
     pub fn prev(&self) -> AmmoType {
         match self {
-            AmmoType::Sabot => AmmoType::Barrage,
+            AmmoType::Sabot => AmmoType::Tremor,
             AmmoType::EMP => AmmoType::Sabot,
             AmmoType::Plasma => AmmoType::EMP,
             AmmoType::Fusion => AmmoType::Plasma,
             AmmoType::Barrage => AmmoType::Fusion,
+            AmmoType::Hail => AmmoType::Barrage,
+            AmmoType::Tremor => AmmoType::Hail,
         }
     }
 
-    /// Get ammo type from number key (1-5)
+    /// Get ammo type from number key (1-7)
     pub fn from_number(n: u8) -> Option<AmmoType> {
         match n {
             1 => Some(AmmoType::Sabot),
@@ -280,6 +399,8 @@ impl AmmoType {
             3 => Some(AmmoType::Plasma),
             4 => Some(AmmoType::Fusion),
             5 => Some(AmmoType::Barrage),
+            6 => Some(AmmoType::Hail),
+            7 => Some(AmmoType::Tremor),
             _ => None,
         }
     }
@@ -313,7 +434,8 @@ pub enum CollectibleType {
     SkillPointDrop, // SP currency drop
 }
 
-/// Upgrade types purchasable with Skill Points
+/// Ship upgrades: roguelite run modules, and the Skill Point shop of older
+/// saves (see `SkillSheet::migrate_upgrade`)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum Upgrade {
     /// +20 max shields
@@ -414,17 +536,6 @@ impl Upgrade {
             Upgrade::ShieldBooster => 100,
         }
     }
-
-    /// Prerequisite upgrade (if any)
-    pub fn requires(&self) -> Option<Upgrade> {
-        match self {
-            Upgrade::ShieldBoost2 => Some(Upgrade::ShieldBoost1),
-            Upgrade::ArmorPlate2 => Some(Upgrade::ArmorPlate1),
-            Upgrade::Gyrostabilizer2 => Some(Upgrade::Gyrostabilizer1),
-            Upgrade::DamageAmplifier2 => Some(Upgrade::DamageAmplifier1),
-            _ => None,
-        }
-    }
 }
 
 /// Skill Points resource - persistent currency
@@ -445,7 +556,7 @@ pub enum ExplosionSize {
 }
 
 /// Sound effect types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SoundType {
     // Weapons
     Autocannon,
@@ -472,9 +583,63 @@ pub enum SoundType {
     PowerUp,
     Liberation, // Soul liberated
     SaltMinerActivate,
+    Graze,
     Warning,
     Victory,
     GameOver,
+
+    // Powerups
+    PowerupOverdrive,
+    PowerupDamage,
+    PowerupInvuln,
+    PowerupHealth,
+
+    // EVE-style health alarms
+    ShieldWarning,
+    ArmorWarning,
+    HullWarning,
+
+    // Game events
+    WaveComplete,
+    BossSpawn,
+    WaveStart,
+    BossPhase,
+
+    // Abilities
+    AbilitySpeed,
+    AbilityShield,
+    AbilityArmor,
+    AbilityWeapon,
+    AbilityDrone,
+    AbilityDebuff,
+    AbilityDamage,
+    AbilityReady,
+}
+
+/// A line of text to narrate
+#[derive(Event, Debug, Clone)]
+pub struct NarrationEvent {
+    pub text: String,
+    /// Cut off whatever is still being spoken (menu navigation)
+    pub interrupt: bool,
+}
+
+impl NarrationEvent {
+    /// Narrate immediately, replacing any line still being spoken
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            interrupt: true,
+        }
+    }
+
+    /// Narrate after the current line finishes (HUD announcements)
+    pub fn queued(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            interrupt: false,
+        }
+    }
 }
 
 /// Plugin to register all events
@@ -485,17 +650,21 @@ impl Plugin for GameEventsPlugin {
         app.add_event::<PlayerDamagedEvent>()
             .add_event::<DamageLayerEvent>()
             .add_event::<EnemyDestroyedEvent>()
+            .add_event::<EnemyHitEvent>()
             .add_event::<PlayerFireEvent>()
             .add_event::<SpawnEnemyEvent>()
             .add_event::<SpawnWaveEvent>()
             .add_event::<StageCompleteEvent>()
             .add_event::<BossDefeatedEvent>()
+            .add_event::<BossPhaseChangeEvent>()
+            .add_event::<WingmanLostEvent>()
             .add_event::<CollectiblePickedUpEvent>()
             .add_event::<PickupEffectEvent>()
             .add_event::<SaltMinerActivatedEvent>()
             .add_event::<SaltMinerEndedEvent>()
             .add_event::<ScreenShakeEvent>()
             .add_event::<ExplosionEvent>()
-            .add_event::<PlaySoundEvent>();
+            .add_event::<PlaySoundEvent>()
+            .add_event::<NarrationEvent>();
     }
 }
@@ -274,6 +274,26 @@ pub enum ShipClass {
 }
 
 impl ShipClass {
+    pub fn all() -> [ShipClass; 8] {
+        [
+            ShipClass::Frigate,
+            ShipClass::AssaultFrigate,
+            ShipClass::Interceptor,
+            ShipClass::Destroyer,
+            ShipClass::TacticalDestroyer,
+            ShipClass::Cruiser,
+            ShipClass::Battlecruiser,
+            ShipClass::Battleship,
+        ]
+    }
+
+    /// Class by display name, ignoring case (mod ship files)
+    pub fn from_name(name: &str) -> Option<ShipClass> {
+        Self::all()
+            .into_iter()
+            .find(|c| c.name().eq_ignore_ascii_case(name))
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             ShipClass::Frigate => "Frigate",
@@ -822,10 +842,10 @@ impl GameSession {
     }
 
     /// Get a random enemy based on spawn weights
-    pub fn random_enemy(&self) -> &'static EnemyShipDef {
+    pub fn random_enemy(&self, rng: &mut fastrand::Rng) -> &'static EnemyShipDef {
         let enemies = self.enemy_ships();
         let total_weight: u32 = enemies.iter().map(|e| e.spawn_weight).sum();
-        let roll = fastrand::u32(0..total_weight);
+        let roll = rng.u32(0..total_weight);
 
         let mut cumulative = 0;
         for enemy in enemies {
@@ -1108,10 +1128,11 @@ mod tests {
     fn game_session_random_enemy_returns_valid() {
         let session = GameSession::new(Faction::Minmatar, Faction::Amarr);
         let enemies = session.enemy_ships();
+        let mut rng = fastrand::Rng::with_seed(7);
 
         // Sample 100 random enemies, all should be from the enemy faction
         for _ in 0..100 {
-            let enemy = session.random_enemy();
+            let enemy = session.random_enemy(&mut rng);
             assert!(
                 enemies.iter().any(|e| e.type_id == enemy.type_id),
                 "Random enemy should be from enemy faction"
@@ -1126,9 +1147,10 @@ mod tests {
         // Sample many enemies and check distribution roughly matches weights
         let mut counts = std::collections::HashMap::new();
         let samples = 10000;
+        let mut rng = fastrand::Rng::with_seed(7);
 
         for _ in 0..samples {
-            let enemy = session.random_enemy();
+            let enemy = session.random_enemy(&mut rng);
             *counts.entry(enemy.name).or_insert(0) += 1;
         }
 
@@ -1145,6 +1167,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn game_session_random_enemy_repeats_for_a_seed() {
+        let session = GameSession::new(Faction::Minmatar, Faction::Amarr);
+        let draw = |seed| {
+            let mut rng = fastrand::Rng::with_seed(seed);
+            (0..20)
+                .map(|_| session.random_enemy(&mut rng).type_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(draw(42), draw(42));
+    }
+
     // ==================== Color Validation ====================
 
     #[test]
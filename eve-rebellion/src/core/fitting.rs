@@ -0,0 +1,569 @@
+//! Ship Fitting
+//!
+//! EVE-style fittings: every hull has high, mid and low slots and a
+//! powergrid and CPU budget. Modules go into slots of their kind and change
+//! the ship's stats, weapon and active ability. Fittings are saved per
+//! ship type in `SaveData` and applied when the player ship spawns.
+
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::constants::CAP_FRIGATE;
+use super::factions::{ShipClass, ShipDef};
+use crate::systems::ability::AbilityType;
+
+// ============================================================================
+// Slots
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SlotKind {
+    /// Weapons
+    High,
+    /// Shield and propulsion
+    Mid,
+    /// Armor, hull and weapon upgrades
+    Low,
+}
+
+impl SlotKind {
+    pub fn all() -> [SlotKind; 3] {
+        [SlotKind::High, SlotKind::Mid, SlotKind::Low]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SlotKind::High => "HIGH",
+            SlotKind::Mid => "MID",
+            SlotKind::Low => "LOW",
+        }
+    }
+}
+
+/// Slots and fitting budget of a hull
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlotLayout {
+    pub high: usize,
+    pub mid: usize,
+    pub low: usize,
+    pub powergrid: f32,
+    pub cpu: f32,
+}
+
+impl SlotLayout {
+    /// Layout of a ship, by hull class
+    pub fn for_ship(ship: &ShipDef) -> Self {
+        let (high, mid, low, powergrid, cpu) = match ship.class {
+            ShipClass::Frigate => (3, 3, 2, 30.0, 60.0),
+            ShipClass::Interceptor => (3, 3, 2, 28.0, 65.0),
+            ShipClass::AssaultFrigate => (4, 3, 3, 40.0, 75.0),
+            ShipClass::Destroyer => (6, 2, 2, 45.0, 70.0),
+            ShipClass::TacticalDestroyer => (5, 3, 3, 50.0, 85.0),
+            ShipClass::Cruiser => (4, 4, 4, 70.0, 110.0),
+            ShipClass::Battlecruiser => (5, 4, 5, 90.0, 130.0),
+            ShipClass::Battleship => (6, 5, 6, 120.0, 160.0),
+        };
+        Self {
+            high,
+            mid,
+            low,
+            powergrid,
+            cpu,
+        }
+    }
+
+    pub fn count(&self, kind: SlotKind) -> usize {
+        match kind {
+            SlotKind::High => self.high,
+            SlotKind::Mid => self.mid,
+            SlotKind::Low => self.low,
+        }
+    }
+}
+
+// ============================================================================
+// Modules
+// ============================================================================
+
+/// A fittable module
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShipModule {
+    // High slots
+    Autocannon,
+    ArtilleryCannon,
+    RocketLauncher,
+    /// Needs the Drones skill
+    DroneLinkAugmentor,
+    // Mid slots
+    ShieldExtender,
+    ShieldBooster,
+    Afterburner,
+    CapRecharger,
+    /// Needs the Propulsion Jamming skill
+    StasisWebifier,
+    // Low slots
+    ArmorPlate,
+    ArmorRepairer,
+    DamageControl,
+    GyroStabilizer,
+}
+
+impl ShipModule {
+    pub fn all() -> &'static [ShipModule] {
+        &[
+            ShipModule::Autocannon,
+            ShipModule::ArtilleryCannon,
+            ShipModule::RocketLauncher,
+            ShipModule::DroneLinkAugmentor,
+            ShipModule::ShieldExtender,
+            ShipModule::ShieldBooster,
+            ShipModule::Afterburner,
+            ShipModule::CapRecharger,
+            ShipModule::StasisWebifier,
+            ShipModule::ArmorPlate,
+            ShipModule::ArmorRepairer,
+            ShipModule::DamageControl,
+            ShipModule::GyroStabilizer,
+        ]
+    }
+
+    /// Modules that fit a slot kind
+    pub fn for_slot(kind: SlotKind) -> Vec<ShipModule> {
+        Self::all()
+            .iter()
+            .copied()
+            .filter(|m| m.slot() == kind)
+            .collect()
+    }
+
+    pub fn slot(&self) -> SlotKind {
+        match self {
+            ShipModule::Autocannon
+            | ShipModule::ArtilleryCannon
+            | ShipModule::RocketLauncher
+            | ShipModule::DroneLinkAugmentor => SlotKind::High,
+            ShipModule::ShieldExtender
+            | ShipModule::ShieldBooster
+            | ShipModule::Afterburner
+            | ShipModule::CapRecharger
+            | ShipModule::StasisWebifier => SlotKind::Mid,
+            ShipModule::ArmorPlate
+            | ShipModule::ArmorRepairer
+            | ShipModule::DamageControl
+            | ShipModule::GyroStabilizer => SlotKind::Low,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ShipModule::Autocannon => "200mm Autocannon",
+            ShipModule::ArtilleryCannon => "280mm Artillery",
+            ShipModule::RocketLauncher => "Rocket Launcher",
+            ShipModule::DroneLinkAugmentor => "Drone Link Augmentor",
+            ShipModule::ShieldExtender => "Medium Shield Extender",
+            ShipModule::ShieldBooster => "Small Shield Booster",
+            ShipModule::Afterburner => "1MN Afterburner",
+            ShipModule::CapRecharger => "Cap Recharger",
+            ShipModule::StasisWebifier => "Stasis Webifier",
+            ShipModule::ArmorPlate => "200mm Steel Plates",
+            ShipModule::ArmorRepairer => "Small Armor Repairer",
+            ShipModule::DamageControl => "Damage Control",
+            ShipModule::GyroStabilizer => "Gyrostabilizer",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ShipModule::Autocannon => "+12% rate of fire",
+            ShipModule::ArtilleryCannon => "+20% damage, -8% rate of fire",
+            ShipModule::RocketLauncher => "+8% damage. Ability: Rocket Barrage",
+            ShipModule::DroneLinkAugmentor => "Ability: Drone Bay",
+            ShipModule::ShieldExtender => "+40 shield",
+            ShipModule::ShieldBooster => "+25% shield regen. Ability: Shield Boost",
+            ShipModule::Afterburner => "+15% speed. Ability: Afterburner",
+            ShipModule::CapRecharger => "+30% capacitor recharge",
+            ShipModule::StasisWebifier => "Ability: Warp Disruptor",
+            ShipModule::ArmorPlate => "+50 armor, -8% speed",
+            ShipModule::ArmorRepairer => "+15 armor. Ability: Armor Repair",
+            ShipModule::DamageControl => "+40% hull, +10% armor",
+            ShipModule::GyroStabilizer => "+10% damage, +5% rate of fire",
+        }
+    }
+
+    /// Powergrid needed (MW)
+    pub fn powergrid(&self) -> f32 {
+        match self {
+            ShipModule::Autocannon => 6.0,
+            ShipModule::ArtilleryCannon => 10.0,
+            ShipModule::RocketLauncher => 5.0,
+            ShipModule::DroneLinkAugmentor => 4.0,
+            ShipModule::ShieldExtender => 8.0,
+            ShipModule::ShieldBooster => 4.0,
+            ShipModule::Afterburner => 10.0,
+            ShipModule::CapRecharger => 1.0,
+            ShipModule::StasisWebifier => 1.0,
+            ShipModule::ArmorPlate => 12.0,
+            ShipModule::ArmorRepairer => 8.0,
+            ShipModule::DamageControl => 1.0,
+            ShipModule::GyroStabilizer => 1.0,
+        }
+    }
+
+    /// CPU needed (tf)
+    pub fn cpu(&self) -> f32 {
+        match self {
+            ShipModule::Autocannon => 5.0,
+            ShipModule::ArtilleryCannon => 8.0,
+            ShipModule::RocketLauncher => 15.0,
+            ShipModule::DroneLinkAugmentor => 20.0,
+            ShipModule::ShieldExtender => 20.0,
+            ShipModule::ShieldBooster => 25.0,
+            ShipModule::Afterburner => 15.0,
+            ShipModule::CapRecharger => 15.0,
+            ShipModule::StasisWebifier => 25.0,
+            ShipModule::ArmorPlate => 0.0,
+            ShipModule::ArmorRepairer => 10.0,
+            ShipModule::DamageControl => 15.0,
+            ShipModule::GyroStabilizer => 20.0,
+        }
+    }
+
+    /// Active ability the module brings, replacing the hull's own
+    pub fn ability(&self) -> Option<AbilityType> {
+        match self {
+            ShipModule::RocketLauncher => Some(AbilityType::RocketBarrage),
+            ShipModule::ShieldBooster => Some(AbilityType::ShieldBoost),
+            ShipModule::Afterburner => Some(AbilityType::Afterburner),
+            ShipModule::ArmorRepairer => Some(AbilityType::ArmorRepair),
+            ShipModule::DroneLinkAugmentor => Some(AbilityType::DroneBay),
+            ShipModule::StasisWebifier => Some(AbilityType::WarpDisruptor),
+            _ => None,
+        }
+    }
+
+    /// Add this module's effect to fitted stats
+    pub fn apply(&self, stats: &mut FittedStats) {
+        match self {
+            ShipModule::Autocannon => stats.fire_rate *= 1.12,
+            ShipModule::ArtilleryCannon => {
+                stats.damage *= 1.2;
+                stats.fire_rate *= 0.92;
+            }
+            ShipModule::RocketLauncher => stats.damage *= 1.08,
+            ShipModule::ShieldExtender => stats.shield += 40.0,
+            ShipModule::ShieldBooster => stats.shield_regen *= 1.25,
+            ShipModule::Afterburner => stats.speed *= 1.15,
+            ShipModule::CapRecharger => stats.capacitor_regen *= 1.3,
+            ShipModule::ArmorPlate => {
+                stats.armor += 50.0;
+                stats.speed *= 0.92;
+            }
+            ShipModule::ArmorRepairer => stats.armor += 15.0,
+            ShipModule::DamageControl => {
+                stats.hull *= 1.4;
+                stats.armor *= 1.1;
+            }
+            ShipModule::GyroStabilizer => {
+                stats.damage *= 1.1;
+                stats.fire_rate *= 1.05;
+            }
+            ShipModule::DroneLinkAugmentor | ShipModule::StasisWebifier => {}
+        }
+    }
+}
+
+// ============================================================================
+// Fittings
+// ============================================================================
+
+/// Ship values a fitting changes; regen values are multipliers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FittedStats {
+    pub shield: f32,
+    pub armor: f32,
+    pub hull: f32,
+    pub capacitor: f32,
+    pub shield_regen: f32,
+    pub capacitor_regen: f32,
+    pub speed: f32,
+    pub fire_rate: f32,
+    pub damage: f32,
+}
+
+impl FittedStats {
+    /// Unfitted hull, with the usual shield/armor/hull split
+    pub fn base(ship: &ShipDef) -> Self {
+        Self {
+            shield: ship.health * 0.4,
+            armor: ship.health * 0.35,
+            hull: ship.health * 0.25,
+            capacitor: CAP_FRIGATE,
+            shield_regen: 1.0,
+            capacitor_regen: 1.0,
+            speed: ship.speed,
+            fire_rate: ship.fire_rate,
+            damage: ship.damage,
+        }
+    }
+
+    /// Labelled values for display
+    pub fn rows(&self) -> [(&'static str, f32); 9] {
+        [
+            ("Shield", self.shield),
+            ("Armor", self.armor),
+            ("Hull", self.hull),
+            ("Capacitor", self.capacitor),
+            ("Shield regen", self.shield_regen * 100.0),
+            ("Cap recharge", self.capacitor_regen * 100.0),
+            ("Speed", self.speed),
+            ("Rate of fire", self.fire_rate),
+            ("Damage", self.damage),
+        ]
+    }
+}
+
+/// Why a fitting does not fit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FittingError {
+    /// More modules than slots, or a module in the wrong slot kind
+    Slots(SlotKind),
+    Powergrid {
+        used: f32,
+        available: f32,
+    },
+    Cpu {
+        used: f32,
+        available: f32,
+    },
+}
+
+impl fmt::Display for FittingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FittingError::Slots(kind) => write!(f, "{} slots overfitted", kind.name()),
+            FittingError::Powergrid { used, available } => {
+                write!(f, "Powergrid {:.0}/{:.0} MW", used, available)
+            }
+            FittingError::Cpu { used, available } => {
+                write!(f, "CPU {:.0}/{:.0} tf", used, available)
+            }
+        }
+    }
+}
+
+/// Modules fitted to one ship; empty slots are `None`
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ShipFitting {
+    #[serde(default)]
+    pub high: Vec<Option<ShipModule>>,
+    #[serde(default)]
+    pub mid: Vec<Option<ShipModule>>,
+    #[serde(default)]
+    pub low: Vec<Option<ShipModule>>,
+}
+
+impl ShipFitting {
+    pub fn slots(&self, kind: SlotKind) -> &[Option<ShipModule>] {
+        match kind {
+            SlotKind::High => &self.high,
+            SlotKind::Mid => &self.mid,
+            SlotKind::Low => &self.low,
+        }
+    }
+
+    fn slots_mut(&mut self, kind: SlotKind) -> &mut Vec<Option<ShipModule>> {
+        match kind {
+            SlotKind::High => &mut self.high,
+            SlotKind::Mid => &mut self.mid,
+            SlotKind::Low => &mut self.low,
+        }
+    }
+
+    pub fn get(&self, kind: SlotKind, index: usize) -> Option<ShipModule> {
+        self.slots(kind).get(index).copied().flatten()
+    }
+
+    /// Put a module in (or clear) a slot
+    pub fn set(&mut self, kind: SlotKind, index: usize, module: Option<ShipModule>) {
+        let slots = self.slots_mut(kind);
+        if slots.len() <= index {
+            slots.resize(index + 1, None);
+        }
+        slots[index] = module;
+        while slots.last() == Some(&None) {
+            slots.pop();
+        }
+    }
+
+    /// Fitted modules, high slots first
+    pub fn modules(&self) -> impl Iterator<Item = ShipModule> + '_ {
+        self.high
+            .iter()
+            .chain(&self.mid)
+            .chain(&self.low)
+            .filter_map(|m| *m)
+    }
+
+    /// Empty every slot whose module fails `keep`; returns what was removed
+    pub fn remove_unless(&mut self, mut keep: impl FnMut(ShipModule) -> bool) -> Vec<ShipModule> {
+        let mut removed = Vec::new();
+        for kind in SlotKind::all() {
+            for index in 0..self.slots(kind).len() {
+                if let Some(module) = self.get(kind, index).filter(|&m| !keep(m)) {
+                    removed.push(module);
+                    self.set(kind, index, None);
+                }
+            }
+        }
+        removed
+    }
+
+    pub fn powergrid_used(&self) -> f32 {
+        self.modules().map(|m| m.powergrid()).sum()
+    }
+
+    pub fn cpu_used(&self) -> f32 {
+        self.modules().map(|m| m.cpu()).sum()
+    }
+
+    /// Check slots and budgets against a hull
+    pub fn check(&self, layout: &SlotLayout) -> Result<(), FittingError> {
+        for kind in SlotKind::all() {
+            let slots = self.slots(kind);
+            let overfitted = slots.iter().skip(layout.count(kind)).any(|m| m.is_some());
+            let misplaced = slots.iter().flatten().any(|m| m.slot() != kind);
+            if overfitted || misplaced {
+                return Err(FittingError::Slots(kind));
+            }
+        }
+        let powergrid = self.powergrid_used();
+        if powergrid > layout.powergrid {
+            return Err(FittingError::Powergrid {
+                used: powergrid,
+                available: layout.powergrid,
+            });
+        }
+        let cpu = self.cpu_used();
+        if cpu > layout.cpu {
+            return Err(FittingError::Cpu {
+                used: cpu,
+                available: layout.cpu,
+            });
+        }
+        Ok(())
+    }
+
+    /// Hull values with every module applied
+    pub fn stats(&self, ship: &ShipDef) -> FittedStats {
+        let mut stats = FittedStats::base(ship);
+        for module in self.modules() {
+            module.apply(&mut stats);
+        }
+        stats
+    }
+
+    /// Ability of the first fitted module that has one
+    pub fn ability(&self) -> Option<AbilityType> {
+        self.modules().find_map(|m| m.ability())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::MINMATAR_SHIPS;
+
+    fn frigate() -> ShipDef {
+        *MINMATAR_SHIPS
+            .iter()
+            .find(|s| s.class == ShipClass::Frigate)
+            .unwrap()
+    }
+
+    #[test]
+    fn modules_change_stats_and_ability() {
+        let ship = frigate();
+        let mut fitting = ShipFitting::default();
+        fitting.set(SlotKind::Mid, 0, Some(ShipModule::ShieldExtender));
+        fitting.set(SlotKind::Mid, 1, Some(ShipModule::Afterburner));
+
+        let base = FittedStats::base(&ship);
+        let fitted = fitting.stats(&ship);
+        assert!((fitted.shield - base.shield - 40.0).abs() < 1e-3);
+        assert!(fitted.speed > base.speed);
+        assert_eq!(fitting.ability(), Some(AbilityType::Afterburner));
+        assert_eq!(ShipFitting::default().stats(&ship), base);
+    }
+
+    #[test]
+    fn budgets_and_slots_are_enforced() {
+        let ship = frigate();
+        let layout = SlotLayout::for_ship(&ship);
+        let mut fitting = ShipFitting::default();
+        assert!(fitting.check(&layout).is_ok());
+
+        // Plates everywhere: two fit the low slots but not the powergrid
+        fitting.set(SlotKind::Low, 0, Some(ShipModule::ArmorPlate));
+        fitting.set(SlotKind::Low, 1, Some(ShipModule::ArmorPlate));
+        fitting.set(SlotKind::High, 0, Some(ShipModule::ArtilleryCannon));
+        assert!(matches!(
+            fitting.check(&layout),
+            Err(FittingError::Powergrid { .. })
+        ));
+
+        let mut fitting = ShipFitting::default();
+        fitting.set(SlotKind::Low, layout.low, Some(ShipModule::DamageControl));
+        assert_eq!(
+            fitting.check(&layout),
+            Err(FittingError::Slots(SlotKind::Low))
+        );
+
+        let mut fitting = ShipFitting::default();
+        fitting.set(SlotKind::High, 0, Some(ShipModule::ArmorPlate));
+        assert_eq!(
+            fitting.check(&layout),
+            Err(FittingError::Slots(SlotKind::High))
+        );
+    }
+
+    #[test]
+    fn clearing_slots_trims_the_fitting() {
+        let mut fitting = ShipFitting::default();
+        fitting.set(SlotKind::High, 2, Some(ShipModule::Autocannon));
+        assert_eq!(fitting.high.len(), 3);
+        fitting.set(SlotKind::High, 2, None);
+        assert!(fitting.high.is_empty());
+        assert_eq!(fitting, ShipFitting::default());
+    }
+
+    #[test]
+    fn removing_modules_keeps_the_rest_in_place() {
+        let mut fitting = ShipFitting::default();
+        fitting.set(SlotKind::High, 0, Some(ShipModule::Autocannon));
+        fitting.set(SlotKind::Mid, 0, Some(ShipModule::StasisWebifier));
+        fitting.set(SlotKind::Mid, 1, Some(ShipModule::ShieldExtender));
+        fitting.set(SlotKind::High, 1, Some(ShipModule::DroneLinkAugmentor));
+        fitting.set(SlotKind::Low, 0, Some(ShipModule::DamageControl));
+
+        let removed = fitting.remove_unless(|m| {
+            m != ShipModule::StasisWebifier && m != ShipModule::DroneLinkAugmentor
+        });
+        assert_eq!(
+            removed,
+            vec![ShipModule::DroneLinkAugmentor, ShipModule::StasisWebifier]
+        );
+        assert_eq!(fitting.get(SlotKind::High, 0), Some(ShipModule::Autocannon));
+        assert_eq!(fitting.get(SlotKind::Mid, 0), None);
+        assert_eq!(
+            fitting.get(SlotKind::Mid, 1),
+            Some(ShipModule::ShieldExtender)
+        );
+        assert_eq!(fitting.high.len(), 1);
+        assert_eq!(
+            fitting.get(SlotKind::Low, 0),
+            Some(ShipModule::DamageControl)
+        );
+    }
+}
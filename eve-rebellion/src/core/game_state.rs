@@ -5,21 +5,27 @@
 #![allow(dead_code)]
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Main game state - controls which systems run and what's displayed
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
 pub enum GameState {
     #[default]
     Loading,
+    ProfileSelect, // Pick or create a pilot profile
     MainMenu,
     Options,       // Audio, display, and control settings
+    Achievements,  // Browse achievements and progress
+    Leaderboards,  // Local top runs per mode and difficulty
     ModuleSelect,  // Choose game module (Elder Fleet, Caldari vs Gallente, etc.)
+    Challenges,    // Daily and weekly challenge runs
     FactionSelect, // Choose faction (for Caldari/Gallente module)
     StageSelect,   // Choose which stage (1-13) to play
     DifficultySelect,
     ShipSelect,
+    Fitting, // Fit modules to the selected ship
     Playing,
-    UpgradeShop,
+    SkillTree, // Train skills with Skill Points
     BossIntro,
     BossFight,
     StageComplete,
@@ -28,9 +34,20 @@ pub enum GameState {
     Paused,
 }
 
+impl GameState {
+    /// Part of a mission in progress. Pausing and boss intros leave and
+    /// re-enter `Playing` without ending the mission.
+    pub fn in_mission(&self) -> bool {
+        matches!(
+            self,
+            GameState::Playing | GameState::Paused | GameState::BossIntro | GameState::BossFight
+        )
+    }
+}
+
 /// Game difficulty settings - EVE-themed
 /// (Wraps DifficultyLevel from resources.rs for backwards compatibility)
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Resource)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Resource, Serialize, Deserialize)]
 pub enum Difficulty {
     /// Carebear - High-sec living, relaxed gameplay
     Carebear,
@@ -305,23 +322,6 @@ impl MinmatarShip {
         }
     }
 
-    /// Whether this ship requires an unlock
-    pub fn requires_unlock(&self) -> bool {
-        matches!(self, MinmatarShip::Wolf | MinmatarShip::Jaguar)
-    }
-
-    /// Which act unlocks this ship (0 = always available)
-    pub fn unlock_act(&self) -> u32 {
-        match self {
-            MinmatarShip::Rifter
-            | MinmatarShip::Slasher
-            | MinmatarShip::Breacher
-            | MinmatarShip::Probe => 0,
-            MinmatarShip::Wolf => 2,
-            MinmatarShip::Jaguar => 3,
-        }
-    }
-
     /// Get all base frigate ships (always available)
     pub fn all() -> &'static [MinmatarShip] {
         &[
@@ -331,81 +331,6 @@ impl MinmatarShip {
             MinmatarShip::Probe,
         ]
     }
-
-    /// Get all ships including unlockables
-    pub fn all_including_unlocks() -> &'static [MinmatarShip] {
-        &[
-            MinmatarShip::Rifter,
-            MinmatarShip::Slasher,
-            MinmatarShip::Breacher,
-            MinmatarShip::Probe,
-            MinmatarShip::Wolf,
-            MinmatarShip::Jaguar,
-        ]
-    }
-}
-
-/// Tracks which ships and acts the player has unlocked
-#[derive(Debug, Clone, Resource)]
-pub struct ShipUnlocks {
-    /// Highest act completed
-    pub highest_act_completed: u32,
-    /// Specific ships that have been unlocked
-    pub unlocked_ships: Vec<MinmatarShip>,
-}
-
-impl Default for ShipUnlocks {
-    fn default() -> Self {
-        Self {
-            highest_act_completed: 0,
-            unlocked_ships: vec![
-                MinmatarShip::Rifter,
-                MinmatarShip::Slasher,
-                MinmatarShip::Breacher,
-                MinmatarShip::Probe,
-            ],
-        }
-    }
-}
-
-impl ShipUnlocks {
-    /// Check if a ship is unlocked
-    pub fn is_unlocked(&self, ship: MinmatarShip) -> bool {
-        if !ship.requires_unlock() {
-            return true;
-        }
-        self.unlocked_ships.contains(&ship) || self.highest_act_completed >= ship.unlock_act()
-    }
-
-    /// Unlock a specific ship
-    pub fn unlock_ship(&mut self, ship: MinmatarShip) {
-        if !self.unlocked_ships.contains(&ship) {
-            self.unlocked_ships.push(ship);
-        }
-    }
-
-    /// Complete an act and unlock associated ships
-    pub fn complete_act(&mut self, act: u32) {
-        if act > self.highest_act_completed {
-            self.highest_act_completed = act;
-
-            // Unlock ships associated with this act
-            match act {
-                2 => self.unlock_ship(MinmatarShip::Wolf),
-                3 => self.unlock_ship(MinmatarShip::Jaguar),
-                _ => {}
-            }
-        }
-    }
-
-    /// Get list of available ships (unlocked only)
-    pub fn available_ships(&self) -> Vec<MinmatarShip> {
-        MinmatarShip::all_including_unlocks()
-            .iter()
-            .filter(|s| self.is_unlocked(**s))
-            .copied()
-            .collect()
-    }
 }
 
 /// Selected ship for current run
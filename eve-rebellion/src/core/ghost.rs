@@ -0,0 +1,183 @@
+//! Ghost Recordings
+//!
+//! The player ship's position and score sampled at a fixed rate through a
+//! mission, plus split times at each wave and boss phase. A ghost replays
+//! only the player, so it can be raced even when the enemies play out
+//! differently. Recordings of a board's best runs are kept next to the
+//! leaderboards and referenced by `LeaderboardEntry::replay`.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::leaderboards::LeaderboardEntry;
+use super::profiles::{load_shared, remove_shared, save_shared};
+
+/// Seconds between samples
+pub const GHOST_SAMPLE_INTERVAL: f32 = 0.1;
+
+/// Entries per board that keep their recording
+pub const GHOSTS_PER_BOARD: usize = 3;
+
+/// One sample: position in whole pixels and the score so far. A tuple
+/// struct, so recordings store as plain arrays.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct GhostFrame(pub i16, pub i16, pub u64);
+
+impl GhostFrame {
+    pub fn new(position: Vec2, score: u64) -> Self {
+        Self(position.x.round() as i16, position.y.round() as i16, score)
+    }
+
+    pub fn position(&self) -> Vec2 {
+        Vec2::new(self.0 as f32, self.1 as f32)
+    }
+
+    pub fn score(&self) -> u64 {
+        self.2
+    }
+}
+
+/// Time and score when a wave or boss phase began
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct GhostSplit {
+    /// "Wave 3", "Boss phase 2"
+    pub label: String,
+    pub time: f32,
+    pub score: u64,
+}
+
+/// A recorded run
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct GhostRecording {
+    pub ship_type_id: u32,
+    /// One every `GHOST_SAMPLE_INTERVAL` seconds from the start
+    pub frames: Vec<GhostFrame>,
+    pub splits: Vec<GhostSplit>,
+}
+
+impl GhostRecording {
+    pub fn new(ship_type_id: u32) -> Self {
+        Self {
+            ship_type_id,
+            ..default()
+        }
+    }
+
+    /// Seconds recorded
+    pub fn duration(&self) -> f32 {
+        self.frames.len().saturating_sub(1) as f32 * GHOST_SAMPLE_INTERVAL
+    }
+
+    /// Time of the next sample due
+    pub fn next_sample_time(&self) -> f32 {
+        self.frames.len() as f32 * GHOST_SAMPLE_INTERVAL
+    }
+
+    pub fn push_split(&mut self, label: impl Into<String>, time: f32, score: u64) {
+        self.splits.push(GhostSplit {
+            label: label.into(),
+            time,
+            score,
+        });
+    }
+
+    pub fn split(&self, label: &str) -> Option<&GhostSplit> {
+        self.splits.iter().find(|s| s.label == label)
+    }
+
+    /// Position at a time, between samples; `None` once the run is over
+    pub fn position_at(&self, time: f32) -> Option<Vec2> {
+        if time < 0.0 || time > self.duration() || self.frames.is_empty() {
+            return None;
+        }
+        let step = time / GHOST_SAMPLE_INTERVAL;
+        let index = (step as usize).min(self.frames.len() - 1);
+        let from = self.frames[index].position();
+        let to = self
+            .frames
+            .get(index + 1)
+            .map_or(from, GhostFrame::position);
+        Some(from.lerp(to, step - index as f32))
+    }
+
+    /// Score at a time; the final score once the run is over
+    pub fn score_at(&self, time: f32) -> u64 {
+        let index = (time.max(0.0) / GHOST_SAMPLE_INTERVAL) as usize;
+        self.frames
+            .get(index)
+            .or(self.frames.last())
+            .map_or(0, GhostFrame::score)
+    }
+
+    /// When the run first reached a score, if it did
+    pub fn time_to_score(&self, score: u64) -> Option<f32> {
+        self.frames
+            .iter()
+            .position(|frame| frame.score() >= score)
+            .map(|index| index as f32 * GHOST_SAMPLE_INTERVAL)
+    }
+
+    pub fn load(id: &str) -> Option<Self> {
+        load_shared(&ghost_key(id))
+    }
+
+    pub fn save(&self, id: &str) {
+        save_shared(&ghost_key(id), self);
+    }
+}
+
+/// Recording id of a leaderboard entry
+pub fn ghost_id(entry: &LeaderboardEntry) -> String {
+    format!("{}-{}-{}", entry.timestamp, entry.ship_type_id, entry.score)
+}
+
+pub fn remove_ghost(id: &str) {
+    remove_shared(&ghost_key(id));
+}
+
+fn ghost_key(id: &str) -> String {
+    format!("ghosts/{}", id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording() -> GhostRecording {
+        let mut recording = GhostRecording::new(587);
+        for i in 0..=10 {
+            recording
+                .frames
+                .push(GhostFrame::new(Vec2::new(i as f32 * 10.0, -250.0), i * 100));
+        }
+        recording.push_split("Wave 2", 0.5, 500);
+        recording
+    }
+
+    #[test]
+    fn playback_interpolates_between_samples() {
+        let ghost = recording();
+        assert!((ghost.duration() - 1.0).abs() < 1e-4);
+        let midway = ghost.position_at(0.25).unwrap();
+        assert!(midway.distance(Vec2::new(25.0, -250.0)) < 1e-3);
+        assert_eq!(ghost.position_at(1.5), None);
+        assert_eq!(ghost.score_at(0.35), 300);
+        assert_eq!(ghost.score_at(9.0), 1000);
+        assert_eq!(ghost.time_to_score(450), Some(0.5));
+        assert_eq!(ghost.time_to_score(2000), None);
+        assert_eq!(ghost.split("Wave 2").map(|s| s.score), Some(500));
+        assert!(ghost.split("Wave 3").is_none());
+    }
+
+    #[test]
+    fn frames_store_as_arrays() {
+        let ghost = recording();
+        let json = serde_json::to_string(&ghost.frames[1]).unwrap();
+        assert_eq!(json, "[10,-250,100]");
+        let back: GhostRecording =
+            serde_json::from_str(&serde_json::to_string(&ghost).unwrap()).unwrap();
+        assert_eq!(back, ghost);
+    }
+}
@@ -0,0 +1,152 @@
+//! Input Log
+//!
+//! Raw player input for every frame of a run: frame time, the gameplay keys
+//! held, gamepad buttons and quantized sticks and triggers. It is small
+//! enough to keep whole. Its SHA-256 goes with leaderboard submissions and
+//! names the stored log, so a log cannot be swapped for another under the
+//! same hash. Nothing replays the log yet.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Keys recorded in `InputFrame::keys`, one bit each in this order
+pub const LOGGED_KEYS: [KeyCode; 28] = [
+    KeyCode::KeyW,
+    KeyCode::KeyA,
+    KeyCode::KeyS,
+    KeyCode::KeyD,
+    KeyCode::ArrowUp,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowRight,
+    KeyCode::KeyI,
+    KeyCode::KeyJ,
+    KeyCode::KeyK,
+    KeyCode::KeyL,
+    KeyCode::Space,
+    KeyCode::ShiftLeft,
+    KeyCode::KeyQ,
+    KeyCode::KeyE,
+    KeyCode::KeyR,
+    KeyCode::KeyF,
+    KeyCode::KeyB,
+    KeyCode::KeyY,
+    KeyCode::KeyZ,
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+];
+
+/// Input during one frame
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InputFrame {
+    /// Frame time in milliseconds
+    pub dt_ms: u16,
+    /// Held keys, bits in `LOGGED_KEYS` order
+    pub keys: u32,
+    /// Held gamepad buttons, bit per button number
+    pub buttons: u16,
+    /// Left x/y then right x/y, scaled to -127..=127
+    pub sticks: [i8; 4],
+    /// Left and right trigger, scaled to 0..=255
+    pub triggers: [u8; 2],
+}
+
+impl InputFrame {
+    /// Canonical bytes the hash is computed over
+    fn to_bytes(self) -> [u8; 14] {
+        let mut bytes = [0; 14];
+        bytes[0..2].copy_from_slice(&self.dt_ms.to_le_bytes());
+        bytes[2..6].copy_from_slice(&self.keys.to_le_bytes());
+        bytes[6..8].copy_from_slice(&self.buttons.to_le_bytes());
+        for (i, axis) in self.sticks.iter().enumerate() {
+            bytes[8 + i] = *axis as u8;
+        }
+        bytes[12..14].copy_from_slice(&self.triggers);
+        bytes
+    }
+}
+
+/// Scale an axis in -1.0..=1.0 to a byte
+pub fn quantize_axis(value: f32) -> i8 {
+    (value.clamp(-1.0, 1.0) * 127.0).round() as i8
+}
+
+/// Scale a trigger in 0.0..=1.0 to a byte
+pub fn quantize_trigger(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Input of the run in progress (or the last one)
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct InputLog {
+    pub frames: Vec<InputFrame>,
+}
+
+impl InputLog {
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    pub fn push(&mut self, frame: InputFrame) {
+        self.frames.push(frame);
+    }
+
+    /// Milliseconds covered by the log
+    pub fn duration_ms(&self) -> u64 {
+        self.frames.iter().map(|f| f.dt_ms as u64).sum()
+    }
+
+    /// SHA-256 of the frames, as 64 hex digits
+    pub fn hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        for frame in &self.frames {
+            hasher.update(frame.to_bytes());
+        }
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_covers_every_field() {
+        let frame = InputFrame {
+            dt_ms: 16,
+            keys: 0b101,
+            buttons: 1,
+            sticks: [quantize_axis(-1.0), 0, quantize_axis(0.5), 0],
+            triggers: [quantize_trigger(1.0), 0],
+        };
+        let mut log = InputLog::default();
+        assert_eq!(
+            log.hash(),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        log.push(frame);
+        log.push(frame);
+        let hash = log.hash();
+        assert_eq!(hash.len(), 64);
+        assert_eq!(log.duration_ms(), 32);
+
+        let mut changed = log.clone();
+        changed.frames[1].triggers[1] = 1;
+        assert_ne!(changed.hash(), hash);
+        assert_eq!(frame.sticks, [-127, 0, 64, 0]);
+
+        let json = serde_json::to_string(&log).unwrap();
+        assert_eq!(
+            serde_json::from_str::<InputLog>(&json).unwrap().hash(),
+            hash
+        );
+    }
+}
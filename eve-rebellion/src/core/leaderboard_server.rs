@@ -0,0 +1,301 @@
+//! Reference Leaderboard Server
+//!
+//! A tiny JSON-over-HTTP server for [`HttpBackend`](super::HttpBackend),
+//! meant for a LAN box: `cargo run --example leaderboard_server`. It speaks
+//! just enough HTTP/1.1 for the game's requests, one thread per connection
+//! up to [`MAX_CONNECTIONS`], and stores everything through a [`FileBackend`] in its data folder, so
+//! entries get the same log-stored mark.
+
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bevy::log::warn;
+
+use super::input_log::InputLog;
+use super::leaderboard_sync::{
+    BackendError, FileBackend, LeaderboardBackend, ScoreSubmission, SubmitResponse,
+};
+
+/// Largest request body accepted (an hour of input at 60 fps is ~10 MB)
+const MAX_BODY: usize = 32 * 1024 * 1024;
+
+/// Connections served at once; more are turned away with a 503
+pub const MAX_CONNECTIONS: usize = 16;
+
+/// How long a client may stall while sending or receiving
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Serve until the listener fails; requests are handled one at a time
+/// against the store, on their own threads
+pub fn serve(listener: TcpListener, data_dir: impl Into<PathBuf>) -> std::io::Result<()> {
+    let store = Arc::new(Mutex::new(FileBackend::new(data_dir)));
+    let open = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let mut stream = stream?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+        if open.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+            open.fetch_sub(1, Ordering::SeqCst);
+            if let Err(e) = respond(&mut stream, 503, "busy") {
+                warn!("Leaderboard server: {}", e);
+            }
+            continue;
+        }
+        let slot = ConnectionSlot(Arc::clone(&open));
+        let store = Arc::clone(&store);
+        std::thread::spawn(move || {
+            let _slot = slot;
+            if let Err(e) = handle_connection(stream, &store) {
+                warn!("Leaderboard server: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Frees a connection slot when its thread ends, even on a panic
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+fn read_request(stream: &TcpStream) -> std::io::Result<Option<Request>> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Ok(None);
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+    if content_length > MAX_BODY {
+        return Ok(None);
+    }
+    // Grows with what actually arrives rather than what the header claims
+    let mut body = Vec::new();
+    reader.take(content_length as u64).read_to_end(&mut body)?;
+    if body.len() < content_length {
+        return Ok(None);
+    }
+    Ok(Some(Request { method, path, body }))
+}
+
+fn handle_connection(mut stream: TcpStream, store: &Mutex<FileBackend>) -> std::io::Result<()> {
+    let (status, body) = match read_request(&stream)? {
+        Some(request) => route(&request, store),
+        None => (400, "bad request".to_string()),
+    };
+    respond(&mut stream, status, &body)
+}
+
+fn respond(stream: &mut TcpStream, status: u16, body: &str) -> std::io::Result<()> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+/// Status and JSON body for a request
+fn route(request: &Request, store: &Mutex<FileBackend>) -> (u16, String) {
+    let store = store.lock().unwrap_or_else(|e| e.into_inner());
+    let result = match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/boards") => store.fetch().and_then(|boards| to_json(&boards)),
+        ("POST", "/submit") => from_json::<ScoreSubmission>(&request.body)
+            .and_then(|submission| store.submit(&submission))
+            .and_then(|rank| to_json(&SubmitResponse { rank })),
+        ("PUT", path) if path.starts_with("/inputs/") => from_json::<InputLog>(&request.body)
+            .and_then(|log| {
+                if log.hash() == path["/inputs/".len()..] {
+                    store.upload_input_log(&log).map(|_| "{}".to_string())
+                } else {
+                    Err(BackendError::Format("hash does not match".to_string()))
+                }
+            }),
+        ("GET", path) if path.starts_with("/inputs/") => {
+            match store.fetch_input_log(&path["/inputs/".len()..]) {
+                Ok(Some(log)) => to_json(&log),
+                Ok(None) => return (404, "{}".to_string()),
+                Err(e) => Err(e),
+            }
+        }
+        _ => return (404, "{}".to_string()),
+    };
+    match result {
+        Ok(body) => (200, body),
+        Err(BackendError::Format(message)) => (400, message),
+        Err(e) => (500, e.to_string()),
+    }
+}
+
+fn from_json<T: for<'de> serde::Deserialize<'de>>(body: &[u8]) -> Result<T, BackendError> {
+    serde_json::from_slice(body).map_err(|e| BackendError::Format(e.to_string()))
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, BackendError> {
+    serde_json::to_string(value).map_err(|e| BackendError::Format(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::input_log::InputFrame;
+    use crate::core::leaderboard_sync::{HttpBackend, RunSettings};
+    use crate::core::{Difficulty, FireMode, LeaderboardEntry, LeaderboardMode};
+
+    #[test]
+    fn http_backend_against_local_server() {
+        let dir = std::env::temp_dir().join(format!(
+            "eve_rebellion_server_{}_{}",
+            std::process::id(),
+            fastrand::u32(..)
+        ));
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let data_dir = dir.clone();
+        std::thread::spawn(move || serve(listener, data_dir));
+
+        let backend = HttpBackend::new(&format!("http://{}/", address));
+        let log = InputLog {
+            frames: vec![
+                InputFrame {
+                    dt_ms: 250,
+                    ..Default::default()
+                };
+                40
+            ],
+        };
+        let submission = ScoreSubmission {
+            mode: LeaderboardMode::Sector,
+            entry: LeaderboardEntry {
+                pilot: "Ana".to_string(),
+                score: 4200,
+                time: 10.0,
+                seed: Some(99),
+                ..Default::default()
+            },
+            settings: RunSettings {
+                difficulty: Difficulty::BitterVet,
+                game_speed: 1.0,
+                fire_mode: FireMode::Hold,
+                version: "test".to_string(),
+            },
+            input_hash: log.hash(),
+        };
+
+        assert_eq!(backend.fetch_input_log(&log.hash()).unwrap(), None);
+        backend.upload_input_log(&log).unwrap();
+        assert_eq!(backend.submit(&submission).unwrap(), Some(0));
+        assert_eq!(
+            backend.fetch_input_log(&log.hash()).unwrap(),
+            Some(log.clone())
+        );
+
+        let boards = backend.fetch().unwrap();
+        let entry = &boards
+            .board(&LeaderboardMode::Sector, Difficulty::BitterVet)
+            .unwrap()
+            .entries[0];
+        assert_eq!(entry.score, 4200);
+        assert_eq!(entry.seed, Some(99));
+        assert!(entry.has_input_log);
+
+        // Logs under the wrong hash and malformed bodies are refused
+        let client = reqwest::blocking::Client::new();
+        let mismatched = client
+            .put(format!("http://{}/inputs/0123456789abcdef", address))
+            .json(&log)
+            .send()
+            .unwrap();
+        assert_eq!(mismatched.status().as_u16(), 400);
+        let bad = client
+            .post(format!("http://{}/submit", address))
+            .body("not json")
+            .send()
+            .unwrap();
+        assert_eq!(bad.status().as_u16(), 400);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn short_bodies_and_extra_connections_are_refused() {
+        let dir = std::env::temp_dir().join(format!(
+            "eve_rebellion_server_{}_{}",
+            std::process::id(),
+            fastrand::u32(..)
+        ));
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let data_dir = dir.clone();
+        std::thread::spawn(move || serve(listener, data_dir));
+
+        let status = |stream: &mut TcpStream| {
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response.split_whitespace().nth(1).unwrap_or("").to_string()
+        };
+
+        // A body shorter than its Content-Length
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "POST /submit HTTP/1.1\r\nContent-Length: {}\r\n\r\n{{}}",
+            MAX_BODY
+        )
+        .unwrap();
+        stream.shutdown(std::net::Shutdown::Write).unwrap();
+        assert_eq!(status(&mut stream), "400");
+
+        // Idle clients hold every slot, so the next one is turned away
+        let idle: Vec<_> = (0..MAX_CONNECTIONS)
+            .map(|_| TcpStream::connect(address).unwrap())
+            .collect();
+        let mut extra = TcpStream::connect(address).unwrap();
+        assert_eq!(status(&mut extra), "503");
+        drop(idle);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,394 @@
+//! Leaderboard Sync
+//!
+//! Score submission and retrieval behind [`LeaderboardBackend`], so a group
+//! can share boards: [`FileBackend`] keeps them in a JSON file (a shared
+//! folder works), [`HttpBackend`] talks to a server such as the reference
+//! one in `leaderboard_server`. A submission carries the run seed, the
+//! settings that affect play and the hash of the run's input log; the log
+//! itself is uploaded next to it so anyone can fetch it and look into the
+//! run. Backends only check that a log matching the run is stored; nothing
+//! re-simulates it.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+use super::accessibility::FireMode;
+use super::game_state::Difficulty;
+use super::input_log::InputLog;
+use super::leaderboards::{LeaderboardEntry, LeaderboardMode, Leaderboards};
+
+/// Slack between the logged input time and the run time, in milliseconds
+#[cfg(not(target_arch = "wasm32"))]
+const DURATION_TOLERANCE_MS: u64 = 2_000;
+
+/// Settings a run was played with
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RunSettings {
+    pub difficulty: Difficulty,
+    pub game_speed: f32,
+    pub fire_mode: FireMode,
+    /// Game version that played the run
+    pub version: String,
+}
+
+/// A run sent to a backend
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ScoreSubmission {
+    pub mode: LeaderboardMode,
+    pub entry: LeaderboardEntry,
+    pub settings: RunSettings,
+    /// `InputLog::hash` of the run
+    pub input_hash: String,
+}
+
+/// Whether an input log belongs to a submission: same hash, and it covers
+/// the run's time (game speed stretches play time against frame time)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn log_matches_submission(submission: &ScoreSubmission, log: &InputLog) -> bool {
+    if log.hash() != submission.input_hash {
+        return false;
+    }
+    let expected =
+        (submission.entry.time * 1000.0 / submission.settings.game_speed.max(0.1)) as u64;
+    log.duration_ms().abs_diff(expected) <= DURATION_TOLERANCE_MS
+}
+
+/// Why a backend call failed (only the native backends do)
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+pub enum BackendError {
+    /// Could not reach or read the store
+    Io(String),
+    /// The other side answered with an error status
+    Rejected(u16, String),
+    /// The data did not parse
+    Format(String),
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendError::Io(message) => write!(f, "I/O error: {}", message),
+            BackendError::Rejected(status, message) => {
+                write!(f, "rejected ({}): {}", status, message)
+            }
+            BackendError::Format(message) => write!(f, "bad data: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
+
+/// Where shared leaderboards live
+pub trait LeaderboardBackend: Send + Sync {
+    /// Shown in logs and on the leaderboard screen
+    fn describe(&self) -> String;
+
+    /// Add a run; returns its rank on its board if it made the cut
+    fn submit(&self, submission: &ScoreSubmission) -> Result<Option<usize>, BackendError>;
+
+    /// Every board
+    fn fetch(&self) -> Result<Leaderboards, BackendError>;
+
+    /// Store the input log of a submitted run, keyed by its hash
+    fn upload_input_log(&self, log: &InputLog) -> Result<(), BackendError>;
+
+    /// Input log by hash, if the backend has it
+    fn fetch_input_log(&self, hash: &str) -> Result<Option<InputLog>, BackendError>;
+}
+
+/// Input log hashes are SHA-256 hex; anything else is not a valid key
+#[cfg(not(target_arch = "wasm32"))]
+fn valid_hash(hash: &str) -> bool {
+    hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+// ============================================================================
+// File backend
+// ============================================================================
+
+/// Boards in `<dir>/leaderboards.json`, input logs in `<dir>/inputs/<hash>.json`
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileBackend {
+    pub dir: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileBackend {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn boards_path(&self) -> PathBuf {
+        self.dir.join("leaderboards.json")
+    }
+
+    fn input_path(&self, hash: &str) -> PathBuf {
+        self.dir.join("inputs").join(format!("{}.json", hash))
+    }
+
+    fn read<T: for<'de> Deserialize<'de>>(path: &PathBuf) -> Result<Option<T>, BackendError> {
+        match std::fs::read_to_string(path) {
+            Ok(data) => serde_json::from_str(&data)
+                .map(Some)
+                .map_err(|e| BackendError::Format(e.to_string())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(BackendError::Io(e.to_string())),
+        }
+    }
+
+    fn write<T: Serialize>(path: &PathBuf, value: &T) -> Result<(), BackendError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| BackendError::Io(e.to_string()))?;
+        }
+        let data =
+            serde_json::to_string_pretty(value).map_err(|e| BackendError::Format(e.to_string()))?;
+        std::fs::write(path, data).map_err(|e| BackendError::Io(e.to_string()))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl LeaderboardBackend for FileBackend {
+    fn describe(&self) -> String {
+        self.dir.display().to_string()
+    }
+
+    /// Notes on the entry whether a matching input log is already here
+    fn submit(&self, submission: &ScoreSubmission) -> Result<Option<usize>, BackendError> {
+        let mut entry = submission.entry.clone();
+        entry.input_hash = Some(submission.input_hash.clone());
+        entry.has_input_log = self
+            .fetch_input_log(&submission.input_hash)?
+            .is_some_and(|log| log_matches_submission(submission, &log));
+        let mut boards = self.fetch()?;
+        let rank = boards.submit(
+            submission.mode.clone(),
+            submission.settings.difficulty,
+            entry,
+        );
+        if rank.is_some() {
+            Self::write(&self.boards_path(), &boards)?;
+        }
+        Ok(rank)
+    }
+
+    fn fetch(&self) -> Result<Leaderboards, BackendError> {
+        Ok(Self::read(&self.boards_path())?.unwrap_or_default())
+    }
+
+    fn upload_input_log(&self, log: &InputLog) -> Result<(), BackendError> {
+        Self::write(&self.input_path(&log.hash()), log)
+    }
+
+    fn fetch_input_log(&self, hash: &str) -> Result<Option<InputLog>, BackendError> {
+        if !valid_hash(hash) {
+            return Ok(None);
+        }
+        Self::read(&self.input_path(hash))
+    }
+}
+
+// ============================================================================
+// HTTP backend
+// ============================================================================
+
+/// JSON over HTTP:
+/// `POST /submit`, `GET /boards`, `PUT /inputs/<hash>`, `GET /inputs/<hash>`
+#[cfg(not(target_arch = "wasm32"))]
+pub struct HttpBackend {
+    pub base_url: String,
+    client: reqwest::blocking::Client,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl HttpBackend {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            client: reqwest::blocking::Client::builder()
+                .timeout(std::time::Duration::from_secs(10))
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Send a request; error statuses become `Rejected` except 404, which
+    /// the caller decides about
+    fn send(
+        request: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response, BackendError> {
+        let response = request
+            .send()
+            .map_err(|e| BackendError::Io(e.to_string()))?;
+        let status = response.status();
+        if status.is_success() || status == reqwest::StatusCode::NOT_FOUND {
+            Ok(response)
+        } else {
+            let message = response.text().unwrap_or_default();
+            Err(BackendError::Rejected(status.as_u16(), message))
+        }
+    }
+
+    fn json<T: for<'de> Deserialize<'de>>(
+        response: reqwest::blocking::Response,
+    ) -> Result<T, BackendError> {
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(BackendError::Rejected(404, "not found".to_string()));
+        }
+        response
+            .json()
+            .map_err(|e| BackendError::Format(e.to_string()))
+    }
+}
+
+/// Answer to `POST /submit`
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SubmitResponse {
+    pub rank: Option<usize>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl LeaderboardBackend for HttpBackend {
+    fn describe(&self) -> String {
+        self.base_url.clone()
+    }
+
+    fn submit(&self, submission: &ScoreSubmission) -> Result<Option<usize>, BackendError> {
+        let response = Self::send(self.client.post(self.url("/submit")).json(submission))?;
+        Ok(Self::json::<SubmitResponse>(response)?.rank)
+    }
+
+    fn fetch(&self) -> Result<Leaderboards, BackendError> {
+        Self::json(Self::send(self.client.get(self.url("/boards")))?)
+    }
+
+    fn upload_input_log(&self, log: &InputLog) -> Result<(), BackendError> {
+        let path = format!("/inputs/{}", log.hash());
+        let response = Self::send(self.client.put(self.url(&path)).json(log))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(BackendError::Rejected(404, "not found".to_string()));
+        }
+        Ok(())
+    }
+
+    fn fetch_input_log(&self, hash: &str) -> Result<Option<InputLog>, BackendError> {
+        if !valid_hash(hash) {
+            return Ok(None);
+        }
+        let response = Self::send(self.client.get(self.url(&format!("/inputs/{}", hash))))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Self::json(response).map(Some)
+    }
+}
+
+/// Backend for a configured location: `http(s)://` URLs use HTTP, anything
+/// else is a folder for the file backend; blank means no sharing
+#[cfg(not(target_arch = "wasm32"))]
+pub fn backend_for(location: &str) -> Option<Box<dyn LeaderboardBackend>> {
+    let location = location.trim();
+    if location.is_empty() {
+        None
+    } else if location.starts_with("http://") || location.starts_with("https://") {
+        Some(Box::new(HttpBackend::new(location)))
+    } else {
+        Some(Box::new(FileBackend::new(location)))
+    }
+}
+
+/// No threads or file system to share through on the web
+#[cfg(target_arch = "wasm32")]
+pub fn backend_for(_location: &str) -> Option<Box<dyn LeaderboardBackend>> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::input_log::InputFrame;
+
+    fn sample_run(score: u64) -> (ScoreSubmission, InputLog) {
+        let log = InputLog {
+            frames: vec![
+                InputFrame {
+                    dt_ms: 500,
+                    keys: 1,
+                    ..Default::default()
+                };
+                20
+            ],
+        };
+        let submission = ScoreSubmission {
+            mode: LeaderboardMode::Endless,
+            entry: LeaderboardEntry {
+                pilot: "Ana".to_string(),
+                score,
+                time: 10.0,
+                ..Default::default()
+            },
+            settings: RunSettings {
+                difficulty: Difficulty::Newbro,
+                game_speed: 1.0,
+                fire_mode: FireMode::Hold,
+                version: "test".to_string(),
+            },
+            input_hash: log.hash(),
+        };
+        (submission, log)
+    }
+
+    #[test]
+    fn log_must_match_hash_and_run_time() {
+        let (submission, log) = sample_run(100);
+        assert!(log_matches_submission(&submission, &log));
+
+        let mut short = log.clone();
+        short.frames.truncate(10);
+        assert!(!log_matches_submission(&submission, &short));
+
+        let mut slow = submission.clone();
+        slow.settings.game_speed = 0.5;
+        assert!(!log_matches_submission(&slow, &log));
+    }
+
+    #[test]
+    fn file_backend_round_trip() {
+        let dir = std::env::temp_dir().join(format!(
+            "eve_rebellion_boards_{}_{}",
+            std::process::id(),
+            fastrand::u32(..)
+        ));
+        let backend = FileBackend::new(&dir);
+        let (submission, log) = sample_run(500);
+
+        assert_eq!(backend.fetch().unwrap().boards.len(), 0);
+        assert_eq!(backend.submit(&submission).unwrap(), Some(0));
+        backend.upload_input_log(&log).unwrap();
+        assert_eq!(backend.submit(&submission).unwrap(), Some(1));
+
+        let boards = backend.fetch().unwrap();
+        let entries = &boards
+            .board(&LeaderboardMode::Endless, Difficulty::Newbro)
+            .unwrap()
+            .entries;
+        assert_eq!(entries.len(), 2);
+        assert!(!entries[0].has_input_log);
+        assert!(entries[1].has_input_log);
+        assert_eq!(entries[1].input_hash, Some(log.hash()));
+        assert_eq!(
+            backend.fetch_input_log(&log.hash()).unwrap(),
+            Some(log.clone())
+        );
+        assert_eq!(backend.fetch_input_log("../escape").unwrap(), None);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,372 @@
+//! Local Leaderboards
+//!
+//! Top runs per mode and difficulty, shared by every pilot on this machine
+//! and stored under `leaderboards` next to the profiles. A mode is a single
+//! campaign mission or one of the survival and arcade modes; each board keeps
+//! the best [`LEADERBOARD_SIZE`] entries. Entries remember who flew what, so
+//! the leaderboard screen can filter by ship and seed as well.
+
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use super::game_state::Difficulty;
+use super::profiles::{load_shared, save_shared};
+
+/// Entries kept per board
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// Storage key of the leaderboards
+const LEADERBOARDS_KEY: &str = "leaderboards";
+
+/// What a board ranks
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LeaderboardMode {
+    /// One campaign mission of a module
+    Mission {
+        module: String,
+        mission: String,
+    },
+    Endless,
+    Abyssal,
+    LastStand,
+    Nightmare,
+    /// Roguelite sector encounters
+    Sector,
+    /// One daily or weekly challenge (`Challenge::title`)
+    Challenge {
+        title: String,
+    },
+}
+
+impl LeaderboardMode {
+    pub fn name(&self) -> String {
+        match self {
+            LeaderboardMode::Mission { mission, .. } => mission.clone(),
+            LeaderboardMode::Endless => "Endless".to_string(),
+            LeaderboardMode::Abyssal => "Abyssal Depths".to_string(),
+            LeaderboardMode::LastStand => "Last Stand".to_string(),
+            LeaderboardMode::Nightmare => "Shiigeru Nightmare".to_string(),
+            LeaderboardMode::Sector => "Sector Runs".to_string(),
+            LeaderboardMode::Challenge { title } => title.clone(),
+        }
+    }
+
+    /// Survival modes always end in death, so every run is ranked;
+    /// other modes only rank completed runs
+    pub fn survival(&self) -> bool {
+        matches!(
+            self,
+            LeaderboardMode::Endless
+                | LeaderboardMode::Nightmare
+                | LeaderboardMode::Challenge { .. }
+        )
+    }
+}
+
+/// One ranked run
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct LeaderboardEntry {
+    pub pilot: String,
+    pub ship_type_id: u32,
+    pub ship: String,
+    pub score: u64,
+    /// Seconds flown
+    pub time: f32,
+    /// Wave reached (survival modes)
+    #[serde(default)]
+    pub wave: u32,
+    /// Style grade of the run's peak multiplier
+    pub grade: String,
+    /// Unix seconds when the run ended
+    pub timestamp: u64,
+    /// Seed of seeded runs
+    #[serde(default)]
+    pub seed: Option<u32>,
+    /// Recording of the run, if one was kept
+    #[serde(default)]
+    pub replay: Option<String>,
+    /// `InputLog::hash` of the run, set by a shared backend
+    #[serde(default)]
+    pub input_hash: Option<String>,
+    /// Log stored: a shared backend holds an input log under the hash that
+    /// covers the run time. The run has not been re-simulated
+    #[serde(default, alias = "verified")]
+    pub has_input_log: bool,
+}
+
+/// Higher score first, then deeper wave, then faster, then older
+pub fn rank_order(a: &LeaderboardEntry, b: &LeaderboardEntry) -> Ordering {
+    b.score
+        .cmp(&a.score)
+        .then(b.wave.cmp(&a.wave))
+        .then(a.time.total_cmp(&b.time))
+        .then(a.timestamp.cmp(&b.timestamp))
+}
+
+/// Top entries of one mode at one difficulty, best first
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Leaderboard {
+    pub mode: LeaderboardMode,
+    pub difficulty: Difficulty,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+/// Which entries the leaderboard screen shows; `None` matches anything
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LeaderboardFilter {
+    pub mode: Option<LeaderboardMode>,
+    pub difficulty: Option<Difficulty>,
+    pub ship_type_id: Option<u32>,
+    pub seed: Option<u32>,
+}
+
+/// Every board
+#[derive(bevy::prelude::Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Leaderboards {
+    pub boards: Vec<Leaderboard>,
+}
+
+impl Leaderboards {
+    pub fn load() -> Self {
+        load_shared(LEADERBOARDS_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        save_shared(LEADERBOARDS_KEY, self);
+    }
+
+    pub fn board(&self, mode: &LeaderboardMode, difficulty: Difficulty) -> Option<&Leaderboard> {
+        self.boards
+            .iter()
+            .find(|b| &b.mode == mode && b.difficulty == difficulty)
+    }
+
+    /// Add a run to its board; returns its rank (0 = best) if it made the cut
+    pub fn submit(
+        &mut self,
+        mode: LeaderboardMode,
+        difficulty: Difficulty,
+        entry: LeaderboardEntry,
+    ) -> Option<usize> {
+        let index = match self
+            .boards
+            .iter()
+            .position(|b| b.mode == mode && b.difficulty == difficulty)
+        {
+            Some(index) => index,
+            None => {
+                self.boards.push(Leaderboard {
+                    mode,
+                    difficulty,
+                    entries: Vec::new(),
+                });
+                self.boards.len() - 1
+            }
+        };
+        let entries = &mut self.boards[index].entries;
+        let rank = entries
+            .iter()
+            .position(|e| rank_order(&entry, e) == Ordering::Less)
+            .unwrap_or(entries.len());
+        if rank >= LEADERBOARD_SIZE {
+            return None;
+        }
+        entries.insert(rank, entry);
+        entries.truncate(LEADERBOARD_SIZE);
+        Some(rank)
+    }
+
+    /// Modes with at least one entry, in a stable order
+    pub fn modes(&self) -> Vec<LeaderboardMode> {
+        let mut modes: Vec<_> = self.boards.iter().map(|b| b.mode.clone()).collect();
+        modes.sort();
+        modes.dedup();
+        modes
+    }
+
+    /// Matching entries across boards, best first, with their difficulty
+    pub fn query(&self, filter: &LeaderboardFilter) -> Vec<(Difficulty, &LeaderboardEntry)> {
+        let mut entries: Vec<_> = self
+            .boards
+            .iter()
+            .filter(|b| filter.mode.as_ref().is_none_or(|mode| &b.mode == mode))
+            .filter(|b| filter.difficulty.is_none_or(|d| b.difficulty == d))
+            .flat_map(|b| b.entries.iter().map(move |e| (b.difficulty, e)))
+            .filter(|(_, e)| filter.ship_type_id.is_none_or(|id| e.ship_type_id == id))
+            .filter(|(_, e)| filter.seed.is_none_or(|seed| e.seed == Some(seed)))
+            .collect();
+        entries.sort_by(|a, b| rank_order(a.1, b.1));
+        entries.truncate(LEADERBOARD_SIZE);
+        entries
+    }
+
+    /// Drop replays below the top `keep` of each board; returns their ids
+    pub fn prune_replays(&mut self, keep: usize) -> Vec<String> {
+        self.boards
+            .iter_mut()
+            .flat_map(|b| b.entries.iter_mut().skip(keep))
+            .filter_map(|e| e.replay.take())
+            .collect()
+    }
+
+    /// Deepest wave and longest time on a mode's boards
+    pub fn best_survival(&self, mode: &LeaderboardMode) -> (u32, f32) {
+        self.boards
+            .iter()
+            .filter(|b| &b.mode == mode)
+            .flat_map(|b| b.entries.iter())
+            .fold((0, 0.0), |(wave, time), e| {
+                (wave.max(e.wave), f32::max(time, e.time))
+            })
+    }
+}
+
+/// `YYYY-MM-DD` (UTC) for a Unix timestamp
+pub fn format_date(timestamp: u64) -> String {
+    // Days to civil date (Howard Hinnant's algorithm)
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(score: u64, ship_type_id: u32, seed: Option<u32>) -> LeaderboardEntry {
+        LeaderboardEntry {
+            score,
+            ship_type_id,
+            seed,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn submit_keeps_best_entries_in_order() {
+        let mut boards = Leaderboards::default();
+        for score in 1..=LEADERBOARD_SIZE as u64 {
+            boards.submit(
+                LeaderboardMode::Endless,
+                Difficulty::Newbro,
+                entry(score * 100, 1, None),
+            );
+        }
+        assert_eq!(
+            boards.submit(
+                LeaderboardMode::Endless,
+                Difficulty::Newbro,
+                entry(50, 1, None)
+            ),
+            None
+        );
+        assert_eq!(
+            boards.submit(
+                LeaderboardMode::Endless,
+                Difficulty::Newbro,
+                entry(550, 1, None)
+            ),
+            Some(5)
+        );
+        let board = boards
+            .board(&LeaderboardMode::Endless, Difficulty::Newbro)
+            .unwrap();
+        assert_eq!(board.entries.len(), LEADERBOARD_SIZE);
+        assert_eq!(board.entries[0].score, 1000);
+        assert_eq!(board.entries.last().unwrap().score, 200);
+
+        // Same score: the faster run ranks higher
+        let mut fast = entry(1000, 1, None);
+        fast.time = -1.0;
+        assert_eq!(
+            boards.submit(LeaderboardMode::Endless, Difficulty::Newbro, fast),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn query_filters_across_boards() {
+        let mut boards = Leaderboards::default();
+        let mission = LeaderboardMode::Mission {
+            module: "elder_fleet".to_string(),
+            mission: "Breakout".to_string(),
+        };
+        boards.submit(mission.clone(), Difficulty::Newbro, entry(300, 1, None));
+        boards.submit(mission.clone(), Difficulty::BitterVet, entry(500, 2, None));
+        boards.submit(
+            LeaderboardMode::Sector,
+            Difficulty::Newbro,
+            entry(900, 1, Some(7)),
+        );
+
+        let all = boards.query(&LeaderboardFilter::default());
+        assert_eq!(
+            all.iter().map(|(_, e)| e.score).collect::<Vec<_>>(),
+            [900, 500, 300]
+        );
+
+        let missions = boards.query(&LeaderboardFilter {
+            mode: Some(mission.clone()),
+            ..Default::default()
+        });
+        assert_eq!(missions.len(), 2);
+        assert_eq!(missions[0].0, Difficulty::BitterVet);
+
+        let ship = boards.query(&LeaderboardFilter {
+            ship_type_id: Some(1),
+            difficulty: Some(Difficulty::Newbro),
+            ..Default::default()
+        });
+        assert_eq!(ship.len(), 2);
+
+        let seeded = boards.query(&LeaderboardFilter {
+            seed: Some(7),
+            ..Default::default()
+        });
+        assert_eq!(seeded.len(), 1);
+        assert_eq!(boards.modes(), vec![mission, LeaderboardMode::Sector]);
+    }
+
+    #[test]
+    fn replays_are_kept_for_the_top_entries() {
+        let mut boards = Leaderboards::default();
+        for score in 1..=4 {
+            let mut entry = entry(score * 100, 1, None);
+            entry.replay = Some(format!("run{}", score));
+            boards.submit(LeaderboardMode::Endless, Difficulty::Newbro, entry);
+        }
+        assert_eq!(boards.prune_replays(3), ["run1"]);
+        assert!(boards.prune_replays(3).is_empty());
+        let board = boards
+            .board(&LeaderboardMode::Endless, Difficulty::Newbro)
+            .unwrap();
+        assert_eq!(board.entries[0].replay.as_deref(), Some("run4"));
+        assert!(board.entries[3].replay.is_none());
+    }
+
+    #[test]
+    fn dates_format_as_utc_days() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_792_368_000), "2026-10-19");
+    }
+
+    #[test]
+    fn old_verified_flag_reads_as_has_input_log() {
+        let json = r#"{"pilot":"Ana","ship_type_id":1,"ship":"Rifter","score":10,
+            "time":5.0,"grade":"C","timestamp":0,"verified":true}"#;
+        let entry: LeaderboardEntry = serde_json::from_str(json).unwrap();
+        assert!(entry.has_input_log);
+        assert_eq!(entry.input_hash, None);
+    }
+}
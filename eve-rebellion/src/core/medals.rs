@@ -0,0 +1,243 @@
+//! Style Medals
+//!
+//! Kills and dodges done with style earn a medal: it pops up where it was
+//! earned, adds its style points to `ScoreSystem::style_points`, pays a
+//! score bonus through the scoring rules and is counted in the run stats.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::entities::Formation;
+
+/// Kills this close to the player are point-blank
+pub const POINT_BLANK_RANGE: f32 = 80.0;
+
+/// Seconds in which `MULTI_KILL_COUNT` kills make a multi-kill
+pub const MULTI_KILL_WINDOW: f32 = 0.5;
+pub const MULTI_KILL_COUNT: usize = 3;
+
+/// Distance beyond the player hitbox an enemy bullet grazes
+pub const GRAZE_MARGIN: f32 = 20.0;
+
+/// A style the player was rewarded for
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Medal {
+    /// Kill within `POINT_BLANK_RANGE`
+    PointBlank,
+    /// `MULTI_KILL_COUNT` kills within `MULTI_KILL_WINDOW`
+    MultiKill,
+    /// Kill during barrel roll i-frames
+    RollKill,
+    /// Kill with a seeking shot that turned onto its target
+    Redirect,
+    /// Last member of a formation, with every member shot down
+    FormationBreaker,
+    /// Every shot of a wave hit
+    NoMissWave,
+    /// Enemy bullet passed inside the graze margin without hitting
+    Graze,
+}
+
+impl Medal {
+    pub const ALL: [Medal; 7] = [
+        Medal::PointBlank,
+        Medal::MultiKill,
+        Medal::RollKill,
+        Medal::Redirect,
+        Medal::FormationBreaker,
+        Medal::NoMissWave,
+        Medal::Graze,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Medal::PointBlank => "POINT BLANK",
+            Medal::MultiKill => "MULTI KILL",
+            Medal::RollKill => "ROLL KILL",
+            Medal::Redirect => "REDIRECT",
+            Medal::FormationBreaker => "FORMATION BREAKER",
+            Medal::NoMissWave => "NO MISS WAVE",
+            Medal::Graze => "GRAZE",
+        }
+    }
+
+    pub fn style_points(&self) -> u32 {
+        match self {
+            Medal::Graze => 1,
+            Medal::PointBlank | Medal::Redirect => 5,
+            Medal::RollKill => 8,
+            Medal::MultiKill | Medal::FormationBreaker => 10,
+            Medal::NoMissWave => 25,
+        }
+    }
+
+    /// Base score bonus, before the scoring rules. Grazes are paid by the
+    /// graze system instead.
+    pub fn bonus(&self) -> u64 {
+        match self {
+            Medal::Graze => 0,
+            _ => self.style_points() as u64 * 50,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Medal::PointBlank => Color::srgb(1.0, 0.4, 0.2),
+            Medal::MultiKill => Color::srgb(1.0, 0.85, 0.2),
+            Medal::RollKill => Color::srgb(0.4, 0.9, 1.0),
+            Medal::Redirect => Color::srgb(0.7, 0.5, 1.0),
+            Medal::FormationBreaker => Color::srgb(0.4, 1.0, 0.5),
+            Medal::NoMissWave => Color::srgb(1.0, 1.0, 1.0),
+            Medal::Graze => Color::srgb(0.8, 0.8, 0.9),
+        }
+    }
+}
+
+/// A medal was earned
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct MedalEvent {
+    pub medal: Medal,
+    pub position: Vec2,
+}
+
+/// How a player shot killed an enemy
+#[derive(Event, Debug, Clone)]
+pub struct KillShotEvent {
+    pub position: Vec2,
+    /// Distance from the player
+    pub distance: f32,
+    /// The shot was a seeking projectile
+    pub seeking: bool,
+    pub formation: Option<Formation>,
+}
+
+/// An enemy bullet passed the player inside the graze margin
+#[derive(Event, Debug, Clone)]
+pub struct GrazeEvent {
+    pub position: Vec2,
+}
+
+/// Kill history the medals are judged on, for the mission in progress
+#[derive(Resource, Debug, Default)]
+pub struct StyleTracker {
+    /// Times of kills inside the multi-kill window
+    recent_kills: Vec<f32>,
+    /// Kills so far per formation leader
+    formation_kills: HashMap<Entity, u32>,
+    /// Wave being flown, and shots and hits when it began
+    wave: u32,
+    wave_shots: u32,
+    wave_hits: u32,
+}
+
+impl StyleTracker {
+    /// Forget the previous mission
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Medals earned by a kill at `time`
+    pub fn judge_kill(&mut self, kill: &KillShotEvent, time: f32, rolling: bool) -> Vec<Medal> {
+        let mut medals = Vec::new();
+        if kill.distance <= POINT_BLANK_RANGE {
+            medals.push(Medal::PointBlank);
+        }
+        if rolling {
+            medals.push(Medal::RollKill);
+        }
+        if kill.seeking {
+            medals.push(Medal::Redirect);
+        }
+
+        self.recent_kills
+            .retain(|&at| time - at <= MULTI_KILL_WINDOW);
+        self.recent_kills.push(time);
+        if self.recent_kills.len() == MULTI_KILL_COUNT {
+            medals.push(Medal::MultiKill);
+        }
+
+        if let Some(formation) = kill.formation {
+            let kills = self.formation_kills.entry(formation.leader).or_default();
+            *kills += 1;
+            if *kills >= formation.size {
+                self.formation_kills.remove(&formation.leader);
+                medals.push(Medal::FormationBreaker);
+            }
+        }
+        medals
+    }
+
+    /// True when `wave` is a new wave and every shot of the last one hit
+    pub fn judge_wave(&mut self, wave: u32, shots_fired: u32, shots_hit: u32) -> bool {
+        if wave == self.wave {
+            return false;
+        }
+        self.wave = wave;
+        let shots = shots_fired.saturating_sub(self.wave_shots);
+        let hits = shots_hit.saturating_sub(self.wave_hits);
+        self.wave_shots = shots_fired;
+        self.wave_hits = shots_hit;
+        shots > 0 && hits >= shots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kill(distance: f32) -> KillShotEvent {
+        KillShotEvent {
+            position: Vec2::ZERO,
+            distance,
+            seeking: false,
+            formation: None,
+        }
+    }
+
+    #[test]
+    fn kills_earn_style_medals() {
+        let mut tracker = StyleTracker::default();
+        assert_eq!(tracker.judge_kill(&kill(300.0), 0.0, false), vec![]);
+        assert_eq!(
+            tracker.judge_kill(&kill(40.0), 0.2, true),
+            vec![Medal::PointBlank, Medal::RollKill]
+        );
+        // Third kill inside the window; a fourth doesn't pay again
+        assert_eq!(
+            tracker.judge_kill(&kill(300.0), 0.4, false),
+            vec![Medal::MultiKill]
+        );
+        assert_eq!(tracker.judge_kill(&kill(300.0), 0.45, false), vec![]);
+        // Window has passed
+        assert_eq!(tracker.judge_kill(&kill(300.0), 2.0, false), vec![]);
+
+        let leader = Entity::from_raw(7);
+        let member = KillShotEvent {
+            seeking: true,
+            formation: Some(Formation { leader, size: 2 }),
+            ..kill(300.0)
+        };
+        assert_eq!(
+            tracker.judge_kill(&member, 5.0, false),
+            vec![Medal::Redirect]
+        );
+        assert_eq!(
+            tracker.judge_kill(&member, 10.0, false),
+            vec![Medal::Redirect, Medal::FormationBreaker]
+        );
+    }
+
+    #[test]
+    fn no_miss_wave_counts_shots_since_the_wave_began() {
+        let mut tracker = StyleTracker::default();
+        assert!(!tracker.judge_wave(1, 0, 0));
+        // Same wave still being flown
+        assert!(!tracker.judge_wave(1, 10, 10));
+        assert!(tracker.judge_wave(2, 10, 10));
+        assert!(!tracker.judge_wave(3, 20, 19));
+        assert!(tracker.judge_wave(4, 25, 24));
+    }
+}
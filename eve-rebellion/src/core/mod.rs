@@ -3,25 +3,77 @@
 //! This module contains the fundamental building blocks:
 //! - Game states and transitions
 //! - Shared resources (score, currency)
+//! - Scoring rules and award breakdowns
+//! - Style medals
 //! - Custom events
 //! - Game constants
 //! - Faction definitions
+//! - Ship fitting (slots, modules, powergrid and CPU)
+//! - Skill tree
+//! - Resist profiles per defense layer and damage type
 //! - Achievement system
+//! - Daily and weekly challenges
+//! - Accessibility options and color palette
+//! - Mission objectives
+//! - Pilot profiles
+//! - Per-run statistics and run history
+//! - Local leaderboards
+//! - Input logs and leaderboard sync backends
+//! - Ghost recordings for racing past runs
+//! - Campaign star maps
+//! - Roguelite sectors, run upgrades and meta-progression
+//! - Ship unlock registry
 
+pub mod accessibility;
 pub mod achievements;
 pub mod campaign;
+pub mod campaign_map;
+pub mod challenges;
 pub mod constants;
 pub mod events;
 pub mod factions;
+pub mod fitting;
 pub mod game_state;
+pub mod ghost;
+pub mod input_log;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod leaderboard_server;
+pub mod leaderboard_sync;
+pub mod leaderboards;
+pub mod medals;
+pub mod objectives;
+pub mod profiles;
+pub mod resists;
 pub mod resources;
+pub mod roguelite;
+pub mod run_stats;
 pub mod save;
+pub mod scoring;
+pub mod skills;
+pub mod unlocks;
 
+pub use accessibility::*;
 pub use achievements::*;
 pub use campaign::*;
+pub use campaign_map::*;
+pub use challenges::*;
 pub use constants::*;
 pub use events::*;
 pub use factions::*;
+pub use fitting::*;
 pub use game_state::*;
+pub use ghost::*;
+pub use input_log::*;
+pub use leaderboard_sync::*;
+pub use leaderboards::*;
+pub use medals::*;
+pub use objectives::*;
+pub use profiles::*;
+pub use resists::*;
 pub use resources::*;
+pub use roguelite::*;
+pub use run_stats::*;
 pub use save::*;
+pub use scoring::*;
+pub use skills::*;
+pub use unlocks::*;
@@ -0,0 +1,652 @@
+//! Mission Objectives
+//!
+//! Typed objectives that a mission combines into primary and optional
+//! goals. A campaign flow loads its mission's list into
+//! [`MissionObjectives`] when the mission starts; `systems::objectives`
+//! advances them from game events, the HUD lists them, and rewards are
+//! paid out when the mission ends.
+
+use bevy::prelude::*;
+
+/// Default reward for a completed primary objective
+pub const PRIMARY_OBJECTIVE_SCORE: u64 = 500;
+pub const PRIMARY_OBJECTIVE_SP: u32 = 1;
+/// Default reward for a completed optional objective
+pub const OPTIONAL_OBJECTIVE_SCORE: u64 = 1500;
+pub const OPTIONAL_OBJECTIVE_SP: u32 = 2;
+
+/// What an objective asks of the player
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObjectiveKind {
+    /// Destroy the mission boss
+    DestroyBoss,
+    /// Keep a convoy ship alive while it crosses the screen from bottom to top
+    Escort {
+        type_id: u32,
+        health: f32,
+        speed: f32,
+    },
+    /// Keep a stationary structure alive until the mission ends
+    Defend { type_id: u32, health: f32 },
+    /// Pick up liberation pods
+    Collect { count: u32 },
+    /// Stay alive for a while
+    Survive { seconds: f32 },
+    /// Destroy enemies of one EVE type (`None` counts any enemy)
+    DestroyTargets { type_id: Option<u32>, count: u32 },
+    /// Spend time inside a circle
+    StayInArea {
+        center: Vec2,
+        radius: f32,
+        seconds: f32,
+    },
+    /// Take no damage for the whole mission
+    NoDamage,
+    /// Take no damage while the boss is in this phase
+    NoDamageInPhase { phase: u32 },
+    /// Shoot down cargo pods drifting down the screen; one slipping past fails it
+    DestroyCargo { count: u32 },
+    /// Lose no wingman, convoy or defended structure
+    NoAlliedLosses,
+    /// Leave no escort alive when the boss goes down
+    EscortsFirst,
+    /// Keep the kill chain going while enemies are on screen
+    SingleChain,
+    /// Clear a number of enemy waves
+    ClearWaves { count: u32 },
+    /// Finish before the clock runs out
+    TimeLimit { seconds: f32 },
+    /// Completed or failed by a mission script
+    Scripted,
+}
+
+impl ObjectiveKind {
+    pub fn default_label(&self) -> String {
+        match self {
+            ObjectiveKind::DestroyBoss => "Destroy the boss".into(),
+            ObjectiveKind::Escort { .. } => "Escort the convoy".into(),
+            ObjectiveKind::Defend { .. } => "Defend the structure".into(),
+            ObjectiveKind::Collect { count } => format!("Liberate {} slaves", count),
+            ObjectiveKind::Survive { seconds } => format!("Survive for {:.0}s", seconds),
+            ObjectiveKind::DestroyTargets { count, .. } => format!("Destroy {} targets", count),
+            ObjectiveKind::StayInArea { seconds, .. } => {
+                format!("Hold the area for {:.0}s", seconds)
+            }
+            ObjectiveKind::NoDamage => "Take no damage".into(),
+            ObjectiveKind::NoDamageInPhase { phase } => {
+                format!("Take no damage in boss phase {}", phase)
+            }
+            ObjectiveKind::DestroyCargo { count } => format!("Destroy {} cargo pods", count),
+            ObjectiveKind::NoAlliedLosses => "No allied losses".into(),
+            ObjectiveKind::EscortsFirst => "Destroy all escorts first".into(),
+            ObjectiveKind::SingleChain => "Destroy all in one chain".into(),
+            ObjectiveKind::ClearWaves { count } => format!("Clear {} waves", count),
+            ObjectiveKind::TimeLimit { seconds } => {
+                let seconds = *seconds as u32;
+                format!("Finish within {}:{:02}", seconds / 60, seconds % 60)
+            }
+            ObjectiveKind::Scripted => "Complete the objective".into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObjectiveStatus {
+    #[default]
+    Active,
+    Complete,
+    Failed,
+}
+
+/// One objective and its progress
+#[derive(Debug, Clone, PartialEq)]
+pub struct Objective {
+    pub kind: ObjectiveKind,
+    pub label: String,
+    /// Optional objectives never fail the mission
+    pub optional: bool,
+    pub score: u64,
+    pub skill_points: u32,
+    pub status: ObjectiveStatus,
+    /// Count for collect/destroy/waves, seconds for survive/stay-in-area,
+    /// remaining health fraction for escort/defend, current chain for
+    /// single-chain
+    pub progress: f32,
+}
+
+impl Objective {
+    pub fn primary(kind: ObjectiveKind) -> Self {
+        Self::new(kind, false, PRIMARY_OBJECTIVE_SCORE, PRIMARY_OBJECTIVE_SP)
+    }
+
+    pub fn optional(kind: ObjectiveKind) -> Self {
+        Self::new(kind, true, OPTIONAL_OBJECTIVE_SCORE, OPTIONAL_OBJECTIVE_SP)
+    }
+
+    fn new(kind: ObjectiveKind, optional: bool, score: u64, skill_points: u32) -> Self {
+        let progress = match kind {
+            ObjectiveKind::Escort { .. } | ObjectiveKind::Defend { .. } => 1.0,
+            _ => 0.0,
+        };
+        Self {
+            kind,
+            label: kind.default_label(),
+            optional,
+            score,
+            skill_points,
+            status: ObjectiveStatus::Active,
+            progress,
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn with_rewards(mut self, score: u64, skill_points: u32) -> Self {
+        self.score = score;
+        self.skill_points = skill_points;
+        self
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == ObjectiveStatus::Active
+    }
+
+    /// Progress suffix shown after the label, if the kind has one
+    pub fn progress_text(&self) -> Option<String> {
+        match self.kind {
+            ObjectiveKind::Collect { count }
+            | ObjectiveKind::DestroyCargo { count }
+            | ObjectiveKind::ClearWaves { count } => Some(format!("{}/{}", self.progress, count)),
+            ObjectiveKind::DestroyTargets { count, .. } => {
+                Some(format!("{}/{}", self.progress, count))
+            }
+            ObjectiveKind::Survive { seconds } | ObjectiveKind::StayInArea { seconds, .. } => {
+                Some(format!("{:.0}/{:.0}s", self.progress.min(seconds), seconds))
+            }
+            ObjectiveKind::Escort { .. } | ObjectiveKind::Defend { .. } => {
+                Some(format!("{:.0}%", self.progress.max(0.0) * 100.0))
+            }
+            _ => None,
+        }
+    }
+
+    /// Single HUD/result-screen line: status mark, label, progress
+    pub fn display_line(&self) -> String {
+        let mark = match self.status {
+            ObjectiveStatus::Active => "◯",
+            ObjectiveStatus::Complete => "✓",
+            ObjectiveStatus::Failed => "✗",
+        };
+        let optional = if self.optional { " (optional)" } else { "" };
+        match self.progress_text().filter(|_| self.is_active()) {
+            Some(progress) => format!("{} {}{} {}", mark, self.label, optional, progress),
+            None => format!("{} {}{}", mark, self.label, optional),
+        }
+    }
+}
+
+/// Score and Skill Points paid for completed objectives
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ObjectiveRewards {
+    pub score: u64,
+    pub skill_points: u32,
+    pub completed: usize,
+    pub total: usize,
+}
+
+/// Objectives of the mission being played
+#[derive(Resource, Debug, Default)]
+pub struct MissionObjectives {
+    pub objectives: Vec<Objective>,
+    /// Seconds since the objectives were set
+    pub elapsed: f32,
+    /// Paid out by [`MissionObjectives::finish`], kept for the result screen
+    pub rewards: Option<ObjectiveRewards>,
+    /// Bumped on every [`MissionObjectives::set`] so entities spawned for
+    /// an earlier mission can tell they are stale
+    pub generation: u32,
+    /// Phase of the boss on screen, if any
+    boss_phase: Option<u32>,
+    /// Status changes not yet announced
+    changes: Vec<usize>,
+}
+
+impl MissionObjectives {
+    /// Start tracking a new mission's objectives
+    pub fn set(&mut self, objectives: Vec<Objective>) {
+        *self = Self {
+            objectives,
+            generation: self.generation.wrapping_add(1),
+            ..default()
+        };
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.objectives.is_empty()
+    }
+
+    fn primaries(&self) -> impl Iterator<Item = &Objective> {
+        self.objectives.iter().filter(|o| !o.optional)
+    }
+
+    /// Every primary objective is done (false when there are none)
+    pub fn primaries_complete(&self) -> bool {
+        self.primaries().next().is_some()
+            && self
+                .primaries()
+                .all(|o| o.status == ObjectiveStatus::Complete)
+    }
+
+    pub fn primary_failed(&self) -> bool {
+        self.primaries()
+            .any(|o| o.status == ObjectiveStatus::Failed)
+    }
+
+    /// Every optional objective is done (false when there are none)
+    pub fn optionals_complete(&self) -> bool {
+        let mut optionals = self.objectives.iter().filter(|o| o.optional).peekable();
+        optionals.peek().is_some() && optionals.all(|o| o.status == ObjectiveStatus::Complete)
+    }
+
+    fn resolve(&mut self, index: usize, status: ObjectiveStatus) {
+        if let Some(objective) = self.objectives.get_mut(index) {
+            if objective.is_active() {
+                objective.status = status;
+                self.changes.push(index);
+            }
+        }
+    }
+
+    pub fn complete(&mut self, index: usize) {
+        self.resolve(index, ObjectiveStatus::Complete);
+    }
+
+    pub fn fail(&mut self, index: usize) {
+        self.resolve(index, ObjectiveStatus::Failed);
+    }
+
+    /// Indices of active objectives matching `filter`
+    fn active_where(&self, filter: impl Fn(&ObjectiveKind) -> bool) -> Vec<usize> {
+        self.objectives
+            .iter()
+            .enumerate()
+            .filter(|(_, o)| o.is_active() && filter(&o.kind))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Count towards objectives with a target; complete them once reached
+    fn advance(&mut self, index: usize, amount: f32, target: f32) {
+        self.objectives[index].progress += amount;
+        if self.objectives[index].progress >= target {
+            self.complete(index);
+        }
+    }
+
+    /// The boss went down with `escorts_left` other enemies still around
+    pub fn on_boss_defeated(&mut self, escorts_left: usize) {
+        for i in self.active_where(|k| matches!(k, ObjectiveKind::DestroyBoss)) {
+            self.complete(i);
+        }
+        for i in self.active_where(|k| matches!(k, ObjectiveKind::EscortsFirst)) {
+            if escorts_left == 0 {
+                self.complete(i);
+            } else {
+                self.fail(i);
+            }
+        }
+    }
+
+    /// Track the boss phase; leaving a no-damage phase unhurt completes it
+    pub fn on_boss_phase(&mut self, phase: Option<u32>) {
+        self.boss_phase = phase;
+        let Some(current) = phase else {
+            return;
+        };
+        for i in self.active_where(|k| matches!(k, ObjectiveKind::NoDamageInPhase { .. })) {
+            if let ObjectiveKind::NoDamageInPhase { phase } = self.objectives[i].kind {
+                if current > phase {
+                    self.complete(i);
+                }
+            }
+        }
+    }
+
+    pub fn on_pods_collected(&mut self, amount: u32) {
+        for i in self.active_where(|k| matches!(k, ObjectiveKind::Collect { .. })) {
+            if let ObjectiveKind::Collect { count } = self.objectives[i].kind {
+                self.advance(i, amount as f32, count as f32);
+            }
+        }
+    }
+
+    pub fn on_enemy_destroyed(&mut self, type_id: u32) {
+        for i in self.active_where(|k| matches!(k, ObjectiveKind::DestroyTargets { .. })) {
+            if let ObjectiveKind::DestroyTargets {
+                type_id: target,
+                count,
+            } = self.objectives[i].kind
+            {
+                if target.is_none() || target == Some(type_id) {
+                    self.advance(i, 1.0, count as f32);
+                }
+            }
+        }
+    }
+
+    pub fn on_player_damaged(&mut self) {
+        let boss_phase = self.boss_phase;
+        let failed = self.active_where(|k| match k {
+            ObjectiveKind::NoDamage => true,
+            ObjectiveKind::NoDamageInPhase { phase } => boss_phase == Some(*phase),
+            _ => false,
+        });
+        for i in failed {
+            self.fail(i);
+        }
+    }
+
+    /// A cargo pod of a destroy-cargo objective was shot down
+    pub fn on_cargo_destroyed(&mut self, index: usize) {
+        if let Some(ObjectiveKind::DestroyCargo { count }) = self
+            .objectives
+            .get(index)
+            .filter(|o| o.is_active())
+            .map(|o| o.kind)
+        {
+            self.advance(index, 1.0, count as f32);
+        }
+    }
+
+    /// A cargo pod got away
+    pub fn on_cargo_escaped(&mut self, index: usize) {
+        self.fail(index);
+    }
+
+    /// A wingman, convoy or defended structure was destroyed
+    pub fn on_ally_lost(&mut self) {
+        for i in self.active_where(|k| matches!(k, ObjectiveKind::NoAlliedLosses)) {
+            self.fail(i);
+        }
+    }
+
+    /// Current kill chain; it may only lapse while no enemies are on screen
+    pub fn on_chain(&mut self, chain: u32, enemies_present: bool) {
+        for i in self.active_where(|k| matches!(k, ObjectiveKind::SingleChain)) {
+            if (chain as f32) < self.objectives[i].progress && enemies_present {
+                self.fail(i);
+            }
+            self.objectives[i].progress = chain as f32;
+        }
+    }
+
+    /// An enemy wave was cleared
+    pub fn on_wave_cleared(&mut self) {
+        for i in self.active_where(|k| matches!(k, ObjectiveKind::ClearWaves { .. })) {
+            if let ObjectiveKind::ClearWaves { count } = self.objectives[i].kind {
+                self.advance(i, 1.0, count as f32);
+            }
+        }
+    }
+
+    /// Health of an escort or defend target changed; fails it at zero
+    pub fn on_protected_health(&mut self, index: usize, fraction: f32) {
+        if let Some(objective) = self.objectives.get_mut(index) {
+            if objective.is_active() {
+                objective.progress = fraction.max(0.0);
+                if fraction <= 0.0 {
+                    self.fail(index);
+                    self.on_ally_lost();
+                }
+            }
+        }
+    }
+
+    /// The convoy of an escort objective made it across
+    pub fn on_escort_arrived(&mut self, index: usize) {
+        self.complete(index);
+    }
+
+    /// Advance the clock-driven objectives
+    pub fn tick(&mut self, delta: f32, player_position: Option<Vec2>) {
+        self.elapsed += delta;
+        for i in self.active_where(|_| true) {
+            match self.objectives[i].kind {
+                ObjectiveKind::Survive { seconds } => self.advance(i, delta, seconds),
+                ObjectiveKind::StayInArea {
+                    center,
+                    radius,
+                    seconds,
+                } if player_position.is_some_and(|p| p.distance(center) <= radius) => {
+                    self.advance(i, delta, seconds);
+                }
+                ObjectiveKind::TimeLimit { seconds } if self.elapsed > seconds => self.fail(i),
+                _ => {}
+            }
+        }
+    }
+
+    /// Mission over: settle what is still open and pay out rewards.
+    /// Objectives that only had to hold until the end (no damage, time
+    /// limit, defend, no losses, one chain) count as complete on success;
+    /// everything else still open is failed.
+    pub fn finish(&mut self, success: bool) -> ObjectiveRewards {
+        for objective in self.objectives.iter_mut().filter(|o| o.is_active()) {
+            let held = matches!(
+                objective.kind,
+                ObjectiveKind::NoDamage
+                    | ObjectiveKind::NoDamageInPhase { .. }
+                    | ObjectiveKind::TimeLimit { .. }
+                    | ObjectiveKind::Defend { .. }
+                    | ObjectiveKind::NoAlliedLosses
+                    | ObjectiveKind::SingleChain
+            );
+            objective.status = if success && held {
+                ObjectiveStatus::Complete
+            } else {
+                ObjectiveStatus::Failed
+            };
+        }
+
+        let mut rewards = ObjectiveRewards {
+            total: self.objectives.len(),
+            ..default()
+        };
+        for objective in &self.objectives {
+            if objective.status == ObjectiveStatus::Complete {
+                rewards.score += objective.score;
+                rewards.skill_points += objective.skill_points;
+                rewards.completed += 1;
+            }
+        }
+        self.rewards = Some(rewards);
+        rewards
+    }
+
+    /// Objectives whose status changed since the last call
+    pub fn take_changes(&mut self) -> Vec<usize> {
+        std::mem::take(&mut self.changes)
+    }
+
+    /// HUD text, one objective per line
+    pub fn hud_text(&self) -> String {
+        self.objectives
+            .iter()
+            .map(Objective::display_line)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// An objective was completed or failed
+#[derive(Event, Debug, Clone)]
+pub struct ObjectiveEvent {
+    pub index: usize,
+    pub status: ObjectiveStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn objectives(list: Vec<Objective>) -> MissionObjectives {
+        let mut objectives = MissionObjectives::default();
+        objectives.set(list);
+        objectives
+    }
+
+    #[test]
+    fn primaries_complete_only_when_all_are_done() {
+        let mut o = objectives(vec![
+            Objective::primary(ObjectiveKind::DestroyBoss),
+            Objective::primary(ObjectiveKind::Collect { count: 3 }),
+            Objective::optional(ObjectiveKind::NoDamage),
+        ]);
+        o.on_boss_defeated(0);
+        assert!(!o.primaries_complete());
+        o.on_pods_collected(2);
+        assert_eq!(o.objectives[1].display_line(), "◯ Liberate 3 slaves 2/3");
+        o.on_pods_collected(1);
+        assert!(o.primaries_complete());
+        assert_eq!(o.take_changes(), vec![0, 1]);
+        assert!(!MissionObjectives::default().primaries_complete());
+    }
+
+    #[test]
+    fn finish_settles_open_objectives_and_pays_rewards() {
+        let mut o = objectives(vec![
+            Objective::primary(ObjectiveKind::DestroyBoss),
+            Objective::optional(ObjectiveKind::NoDamage),
+            Objective::optional(ObjectiveKind::Survive { seconds: 60.0 }),
+            Objective::optional(ObjectiveKind::DestroyTargets {
+                type_id: Some(602),
+                count: 2,
+            })
+            .with_rewards(100, 5),
+        ]);
+        o.on_boss_defeated(0);
+        o.on_enemy_destroyed(602);
+        o.on_enemy_destroyed(594);
+        o.on_enemy_destroyed(602);
+        o.tick(10.0, None);
+
+        let rewards = o.finish(true);
+        assert_eq!(rewards.completed, 3);
+        assert_eq!(rewards.total, 4);
+        assert_eq!(
+            rewards.score,
+            PRIMARY_OBJECTIVE_SCORE + OPTIONAL_OBJECTIVE_SCORE + 100
+        );
+        assert_eq!(
+            rewards.skill_points,
+            PRIMARY_OBJECTIVE_SP + OPTIONAL_OBJECTIVE_SP + 5
+        );
+        assert_eq!(o.objectives[2].status, ObjectiveStatus::Failed);
+        assert!(!o.optionals_complete());
+    }
+
+    #[test]
+    fn clock_objectives() {
+        let mut o = objectives(vec![
+            Objective::optional(ObjectiveKind::TimeLimit { seconds: 5.0 }),
+            Objective::primary(ObjectiveKind::StayInArea {
+                center: Vec2::ZERO,
+                radius: 50.0,
+                seconds: 3.0,
+            }),
+        ]);
+        o.tick(2.0, Some(Vec2::new(10.0, 10.0)));
+        o.tick(2.0, Some(Vec2::new(200.0, 0.0)));
+        assert!(o.objectives[1].is_active());
+        o.tick(1.5, Some(Vec2::ZERO));
+        assert_eq!(o.objectives[0].status, ObjectiveStatus::Failed);
+        assert_eq!(o.objectives[1].status, ObjectiveStatus::Complete);
+    }
+
+    #[test]
+    fn protected_targets_fail_the_mission_at_zero_health() {
+        let mut o = objectives(vec![
+            Objective::primary(ObjectiveKind::Defend {
+                type_id: 12235,
+                health: 500.0,
+            }),
+            Objective::optional(ObjectiveKind::NoDamage),
+        ]);
+        o.on_protected_health(0, 0.4);
+        assert_eq!(o.objectives[0].display_line(), "◯ Defend the structure 40%");
+        o.on_player_damaged();
+        assert!(!o.primary_failed());
+        o.on_protected_health(0, 0.0);
+        assert!(o.primary_failed());
+    }
+
+    #[test]
+    fn boss_and_chain_objectives() {
+        let mut o = objectives(vec![
+            Objective::primary(ObjectiveKind::DestroyBoss),
+            Objective::optional(ObjectiveKind::EscortsFirst),
+            Objective::optional(ObjectiveKind::NoDamageInPhase { phase: 1 }),
+            Objective::optional(ObjectiveKind::SingleChain),
+            Objective::optional(ObjectiveKind::NoAlliedLosses),
+        ]);
+        // Damage before the boss shows up and chain breaks between waves are fine
+        o.on_player_damaged();
+        o.on_chain(4, true);
+        o.on_chain(0, false);
+        o.on_boss_phase(Some(1));
+        o.on_chain(3, true);
+        o.on_boss_phase(Some(2));
+        o.on_player_damaged();
+        assert_eq!(o.objectives[2].status, ObjectiveStatus::Complete);
+
+        o.on_chain(0, true);
+        assert_eq!(o.objectives[3].status, ObjectiveStatus::Failed);
+
+        o.on_boss_defeated(2);
+        assert_eq!(o.objectives[1].status, ObjectiveStatus::Failed);
+        o.finish(true);
+        assert_eq!(o.objectives[4].status, ObjectiveStatus::Complete);
+    }
+
+    #[test]
+    fn cargo_waves_and_allies() {
+        let mut o = objectives(vec![
+            Objective::primary(ObjectiveKind::ClearWaves { count: 2 }),
+            Objective::optional(ObjectiveKind::DestroyCargo { count: 2 }),
+            Objective::optional(ObjectiveKind::NoAlliedLosses),
+            Objective::optional(ObjectiveKind::Escort {
+                type_id: 648,
+                health: 300.0,
+                speed: 20.0,
+            }),
+        ]);
+        o.on_wave_cleared();
+        o.on_cargo_destroyed(1);
+        assert_eq!(
+            o.objectives[1].display_line(),
+            "◯ Destroy 2 cargo pods (optional) 1/2"
+        );
+        o.on_wave_cleared();
+        o.on_cargo_destroyed(1);
+        assert!(o.primaries_complete());
+        assert_eq!(o.objectives[1].status, ObjectiveStatus::Complete);
+
+        o.on_protected_health(3, 0.0);
+        assert_eq!(o.objectives[2].status, ObjectiveStatus::Failed);
+
+        let mut o = objectives(vec![Objective::optional(ObjectiveKind::DestroyCargo {
+            count: 3,
+        })]);
+        o.on_cargo_escaped(0);
+        o.on_cargo_destroyed(0);
+        assert_eq!(o.objectives[0].status, ObjectiveStatus::Failed);
+        assert_eq!(o.objectives[0].progress, 0.0);
+    }
+}
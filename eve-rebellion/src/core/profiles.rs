@@ -0,0 +1,455 @@
+//! Pilot Profiles
+//!
+//! Named pilots, each with its own `SaveData` (progress, unlocks, upgrades,
+//! fittings, stats and settings). The profile index lives next to the saves
+//! in `profiles.json`; each pilot is saved to `profiles/<id>.json` (or the
+//! matching localStorage keys on WASM). Settings can optionally be shared,
+//! in which case the index keeps one copy that every pilot loads.
+//! Per-pilot logs such as run history are appended to `<log>/<id>.jsonl`;
+//! data shared by all pilots (leaderboards) is stored under its own key.
+//!
+//! An old single `save.json` becomes the first pilot on first launch.
+
+#![allow(dead_code)]
+
+use super::save::{GameSettings, SaveData};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+/// Longest pilot name
+pub const MAX_PILOT_NAME: usize = 20;
+
+/// One named pilot
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PilotProfile {
+    /// Storage key, stable across renames
+    pub id: String,
+    pub name: String,
+}
+
+/// Profile index: known pilots, the last one flown and shared settings
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct PilotProfiles {
+    pub profiles: Vec<PilotProfile>,
+    /// Pilot whose save is loaded into `SaveData` (saves go nowhere without one)
+    #[serde(default)]
+    pub active: Option<String>,
+    /// Every pilot uses `shared_settings` instead of their own
+    #[serde(default)]
+    pub share_settings: bool,
+    #[serde(default)]
+    pub shared_settings: GameSettings,
+    /// Counter for new profile ids
+    #[serde(default)]
+    pub next_id: u32,
+}
+
+impl PilotProfiles {
+    pub fn get(&self, id: &str) -> Option<&PilotProfile> {
+        self.profiles.iter().find(|p| p.id == id)
+    }
+
+    pub fn active_profile(&self) -> Option<&PilotProfile> {
+        self.active.as_deref().and_then(|id| self.get(id))
+    }
+
+    /// Add a pilot and return its id. Blank names become "Pilot N".
+    pub fn create(&mut self, name: &str) -> String {
+        self.next_id += 1;
+        let id = format!("pilot{}", self.next_id);
+        let name = match clean_name(name) {
+            Some(name) => name,
+            None => format!("Pilot {}", self.profiles.len() + 1),
+        };
+        self.profiles.push(PilotProfile {
+            id: id.clone(),
+            name: self.unique_name(&name, None),
+        });
+        id
+    }
+
+    /// Rename a pilot; false if the id is unknown or the name is blank
+    pub fn rename(&mut self, id: &str, name: &str) -> bool {
+        let Some(name) = clean_name(name) else {
+            return false;
+        };
+        let name = self.unique_name(&name, Some(id));
+        match self.profiles.iter_mut().find(|p| p.id == id) {
+            Some(profile) => {
+                profile.name = name;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove a pilot from the index; clears `active` if it was the one
+    pub fn remove(&mut self, id: &str) -> bool {
+        let before = self.profiles.len();
+        self.profiles.retain(|p| p.id != id);
+        if self.active.as_deref() == Some(id) {
+            self.active = None;
+        }
+        self.profiles.len() != before
+    }
+
+    /// Add a pilot named after `id` ("<name> (copy)"); the caller copies the save
+    pub fn duplicate(&mut self, id: &str) -> Option<String> {
+        let name = format!("{} (copy)", self.get(id)?.name);
+        Some(self.create(&name))
+    }
+
+    /// `name`, or `name 2`, `name 3`... if another pilot already has it
+    fn unique_name(&self, name: &str, except: Option<&str>) -> String {
+        let taken = |candidate: &str| {
+            self.profiles
+                .iter()
+                .any(|p| Some(p.id.as_str()) != except && p.name.eq_ignore_ascii_case(candidate))
+        };
+        if !taken(name) {
+            return name.to_string();
+        }
+        (2..)
+            .map(|n| format!("{} {}", name, n))
+            .find(|candidate| !taken(candidate))
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// Settings a pilot should play with
+    pub fn settings_for(&self, save: &SaveData) -> GameSettings {
+        if self.share_settings {
+            self.shared_settings.clone()
+        } else {
+            save.settings.clone()
+        }
+    }
+
+    /// Load a pilot's save, with shared settings applied
+    pub fn load_save(&self, id: &str) -> SaveData {
+        let mut save = read_json::<SaveData>(&profile_key(id)).unwrap_or_default();
+        save.settings = self.settings_for(&save);
+        save.migrate_upgrades();
+        save
+    }
+
+    /// Load the index, creating it from the single-file save if needed
+    pub fn load() -> Self {
+        if let Some(profiles) = read_json::<PilotProfiles>(INDEX_KEY) {
+            info!("Loaded {} pilot profiles", profiles.profiles.len());
+            return profiles;
+        }
+
+        let mut profiles = Self::default();
+        if let Some(legacy) = read_json::<SaveData>(LEGACY_KEY) {
+            info!("Moving existing save into the first pilot profile");
+            let id = profiles.create("");
+            profiles.shared_settings = legacy.settings.clone();
+            write_json(&profile_key(&id), &legacy);
+            profiles.active = Some(id);
+            profiles.save();
+        }
+        profiles
+    }
+
+    pub fn save(&self) {
+        write_json(INDEX_KEY, self);
+    }
+}
+
+/// Trim a pilot name to printable characters; None if nothing is left
+pub fn clean_name(name: &str) -> Option<String> {
+    let name: String = name
+        .trim()
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(MAX_PILOT_NAME)
+        .collect();
+    let name = name.trim().to_string();
+    (!name.is_empty()).then_some(name)
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+const INDEX_KEY: &str = "profiles";
+/// Pre-profile single save
+const LEGACY_KEY: &str = "save";
+
+fn profile_key(id: &str) -> String {
+    format!("profiles/{}", id)
+}
+
+/// Save a pilot's data
+pub fn save_profile(id: &str, save: &SaveData) {
+    write_json(&profile_key(id), save);
+}
+
+/// Copy one pilot's save to another id
+pub fn copy_profile(from: &str, to: &str) {
+    if let Some(save) = read_json::<SaveData>(&profile_key(from)) {
+        write_json(&profile_key(to), &save);
+    }
+}
+
+/// Delete a pilot's save and logs
+pub fn delete_profile(id: &str) {
+    remove_key(&profile_key(id));
+    for log in PILOT_LOGS {
+        remove_log(&log_key(log, id));
+    }
+}
+
+/// Load data shared by every pilot (not part of any save)
+pub fn load_shared<T: for<'de> Deserialize<'de>>(key: &str) -> Option<T> {
+    read_json(key)
+}
+
+/// Save data shared by every pilot
+pub fn save_shared<T: Serialize>(key: &str, value: &T) {
+    write_json(key, value);
+}
+
+/// Delete data shared by every pilot
+pub fn remove_shared(key: &str) {
+    remove_key(key);
+}
+
+/// Append-only per-pilot logs, one JSON value per line
+pub const PILOT_LOGS: &[&str] = &["history"];
+
+/// Lines kept per log in localStorage, which has no room for long logs
+#[cfg(target_arch = "wasm32")]
+const MAX_STORED_LOG_LINES: usize = 200;
+
+fn log_key(log: &str, id: &str) -> String {
+    format!("{}/{}", log, id)
+}
+
+/// Append a value to one of a pilot's logs
+pub fn append_log<T: Serialize>(log: &str, id: &str, value: &T) {
+    match serde_json::to_string(value) {
+        Ok(line) => append_line(&log_key(log, id), &line),
+        Err(e) => warn!("Failed to serialize {} entry: {}", log, e),
+    }
+}
+
+/// Every entry of a pilot's log that still parses, oldest first
+pub fn read_log<T: for<'de> Deserialize<'de>>(log: &str, id: &str) -> Vec<T> {
+    read_lines(&log_key(log, id))
+        .iter()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Data file path for a key (native only)
+#[cfg(not(target_arch = "wasm32"))]
+fn key_path(key: &str) -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("eve_rebellion")
+        .join(format!("{}.json", key))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_json<T: for<'de> Deserialize<'de>>(key: &str) -> Option<T> {
+    let path = key_path(key);
+    let data = fs::read_to_string(&path).ok()?;
+    match serde_json::from_str(&data) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            warn!("Failed to parse {:?}: {}", path, e);
+            None
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_json<T: Serialize>(key: &str, value: &T) {
+    let path = key_path(key);
+    if let Some(parent) = path.parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            warn!("Failed to create save directory: {}", e);
+            return;
+        }
+    }
+    match serde_json::to_string_pretty(value) {
+        Ok(data) => {
+            if let Err(e) = fs::write(&path, data) {
+                warn!("Failed to write {:?}: {}", path, e);
+            } else {
+                info!("Saved {:?}", path);
+            }
+        }
+        Err(e) => warn!("Failed to serialize {}: {}", key, e),
+    }
+}
+
+/// Log file path for a key (native only)
+#[cfg(not(target_arch = "wasm32"))]
+fn log_path(key: &str) -> PathBuf {
+    key_path(key).with_extension("jsonl")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn append_line(key: &str, line: &str) {
+    use std::io::Write;
+
+    let path = log_path(key);
+    if let Some(parent) = path.parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            warn!("Failed to create save directory: {}", e);
+            return;
+        }
+    }
+    let result = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{}", line));
+    if let Err(e) = result {
+        warn!("Failed to append to {:?}: {}", path, e);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_lines(key: &str) -> Vec<String> {
+    fs::read_to_string(log_path(key))
+        .map(|data| data.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn remove_key(key: &str) {
+    remove_path(key_path(key));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn remove_log(key: &str) {
+    remove_path(log_path(key));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn remove_path(path: PathBuf) {
+    if path.exists() {
+        if let Err(e) = fs::remove_file(&path) {
+            warn!("Failed to delete {:?}: {}", path, e);
+        }
+    }
+}
+
+/// localStorage key ("eve_rebellion_save" for the legacy save)
+#[cfg(target_arch = "wasm32")]
+fn storage_key(key: &str) -> String {
+    format!("eve_rebellion_{}", key.replace('/', "_"))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_json<T: for<'de> Deserialize<'de>>(key: &str) -> Option<T> {
+    let data = local_storage()?.get_item(&storage_key(key)).ok()??;
+    serde_json::from_str(&data).ok()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_json<T: Serialize>(key: &str, value: &T) {
+    if let (Some(storage), Ok(data)) = (local_storage(), serde_json::to_string(value)) {
+        if storage.set_item(&storage_key(key), &data).is_ok() {
+            info!("Saved {} to localStorage", key);
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn append_line(key: &str, line: &str) {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+    let mut lines = read_lines(key);
+    lines.push(line.to_string());
+    let start = lines.len().saturating_sub(MAX_STORED_LOG_LINES);
+    let _ = storage.set_item(&storage_key(key), &lines[start..].join("\n"));
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_lines(key: &str) -> Vec<String> {
+    local_storage()
+        .and_then(|storage| storage.get_item(&storage_key(key)).ok().flatten())
+        .map(|data| data.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn remove_key(key: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(&storage_key(key));
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn remove_log(key: &str) {
+    remove_key(key);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_rename_and_remove() {
+        let mut profiles = PilotProfiles::default();
+        let first = profiles.create("Ana");
+        let second = profiles.create("  ");
+        assert_eq!(profiles.get(&second).unwrap().name, "Pilot 2");
+
+        // Names stay unique, ignoring case
+        let third = profiles.create("ana");
+        assert_eq!(profiles.get(&third).unwrap().name, "ana 2");
+        assert!(profiles.rename(&second, "Ana"));
+        assert_eq!(profiles.get(&second).unwrap().name, "Ana 3");
+        assert!(!profiles.rename(&second, "\n"));
+
+        profiles.active = Some(first.clone());
+        assert!(profiles.remove(&first));
+        assert!(profiles.active.is_none());
+        assert!(!profiles.remove(&first));
+
+        // Ids are never reused
+        assert_eq!(profiles.create("Mo"), "pilot4");
+    }
+
+    #[test]
+    fn duplicate_names_the_copy() {
+        let mut profiles = PilotProfiles::default();
+        let id = profiles.create("Vel");
+        let copy = profiles.duplicate(&id).unwrap();
+        assert_eq!(profiles.get(&copy).unwrap().name, "Vel (copy)");
+        assert!(profiles.duplicate("missing").is_none());
+        assert_eq!(
+            clean_name("  a very long pilot name indeed  ")
+                .unwrap()
+                .len(),
+            20
+        );
+    }
+
+    #[test]
+    fn shared_settings_override_the_pilot() {
+        let mut profiles = PilotProfiles::default();
+        let mut save = SaveData::default();
+        save.settings.music_volume = 0.1;
+        profiles.shared_settings.music_volume = 0.9;
+
+        assert_eq!(profiles.settings_for(&save).music_volume, 0.1);
+        profiles.share_settings = true;
+        assert_eq!(profiles.settings_for(&save).music_volume, 0.9);
+    }
+}
@@ -0,0 +1,233 @@
+//! Resist Profiles
+//!
+//! EVE-style resistances per defense layer (shield, armor, hull) and damage
+//! type (EM, thermal, kinetic, explosive). Every faction starts from the
+//! same base profile - shields weak to EM, armor weak to explosive - and its
+//! tank doctrine and racial hull bonuses harden the layer it relies on.
+//! Both the player ship and enemies take damage through a profile.
+
+#![allow(dead_code)]
+
+use super::events::{AmmoType, DamageLayer, DamageType};
+use super::factions::{Faction, TankDoctrine};
+
+/// Resistances (0.0 - 1.0) indexed by `DamageType::index`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResistProfile {
+    pub shield: [f32; 4],
+    pub armor: [f32; 4],
+    pub hull: [f32; 4],
+}
+
+impl Default for ResistProfile {
+    fn default() -> Self {
+        Self::BASE
+    }
+}
+
+impl ResistProfile {
+    /// Uniform base resists before doctrine bonuses (EVE T1 hull values)
+    pub const BASE: ResistProfile = ResistProfile {
+        shield: [0.0, 0.2, 0.4, 0.5],
+        armor: [0.5, 0.35, 0.25, 0.1],
+        hull: [0.33, 0.33, 0.33, 0.33],
+    };
+
+    /// Profile of a tank doctrine: the tanked layer closes 30% of its holes
+    pub fn for_doctrine(doctrine: TankDoctrine) -> Self {
+        let mut profile = Self::BASE;
+        match doctrine {
+            TankDoctrine::Shield => profile.harden(DamageLayer::Shield, &DamageType::all(), 0.3),
+            TankDoctrine::Armor => profile.harden(DamageLayer::Armor, &DamageType::all(), 0.3),
+            // Speed tanks rely on not getting hit
+            TankDoctrine::Speed => {}
+        }
+        profile
+    }
+
+    /// Faction profile: doctrine plus racial resist bonuses
+    pub fn for_faction(faction: Faction) -> Self {
+        let mut profile = Self::for_doctrine(faction.tank_type());
+        match faction {
+            // Amarr plating shrugs off lasers
+            Faction::Amarr => profile.harden(
+                DamageLayer::Armor,
+                &[DamageType::EM, DamageType::Thermal],
+                0.2,
+            ),
+            Faction::Caldari => profile.harden(
+                DamageLayer::Shield,
+                &[DamageType::Kinetic, DamageType::Thermal],
+                0.2,
+            ),
+            Faction::Gallente => profile.harden(
+                DamageLayer::Armor,
+                &[DamageType::Kinetic, DamageType::Thermal],
+                0.2,
+            ),
+            // Minmatar hulls are patched together to survive anything
+            Faction::Minmatar => profile.harden(DamageLayer::Hull, &DamageType::all(), 0.15),
+        }
+        profile
+    }
+
+    /// Close `amount` of the remaining hole for the given damage types
+    pub fn harden(&mut self, layer: DamageLayer, types: &[DamageType], amount: f32) {
+        let resists = self.layer_mut(layer);
+        for damage_type in types {
+            let resist = &mut resists[damage_type.index()];
+            *resist += (1.0 - *resist) * amount;
+        }
+    }
+
+    fn layer_mut(&mut self, layer: DamageLayer) -> &mut [f32; 4] {
+        match layer {
+            DamageLayer::Shield => &mut self.shield,
+            DamageLayer::Armor => &mut self.armor,
+            DamageLayer::Hull => &mut self.hull,
+        }
+    }
+
+    pub fn layer(&self, layer: DamageLayer) -> &[f32; 4] {
+        match layer {
+            DamageLayer::Shield => &self.shield,
+            DamageLayer::Armor => &self.armor,
+            DamageLayer::Hull => &self.hull,
+        }
+    }
+
+    pub fn resist(&self, layer: DamageLayer, damage_type: DamageType) -> f32 {
+        self.layer(layer)[damage_type.index()]
+    }
+
+    /// Fraction of incoming damage a layer takes
+    pub fn multiplier(&self, layer: DamageLayer, damage_type: DamageType) -> f32 {
+        1.0 - self.resist(layer, damage_type)
+    }
+
+    /// Damage taken relative to an average hit on the base profile's layer.
+    ///
+    /// Enemy health pools were tuned without resists, so enemies take damage
+    /// through this: the right damage type hits harder than before, the
+    /// wrong one softer, and mixed fire kills as fast as it used to.
+    pub fn relative_multiplier(&self, layer: DamageLayer, damage_type: DamageType) -> f32 {
+        let base = Self::BASE.layer(layer);
+        let average = base.iter().map(|r| 1.0 - r).sum::<f32>() / base.len() as f32;
+        self.multiplier(layer, damage_type) / average
+    }
+}
+
+/// How well a hit landed against the layer it struck
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DamageEffectiveness {
+    /// Mostly resisted
+    Resisted,
+    #[default]
+    Normal,
+    /// Hit a resist hole
+    Effective,
+}
+
+impl DamageEffectiveness {
+    /// Classify a relative damage multiplier
+    pub fn from_multiplier(multiplier: f32) -> Self {
+        if multiplier >= 1.15 {
+            DamageEffectiveness::Effective
+        } else if multiplier <= 0.85 {
+            DamageEffectiveness::Resisted
+        } else {
+            DamageEffectiveness::Normal
+        }
+    }
+}
+
+impl TankDoctrine {
+    /// Share of a single health pool read as shield, armor and hull
+    pub fn layer_split(&self) -> [f32; 3] {
+        match self {
+            TankDoctrine::Shield => [0.6, 0.25, 0.15],
+            TankDoctrine::Armor => [0.2, 0.6, 0.2],
+            TankDoctrine::Speed => [0.4, 0.35, 0.25],
+        }
+    }
+
+    /// Layer currently being hit on a single health pool at `health_fraction`
+    pub fn layer_at(&self, health_fraction: f32) -> DamageLayer {
+        let [_, armor, hull] = self.layer_split();
+        if health_fraction > armor + hull {
+            DamageLayer::Shield
+        } else if health_fraction > hull {
+            DamageLayer::Armor
+        } else {
+            DamageLayer::Hull
+        }
+    }
+}
+
+impl AmmoType {
+    /// Ammo damage multiplier against a layer (hull takes ammo as-is)
+    pub fn layer_mult(&self, layer: DamageLayer) -> f32 {
+        match layer {
+            DamageLayer::Shield => self.shield_mult(),
+            DamageLayer::Armor => self.armor_mult(),
+            DamageLayer::Hull => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn doctrine_hardens_the_tanked_layer() {
+        let amarr = ResistProfile::for_faction(Faction::Amarr);
+        let caldari = ResistProfile::for_faction(Faction::Caldari);
+
+        assert!(amarr.resist(DamageLayer::Armor, DamageType::EM) > 0.7);
+        assert_eq!(amarr.shield, ResistProfile::BASE.shield);
+        assert!(
+            caldari.resist(DamageLayer::Shield, DamageType::Kinetic)
+                > ResistProfile::BASE.shield[DamageType::Kinetic.index()]
+        );
+        assert_eq!(caldari.armor, ResistProfile::BASE.armor);
+    }
+
+    #[test]
+    fn ammo_choice_matters_per_layer() {
+        let amarr = ResistProfile::for_faction(Faction::Amarr);
+        let caldari = ResistProfile::for_faction(Faction::Caldari);
+        let hit = |profile: &ResistProfile, layer, ammo: AmmoType| {
+            profile.relative_multiplier(layer, ammo.damage_type()) * ammo.layer_mult(layer)
+        };
+
+        // Plasma beats EMP against Amarr armor, EMP beats plasma on Caldari shields
+        assert!(
+            hit(&amarr, DamageLayer::Armor, AmmoType::Plasma)
+                > hit(&amarr, DamageLayer::Armor, AmmoType::EMP)
+        );
+        assert!(
+            hit(&caldari, DamageLayer::Shield, AmmoType::EMP)
+                > hit(&caldari, DamageLayer::Shield, AmmoType::Plasma)
+        );
+        assert_eq!(
+            DamageEffectiveness::from_multiplier(hit(&caldari, DamageLayer::Shield, AmmoType::EMP)),
+            DamageEffectiveness::Effective
+        );
+    }
+
+    #[test]
+    fn base_profile_averages_to_one() {
+        for layer in [DamageLayer::Shield, DamageLayer::Armor, DamageLayer::Hull] {
+            let average = DamageType::all()
+                .iter()
+                .map(|&t| ResistProfile::BASE.relative_multiplier(layer, t))
+                .sum::<f32>()
+                / 4.0;
+            assert!((average - 1.0).abs() < 1e-4);
+        }
+        assert_eq!(TankDoctrine::Armor.layer_at(1.0), DamageLayer::Shield);
+        assert_eq!(TankDoctrine::Armor.layer_at(0.5), DamageLayer::Armor);
+        assert_eq!(TankDoctrine::Armor.layer_at(0.1), DamageLayer::Hull);
+    }
+}
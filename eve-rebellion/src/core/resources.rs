@@ -6,12 +6,13 @@
 
 use bevy::prelude::*;
 
-/// Player score and combo system
+/// Player score and kill chain. Points are added by the scoring pipeline
+/// (see [`crate::core::scoring`]), not directly.
 #[derive(Debug, Clone, Resource)]
 pub struct ScoreSystem {
     /// Current score
     pub score: u64,
-    /// Current multiplier (1.0 - 99.9)
+    /// Chain multiplier (1.0 - 99.9)
     pub multiplier: f32,
     /// Kill chain count
     pub chain: u32,
@@ -25,6 +26,9 @@ pub struct ScoreSystem {
     pub no_damage_bonus: bool,
     /// Souls liberated count (Elder Fleet campaign)
     pub souls_liberated: u32,
+    /// Latest award and how long ago it was made
+    pub last_award: Option<super::ScoreBreakdown>,
+    pub last_award_age: f32,
 }
 
 impl Default for ScoreSystem {
@@ -38,27 +42,44 @@ impl Default for ScoreSystem {
             style_points: 0,
             no_damage_bonus: true,
             souls_liberated: 0,
+            last_award: None,
+            last_award_age: 0.0,
         }
     }
 }
 
 impl ScoreSystem {
-    /// Add points with current multiplier
-    pub fn add_score(&mut self, base_points: u64) {
-        let final_points = (base_points as f32 * self.multiplier) as u64;
-        self.score += final_points;
-    }
-
-    /// Register a kill and extend chain
-    pub fn on_kill(&mut self, base_points: u64) {
+    /// Extend the chain with a kill
+    pub fn register_kill(&mut self) {
         self.chain += 1;
         self.chain_timer = self.max_chain_time;
         self.multiplier = (1.0 + self.chain as f32 * 0.1).min(99.9);
-        self.add_score(base_points);
+    }
+
+    /// Add an award worked out by the scoring rules
+    pub fn award(&mut self, breakdown: super::ScoreBreakdown) {
+        self.score += breakdown.total;
+        self.last_award = Some(breakdown);
+        self.last_award_age = 0.0;
+    }
+
+    /// Callout for the current chain
+    pub fn combo_tier_name(&self) -> Option<&'static str> {
+        super::combo_tier_name(self.chain)
+    }
+
+    /// Chain time left (0.0 - 1.0)
+    pub fn chain_timer_percent(&self) -> f32 {
+        if self.chain > 0 {
+            self.chain_timer / self.max_chain_time
+        } else {
+            0.0
+        }
     }
 
     /// Update chain timer (call each frame)
     pub fn update(&mut self, dt: f32) {
+        self.last_award_age += dt;
         if self.chain > 0 {
             self.chain_timer -= dt;
             if self.chain_timer <= 0.0 {
@@ -70,15 +91,7 @@ impl ScoreSystem {
 
     /// Get style grade based on average multiplier
     pub fn get_grade(&self) -> StyleGrade {
-        match self.multiplier {
-            m if m >= 50.0 => StyleGrade::SSS,
-            m if m >= 20.0 => StyleGrade::SS,
-            m if m >= 10.0 => StyleGrade::S,
-            m if m >= 5.0 => StyleGrade::A,
-            m if m >= 3.0 => StyleGrade::B,
-            m if m >= 1.5 => StyleGrade::C,
-            _ => StyleGrade::D,
-        }
+        StyleGrade::from_multiplier(self.multiplier)
     }
 
     /// Reset for new stage
@@ -87,6 +100,7 @@ impl ScoreSystem {
         self.chain_timer = 0.0;
         self.multiplier = 1.0;
         self.no_damage_bonus = true;
+        self.last_award = None;
     }
 
     /// Reset for new game
@@ -109,6 +123,19 @@ pub enum StyleGrade {
 }
 
 impl StyleGrade {
+    /// Grade for a score multiplier
+    pub fn from_multiplier(multiplier: f32) -> Self {
+        match multiplier {
+            m if m >= 50.0 => StyleGrade::SSS,
+            m if m >= 20.0 => StyleGrade::SS,
+            m if m >= 10.0 => StyleGrade::S,
+            m if m >= 5.0 => StyleGrade::A,
+            m if m >= 3.0 => StyleGrade::B,
+            m if m >= 1.5 => StyleGrade::C,
+            _ => StyleGrade::D,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             StyleGrade::D => "D",
@@ -134,7 +161,7 @@ impl StyleGrade {
     }
 }
 
-/// Salt Miner mode - meter fills from proximity kills, manual activation with B/Y
+/// Salt Miner mode - meter fills from proximity kills and grazes, manual activation with B/Y
 /// Based on finishing guide: meter 0-100, manual activation, 5x score, 8 second duration
 #[derive(Debug, Clone, Resource)]
 pub struct SaltMinerSystem {
@@ -206,6 +233,16 @@ impl SaltMinerSystem {
         gain
     }
 
+    /// Register a graze. Returns meter gained.
+    pub fn on_graze(&mut self) -> f32 {
+        if self.is_active {
+            return 0.0;
+        }
+        let gain = crate::core::constants::SALT_MINER_GAIN_PER_GRAZE.min(100.0 - self.meter);
+        self.meter += gain;
+        gain
+    }
+
     /// Legacy on_kill for compatibility (assumes point-blank)
     pub fn on_kill(&mut self) {
         self.on_kill_at_distance(0.0);
@@ -468,6 +505,8 @@ pub struct PlayerModifiers {
     pub capacitor_drain_multiplier: f32,
     pub maneuver_cooldown_multiplier: f32,
     pub invincibility_duration_multiplier: f32,
+    /// Salt Miner stays active for the whole mission
+    pub salt_miner_always: bool,
 }
 
 impl Default for PlayerModifiers {
@@ -481,6 +520,7 @@ impl Default for PlayerModifiers {
             capacitor_drain_multiplier: 1.0,
             maneuver_cooldown_multiplier: 1.0,
             invincibility_duration_multiplier: 1.0,
+            salt_miner_always: false,
         }
     }
 }
@@ -494,6 +534,8 @@ pub struct EnemyModifiers {
     pub speed_multiplier: f32,
     pub accuracy_multiplier: f32,
     pub spawn_rate_multiplier: f32,
+    /// Behaviors wave enemies are limited to (None = the wave's own mix)
+    pub wave_behaviors: Option<&'static [crate::entities::EnemyBehavior]>,
 }
 
 impl Default for EnemyModifiers {
@@ -505,6 +547,7 @@ impl Default for EnemyModifiers {
             speed_multiplier: 1.0,
             accuracy_multiplier: 1.0,
             spawn_rate_multiplier: 1.0,
+            wave_behaviors: None,
         }
     }
 }
@@ -574,6 +617,7 @@ impl DifficultySettings {
                     capacitor_drain_multiplier: 0.7,
                     maneuver_cooldown_multiplier: 0.7,
                     invincibility_duration_multiplier: 1.5,
+                    salt_miner_always: false,
                 },
                 enemy: EnemyModifiers {
                     health_multiplier: 0.7,
@@ -582,6 +626,7 @@ impl DifficultySettings {
                     speed_multiplier: 0.85,
                     accuracy_multiplier: 0.6,
                     spawn_rate_multiplier: 0.8,
+                    wave_behaviors: None,
                 },
                 boss: BossModifiers {
                     health_multiplier: 0.6,
@@ -611,6 +656,7 @@ impl DifficultySettings {
                     capacitor_drain_multiplier: 1.2,
                     maneuver_cooldown_multiplier: 1.2,
                     invincibility_duration_multiplier: 0.8,
+                    salt_miner_always: false,
                 },
                 enemy: EnemyModifiers {
                     health_multiplier: 1.3,
@@ -619,6 +665,7 @@ impl DifficultySettings {
                     speed_multiplier: 1.15,
                     accuracy_multiplier: 1.3,
                     spawn_rate_multiplier: 1.2,
+                    wave_behaviors: None,
                 },
                 boss: BossModifiers {
                     health_multiplier: 1.4,
@@ -641,6 +688,7 @@ impl DifficultySettings {
                     capacitor_drain_multiplier: 1.5,
                     maneuver_cooldown_multiplier: 1.4,
                     invincibility_duration_multiplier: 0.5,
+                    salt_miner_always: false,
                 },
                 enemy: EnemyModifiers {
                     health_multiplier: 1.5,
@@ -649,6 +697,7 @@ impl DifficultySettings {
                     speed_multiplier: 1.3,
                     accuracy_multiplier: 1.5,
                     spawn_rate_multiplier: 1.5,
+                    wave_behaviors: None,
                 },
                 boss: BossModifiers {
                     health_multiplier: 2.0,
@@ -772,6 +821,27 @@ impl EndlessMode {
     }
 }
 
+// =============================================================================
+// RANDOM NUMBERS
+// =============================================================================
+
+/// Random numbers for enemy spawning. `fastrand`'s global generator is per
+/// thread and systems hop threads, so seeded runs reseed this one instead.
+#[derive(Debug, Clone, Resource)]
+pub struct GameRng(pub fastrand::Rng);
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self(fastrand::Rng::new())
+    }
+}
+
+impl GameRng {
+    pub fn reseed(&mut self, seed: u64) {
+        self.0.seed(seed);
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::field_reassign_with_default)]
 mod tests {
@@ -789,22 +859,30 @@ mod tests {
     }
 
     #[test]
-    fn score_system_add_score_applies_multiplier() {
-        let mut s = ScoreSystem {
-            multiplier: 2.0,
+    fn score_system_award_adds_total() {
+        let mut s = ScoreSystem::default();
+        let state = crate::core::ScoreState {
+            chain_multiplier: 2.0,
             ..Default::default()
         };
-        s.add_score(100);
+        s.award(crate::core::ScoreBreakdown::new(
+            100,
+            crate::core::ScoreSource::Pickup,
+            crate::core::DEFAULT_SCORE_RULES,
+            &state,
+        ));
         assert_eq!(s.score, 200);
+        assert_eq!(s.last_award.as_ref().map(|a| a.base), Some(100));
     }
 
     #[test]
-    fn score_system_on_kill_extends_chain() {
+    fn score_system_register_kill_extends_chain() {
         let mut s = ScoreSystem::default();
-        s.on_kill(100);
+        s.register_kill();
         assert_eq!(s.chain, 1);
         assert_eq!(s.chain_timer, 2.5); // CHAIN_TIMEOUT
         assert_eq!(s.multiplier, 1.1); // 1.0 + 1 * 0.1
+        assert_eq!(s.chain_timer_percent(), 1.0);
     }
 
     #[test]
@@ -812,7 +890,7 @@ mod tests {
         let mut s = ScoreSystem::default();
         // Kill 1000 times to push multiplier
         for _ in 0..1000 {
-            s.on_kill(1);
+            s.register_kill();
         }
         assert!(s.multiplier <= 99.9);
         assert!(s.multiplier >= 99.0);
@@ -821,7 +899,7 @@ mod tests {
     #[test]
     fn score_system_chain_timer_decay_resets_chain() {
         let mut s = ScoreSystem::default();
-        s.on_kill(100);
+        s.register_kill();
         assert_eq!(s.chain, 1);
 
         // Simulate time passing (past CHAIN_TIMEOUT of 2.5s)
@@ -859,7 +937,7 @@ mod tests {
     #[test]
     fn score_system_reset_stage() {
         let mut s = ScoreSystem::default();
-        s.on_kill(100);
+        s.register_kill();
         s.score = 5000;
         s.no_damage_bonus = false;
 
@@ -875,7 +953,7 @@ mod tests {
     #[test]
     fn score_system_reset_game() {
         let mut s = ScoreSystem::default();
-        s.on_kill(100);
+        s.register_kill();
         s.score = 5000;
         s.souls_liberated = 42;
 
@@ -918,6 +996,17 @@ mod tests {
         assert!(gain_close > gain_far, "closer kills should give more meter");
     }
 
+    #[test]
+    fn salt_miner_grazes_fill_meter_until_active() {
+        let mut b = SaltMinerSystem::default();
+        assert_eq!(b.on_graze(), 1.0); // SALT_MINER_GAIN_PER_GRAZE
+        b.meter = 99.5;
+        assert_eq!(b.on_graze(), 0.5);
+        assert!(b.try_activate());
+        assert_eq!(b.on_graze(), 0.0);
+        assert_eq!(b.meter, 0.0);
+    }
+
     #[test]
     fn salt_miner_cannot_activate_when_meter_not_full() {
         let mut b = SaltMinerSystem::default();
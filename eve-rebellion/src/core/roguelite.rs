@@ -0,0 +1,829 @@
+//! Roguelite Runs
+//!
+//! Seeded sectors of encounter nodes (combat, elite, shop, event, boss),
+//! the pool of in-run upgrades and the meta-progression kept between runs.
+//! Everything here is plain data: the same seed always builds the same
+//! sector and the same encounters, so a run can be shared as its seed code.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::events::{AmmoType, Upgrade};
+use super::factions::Faction;
+use super::save::UpgradeBonuses;
+
+/// Columns in a sector; the last one is the boss
+pub const SECTOR_DEPTH: u32 = 8;
+/// Upgrades offered by a shop
+pub const SHOP_OFFERS: usize = 3;
+/// Damage bonus while faction ammo of the found type is loaded
+pub const FACTION_AMMO_BONUS: f32 = 1.2;
+
+// ============================================================================
+// Sector Map
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectorNodeKind {
+    Combat,
+    /// Tougher fight that drops a module
+    Elite,
+    Shop,
+    Event,
+    Boss,
+}
+
+impl SectorNodeKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SectorNodeKind::Combat => "COMBAT",
+            SectorNodeKind::Elite => "ELITE",
+            SectorNodeKind::Shop => "SHOP",
+            SectorNodeKind::Event => "EVENT",
+            SectorNodeKind::Boss => "BOSS",
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SectorNodeKind::Combat => "⚔",
+            SectorNodeKind::Elite => "☠",
+            SectorNodeKind::Shop => "$",
+            SectorNodeKind::Event => "?",
+            SectorNodeKind::Boss => "◈",
+        }
+    }
+
+    /// Played out in space rather than on the sector screen
+    pub fn is_fight(&self) -> bool {
+        matches!(
+            self,
+            SectorNodeKind::Combat | SectorNodeKind::Elite | SectorNodeKind::Boss
+        )
+    }
+}
+
+/// One encounter in the sector
+#[derive(Debug, Clone)]
+pub struct SectorNode {
+    pub kind: SectorNodeKind,
+    /// Column, 0 = entry
+    pub depth: u32,
+    /// Row within the column
+    pub lane: u32,
+    /// Who the player fights here
+    pub enemy_faction: Faction,
+    /// Nodes in the next column this one jumps to
+    pub next: Vec<usize>,
+}
+
+/// A generated sector
+#[derive(Debug, Clone, Default)]
+pub struct SectorMap {
+    pub seed: u32,
+    pub nodes: Vec<SectorNode>,
+}
+
+impl SectorMap {
+    /// Build the sector for a seed: three entry fights, 2-4 nodes per
+    /// column, a row of shops and events before a single boss
+    pub fn generate(seed: u32) -> Self {
+        let mut rng = fastrand::Rng::with_seed(seed as u64);
+        let mut nodes: Vec<SectorNode> = Vec::new();
+        let mut previous: Vec<usize> = Vec::new();
+
+        for depth in 0..SECTOR_DEPTH {
+            let width = match depth {
+                0 => 3,
+                d if d == SECTOR_DEPTH - 1 => 1,
+                _ => rng.u32(2..=4),
+            };
+
+            let mut column = Vec::new();
+            for lane in 0..width {
+                let kind = if depth == 0 {
+                    SectorNodeKind::Combat
+                } else if depth == SECTOR_DEPTH - 1 {
+                    SectorNodeKind::Boss
+                } else if depth == SECTOR_DEPTH - 2 {
+                    // Last stop before the boss: refit or gamble
+                    if lane % 2 == 0 {
+                        SectorNodeKind::Shop
+                    } else {
+                        SectorNodeKind::Event
+                    }
+                } else {
+                    roll_kind(&mut rng, depth)
+                };
+                let enemy_faction = Faction::all()[rng.usize(0..Faction::all().len())];
+                column.push(nodes.len());
+                nodes.push(SectorNode {
+                    kind,
+                    depth,
+                    lane,
+                    enemy_faction,
+                    next: Vec::new(),
+                });
+            }
+
+            link_columns(&mut nodes, &previous, &column, &mut rng);
+            previous = column;
+        }
+
+        Self { seed, nodes }
+    }
+
+    /// Node indices in a column, top to bottom
+    pub fn column(&self, depth: u32) -> Vec<usize> {
+        (0..self.nodes.len())
+            .filter(|&i| self.nodes[i].depth == depth)
+            .collect()
+    }
+
+    pub fn boss(&self) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|n| n.kind == SectorNodeKind::Boss)
+    }
+}
+
+fn roll_kind(rng: &mut fastrand::Rng, depth: u32) -> SectorNodeKind {
+    let roll = rng.u32(0..100);
+    match roll {
+        0..45 => SectorNodeKind::Combat,
+        // No elites in the first two columns
+        45..60 if depth >= 2 => SectorNodeKind::Elite,
+        45..60 => SectorNodeKind::Combat,
+        60..80 => SectorNodeKind::Event,
+        _ => SectorNodeKind::Shop,
+    }
+}
+
+/// Jump lines from one column to the next: each node links to the lane at
+/// the same height and sometimes a neighbor, and every node is reachable
+fn link_columns(nodes: &mut [SectorNode], from: &[usize], to: &[usize], rng: &mut fastrand::Rng) {
+    if from.is_empty() || to.is_empty() {
+        return;
+    }
+    let lane_at = |i: usize, width: usize, other: usize| -> usize {
+        (((i as f32 + 0.5) / width as f32) * other as f32) as usize
+    };
+
+    for (i, &node) in from.iter().enumerate() {
+        let target = lane_at(i, from.len(), to.len()).min(to.len() - 1);
+        nodes[node].next.push(to[target]);
+        if rng.bool() {
+            let neighbor = if rng.bool() {
+                target.checked_sub(1)
+            } else {
+                Some(target + 1).filter(|&t| t < to.len())
+            };
+            if let Some(neighbor) = neighbor {
+                nodes[node].next.push(to[neighbor]);
+            }
+        }
+    }
+
+    for (j, &node) in to.iter().enumerate() {
+        if !from.iter().any(|&f| nodes[f].next.contains(&node)) {
+            let source = lane_at(j, to.len(), from.len()).min(from.len() - 1);
+            nodes[from[source]].next.push(node);
+        }
+    }
+
+    for &node in from {
+        nodes[node].next.sort_unstable();
+        nodes[node].next.dedup();
+    }
+}
+
+/// Shareable form of a seed, e.g. `3F9A-11C2`
+pub fn seed_code(seed: u32) -> String {
+    format!("{:04X}-{:04X}", seed >> 16, seed & 0xFFFF)
+}
+
+/// Parse a seed code; dashes, spaces and case are ignored
+pub fn parse_seed_code(code: &str) -> Option<u32> {
+    let hex: String = code.chars().filter(|c| !matches!(c, '-' | ' ')).collect();
+    if hex.is_empty() || hex.len() > 8 {
+        return None;
+    }
+    u32::from_str_radix(&hex, 16).ok()
+}
+
+// ============================================================================
+// In-Run Upgrades
+// ============================================================================
+
+/// Upgrades found or bought during a run; lost when it ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunUpgrade {
+    /// A shop module fitted for this run only
+    Module(Upgrade),
+    /// Faction ammo: loaded at launch and hits harder while loaded
+    Ammo(AmmoType),
+    /// +50% hull
+    ReinforcedBulkheads,
+    /// Repairs a fifth of the structure after every cleared encounter
+    NanitePaste,
+    /// +50% credits from kills
+    SalvageDrones,
+}
+
+/// Everything a run can hand out
+pub const RUN_UPGRADE_POOL: &[RunUpgrade] = &[
+    RunUpgrade::Module(Upgrade::ShieldBoost1),
+    RunUpgrade::Module(Upgrade::ShieldBoost2),
+    RunUpgrade::Module(Upgrade::ArmorPlate1),
+    RunUpgrade::Module(Upgrade::ArmorPlate2),
+    RunUpgrade::Module(Upgrade::Gyrostabilizer1),
+    RunUpgrade::Module(Upgrade::Gyrostabilizer2),
+    RunUpgrade::Module(Upgrade::DamageAmplifier1),
+    RunUpgrade::Module(Upgrade::DamageAmplifier2),
+    RunUpgrade::Module(Upgrade::Afterburner),
+    RunUpgrade::Module(Upgrade::CapacitorBattery),
+    RunUpgrade::Module(Upgrade::ShieldBooster),
+    RunUpgrade::Ammo(AmmoType::EMP),
+    RunUpgrade::Ammo(AmmoType::Plasma),
+    RunUpgrade::Ammo(AmmoType::Fusion),
+    RunUpgrade::Ammo(AmmoType::Barrage),
+    RunUpgrade::ReinforcedBulkheads,
+    RunUpgrade::NanitePaste,
+    RunUpgrade::SalvageDrones,
+];
+
+impl RunUpgrade {
+    pub fn name(&self) -> String {
+        match self {
+            RunUpgrade::Module(upgrade) => upgrade.name().to_string(),
+            RunUpgrade::Ammo(ammo) => format!("Faction {} Ammo", ammo.full_name()),
+            RunUpgrade::ReinforcedBulkheads => "Reinforced Bulkheads".to_string(),
+            RunUpgrade::NanitePaste => "Nanite Repair Paste".to_string(),
+            RunUpgrade::SalvageDrones => "Salvage Drones".to_string(),
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            RunUpgrade::Module(upgrade) => upgrade.description().to_string(),
+            RunUpgrade::Ammo(ammo) => format!(
+                "Loaded at launch. +{:.0}% damage while {} is loaded.",
+                (FACTION_AMMO_BONUS - 1.0) * 100.0,
+                ammo.name()
+            ),
+            RunUpgrade::ReinforcedBulkheads => "+50% hull.".to_string(),
+            RunUpgrade::NanitePaste => "Repair 20% structure after every encounter.".to_string(),
+            RunUpgrade::SalvageDrones => "+50% credits from kills.".to_string(),
+        }
+    }
+
+    /// Shop price in credits
+    pub fn price(&self) -> u32 {
+        match self {
+            RunUpgrade::Module(upgrade) => 40 + upgrade.cost() * 10,
+            RunUpgrade::Ammo(_) => 60,
+            RunUpgrade::ReinforcedBulkheads => 90,
+            RunUpgrade::NanitePaste => 80,
+            RunUpgrade::SalvageDrones => 70,
+        }
+    }
+}
+
+/// Draw up to `count` different upgrades the run does not have yet
+pub fn draw_run_upgrades(
+    rng: &mut fastrand::Rng,
+    count: usize,
+    owned: &[RunUpgrade],
+) -> Vec<RunUpgrade> {
+    let mut candidates: Vec<RunUpgrade> = RUN_UPGRADE_POOL
+        .iter()
+        .copied()
+        .filter(|u| !owned.contains(u))
+        .collect();
+    rng.shuffle(&mut candidates);
+    candidates.truncate(count);
+    candidates
+}
+
+/// Stat bonuses of the run's modules and ammo, on top of the save's
+pub fn run_bonuses(upgrades: &[RunUpgrade]) -> UpgradeBonuses {
+    let mut bonuses = UpgradeBonuses::new();
+    for upgrade in upgrades {
+        if let RunUpgrade::Module(module) = upgrade {
+            bonuses.apply(*module);
+        }
+    }
+    bonuses
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// What picking an event option does
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventOutcome {
+    pub credits: i32,
+    /// Structure change as a fraction of the maximum
+    pub structure: f32,
+    /// Draw a random upgrade
+    pub upgrade: bool,
+}
+
+/// Non-combat encounters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectorEvent {
+    DerelictCache,
+    RepairOutpost,
+    SmugglerDeal,
+    DistressBeacon,
+}
+
+impl SectorEvent {
+    pub fn all() -> &'static [SectorEvent] {
+        &[
+            SectorEvent::DerelictCache,
+            SectorEvent::RepairOutpost,
+            SectorEvent::SmugglerDeal,
+            SectorEvent::DistressBeacon,
+        ]
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            SectorEvent::DerelictCache => "DERELICT CACHE",
+            SectorEvent::RepairOutpost => "REPAIR OUTPOST",
+            SectorEvent::SmugglerDeal => "SMUGGLER'S DEAL",
+            SectorEvent::DistressBeacon => "DISTRESS BEACON",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            SectorEvent::DerelictCache => {
+                "A wrecked hauler drifts in the dark. Its cargo hold may be rigged."
+            }
+            SectorEvent::RepairOutpost => "An abandoned outpost still has power in its repair bay.",
+            SectorEvent::SmugglerDeal => "A smuggler offers a sealed crate. No questions asked.",
+            SectorEvent::DistressBeacon => {
+                "A capsuleer is pinned down by rats and begging for help."
+            }
+        }
+    }
+
+    /// The two options: label and outcome
+    pub fn options(&self) -> [(&'static str, EventOutcome); 2] {
+        let outcome = |credits, structure, upgrade| EventOutcome {
+            credits,
+            structure,
+            upgrade,
+        };
+        match self {
+            SectorEvent::DerelictCache => [
+                ("Crack the hold open", outcome(0, -0.15, true)),
+                ("Salvage the hull", outcome(60, 0.0, false)),
+            ],
+            SectorEvent::RepairOutpost => [
+                ("Dock for repairs", outcome(0, 0.4, false)),
+                ("Strip it for parts", outcome(100, -0.1, false)),
+            ],
+            SectorEvent::SmugglerDeal => [
+                ("Buy the crate (80 ISK)", outcome(-80, 0.0, true)),
+                ("Turn them in", outcome(40, 0.0, false)),
+            ],
+            SectorEvent::DistressBeacon => [
+                ("Answer the call", outcome(50, -0.25, true)),
+                ("Keep flying", outcome(0, 0.0, false)),
+            ],
+        }
+    }
+}
+
+// ============================================================================
+// Run State
+// ============================================================================
+
+/// How a finished run went
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunOutcome {
+    pub seed: u32,
+    pub won: bool,
+    /// Encounters cleared
+    pub depth: u32,
+    /// Meta currency earned
+    pub salvage: u32,
+}
+
+/// The run in progress
+#[derive(Resource, Debug, Clone)]
+pub struct RogueliteRun {
+    pub active: bool,
+    pub map: SectorMap,
+    /// Last cleared node; `None` before the first jump
+    pub position: Option<usize>,
+    /// Node being played
+    pub current: Option<usize>,
+    pub cleared: Vec<usize>,
+    pub upgrades: Vec<RunUpgrade>,
+    /// In-run currency from kills and events, spent in shops
+    pub credits: u32,
+    /// Armor and hull left, as a fraction carried between encounters
+    pub structure: f32,
+    /// Ship picked for the run (first launch goes through ship select)
+    pub ship_chosen: bool,
+    pub kills: u32,
+    /// Result of the last finished run
+    pub outcome: Option<RunOutcome>,
+}
+
+impl Default for RogueliteRun {
+    fn default() -> Self {
+        Self {
+            active: false,
+            map: SectorMap::default(),
+            position: None,
+            current: None,
+            cleared: Vec::new(),
+            upgrades: Vec::new(),
+            credits: 0,
+            structure: 1.0,
+            ship_chosen: false,
+            kills: 0,
+            outcome: None,
+        }
+    }
+}
+
+impl RogueliteRun {
+    /// Start a run on a seed with the meta unlocks bought so far
+    pub fn start(&mut self, seed: u32, unlocks: &HashSet<MetaUnlock>) {
+        let outcome = self.outcome.take();
+        *self = Self {
+            active: true,
+            map: SectorMap::generate(seed),
+            outcome,
+            ..default()
+        };
+        if unlocks.contains(&MetaUnlock::WarChest) {
+            self.credits += 100;
+        }
+        if unlocks.contains(&MetaUnlock::FittedHangar) {
+            let mut rng = self.node_rng(usize::MAX);
+            self.upgrades.extend(draw_run_upgrades(&mut rng, 1, &[]));
+        }
+    }
+
+    pub fn seed(&self) -> u32 {
+        self.map.seed
+    }
+
+    /// Nodes the player can jump to next
+    pub fn choices(&self) -> Vec<usize> {
+        match self.position {
+            None => self.map.column(0),
+            Some(node) => self.map.nodes[node].next.clone(),
+        }
+    }
+
+    /// Jump to a node; false if it is not reachable from here
+    pub fn enter(&mut self, node: usize) -> bool {
+        if !self.active || !self.choices().contains(&node) {
+            return false;
+        }
+        self.current = Some(node);
+        true
+    }
+
+    pub fn current_node(&self) -> Option<&SectorNode> {
+        self.current.and_then(|i| self.map.nodes.get(i))
+    }
+
+    /// Mark the current node cleared; returns it
+    pub fn clear_current(&mut self) -> Option<usize> {
+        let node = self.current.take()?;
+        self.position = Some(node);
+        self.cleared.push(node);
+        if self.has(RunUpgrade::NanitePaste) {
+            self.repair(0.2);
+        }
+        Some(node)
+    }
+
+    pub fn depth(&self) -> u32 {
+        self.cleared.len() as u32
+    }
+
+    pub fn has(&self, upgrade: RunUpgrade) -> bool {
+        self.upgrades.contains(&upgrade)
+    }
+
+    /// Faction ammo types found this run
+    pub fn has_ammo(&self, ammo: AmmoType) -> bool {
+        self.has(RunUpgrade::Ammo(ammo))
+    }
+
+    pub fn add_upgrade(&mut self, upgrade: RunUpgrade) {
+        if !self.has(upgrade) {
+            self.upgrades.push(upgrade);
+        }
+    }
+
+    pub fn add_credits(&mut self, amount: u32) {
+        let amount = if self.has(RunUpgrade::SalvageDrones) {
+            amount * 3 / 2
+        } else {
+            amount
+        };
+        self.credits += amount;
+    }
+
+    /// Buy an upgrade; false if it is owned or too expensive
+    pub fn buy(&mut self, upgrade: RunUpgrade) -> bool {
+        if self.has(upgrade) || self.credits < upgrade.price() {
+            return false;
+        }
+        self.credits -= upgrade.price();
+        self.upgrades.push(upgrade);
+        true
+    }
+
+    pub fn repair(&mut self, amount: f32) {
+        self.structure = (self.structure + amount).clamp(0.05, 1.0);
+    }
+
+    /// Apply an event option; the drawn upgrade (if any) is returned
+    pub fn apply_event(&mut self, node: usize, outcome: EventOutcome) -> Option<RunUpgrade> {
+        if outcome.credits >= 0 {
+            self.credits += outcome.credits as u32;
+        } else {
+            self.credits = self.credits.saturating_sub(outcome.credits.unsigned_abs());
+        }
+        self.repair(outcome.structure);
+        if !outcome.upgrade {
+            return None;
+        }
+        let mut rng = self.node_rng(node);
+        let upgrade = draw_run_upgrades(&mut rng, 1, &self.upgrades).pop()?;
+        self.upgrades.push(upgrade);
+        Some(upgrade)
+    }
+
+    /// Shop stock at a node
+    pub fn shop_offers(&self, node: usize, count: usize) -> Vec<RunUpgrade> {
+        let mut rng = self.node_rng(node);
+        draw_run_upgrades(&mut rng, count, &[])
+    }
+
+    /// Event at a node
+    pub fn event_at(&self, node: usize) -> SectorEvent {
+        let mut rng = self.node_rng(node);
+        SectorEvent::all()[rng.usize(0..SectorEvent::all().len())]
+    }
+
+    /// RNG for everything inside one node, so encounters follow the seed
+    pub fn node_rng(&self, node: usize) -> fastrand::Rng {
+        fastrand::Rng::with_seed(((self.seed() as u64) << 32) ^ node as u64)
+    }
+
+    /// End the run and work out its salvage
+    pub fn finish(&mut self, won: bool) -> RunOutcome {
+        let depth = self.depth();
+        let outcome = RunOutcome {
+            seed: self.seed(),
+            won,
+            depth,
+            salvage: depth * 5 + self.kills / 10 + if won { 50 } else { 0 },
+        };
+        self.active = false;
+        self.current = None;
+        self.outcome = Some(outcome);
+        outcome
+    }
+}
+
+// ============================================================================
+// Meta-Progression
+// ============================================================================
+
+/// Permanent unlocks bought with salvage between runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MetaUnlock {
+    /// Start every run with 100 credits
+    WarChest,
+    /// Start every run with a random module fitted
+    FittedHangar,
+    /// Shops stock one more upgrade
+    BrokerContacts,
+    /// Elites drop two upgrades
+    EliteSalvage,
+}
+
+impl MetaUnlock {
+    pub fn all() -> &'static [MetaUnlock] {
+        &[
+            MetaUnlock::WarChest,
+            MetaUnlock::FittedHangar,
+            MetaUnlock::BrokerContacts,
+            MetaUnlock::EliteSalvage,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MetaUnlock::WarChest => "War Chest",
+            MetaUnlock::FittedHangar => "Fitted Hangar",
+            MetaUnlock::BrokerContacts => "Broker Contacts",
+            MetaUnlock::EliteSalvage => "Elite Salvage",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            MetaUnlock::WarChest => "Start every run with 100 credits.",
+            MetaUnlock::FittedHangar => "Start every run with a random module fitted.",
+            MetaUnlock::BrokerContacts => "Shops stock one more upgrade.",
+            MetaUnlock::EliteSalvage => "Elite encounters drop two upgrades.",
+        }
+    }
+
+    /// Cost in salvage
+    pub fn cost(&self) -> u32 {
+        match self {
+            MetaUnlock::WarChest => 40,
+            MetaUnlock::FittedHangar => 80,
+            MetaUnlock::BrokerContacts => 60,
+            MetaUnlock::EliteSalvage => 100,
+        }
+    }
+}
+
+/// Roguelite progress kept in the save
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RogueliteProgress {
+    /// Unspent meta currency
+    pub salvage: u32,
+    pub unlocks: HashSet<MetaUnlock>,
+    pub runs: u32,
+    pub wins: u32,
+    pub best_depth: u32,
+}
+
+impl RogueliteProgress {
+    /// Bank a finished run
+    pub fn record(&mut self, outcome: &RunOutcome) {
+        self.runs += 1;
+        if outcome.won {
+            self.wins += 1;
+        }
+        self.best_depth = self.best_depth.max(outcome.depth);
+        self.salvage += outcome.salvage;
+    }
+
+    /// Buy an unlock (returns true if successful)
+    pub fn purchase(&mut self, unlock: MetaUnlock) -> bool {
+        if self.unlocks.contains(&unlock) || self.salvage < unlock.cost() {
+            return false;
+        }
+        self.salvage -= unlock.cost();
+        self.unlocks.insert(unlock);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sectors_follow_the_seed() {
+        let a = SectorMap::generate(0x3F9A_11C2);
+        let b = SectorMap::generate(0x3F9A_11C2);
+        let kinds = |m: &SectorMap| {
+            m.nodes
+                .iter()
+                .map(|n| (n.kind, n.next.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(kinds(&a), kinds(&b));
+        assert_ne!(kinds(&a), kinds(&SectorMap::generate(7)));
+    }
+
+    #[test]
+    fn every_node_is_reachable_and_ends_at_the_boss() {
+        for seed in 0..50 {
+            let map = SectorMap::generate(seed);
+            assert_eq!(map.column(0).len(), 3);
+            assert_eq!(map.column(SECTOR_DEPTH - 1).len(), 1);
+            let boss = map.boss().unwrap();
+            assert_eq!(map.nodes[boss].depth, SECTOR_DEPTH - 1);
+
+            for (i, node) in map.nodes.iter().enumerate() {
+                if node.depth > 0 {
+                    assert!(
+                        map.nodes.iter().any(|n| n.next.contains(&i)),
+                        "seed {}",
+                        seed
+                    );
+                }
+                if node.depth < SECTOR_DEPTH - 1 {
+                    assert!(!node.next.is_empty());
+                    assert!(node
+                        .next
+                        .iter()
+                        .all(|&n| map.nodes[n].depth == node.depth + 1));
+                }
+                if node.depth < 2 {
+                    assert_ne!(node.kind, SectorNodeKind::Elite);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn seed_codes_round_trip() {
+        assert_eq!(seed_code(0x3F9A_11C2), "3F9A-11C2");
+        assert_eq!(parse_seed_code("3f9a-11c2"), Some(0x3F9A_11C2));
+        assert_eq!(parse_seed_code("  beef "), Some(0xBEEF));
+        assert_eq!(parse_seed_code("XYZ"), None);
+        assert_eq!(parse_seed_code("123456789"), None);
+        assert_eq!(parse_seed_code(""), None);
+    }
+
+    #[test]
+    fn run_moves_along_jump_lines() {
+        let mut run = RogueliteRun::default();
+        assert!(!run.enter(0));
+        run.start(42, &HashSet::new());
+        assert_eq!(run.choices(), run.map.column(0));
+
+        let deep = run.map.column(2)[0];
+        assert!(!run.enter(deep));
+        let first = run.choices()[0];
+        assert!(run.enter(first));
+        assert_eq!(run.clear_current(), Some(first));
+        assert_eq!(run.choices(), run.map.nodes[first].next);
+        assert_eq!(run.depth(), 1);
+
+        run.kills = 25;
+        let outcome = run.finish(false);
+        assert!(!run.active);
+        assert_eq!(outcome.salvage, 5 + 2);
+        assert_eq!(run.outcome, Some(outcome));
+    }
+
+    #[test]
+    fn shops_events_and_credits() {
+        let mut run = RogueliteRun::default();
+        run.start(9, &[MetaUnlock::WarChest].into_iter().collect());
+        assert_eq!(run.credits, 100);
+        assert_eq!(
+            run.shop_offers(4, SHOP_OFFERS),
+            run.shop_offers(4, SHOP_OFFERS)
+        );
+
+        let cheap = RunUpgrade::Ammo(AmmoType::EMP);
+        assert!(run.buy(cheap));
+        assert!(!run.buy(cheap));
+        assert_eq!(run.credits, 40);
+
+        run.add_upgrade(RunUpgrade::SalvageDrones);
+        run.add_credits(10);
+        assert_eq!(run.credits, 55);
+
+        run.structure = 0.5;
+        let drawn = run.apply_event(
+            3,
+            EventOutcome {
+                credits: -80,
+                structure: -0.25,
+                upgrade: true,
+            },
+        );
+        assert_eq!(run.credits, 0);
+        assert!((run.structure - 0.25).abs() < 1e-6);
+        assert!(drawn.is_some_and(|u| run.has(u)));
+    }
+
+    #[test]
+    fn meta_unlocks_cost_salvage() {
+        let mut progress = RogueliteProgress::default();
+        assert!(!progress.purchase(MetaUnlock::WarChest));
+        progress.record(&RunOutcome {
+            seed: 1,
+            won: true,
+            depth: 8,
+            salvage: 90,
+        });
+        assert_eq!(
+            (progress.runs, progress.wins, progress.best_depth),
+            (1, 1, 8)
+        );
+        assert!(progress.purchase(MetaUnlock::WarChest));
+        assert!(!progress.purchase(MetaUnlock::WarChest));
+        assert_eq!(progress.salvage, 50);
+        assert!(run_bonuses(&[RunUpgrade::Module(Upgrade::DamageAmplifier1)]).damage_mult > 1.0);
+    }
+}
@@ -0,0 +1,368 @@
+//! Run Statistics
+//!
+//! What happened in one mission: shots and hits, damage dealt and taken by
+//! damage type and layer, kills per enemy type, pickups, ability uses,
+//! style medals, combo peaks and a timeline in fixed buckets. `RunStats` is filled from
+//! gameplay events while a run is active; finishing it produces a
+//! `RunSummary` for the debrief screen and the pilot's run history.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use bevy::utils::SystemTime;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::events::{DamageLayer, DamageType};
+
+/// Seconds covered by one timeline bucket
+pub const TIMELINE_BUCKET_SECS: f32 = 5.0;
+
+/// Log the run history is appended to (see `profiles::append_log`)
+pub const RUN_HISTORY_LOG: &str = "history";
+
+/// How a run ended
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RunResult {
+    /// Mission complete or campaign victory
+    Completed,
+    /// Player ship destroyed
+    Failed,
+    /// Left play without a result (quit, or a module's own flow)
+    #[default]
+    Left,
+}
+
+impl RunResult {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RunResult::Completed => "COMPLETED",
+            RunResult::Failed => "FAILED",
+            RunResult::Left => "ENDED",
+        }
+    }
+}
+
+/// Activity in one timeline bucket
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct TimelineBucket {
+    pub kills: u32,
+    pub damage_dealt: f32,
+    pub damage_taken: f32,
+}
+
+fn layer_index(layer: DamageLayer) -> usize {
+    match layer {
+        DamageLayer::Shield => 0,
+        DamageLayer::Armor => 1,
+        DamageLayer::Hull => 2,
+    }
+}
+
+/// Stats of the run in progress
+#[derive(Resource, Clone, Debug, Default)]
+pub struct RunStats {
+    /// Collecting events
+    pub active: bool,
+    /// Seconds of play
+    pub elapsed: f32,
+    pub shots_fired: u32,
+    pub shots_hit: u32,
+    pub crits: u32,
+    /// Damage dealt per `DamageType::index`
+    pub damage_dealt: [f32; 4],
+    /// Damage dealt per enemy layer (shield, armor, hull)
+    pub damage_dealt_by_layer: [f32; 3],
+    /// Incoming damage per `DamageType::index`, before resists
+    pub damage_taken: [f32; 4],
+    /// Damage absorbed per own layer (shield, armor, hull)
+    pub damage_taken_by_layer: [f32; 3],
+    pub kills: BTreeMap<String, u32>,
+    pub bosses_killed: u32,
+    pub pickups: BTreeMap<String, u32>,
+    pub ability_uses: BTreeMap<String, u32>,
+    /// Medals earned per `Medal::name`
+    pub medals: BTreeMap<String, u32>,
+    pub peak_chain: u32,
+    pub peak_multiplier: f32,
+    pub timeline: Vec<TimelineBucket>,
+    /// Summary of the last finished run (for the debrief)
+    pub last_summary: Option<RunSummary>,
+}
+
+impl RunStats {
+    /// Clear counters and start collecting
+    pub fn start(&mut self) {
+        let last_summary = self.last_summary.take();
+        *self = Self {
+            active: true,
+            last_summary,
+            ..default()
+        };
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        self.elapsed += dt;
+    }
+
+    /// Bucket for the current moment, growing the timeline as needed
+    fn bucket(&mut self) -> &mut TimelineBucket {
+        let index = (self.elapsed / TIMELINE_BUCKET_SECS) as usize;
+        if self.timeline.len() <= index {
+            self.timeline.resize(index + 1, TimelineBucket::default());
+        }
+        &mut self.timeline[index]
+    }
+
+    pub fn record_shots(&mut self, count: u32) {
+        self.shots_fired += count.max(1);
+    }
+
+    pub fn record_hit(
+        &mut self,
+        damage: f32,
+        damage_type: DamageType,
+        layer: Option<DamageLayer>,
+        crit: bool,
+    ) {
+        self.shots_hit += 1;
+        if crit {
+            self.crits += 1;
+        }
+        self.damage_dealt[damage_type.index()] += damage;
+        // Enemies without resists are a single armor-like pool
+        self.damage_dealt_by_layer[layer_index(layer.unwrap_or(DamageLayer::Armor))] += damage;
+        self.bucket().damage_dealt += damage;
+    }
+
+    pub fn record_kill(&mut self, enemy: &str, boss: bool) {
+        *self.kills.entry(enemy.to_string()).or_default() += 1;
+        if boss {
+            self.bosses_killed += 1;
+        }
+        self.bucket().kills += 1;
+    }
+
+    pub fn record_damage_taken(&mut self, damage: f32, damage_type: DamageType) {
+        self.damage_taken[damage_type.index()] += damage;
+    }
+
+    pub fn record_layer_damage(&mut self, layer: DamageLayer, damage: f32) {
+        self.damage_taken_by_layer[layer_index(layer)] += damage;
+        self.bucket().damage_taken += damage;
+    }
+
+    pub fn record_pickup(&mut self, pickup: &str) {
+        *self.pickups.entry(pickup.to_string()).or_default() += 1;
+    }
+
+    pub fn record_ability(&mut self, ability: &str) {
+        *self.ability_uses.entry(ability.to_string()).or_default() += 1;
+    }
+
+    pub fn record_medal(&mut self, medal: &str) {
+        *self.medals.entry(medal.to_string()).or_default() += 1;
+    }
+
+    pub fn record_combo(&mut self, chain: u32, multiplier: f32) {
+        self.peak_chain = self.peak_chain.max(chain);
+        self.peak_multiplier = self.peak_multiplier.max(multiplier);
+    }
+
+    /// Hits per shot, 0.0 - 1.0 (hits can outnumber shots with piercing fire)
+    pub fn accuracy(&self) -> f32 {
+        if self.shots_fired == 0 {
+            0.0
+        } else {
+            (self.shots_hit as f32 / self.shots_fired as f32).min(1.0)
+        }
+    }
+
+    pub fn total_kills(&self) -> u32 {
+        self.kills.values().sum()
+    }
+
+    /// Stop collecting and summarize; the summary is kept for the debrief
+    pub fn finish(&mut self, outcome: RunResult, context: RunContext) -> RunSummary {
+        self.active = false;
+        let summary = RunSummary {
+            timestamp: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            mission: context.mission,
+            faction: context.faction,
+            ship: context.ship,
+            outcome,
+            score: context.score,
+            duration: self.elapsed,
+            shots_fired: self.shots_fired,
+            shots_hit: self.shots_hit,
+            crits: self.crits,
+            accuracy: self.accuracy(),
+            damage_dealt: self.damage_dealt,
+            damage_dealt_by_layer: self.damage_dealt_by_layer,
+            damage_taken: self.damage_taken,
+            damage_taken_by_layer: self.damage_taken_by_layer,
+            kills: self.kills.clone(),
+            bosses_killed: self.bosses_killed,
+            pickups: self.pickups.clone(),
+            ability_uses: self.ability_uses.clone(),
+            medals: self.medals.clone(),
+            peak_chain: self.peak_chain,
+            peak_multiplier: self.peak_multiplier,
+            timeline: self.timeline.clone(),
+        };
+        self.last_summary = Some(summary.clone());
+        summary
+    }
+}
+
+/// A run ended; sent once its summary is recorded
+#[derive(Event, Clone, Debug)]
+pub struct RunFinishedEvent {
+    pub summary: RunSummary,
+    /// Ended on the victory screen (campaign or mode finished)
+    pub victory: bool,
+}
+
+/// What the run was, filled in by whoever finishes it
+#[derive(Clone, Debug, Default)]
+pub struct RunContext {
+    pub mission: String,
+    pub faction: String,
+    pub ship: String,
+    pub score: u64,
+}
+
+/// A finished run, as shown in the debrief and stored in the run history
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RunSummary {
+    /// Unix seconds when the run ended
+    pub timestamp: u64,
+    pub mission: String,
+    pub faction: String,
+    pub ship: String,
+    pub outcome: RunResult,
+    pub score: u64,
+    pub duration: f32,
+    pub shots_fired: u32,
+    pub shots_hit: u32,
+    pub crits: u32,
+    pub accuracy: f32,
+    pub damage_dealt: [f32; 4],
+    pub damage_dealt_by_layer: [f32; 3],
+    pub damage_taken: [f32; 4],
+    pub damage_taken_by_layer: [f32; 3],
+    pub kills: BTreeMap<String, u32>,
+    pub bosses_killed: u32,
+    pub pickups: BTreeMap<String, u32>,
+    pub ability_uses: BTreeMap<String, u32>,
+    /// Older history lines have none
+    #[serde(default)]
+    pub medals: BTreeMap<String, u32>,
+    pub peak_chain: u32,
+    pub peak_multiplier: f32,
+    pub timeline: Vec<TimelineBucket>,
+}
+
+impl RunSummary {
+    pub fn total_kills(&self) -> u32 {
+        self.kills.values().sum()
+    }
+
+    pub fn total_dealt(&self) -> f32 {
+        self.damage_dealt.iter().sum()
+    }
+
+    pub fn total_taken(&self) -> f32 {
+        self.damage_taken_by_layer.iter().sum()
+    }
+
+    /// Timeline merged down to at most `max` buckets for charting
+    pub fn compact_timeline(&self, max: usize) -> Vec<TimelineBucket> {
+        if max == 0 || self.timeline.len() <= max {
+            return self.timeline.clone();
+        }
+        let per = self.timeline.len().div_ceil(max);
+        self.timeline
+            .chunks(per)
+            .map(|chunk| TimelineBucket {
+                kills: chunk.iter().map(|b| b.kills).sum(),
+                damage_dealt: chunk.iter().map(|b| b.damage_dealt).sum(),
+                damage_taken: chunk.iter().map(|b| b.damage_taken).sum(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_hits_kills_and_timeline() {
+        let mut stats = RunStats::default();
+        stats.start();
+        stats.record_shots(3);
+        stats.record_hit(10.0, DamageType::EM, Some(DamageLayer::Shield), true);
+        stats.tick(TIMELINE_BUCKET_SECS * 2.5);
+        stats.record_hit(5.0, DamageType::Kinetic, None, false);
+        stats.record_kill("Punisher", false);
+        stats.record_kill("Punisher", false);
+        stats.record_layer_damage(DamageLayer::Armor, 4.0);
+
+        assert_eq!(stats.shots_hit, 2);
+        assert!((stats.accuracy() - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(stats.damage_dealt_by_layer, [10.0, 5.0, 0.0]);
+        assert_eq!(stats.kills["Punisher"], 2);
+        assert_eq!(stats.timeline.len(), 3);
+        assert_eq!(stats.timeline[0].damage_dealt, 10.0);
+        assert_eq!(stats.timeline[2].kills, 2);
+        assert_eq!(stats.timeline[2].damage_taken, 4.0);
+    }
+
+    #[test]
+    fn finish_keeps_summary_across_restart() {
+        let mut stats = RunStats::default();
+        stats.start();
+        stats.record_medal("GRAZE");
+        stats.record_combo(12, 3.5);
+        stats.record_combo(4, 1.0);
+        let summary = stats.finish(
+            RunResult::Failed,
+            RunContext {
+                score: 900,
+                ..default()
+            },
+        );
+        assert!(!stats.active);
+        assert_eq!(summary.peak_chain, 12);
+        assert_eq!(summary.score, 900);
+        assert_eq!(summary.medals["GRAZE"], 1);
+
+        stats.start();
+        assert_eq!(stats.peak_chain, 0);
+        assert_eq!(stats.last_summary.as_ref(), Some(&summary));
+
+        let line = serde_json::to_string(&summary).unwrap();
+        assert_eq!(serde_json::from_str::<RunSummary>(&line).unwrap(), summary);
+    }
+
+    #[test]
+    fn compact_timeline_merges_buckets() {
+        let summary = RunSummary {
+            timeline: (0..10)
+                .map(|i| TimelineBucket {
+                    kills: i,
+                    ..default()
+                })
+                .collect(),
+            ..default()
+        };
+        let compact = summary.compact_timeline(4);
+        assert_eq!(compact.len(), 4);
+        assert_eq!(compact.iter().map(|b| b.kills).sum::<u32>(), 45);
+        assert_eq!(summary.compact_timeline(20).len(), 10);
+    }
+}
@@ -1,17 +1,15 @@
 //! Save/Load System
 //!
-//! Persists player progression, unlocks, and settings.
+//! Persists player progression, unlocks, and settings. Each pilot profile
+//! has its own `SaveData`; see `profiles` for where it is stored.
 
 #![allow(dead_code)]
 
+use crate::core::accessibility::AccessibilitySettings;
 use crate::systems::{RumbleSettings, ScreenShake, SoundSettings};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
-#[cfg(not(target_arch = "wasm32"))]
-use std::fs;
-#[cfg(not(target_arch = "wasm32"))]
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
 
 /// Save system plugin
 pub struct SavePlugin;
@@ -19,9 +17,15 @@ pub struct SavePlugin;
 impl Plugin for SavePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SaveData>()
+            .init_resource::<super::PilotProfiles>()
             .add_systems(Startup, load_save_data)
             .add_systems(PostStartup, apply_saved_settings)
             .add_systems(Update, auto_save.run_if(resource_changed::<SaveData>))
+            // Switching pilots swaps in their settings
+            .add_systems(
+                Update,
+                apply_saved_settings.run_if(resource_changed::<super::PilotProfiles>),
+            )
             .add_systems(Update, sync_settings_to_save);
     }
 }
@@ -39,18 +43,34 @@ pub struct SaveData {
     pub high_scores: Vec<HighScore>,
     /// Settings
     pub settings: GameSettings,
-    /// Unlocked achievements
+    /// Unlocked achievement ids
     #[serde(default)]
-    pub achievements: HashSet<super::Achievement>,
+    pub achievements: HashSet<String>,
+    /// Achievement stats: lifetime totals and best mission/run values
+    #[serde(default)]
+    pub achievement_stats: HashMap<String, u64>,
     /// Lifetime statistics
     #[serde(default)]
     pub lifetime_stats: LifetimeStats,
     /// Available skill points (persistent currency)
     #[serde(default)]
     pub skill_points: u32,
-    /// Purchased upgrades
+    /// Shop upgrades from before the skill tree; `migrate_upgrades` turns
+    /// them into skills on load
     #[serde(default)]
     pub purchased_upgrades: HashSet<super::Upgrade>,
+    /// Trained skills
+    #[serde(default)]
+    pub skills: super::SkillSheet,
+    /// Roguelite salvage, unlocks and run records
+    #[serde(default)]
+    pub roguelite: super::RogueliteProgress,
+    /// Module fittings per ship (by type_id)
+    #[serde(default)]
+    pub fittings: HashMap<u32, super::ShipFitting>,
+    /// Challenges already attempted (`Challenge::key`)
+    #[serde(default)]
+    pub challenge_attempts: HashSet<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
@@ -59,6 +79,12 @@ pub struct FactionProgress {
     pub enemy_faction: String,
     pub highest_stage: u32,
     pub highest_mission: u32,
+    /// Campaign map nodes cleared (mission ids)
+    #[serde(default)]
+    pub completed_nodes: HashSet<String>,
+    /// Branch nodes committed to; each closes its alternatives
+    #[serde(default)]
+    pub choices: HashSet<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
@@ -88,7 +114,7 @@ pub struct LifetimeStats {
     pub highest_score: u64,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct GameSettings {
     pub master_volume: f32,
     pub sfx_volume: f32,
@@ -99,6 +125,22 @@ pub struct GameSettings {
     /// Controller rumble intensity (0.0 = off, 1.0 = full)
     #[serde(default = "default_rumble_intensity")]
     pub rumble_intensity: f32,
+    /// Mixer sub-bus volumes
+    #[serde(default = "default_bus_volume")]
+    pub weapons_volume: f32,
+    #[serde(default = "default_bus_volume")]
+    pub explosions_volume: f32,
+    #[serde(default = "default_bus_volume")]
+    pub ui_volume: f32,
+    #[serde(default = "default_bus_volume")]
+    pub warnings_volume: f32,
+    /// Colorblind palette, reduced flashing, text scale, fire mode, game speed
+    #[serde(default)]
+    pub accessibility: AccessibilitySettings,
+    /// Shared leaderboard location: an `http(s)://` server or a folder
+    /// (blank keeps boards local)
+    #[serde(default)]
+    pub leaderboard_server: String,
 }
 
 fn default_shake_intensity() -> f32 {
@@ -109,6 +151,10 @@ fn default_rumble_intensity() -> f32 {
     1.0
 }
 
+fn default_bus_volume() -> f32 {
+    1.0
+}
+
 impl Default for GameSettings {
     fn default() -> Self {
         Self {
@@ -117,129 +163,18 @@ impl Default for GameSettings {
             music_volume: 0.5,
             screen_shake_intensity: 1.0,
             rumble_intensity: 1.0,
+            weapons_volume: 1.0,
+            explosions_volume: 1.0,
+            ui_volume: 1.0,
+            warnings_volume: 1.0,
+            accessibility: AccessibilitySettings::default(),
+            leaderboard_server: String::new(),
         }
     }
 }
 
 impl SaveData {
-    /// Get save file path (native only)
-    #[cfg(not(target_arch = "wasm32"))]
-    fn save_path() -> PathBuf {
-        dirs::data_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join("eve_rebellion")
-            .join("save.json")
-    }
-
-    /// Load from disk (native)
-    #[cfg(not(target_arch = "wasm32"))]
-    pub fn load() -> Self {
-        let path = Self::save_path();
-        if path.exists() {
-            match fs::read_to_string(&path) {
-                Ok(data) => match serde_json::from_str(&data) {
-                    Ok(save) => {
-                        info!("Loaded save data from {:?}", path);
-                        return save;
-                    }
-                    Err(e) => warn!("Failed to parse save data: {}", e),
-                },
-                Err(e) => warn!("Failed to read save file: {}", e),
-            }
-        }
-        info!("No save data found, using defaults");
-        Self::default()
-    }
-
-    /// Load from localStorage (WASM)
-    #[cfg(target_arch = "wasm32")]
-    pub fn load() -> Self {
-        use web_sys::window;
-
-        if let Some(window) = window() {
-            if let Ok(Some(storage)) = window.local_storage() {
-                if let Ok(Some(data)) = storage.get_item("eve_rebellion_save") {
-                    if let Ok(save) = serde_json::from_str(&data) {
-                        info!("Loaded save data from localStorage");
-                        return save;
-                    }
-                }
-            }
-        }
-        info!("No save data found, using defaults");
-        Self::default()
-    }
-
-    /// Save to disk (native)
-    #[cfg(not(target_arch = "wasm32"))]
-    pub fn save(&self) {
-        let path = Self::save_path();
-
-        // Create directory if needed
-        if let Some(parent) = path.parent() {
-            if let Err(e) = fs::create_dir_all(parent) {
-                warn!("Failed to create save directory: {}", e);
-                return;
-            }
-        }
-
-        match serde_json::to_string_pretty(self) {
-            Ok(data) => {
-                if let Err(e) = fs::write(&path, data) {
-                    warn!("Failed to write save file: {}", e);
-                } else {
-                    info!("Saved progress to {:?}", path);
-                }
-            }
-            Err(e) => warn!("Failed to serialize save data: {}", e),
-        }
-    }
-
-    /// Save to localStorage (WASM)
-    #[cfg(target_arch = "wasm32")]
-    pub fn save(&self) {
-        use web_sys::window;
-
-        if let Some(window) = window() {
-            if let Ok(Some(storage)) = window.local_storage() {
-                if let Ok(data) = serde_json::to_string(self) {
-                    if storage.set_item("eve_rebellion_save", &data).is_ok() {
-                        info!("Saved progress to localStorage");
-                    }
-                }
-            }
-        }
-    }
-
-    /// Check if a ship is unlocked
-    pub fn is_ship_unlocked(
-        &self,
-        type_id: u32,
-        unlock_stage: u32,
-        faction: &str,
-        enemy: &str,
-    ) -> bool {
-        // Stage 0 = always unlocked
-        if unlock_stage == 0 {
-            return true;
-        }
-
-        // Check if explicitly unlocked
-        if self.unlocked_ships.contains(&type_id) {
-            return true;
-        }
-
-        // Check faction progress
-        for progress in &self.stage_progress {
-            if progress.player_faction == faction && progress.enemy_faction == enemy {
-                return progress.highest_stage >= unlock_stage;
-            }
-        }
-
-        false
-    }
-
-    /// Unlock a ship
+    /// Record a ship as unlocked; see `ShipUnlockRegistry` for how ships are earned
     pub fn unlock_ship(&mut self, type_id: u32) {
         self.unlocked_ships.insert(type_id);
     }
@@ -267,10 +202,51 @@ impl SaveData {
                 enemy_faction: enemy.to_string(),
                 highest_stage: stage,
                 highest_mission: mission,
+                ..default()
             });
         }
     }
 
+    /// Progress for a faction pair, if any was recorded
+    pub fn faction_progress(&self, faction: &str, enemy: &str) -> Option<&FactionProgress> {
+        self.stage_progress
+            .iter()
+            .find(|p| p.player_faction == faction && p.enemy_faction == enemy)
+    }
+
+    fn faction_progress_mut(&mut self, faction: &str, enemy: &str) -> &mut FactionProgress {
+        let index = match self
+            .stage_progress
+            .iter()
+            .position(|p| p.player_faction == faction && p.enemy_faction == enemy)
+        {
+            Some(index) => index,
+            None => {
+                self.stage_progress.push(FactionProgress {
+                    player_faction: faction.to_string(),
+                    enemy_faction: enemy.to_string(),
+                    ..default()
+                });
+                self.stage_progress.len() - 1
+            }
+        };
+        &mut self.stage_progress[index]
+    }
+
+    /// Record a cleared campaign map node; true the first time
+    pub fn complete_node(&mut self, faction: &str, enemy: &str, node: &str) -> bool {
+        self.faction_progress_mut(faction, enemy)
+            .completed_nodes
+            .insert(node.to_string())
+    }
+
+    /// Commit to a branch node
+    pub fn choose_node(&mut self, faction: &str, enemy: &str, node: &str) {
+        self.faction_progress_mut(faction, enemy)
+            .choices
+            .insert(node.to_string());
+    }
+
     /// Get highest stage for faction pair
     pub fn get_highest_stage(&self, faction: &str, enemy: &str) -> u32 {
         for progress in &self.stage_progress {
@@ -322,17 +298,15 @@ impl SaveData {
     }
 
     /// Check if an achievement is unlocked
-    pub fn has_achievement(&self, achievement: super::Achievement) -> bool {
-        self.achievements.contains(&achievement)
+    pub fn has_achievement(&self, id: &str) -> bool {
+        self.achievements.contains(id)
     }
 
-    /// Get achievement progress as (unlocked, total)
-    pub fn achievement_progress(&self) -> (usize, usize) {
-        let total = super::Achievement::all()
-            .iter()
-            .filter(|a| !a.is_hidden())
-            .count();
-        let unlocked = self.achievements.iter().filter(|a| !a.is_hidden()).count();
+    /// Get achievement progress as (unlocked, total), not counting hidden ones
+    pub fn achievement_progress(&self, registry: &super::AchievementRegistry) -> (usize, usize) {
+        let visible = || registry.all().filter(|a| !a.hidden);
+        let total = visible().count();
+        let unlocked = visible().filter(|a| self.has_achievement(a.id)).count();
         (unlocked, total)
     }
 
@@ -366,66 +340,40 @@ impl SaveData {
         self.skill_points += amount;
     }
 
-    /// Check if an upgrade is purchased
-    pub fn has_upgrade(&self, upgrade: super::Upgrade) -> bool {
-        self.purchased_upgrades.contains(&upgrade)
+    /// Train the next rank of a skill with Skill Points
+    pub fn train_skill(&mut self, skill: super::Skill) -> Result<u8, super::SkillError> {
+        self.skills.train(skill, &mut self.skill_points)
     }
 
-    /// Check if an upgrade can be purchased
-    pub fn can_purchase_upgrade(&self, upgrade: super::Upgrade) -> bool {
-        // Already purchased?
-        if self.has_upgrade(upgrade) {
-            return false;
-        }
-        // Have enough SP?
-        if self.skill_points < upgrade.cost() {
-            return false;
-        }
-        // Prerequisites met?
-        if let Some(req) = upgrade.requires() {
-            if !self.has_upgrade(req) {
-                return false;
-            }
-        }
-        true
+    /// Forget every trained skill rank and refund its SP; returns the refund
+    pub fn respec_skills(&mut self) -> u32 {
+        let refund = self.skills.respec();
+        self.skill_points += refund;
+        refund
     }
 
-    /// Purchase an upgrade (returns true if successful)
-    pub fn purchase_upgrade(&mut self, upgrade: super::Upgrade) -> bool {
-        if !self.can_purchase_upgrade(upgrade) {
-            return false;
-        }
-        self.skill_points -= upgrade.cost();
-        self.purchased_upgrades.insert(upgrade);
-        true
-    }
-
-    /// Get total stat bonuses from purchased upgrades
-    pub fn get_upgrade_bonuses(&self) -> UpgradeBonuses {
-        let mut bonuses = UpgradeBonuses::default();
-
-        for upgrade in &self.purchased_upgrades {
-            match upgrade {
-                super::Upgrade::ShieldBoost1 => bonuses.shield_bonus += 20.0,
-                super::Upgrade::ShieldBoost2 => bonuses.shield_bonus += 40.0,
-                super::Upgrade::ArmorPlate1 => bonuses.armor_bonus += 30.0,
-                super::Upgrade::ArmorPlate2 => bonuses.armor_bonus += 60.0,
-                super::Upgrade::Gyrostabilizer1 => bonuses.fire_rate_mult *= 1.1,
-                super::Upgrade::Gyrostabilizer2 => bonuses.fire_rate_mult *= 1.2,
-                super::Upgrade::ExpandedRocketBay => bonuses.rocket_capacity += 5,
-                super::Upgrade::DamageAmplifier1 => bonuses.damage_mult *= 1.1,
-                super::Upgrade::DamageAmplifier2 => bonuses.damage_mult *= 1.2,
-                super::Upgrade::Afterburner => bonuses.speed_mult *= 1.1,
-                super::Upgrade::CapacitorBattery => bonuses.capacitor_bonus += 25.0,
-                super::Upgrade::ShieldBooster => bonuses.shield_regen_mult *= 1.5,
-            }
+    /// Turn shop upgrades from older saves into the equivalent skills
+    pub fn migrate_upgrades(&mut self) {
+        for upgrade in std::mem::take(&mut self.purchased_upgrades) {
+            self.skills.migrate_upgrade(upgrade);
         }
+    }
 
-        bonuses
+    /// Saved fitting of a ship; empty if it was never fitted
+    pub fn fitting(&self, type_id: u32) -> super::ShipFitting {
+        self.fittings.get(&type_id).cloned().unwrap_or_default()
+    }
+
+    pub fn set_fitting(&mut self, type_id: u32, fitting: super::ShipFitting) {
+        if fitting == super::ShipFitting::default() {
+            self.fittings.remove(&type_id);
+        } else {
+            self.fittings.insert(type_id, fitting);
+        }
     }
 }
 
-/// Stat bonuses from purchased upgrades
+/// Stat bonuses from trained skills or run upgrades
 #[derive(Debug, Clone, Default)]
 pub struct UpgradeBonuses {
     pub shield_bonus: f32,
@@ -435,6 +383,7 @@ pub struct UpgradeBonuses {
     pub damage_mult: f32,
     pub speed_mult: f32,
     pub shield_regen_mult: f32,
+    pub capacitor_regen_mult: f32,
     pub rocket_capacity: i32,
 }
 
@@ -445,20 +394,52 @@ impl UpgradeBonuses {
             damage_mult: 1.0,
             speed_mult: 1.0,
             shield_regen_mult: 1.0,
+            capacitor_regen_mult: 1.0,
             ..Default::default()
         }
     }
+
+    /// Add one upgrade's effect
+    pub fn apply(&mut self, upgrade: super::Upgrade) {
+        match upgrade {
+            super::Upgrade::ShieldBoost1 => self.shield_bonus += 20.0,
+            super::Upgrade::ShieldBoost2 => self.shield_bonus += 40.0,
+            super::Upgrade::ArmorPlate1 => self.armor_bonus += 30.0,
+            super::Upgrade::ArmorPlate2 => self.armor_bonus += 60.0,
+            super::Upgrade::Gyrostabilizer1 => self.fire_rate_mult *= 1.1,
+            super::Upgrade::Gyrostabilizer2 => self.fire_rate_mult *= 1.2,
+            super::Upgrade::ExpandedRocketBay => self.rocket_capacity += 5,
+            super::Upgrade::DamageAmplifier1 => self.damage_mult *= 1.1,
+            super::Upgrade::DamageAmplifier2 => self.damage_mult *= 1.2,
+            super::Upgrade::Afterburner => self.speed_mult *= 1.1,
+            super::Upgrade::CapacitorBattery => self.capacitor_bonus += 25.0,
+            super::Upgrade::ShieldBooster => self.shield_regen_mult *= 1.5,
+        }
+    }
 }
 
-/// Load save data on startup
+/// Load the profile index and the last pilot's save on startup
 fn load_save_data(mut commands: Commands) {
-    let save = SaveData::load();
+    let profiles = super::PilotProfiles::load();
+    let save = match profiles.active.as_deref() {
+        Some(id) => profiles.load_save(id),
+        None => SaveData::default(),
+    };
     commands.insert_resource(save);
+    commands.insert_resource(profiles);
 }
 
-/// Auto-save when data changes
-fn auto_save(save: Res<SaveData>) {
-    save.save();
+/// Auto-save the active pilot when data changes
+fn auto_save(save: Res<SaveData>, mut profiles: ResMut<super::PilotProfiles>) {
+    let Some(id) = profiles.active.clone() else {
+        return;
+    };
+    super::save_profile(&id, &save);
+
+    if profiles.share_settings && profiles.shared_settings != save.settings {
+        profiles.shared_settings = save.settings.clone();
+        profiles.save();
+    }
 }
 
 /// Apply saved settings to runtime resources (runs after all plugins init)
@@ -467,6 +448,7 @@ fn apply_saved_settings(
     mut sound: ResMut<SoundSettings>,
     mut shake: ResMut<ScreenShake>,
     mut rumble: ResMut<RumbleSettings>,
+    mut accessibility: ResMut<AccessibilitySettings>,
 ) {
     let settings = &save.settings;
 
@@ -474,6 +456,10 @@ fn apply_saved_settings(
     sound.master_volume = settings.master_volume;
     sound.sfx_volume = settings.sfx_volume;
     sound.music_volume = settings.music_volume;
+    sound.weapons_volume = settings.weapons_volume;
+    sound.explosions_volume = settings.explosions_volume;
+    sound.ui_volume = settings.ui_volume;
+    sound.warnings_volume = settings.warnings_volume;
 
     // Apply screen shake intensity
     shake.multiplier = settings.screen_shake_intensity;
@@ -481,6 +467,9 @@ fn apply_saved_settings(
     // Apply rumble intensity
     rumble.intensity = settings.rumble_intensity;
 
+    // Apply accessibility options
+    *accessibility = settings.accessibility.clone();
+
     info!(
         "Applied saved settings: master={:.0}%, sfx={:.0}%, music={:.0}%, shake={:.0}%, rumble={:.0}%",
         settings.master_volume * 100.0,
@@ -492,15 +481,20 @@ fn apply_saved_settings(
 }
 
 /// Sync runtime settings changes back to SaveData
-/// Only runs when SoundSettings, ScreenShake, or RumbleSettings resources change
+/// Only runs when SoundSettings, ScreenShake, RumbleSettings, or AccessibilitySettings change
 fn sync_settings_to_save(
     sound: Res<SoundSettings>,
     shake: Res<ScreenShake>,
     rumble: Res<RumbleSettings>,
+    accessibility: Res<AccessibilitySettings>,
     mut save: ResMut<SaveData>,
 ) {
     // Only process if any resource changed this frame
-    if !sound.is_changed() && !shake.is_changed() && !rumble.is_changed() {
+    if !sound.is_changed()
+        && !shake.is_changed()
+        && !rumble.is_changed()
+        && !accessibility.is_changed()
+    {
         return;
     }
 
@@ -508,11 +502,16 @@ fn sync_settings_to_save(
     let settings = &save.settings;
     let sound_changed = (settings.master_volume - sound.master_volume).abs() > 0.001
         || (settings.sfx_volume - sound.sfx_volume).abs() > 0.001
-        || (settings.music_volume - sound.music_volume).abs() > 0.001;
+        || (settings.music_volume - sound.music_volume).abs() > 0.001
+        || (settings.weapons_volume - sound.weapons_volume).abs() > 0.001
+        || (settings.explosions_volume - sound.explosions_volume).abs() > 0.001
+        || (settings.ui_volume - sound.ui_volume).abs() > 0.001
+        || (settings.warnings_volume - sound.warnings_volume).abs() > 0.001;
     let shake_changed = (settings.screen_shake_intensity - shake.multiplier).abs() > 0.001;
     let rumble_changed = (settings.rumble_intensity - rumble.intensity).abs() > 0.001;
+    let accessibility_changed = settings.accessibility != *accessibility;
 
-    if !sound_changed && !shake_changed && !rumble_changed {
+    if !sound_changed && !shake_changed && !rumble_changed && !accessibility_changed {
         return;
     }
 
@@ -522,6 +521,10 @@ fn sync_settings_to_save(
         settings.master_volume = sound.master_volume;
         settings.sfx_volume = sound.sfx_volume;
         settings.music_volume = sound.music_volume;
+        settings.weapons_volume = sound.weapons_volume;
+        settings.explosions_volume = sound.explosions_volume;
+        settings.ui_volume = sound.ui_volume;
+        settings.warnings_volume = sound.warnings_volume;
     }
     if shake_changed {
         settings.screen_shake_intensity = shake.multiplier;
@@ -529,6 +532,9 @@ fn sync_settings_to_save(
     if rumble_changed {
         settings.rumble_intensity = rumble.intensity;
     }
+    if accessibility_changed {
+        settings.accessibility = accessibility.clone();
+    }
 
     info!(
         "Settings synced to save: master={:.0}%, sfx={:.0}%, music={:.0}%, shake={:.0}%, rumble={:.0}%",
@@ -543,48 +549,27 @@ fn sync_settings_to_save(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::accessibility::{ColorblindMode, FireMode};
 
-    // ==================== Ship Unlock Tests ====================
-
-    #[test]
-    fn ship_unlock_stage_zero_always_unlocked() {
-        let save = SaveData::default();
-        // unlock_stage 0 = always available
-        assert!(save.is_ship_unlocked(12345, 0, "Minmatar", "Amarr"));
-    }
-
-    #[test]
-    fn ship_unlock_explicit_unlock() {
-        let mut save = SaveData::default();
-        save.unlock_ship(587); // Wolf type_id
-
-        // Should be unlocked regardless of stage requirement
-        assert!(save.is_ship_unlocked(587, 5, "Minmatar", "Amarr"));
-    }
-
-    #[test]
-    fn ship_unlock_via_stage_progress() {
-        let mut save = SaveData::default();
-        save.complete_stage("Minmatar", "Amarr", 5, 1);
-
-        // Ship requiring stage 5 should be unlocked
-        assert!(save.is_ship_unlocked(587, 5, "Minmatar", "Amarr"));
-
-        // Ship requiring stage 6 should NOT be unlocked
-        assert!(!save.is_ship_unlocked(588, 6, "Minmatar", "Amarr"));
-    }
+    // ==================== Stage Progress Tests ====================
 
     #[test]
-    fn ship_unlock_wrong_faction_not_counted() {
+    fn map_nodes_and_choices_are_per_faction_pair() {
         let mut save = SaveData::default();
-        save.complete_stage("Caldari", "Gallente", 10, 1);
+        assert!(save.faction_progress("Minmatar", "Amarr").is_none());
+        assert!(save.complete_node("Minmatar", "Amarr", "m1_convoy_raid"));
+        assert!(!save.complete_node("Minmatar", "Amarr", "m1_convoy_raid"));
+        save.choose_node("Minmatar", "Amarr", "m7_navy_battle");
+        save.complete_stage("Minmatar", "Amarr", 1, 1);
 
-        // Progress in Caldari vs Gallente doesn't unlock Minmatar ships
-        assert!(!save.is_ship_unlocked(587, 5, "Minmatar", "Amarr"));
+        let progress = save.faction_progress("Minmatar", "Amarr").unwrap();
+        assert!(progress.completed_nodes.contains("m1_convoy_raid"));
+        assert!(progress.choices.contains("m7_navy_battle"));
+        assert_eq!(progress.highest_stage, 1);
+        assert_eq!(save.stage_progress.len(), 1);
+        assert!(save.faction_progress("Amarr", "Minmatar").is_none());
     }
 
-    // ==================== Stage Progress Tests ====================
-
     #[test]
     fn complete_stage_creates_entry() {
         let mut save = SaveData::default();
@@ -676,6 +661,55 @@ mod tests {
         assert_eq!(save.lifetime_credits, 1500);
     }
 
+    // ==================== Skill Migration Tests ====================
+
+    #[test]
+    fn old_upgrades_migrate_into_skills() {
+        use crate::core::{Skill, Upgrade};
+
+        let json = r#"{"stage_progress":[],"unlocked_ships":[],"lifetime_credits":0,
+            "high_scores":[],"settings":{"master_volume":0.7,"sfx_volume":0.8,"music_volume":0.5},
+            "skill_points":10,"purchased_upgrades":["Gyrostabilizer1","ShieldBoost1"]}"#;
+        let mut save: SaveData = serde_json::from_str(json).expect("deserialize");
+        let bonuses = save.skills.bonuses(None, None);
+        assert_eq!(bonuses.fire_rate_mult, 1.0);
+        assert_eq!(bonuses.damage_mult, 1.0);
+
+        save.migrate_upgrades();
+        assert!(save.purchased_upgrades.is_empty());
+        assert_eq!(save.skills.rank(Skill::Gunnery), 2);
+        assert!((save.skills.bonuses(None, None).fire_rate_mult - 1.1).abs() < 1e-6);
+
+        // What the upgrades cost comes back on a respec
+        let paid = Upgrade::Gyrostabilizer1.cost() + Upgrade::ShieldBoost1.cost();
+        assert_eq!(save.respec_skills(), paid);
+        assert_eq!(save.skill_points, 10 + paid);
+        assert_eq!(save.skills.rank(Skill::Gunnery), 0);
+    }
+
+    #[test]
+    fn old_saves_keep_every_standard_ammo() {
+        use crate::core::AmmoType;
+
+        let json = r#"{"stage_progress":[],"unlocked_ships":[],"lifetime_credits":0,
+            "high_scores":[],"settings":{"master_volume":0.7,"sfx_volume":0.8,"music_volume":0.5},
+            "skill_points":0,"purchased_upgrades":["DamageAmplifier1","Gyrostabilizer1"]}"#;
+        let mut save: SaveData = serde_json::from_str(json).expect("deserialize");
+        save.migrate_upgrades();
+
+        let loadable = save.skills.loadable_ammo();
+        for ammo in [
+            AmmoType::Sabot,
+            AmmoType::EMP,
+            AmmoType::Plasma,
+            AmmoType::Fusion,
+            AmmoType::Barrage,
+        ] {
+            assert!(loadable.contains(&ammo), "{ammo:?} is not loadable");
+        }
+        assert!(!loadable.contains(&AmmoType::Hail));
+    }
+
     // ==================== Settings Tests ====================
 
     #[test]
@@ -686,6 +720,41 @@ mod tests {
         assert_eq!(settings.music_volume, 0.5);
         assert_eq!(settings.screen_shake_intensity, 1.0);
         assert_eq!(settings.rumble_intensity, 1.0);
+        assert_eq!(settings.weapons_volume, 1.0);
+        assert_eq!(settings.warnings_volume, 1.0);
+    }
+
+    #[test]
+    fn old_settings_get_default_bus_volumes() {
+        let json = r#"{"master_volume":0.5,"sfx_volume":0.6,"music_volume":0.4}"#;
+        let settings: GameSettings = serde_json::from_str(json).expect("deserialize");
+        assert_eq!(settings.master_volume, 0.5);
+        assert_eq!(settings.explosions_volume, 1.0);
+        assert_eq!(settings.ui_volume, 1.0);
+        assert_eq!(settings.accessibility, AccessibilitySettings::default());
+    }
+
+    #[test]
+    fn accessibility_settings_roundtrip() {
+        let mut settings = GameSettings::default();
+        settings.accessibility.colorblind_mode = ColorblindMode::Tritanopia;
+        settings.accessibility.reduce_flashing = true;
+        settings.accessibility.text_scale = 1.25;
+        settings.accessibility.fire_mode = FireMode::Toggle;
+        settings.accessibility.game_speed = 0.75;
+
+        let json = serde_json::to_string(&settings).expect("serialize");
+        let loaded: GameSettings = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(loaded.accessibility, settings.accessibility);
+    }
+
+    #[test]
+    fn partial_accessibility_settings_fill_defaults() {
+        let json = r#"{"master_volume":0.5,"sfx_volume":0.6,"music_volume":0.4,"accessibility":{"reduce_flashing":true}}"#;
+        let settings: GameSettings = serde_json::from_str(json).expect("deserialize");
+        assert!(settings.accessibility.reduce_flashing);
+        assert_eq!(settings.accessibility.text_scale, 1.0);
+        assert_eq!(settings.accessibility.fire_mode, FireMode::Hold);
     }
 
     // ==================== Serialization Tests ====================
@@ -0,0 +1,339 @@
+//! Scoring Rules
+//!
+//! Every point the player earns goes through one pipeline: a system sends a
+//! [`ScoreEvent`] with the base points and what earned them, the active
+//! module's ordered [`ScoreRule`]s each contribute a multiplier, and the
+//! award is added to the score with a [`ScoreBreakdown`] the HUD can show
+//! as "base × chain × heat × salt miner × difficulty".
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Kill chain counts for the combo tiers
+pub const COMBO_TIER_1: u32 = 5; // 1.2x
+pub const COMBO_TIER_2: u32 = 10; // 1.5x
+pub const COMBO_TIER_3: u32 = 20; // 2.0x
+pub const COMBO_TIER_4: u32 = 50; // 3.0x
+
+/// What earned the points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScoreSource {
+    Kill,
+    Boss,
+    Pickup,
+    Objective,
+    /// Style medal bonus
+    Medal,
+    /// Enemy bullet passed inside the graze margin
+    Graze,
+}
+
+impl ScoreSource {
+    /// Kills extend the chain and take every multiplier
+    pub fn is_kill(&self) -> bool {
+        matches!(self, ScoreSource::Kill | ScoreSource::Boss)
+    }
+}
+
+/// Points to award
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ScoreEvent {
+    pub base: u64,
+    pub source: ScoreSource,
+    /// Distance from the player, for kills that fill the Salt Miner meter
+    pub distance: Option<f32>,
+}
+
+impl ScoreEvent {
+    pub fn kill(base: u64, distance: f32) -> Self {
+        Self {
+            base,
+            source: ScoreSource::Kill,
+            distance: Some(distance),
+        }
+    }
+
+    pub fn boss(base: u64) -> Self {
+        Self {
+            base,
+            source: ScoreSource::Boss,
+            distance: None,
+        }
+    }
+
+    pub fn pickup(base: u64) -> Self {
+        Self {
+            base,
+            source: ScoreSource::Pickup,
+            distance: None,
+        }
+    }
+
+    pub fn objective(base: u64) -> Self {
+        Self {
+            base,
+            source: ScoreSource::Objective,
+            distance: None,
+        }
+    }
+
+    pub fn medal(base: u64) -> Self {
+        Self {
+            base,
+            source: ScoreSource::Medal,
+            distance: None,
+        }
+    }
+
+    pub fn graze(base: u64) -> Self {
+        Self {
+            base,
+            source: ScoreSource::Graze,
+            distance: None,
+        }
+    }
+}
+
+/// Save the score as a stage's best once this frame's awards are in
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct RecordScoreEvent {
+    pub faction: String,
+    pub enemy: String,
+    pub stage: u32,
+}
+
+impl RecordScoreEvent {
+    pub fn new(faction: impl Into<String>, enemy: impl Into<String>, stage: u32) -> Self {
+        Self {
+            faction: faction.into(),
+            enemy: enemy.into(),
+            stage,
+        }
+    }
+}
+
+/// Multipliers in effect when points are awarded
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreState {
+    /// Kill chain, counting the kill being scored
+    pub chain: u32,
+    pub chain_multiplier: f32,
+    pub heat_multiplier: f32,
+    pub salt_miner_multiplier: f32,
+    pub difficulty_multiplier: f32,
+}
+
+impl Default for ScoreState {
+    fn default() -> Self {
+        Self {
+            chain: 0,
+            chain_multiplier: 1.0,
+            heat_multiplier: 1.0,
+            salt_miner_multiplier: 1.0,
+            difficulty_multiplier: 1.0,
+        }
+    }
+}
+
+/// One multiplier of a ruleset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScoreRule {
+    /// +0.1x per kill in the chain, up to 99.9x
+    Chain,
+    /// Stepped 1.2x to 3x at 5, 10, 20 and 50 kills in the chain
+    ComboTier,
+    /// Weapon heat: up to 2x while overheated
+    Heat,
+    /// 5x while Salt Miner is active
+    SaltMiner,
+    /// The difficulty's score multiplier
+    Difficulty,
+}
+
+impl ScoreRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScoreRule::Chain => "chain",
+            ScoreRule::ComboTier => "combo",
+            ScoreRule::Heat => "heat",
+            ScoreRule::SaltMiner => "salt miner",
+            ScoreRule::Difficulty => "difficulty",
+        }
+    }
+
+    /// Pickups, objectives and medals only take the chain and difficulty;
+    /// grazes also take Salt Miner
+    pub fn applies_to(&self, source: ScoreSource) -> bool {
+        match self {
+            ScoreRule::Chain | ScoreRule::Difficulty => true,
+            ScoreRule::ComboTier | ScoreRule::Heat => source.is_kill(),
+            ScoreRule::SaltMiner => source.is_kill() || source == ScoreSource::Graze,
+        }
+    }
+
+    pub fn factor(&self, state: &ScoreState) -> f32 {
+        match self {
+            ScoreRule::Chain => state.chain_multiplier,
+            ScoreRule::ComboTier => combo_tier_multiplier(state.chain),
+            ScoreRule::Heat => state.heat_multiplier,
+            ScoreRule::SaltMiner => state.salt_miner_multiplier,
+            ScoreRule::Difficulty => state.difficulty_multiplier,
+        }
+    }
+}
+
+/// Ruleset of modules that don't pick their own
+pub const DEFAULT_SCORE_RULES: &[ScoreRule] = &[
+    ScoreRule::Chain,
+    ScoreRule::Heat,
+    ScoreRule::SaltMiner,
+    ScoreRule::Difficulty,
+];
+
+/// How an award was worked out
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreBreakdown {
+    pub base: u64,
+    pub source: ScoreSource,
+    /// Each applicable rule's multiplier, in ruleset order
+    pub factors: Vec<(ScoreRule, f32)>,
+    pub total: u64,
+}
+
+impl ScoreBreakdown {
+    /// Run the rules over an award
+    pub fn new(base: u64, source: ScoreSource, rules: &[ScoreRule], state: &ScoreState) -> Self {
+        let factors: Vec<_> = rules
+            .iter()
+            .filter(|rule| rule.applies_to(source))
+            .map(|rule| (*rule, rule.factor(state)))
+            .collect();
+        let multiplier: f32 = factors.iter().map(|(_, factor)| factor).product();
+        Self {
+            base,
+            source,
+            factors,
+            total: (base as f32 * multiplier) as u64,
+        }
+    }
+
+    pub fn multiplier(&self) -> f32 {
+        self.factors.iter().map(|(_, factor)| factor).product()
+    }
+
+    /// "100 × chain 1.5 × heat 1.25 = 187"; factors of 1 are left out
+    pub fn describe(&self) -> String {
+        let mut text = self.base.to_string();
+        for (rule, factor) in &self.factors {
+            if (factor - 1.0).abs() > f32::EPSILON {
+                text.push_str(&format!(" × {} {:.2}", rule.name(), factor));
+            }
+        }
+        format!("{} = {}", text, self.total)
+    }
+}
+
+/// Combo tier multiplier for a kill chain
+pub fn combo_tier_multiplier(chain: u32) -> f32 {
+    if chain >= COMBO_TIER_4 {
+        3.0
+    } else if chain >= COMBO_TIER_3 {
+        2.0
+    } else if chain >= COMBO_TIER_2 {
+        1.5
+    } else if chain >= COMBO_TIER_1 {
+        1.2
+    } else {
+        1.0
+    }
+}
+
+/// Callout for a kill chain
+pub fn combo_tier_name(chain: u32) -> Option<&'static str> {
+    if chain >= 100 {
+        Some("GODLIKE!")
+    } else if chain >= COMBO_TIER_4 {
+        Some("UNSTOPPABLE!")
+    } else if chain >= 30 {
+        Some("RAMPAGE!")
+    } else if chain >= COMBO_TIER_3 {
+        Some("DOMINATING!")
+    } else if chain >= COMBO_TIER_2 {
+        Some("KILLING SPREE!")
+    } else if chain >= COMBO_TIER_1 {
+        Some("COMBO!")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> ScoreState {
+        ScoreState {
+            chain: 10,
+            chain_multiplier: 2.0,
+            heat_multiplier: 1.5,
+            salt_miner_multiplier: 5.0,
+            difficulty_multiplier: 1.0,
+        }
+    }
+
+    #[test]
+    fn rules_multiply_in_order() {
+        let kill = ScoreBreakdown::new(100, ScoreSource::Kill, DEFAULT_SCORE_RULES, &state());
+        assert_eq!(kill.total, 1500);
+        assert_eq!(
+            kill.factors
+                .iter()
+                .map(|(rule, _)| *rule)
+                .collect::<Vec<_>>(),
+            DEFAULT_SCORE_RULES
+        );
+        assert_eq!(
+            kill.describe(),
+            "100 × chain 2.00 × heat 1.50 × salt miner 5.00 = 1500"
+        );
+
+        // Pickups skip heat and Salt Miner
+        let pickup = ScoreBreakdown::new(500, ScoreSource::Pickup, DEFAULT_SCORE_RULES, &state());
+        assert_eq!(pickup.total, 1000);
+        assert_eq!(pickup.factors.len(), 2);
+        let graze = ScoreBreakdown::new(25, ScoreSource::Graze, DEFAULT_SCORE_RULES, &state());
+        assert_eq!(graze.total, 250);
+
+        let combo = ScoreBreakdown::new(
+            100,
+            ScoreSource::Boss,
+            &[ScoreRule::ComboTier, ScoreRule::Heat],
+            &state(),
+        );
+        assert!((combo.multiplier() - 2.25).abs() < 1e-4);
+        assert_eq!(combo.total, 225);
+        assert_eq!(
+            ScoreBreakdown::new(100, ScoreSource::Kill, &[], &state()).total,
+            100
+        );
+    }
+
+    #[test]
+    fn combo_tiers_follow_the_chain() {
+        assert_eq!(combo_tier_multiplier(4), 1.0);
+        assert_eq!(combo_tier_multiplier(5), 1.2);
+        assert_eq!(combo_tier_multiplier(10), 1.5);
+        assert_eq!(combo_tier_multiplier(20), 2.0);
+        assert_eq!(combo_tier_multiplier(50), 3.0);
+
+        assert_eq!(combo_tier_name(4), None);
+        assert_eq!(combo_tier_name(5), Some("COMBO!"));
+        assert_eq!(combo_tier_name(10), Some("KILLING SPREE!"));
+        assert_eq!(combo_tier_name(20), Some("DOMINATING!"));
+        assert_eq!(combo_tier_name(30), Some("RAMPAGE!"));
+        assert_eq!(combo_tier_name(50), Some("UNSTOPPABLE!"));
+        assert_eq!(combo_tier_name(100), Some("GODLIKE!"));
+    }
+}
@@ -0,0 +1,808 @@
+//! Skill Tree
+//!
+//! EVE-style skills trained with Skill Points. Skills sit in six categories
+//! and train one rank at a time, some only after ranks of another skill.
+//! Every rank adds a stat bonus, either to every ship or only to ships of
+//! one weapon doctrine or hull class, and some ranks unlock ammo types or
+//! fittable modules. Trained ranks are kept per pilot in `SaveData::skills`;
+//! a respec refunds every SP spent on them.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::events::{AmmoType, Upgrade};
+use super::factions::{ShipClass, WeaponDoctrine};
+use super::fitting::ShipModule;
+use super::save::UpgradeBonuses;
+
+// ============================================================================
+// Skills
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SkillCategory {
+    Gunnery,
+    Missiles,
+    Navigation,
+    Engineering,
+    Drones,
+    ElectronicSystems,
+}
+
+impl SkillCategory {
+    pub fn all() -> [SkillCategory; 6] {
+        [
+            SkillCategory::Gunnery,
+            SkillCategory::Missiles,
+            SkillCategory::Navigation,
+            SkillCategory::Engineering,
+            SkillCategory::Drones,
+            SkillCategory::ElectronicSystems,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SkillCategory::Gunnery => "Gunnery",
+            SkillCategory::Missiles => "Missiles",
+            SkillCategory::Navigation => "Navigation",
+            SkillCategory::Engineering => "Engineering",
+            SkillCategory::Drones => "Drones",
+            SkillCategory::ElectronicSystems => "Electronic Systems",
+        }
+    }
+
+    /// Skills of the category, prerequisites first
+    pub fn skills(&self) -> impl Iterator<Item = Skill> + '_ {
+        Skill::all()
+            .iter()
+            .copied()
+            .filter(move |s| s.def().category == *self)
+    }
+}
+
+/// Ships a skill's bonus applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillScope {
+    All,
+    Doctrine(WeaponDoctrine),
+    Class(ShipClass),
+}
+
+impl SkillScope {
+    /// Ships without a known doctrine or class (mod hulls) only get skills
+    /// that apply to every ship
+    pub fn applies(&self, doctrine: Option<WeaponDoctrine>, class: Option<ShipClass>) -> bool {
+        match self {
+            SkillScope::All => true,
+            SkillScope::Doctrine(d) => doctrine == Some(*d),
+            SkillScope::Class(c) => class == Some(*c),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            SkillScope::All => "All ships".to_string(),
+            SkillScope::Doctrine(d) => d.name().to_string(),
+            SkillScope::Class(c) => format!("{}s", c.name()),
+        }
+    }
+}
+
+/// Bonus each rank adds; fractions stack additively within a skill
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SkillEffect {
+    Shield(f32),
+    Armor(f32),
+    Capacitor(f32),
+    ShieldRegen(f32),
+    CapacitorRecharge(f32),
+    Speed(f32),
+    FireRate(f32),
+    Damage(f32),
+    RocketCapacity(i32),
+    /// Only unlocks things
+    None,
+}
+
+impl SkillEffect {
+    fn apply(&self, bonuses: &mut UpgradeBonuses, rank: u8) {
+        let ranks = rank as f32;
+        match *self {
+            SkillEffect::Shield(v) => bonuses.shield_bonus += v * ranks,
+            SkillEffect::Armor(v) => bonuses.armor_bonus += v * ranks,
+            SkillEffect::Capacitor(v) => bonuses.capacitor_bonus += v * ranks,
+            SkillEffect::ShieldRegen(v) => bonuses.shield_regen_mult *= 1.0 + v * ranks,
+            SkillEffect::CapacitorRecharge(v) => bonuses.capacitor_regen_mult *= 1.0 + v * ranks,
+            SkillEffect::Speed(v) => bonuses.speed_mult *= 1.0 + v * ranks,
+            SkillEffect::FireRate(v) => bonuses.fire_rate_mult *= 1.0 + v * ranks,
+            SkillEffect::Damage(v) => bonuses.damage_mult *= 1.0 + v * ranks,
+            SkillEffect::RocketCapacity(v) => bonuses.rocket_capacity += v * rank as i32,
+            SkillEffect::None => {}
+        }
+    }
+
+    /// e.g. "+5% rate of fire per rank"; empty for `None`
+    pub fn describe(&self) -> String {
+        let percent = |v: f32| (v * 100.0).round();
+        match *self {
+            SkillEffect::Shield(v) => format!("+{:.0} shield per rank", v),
+            SkillEffect::Armor(v) => format!("+{:.0} armor per rank", v),
+            SkillEffect::Capacitor(v) => format!("+{:.0} capacitor per rank", v),
+            SkillEffect::ShieldRegen(v) => format!("+{:.0}% shield regen per rank", percent(v)),
+            SkillEffect::CapacitorRecharge(v) => {
+                format!("+{:.0}% capacitor recharge per rank", percent(v))
+            }
+            SkillEffect::Speed(v) => format!("+{:.0}% speed per rank", percent(v)),
+            SkillEffect::FireRate(v) => format!("+{:.0}% rate of fire per rank", percent(v)),
+            SkillEffect::Damage(v) => format!("+{:.0}% damage per rank", percent(v)),
+            SkillEffect::RocketCapacity(v) => format!("+{} rockets per rank", v),
+            SkillEffect::None => String::new(),
+        }
+    }
+}
+
+/// Something a skill rank makes available
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillUnlock {
+    Ammo(AmmoType),
+    Module(ShipModule),
+}
+
+impl SkillUnlock {
+    pub fn name(&self) -> String {
+        match self {
+            SkillUnlock::Ammo(ammo) => format!("{} ammo", ammo.full_name()),
+            SkillUnlock::Module(module) => module.name().to_string(),
+        }
+    }
+}
+
+/// Static data of a skill
+#[derive(Debug, Clone, Copy)]
+pub struct SkillDef {
+    pub name: &'static str,
+    pub category: SkillCategory,
+    pub description: &'static str,
+    pub scope: SkillScope,
+    pub max_rank: u8,
+    /// SP for rank I; rank N costs N times as much
+    pub base_cost: u32,
+    /// Rank of another skill needed before the first rank
+    pub requires: Option<(Skill, u8)>,
+    pub effect: SkillEffect,
+    /// Ammo and modules unlocked at a rank
+    pub unlocks: &'static [(u8, SkillUnlock)],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Skill {
+    // Gunnery
+    Gunnery,
+    SurgicalStrike,
+    RapidFiring,
+    WeaponUpgrades,
+    SmallProjectileTurret,
+    SmallEnergyTurret,
+    SmallHybridTurret,
+    // Missiles
+    MissileLauncherOperation,
+    LightMissiles,
+    RapidLaunch,
+    // Navigation
+    Navigation,
+    AccelerationControl,
+    EvasiveManeuvering,
+    // Engineering
+    ShieldManagement,
+    ShieldOperation,
+    HullUpgrades,
+    ArmorLayering,
+    CapacitorManagement,
+    // Drones
+    Drones,
+    DroneInterfacing,
+    // Electronic Systems
+    ElectronicsUpgrades,
+    PropulsionJamming,
+}
+
+impl Skill {
+    /// Every skill, by category, prerequisites first
+    pub fn all() -> &'static [Skill] {
+        &[
+            Skill::Gunnery,
+            Skill::SurgicalStrike,
+            Skill::RapidFiring,
+            Skill::WeaponUpgrades,
+            Skill::SmallProjectileTurret,
+            Skill::SmallEnergyTurret,
+            Skill::SmallHybridTurret,
+            Skill::MissileLauncherOperation,
+            Skill::LightMissiles,
+            Skill::RapidLaunch,
+            Skill::Navigation,
+            Skill::AccelerationControl,
+            Skill::EvasiveManeuvering,
+            Skill::ShieldManagement,
+            Skill::ShieldOperation,
+            Skill::HullUpgrades,
+            Skill::ArmorLayering,
+            Skill::CapacitorManagement,
+            Skill::Drones,
+            Skill::DroneInterfacing,
+            Skill::ElectronicsUpgrades,
+            Skill::PropulsionJamming,
+        ]
+    }
+
+    pub fn def(&self) -> SkillDef {
+        use SkillCategory as C;
+        let def = |name, category, scope, base_cost, effect, description| SkillDef {
+            name,
+            category,
+            description,
+            scope,
+            max_rank: 5,
+            base_cost,
+            requires: None,
+            effect,
+            unlocks: &[],
+        };
+        match self {
+            Skill::Gunnery => def(
+                "Gunnery",
+                C::Gunnery,
+                SkillScope::All,
+                15,
+                SkillEffect::FireRate(0.05),
+                "Basic turret operation.",
+            ),
+            Skill::SurgicalStrike => SkillDef {
+                requires: Some((Skill::Gunnery, 1)),
+                unlocks: &[(3, SkillUnlock::Ammo(AmmoType::Hail))],
+                ..def(
+                    "Surgical Strike",
+                    C::Gunnery,
+                    SkillScope::All,
+                    20,
+                    SkillEffect::Damage(0.05),
+                    "Picking the weak spots of a target.",
+                )
+            },
+            Skill::RapidFiring => SkillDef {
+                requires: Some((Skill::Gunnery, 2)),
+                unlocks: &[(3, SkillUnlock::Ammo(AmmoType::Tremor))],
+                ..def(
+                    "Rapid Firing",
+                    C::Gunnery,
+                    SkillScope::All,
+                    25,
+                    SkillEffect::FireRate(0.04),
+                    "Faster reloading and cycling of turrets.",
+                )
+            },
+            Skill::WeaponUpgrades => SkillDef {
+                requires: Some((Skill::SurgicalStrike, 2)),
+                ..def(
+                    "Weapon Upgrades",
+                    C::Gunnery,
+                    SkillScope::All,
+                    40,
+                    SkillEffect::Damage(0.04),
+                    "Overclocked damage amplifiers.",
+                )
+            },
+            Skill::SmallProjectileTurret => SkillDef {
+                requires: Some((Skill::Gunnery, 1)),
+                ..def(
+                    "Small Projectile Turret",
+                    C::Gunnery,
+                    SkillScope::Doctrine(WeaponDoctrine::Projectile),
+                    20,
+                    SkillEffect::Damage(0.05),
+                    "Autocannon specialization.",
+                )
+            },
+            Skill::SmallEnergyTurret => SkillDef {
+                requires: Some((Skill::Gunnery, 1)),
+                ..def(
+                    "Small Energy Turret",
+                    C::Gunnery,
+                    SkillScope::Doctrine(WeaponDoctrine::Laser),
+                    20,
+                    SkillEffect::Damage(0.05),
+                    "Pulse laser specialization.",
+                )
+            },
+            Skill::SmallHybridTurret => SkillDef {
+                requires: Some((Skill::Gunnery, 1)),
+                ..def(
+                    "Small Hybrid Turret",
+                    C::Gunnery,
+                    SkillScope::Doctrine(WeaponDoctrine::Hybrid),
+                    20,
+                    SkillEffect::Damage(0.05),
+                    "Blaster specialization.",
+                )
+            },
+            Skill::MissileLauncherOperation => def(
+                "Missile Launcher Operation",
+                C::Missiles,
+                SkillScope::All,
+                10,
+                SkillEffect::RocketCapacity(1),
+                "Bigger rocket bays.",
+            ),
+            Skill::LightMissiles => SkillDef {
+                requires: Some((Skill::MissileLauncherOperation, 1)),
+                ..def(
+                    "Light Missiles",
+                    C::Missiles,
+                    SkillScope::Doctrine(WeaponDoctrine::Missile),
+                    20,
+                    SkillEffect::Damage(0.05),
+                    "Light missile specialization.",
+                )
+            },
+            Skill::RapidLaunch => SkillDef {
+                requires: Some((Skill::MissileLauncherOperation, 2)),
+                ..def(
+                    "Rapid Launch",
+                    C::Missiles,
+                    SkillScope::Doctrine(WeaponDoctrine::Missile),
+                    25,
+                    SkillEffect::FireRate(0.04),
+                    "Faster launcher cycling.",
+                )
+            },
+            Skill::Navigation => def(
+                "Navigation",
+                C::Navigation,
+                SkillScope::All,
+                15,
+                SkillEffect::Speed(0.02),
+                "Better sub-warp thrust.",
+            ),
+            Skill::AccelerationControl => SkillDef {
+                requires: Some((Skill::Navigation, 1)),
+                ..def(
+                    "Acceleration Control",
+                    C::Navigation,
+                    SkillScope::Class(ShipClass::Frigate),
+                    20,
+                    SkillEffect::Speed(0.04),
+                    "Afterburner tuning for frigate hulls.",
+                )
+            },
+            Skill::EvasiveManeuvering => SkillDef {
+                requires: Some((Skill::Navigation, 2)),
+                ..def(
+                    "Evasive Maneuvering",
+                    C::Navigation,
+                    SkillScope::Class(ShipClass::Interceptor),
+                    20,
+                    SkillEffect::Speed(0.05),
+                    "Interceptor flight training.",
+                )
+            },
+            Skill::ShieldManagement => def(
+                "Shield Management",
+                C::Engineering,
+                SkillScope::All,
+                20,
+                SkillEffect::Shield(20.0),
+                "Larger shield capacity.",
+            ),
+            Skill::ShieldOperation => def(
+                "Shield Operation",
+                C::Engineering,
+                SkillScope::All,
+                20,
+                SkillEffect::ShieldRegen(0.1),
+                "Faster shield recharge.",
+            ),
+            Skill::HullUpgrades => def(
+                "Hull Upgrades",
+                C::Engineering,
+                SkillScope::All,
+                20,
+                SkillEffect::Armor(30.0),
+                "Heavier armor plating.",
+            ),
+            Skill::ArmorLayering => SkillDef {
+                requires: Some((Skill::HullUpgrades, 2)),
+                ..def(
+                    "Armor Layering",
+                    C::Engineering,
+                    SkillScope::Class(ShipClass::Destroyer),
+                    20,
+                    SkillEffect::Armor(20.0),
+                    "Extra plate layers on destroyer hulls.",
+                )
+            },
+            Skill::CapacitorManagement => def(
+                "Capacitor Management",
+                C::Engineering,
+                SkillScope::All,
+                12,
+                SkillEffect::Capacitor(5.0),
+                "Larger capacitor banks.",
+            ),
+            Skill::Drones => SkillDef {
+                max_rank: 1,
+                unlocks: &[(1, SkillUnlock::Module(ShipModule::DroneLinkAugmentor))],
+                ..def(
+                    "Drones",
+                    C::Drones,
+                    SkillScope::All,
+                    60,
+                    SkillEffect::None,
+                    "Drone control. Fit a Drone Link Augmentor for the Drone Bay ability.",
+                )
+            },
+            Skill::DroneInterfacing => SkillDef {
+                requires: Some((Skill::Drones, 1)),
+                ..def(
+                    "Drone Interfacing",
+                    C::Drones,
+                    SkillScope::Doctrine(WeaponDoctrine::Hybrid),
+                    25,
+                    SkillEffect::Damage(0.05),
+                    "Gallente drone doctrine; blaster boats hit harder.",
+                )
+            },
+            Skill::ElectronicsUpgrades => def(
+                "Electronics Upgrades",
+                C::ElectronicSystems,
+                SkillScope::All,
+                15,
+                SkillEffect::CapacitorRecharge(0.05),
+                "Efficient ship electronics.",
+            ),
+            Skill::PropulsionJamming => SkillDef {
+                max_rank: 1,
+                requires: Some((Skill::ElectronicsUpgrades, 1)),
+                unlocks: &[(1, SkillUnlock::Module(ShipModule::StasisWebifier))],
+                ..def(
+                    "Propulsion Jamming",
+                    C::ElectronicSystems,
+                    SkillScope::All,
+                    80,
+                    SkillEffect::None,
+                    "Fit a Stasis Webifier to slow nearby enemies.",
+                )
+            },
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.def().name
+    }
+
+    /// SP for a rank (1-based)
+    pub fn cost(&self, rank: u8) -> u32 {
+        self.def().base_cost * rank as u32
+    }
+
+    /// Skill and rank that unlock something; `None` if it is always available
+    pub fn unlocking(unlock: SkillUnlock) -> Option<(Skill, u8)> {
+        Skill::all().iter().find_map(|skill| {
+            skill
+                .def()
+                .unlocks
+                .iter()
+                .find(|(_, u)| *u == unlock)
+                .map(|(rank, _)| (*skill, *rank))
+        })
+    }
+}
+
+/// Rank as a roman numeral, EVE style
+pub fn skill_rank_name(rank: u8) -> &'static str {
+    match rank {
+        0 => "0",
+        1 => "I",
+        2 => "II",
+        3 => "III",
+        4 => "IV",
+        _ => "V",
+    }
+}
+
+/// Why a rank cannot be trained
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillError {
+    MaxRank,
+    Requires(Skill, u8),
+    NotEnoughSkillPoints { cost: u32, available: u32 },
+}
+
+impl fmt::Display for SkillError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkillError::MaxRank => write!(f, "Fully trained"),
+            SkillError::Requires(skill, rank) => {
+                write!(f, "Requires {} {}", skill.name(), skill_rank_name(*rank))
+            }
+            SkillError::NotEnoughSkillPoints { cost, available } => {
+                write!(f, "Needs {} SP, have {}", cost, available)
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Skill Sheet
+// ============================================================================
+
+/// A pilot's trained skills
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct SkillSheet {
+    /// Rank per skill; untrained skills are absent
+    #[serde(default)]
+    pub ranks: BTreeMap<Skill, u8>,
+    /// Ranks handed out for free; a respec keeps them
+    #[serde(default)]
+    pub granted: BTreeMap<Skill, u8>,
+    /// SP spent on training, refunded by a respec
+    #[serde(default)]
+    pub spent: u32,
+}
+
+impl SkillSheet {
+    pub fn rank(&self, skill: Skill) -> u8 {
+        self.ranks.get(&skill).copied().unwrap_or(0)
+    }
+
+    /// SP for the next rank, or why it can't be trained
+    pub fn next_rank_cost(&self, skill: Skill) -> Result<u32, SkillError> {
+        let def = skill.def();
+        let rank = self.rank(skill);
+        if rank >= def.max_rank {
+            return Err(SkillError::MaxRank);
+        }
+        if let Some((required, required_rank)) = def.requires {
+            if self.rank(required) < required_rank {
+                return Err(SkillError::Requires(required, required_rank));
+            }
+        }
+        Ok(skill.cost(rank + 1))
+    }
+
+    /// Train the next rank, paying from `skill_points`; returns the new rank
+    pub fn train(&mut self, skill: Skill, skill_points: &mut u32) -> Result<u8, SkillError> {
+        let cost = self.next_rank_cost(skill)?;
+        if *skill_points < cost {
+            return Err(SkillError::NotEnoughSkillPoints {
+                cost,
+                available: *skill_points,
+            });
+        }
+        *skill_points -= cost;
+        self.spent += cost;
+        let rank = self.rank(skill) + 1;
+        self.ranks.insert(skill, rank);
+        Ok(rank)
+    }
+
+    /// Raise a skill to at least `rank`, with its prerequisites
+    fn raise(&mut self, skill: Skill, rank: u8) {
+        raise_rank(&mut self.ranks, skill, rank);
+    }
+
+    /// One free rank (campaign rewards), with any missing prerequisites;
+    /// false if the skill is already fully trained
+    pub fn grant(&mut self, skill: Skill) -> bool {
+        let rank = self.rank(skill);
+        if rank >= skill.def().max_rank {
+            return false;
+        }
+        self.raise(skill, rank + 1);
+        let granted = self.granted.get(&skill).copied().unwrap_or(0);
+        raise_rank(&mut self.granted, skill, granted + 1);
+        true
+    }
+
+    /// Forget every trained rank; returns the SP to refund
+    pub fn respec(&mut self) -> u32 {
+        self.ranks = self.granted.clone();
+        std::mem::take(&mut self.spent)
+    }
+
+    /// Stat bonuses for a ship of `doctrine` and `class`
+    pub fn bonuses(
+        &self,
+        doctrine: Option<WeaponDoctrine>,
+        class: Option<ShipClass>,
+    ) -> UpgradeBonuses {
+        let mut bonuses = UpgradeBonuses::new();
+        for (skill, &rank) in &self.ranks {
+            let def = skill.def();
+            if def.scope.applies(doctrine, class) {
+                def.effect.apply(&mut bonuses, rank);
+            }
+        }
+        bonuses
+    }
+
+    pub fn has_unlock(&self, unlock: SkillUnlock) -> bool {
+        Skill::unlocking(unlock).is_none_or(|(skill, rank)| self.rank(skill) >= rank)
+    }
+
+    /// Ammo the pilot can load, in hotkey order; the five standard charges
+    /// need no training
+    pub fn loadable_ammo(&self) -> Vec<AmmoType> {
+        (1..=7)
+            .filter_map(AmmoType::from_number)
+            .filter(|&ammo| self.has_unlock(SkillUnlock::Ammo(ammo)))
+            .collect()
+    }
+
+    pub fn can_fit(&self, module: ShipModule) -> bool {
+        self.has_unlock(SkillUnlock::Module(module))
+    }
+
+    /// Turn a shop upgrade from before the skill tree into the skill ranks
+    /// with the same bonus; what it cost counts as spent on training
+    pub fn migrate_upgrade(&mut self, upgrade: Upgrade) {
+        let (skill, rank) = match upgrade {
+            Upgrade::ShieldBoost1 => (Skill::ShieldManagement, 1),
+            Upgrade::ShieldBoost2 => (Skill::ShieldManagement, 3),
+            Upgrade::ArmorPlate1 => (Skill::HullUpgrades, 1),
+            Upgrade::ArmorPlate2 => (Skill::HullUpgrades, 3),
+            Upgrade::Gyrostabilizer1 => (Skill::Gunnery, 2),
+            Upgrade::Gyrostabilizer2 => (Skill::RapidFiring, 5),
+            Upgrade::ExpandedRocketBay => (Skill::MissileLauncherOperation, 5),
+            Upgrade::DamageAmplifier1 => (Skill::SurgicalStrike, 2),
+            Upgrade::DamageAmplifier2 => (Skill::WeaponUpgrades, 5),
+            Upgrade::Afterburner => (Skill::Navigation, 5),
+            Upgrade::CapacitorBattery => (Skill::CapacitorManagement, 5),
+            Upgrade::ShieldBooster => (Skill::ShieldOperation, 5),
+        };
+        self.raise(skill, rank);
+        self.spent += upgrade.cost();
+    }
+}
+
+/// Raise a skill in `ranks` to at least `rank`, with its prerequisites
+fn raise_rank(ranks: &mut BTreeMap<Skill, u8>, skill: Skill, rank: u8) {
+    if let Some((required, required_rank)) = skill.def().requires {
+        raise_rank(ranks, required, required_rank);
+    }
+    let rank = rank.min(skill.def().max_rank);
+    let current = ranks.entry(skill).or_insert(0);
+    *current = (*current).max(rank);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASELINE_AMMO: [AmmoType; 5] = [
+        AmmoType::Sabot,
+        AmmoType::EMP,
+        AmmoType::Plasma,
+        AmmoType::Fusion,
+        AmmoType::Barrage,
+    ];
+
+    #[test]
+    fn training_needs_prerequisites_and_points() {
+        let mut sheet = SkillSheet::default();
+        let mut sp = 100;
+        assert_eq!(
+            sheet.train(Skill::RapidFiring, &mut sp),
+            Err(SkillError::Requires(Skill::Gunnery, 2))
+        );
+        assert_eq!(sheet.train(Skill::Gunnery, &mut sp), Ok(1));
+        assert_eq!(sheet.train(Skill::Gunnery, &mut sp), Ok(2));
+        assert_eq!(sp, 100 - 15 - 30);
+        assert_eq!(sheet.train(Skill::Gunnery, &mut sp), Ok(3));
+        assert_eq!(sp, 10);
+        assert_eq!(sheet.next_rank_cost(Skill::RapidFiring), Ok(25));
+        assert_eq!(
+            sheet.train(Skill::RapidFiring, &mut sp),
+            Err(SkillError::NotEnoughSkillPoints {
+                cost: 25,
+                available: 10
+            })
+        );
+        sheet.raise(Skill::Gunnery, 5);
+        assert_eq!(
+            sheet.next_rank_cost(Skill::Gunnery),
+            Err(SkillError::MaxRank)
+        );
+    }
+
+    #[test]
+    fn bonuses_follow_doctrine_and_class() {
+        let mut sheet = SkillSheet::default();
+        sheet.raise(Skill::SmallProjectileTurret, 2);
+        sheet.raise(Skill::EvasiveManeuvering, 4);
+
+        let rifter = sheet.bonuses(Some(WeaponDoctrine::Projectile), Some(ShipClass::Frigate));
+        assert!((rifter.damage_mult - 1.1).abs() < 1e-6);
+        // Navigation II came with the prerequisite
+        assert!((rifter.speed_mult - 1.04).abs() < 1e-6);
+
+        let crusader = sheet.bonuses(Some(WeaponDoctrine::Laser), Some(ShipClass::Interceptor));
+        assert_eq!(crusader.damage_mult, 1.0);
+        assert!((crusader.speed_mult - 1.04 * 1.2).abs() < 1e-6);
+
+        let mod_ship = sheet.bonuses(None, None);
+        assert_eq!(mod_ship.damage_mult, 1.0);
+        assert!((mod_ship.speed_mult - 1.04).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ranks_unlock_ammo_and_modules() {
+        let mut sheet = SkillSheet::default();
+        assert_eq!(sheet.loadable_ammo(), BASELINE_AMMO);
+        assert!(sheet.can_fit(ShipModule::Autocannon));
+        assert!(!sheet.can_fit(ShipModule::StasisWebifier));
+
+        sheet.raise(Skill::SurgicalStrike, 3);
+        sheet.raise(Skill::PropulsionJamming, 1);
+        assert!(sheet.loadable_ammo().contains(&AmmoType::Hail));
+        assert!(!sheet.loadable_ammo().contains(&AmmoType::Tremor));
+        assert!(sheet.can_fit(ShipModule::StasisWebifier));
+        assert!(!sheet.can_fit(ShipModule::DroneLinkAugmentor));
+    }
+
+    #[test]
+    fn respec_refunds_training_and_keeps_grants() {
+        let mut sheet = SkillSheet::default();
+        let mut sp = 200;
+        assert!(sheet.grant(Skill::SurgicalStrike));
+        assert_eq!(sheet.rank(Skill::Gunnery), 1);
+        assert_eq!(sheet.rank(Skill::SurgicalStrike), 1);
+        sheet.train(Skill::SurgicalStrike, &mut sp).unwrap();
+        sheet.train(Skill::Navigation, &mut sp).unwrap();
+        assert_eq!(sp, 200 - 40 - 15);
+
+        sp += sheet.respec();
+        assert_eq!(sp, 200);
+        assert_eq!(sheet.rank(Skill::Gunnery), 1);
+        assert_eq!(sheet.rank(Skill::SurgicalStrike), 1);
+        assert_eq!(sheet.rank(Skill::Navigation), 0);
+        assert_eq!(sheet.respec(), 0);
+    }
+
+    #[test]
+    fn migrated_upgrades_keep_their_bonuses() {
+        let mut old = UpgradeBonuses::new();
+        let mut sheet = SkillSheet::default();
+        for &upgrade in Upgrade::all() {
+            old.apply(upgrade);
+            sheet.migrate_upgrade(upgrade);
+        }
+        let new = sheet.bonuses(None, None);
+        assert!((new.shield_bonus - old.shield_bonus).abs() < 1e-4);
+        assert!((new.armor_bonus - old.armor_bonus).abs() < 1e-4);
+        assert!((new.capacitor_bonus - old.capacitor_bonus).abs() < 1e-4);
+        assert!((new.fire_rate_mult - old.fire_rate_mult).abs() < 1e-4);
+        assert!((new.damage_mult - old.damage_mult).abs() < 1e-4);
+        assert!((new.speed_mult - old.speed_mult).abs() < 1e-4);
+        assert!((new.shield_regen_mult - old.shield_regen_mult).abs() < 1e-4);
+        assert_eq!(new.rocket_capacity, old.rocket_capacity);
+
+        let paid: u32 = Upgrade::all().iter().map(|u| u.cost()).sum();
+        assert_eq!(sheet.respec(), paid);
+    }
+
+    #[test]
+    fn prerequisites_stay_in_their_category() {
+        for skill in Skill::all() {
+            let def = skill.def();
+            if let Some((required, rank)) = def.requires {
+                assert_eq!(required.def().category, def.category, "{:?}", skill);
+                assert!(rank <= required.def().max_rank, "{:?}", skill);
+                // Listed before the skills that need it
+                let position = |s: &Skill| Skill::all().iter().position(|x| x == s);
+                assert!(position(&required) < position(skill), "{:?}", skill);
+            }
+        }
+    }
+}
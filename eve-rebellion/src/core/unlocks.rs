@@ -0,0 +1,400 @@
+//! Ship Unlocks
+//!
+//! One registry of every hull a hangar offers, keyed by type id, with the
+//! requirements that unlock it. Modules declare their hulls through
+//! `GameModule::ship_unlocks`; the core faction rosters fill in the rest.
+//! Every unlock ends up in `SaveData::unlocked_ships`, whichever way it was
+//! earned, and announces itself with a `ShipUnlockedEvent`.
+
+use bevy::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use super::{AchievementRegistry, Faction, PilotProfiles, SaveData};
+
+/// Ship unlock plugin
+pub struct ShipUnlockPlugin;
+
+impl Plugin for ShipUnlockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShipUnlockRegistry>()
+            .add_event::<ShipUnlockedEvent>()
+            .add_systems(
+                Update,
+                grant_ship_unlocks.run_if(resource_changed::<SaveData>),
+            );
+    }
+}
+
+/// Something the player must do before a hull can be flown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnlockRequirement {
+    /// Clear `stage` of a campaign; `faction`/`enemy` are the progress keys
+    /// passed to `SaveData::complete_stage`
+    Stage {
+        faction: String,
+        enemy: String,
+        stage: u32,
+    },
+    /// Earn an achievement (by id)
+    Achievement(String),
+    /// Reach a lifetime best score
+    Score(u64),
+    /// Buy the hull with Skill Points from the hangar
+    SkillPoints(u32),
+}
+
+impl UnlockRequirement {
+    /// Clear `stage` of a core faction's campaign against its rival
+    pub fn stage(faction: Faction, stage: u32) -> Self {
+        Self::Stage {
+            faction: faction.short_name().to_string(),
+            enemy: faction.rival().short_name().to_string(),
+            stage,
+        }
+    }
+
+    /// Met by play alone; purchases are only met once bought
+    pub fn is_met(&self, save: &SaveData) -> bool {
+        match self {
+            Self::Stage {
+                faction,
+                enemy,
+                stage,
+            } => save.get_highest_stage(faction, enemy) >= *stage,
+            Self::Achievement(id) => save.has_achievement(id),
+            Self::Score(score) => save.lifetime_stats.highest_score >= *score,
+            Self::SkillPoints(_) => false,
+        }
+    }
+
+    /// Short hangar hint, e.g. "Clear stage 4"
+    pub fn hint(&self, achievements: &AchievementRegistry) -> String {
+        match self {
+            Self::Stage { stage, .. } => format!("Clear stage {}", stage),
+            Self::Achievement(id) => {
+                let name = achievements.get(id).map_or(id.as_str(), |a| a.name);
+                format!("Earn {}", name)
+            }
+            Self::Score(score) => format!("Score {}", score),
+            Self::SkillPoints(cost) => format!("Buy for {} SP", cost),
+        }
+    }
+}
+
+/// A hull and everything required to fly it; no requirements = always
+/// available
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShipUnlock {
+    pub type_id: u32,
+    pub name: &'static str,
+    pub requirements: Vec<UnlockRequirement>,
+}
+
+impl ShipUnlock {
+    pub fn new(type_id: u32, name: &'static str, requirements: Vec<UnlockRequirement>) -> Self {
+        Self {
+            type_id,
+            name,
+            requirements,
+        }
+    }
+
+    /// Skill Point price, if the hull is bought
+    pub fn cost(&self) -> Option<u32> {
+        self.requirements.iter().find_map(|r| match r {
+            UnlockRequirement::SkillPoints(cost) => Some(*cost),
+            _ => None,
+        })
+    }
+
+    /// Unlocked by clearing exactly this stage of this campaign
+    pub fn cleared_by(&self, faction: &str, enemy: &str, stage: u32) -> bool {
+        self.requirements.iter().any(|r| {
+            matches!(r, UnlockRequirement::Stage { faction: f, enemy: e, stage: s }
+                if f == faction && e == enemy && *s == stage)
+        })
+    }
+}
+
+/// Why a hull could not be bought
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnlockError {
+    /// Already flyable
+    AlreadyUnlocked,
+    /// Not for sale, or other requirements are still open
+    NotPurchasable,
+    NotEnoughSkillPoints {
+        cost: u32,
+        available: u32,
+    },
+}
+
+impl fmt::Display for UnlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnlockError::AlreadyUnlocked => write!(f, "Already unlocked"),
+            UnlockError::NotPurchasable => write!(f, "Not available for purchase"),
+            UnlockError::NotEnoughSkillPoints { cost, available } => {
+                write!(f, "Needs {} SP ({} available)", cost, available)
+            }
+        }
+    }
+}
+
+/// Every hull a hangar can offer, by type id
+#[derive(Resource, Default, Debug)]
+pub struct ShipUnlockRegistry {
+    ships: BTreeMap<u32, ShipUnlock>,
+}
+
+impl ShipUnlockRegistry {
+    /// First registration of a hull wins; false if it was already taken
+    pub fn register(&mut self, unlock: ShipUnlock) -> bool {
+        if self.ships.contains_key(&unlock.type_id) {
+            return false;
+        }
+        self.ships.insert(unlock.type_id, unlock);
+        true
+    }
+
+    /// Core faction rosters, keyed on the core campaign's progress
+    pub fn register_core_factions(&mut self) {
+        for faction in Faction::all() {
+            for ship in faction.player_ships() {
+                let requirements = if ship.unlock_stage > 0 {
+                    vec![UnlockRequirement::stage(*faction, ship.unlock_stage)]
+                } else {
+                    Vec::new()
+                };
+                self.register(ShipUnlock::new(ship.type_id, ship.name, requirements));
+            }
+        }
+    }
+
+    pub fn get(&self, type_id: u32) -> Option<&ShipUnlock> {
+        self.ships.get(&type_id)
+    }
+
+    /// Unregistered hulls are never locked
+    pub fn is_unlocked(&self, type_id: u32, save: &SaveData) -> bool {
+        if save.unlocked_ships.contains(&type_id) {
+            return true;
+        }
+        self.get(type_id)
+            .is_none_or(|ship| ship.requirements.iter().all(|r| r.is_met(save)))
+    }
+
+    /// What is still missing, or `None` if the hull is unlocked
+    pub fn hint(
+        &self,
+        type_id: u32,
+        save: &SaveData,
+        achievements: &AchievementRegistry,
+    ) -> Option<String> {
+        if self.is_unlocked(type_id, save) {
+            return None;
+        }
+        let ship = self.get(type_id)?;
+        let open: Vec<String> = ship
+            .requirements
+            .iter()
+            .filter(|r| !r.is_met(save))
+            .map(|r| r.hint(achievements))
+            .collect();
+        Some(open.join(", "))
+    }
+
+    /// Locked hulls whose requirements have all been met by play
+    pub fn newly_earned(&self, save: &SaveData) -> Vec<u32> {
+        self.ships
+            .values()
+            .filter(|ship| !ship.requirements.is_empty())
+            .filter(|ship| !save.unlocked_ships.contains(&ship.type_id))
+            .filter(|ship| ship.requirements.iter().all(|r| r.is_met(save)))
+            .map(|ship| ship.type_id)
+            .collect()
+    }
+
+    /// Spend Skill Points on a hull whose other requirements are met
+    pub fn purchase(&self, type_id: u32, save: &mut SaveData) -> Result<(), UnlockError> {
+        if self.is_unlocked(type_id, save) {
+            return Err(UnlockError::AlreadyUnlocked);
+        }
+        let ship = self.get(type_id).ok_or(UnlockError::NotPurchasable)?;
+        let cost = ship.cost().ok_or(UnlockError::NotPurchasable)?;
+        let other_open = ship
+            .requirements
+            .iter()
+            .any(|r| !matches!(r, UnlockRequirement::SkillPoints(_)) && !r.is_met(save));
+        if other_open {
+            return Err(UnlockError::NotPurchasable);
+        }
+        if save.skill_points < cost {
+            return Err(UnlockError::NotEnoughSkillPoints {
+                cost,
+                available: save.skill_points,
+            });
+        }
+        save.skill_points -= cost;
+        save.unlock_ship(type_id);
+        Ok(())
+    }
+}
+
+/// A hull became flyable
+#[derive(Event, Debug, Clone)]
+pub struct ShipUnlockedEvent {
+    pub type_id: u32,
+    pub name: &'static str,
+}
+
+/// Records hulls earned by play and announces every new entry of
+/// `unlocked_ships`, however it got there. Loading a save or switching
+/// pilots only takes a fresh snapshot.
+fn grant_ship_unlocks(
+    mut save: ResMut<SaveData>,
+    registry: Res<ShipUnlockRegistry>,
+    profiles: Res<PilotProfiles>,
+    mut known: Local<Option<(Option<String>, HashSet<u32>)>>,
+    mut events: EventWriter<ShipUnlockedEvent>,
+) {
+    for type_id in registry.newly_earned(&save) {
+        save.unlock_ship(type_id);
+    }
+
+    let pilot = profiles.active.clone();
+    match known.as_mut() {
+        Some((known_pilot, ships)) if *known_pilot == pilot => {
+            for &type_id in save.unlocked_ships.difference(ships) {
+                let name = registry.get(type_id).map_or("Ship", |s| s.name);
+                info!("Ship unlocked: {} ({})", name, type_id);
+                events.send(ShipUnlockedEvent { type_id, name });
+            }
+            *ships = save.unlocked_ships.clone();
+        }
+        _ => *known = Some((pilot, save.unlocked_ships.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> ShipUnlockRegistry {
+        let mut registry = ShipUnlockRegistry::default();
+        registry.register(ShipUnlock::new(1, "Free", Vec::new()));
+        registry.register(ShipUnlock::new(
+            2,
+            "Staged",
+            vec![UnlockRequirement::stage(Faction::Minmatar, 4)],
+        ));
+        registry.register(ShipUnlock::new(
+            3,
+            "Bought",
+            vec![
+                UnlockRequirement::Score(1000),
+                UnlockRequirement::SkillPoints(5),
+            ],
+        ));
+        registry.register(ShipUnlock::new(
+            4,
+            "Earned",
+            vec![UnlockRequirement::Achievement("FirstBlood".into())],
+        ));
+        registry
+    }
+
+    #[test]
+    fn first_registration_wins() {
+        let mut registry = registry();
+        assert!(!registry.register(ShipUnlock::new(2, "Other", Vec::new())));
+        assert_eq!(registry.get(2).unwrap().name, "Staged");
+        assert!(registry.is_unlocked(99, &SaveData::default()));
+    }
+
+    #[test]
+    fn requirements_are_met_by_play() {
+        let registry = registry();
+        let mut save = SaveData::default();
+        assert!(registry.is_unlocked(1, &save));
+        assert!(!registry.is_unlocked(2, &save));
+        assert!(registry.newly_earned(&save).is_empty());
+
+        save.complete_stage("MINMATAR", "AMARR", 4, 4);
+        save.achievements.insert("FirstBlood".into());
+        assert!(registry.is_unlocked(2, &save));
+        assert_eq!(registry.newly_earned(&save), vec![2, 4]);
+
+        // Progress in another campaign does not count
+        let mut other = SaveData::default();
+        other.complete_stage("AMARR", "MINMATAR", 9, 9);
+        assert!(!registry.is_unlocked(2, &other));
+    }
+
+    #[test]
+    fn hints_list_what_is_open() {
+        let registry = registry();
+        let achievements = AchievementRegistry::default();
+        let mut save = SaveData::default();
+        assert_eq!(registry.hint(1, &save, &achievements), None);
+        assert_eq!(
+            registry.hint(2, &save, &achievements).as_deref(),
+            Some("Clear stage 4")
+        );
+        assert_eq!(
+            registry.hint(3, &save, &achievements).as_deref(),
+            Some("Score 1000, Buy for 5 SP")
+        );
+        save.lifetime_stats.highest_score = 1000;
+        assert_eq!(
+            registry.hint(3, &save, &achievements).as_deref(),
+            Some("Buy for 5 SP")
+        );
+    }
+
+    #[test]
+    fn purchase_needs_other_requirements_and_points() {
+        let registry = registry();
+        let mut save = SaveData {
+            skill_points: 3,
+            ..default()
+        };
+        assert_eq!(
+            registry.purchase(3, &mut save),
+            Err(UnlockError::NotPurchasable)
+        );
+        assert_eq!(
+            registry.purchase(2, &mut save),
+            Err(UnlockError::NotPurchasable)
+        );
+
+        save.lifetime_stats.highest_score = 5000;
+        assert_eq!(
+            registry.purchase(3, &mut save),
+            Err(UnlockError::NotEnoughSkillPoints {
+                cost: 5,
+                available: 3
+            })
+        );
+
+        save.add_skill_points(4);
+        assert_eq!(registry.purchase(3, &mut save), Ok(()));
+        assert_eq!(save.skill_points, 2);
+        assert!(registry.is_unlocked(3, &save));
+        assert_eq!(
+            registry.purchase(3, &mut save),
+            Err(UnlockError::AlreadyUnlocked)
+        );
+    }
+
+    #[test]
+    fn core_rosters_use_core_progress_keys() {
+        let mut registry = ShipUnlockRegistry::default();
+        registry.register_core_factions();
+        let wolf = registry.get(11371).unwrap();
+        assert_eq!(wolf.name, "Wolf");
+        assert!(wolf.cleared_by("MINMATAR", "AMARR", 4));
+        assert!(registry.get(587).unwrap().requirements.is_empty());
+    }
+}
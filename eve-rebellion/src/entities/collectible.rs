@@ -6,7 +6,7 @@
 #![allow(dead_code)]
 
 use crate::core::*;
-use crate::systems::{check_liberation_milestone, ComboHeatSystem, DialogueEvent};
+use crate::systems::{check_liberation_milestone, DialogueEvent, HeatSystem};
 use bevy::prelude::*;
 use std::f32::consts::TAU;
 
@@ -456,9 +456,10 @@ fn handle_pickup_effects(
         With<super::Player>,
     >,
     mut score: ResMut<ScoreSystem>,
+    mut score_events: EventWriter<ScoreEvent>,
     mut progress: ResMut<GameProgress>,
     mut save_data: ResMut<crate::core::SaveData>,
-    mut heat_system: ResMut<ComboHeatSystem>,
+    mut heat_system: ResMut<HeatSystem>,
     mut dialogue_events: EventWriter<DialogueEvent>,
     mut rumble_events: EventWriter<crate::systems::RumbleRequest>,
 ) {
@@ -471,7 +472,7 @@ fn handle_pickup_effects(
             CollectibleType::LiberationPod => {
                 let old_count = score.souls_liberated;
                 score.souls_liberated += 1;
-                score.add_score(500);
+                score_events.send(ScoreEvent::pickup(500));
 
                 // Award skill points (1 SP per soul liberated)
                 save_data.add_skill_points(1);
@@ -110,6 +110,33 @@ impl Default for EnemyStats {
     }
 }
 
+/// Enemy resists. The single health pool is read as shield, then armor,
+/// then hull in the proportions of the tank doctrine.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EnemyResists {
+    pub doctrine: TankDoctrine,
+    pub profile: ResistProfile,
+}
+
+impl EnemyResists {
+    pub fn for_faction(faction: Faction) -> Self {
+        Self {
+            doctrine: faction.tank_type(),
+            profile: ResistProfile::for_faction(faction),
+        }
+    }
+
+    /// Layer a hit lands on at the enemy's current health
+    pub fn layer(&self, stats: &EnemyStats) -> DamageLayer {
+        let fraction = if stats.max_health > 0.0 {
+            stats.health / stats.max_health
+        } else {
+            0.0
+        };
+        self.doctrine.layer_at(fraction)
+    }
+}
+
 /// Enemy weapon
 #[derive(Component, Debug, Clone)]
 pub struct EnemyWeapon {
@@ -212,6 +239,14 @@ impl Default for EnemySpawner {
     }
 }
 
+/// Enemies launched together as one formation; `leader` is the first one
+/// spawned and identifies the group even after it is destroyed
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Formation {
+    pub leader: Entity,
+    pub size: u32,
+}
+
 /// Triglavian Disintegrator ramping damage component
 /// Damage increases the longer the beam stays on target
 #[derive(Component, Debug, Clone)]
@@ -613,6 +648,7 @@ fn enemy_shooting(
     mut commands: Commands,
     time: Res<Time>,
     player_tracker: Res<PlayerTracker>,
+    difficulty: Res<crate::core::DifficultySettings>,
     mut query: Query<(&Transform, &mut EnemyWeapon, &EnemyAI), With<Enemy>>,
 ) {
     let dt = time.delta_secs();
@@ -626,7 +662,7 @@ fn enemy_shooting(
 
         weapon.cooldown -= dt;
         if weapon.cooldown <= 0.0 {
-            weapon.cooldown = 1.0 / weapon.fire_rate;
+            weapon.cooldown = 1.0 / (weapon.fire_rate * difficulty.enemy.fire_rate_multiplier);
 
             let pos = transform.translation.truncate();
 
@@ -809,7 +845,7 @@ fn get_faction_engine_trail(type_id: u32) -> EngineTrail {
         // Caldari - blue engines (frigates, destroyers, battlecruisers)
         603 | 602 | 583 | 16238 | 24688 | 11381 | 11387 | 35683 => EngineTrail::caldari(),
         // Gallente - green engines (frigates, destroyers, battlecruisers)
-        593 | 594 | 608 | 16242 | 24700 | 11371 | 35685 => EngineTrail::gallente(),
+        593 | 594 | 608 | 16242 | 24700 | 11377 | 35685 => EngineTrail::gallente(),
         // Minmatar - rust engines
         587 | 585 | 598 => EngineTrail::minmatar(),
         _ => EngineTrail::amarr(), // Default to Amarr (enemies)
@@ -836,6 +872,34 @@ fn get_faction_weapon(type_id: u32) -> WeaponType {
     }
 }
 
+/// Get resist profile for faction based on type_id
+fn get_faction_resists(type_id: u32) -> EnemyResists {
+    match type_id {
+        // Caldari - shield tanked
+        603 | 602 | 583 | 16238 | 24688 | 11381 | 11387 | 35683 => {
+            EnemyResists::for_faction(Faction::Caldari)
+        }
+        // Gallente - armor tanked, hardened vs kinetic/thermal
+        593 | 594 | 608 | 16242 | 24700 | 11377 | 35685 => {
+            EnemyResists::for_faction(Faction::Gallente)
+        }
+        // Minmatar - speed tanked
+        587 | 585 | 598 => EnemyResists::for_faction(Faction::Minmatar),
+        // Triglavian - armor tanked
+        47269 | 49710 | 47271 | 49711 | 47273 | 47466 | 56756 => EnemyResists {
+            doctrine: TankDoctrine::Armor,
+            profile: ResistProfile::for_doctrine(TankDoctrine::Armor),
+        },
+        // EDENCOM - shield tanked
+        56757 | 56759 | 56760 => EnemyResists {
+            doctrine: TankDoctrine::Shield,
+            profile: ResistProfile::for_doctrine(TankDoctrine::Shield),
+        },
+        // Amarr and anything unknown - armor tanked, hardened vs EM/thermal
+        _ => EnemyResists::for_faction(Faction::Amarr),
+    }
+}
+
 /// Get rotation correction for ships with non-standard orientations from CCP renders
 /// Returns additional rotation in radians to apply on top of base rotation
 pub fn get_ship_rotation_correction(type_id: u32) -> f32 {
@@ -959,6 +1023,7 @@ pub fn spawn_enemy(
 
     let base_color = get_enemy_color(type_id);
     let weapon_type = get_faction_weapon(type_id);
+    let resists = get_faction_resists(type_id);
 
     // Configure weapon based on faction
     let weapon = EnemyWeapon {
@@ -1038,6 +1103,7 @@ pub fn spawn_enemy(
             .spawn((
                 Enemy,
                 stats,
+                resists,
                 weapon,
                 ai,
                 engine_trail,
@@ -1056,6 +1122,7 @@ pub fn spawn_enemy(
             .spawn((
                 Enemy,
                 stats,
+                resists,
                 weapon,
                 ai,
                 engine_trail,
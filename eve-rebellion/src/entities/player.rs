@@ -5,6 +5,7 @@
 #![allow(dead_code)]
 
 use crate::core::*;
+use crate::games::{core_enabled, CoreSystem};
 use crate::systems::{Ability, AbilityEffects, AbilityType, EngineTrail, ManeuverState};
 use bevy::prelude::*;
 
@@ -52,6 +53,8 @@ pub struct ShipStats {
     pub capacitor: f32,
     /// Capacitor recharge rate per second
     pub capacitor_recharge: f32,
+    /// Resists per layer and damage type
+    pub resists: ResistProfile,
 }
 
 impl Default for ShipStats {
@@ -71,6 +74,7 @@ impl Default for ShipStats {
             max_capacitor: CAP_FRIGATE,
             capacitor: CAP_FRIGATE,
             capacitor_recharge: 10.0,
+            resists: ResistProfile::default(),
         }
     }
 }
@@ -117,35 +121,39 @@ impl ShipStats {
 
     /// Take damage with detailed layer information
     pub fn take_damage_detailed(&mut self, damage: f32, damage_type: DamageType) -> DamageResult {
-        // Apply damage type resistances (simplified)
-        let resistance = match damage_type {
-            DamageType::EM => 0.0, // Shield weak to EM
-            DamageType::Thermal => 0.2,
-            DamageType::Kinetic => 0.4,
-            DamageType::Explosive => 0.5, // Shield strong vs Explosive
-        };
-
-        let mut remaining = damage * (1.0 - resistance);
+        // Raw damage left over after each layer; each layer applies its own
+        // resists, so overflow from a broken shield is resisted by the armor
+        let mut remaining = damage;
         let mut shield_damage = 0.0;
         let mut armor_damage = 0.0;
         let mut hull_damage = 0.0;
 
         // Damage order: Shield -> Armor -> Hull
         if self.shield > 0.0 {
-            shield_damage = remaining.min(self.shield);
+            let mult = self.resists.multiplier(DamageLayer::Shield, damage_type);
+            shield_damage = (remaining * mult).min(self.shield);
             self.shield -= shield_damage;
-            remaining -= shield_damage;
+            remaining = if mult > 0.0 {
+                remaining - shield_damage / mult
+            } else {
+                0.0
+            };
             self.shield_timer = self.shield_recharge_delay;
         }
 
         if remaining > 0.0 && self.armor > 0.0 {
-            armor_damage = remaining.min(self.armor);
+            let mult = self.resists.multiplier(DamageLayer::Armor, damage_type);
+            armor_damage = (remaining * mult).min(self.armor);
             self.armor -= armor_damage;
-            remaining -= armor_damage;
+            remaining = if mult > 0.0 {
+                remaining - armor_damage / mult
+            } else {
+                0.0
+            };
         }
 
         if remaining > 0.0 {
-            hull_damage = remaining;
+            hull_damage = remaining * self.resists.multiplier(DamageLayer::Hull, damage_type);
             self.hull -= hull_damage;
         }
 
@@ -226,6 +234,22 @@ pub struct Weapon {
     pub bullet_color: Color,
     /// Current ammo type (for autocannons)
     pub ammo_type: AmmoType,
+    /// Ammo the pilot is trained to load, in hotkey order
+    pub loadable_ammo: Vec<AmmoType>,
+}
+
+impl Weapon {
+    /// Next (or previous) loadable ammo type
+    pub fn cycle_ammo(&mut self, forward: bool) {
+        let mut ammo = self.ammo_type;
+        for _ in 0..7 {
+            ammo = if forward { ammo.next() } else { ammo.prev() };
+            if self.loadable_ammo.contains(&ammo) {
+                self.ammo_type = ammo;
+                return;
+            }
+        }
+    }
 }
 
 impl Default for Weapon {
@@ -240,6 +264,7 @@ impl Default for Weapon {
             aim_direction: Vec2::Y,                   // Up by default
             bullet_color: Color::srgb(1.0, 0.8, 0.4), // Default orange tracer
             ammo_type: AmmoType::default(),           // Sabot by default
+            loadable_ammo: SkillSheet::default().loadable_ammo(),
         }
     }
 }
@@ -298,26 +323,19 @@ impl Plugin for PlayerPlugin {
                 Update,
                 (player_movement, player_shooting, update_player_stats)
                     .run_if(in_state(GameState::Playing))
-                    .run_if(not_last_stand),
+                    .run_if(core_enabled(CoreSystem::PlayerControl)),
             )
             .add_systems(OnExit(GameState::Playing), despawn_player);
     }
 }
 
-/// Run condition: NOT in Last Stand mode
-fn not_last_stand(last_stand: Option<Res<crate::games::caldari_gallente::LastStandState>>) -> bool {
-    match last_stand {
-        Some(ls) => !ls.active,
-        None => true,
-    }
-}
-
 /// Spawn player at start of gameplay
 fn spawn_player(
     mut commands: Commands,
     session: Res<GameSession>,
     sprite_cache: Res<crate::assets::ShipSpriteCache>,
     save_data: Res<crate::core::SaveData>,
+    difficulty: Res<crate::core::DifficultySettings>,
     last_stand: Option<Res<crate::games::caldari_gallente::LastStandState>>,
 ) {
     // Skip player spawn in Last Stand mode (titan is spawned instead)
@@ -338,44 +356,65 @@ fn spawn_player(
     let faction = session.player_faction;
     let type_id = ship_def.type_id;
 
-    // Get upgrade bonuses from persistent save data
-    let bonuses = save_data.get_upgrade_bonuses();
+    // Skill bonuses for this doctrine and hull class
+    let skills = &save_data.skills;
+    let bonuses = skills.bonuses(Some(faction.weapon_type()), Some(ship_def.class));
+
+    // Saved fitting for this hull; one that no longer fits is ignored, and
+    // modules the pilot has not trained are left in the hangar
+    let mut fitting = save_data.fitting(type_id);
+    if let Err(error) = fitting.check(&SlotLayout::for_ship(ship_def)) {
+        warn!("Ignoring fitting for {}: {}", ship_def.name, error);
+        fitting = ShipFitting::default();
+    }
+    for module in fitting.remove_unless(|m| skills.can_fit(m)) {
+        warn!(
+            "Unfitting {} from {}: it is not trained",
+            module.name(),
+            ship_def.name
+        );
+    }
+    let fitted = fitting.stats(ship_def);
 
-    // Create stats from ship definition + upgrade bonuses
-    let base_shield = ship_def.health * 0.4;
-    let base_armor = ship_def.health * 0.35;
-    let base_hull = ship_def.health * 0.25;
+    // Create stats from fitted ship + skill bonuses
+    let shield = (fitted.shield + bonuses.shield_bonus) * difficulty.player.shield_multiplier;
+    let base_armor = fitted.armor;
+    let base_hull = fitted.hull;
 
     let stats = ShipStats {
         type_id,
         name: ship_def.name.to_string(),
-        max_shield: base_shield + bonuses.shield_bonus,
-        shield: base_shield + bonuses.shield_bonus,
-        shield_recharge: PLAYER_SHIELD_RECHARGE_RATE * bonuses.shield_regen_mult,
+        max_shield: shield,
+        shield,
+        shield_recharge: PLAYER_SHIELD_RECHARGE_RATE
+            * fitted.shield_regen
+            * bonuses.shield_regen_mult,
         shield_recharge_delay: PLAYER_SHIELD_RECHARGE_DELAY,
         shield_timer: 0.0,
         max_armor: base_armor + bonuses.armor_bonus,
         armor: base_armor + bonuses.armor_bonus,
         max_hull: base_hull,
         hull: base_hull,
-        max_capacitor: CAP_FRIGATE + bonuses.capacitor_bonus,
-        capacitor: CAP_FRIGATE + bonuses.capacitor_bonus,
-        capacitor_recharge: 10.0,
+        max_capacitor: fitted.capacitor + bonuses.capacitor_bonus,
+        capacitor: fitted.capacitor + bonuses.capacitor_bonus,
+        capacitor_recharge: 10.0 * fitted.capacitor_regen * bonuses.capacitor_regen_mult,
+        resists: ResistProfile::for_faction(faction),
     };
 
-    // Create movement from ship speed + upgrade bonus
+    // Create movement from ship speed + skill bonus
     let movement = Movement {
         velocity: Vec2::ZERO,
-        max_speed: ship_def.speed * bonuses.speed_mult,
-        acceleration: ship_def.speed * 3.0 * bonuses.speed_mult,
+        max_speed: fitted.speed * bonuses.speed_mult,
+        acceleration: fitted.speed * 3.0 * bonuses.speed_mult,
         friction: 8.0,
     };
 
-    // Create weapon from ship stats + upgrade bonuses
+    // Create weapon from ship stats + skill bonuses
     let weapon = Weapon {
-        fire_rate: ship_def.fire_rate * bonuses.fire_rate_mult,
-        damage: ship_def.damage * bonuses.damage_mult,
+        fire_rate: fitted.fire_rate * bonuses.fire_rate_mult,
+        damage: fitted.damage * bonuses.damage_mult,
         bullet_color: faction.weapon_type().bullet_color(),
+        loadable_ammo: skills.loadable_ammo(),
         ..default()
     };
 
@@ -396,8 +435,10 @@ fn spawn_player(
         engine_trail.offset.y = -engine_trail.offset.y;
     }
 
-    // Create ability from ship definition
-    let ability_type = AbilityType::from_special(ship_def.special);
+    // Ability from a fitted module, else from the ship definition
+    let ability_type = fitting
+        .ability()
+        .unwrap_or_else(|| AbilityType::from_special(ship_def.special));
 
     info!("Ship ability: {:?} ({})", ability_type, ability_type.name());
 
@@ -557,7 +598,10 @@ fn player_shooting(
     mut query: Query<(&Transform, &mut Weapon, &AbilityEffects), With<Player>>,
     mut fire_events: EventWriter<PlayerFireEvent>,
     salt_miner: Res<SaltMinerSystem>,
-    mut heat_system: ResMut<crate::systems::ComboHeatSystem>,
+    mut heat_system: ResMut<crate::systems::HeatSystem>,
+    accessibility: Res<AccessibilitySettings>,
+    palette: Res<Palette>,
+    mut fire_latched: Local<bool>,
 ) {
     let Ok((transform, mut weapon, ability_effects)) = query.get_single_mut() else {
         return;
@@ -570,25 +614,30 @@ fn player_shooting(
         weapon.cooldown -= dt;
     }
 
-    // Ammo switching (only for autocannons)
+    // Ammo switching (only for autocannons, and only trained ammo)
     if weapon.weapon_type == WeaponType::Autocannon {
-        // Number keys 1-5 for direct selection
-        if keyboard.just_pressed(KeyCode::Digit1) {
-            weapon.ammo_type = AmmoType::Sabot;
-        } else if keyboard.just_pressed(KeyCode::Digit2) {
-            weapon.ammo_type = AmmoType::EMP;
-        } else if keyboard.just_pressed(KeyCode::Digit3) {
-            weapon.ammo_type = AmmoType::Plasma;
-        } else if keyboard.just_pressed(KeyCode::Digit4) {
-            weapon.ammo_type = AmmoType::Fusion;
-        } else if keyboard.just_pressed(KeyCode::Digit5) {
-            weapon.ammo_type = AmmoType::Barrage;
+        // Number keys 1-7 for direct selection
+        let picked = [
+            (KeyCode::Digit1, AmmoType::Sabot),
+            (KeyCode::Digit2, AmmoType::EMP),
+            (KeyCode::Digit3, AmmoType::Plasma),
+            (KeyCode::Digit4, AmmoType::Fusion),
+            (KeyCode::Digit5, AmmoType::Barrage),
+            (KeyCode::Digit6, AmmoType::Hail),
+            (KeyCode::Digit7, AmmoType::Tremor),
+        ]
+        .into_iter()
+        .find(|(key, _)| keyboard.just_pressed(*key));
+        if let Some((_, ammo)) = picked {
+            if weapon.loadable_ammo.contains(&ammo) {
+                weapon.ammo_type = ammo;
+            }
         }
         // Q/E or D-pad left/right for cycling
         else if keyboard.just_pressed(KeyCode::KeyQ) || joystick.dpad_just_left() {
-            weapon.ammo_type = weapon.ammo_type.prev();
+            weapon.cycle_ammo(false);
         } else if keyboard.just_pressed(KeyCode::KeyE) || joystick.dpad_just_right() {
-            weapon.ammo_type = weapon.ammo_type.next();
+            weapon.cycle_ammo(true);
         }
     }
 
@@ -620,8 +669,18 @@ fn player_shooting(
         weapon.aim_direction = aim.normalize();
     }
 
-    // Fire if: Space pressed, OR right stick is pushed (twin-stick style)
-    let fire_pressed = keyboard.pressed(KeyCode::Space) || joystick_firing;
+    // Fire if: Space fires (per fire mode), OR right stick is pushed (twin-stick style)
+    let keyboard_firing = match accessibility.fire_mode {
+        FireMode::Hold => keyboard.pressed(KeyCode::Space),
+        FireMode::Toggle => {
+            if keyboard.just_pressed(KeyCode::Space) {
+                *fire_latched = !*fire_latched;
+            }
+            *fire_latched
+        }
+        FireMode::Auto => true,
+    };
+    let fire_pressed = keyboard_firing || joystick_firing;
 
     if fire_pressed && weapon.cooldown <= 0.0 {
         // Track heat (doesn't block firing, just affects fire rate)
@@ -651,7 +710,7 @@ fn player_shooting(
 
         // Use ammo color for autocannons, weapon color for others
         let bullet_color = if weapon.weapon_type == WeaponType::Autocannon {
-            weapon.ammo_type.color()
+            palette.ammo(weapon.ammo_type)
         } else {
             weapon.bullet_color
         };
@@ -16,6 +16,14 @@ pub struct PlayerProjectile;
 #[derive(Component, Debug)]
 pub struct EnemyProjectile;
 
+/// Enemy projectile inside the player's graze margin
+#[derive(Component, Debug)]
+pub struct Grazing;
+
+/// Enemy projectile that left the graze margin without hitting
+#[derive(Component, Debug)]
+pub struct Grazed;
+
 /// Seeking/homing projectile - tracks nearest enemy
 #[derive(Component, Debug)]
 pub struct SeekingProjectile {
@@ -160,7 +168,7 @@ fn spawn_player_projectiles(
 
         // Determine damage type from weapon
         let damage_type = match event.weapon_type {
-            WeaponType::Autocannon | WeaponType::Artillery => DamageType::Kinetic,
+            WeaponType::Autocannon | WeaponType::Artillery => event.ammo_type.damage_type(),
             WeaponType::Laser => DamageType::EM,
             WeaponType::Railgun => DamageType::Kinetic,
             WeaponType::MissileLauncher => DamageType::Explosive,
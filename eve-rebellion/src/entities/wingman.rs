@@ -351,6 +351,7 @@ fn wingman_damage(
     mut commands: Commands,
     projectile_query: Query<(Entity, &Transform, &ProjectileDamage), With<super::EnemyProjectile>>,
     mut wingmen_query: Query<(Entity, &Transform, &mut WingmanStats), With<Wingman>>,
+    mut lost_events: EventWriter<WingmanLostEvent>,
 ) {
     for (proj_entity, proj_transform, proj_damage) in projectile_query.iter() {
         let proj_pos = proj_transform.translation.truncate();
@@ -368,6 +369,9 @@ fn wingman_damage(
 
                 if stats.health <= 0.0 {
                     commands.entity(wingman_entity).despawn_recursive();
+                    lost_events.send(WingmanLostEvent {
+                        position: wingman_pos,
+                    });
                     info!("Wingman destroyed!");
                 }
 
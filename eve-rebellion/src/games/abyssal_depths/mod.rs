@@ -7,18 +7,58 @@ use bevy::prelude::*;
 
 use crate::core::*;
 use crate::entities::{Enemy, Player};
-use crate::games::{GameModuleInfo, ModuleRegistry};
+use crate::games::{
+    sourced_replacements, ContentSource, CoreSystem, GameModule, GameModuleInfo, ModuleHudSet,
+};
 use crate::systems::JoystickState;
 
-/// Abyssal Depths game module plugin
-pub struct AbyssalDepthsPlugin;
+/// Module id
+pub const MODULE_ID: &str = "abyssal_depths";
+
+/// Abyssal Depths module - room-based spawning with no campaign, factions or result screens
+pub struct AbyssalDepthsModule;
+
+impl GameModule for AbyssalDepthsModule {
+    fn id(&self) -> &'static str {
+        MODULE_ID
+    }
+
+    fn info(&self) -> GameModuleInfo {
+        GameModuleInfo {
+            id: MODULE_ID,
+            display_name: "ABYSSAL DEPTHS",
+            subtitle: "Triglavian Extraction",
+            description: "Enter the Abyss. Survive 3 rooms. Extract or die.",
+            factions: vec![], // No faction selection - always Triglavian enemies
+        }
+    }
+
+    fn achievements(&self) -> &'static [AchievementSet] {
+        ACHIEVEMENTS
+    }
+
+    fn wave_source(&self) -> ContentSource {
+        ContentSource::Module
+    }
+
+    fn boss_source(&self) -> ContentSource {
+        ContentSource::Module
+    }
+
+    fn replaces(&self, _world: &World) -> Vec<CoreSystem> {
+        let mut replaced = sourced_replacements(self);
+        replaced.extend([
+            CoreSystem::Spawning,
+            CoreSystem::FactionSelect,
+            CoreSystem::ResultScreens,
+        ]);
+        replaced
+    }
 
-impl Plugin for AbyssalDepthsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AbyssalState>()
             .add_event::<AbyssalRoomClearEvent>()
             .add_event::<AbyssalExtractionEvent>()
-            .add_systems(Startup, register_module)
             .add_systems(
                 OnEnter(GameState::Playing),
                 setup_abyssal.run_if(is_abyssal),
@@ -34,28 +74,100 @@ impl Plugin for AbyssalDepthsPlugin {
                     check_room_clear,
                     update_gate,
                     handle_extraction,
-                    abyssal_hud,
                 )
                     .run_if(in_state(GameState::Playing))
                     .run_if(is_abyssal),
-            );
+            )
+            .add_systems(Update, abyssal_hud.in_set(ModuleHudSet).run_if(is_abyssal))
+            // Extraction leaves Playing in the same frame, so this runs in any state
+            .add_systems(Update, abyssal_achievement_progress.run_if(is_abyssal));
     }
 }
 
-/// Run condition: is the active module Abyssal Depths?
-fn is_abyssal(active: Res<crate::games::ActiveModule>) -> bool {
-    active.module_id.as_deref() == Some("abyssal_depths")
+/// Rooms cleared across all dives
+pub const STAT_ROOMS_CLEARED: &str = "abyssal_rooms";
+/// Successful extractions
+pub const STAT_EXTRACTIONS: &str = "abyssal_extractions";
+/// Most seconds left on the clock at extraction
+pub const STAT_EXTRACTION_TIME_LEFT: &str = "abyssal_time_left";
+
+static ACHIEVEMENTS: &[AchievementSet] = &[AchievementSet {
+    name: "Abyssal Depths",
+    achievements: &[
+        AchievementDef::new(
+            "AbyssalFirstPocket",
+            "Into the Abyss",
+            "Clear your first abyssal room",
+            STAT_ROOMS_CLEARED,
+            1,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID]),
+        AchievementDef::new(
+            "AbyssalExtraction",
+            "Filament Runner",
+            "Extract from the Abyss",
+            STAT_EXTRACTIONS,
+            1,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Silver),
+        AchievementDef::new(
+            "AbyssalRegular",
+            "Deadspace Regular",
+            "Extract from the Abyss 10 times",
+            STAT_EXTRACTIONS,
+            10,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Gold),
+        AchievementDef::new(
+            "AbyssalRoomClearer",
+            "Pocket Sweeper",
+            "Clear 30 abyssal rooms",
+            STAT_ROOMS_CLEARED,
+            30,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID]),
+        AchievementDef::new(
+            "AbyssalSpeed",
+            "Ahead of the Collapse",
+            "Extract with 5 minutes still on the clock",
+            STAT_EXTRACTION_TIME_LEFT,
+            300,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Gold)
+        .hidden(),
+    ],
+}];
+
+/// Feed room clears and extractions to the achievement evaluator
+fn abyssal_achievement_progress(
+    state: Res<AbyssalState>,
+    mut clear_events: EventReader<AbyssalRoomClearEvent>,
+    mut extraction_events: EventReader<AbyssalExtractionEvent>,
+    mut progress: EventWriter<AchievementProgressEvent>,
+) {
+    for _ in clear_events.read() {
+        progress.send(AchievementProgressEvent::add(STAT_ROOMS_CLEARED, 1));
+    }
+    for _ in extraction_events.read() {
+        progress.send(AchievementProgressEvent::add(STAT_EXTRACTIONS, 1));
+        progress.send(AchievementProgressEvent::peak(
+            STAT_EXTRACTION_TIME_LEFT,
+            state.time_remaining as u64,
+        ));
+    }
 }
 
-/// Register this module in the module registry
-fn register_module(mut registry: ResMut<ModuleRegistry>) {
-    registry.register(GameModuleInfo {
-        id: "abyssal_depths",
-        display_name: "ABYSSAL DEPTHS",
-        subtitle: "Triglavian Extraction",
-        description: "Enter the Abyss. Survive 3 rooms. Extract or die.",
-        factions: vec![], // No faction selection - always Triglavian enemies
-    });
+/// Run condition: is the active module Abyssal Depths?
+fn is_abyssal(active: Res<crate::games::ActiveModule>) -> bool {
+    active.is(MODULE_ID)
 }
 
 /// Current room in the abyss
@@ -1,11 +1,14 @@
 //! Caldari/Gallente Campaign Missions
 //!
-//! Battle of Caldari Prime mission chain.
+//! Battle of Caldari Prime mission chain, plus the detours only reachable
+//! from the campaign map.
 
 #![allow(dead_code)]
 
 use bevy::prelude::*;
 
+use crate::core::{CampaignMap, MapBriefing, MapNode, NodeReward, Objective, ObjectiveKind};
+
 /// Mission definition for Caldari/Gallente campaign
 #[derive(Debug, Clone)]
 pub struct CGMission {
@@ -14,12 +17,30 @@ pub struct CGMission {
     pub description: &'static str,
     pub primary_objective: &'static str,
     pub bonus_objective: Option<&'static str>,
+    /// How the bonus objective is tracked
+    pub bonus: Option<ObjectiveKind>,
     pub waves: u32,
     pub boss: Option<CGBossType>,
     pub is_tutorial: bool,
     pub unlocks_t3: bool,
 }
 
+impl CGMission {
+    /// Clearing the waves is the primary objective of a mission without a
+    /// boss, destroying the boss otherwise; the bonus objective is optional
+    pub fn objectives(&self) -> Vec<Objective> {
+        let primary = match self.boss {
+            Some(_) => ObjectiveKind::DestroyBoss,
+            None => ObjectiveKind::ClearWaves { count: self.waves },
+        };
+        let mut objectives = vec![Objective::primary(primary).with_label(self.primary_objective)];
+        if let (Some(kind), Some(label)) = (self.bonus, self.bonus_objective) {
+            objectives.push(Objective::optional(kind).with_label(label));
+        }
+        objectives
+    }
+}
+
 /// Boss types for Caldari/Gallente campaign
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CGBossType {
@@ -57,6 +78,15 @@ impl CGBossType {
         }
     }
 
+    /// Escort frigates that arrive with the boss
+    pub fn escorts(&self) -> u32 {
+        match self {
+            CGBossType::FleetCommander => 4,
+            CGBossType::EliteSquadron => 3,
+            _ => 0,
+        }
+    }
+
     pub fn title(&self) -> &'static str {
         match self {
             CGBossType::PatrolCommander => "Caldari Navy Patrol Wing",
@@ -109,6 +139,7 @@ pub const CG_MISSIONS: [CGMission; 5] = [
         description: "Federation forces probe Caldari orbital defenses. First contact.",
         primary_objective: "Destroy enemy patrol ships",
         bonus_objective: Some("No damage taken"),
+        bonus: Some(ObjectiveKind::NoDamage),
         waves: 3,
         boss: None,
         is_tutorial: true,
@@ -120,6 +151,11 @@ pub const CG_MISSIONS: [CGMission; 5] = [
         description: "Combat above Caldari Prime's cities. The skyline burns.",
         primary_objective: "Clear the airspace",
         bonus_objective: Some("Protect civilian transports"),
+        bonus: Some(ObjectiveKind::Escort {
+            type_id: 648, // Badger
+            health: 300.0,
+            speed: 18.0,
+        }),
         waves: 4,
         boss: Some(CGBossType::PatrolCommander),
         is_tutorial: false,
@@ -131,6 +167,7 @@ pub const CG_MISSIONS: [CGMission; 5] = [
         description: "Enemy reinforcements inbound. Intercept before they reach the front.",
         primary_objective: "Destroy the convoy",
         bonus_objective: Some("Destroy all escorts first"),
+        bonus: Some(ObjectiveKind::EscortsFirst),
         waves: 5,
         boss: Some(CGBossType::FleetCommander),
         is_tutorial: false,
@@ -142,6 +179,8 @@ pub const CG_MISSIONS: [CGMission; 5] = [
         description: "Both sides commit heavier assets. T3 destroyers enter the fray.",
         primary_objective: "Hold the line",
         bonus_objective: Some("Destroy elite squadron"),
+        // The whole squadron: its wingmen must not outlive the leader
+        bonus: Some(ObjectiveKind::EscortsFirst),
         waves: 6,
         boss: Some(CGBossType::EliteSquadron),
         is_tutorial: false,
@@ -153,6 +192,7 @@ pub const CG_MISSIONS: [CGMission; 5] = [
         description: "The final battle for orbital superiority. No retreat.",
         primary_objective: "Achieve air dominance",
         bonus_objective: Some("Perfect victory"),
+        bonus: Some(ObjectiveKind::NoDamage),
         waves: 8,
         boss: Some(CGBossType::FleetAdmiral),
         is_tutorial: false,
@@ -160,6 +200,119 @@ pub const CG_MISSIONS: [CGMission; 5] = [
     },
 ];
 
+/// Missions off the numbered chain, launched from the campaign map
+pub const CG_DETOURS: [CGMission; 2] = [
+    CGMission {
+        id: "cg_x1_gate_blockade",
+        name: "GATE BLOCKADE",
+        description: "Hold the Old Man Star gate shut. Reinforcements never reach the front.",
+        primary_objective: "Break the blockade runners",
+        bonus_objective: Some("Complete within 4 minutes"),
+        bonus: Some(ObjectiveKind::TimeLimit { seconds: 240.0 }),
+        waves: 5,
+        boss: Some(CGBossType::FleetCommander),
+        is_tutorial: false,
+        unlocks_t3: false,
+    },
+    CGMission {
+        id: "cg_x2_salvage_sweep",
+        name: "SALVAGE SWEEP",
+        description: "Strip the wrecks drifting through Intaki before the other side does.",
+        primary_objective: "Clear the salvage field",
+        bonus_objective: Some("No damage taken"),
+        bonus: Some(ObjectiveKind::NoDamage),
+        waves: 3,
+        boss: None,
+        is_tutorial: false,
+        unlocks_t3: false,
+    },
+];
+
+/// Mission behind a map node id
+pub fn find_mission(id: &str) -> Option<&'static CGMission> {
+    CG_MISSIONS.iter().chain(&CG_DETOURS).find(|m| m.id == id)
+}
+
+fn cg_briefing(id: &str) -> Option<MapBriefing> {
+    find_mission(id).map(|mission| MapBriefing {
+        name: mission.name,
+        description: mission.description,
+        objectives: mission.objectives(),
+    })
+}
+
+/// Battle of Caldari Prime: the gate blockade stands in for the fleet
+/// interdiction, and a salvage sweep pays skill points on the side
+pub static CG_MAP: CampaignMap = CampaignMap {
+    finale: "cg_m5_decisive_push",
+    briefing: cg_briefing,
+    nodes: &[
+        MapNode {
+            mission: "cg_m1_orbital_skirmish",
+            system: "Luminaire",
+            position: Vec2::new(-300.0, 0.0),
+            side: false,
+            requires: &[],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "cg_m2_urban_firefight",
+            system: "Caldari Prime",
+            position: Vec2::new(-180.0, 60.0),
+            side: false,
+            requires: &["cg_m1_orbital_skirmish"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "cg_m3_fleet_interdiction",
+            system: "Villore",
+            position: Vec2::new(-50.0, 130.0),
+            side: false,
+            requires: &["cg_m2_urban_firefight"],
+            closes: &["cg_x1_gate_blockade"],
+            reward: None,
+        },
+        MapNode {
+            mission: "cg_x1_gate_blockade",
+            system: "Old Man Star",
+            position: Vec2::new(-50.0, -20.0),
+            side: false,
+            requires: &["cg_m2_urban_firefight"],
+            closes: &["cg_m3_fleet_interdiction"],
+            reward: None,
+        },
+        MapNode {
+            mission: "cg_x2_salvage_sweep",
+            system: "Intaki",
+            position: Vec2::new(-160.0, -120.0),
+            side: true,
+            requires: &["cg_m2_urban_firefight"],
+            closes: &[],
+            reward: Some(NodeReward::SkillPoints(10)),
+        },
+        MapNode {
+            mission: "cg_m4_escalation",
+            system: "Nennamaila",
+            position: Vec2::new(80.0, 60.0),
+            side: false,
+            requires: &["cg_m3_fleet_interdiction", "cg_x1_gate_blockade"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "cg_m5_decisive_push",
+            system: "Caldari Prime Orbit",
+            position: Vec2::new(260.0, 0.0),
+            side: false,
+            requires: &["cg_m4_escalation"],
+            closes: &[],
+            reward: None,
+        },
+    ],
+};
+
 /// Epilogue mission - Shiigeru Endless Nightmare
 pub const CG_EPILOGUE_SHIIGERU: CGMission = CGMission {
     id: "cg_epilogue_shiigeru",
@@ -167,6 +320,7 @@ pub const CG_EPILOGUE_SHIIGERU: CGMission = CGMission {
     description: "The Caldari titan Shiigeru falls. An endless nightmare aboard the dying vessel.",
     primary_objective: "Survive as long as possible",
     bonus_objective: None,
+    bonus: None,
     waves: 0,   // Endless
     boss: None, // Multiple mini-bosses spawn over time
     is_tutorial: false,
@@ -392,6 +546,8 @@ pub enum NightmareEvent {
 #[derive(Debug, Clone, Resource, Default)]
 pub struct CGCampaignState {
     pub mission_index: usize,
+    /// Detour launched from the map, in place of the numbered mission
+    pub detour: Option<usize>,
     pub current_wave: u32,
     pub in_mission: bool,
     pub boss_spawned: bool,
@@ -401,13 +557,28 @@ pub struct CGCampaignState {
 
 impl CGCampaignState {
     pub fn current_mission(&self) -> Option<&'static CGMission> {
-        if self.mission_index < CG_MISSIONS.len() {
+        if let Some(detour) = self.detour {
+            CG_DETOURS.get(detour)
+        } else if self.mission_index < CG_MISSIONS.len() {
             Some(&CG_MISSIONS[self.mission_index])
         } else {
             None
         }
     }
 
+    /// Point the campaign at a map node's mission; false for unknown ids
+    pub fn select_mission(&mut self, id: &str) -> bool {
+        if let Some(index) = CG_MISSIONS.iter().position(|m| m.id == id) {
+            self.mission_index = index;
+            self.detour = None;
+        } else if let Some(detour) = CG_DETOURS.iter().position(|m| m.id == id) {
+            self.detour = Some(detour);
+        } else {
+            return false;
+        }
+        true
+    }
+
     pub fn mission_number(&self) -> usize {
         self.mission_index + 1
     }
@@ -433,6 +604,11 @@ impl CGCampaignState {
             }
         }
 
+        // A detour leaves the numbered chain where it was
+        if self.detour.take().is_some() {
+            return true;
+        }
+
         if self.mission_index + 1 < CG_MISSIONS.len() {
             self.mission_index += 1;
             true
@@ -457,6 +633,7 @@ impl CGCampaignState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{FactionProgress, NodeStatus};
 
     #[test]
     fn nightmare_starts_correctly() {
@@ -577,4 +754,75 @@ mod tests {
 
         assert!(wave10_enemies > wave1_enemies);
     }
+
+    #[test]
+    fn missions_track_their_bonus_objectives() {
+        for mission in CG_MISSIONS.iter().chain(&CG_DETOURS) {
+            let objectives = mission.objectives();
+            assert_eq!(objectives.len(), 2, "{}", mission.name);
+            assert!(!objectives[0].optional);
+            assert!(objectives[1].optional);
+            assert_eq!(objectives[1].label, mission.bonus_objective.unwrap());
+        }
+
+        // Without a boss, clearing the waves is the job
+        let patrol = CG_MISSIONS[0].objectives();
+        assert_eq!(patrol[0].kind, ObjectiveKind::ClearWaves { count: 3 });
+    }
+
+    #[test]
+    fn map_nodes_resolve_to_missions() {
+        for node in CG_MAP.nodes {
+            assert!(
+                (CG_MAP.briefing)(node.mission).is_some(),
+                "{}",
+                node.mission
+            );
+            for id in node.requires.iter().chain(node.closes) {
+                assert!(CG_MAP.node(id).is_some(), "{}", id);
+            }
+        }
+        assert!(CG_MAP.node(CG_MAP.finale).is_some());
+        // Every mission is on the map
+        for mission in CG_MISSIONS.iter().chain(&CG_DETOURS) {
+            assert!(CG_MAP.node(mission.id).is_some(), "{}", mission.id);
+        }
+    }
+
+    #[test]
+    fn gate_blockade_closes_the_interdiction() {
+        let index = |id| CG_MAP.nodes.iter().position(|n| n.mission == id).unwrap();
+        let mut progress = FactionProgress::default();
+        for id in ["cg_m1_orbital_skirmish", "cg_m2_urban_firefight"] {
+            progress.completed_nodes.insert(id.to_string());
+        }
+        let blockade = index("cg_x1_gate_blockade");
+        assert!(CG_MAP.needs_commitment(blockade, &progress));
+
+        progress.choices.insert("cg_x1_gate_blockade".to_string());
+        let interdiction = index("cg_m3_fleet_interdiction");
+        assert_eq!(CG_MAP.status(interdiction, &progress), NodeStatus::Closed);
+
+        progress
+            .completed_nodes
+            .insert("cg_x1_gate_blockade".to_string());
+        let escalation = index("cg_m4_escalation");
+        assert_eq!(CG_MAP.status(escalation, &progress), NodeStatus::Available);
+    }
+
+    #[test]
+    fn detours_leave_the_chain_in_place() {
+        let mut state = CGCampaignState::default();
+        assert!(state.select_mission("cg_m2_urban_firefight"));
+        state.complete_mission();
+        assert_eq!(state.mission_index, 2);
+
+        assert!(state.select_mission("cg_x1_gate_blockade"));
+        assert_eq!(state.current_mission().unwrap().name, "GATE BLOCKADE");
+        assert!(state.complete_mission());
+        assert_eq!(state.detour, None);
+        assert_eq!(state.mission_index, 2);
+
+        assert!(!state.select_mission("m1_convoy_raid"));
+    }
 }
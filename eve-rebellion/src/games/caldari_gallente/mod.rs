@@ -2,8 +2,15 @@
 //!
 //! Caldari vs Gallente faction warfare over Caldari Prime.
 
-use super::{ActiveModule, FactionInfo, GameModuleInfo, ModuleRegistry};
-use crate::core::{Difficulty, Faction, GameSession, GameState, LAYER_PLAYER_BULLETS};
+use super::{
+    sourced_replacements, ActiveModule, ContentSource, CoreSystem, DialogueProvider, FactionInfo,
+    FactionShipPool, GameModule, GameModuleInfo,
+};
+use crate::core::{
+    AchievementDef, AchievementProgressEvent, AchievementScope, AchievementSet, AchievementTier,
+    CampaignMap, Difficulty, Faction, GameSession, GameState, MapNodeLaunched, MissionObjectives,
+    ObjectiveStatus, SaveData, ScoreEvent, ScoreRule, LAYER_PLAYER_BULLETS, STAT_KILLS,
+};
 use crate::entities::projectile::ProjectilePhysics;
 use crate::systems::JoystickState;
 use bevy::ecs::schedule::common_conditions::not;
@@ -13,18 +20,96 @@ pub mod campaign;
 pub mod last_stand;
 pub mod ships;
 
-pub use campaign::{CGBossType, CGCampaignState, NightmareBoss, NightmareEvent, ShiigeruNightmare};
+pub use campaign::{
+    CGBossType, CGCampaignState, NightmareBoss, NightmareEvent, ShiigeruNightmare, CG_MAP,
+};
 pub use last_stand::{LastStandAction, LastStandEvent, LastStandState};
 pub use ships::*;
 
-/// Caldari/Gallente module plugin
-pub struct CaldariGallentePlugin;
+/// Module id
+pub const MODULE_ID: &str = "caldari_gallente";
 
-impl Plugin for CaldariGallentePlugin {
-    fn build(&self, app: &mut App) {
-        // Register module
-        app.add_systems(Startup, register_module);
+/// Caldari/Gallente module - own campaign, bosses, dialogue and screens,
+/// plus the Last Stand sub-mode which also takes over the player and HUD
+pub struct CaldariGallenteModule {
+    ships: CaldariGallenteShips,
+}
+
+impl Default for CaldariGallenteModule {
+    fn default() -> Self {
+        Self {
+            ships: CaldariGallenteShips::new(),
+        }
+    }
+}
+
+impl GameModule for CaldariGallenteModule {
+    fn id(&self) -> &'static str {
+        MODULE_ID
+    }
+
+    fn info(&self) -> GameModuleInfo {
+        module_info()
+    }
+
+    fn wave_source(&self) -> ContentSource {
+        ContentSource::Module
+    }
+
+    fn boss_source(&self) -> ContentSource {
+        ContentSource::Module
+    }
+
+    fn dialogue(&self) -> Option<DialogueProvider> {
+        Some(DialogueProvider {
+            speaker: "Fleet Command",
+            text: std::sync::Arc::new(crate::systems::dialogue::get_cg_dialogue_text),
+        })
+    }
+
+    fn ship_pool(&self, faction: &str) -> Option<&FactionShipPool> {
+        match faction {
+            "caldari" => Some(&self.ships.caldari),
+            "gallente" => Some(&self.ships.gallente),
+            _ => None,
+        }
+    }
+
+    fn achievements(&self) -> &'static [AchievementSet] {
+        ACHIEVEMENTS
+    }
+
+    fn campaign_map(&self, _active: &ActiveModule) -> Option<&'static CampaignMap> {
+        Some(&CG_MAP)
+    }
+
+    /// Faction warfare scores by combo tier rather than the raw chain
+    fn score_rules(&self) -> &'static [ScoreRule] {
+        &[
+            ScoreRule::ComboTier,
+            ScoreRule::Heat,
+            ScoreRule::SaltMiner,
+            ScoreRule::Difficulty,
+        ]
+    }
+
+    fn replaces(&self, world: &World) -> Vec<CoreSystem> {
+        let mut replaced = sourced_replacements(self);
+        replaced.extend([CoreSystem::FactionSelect, CoreSystem::ResultScreens]);
+        if world
+            .get_resource::<LastStandState>()
+            .is_some_and(|ls| ls.active)
+        {
+            replaced.extend([
+                CoreSystem::Spawning,
+                CoreSystem::PlayerControl,
+                CoreSystem::Hud,
+            ]);
+        }
+        replaced
+    }
 
+    fn build(&self, app: &mut App) {
         // Initialize state for mode select
         app.init_state::<CGModeSelect>();
 
@@ -53,7 +138,7 @@ impl Plugin for CaldariGallentePlugin {
             .add_systems(OnExit(CGModeSelect::Active), despawn_mode_select);
 
         // Initialize resources
-        app.init_resource::<CaldariGallenteShips>();
+        app.insert_resource(self.ships.clone());
         app.init_resource::<ShiigeruNightmare>();
         app.init_resource::<CGCampaignState>();
         app.init_resource::<LastStandState>();
@@ -61,6 +146,12 @@ impl Plugin for CaldariGallentePlugin {
         // CG Campaign systems - run instead of main campaign when CG module is active
         // Skip when nightmare mode or Last Stand mode is active
         app.add_systems(
+            Update,
+            launch_cg_map_node
+                .run_if(in_state(GameState::StageSelect))
+                .run_if(is_caldari_gallente),
+        )
+        .add_systems(
             OnEnter(GameState::Playing),
             start_cg_mission
                 .run_if(is_caldari_gallente)
@@ -176,8 +267,8 @@ impl Plugin for CaldariGallentePlugin {
 }
 
 /// Run condition: is the active module Caldari vs Gallente?
-fn is_caldari_gallente(active_module: Res<ActiveModule>) -> bool {
-    active_module.is_caldari_gallente()
+fn is_caldari_gallente(active_module: Res<super::ActiveModule>) -> bool {
+    active_module.is(MODULE_ID)
 }
 
 /// Run condition: is nightmare mode active?
@@ -186,6 +277,106 @@ fn nightmare_active(nightmare: Res<ShiigeruNightmare>) -> bool {
 }
 
 /// Run condition: is Last Stand mode active?
+/// Highest evacuation percentage reached in Last Stand
+pub const STAT_EVACUATION: &str = "last_stand_evacuation";
+/// Last Stands ridden all the way down to Gallente Prime
+pub const STAT_DESCENTS: &str = "last_stand_descents";
+/// Doomsday devices fired
+pub const STAT_DOOMSDAYS: &str = "last_stand_doomsdays";
+
+/// Faction warfare and Last Stand achievements
+static ACHIEVEMENTS: &[AchievementSet] = &[
+    AchievementSet {
+        name: "Caldari Prime",
+        achievements: &[
+            AchievementDef::new(
+                "CgStateLoyalist",
+                "State Loyalist",
+                "Complete 5 missions for the Caldari State",
+                "missions_completed/caldari",
+                5,
+                AchievementScope::Lifetime,
+            )
+            .modules(&[MODULE_ID])
+            .factions(&["caldari"]),
+            AchievementDef::new(
+                "CgFederationPatriot",
+                "Federation Patriot",
+                "Complete 5 missions for the Gallente Federation",
+                "missions_completed/gallente",
+                5,
+                AchievementScope::Lifetime,
+            )
+            .modules(&[MODULE_ID])
+            .factions(&["gallente"]),
+            AchievementDef::new(
+                "CgWarzoneAce",
+                "Warzone Ace",
+                "Destroy 200 enemies in a single Caldari Prime run",
+                STAT_KILLS,
+                200,
+                AchievementScope::Run,
+            )
+            .modules(&[MODULE_ID])
+            .tier(AchievementTier::Silver),
+            AchievementDef::new(
+                "CgBothSides",
+                "Both Sides of the War",
+                "Complete 20 Caldari Prime missions",
+                "missions_completed/caldari_gallente",
+                20,
+                AchievementScope::Lifetime,
+            )
+            .modules(&[MODULE_ID])
+            .tier(AchievementTier::Gold),
+        ],
+    },
+    AchievementSet {
+        name: "Last Stand",
+        achievements: &[
+            AchievementDef::new(
+                "LsHoldTheLine",
+                "Hold the Line",
+                "Evacuate half of the Kairiola's crew",
+                STAT_EVACUATION,
+                50,
+                AchievementScope::Mission,
+            )
+            .modules(&[MODULE_ID]),
+            AchievementDef::new(
+                "LsEvacuationComplete",
+                "Every Last Transport",
+                "Complete the evacuation",
+                STAT_EVACUATION,
+                100,
+                AchievementScope::Mission,
+            )
+            .modules(&[MODULE_ID])
+            .tier(AchievementTier::Silver),
+            AchievementDef::new(
+                "LsDoomsday",
+                "Judgement",
+                "Fire the Kairiola's doomsday device",
+                STAT_DOOMSDAYS,
+                1,
+                AchievementScope::Lifetime,
+            )
+            .modules(&[MODULE_ID]),
+            AchievementDef::new(
+                "LsShiigeru",
+                "The State Remembers",
+                "Ride the Kairiola down into Gallente Prime",
+                STAT_DESCENTS,
+                1,
+                AchievementScope::Lifetime,
+            )
+            .modules(&[MODULE_ID])
+            .tier(AchievementTier::Gold)
+            .hidden(),
+        ],
+    },
+];
+
 fn last_stand_active(last_stand: Res<LastStandState>) -> bool {
     last_stand.active
 }
@@ -948,11 +1139,51 @@ struct CGBossIntroDialogue {
     timer: f32,
 }
 
+/// Pick up the mission launched from the campaign map
+fn launch_cg_map_node(
+    mut launched: EventReader<MapNodeLaunched>,
+    mut cg_campaign: ResMut<CGCampaignState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for event in launched.read() {
+        if cg_campaign.select_mission(event.mission) {
+            next_state.set(GameState::ShipSelect);
+        }
+    }
+}
+
+/// Record a cleared map node; the numbered chain also saves its stage,
+/// which the T3 destroyers unlock from
+fn save_cg_progress(
+    cg_campaign: &CGCampaignState,
+    save_data: &mut SaveData,
+    session: &GameSession,
+    active_module: &ActiveModule,
+) {
+    let Some(mission) = cg_campaign.current_mission() else {
+        return;
+    };
+    let (faction, enemy) = active_module.progress_factions(session);
+    CG_MAP.clear_node(save_data, faction, enemy, mission.id);
+    if cg_campaign.detour.is_none() {
+        save_data.complete_stage(
+            faction,
+            enemy,
+            cg_campaign.mission_number() as u32,
+            cg_campaign.mission_index as u32,
+        );
+    }
+}
+
 /// Start a CG mission when entering Playing state
-fn start_cg_mission(mut cg_campaign: ResMut<CGCampaignState>) {
+fn start_cg_mission(
+    mut cg_campaign: ResMut<CGCampaignState>,
+    mut objectives: ResMut<MissionObjectives>,
+) {
     cg_campaign.start_mission();
 
     if let Some(mission) = cg_campaign.current_mission() {
+        objectives.set(mission.objectives());
         info!(
             "Starting CG Mission {}: {} - {}",
             cg_campaign.mission_number(),
@@ -1005,6 +1236,34 @@ fn check_cg_wave_complete(
     }
 }
 
+/// Frigate type IDs flown by the enemy faction
+fn cg_frigates(faction: Faction) -> [u32; 3] {
+    match faction {
+        Faction::Caldari => [583, 602, 603],  // Condor, Kestrel, Merlin
+        Faction::Gallente => [608, 594, 593], // Atron, Incursus, Tristan
+        Faction::Amarr => [597, 589, 591],    // Punisher, Executioner, Tormentor
+        Faction::Minmatar => [587, 585, 598], // Rifter, Slasher, Breacher
+    }
+}
+
+/// Settle the mission's objectives and pay out their rewards
+fn settle_cg_objectives(
+    objectives: &mut MissionObjectives,
+    score_events: &mut EventWriter<ScoreEvent>,
+    save_data: &mut SaveData,
+) {
+    if objectives.rewards.is_some() {
+        return;
+    }
+    let rewards = objectives.finish(true);
+    score_events.send(ScoreEvent::objective(rewards.score));
+    save_data.add_skill_points(rewards.skill_points);
+    info!(
+        "Objectives {}/{}: +{} score, +{} SP",
+        rewards.completed, rewards.total, rewards.score, rewards.skill_points
+    );
+}
+
 /// Spawn next wave of enemies for CG campaign
 fn spawn_cg_wave(
     mut commands: Commands,
@@ -1014,6 +1273,10 @@ fn spawn_cg_wave(
     sprite_cache: Res<crate::assets::ShipSpriteCache>,
     enemy_query: Query<Entity, With<crate::entities::Enemy>>,
     boss_query: Query<Entity, With<CGBoss>>,
+    mut objectives: ResMut<MissionObjectives>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut save_data: ResMut<SaveData>,
+    active_module: Res<ActiveModule>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     use crate::entities::enemy::{spawn_enemy, EnemyBehavior};
@@ -1027,6 +1290,11 @@ fn spawn_cg_wave(
         return;
     };
 
+    // The previous wave is down
+    if cg_campaign.current_wave > 1 && !cg_campaign.boss_spawned {
+        objectives.on_wave_cleared();
+    }
+
     // Check if it's boss time
     if cg_campaign.current_wave > mission.waves {
         if !cg_campaign.boss_spawned && mission.boss.is_some() {
@@ -1034,6 +1302,8 @@ fn spawn_cg_wave(
             next_state.set(GameState::BossIntro);
         } else if mission.boss.is_none() {
             // No boss mission - complete immediately
+            settle_cg_objectives(&mut objectives, &mut score_events, &mut save_data);
+            save_cg_progress(&cg_campaign, &mut save_data, &session, &active_module);
             next_state.set(GameState::StageComplete);
         }
         return;
@@ -1047,13 +1317,7 @@ fn spawn_cg_wave(
 
     info!("CG: Spawning wave {} with {} enemies", wave, count);
 
-    // Get enemy type IDs based on enemy faction
-    let enemy_types: Vec<u32> = match session.enemy_faction {
-        Faction::Caldari => vec![583, 602, 603], // Condor, Kestrel, Merlin
-        Faction::Gallente => vec![608, 594, 593], // Atron, Incursus, Tristan
-        Faction::Amarr => vec![597, 589, 591],   // Punisher, Executioner, Tormentor
-        Faction::Minmatar => vec![587, 585, 598], // Rifter, Slasher, Breacher
-    };
+    let enemy_types = cg_frigates(session.enemy_faction);
 
     for i in 0..count {
         let type_id = enemy_types[fastrand::usize(..enemy_types.len())];
@@ -1183,6 +1447,23 @@ fn spawn_cg_boss(
             .with_rotation(Quat::from_rotation_z(std::f32::consts::PI)),
     ));
 
+    // Escorts fly in formation on the boss's flanks
+    let escorts = boss_type.escorts();
+    let frigates = cg_frigates(session.enemy_faction);
+    for i in 0..escorts {
+        let type_id = frigates[i as usize % frigates.len()];
+        let side = if i % 2 == 0 { -1.0 } else { 1.0 };
+        let x = side * (110.0 + (i / 2) as f32 * 70.0);
+        crate::entities::enemy::spawn_enemy(
+            &mut commands,
+            type_id,
+            Vec2::new(x, 260.0),
+            crate::entities::enemy::EnemyBehavior::Weaver,
+            sprite_cache.get(type_id),
+            None,
+        );
+    }
+
     cg_campaign.boss_spawned = true;
 }
 
@@ -1447,7 +1728,13 @@ fn update_cg_boss(
 fn check_cg_boss_defeated(
     mut commands: Commands,
     mut cg_campaign: ResMut<CGCampaignState>,
+    mut save_data: ResMut<SaveData>,
+    session: Res<GameSession>,
+    active_module: Res<ActiveModule>,
     boss_query: Query<(Entity, &CGBoss, &crate::entities::EnemyStats)>,
+    escorts: Query<(), (With<crate::entities::Enemy>, Without<CGBoss>)>,
+    mut objectives: ResMut<MissionObjectives>,
+    mut score_events: EventWriter<ScoreEvent>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     for (entity, boss, enemy_stats) in boss_query.iter() {
@@ -1458,6 +1745,11 @@ fn check_cg_boss_defeated(
             // Mark boss defeated
             cg_campaign.boss_defeated = true;
 
+            objectives.on_boss_defeated(escorts.iter().count());
+            settle_cg_objectives(&mut objectives, &mut score_events, &mut save_data);
+
+            save_cg_progress(&cg_campaign, &mut save_data, &session, &active_module);
+
             // Despawn boss
             commands.entity(entity).despawn_recursive();
 
@@ -1480,6 +1772,7 @@ fn spawn_cg_stage_complete(
     cg_campaign: Res<CGCampaignState>,
     score: Res<crate::core::ScoreSystem>,
     session: Res<GameSession>,
+    objectives: Res<MissionObjectives>,
 ) {
     let mission_name = cg_campaign
         .current_mission()
@@ -1557,6 +1850,36 @@ fn spawn_cg_stage_complete(
                 TextColor(Color::srgb(0.8, 0.8, 0.8)),
             ));
 
+            // Objectives
+            for objective in &objectives.objectives {
+                let color = match (objective.status, objective.optional) {
+                    (ObjectiveStatus::Complete, true) => Color::srgb(1.0, 0.85, 0.2), // Gold
+                    (ObjectiveStatus::Complete, false) => Color::srgb(0.3, 1.0, 0.3),
+                    _ => Color::srgb(0.5, 0.5, 0.5), // Gray
+                };
+                parent.spawn((
+                    Text::new(objective.display_line()),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            }
+            if let Some(rewards) = objectives.rewards.filter(|r| r.completed > 0) {
+                parent.spawn((
+                    Text::new(format!(
+                        "Objective bonus: +{} score • +{} SP",
+                        rewards.score, rewards.skill_points
+                    )),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.4, 0.8, 1.0)),
+                ));
+            }
+
             // T3 unlock notification
             if t3_just_unlocked {
                 parent.spawn(Node {
@@ -1605,19 +1928,26 @@ fn cg_stage_complete_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
     mut cg_campaign: ResMut<CGCampaignState>,
+    save_data: Res<SaveData>,
+    session: Res<GameSession>,
+    active_module: Res<ActiveModule>,
     mut transitions: EventWriter<crate::ui::TransitionEvent>,
 ) {
     if keyboard.just_pressed(KeyCode::Space)
         || keyboard.just_pressed(KeyCode::Enter)
         || joystick.confirm()
     {
-        // Advance to next mission (returns false if campaign complete)
-        if cg_campaign.complete_mission() {
-            // More missions available
-            transitions.send(crate::ui::TransitionEvent::to(GameState::Playing));
-        } else {
+        cg_campaign.complete_mission();
+        let (faction, enemy) = active_module.progress_factions(&session);
+        let finished = save_data
+            .faction_progress(faction, enemy)
+            .is_some_and(|p| CG_MAP.is_complete(p));
+        if finished {
             // Campaign complete!
             transitions.send(crate::ui::TransitionEvent::slow(GameState::Victory));
+        } else {
+            // Back to the star map to pick the next jump
+            transitions.send(crate::ui::TransitionEvent::to(GameState::StageSelect));
         }
     }
 
@@ -1989,9 +2319,10 @@ fn despawn_cg_victory(mut commands: Commands, query: Query<Entity, With<CGVictor
     }
 }
 
-fn register_module(mut registry: ResMut<ModuleRegistry>) {
-    registry.register(GameModuleInfo {
-        id: "caldari_gallente",
+/// Registry entry for the Caldari/Gallente module
+fn module_info() -> GameModuleInfo {
+    GameModuleInfo {
+        id: MODULE_ID,
         display_name: "Battle of Caldari Prime",
         subtitle: "The War for Caldari Prime",
         description: "Experience the brutal conflict between Caldari and Gallente forces.",
@@ -2015,7 +2346,7 @@ fn register_module(mut registry: ResMut<ModuleRegistry>) {
                 description: "Freedom through firepower.",
             },
         ],
-    });
+    }
 }
 
 // ============================================================================
@@ -2735,9 +3066,16 @@ fn update_last_stand(
     mut last_stand: ResMut<LastStandState>,
     mut next_state: ResMut<NextState<GameState>>,
     mut dialogue_events: EventWriter<crate::systems::DialogueEvent>,
+    mut progress: EventWriter<AchievementProgressEvent>,
 ) {
     let dt = time.delta_secs();
     let event = last_stand.update(dt);
+    if event != LastStandEvent::None {
+        progress.send(AchievementProgressEvent::peak(
+            STAT_EVACUATION,
+            last_stand.evacuation_progress as u64,
+        ));
+    }
 
     match event {
         LastStandEvent::Milestone(_idx) => {
@@ -2768,6 +3106,7 @@ fn update_last_stand(
         LastStandEvent::DescentComplete => {
             // Victory! Show special victory screen
             info!("CNS Kairiola completes its final mission");
+            progress.send(AchievementProgressEvent::add(STAT_DESCENTS, 1));
             next_state.set(GameState::Victory);
         }
         LastStandEvent::Destroyed => {
@@ -2788,6 +3127,7 @@ fn last_stand_input(
     joystick: Res<JoystickState>,
     mut last_stand: ResMut<LastStandState>,
     mut commands: Commands,
+    mut progress: EventWriter<AchievementProgressEvent>,
 ) {
     // Fighter Launch (RT / F)
     if (keyboard.just_pressed(KeyCode::KeyF) || joystick.right_trigger_pressed())
@@ -2851,6 +3191,7 @@ fn last_stand_input(
         && last_stand.perform(LastStandAction::Doomsday)
     {
         info!("DOOMSDAY DEVICE ACTIVATED!");
+        progress.send(AchievementProgressEvent::add(STAT_DOOMSDAYS, 1));
         // Spawn doomsday beam
         commands.spawn((
             last_stand::DoomsdayBeam {
@@ -4,11 +4,12 @@
 
 #![allow(dead_code)]
 
+use crate::core::{Faction, UnlockRequirement};
 use crate::games::{EnemyShipDef, FactionShipPool, ModuleShip};
 use bevy::prelude::*;
 
 /// Ship pools for both Caldari and Gallente factions
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Clone)]
 pub struct CaldariGallenteShips {
     pub caldari: FactionShipPool,
     pub gallente: FactionShipPool,
@@ -76,8 +77,7 @@ fn build_caldari_pool() -> FactionShipPool {
                 fire_rate: 0.3,
                 damage: 18.0,
                 unlimited_thrust: false,
-                unlocked: true,
-                unlock_mission: None,
+                requirements: Vec::new(),
             },
             // Harpy - Assault Frigate, Railgun Platform
             ModuleShip {
@@ -90,8 +90,7 @@ fn build_caldari_pool() -> FactionShipPool {
                 fire_rate: 0.25,
                 damage: 22.0,
                 unlimited_thrust: false,
-                unlocked: true,
-                unlock_mission: None,
+                requirements: Vec::new(),
             },
             // Jackdaw - T3 Tactical Destroyer (unlockable)
             ModuleShip {
@@ -104,8 +103,7 @@ fn build_caldari_pool() -> FactionShipPool {
                 fire_rate: 0.35,
                 damage: 25.0,
                 unlimited_thrust: false,
-                unlocked: false,
-                unlock_mission: Some(4),
+                requirements: vec![UnlockRequirement::stage(Faction::Caldari, 4)],
             },
         ],
         enemy_ships: vec![
@@ -117,7 +115,7 @@ fn build_caldari_pool() -> FactionShipPool {
                 spawn_weight: 30,
             },
             EnemyShipDef {
-                type_id: 11371, // Ishkur
+                type_id: 11377, // Ishkur
                 name: "Ishkur",
                 class: "Assault Frigate",
                 spawn_weight: 30,
@@ -152,12 +150,11 @@ fn build_gallente_pool() -> FactionShipPool {
                 fire_rate: 0.2,
                 damage: 20.0,
                 unlimited_thrust: false,
-                unlocked: true,
-                unlock_mission: None,
+                requirements: Vec::new(),
             },
             // Ishkur - Assault Frigate, Drone Boat
             ModuleShip {
-                type_id: 11371,
+                type_id: 11377,
                 name: "Ishkur",
                 class: "Assault Frigate",
                 role: "Drone Boat",
@@ -166,8 +163,7 @@ fn build_gallente_pool() -> FactionShipPool {
                 fire_rate: 0.35,
                 damage: 16.0,
                 unlimited_thrust: false,
-                unlocked: true,
-                unlock_mission: None,
+                requirements: Vec::new(),
             },
             // Hecate - T3 Tactical Destroyer (unlockable)
             ModuleShip {
@@ -180,8 +176,7 @@ fn build_gallente_pool() -> FactionShipPool {
                 fire_rate: 0.22,
                 damage: 28.0,
                 unlimited_thrust: false,
-                unlocked: false,
-                unlock_mission: Some(4),
+                requirements: vec![UnlockRequirement::stage(Faction::Gallente, 4)],
             },
         ],
         enemy_ships: vec![
@@ -3,30 +3,169 @@
 //! Minmatar Republic vs Amarr Empire campaign.
 //! The original EVE Rebellion campaign - 13 missions across 3 acts.
 
-use super::{ActiveModule, FactionInfo, GameModuleInfo, ModuleRegistry};
+use super::{ActiveModule, FactionInfo, FactionShipPool, GameModule, GameModuleInfo};
+use crate::assets::ShipSpriteCache;
+use crate::core::{
+    AchievementDef, AchievementScope, AchievementSet, AchievementTier, CampaignMap,
+    ELDER_FLEET_MAP, STAT_ACTS_COMPLETED, STAT_SOULS,
+};
 use bevy::prelude::*;
 
 pub mod ships;
 
 pub use ships::ElderFleetShips;
 
-/// Elder Fleet module plugin
-pub struct ElderFleetPlugin;
+/// Module id
+pub const MODULE_ID: &str = "elder_fleet";
 
-impl Plugin for ElderFleetPlugin {
-    fn build(&self, app: &mut App) {
-        // Register module at startup
-        app.add_systems(Startup, register_module);
+/// Fenrir freighter escorted in the Exodus side mission
+const EXODUS_FREIGHTER: u32 = 20189;
+
+/// Elder Fleet module - the reference campaign, driven entirely by core systems
+#[derive(Default)]
+pub struct ElderFleetModule {
+    ships: ElderFleetShips,
+}
+
+impl GameModule for ElderFleetModule {
+    fn id(&self) -> &'static str {
+        MODULE_ID
+    }
+
+    fn info(&self) -> GameModuleInfo {
+        module_info()
+    }
+
+    fn ship_pool(&self, faction: &str) -> Option<&FactionShipPool> {
+        match faction {
+            "minmatar" => Some(&self.ships.minmatar),
+            "amarr" => Some(&self.ships.amarr),
+            _ => None,
+        }
+    }
+
+    fn campaign_map(&self, _active: &ActiveModule) -> Option<&'static CampaignMap> {
+        Some(&ELDER_FLEET_MAP)
+    }
 
-        // Initialize resources
-        app.init_resource::<ElderFleetShips>();
+    fn achievements(&self) -> &'static [AchievementSet] {
+        ACHIEVEMENTS
+    }
+
+    fn build(&self, app: &mut App) {
+        if let Some(mut cache) = app.world_mut().get_resource_mut::<ShipSpriteCache>() {
+            cache.request([EXODUS_FREIGHTER]);
+        }
+        app.insert_resource(self.ships.clone());
     }
 }
 
-/// Register the Elder Fleet module with the registry
-fn register_module(mut registry: ResMut<ModuleRegistry>) {
-    registry.register(GameModuleInfo {
-        id: "elder_fleet",
+/// Liberation, act and Amarr boss achievements. Ids match the old
+/// achievement enum so existing saves keep their unlocks.
+static ACHIEVEMENTS: &[AchievementSet] = &[AchievementSet {
+    name: "Elder Fleet",
+    achievements: &[
+        AchievementDef::new(
+            "Liberator",
+            "Liberator",
+            "Liberate 25 souls in a single run",
+            STAT_SOULS,
+            25,
+            AchievementScope::Run,
+        )
+        .modules(&[MODULE_ID]),
+        AchievementDef::new(
+            "FreedomFighter",
+            "Freedom Fighter",
+            "Liberate 100 souls in a single run",
+            STAT_SOULS,
+            100,
+            AchievementScope::Run,
+        )
+        .modules(&[MODULE_ID]),
+        AchievementDef::new(
+            "Emancipator",
+            "Emancipator",
+            "Liberate 250 souls in a single run",
+            STAT_SOULS,
+            250,
+            AchievementScope::Run,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Silver),
+        AchievementDef::new(
+            "ActOneComplete",
+            "Act I Complete",
+            "Complete Act I - Liberation Begins",
+            STAT_ACTS_COMPLETED,
+            1,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Silver),
+        AchievementDef::new(
+            "ActTwoComplete",
+            "Act II Complete",
+            "Complete Act II - Reclaiming Freedom",
+            STAT_ACTS_COMPLETED,
+            2,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Silver),
+        AchievementDef::new(
+            "CampaignVictory",
+            "Campaign Victory",
+            "Defeat the Avatar and complete the campaign",
+            STAT_ACTS_COMPLETED,
+            3,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Gold),
+        AchievementDef::new(
+            "ApocalypseFallen",
+            "Apocalypse Fallen",
+            "Defeat an Apocalypse-class battleship",
+            "bosses_killed/apocalypse",
+            1,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID]),
+        AchievementDef::new(
+            "TitanKiller",
+            "Titan Killer",
+            "Defeat the Avatar titan",
+            "bosses_killed/avatar",
+            1,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Gold)
+        .hidden(),
+        AchievementDef::new(
+            "RifterPilot",
+            "Rifter Pilot",
+            "Complete a mission with the Rifter",
+            "ship_missions/rifter",
+            1,
+            AchievementScope::Lifetime,
+        ),
+        AchievementDef::new(
+            "SlasherPilot",
+            "Slasher Pilot",
+            "Complete a mission with the Slasher",
+            "ship_missions/slasher",
+            1,
+            AchievementScope::Lifetime,
+        ),
+    ],
+}];
+
+/// Registry entry for the Elder Fleet module
+fn module_info() -> GameModuleInfo {
+    GameModuleInfo {
+        id: MODULE_ID,
         display_name: "Elder Fleet Invasion",
         subtitle: "Minmatar vs Amarr",
         description:
@@ -52,15 +191,5 @@ fn register_module(mut registry: ResMut<ModuleRegistry>) {
                 description: "Crush the rebel insurrection. Restore order through strength.",
             },
         ],
-    });
-}
-
-/// Run condition: is Elder Fleet module active?
-pub fn is_elder_fleet(active_module: Res<ActiveModule>) -> bool {
-    active_module.is_elder_fleet()
-}
-
-/// Run condition: is Elder Fleet module NOT active?
-pub fn not_elder_fleet(active_module: Res<ActiveModule>) -> bool {
-    !active_module.is_elder_fleet()
+    }
 }
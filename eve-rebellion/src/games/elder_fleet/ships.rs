@@ -4,6 +4,7 @@
 //! Wraps the core faction ship definitions for module-specific use.
 
 use crate::core::factions::{EnemyShipDef, Faction, ShipDef};
+use crate::core::UnlockRequirement;
 use crate::games::{EnemyShipDef as ModuleEnemyShipDef, FactionShipPool, ModuleShip};
 use bevy::prelude::*;
 
@@ -33,7 +34,10 @@ impl ElderFleetShips {
         let core_enemies = Faction::Minmatar.enemy_ships();
 
         FactionShipPool {
-            player_ships: core_ships.iter().map(Self::convert_ship).collect(),
+            player_ships: core_ships
+                .iter()
+                .map(|def| Self::convert_ship(def, Faction::Minmatar))
+                .collect(),
             enemy_ships: core_enemies.iter().map(Self::convert_enemy).collect(),
         }
     }
@@ -43,12 +47,15 @@ impl ElderFleetShips {
         let core_enemies = Faction::Amarr.enemy_ships();
 
         FactionShipPool {
-            player_ships: core_ships.iter().map(Self::convert_ship).collect(),
+            player_ships: core_ships
+                .iter()
+                .map(|def| Self::convert_ship(def, Faction::Amarr))
+                .collect(),
             enemy_ships: core_enemies.iter().map(Self::convert_enemy).collect(),
         }
     }
 
-    fn convert_ship(def: &ShipDef) -> ModuleShip {
+    fn convert_ship(def: &ShipDef, faction: Faction) -> ModuleShip {
         ModuleShip {
             type_id: def.type_id,
             name: def.name,
@@ -59,11 +66,10 @@ impl ElderFleetShips {
             fire_rate: def.fire_rate,
             damage: def.damage,
             unlimited_thrust: false, // Determined by ship special ability
-            unlocked: def.unlock_stage == 0,
-            unlock_mission: if def.unlock_stage > 0 {
-                Some(def.unlock_stage)
+            requirements: if def.unlock_stage > 0 {
+                vec![UnlockRequirement::stage(faction, def.unlock_stage)]
             } else {
-                None
+                Vec::new()
             },
         }
     }
@@ -1,15 +1,33 @@
 //! Game Modules System
 //!
 //! Supports multiple game modules (campaigns) that share the core engine.
-//! Each module defines its own factions, ships, missions, and theme.
+//! Each module implements [`GameModule`]: it declares its factions, ships,
+//! wave/boss/dialogue sources and which core systems it replaces, then
+//! registers its own systems. Core plugins gate themselves with
+//! [`core_enabled`] instead of knowing about individual modules, so adding a
+//! module never touches `spawning.rs`, `player.rs` or the HUD.
+//!
+//! Community campaigns are loaded from data by [`mod_packages`] and join
+//! the same registry as the compiled-in modules.
 
 #![allow(dead_code)]
 
+use std::collections::HashSet;
+use std::sync::Arc;
+
 use bevy::prelude::*;
 
+use crate::core::{
+    AchievementRegistry, AchievementSet, CampaignMap, GameSession, GameState, ScoreRule,
+    ShipUnlock, ShipUnlockRegistry, UnlockRequirement, DEFAULT_SCORE_RULES,
+};
+use crate::systems::dialogue::DialogueTrigger;
+
 pub mod abyssal_depths;
 pub mod caldari_gallente;
 pub mod elder_fleet;
+pub mod mod_packages;
+pub mod roguelite;
 pub mod triglavian_invasion;
 
 /// Game modules plugin - registers all available game modules
@@ -17,12 +35,259 @@ pub struct GameModulesPlugin;
 
 impl Plugin for GameModulesPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<ModuleRegistry>()
+        let mut modules: Vec<Box<dyn GameModule>> = vec![
+            Box::new(elder_fleet::ElderFleetModule::default()),
+            Box::new(caldari_gallente::CaldariGallenteModule::default()),
+            Box::new(abyssal_depths::AbyssalDepthsModule),
+            Box::new(triglavian_invasion::TriglavianInvasionModule),
+            Box::new(roguelite::RogueliteModule),
+        ];
+
+        // Community packages may not reuse a built-in id
+        let reserved: Vec<&str> = modules.iter().map(|m| m.id()).collect();
+        let built_in = reserved.len();
+        let (packages, errors) = mod_packages::load_installed(&reserved);
+        let packages = mod_packages::ModPackages::new(packages);
+        modules.extend(packages.modules());
+
+        let mut registry = ModuleRegistry::default();
+        let mut achievements = AchievementRegistry::default();
+        for module in &modules {
+            registry.register(module.info());
+            achievements.register(module.achievements());
+            module.build(app);
+        }
+
+        // Built-in modules claim their hulls first, then the core faction
+        // rosters; packages cannot relock a hull the game already offers
+        let mut ship_unlocks = ShipUnlockRegistry::default();
+        for module in &modules[..built_in] {
+            for unlock in module.ship_unlocks() {
+                ship_unlocks.register(unlock);
+            }
+        }
+        ship_unlocks.register_core_factions();
+        for module in &modules[built_in..] {
+            for unlock in module.ship_unlocks() {
+                let conflicts = ship_unlocks
+                    .get(unlock.type_id)
+                    .is_some_and(|taken| taken.requirements != unlock.requirements);
+                if conflicts {
+                    warn!(
+                        "{}: unlock for {} ({}) ignored, the hull is already registered",
+                        module.id(),
+                        unlock.name,
+                        unlock.type_id
+                    );
+                }
+                ship_unlocks.register(unlock);
+            }
+        }
+
+        app.insert_resource(packages)
+            .insert_resource(mod_packages::ModLoadErrors { errors })
+            .add_plugins(mod_packages::ModPackagesPlugin);
+
+        app.insert_resource(registry)
+            .insert_resource(achievements)
+            .insert_resource(ship_unlocks)
+            .insert_resource(GameModules { modules })
             .init_resource::<ActiveModule>()
-            .add_plugins(elder_fleet::ElderFleetPlugin)
-            .add_plugins(caldari_gallente::CaldariGallentePlugin)
-            .add_plugins(abyssal_depths::AbyssalDepthsPlugin)
-            .add_plugins(triglavian_invasion::TriglavianInvasionPlugin);
+            .init_resource::<CoreOverrides>()
+            .configure_sets(Update, ModuleHudSet.run_if(in_state(GameState::Playing)))
+            .add_systems(PreUpdate, refresh_core_overrides);
+    }
+}
+
+// =============================================================================
+// MODULE API
+// =============================================================================
+
+/// Core systems a module can switch off while it is active
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoreSystem {
+    /// `SpawningPlugin`: wave manager, enemy carrier and spawn events
+    Spawning,
+    /// `CampaignPlugin`: mission timer, waves and mission completion
+    Campaign,
+    /// `CampaignPlugin`: mission boss spawn, intro and defeat
+    Bosses,
+    /// `PlayerPlugin`: ship movement, shooting and stat updates
+    PlayerControl,
+    /// Main in-game HUD
+    Hud,
+    /// Shared faction select screen
+    FactionSelect,
+    /// Shared stage select screen (and the campaign star map)
+    StageSelect,
+    /// Shared boss intro, stage complete and victory screens
+    ResultScreens,
+}
+
+/// Who provides a kind of content while a module is active
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContentSource {
+    /// The shared core systems
+    #[default]
+    Core,
+    /// The module's own systems
+    Module,
+}
+
+/// Module-specific radio chatter
+#[derive(Clone)]
+pub struct DialogueProvider {
+    pub speaker: &'static str,
+    pub text: Arc<dyn Fn(&DialogueTrigger) -> String + Send + Sync>,
+}
+
+/// A game module (campaign) built on the shared engine
+pub trait GameModule: Send + Sync + 'static {
+    /// Stable id stored in `ActiveModule` and saves
+    fn id(&self) -> &'static str;
+
+    /// Registry metadata shown in module select
+    fn info(&self) -> GameModuleInfo;
+
+    /// Who spawns waves and runs the mission flow
+    fn wave_source(&self) -> ContentSource {
+        ContentSource::Core
+    }
+
+    /// Who spawns and resolves bosses
+    fn boss_source(&self) -> ContentSource {
+        ContentSource::Core
+    }
+
+    /// Radio chatter; `None` uses the core Elder Fleet lines
+    fn dialogue(&self) -> Option<DialogueProvider> {
+        None
+    }
+
+    /// Player and enemy ships for one of this module's factions
+    fn ship_pool(&self, _faction: &str) -> Option<&FactionShipPool> {
+        None
+    }
+
+    /// Hulls this module's hangars offer and what unlocks them; defaults to
+    /// the player ships of every faction's pool
+    fn ship_unlocks(&self) -> Vec<ShipUnlock> {
+        self.info()
+            .factions
+            .iter()
+            .filter_map(|faction| self.ship_pool(faction.id))
+            .flat_map(|pool| &pool.player_ships)
+            .map(|ship| ShipUnlock::new(ship.type_id, ship.name, ship.requirements.clone()))
+            .collect()
+    }
+
+    /// Star map the campaign is played on for the selected side; `None`
+    /// keeps the linear stage select
+    fn campaign_map(&self, _active: &ActiveModule) -> Option<&'static CampaignMap> {
+        None
+    }
+
+    /// Achievement sets earned in this module, shown as their own tabs in
+    /// the achievements browser
+    fn achievements(&self) -> &'static [AchievementSet] {
+        &[]
+    }
+
+    /// Multipliers every award runs through, in order
+    fn score_rules(&self) -> &'static [ScoreRule] {
+        DEFAULT_SCORE_RULES
+    }
+
+    /// Core systems to switch off while this module is active. Evaluated
+    /// every frame so sub-modes (e.g. Last Stand) can depend on world state.
+    fn replaces(&self, _world: &World) -> Vec<CoreSystem> {
+        sourced_replacements(self)
+    }
+
+    /// Register the module's own systems. Gate them with [`module_active`];
+    /// HUD additions go in [`ModuleHudSet`].
+    fn build(&self, app: &mut App);
+}
+
+/// Core systems implied by a module's wave and boss sources
+pub fn sourced_replacements<M: GameModule + ?Sized>(module: &M) -> Vec<CoreSystem> {
+    let mut replaced = Vec::new();
+    if module.wave_source() == ContentSource::Module {
+        replaced.push(CoreSystem::Campaign);
+    }
+    if module.boss_source() == ContentSource::Module {
+        replaced.push(CoreSystem::Bosses);
+    }
+    replaced
+}
+
+/// System set for module HUD extensions (runs while Playing)
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModuleHudSet;
+
+/// All compiled-in game modules
+#[derive(Resource)]
+pub struct GameModules {
+    pub modules: Vec<Box<dyn GameModule>>,
+}
+
+impl GameModules {
+    pub fn get(&self, id: &str) -> Option<&dyn GameModule> {
+        self.modules
+            .iter()
+            .find(|m| m.id() == id)
+            .map(|m| m.as_ref())
+    }
+
+    /// The module currently selected, if any
+    pub fn active(&self, active: &ActiveModule) -> Option<&dyn GameModule> {
+        active.module_id.as_deref().and_then(|id| self.get(id))
+    }
+}
+
+/// Core systems switched off by the active module this frame
+#[derive(Resource, Debug, Default)]
+pub struct CoreOverrides {
+    pub replaced: HashSet<CoreSystem>,
+}
+
+impl CoreOverrides {
+    pub fn enabled(&self, system: CoreSystem) -> bool {
+        !self.replaced.contains(&system)
+    }
+}
+
+/// Run condition: the core system is not replaced by the active module
+pub fn core_enabled(system: CoreSystem) -> impl Fn(Res<CoreOverrides>) -> bool + Clone {
+    move |overrides: Res<CoreOverrides>| overrides.enabled(system)
+}
+
+/// Run condition: the given module is active
+pub fn module_active(id: &'static str) -> impl Fn(Res<ActiveModule>) -> bool + Clone {
+    move |active: Res<ActiveModule>| active.is(id)
+}
+
+/// Run condition: the active module plays its campaign on a star map
+pub fn campaign_map_active(modules: Res<GameModules>, active: Res<ActiveModule>) -> bool {
+    modules
+        .active(&active)
+        .is_some_and(|m| m.campaign_map(&active).is_some())
+}
+
+/// Recompute which core systems the active module replaces
+fn refresh_core_overrides(world: &mut World) {
+    let replaced: HashSet<CoreSystem> = {
+        let modules = world.resource::<GameModules>();
+        let active = world.resource::<ActiveModule>();
+        modules
+            .active(active)
+            .map(|m| m.replaces(world).into_iter().collect())
+            .unwrap_or_default()
+    };
+
+    let mut overrides = world.resource_mut::<CoreOverrides>();
+    if overrides.replaced != replaced {
+        overrides.replaced = replaced;
     }
 }
 
@@ -84,20 +349,20 @@ impl ActiveModule {
         self.enemy_faction = Some(enemy.to_string());
     }
 
-    pub fn is_caldari_gallente(&self) -> bool {
-        self.module_id.as_deref() == Some("caldari_gallente")
-    }
-
-    pub fn is_elder_fleet(&self) -> bool {
-        self.module_id.as_deref() == Some("elder_fleet")
+    pub fn is(&self, module_id: &str) -> bool {
+        self.module_id.as_deref() == Some(module_id)
     }
 
-    pub fn is_abyssal_depths(&self) -> bool {
-        self.module_id.as_deref() == Some("abyssal_depths")
-    }
-
-    pub fn is_triglavian_invasion(&self) -> bool {
-        self.module_id.as_deref() == Some("triglavian_invasion")
+    /// Faction pair campaign progress is saved under: the module's own
+    /// pick, else the core session's
+    pub fn progress_factions<'a>(&'a self, session: &'a GameSession) -> (&'a str, &'a str) {
+        match (&self.player_faction, &self.enemy_faction) {
+            (Some(player), Some(enemy)) => (player, enemy),
+            _ => (
+                session.player_faction.short_name(),
+                session.enemy_faction.short_name(),
+            ),
+        }
     }
 }
 
@@ -113,8 +378,8 @@ pub struct ModuleShip {
     pub fire_rate: f32,
     pub damage: f32,
     pub unlimited_thrust: bool,
-    pub unlocked: bool,
-    pub unlock_mission: Option<u32>,
+    /// What unlocks the ship; empty = always available
+    pub requirements: Vec<UnlockRequirement>,
 }
 
 /// Ship pools for a faction
@@ -132,3 +397,100 @@ pub struct EnemyShipDef {
     pub class: &'static str,
     pub spawn_weight: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_modules() -> Vec<Box<dyn GameModule>> {
+        vec![
+            Box::new(elder_fleet::ElderFleetModule::default()),
+            Box::new(caldari_gallente::CaldariGallenteModule::default()),
+            Box::new(abyssal_depths::AbyssalDepthsModule),
+            Box::new(triglavian_invasion::TriglavianInvasionModule),
+            Box::new(roguelite::RogueliteModule),
+        ]
+    }
+
+    #[test]
+    fn module_ids_match_registry_info() {
+        let modules = all_modules();
+        let ids: HashSet<_> = modules.iter().map(|m| m.id()).collect();
+        assert_eq!(ids.len(), modules.len());
+        for module in &modules {
+            assert_eq!(module.id(), module.info().id);
+        }
+    }
+
+    #[test]
+    fn elder_fleet_uses_every_core_system() {
+        let world = World::new();
+        assert!(elder_fleet::ElderFleetModule::default()
+            .replaces(&world)
+            .is_empty());
+    }
+
+    #[test]
+    fn module_sources_replace_campaign_and_bosses() {
+        let world = World::new();
+        let replaced = triglavian_invasion::TriglavianInvasionModule.replaces(&world);
+        assert!(replaced.contains(&CoreSystem::Campaign));
+        assert!(replaced.contains(&CoreSystem::Bosses));
+        assert!(!replaced.contains(&CoreSystem::PlayerControl));
+    }
+
+    #[test]
+    fn roguelite_takes_over_stage_select() {
+        let world = World::new();
+        let replaced = roguelite::RogueliteModule.replaces(&world);
+        assert!(replaced.contains(&CoreSystem::StageSelect));
+        assert!(replaced.contains(&CoreSystem::Spawning));
+        assert!(!replaced.contains(&CoreSystem::FactionSelect));
+    }
+
+    #[test]
+    fn last_stand_takes_over_player_and_hud() {
+        let module = caldari_gallente::CaldariGallenteModule::default();
+        let mut world = World::new();
+        world.insert_resource(caldari_gallente::LastStandState::default());
+        assert!(!module.replaces(&world).contains(&CoreSystem::PlayerControl));
+
+        world
+            .resource_mut::<caldari_gallente::LastStandState>()
+            .active = true;
+        let replaced = module.replaces(&world);
+        assert!(replaced.contains(&CoreSystem::PlayerControl));
+        assert!(replaced.contains(&CoreSystem::Hud));
+        assert!(replaced.contains(&CoreSystem::Spawning));
+    }
+
+    #[test]
+    fn ship_pools_resolve_by_faction() {
+        let module = caldari_gallente::CaldariGallenteModule::default();
+        assert!(module
+            .ship_pool("caldari")
+            .is_some_and(|p| !p.player_ships.is_empty()));
+        assert!(module.ship_pool("amarr").is_none());
+    }
+
+    #[test]
+    fn built_in_modules_agree_on_every_hull() {
+        let mut registry = ShipUnlockRegistry::default();
+        for module in all_modules() {
+            for unlock in module.ship_unlocks() {
+                if let Some(taken) = registry.get(unlock.type_id) {
+                    assert_eq!(taken.requirements, unlock.requirements, "{}", unlock.name);
+                }
+                registry.register(unlock);
+            }
+        }
+
+        // Tactical destroyers open up with mission 4 of the C/G campaign
+        let jackdaw = registry.get(35683).unwrap();
+        assert!(jackdaw.cleared_by("CALDARI", "GALLENTE", 4));
+        // Invasion hulls are declared without a ship pool
+        assert!(registry
+            .get(triglavian_invasion::triglavian::VEDMAK)
+            .is_some_and(|s| s.cleared_by("triglavian", "edencom", 3)));
+    }
+}
@@ -0,0 +1,671 @@
+//! Mod Package Campaign
+//!
+//! Runs a package's missions in order: waves from the mission data, then
+//! the mission boss, then the next mission's briefing. Missions with a
+//! script skip the fixed order and do whatever the script's hooks ask.
+//! A mission fails as soon as a primary objective fails; completed
+//! objectives pay their rewards when the mission ends.
+//! Enemies and bosses are ordinary core entities, so the shared AI,
+//! collision and boss phase systems drive them.
+
+use bevy::prelude::*;
+
+use super::{MissionScript, ModPackage, ModPackages, ScriptCommand, ScriptView, WaveBehavior};
+use crate::assets::ShipSpriteCache;
+use crate::core::events::{EnemyDestroyedEvent, SpawnWaveEvent};
+use crate::core::{
+    GameState, MissionObjectives, ObjectiveEvent, ObjectiveStatus, RecordScoreEvent, ScoreEvent,
+    ShipClass, LAYER_ENEMIES, SCREEN_HEIGHT, SCREEN_WIDTH,
+};
+use crate::entities::{
+    spawn_enemy, Boss, BossAttack, BossBundle, BossData, BossMovement, BossState, Enemy,
+    EnemyStats, Hitbox, Movement, MovementPattern, Player, ShipStats, Weapon,
+};
+use crate::games::ActiveModule;
+use crate::systems::boss::BossDefeatedEvent;
+use crate::systems::dialogue::{DialogueEvent, DialogueTrigger};
+use crate::ui::HudObjective;
+
+/// Pause between waves, and before the first wave of a mission
+pub const MOD_WAVE_DELAY: f32 = 2.5;
+/// Longer pause after a mission so the success line can be read
+pub const MOD_MISSION_DELAY: f32 = 5.0;
+
+/// Progress through the active package's campaign
+#[derive(Resource, Default, Debug)]
+pub struct ModCampaignState {
+    /// Player ship chosen in select (EVE type id)
+    pub ship_type_id: u32,
+    /// Mission index into `ModPackage::missions`
+    pub mission: usize,
+    /// Waves of the current mission already spawned
+    pub wave: usize,
+    pub wave_timer: f32,
+    pub boss_spawned: bool,
+    pub briefing_shown: bool,
+    /// Script (if any) has been started for the current mission
+    pub script_started: bool,
+    /// Set when a script calls `end_mission`
+    pub script_outcome: Option<bool>,
+}
+
+impl ModCampaignState {
+    /// Start a run at `mission` flying `ship_type_id`
+    pub fn begin(&mut self, ship_type_id: u32, mission: usize) {
+        *self = Self {
+            ship_type_id,
+            mission,
+            wave_timer: MOD_WAVE_DELAY,
+            ..default()
+        };
+    }
+
+    fn next_mission(&mut self) {
+        self.mission += 1;
+        self.wave = 0;
+        self.wave_timer = MOD_MISSION_DELAY;
+        self.boss_spawned = false;
+        self.briefing_shown = false;
+        self.script_started = false;
+        self.script_outcome = None;
+    }
+}
+
+/// The running script of a scripted package mission
+#[derive(Resource, Default)]
+pub struct ModScriptRuntime {
+    pub script: Option<MissionScript>,
+    /// Waves spawned by the script, from the mission list or `spawn_ships`
+    pub waves: u32,
+    pub kills: u32,
+    /// A spawned wave has not been cleared yet
+    pub wave_active: bool,
+}
+
+/// Save-file faction key for a package faction. Namespaced by package so
+/// progress never collides with the built-in factions.
+pub fn progress_key(package: &ModPackage, faction: &str) -> String {
+    format!("{}/{}", package.id(), faction)
+}
+
+/// Stats of the chosen package ship on the freshly spawned player
+pub fn apply_mod_player_ship(
+    state: Res<ModCampaignState>,
+    packages: Res<ModPackages>,
+    active: Res<ActiveModule>,
+    sprite_cache: Res<ShipSpriteCache>,
+    save_data: Res<crate::core::SaveData>,
+    mut players: Query<
+        (
+            &mut ShipStats,
+            &mut Movement,
+            &mut Weapon,
+            Option<&mut Sprite>,
+        ),
+        Added<Player>,
+    >,
+) {
+    let Some(package) = packages.active(&active) else {
+        return;
+    };
+    let faction = active.player_faction.as_deref().unwrap_or_default();
+    let Some(ship) = package.pools.get(faction).and_then(|pool| {
+        pool.player_ships
+            .iter()
+            .find(|s| s.type_id == state.ship_type_id)
+    }) else {
+        return;
+    };
+
+    // Mod factions have no core doctrine; class skills apply by class name
+    let bonuses = save_data
+        .skills
+        .bonuses(None, ShipClass::from_name(ship.class));
+    for (mut stats, mut movement, mut weapon, sprite) in players.iter_mut() {
+        // Same shield/armor/hull split as the core ships
+        stats.type_id = ship.type_id;
+        stats.name = ship.name.to_string();
+        stats.max_shield = ship.health * 0.4 + bonuses.shield_bonus;
+        stats.shield = stats.max_shield;
+        stats.max_armor = ship.health * 0.35 + bonuses.armor_bonus;
+        stats.armor = stats.max_armor;
+        stats.max_hull = ship.health * 0.25;
+        stats.hull = stats.max_hull;
+
+        movement.max_speed = ship.speed * bonuses.speed_mult;
+        movement.acceleration = ship.speed * 3.0 * bonuses.speed_mult;
+
+        weapon.fire_rate = ship.fire_rate * bonuses.fire_rate_mult;
+        weapon.damage = ship.damage * bonuses.damage_mult;
+
+        if let (Some(mut sprite), Some(image)) = (sprite, sprite_cache.get(ship.type_id)) {
+            sprite.image = image;
+            sprite.color = Color::WHITE;
+        }
+
+        info!("Flying package ship {} ({})", ship.name, ship.class);
+    }
+}
+
+/// Brief the mission, then spawn its waves one at a time and finally its boss
+pub fn run_mod_mission(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut state: ResMut<ModCampaignState>,
+    packages: Res<ModPackages>,
+    active: Res<ActiveModule>,
+    sprite_cache: Res<ShipSpriteCache>,
+    mut objectives: ResMut<MissionObjectives>,
+    enemies: Query<(), With<Enemy>>,
+    bosses: Query<(), With<Boss>>,
+    mut wave_events: EventWriter<SpawnWaveEvent>,
+    mut dialogue: EventWriter<DialogueEvent>,
+) {
+    let Some(package) = packages.active(&active) else {
+        return;
+    };
+    let Some(mission) = package.missions.get(state.mission) else {
+        return;
+    };
+
+    if !state.briefing_shown {
+        state.briefing_shown = true;
+        info!(
+            "Starting package mission {}: {}",
+            state.mission + 1,
+            mission.name
+        );
+        dialogue.send(DialogueEvent::stage_briefing(state.mission as u32 + 1));
+        objectives.set(package.objectives(state.mission));
+    }
+
+    // Scripted missions spawn through `run_mod_script`
+    if package.script(state.mission).is_some() {
+        return;
+    }
+
+    if state.boss_spawned || !enemies.is_empty() || !bosses.is_empty() {
+        return;
+    }
+
+    state.wave_timer -= time.delta_secs();
+    if state.wave_timer > 0.0 {
+        return;
+    }
+    state.wave_timer = MOD_WAVE_DELAY;
+
+    let enemy_faction = active.enemy_faction.as_deref().unwrap_or_default();
+    if let Some(wave) = mission.waves.get(state.wave) {
+        let spawned = spawn_mod_wave(
+            &mut commands,
+            package,
+            enemy_faction,
+            &wave.ships,
+            wave.count,
+            wave.behavior,
+            &sprite_cache,
+        );
+        state.wave += 1;
+        wave_events.send(SpawnWaveEvent {
+            wave_number: state.wave as u32,
+            enemy_count: spawned.len() as u32,
+            enemy_types: spawned,
+        });
+    } else {
+        spawn_mod_boss(&mut commands, package, state.mission, &sprite_cache);
+        state.boss_spawned = true;
+    }
+}
+
+/// Drive a scripted mission: feed game events to the script's hooks and
+/// carry out the commands they queue
+pub fn run_mod_script(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut state: ResMut<ModCampaignState>,
+    mut runtime: ResMut<ModScriptRuntime>,
+    packages: Res<ModPackages>,
+    active: Res<ActiveModule>,
+    sprite_cache: Res<ShipSpriteCache>,
+    mut objective: ResMut<HudObjective>,
+    mut objectives: ResMut<MissionObjectives>,
+    enemies: Query<(), With<Enemy>>,
+    players: Query<&ShipStats, With<Player>>,
+    mut destroyed: EventReader<EnemyDestroyedEvent>,
+    mut bosses_defeated: EventReader<BossDefeatedEvent>,
+    mut objective_events: EventReader<ObjectiveEvent>,
+    mut wave_events: EventWriter<SpawnWaveEvent>,
+    mut dialogue: EventWriter<DialogueEvent>,
+) {
+    let Some(package) = packages.active(&active) else {
+        return;
+    };
+    let Some(mission) = package.missions.get(state.mission) else {
+        return;
+    };
+
+    if !state.script_started {
+        state.script_started = true;
+        *runtime = ModScriptRuntime {
+            script: package
+                .script(state.mission)
+                .cloned()
+                .map(MissionScript::new),
+            ..default()
+        };
+        objective.0 = None;
+        if let Some(script) = runtime.script.as_mut() {
+            script.call("on_mission_start", vec![]);
+        }
+    }
+    if runtime.script.is_none() || state.script_outcome.is_some() {
+        destroyed.clear();
+        bosses_defeated.clear();
+        objective_events.clear();
+        return;
+    }
+
+    let ModScriptRuntime {
+        script,
+        waves,
+        kills,
+        wave_active,
+    } = &mut *runtime;
+    let Some(script) = script.as_mut() else {
+        return;
+    };
+
+    let player_health = players
+        .iter()
+        .next()
+        .map(|stats| {
+            let max = stats.max_shield + stats.max_armor + stats.max_hull;
+            if max > 0.0 {
+                (stats.shield + stats.armor + stats.hull) / max
+            } else {
+                0.0
+            }
+        })
+        .unwrap_or(0.0);
+    let view = |kills: u32, waves: u32| ScriptView {
+        wave: waves,
+        waves_total: mission.waves.len() as u32,
+        enemies_alive: enemies.iter().count() as u32,
+        kills,
+        player_health,
+    };
+    script.set_view(view(*kills, *waves));
+
+    for event in destroyed.read().filter(|e| !e.was_boss) {
+        *kills += 1;
+        script.set_view(view(*kills, *waves));
+        script.call("on_enemy_destroyed", vec![event.enemy_type.clone().into()]);
+    }
+    for _ in bosses_defeated.read() {
+        script.call("on_boss_defeated", vec![]);
+    }
+    for event in objective_events.read() {
+        let hook = match event.status {
+            ObjectiveStatus::Complete => "on_objective_complete",
+            ObjectiveStatus::Failed => "on_objective_failed",
+            ObjectiveStatus::Active => continue,
+        };
+        script.call(hook, vec![(event.index as i64).into()]);
+    }
+    script.tick(time.delta_secs());
+
+    // Ships spawned this frame only show up next frame, so the check comes
+    // before this frame's commands are carried out
+    if *wave_active && enemies.is_empty() {
+        *wave_active = false;
+        script.call("on_wave_complete", vec![(*waves as i64).into()]);
+    }
+
+    let enemy_faction = active.enemy_faction.as_deref().unwrap_or_default();
+    for command in script.take_commands() {
+        let spawned = match command {
+            ScriptCommand::SpawnWave => match mission.waves.get(state.wave) {
+                Some(wave) => {
+                    state.wave += 1;
+                    spawn_mod_wave(
+                        &mut commands,
+                        package,
+                        enemy_faction,
+                        &wave.ships,
+                        wave.count,
+                        wave.behavior,
+                        &sprite_cache,
+                    )
+                }
+                None => {
+                    warn!("Mission script asked for a wave past the last one");
+                    Vec::new()
+                }
+            },
+            ScriptCommand::SpawnShips { type_id, count } => spawn_mod_wave(
+                &mut commands,
+                package,
+                enemy_faction,
+                type_id.as_slice(),
+                count,
+                None,
+                &sprite_cache,
+            ),
+            ScriptCommand::SpawnBoss => {
+                if !state.boss_spawned {
+                    spawn_mod_boss(&mut commands, package, state.mission, &sprite_cache);
+                    state.boss_spawned = true;
+                }
+                Vec::new()
+            }
+            ScriptCommand::Say(text) => {
+                dialogue.send(DialogueEvent {
+                    trigger: DialogueTrigger::Custom(text),
+                    duration: 4.0,
+                    priority: 6,
+                    ..default()
+                });
+                Vec::new()
+            }
+            ScriptCommand::SetObjective(text) => {
+                objective.0 = Some(text).filter(|t| !t.is_empty());
+                Vec::new()
+            }
+            ScriptCommand::CompleteObjective(index) => {
+                objectives.complete(index);
+                Vec::new()
+            }
+            ScriptCommand::FailObjective(index) => {
+                objectives.fail(index);
+                Vec::new()
+            }
+            ScriptCommand::EndMission { success } => {
+                state.script_outcome = Some(success);
+                break;
+            }
+        };
+
+        if !spawned.is_empty() {
+            *waves += 1;
+            *wave_active = true;
+            wave_events.send(SpawnWaveEvent {
+                wave_number: *waves,
+                enemy_count: spawned.len() as u32,
+                enemy_types: spawned,
+            });
+        }
+    }
+}
+
+/// Spawn one wave, returning the names of the ships spawned
+fn spawn_mod_wave(
+    commands: &mut Commands,
+    package: &ModPackage,
+    enemy_faction: &str,
+    ships: &[u32],
+    count: u32,
+    behavior: Option<WaveBehavior>,
+    sprite_cache: &ShipSpriteCache,
+) -> Vec<String> {
+    // Explicit wave ships are equally likely; otherwise use pool weights
+    let candidates: Vec<(u32, u32)> = if ships.is_empty() {
+        package
+            .pools
+            .get(enemy_faction)
+            .map(|pool| {
+                pool.enemy_ships
+                    .iter()
+                    .map(|s| (s.type_id, s.spawn_weight))
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        ships.iter().map(|&id| (id, 1)).collect()
+    };
+    let total_weight: u32 = candidates.iter().map(|(_, w)| w).sum();
+    if total_weight == 0 {
+        return Vec::new();
+    }
+
+    let spread = SCREEN_WIDTH * 0.8;
+    let spawn_y = SCREEN_HEIGHT / 2.0 + 50.0;
+    let mut names = Vec::new();
+
+    for i in 0..count {
+        let roll = fastrand::u32(0..total_weight);
+        let mut cumulative = 0;
+        let mut type_id = candidates[0].0;
+        for &(id, weight) in &candidates {
+            cumulative += weight;
+            if roll < cumulative {
+                type_id = id;
+                break;
+            }
+        }
+
+        let x = -spread / 2.0 + (i as f32 + 0.5) / count as f32 * spread + fastrand::f32() * 40.0
+            - 20.0;
+        let y = spawn_y + fastrand::f32() * 100.0;
+
+        let tuning = package.enemy_tuning.get(&type_id).cloned();
+        let behavior = behavior
+            .or(tuning.as_ref().map(|t| t.behavior))
+            .unwrap_or(WaveBehavior::Linear);
+        let name = package
+            .pools
+            .values()
+            .flat_map(|pool| pool.enemy_ships.iter())
+            .find(|s| s.type_id == type_id)
+            .map(|s| s.name.to_string());
+
+        let entity = spawn_enemy(
+            commands,
+            type_id,
+            Vec2::new(x, y),
+            behavior.enemy_behavior(),
+            sprite_cache.get(type_id),
+            None,
+        );
+
+        // Package values win over the built-in table for this type id
+        let override_name = name.clone();
+        commands
+            .entity(entity)
+            .queue(move |mut entity: EntityWorldMut| {
+                let Some(mut stats) = entity.get_mut::<EnemyStats>() else {
+                    return;
+                };
+                if let Some(name) = override_name {
+                    stats.name = name;
+                }
+                if let Some(tuning) = tuning {
+                    if let Some(health) = tuning.health {
+                        stats.health = health;
+                        stats.max_health = health;
+                    }
+                    if let Some(speed) = tuning.speed {
+                        stats.speed = speed;
+                    }
+                    if let Some(score) = tuning.score {
+                        stats.score_value = score;
+                    }
+                }
+            });
+
+        names.push(name.unwrap_or_else(|| type_id.to_string()));
+    }
+
+    names
+}
+
+/// Spawn the mission boss from package data
+fn spawn_mod_boss(
+    commands: &mut Commands,
+    package: &ModPackage,
+    mission: usize,
+    sprite_cache: &ShipSpriteCache,
+) {
+    let Some(boss) = package.missions.get(mission).map(|m| &m.boss) else {
+        return;
+    };
+
+    let size = 64.0 * boss.scale;
+    let stage = mission as u32 + 1;
+    let sprite = match sprite_cache.get(boss.type_id) {
+        Some(image) => Sprite {
+            image,
+            custom_size: Some(Vec2::splat(size)),
+            ..default()
+        },
+        None => Sprite {
+            color: package
+                .info
+                .factions
+                .last()
+                .map(|f| f.primary_color)
+                .unwrap_or(Color::srgb(0.8, 0.2, 0.2)),
+            custom_size: Some(Vec2::splat(size)),
+            ..default()
+        },
+    };
+
+    info!("Spawning package boss: {} ({})", boss.name, boss.type_id);
+
+    commands.spawn(BossBundle {
+        boss: Boss,
+        data: BossData {
+            id: stage,
+            stage,
+            name: boss.name.clone(),
+            title: if boss.title.is_empty() {
+                package.missions[mission].name.clone()
+            } else {
+                boss.title.clone()
+            },
+            ship_class: boss.ship_class.clone(),
+            type_id: boss.type_id,
+            max_health: boss.health,
+            health: boss.health,
+            current_phase: 1,
+            total_phases: boss.phases,
+            score_value: boss.score,
+            liberation_value: 0,
+            stationary: boss.stationary,
+            dialogue_intro: if boss.intro.is_empty() {
+                format!("{} has engaged!", boss.name)
+            } else {
+                boss.intro.clone()
+            },
+            dialogue_defeat: if boss.defeat.is_empty() {
+                format!("{} has been destroyed!", boss.name)
+            } else {
+                boss.defeat.clone()
+            },
+            is_enraged: false,
+            enrage_threshold: 0.2,
+        },
+        state: BossState::Intro,
+        movement: BossMovement {
+            pattern: if boss.stationary {
+                MovementPattern::Stationary
+            } else {
+                MovementPattern::Descend
+            },
+            timer: 0.0,
+            speed: 80.0,
+        },
+        attack: BossAttack::default(),
+        hitbox: Hitbox {
+            radius: size / 2.0 * 0.8,
+        },
+        sprite,
+        // EVE renders face UP, rotate 180° to face DOWN
+        transform: Transform::from_xyz(0.0, SCREEN_HEIGHT / 2.0 + size, LAYER_ENEMIES)
+            .with_rotation(Quat::from_rotation_z(std::f32::consts::PI)),
+    });
+}
+
+/// Once the boss is gone (or the script ends the mission), pay out
+/// objective rewards, record progress and move to the next mission or the
+/// victory screen. A failed primary objective ends the run.
+pub fn check_mod_mission_complete(
+    mut state: ResMut<ModCampaignState>,
+    mut runtime: ResMut<ModScriptRuntime>,
+    packages: Res<ModPackages>,
+    active: Res<ActiveModule>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut record_events: EventWriter<RecordScoreEvent>,
+    mut save_data: ResMut<crate::core::SaveData>,
+    mut objective: ResMut<HudObjective>,
+    mut objectives: ResMut<MissionObjectives>,
+    bosses: Query<(), With<Boss>>,
+    mut dialogue: EventWriter<DialogueEvent>,
+    mut transitions: EventWriter<crate::ui::TransitionEvent>,
+) {
+    let Some(package) = packages.active(&active) else {
+        return;
+    };
+    // Already settled; the next briefing sets fresh objectives
+    if objectives.rewards.is_some() {
+        return;
+    }
+    // Scripted missions end only through `end_mission`
+    let outcome = if objectives.primary_failed() {
+        Some(false)
+    } else if package.script(state.mission).is_some() {
+        state.script_outcome
+    } else {
+        (state.boss_spawned && bosses.is_empty()).then_some(true)
+    };
+    let Some(success) = outcome else {
+        return;
+    };
+    // Primaries still open when the mission ends fail it
+    let rewards = objectives.finish(success);
+    if !success || objectives.primary_failed() {
+        info!("Package mission {} failed", state.mission + 1);
+        // Stop the script so the failure is only handled once
+        state.script_outcome = None;
+        runtime.script = None;
+        objective.0 = None;
+        transitions.send(crate::ui::TransitionEvent::slow(GameState::GameOver));
+        return;
+    }
+    objective.0 = None;
+
+    score_events.send(ScoreEvent::objective(rewards.score));
+    save_data.add_skill_points(rewards.skill_points);
+    info!(
+        "Objectives {}/{}: +{} score, +{} SP",
+        rewards.completed, rewards.total, rewards.score, rewards.skill_points
+    );
+
+    let faction = progress_key(
+        package,
+        active.player_faction.as_deref().unwrap_or_default(),
+    );
+    let enemy = active.enemy_faction.as_deref().unwrap_or_default();
+    let stage = state.mission as u32 + 1;
+    save_data.complete_stage(&faction, enemy, stage, state.mission as u32);
+    record_events.send(RecordScoreEvent::new(faction, enemy, stage));
+
+    info!("Package mission {} complete", stage);
+    state.next_mission();
+
+    if state.mission >= package.missions.len() {
+        transitions.send(crate::ui::TransitionEvent::slow(GameState::Victory));
+    } else {
+        dialogue.send(DialogueEvent {
+            trigger: DialogueTrigger::MissionSuccess,
+            duration: 4.0,
+            priority: 9,
+            ..default()
+        });
+    }
+}
+
+/// Retry from the death screen goes to ship select; packages pick ships on
+/// their own select screen instead
+pub fn redirect_ship_select(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::FactionSelect);
+}
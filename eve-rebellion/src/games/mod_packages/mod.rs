@@ -0,0 +1,259 @@
+//! Mod Packages
+//!
+//! Community campaigns loaded at startup from
+//! `<data dir>/eve_rebellion/mods/<folder>/` without recompiling. Every valid
+//! package becomes a [`DataModule`] registered in `GameModules` and
+//! `ModuleRegistry`, so it shows up in module select next to the built-in
+//! campaigns. Broken packages are skipped and their errors listed on the
+//! module select screen. Missions may hand their flow to a sandboxed
+//! [`script`].
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use super::{
+    sourced_replacements, ActiveModule, ContentSource, CoreSystem, DialogueProvider,
+    FactionShipPool, GameModule, GameModuleInfo,
+};
+use crate::assets::ShipSpriteCache;
+use crate::core::GameState;
+use crate::systems::dialogue::{CombatCalloutType, DialogueTrigger};
+
+pub mod campaign;
+pub mod package;
+pub mod script;
+pub mod select;
+
+pub use campaign::*;
+pub use package::*;
+pub use script::*;
+pub use select::*;
+
+/// Folder scanned for packages
+#[cfg(not(target_arch = "wasm32"))]
+pub fn mods_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("eve_rebellion")
+        .join("mods")
+}
+
+/// WASM has no data dir; no packages are loaded
+#[cfg(target_arch = "wasm32")]
+pub fn mods_dir() -> PathBuf {
+    PathBuf::new()
+}
+
+/// Load all installed packages, logging each problem
+pub fn load_installed(reserved: &[&str]) -> (Vec<ModPackage>, Vec<ModError>) {
+    let dir = mods_dir();
+    if dir.as_os_str().is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let (packages, errors) = load_packages(&dir, reserved);
+    for package in &packages {
+        info!(
+            "Loaded mod package '{}' ({}) from {:?}",
+            package.info.display_name,
+            package.id(),
+            dir.join(&package.folder)
+        );
+    }
+    for error in &errors {
+        warn!("Mod package error: {}", error);
+    }
+    (packages, errors)
+}
+
+/// Loaded packages, in module select order
+#[derive(Resource, Default)]
+pub struct ModPackages {
+    pub packages: Vec<Arc<ModPackage>>,
+}
+
+impl ModPackages {
+    pub fn new(packages: Vec<ModPackage>) -> Self {
+        Self {
+            packages: packages.into_iter().map(Arc::new).collect(),
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ModPackage> {
+        self.packages
+            .iter()
+            .find(|p| p.id() == id)
+            .map(|p| p.as_ref())
+    }
+
+    /// The package behind the active module, if it is one
+    pub fn active(&self, active: &ActiveModule) -> Option<&ModPackage> {
+        active.module_id.as_deref().and_then(|id| self.get(id))
+    }
+
+    /// One [`DataModule`] per package
+    pub fn modules(&self) -> Vec<Box<dyn GameModule>> {
+        self.packages
+            .iter()
+            .map(|package| {
+                Box::new(DataModule {
+                    package: package.clone(),
+                }) as Box<dyn GameModule>
+            })
+            .collect()
+    }
+}
+
+/// Problems found while loading packages, shown in module select
+#[derive(Resource, Default)]
+pub struct ModLoadErrors {
+    pub errors: Vec<ModError>,
+}
+
+/// Run condition: the active module is a loaded package
+pub fn mod_package_active(active: Res<ActiveModule>, packages: Res<ModPackages>) -> bool {
+    packages.active(&active).is_some()
+}
+
+/// A campaign defined entirely by package data
+pub struct DataModule {
+    package: Arc<ModPackage>,
+}
+
+impl GameModule for DataModule {
+    fn id(&self) -> &'static str {
+        self.package.id()
+    }
+
+    fn info(&self) -> GameModuleInfo {
+        self.package.info.clone()
+    }
+
+    fn wave_source(&self) -> ContentSource {
+        ContentSource::Module
+    }
+
+    fn boss_source(&self) -> ContentSource {
+        ContentSource::Module
+    }
+
+    fn dialogue(&self) -> Option<DialogueProvider> {
+        let package = self.package.clone();
+        Some(DialogueProvider {
+            speaker: package.speaker,
+            text: Arc::new(move |trigger: &DialogueTrigger| dialogue_line(&package, trigger)),
+        })
+    }
+
+    fn ship_pool(&self, faction: &str) -> Option<&FactionShipPool> {
+        self.package.pools.get(faction)
+    }
+
+    fn replaces(&self, _world: &World) -> Vec<CoreSystem> {
+        let mut replaced = sourced_replacements(self);
+        replaced.extend([CoreSystem::Spawning, CoreSystem::FactionSelect]);
+        replaced
+    }
+
+    /// Package systems are shared and registered once by [`ModPackagesPlugin`]
+    fn build(&self, _app: &mut App) {}
+}
+
+/// Radio line for a trigger, falling back to neutral chatter where the
+/// package has nothing to say
+pub fn dialogue_line(package: &ModPackage, trigger: &DialogueTrigger) -> String {
+    fn pick(lines: &[String], fallback: &str) -> String {
+        if lines.is_empty() {
+            fallback.to_string()
+        } else {
+            lines[fastrand::usize(..lines.len())].clone()
+        }
+    }
+
+    let dialogue = &package.dialogue;
+    match trigger {
+        DialogueTrigger::StageBriefing(mission) => dialogue
+            .briefings
+            .get((*mission as usize).saturating_sub(1))
+            .cloned()
+            .or_else(|| {
+                package
+                    .missions
+                    .get((*mission as usize).saturating_sub(1))
+                    .map(|m| m.description.clone())
+                    .filter(|d| !d.is_empty())
+            })
+            .unwrap_or_else(|| "Hostiles inbound. Engage at will.".to_string()),
+        DialogueTrigger::BossIntro(name) => format!("{} is on grid!", name),
+        DialogueTrigger::BossDefeated(name) => format!("{} destroyed.", name),
+        DialogueTrigger::MissionSuccess => pick(&dialogue.mission_success, "Mission complete."),
+        DialogueTrigger::PlayerDeath => pick(&dialogue.player_death, "We've lost you, pilot."),
+        DialogueTrigger::CombatCallout(CombatCalloutType::LowHealth)
+        | DialogueTrigger::CombatCallout(CombatCalloutType::NearDeath) => {
+            pick(&dialogue.low_health, "You're taking heavy damage!")
+        }
+        DialogueTrigger::CombatCallout(CombatCalloutType::BossLowHealth) => {
+            pick(&dialogue.boss_low_health, "It's breaking up! Finish it!")
+        }
+        DialogueTrigger::CombatCallout(CombatCalloutType::WaveIncoming) => {
+            "More hostiles incoming.".to_string()
+        }
+        DialogueTrigger::Custom(text) => text.clone(),
+        _ => "Stay sharp, pilot.".to_string(),
+    }
+}
+
+/// Shared systems for every package: sprite preloading, faction and ship
+/// select, waves, bosses and mission flow
+pub struct ModPackagesPlugin;
+
+impl Plugin for ModPackagesPlugin {
+    fn build(&self, app: &mut App) {
+        // Packages are inserted by `GameModulesPlugin` before this runs
+        let type_ids: Vec<u32> = app
+            .world()
+            .get_resource::<ModPackages>()
+            .map(|p| p.packages.iter().flat_map(|p| p.type_ids()).collect())
+            .unwrap_or_default();
+        if let Some(mut cache) = app.world_mut().get_resource_mut::<ShipSpriteCache>() {
+            cache.request(type_ids);
+        }
+
+        app.init_resource::<ModPackages>()
+            .init_resource::<ModLoadErrors>()
+            .init_resource::<ModCampaignState>()
+            .init_resource::<ModScriptRuntime>()
+            .init_resource::<ModSelection>()
+            .add_systems(
+                OnEnter(GameState::FactionSelect),
+                spawn_mod_select.run_if(mod_package_active),
+            )
+            .add_systems(
+                Update,
+                mod_select_input
+                    .run_if(in_state(GameState::FactionSelect))
+                    .run_if(mod_package_active),
+            )
+            .add_systems(OnExit(GameState::FactionSelect), despawn_mod_select)
+            .add_systems(
+                Update,
+                redirect_ship_select
+                    .run_if(in_state(GameState::ShipSelect))
+                    .run_if(mod_package_active),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_mod_player_ship,
+                    run_mod_mission,
+                    run_mod_script,
+                    check_mod_mission_complete,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(mod_package_active),
+            );
+    }
+}
@@ -0,0 +1,1211 @@
+//! Mod Package Data
+//!
+//! On-disk format of a mod package and its validation. A package is a
+//! folder with four JSON files:
+//!
+//! - `manifest.json`: id, display name, subtitle, description and factions
+//! - `ships.json`: player and enemy ship pools keyed by faction id
+//! - `missions.json`: missions, each with waves, a boss and optional
+//!   typed objectives
+//! - `dialogue.json` (optional): speaker and radio lines
+//!
+//! Missions may also name a Rhai script (see [`super::script`]) that takes
+//! over their flow. Scripts are compiled at load so syntax errors show up
+//! with the other package errors.
+//!
+//! Parsing never panics; every problem becomes a [`ModError`] naming the
+//! package, file and field so it can be shown in-game.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+
+use bevy::prelude::*;
+use rhai::AST;
+use serde::Deserialize;
+
+use super::compile_script;
+use crate::core::{Objective, ObjectiveKind, UnlockRequirement};
+use crate::entities::EnemyBehavior;
+use crate::games::{EnemyShipDef, FactionInfo, FactionShipPool, GameModuleInfo, ModuleShip};
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+pub const SHIPS_FILE: &str = "ships.json";
+pub const MISSIONS_FILE: &str = "missions.json";
+pub const DIALOGUE_FILE: &str = "dialogue.json";
+/// Extension required for mission scripts
+pub const SCRIPT_EXTENSION: &str = "rhai";
+
+/// Upper bound on enemies in a single wave
+pub const MAX_WAVE_SIZE: u32 = 30;
+/// Upper bound on boss phases (matches the core boss health bar)
+pub const MAX_BOSS_PHASES: u32 = 5;
+
+/// A problem found while loading a package
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModError {
+    /// Package folder name
+    pub package: String,
+    /// File inside the package, empty for folder-level problems
+    pub file: String,
+    pub message: String,
+}
+
+impl ModError {
+    pub fn new(package: &str, file: &str, message: impl Into<String>) -> Self {
+        Self {
+            package: package.to_string(),
+            file: file.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ModError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.file.is_empty() {
+            write!(f, "{}: {}", self.package, self.message)
+        } else {
+            write!(f, "{}/{}: {}", self.package, self.file, self.message)
+        }
+    }
+}
+
+// =============================================================================
+// FILE FORMAT
+// =============================================================================
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestFile {
+    pub id: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub subtitle: String,
+    #[serde(default)]
+    pub description: String,
+    pub factions: Vec<FactionFile>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct FactionFile {
+    pub id: String,
+    pub name: String,
+    /// Hex colors, e.g. `"#cc4422"`
+    pub primary_color: String,
+    pub secondary_color: String,
+    pub accent_color: String,
+    #[serde(default)]
+    pub doctrine: Vec<String>,
+    #[serde(default)]
+    pub description: String,
+    /// Faction fought when playing this one; defaults to the next faction
+    #[serde(default)]
+    pub rival: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ShipPoolFile {
+    #[serde(default)]
+    pub player_ships: Vec<PlayerShipFile>,
+    #[serde(default)]
+    pub enemy_ships: Vec<EnemyShipFile>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PlayerShipFile {
+    /// EVE type id, resolved through `ShipSpriteCache`
+    pub type_id: u32,
+    pub name: String,
+    pub class: String,
+    #[serde(default)]
+    pub role: String,
+    pub health: f32,
+    pub speed: f32,
+    pub fire_rate: f32,
+    pub damage: f32,
+    #[serde(default)]
+    pub unlimited_thrust: bool,
+    /// Mission (1-based) that must be cleared before the ship can be flown
+    #[serde(default)]
+    pub unlock_mission: Option<u32>,
+    /// Achievement id that must be earned first
+    #[serde(default)]
+    pub unlock_achievement: Option<String>,
+    /// Lifetime best score needed
+    #[serde(default)]
+    pub unlock_score: Option<u64>,
+    /// Skill Points to buy the ship from the hangar
+    #[serde(default)]
+    pub unlock_cost: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct EnemyShipFile {
+    pub type_id: u32,
+    pub name: String,
+    pub class: String,
+    #[serde(default = "default_spawn_weight")]
+    pub spawn_weight: u32,
+    /// Overrides for the built-in stats of this type id
+    #[serde(default)]
+    pub health: Option<f32>,
+    #[serde(default)]
+    pub speed: Option<f32>,
+    #[serde(default)]
+    pub score: Option<u64>,
+    #[serde(default)]
+    pub behavior: Option<WaveBehavior>,
+}
+
+fn default_spawn_weight() -> u32 {
+    10
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct MissionFile {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Waves in order; scripted missions may leave this empty and spawn
+    /// ships themselves
+    #[serde(default)]
+    pub waves: Vec<WaveFile>,
+    pub boss: BossFile,
+    /// Rhai script driving the mission, relative to the package folder
+    #[serde(default)]
+    pub script: Option<String>,
+    /// Primary and optional objectives; unscripted missions without a
+    /// primary one get `destroy_boss`
+    #[serde(default)]
+    pub objectives: Vec<ObjectiveFile>,
+}
+
+/// One objective; which fields apply depends on `kind`
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ObjectiveFile {
+    pub kind: ObjectiveKindFile,
+    /// HUD text; defaults to a description of the kind
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub optional: bool,
+    /// Pods for `collect`, kills for `destroy_targets`
+    #[serde(default)]
+    pub count: Option<u32>,
+    /// For `survive`, `stay_in_area` and `time_limit`
+    #[serde(default)]
+    pub seconds: Option<f32>,
+    /// Convoy or structure for `escort`/`defend`; target filter for
+    /// `destroy_targets`
+    #[serde(default)]
+    pub type_id: Option<u32>,
+    #[serde(default)]
+    pub health: Option<f32>,
+    /// Convoy speed in pixels per second
+    #[serde(default)]
+    pub speed: Option<f32>,
+    /// Center and radius of a `stay_in_area` circle, in screen pixels
+    /// from the middle of the screen
+    #[serde(default)]
+    pub x: f32,
+    #[serde(default)]
+    pub y: f32,
+    #[serde(default)]
+    pub radius: Option<f32>,
+    /// Rewards; default to the core primary/optional values
+    #[serde(default)]
+    pub score: Option<u64>,
+    #[serde(default)]
+    pub skill_points: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectiveKindFile {
+    DestroyBoss,
+    Escort,
+    Defend,
+    Collect,
+    Survive,
+    DestroyTargets,
+    StayInArea,
+    NoDamage,
+    TimeLimit,
+    Scripted,
+}
+
+const DEFAULT_ESCORT_HEALTH: f32 = 300.0;
+const DEFAULT_ESCORT_SPEED: f32 = 25.0;
+const DEFAULT_DEFEND_HEALTH: f32 = 500.0;
+const DEFAULT_AREA_RADIUS: f32 = 80.0;
+
+impl ObjectiveFile {
+    /// Build the objective, or say which field is missing or out of range
+    pub fn objective(&self) -> Result<Objective, String> {
+        fn positive(value: Option<f32>, field: &str) -> Result<f32, String> {
+            match value {
+                Some(v) if v.is_finite() && v > 0.0 => Ok(v),
+                Some(_) => Err(format!("{} must be positive", field)),
+                None => Err(format!("{} is required", field)),
+            }
+        }
+        let count = || match self.count {
+            Some(0) | None => Err("count must be at least 1".to_string()),
+            Some(count) => Ok(count),
+        };
+        let type_id = || match self.type_id {
+            Some(0) | None => Err("type_id must be an EVE type id".to_string()),
+            Some(id) => Ok(id),
+        };
+
+        let kind = match self.kind {
+            ObjectiveKindFile::DestroyBoss => ObjectiveKind::DestroyBoss,
+            ObjectiveKindFile::Escort => ObjectiveKind::Escort {
+                type_id: type_id()?,
+                health: positive(self.health.or(Some(DEFAULT_ESCORT_HEALTH)), "health")?,
+                speed: positive(self.speed.or(Some(DEFAULT_ESCORT_SPEED)), "speed")?,
+            },
+            ObjectiveKindFile::Defend => ObjectiveKind::Defend {
+                type_id: type_id()?,
+                health: positive(self.health.or(Some(DEFAULT_DEFEND_HEALTH)), "health")?,
+            },
+            ObjectiveKindFile::Collect => ObjectiveKind::Collect { count: count()? },
+            ObjectiveKindFile::Survive => ObjectiveKind::Survive {
+                seconds: positive(self.seconds, "seconds")?,
+            },
+            ObjectiveKindFile::DestroyTargets => ObjectiveKind::DestroyTargets {
+                type_id: self.type_id.filter(|&id| id != 0),
+                count: count()?,
+            },
+            ObjectiveKindFile::StayInArea => ObjectiveKind::StayInArea {
+                center: Vec2::new(self.x, self.y),
+                radius: positive(self.radius.or(Some(DEFAULT_AREA_RADIUS)), "radius")?,
+                seconds: positive(self.seconds, "seconds")?,
+            },
+            ObjectiveKindFile::NoDamage => ObjectiveKind::NoDamage,
+            ObjectiveKindFile::TimeLimit => ObjectiveKind::TimeLimit {
+                seconds: positive(self.seconds, "seconds")?,
+            },
+            ObjectiveKindFile::Scripted => ObjectiveKind::Scripted,
+        };
+
+        let mut objective = if self.optional {
+            Objective::optional(kind)
+        } else {
+            Objective::primary(kind)
+        };
+        if let Some(label) = self.label.as_ref().filter(|l| !l.trim().is_empty()) {
+            objective = objective.with_label(label.clone());
+        }
+        let (score, skill_points) = (
+            self.score.unwrap_or(objective.score),
+            self.skill_points.unwrap_or(objective.skill_points),
+        );
+        Ok(objective.with_rewards(score, skill_points))
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct WaveFile {
+    pub count: u32,
+    /// Type ids to draw from; empty uses the enemy faction's pool
+    #[serde(default)]
+    pub ships: Vec<u32>,
+    /// Forces a behavior for the whole wave
+    #[serde(default)]
+    pub behavior: Option<WaveBehavior>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BossFile {
+    pub type_id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub ship_class: String,
+    pub health: f32,
+    #[serde(default = "default_boss_phases")]
+    pub phases: u32,
+    #[serde(default = "default_boss_score")]
+    pub score: u64,
+    #[serde(default = "default_boss_scale")]
+    pub scale: f32,
+    #[serde(default)]
+    pub stationary: bool,
+    #[serde(default)]
+    pub intro: String,
+    #[serde(default)]
+    pub defeat: String,
+}
+
+fn default_boss_phases() -> u32 {
+    3
+}
+
+fn default_boss_score() -> u64 {
+    1000
+}
+
+fn default_boss_scale() -> f32 {
+    2.5
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct DialogueFile {
+    #[serde(default)]
+    pub speaker: String,
+    /// One briefing per mission, in mission order
+    #[serde(default)]
+    pub briefings: Vec<String>,
+    #[serde(default)]
+    pub mission_success: Vec<String>,
+    #[serde(default)]
+    pub player_death: Vec<String>,
+    #[serde(default)]
+    pub low_health: Vec<String>,
+    #[serde(default)]
+    pub boss_low_health: Vec<String>,
+}
+
+/// Enemy movement patterns a package may name
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WaveBehavior {
+    Linear,
+    Zigzag,
+    Homing,
+    Orbital,
+    Sniper,
+    Kamikaze,
+    Weaver,
+    Spawner,
+    Tank,
+    Disintegrator,
+}
+
+impl WaveBehavior {
+    pub fn enemy_behavior(self) -> EnemyBehavior {
+        match self {
+            WaveBehavior::Linear => EnemyBehavior::Linear,
+            WaveBehavior::Zigzag => EnemyBehavior::Zigzag,
+            WaveBehavior::Homing => EnemyBehavior::Homing,
+            WaveBehavior::Orbital => EnemyBehavior::Orbital,
+            WaveBehavior::Sniper => EnemyBehavior::Sniper,
+            WaveBehavior::Kamikaze => EnemyBehavior::Kamikaze,
+            WaveBehavior::Weaver => EnemyBehavior::Weaver,
+            WaveBehavior::Spawner => EnemyBehavior::Spawner,
+            WaveBehavior::Tank => EnemyBehavior::Tank,
+            WaveBehavior::Disintegrator => EnemyBehavior::Disintegrator,
+        }
+    }
+
+    /// Default behavior for a ship class name
+    pub fn for_class(class: &str) -> Self {
+        match class.to_ascii_lowercase().as_str() {
+            "frigate" | "interceptor" | "assault frigate" => WaveBehavior::Zigzag,
+            "destroyer" | "cruiser" => WaveBehavior::Linear,
+            "battlecruiser" | "battleship" => WaveBehavior::Tank,
+            "carrier" => WaveBehavior::Spawner,
+            _ => WaveBehavior::Linear,
+        }
+    }
+}
+
+// =============================================================================
+// LOADED PACKAGE
+// =============================================================================
+
+/// Stat overrides for an enemy type id
+#[derive(Clone, Debug)]
+pub struct EnemyTuning {
+    pub health: Option<f32>,
+    pub speed: Option<f32>,
+    pub score: Option<u64>,
+    pub behavior: WaveBehavior,
+}
+
+/// A validated package ready to play
+#[derive(Clone, Debug)]
+pub struct ModPackage {
+    /// Folder name, used in error messages
+    pub folder: String,
+    pub info: GameModuleInfo,
+    /// Enemy faction for each faction id
+    pub rivals: HashMap<String, String>,
+    pub pools: HashMap<String, FactionShipPool>,
+    pub enemy_tuning: HashMap<u32, EnemyTuning>,
+    pub missions: Vec<MissionFile>,
+    /// Compiled script for each mission, parallel to `missions`
+    pub scripts: Vec<Option<AST>>,
+    /// Objectives for each mission, parallel to `missions`
+    pub objectives: Vec<Vec<Objective>>,
+    pub dialogue: DialogueFile,
+    /// Radio speaker name
+    pub speaker: &'static str,
+}
+
+impl ModPackage {
+    pub fn id(&self) -> &'static str {
+        self.info.id
+    }
+
+    pub fn rival_of(&self, faction: &str) -> Option<&str> {
+        self.rivals.get(faction).map(String::as_str)
+    }
+
+    /// Script driving `mission`, if it has one
+    pub fn script(&self, mission: usize) -> Option<&AST> {
+        self.scripts.get(mission).and_then(Option::as_ref)
+    }
+
+    /// Fresh objectives for `mission`
+    pub fn objectives(&self, mission: usize) -> Vec<Objective> {
+        self.objectives.get(mission).cloned().unwrap_or_default()
+    }
+
+    /// Every type id the package shows, for sprite preloading
+    pub fn type_ids(&self) -> Vec<u32> {
+        let mut ids: Vec<u32> = self
+            .pools
+            .values()
+            .flat_map(|pool| {
+                pool.player_ships
+                    .iter()
+                    .map(|s| s.type_id)
+                    .chain(pool.enemy_ships.iter().map(|s| s.type_id))
+            })
+            .chain(self.missions.iter().flat_map(|m| {
+                m.waves
+                    .iter()
+                    .flat_map(|w| w.ships.iter().copied())
+                    .chain(std::iter::once(m.boss.type_id))
+                    .chain(m.objectives.iter().filter_map(|o| o.type_id))
+            }))
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+}
+
+/// Package strings live for the whole run, matching the `&'static str`
+/// fields of the compiled-in modules
+fn leak(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
+
+fn parse_color(hex: &str) -> Option<Color> {
+    Srgba::hex(hex.trim()).ok().map(Color::from)
+}
+
+fn valid_id(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+}
+
+/// Script paths stay inside the package folder
+fn valid_script_path(path: &str) -> bool {
+    let path = Path::new(path);
+    path.is_relative()
+        && path
+            .components()
+            .all(|c| matches!(c, std::path::Component::Normal(_)))
+        && path.extension().is_some_and(|ext| ext == SCRIPT_EXTENSION)
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(
+    dir: &Path,
+    folder: &str,
+    file: &str,
+    errors: &mut Vec<ModError>,
+) -> Option<T> {
+    let text = match std::fs::read_to_string(dir.join(file)) {
+        Ok(text) => text,
+        Err(e) => {
+            errors.push(ModError::new(folder, file, format!("cannot read: {}", e)));
+            return None;
+        }
+    };
+    parse_json(&text, folder, file, errors)
+}
+
+fn parse_json<T: for<'de> Deserialize<'de>>(
+    text: &str,
+    folder: &str,
+    file: &str,
+    errors: &mut Vec<ModError>,
+) -> Option<T> {
+    match serde_json::from_str(text) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            errors.push(ModError::new(folder, file, e.to_string()));
+            None
+        }
+    }
+}
+
+/// Load one package folder. Returns the package only if it has no errors.
+pub fn load_package(dir: &Path) -> Result<ModPackage, Vec<ModError>> {
+    let folder = dir
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut errors = Vec::new();
+
+    let manifest: Option<ManifestFile> = read_json(dir, &folder, MANIFEST_FILE, &mut errors);
+    let ships: Option<HashMap<String, ShipPoolFile>> =
+        read_json(dir, &folder, SHIPS_FILE, &mut errors);
+    let missions: Option<Vec<MissionFile>> = read_json(dir, &folder, MISSIONS_FILE, &mut errors);
+    let dialogue: Option<DialogueFile> = if dir.join(DIALOGUE_FILE).exists() {
+        read_json(dir, &folder, DIALOGUE_FILE, &mut errors)
+    } else {
+        Some(DialogueFile::default())
+    };
+
+    // Script sources by path; bad paths are reported by `build_package`
+    let mut scripts = HashMap::new();
+    for path in missions
+        .iter()
+        .flatten()
+        .filter_map(|m| m.script.as_deref())
+    {
+        if valid_script_path(path) {
+            match std::fs::read_to_string(dir.join(path)) {
+                Ok(source) => {
+                    scripts.insert(path.to_string(), source);
+                }
+                Err(e) => {
+                    errors.push(ModError::new(&folder, path, format!("cannot read: {}", e)));
+                }
+            }
+        }
+    }
+
+    match (manifest, ships, missions, dialogue) {
+        (Some(manifest), Some(ships), Some(missions), Some(dialogue)) if errors.is_empty() => {
+            build_package(&folder, manifest, ships, missions, dialogue, &scripts)
+        }
+        _ => Err(errors),
+    }
+}
+
+/// Validate parsed files and assemble the package
+pub fn build_package(
+    folder: &str,
+    manifest: ManifestFile,
+    ships: HashMap<String, ShipPoolFile>,
+    missions: Vec<MissionFile>,
+    dialogue: DialogueFile,
+    script_sources: &HashMap<String, String>,
+) -> Result<ModPackage, Vec<ModError>> {
+    let mut errors = Vec::new();
+    let mut manifest_error =
+        |message: String| errors.push(ModError::new(folder, MANIFEST_FILE, message));
+
+    // --- Manifest ---
+    if !valid_id(&manifest.id) {
+        manifest_error(format!(
+            "id '{}' must be lowercase letters, digits, '_' or '-'",
+            manifest.id
+        ));
+    }
+    if manifest.display_name.trim().is_empty() {
+        manifest_error("display_name is empty".into());
+    }
+    if manifest.factions.is_empty() {
+        manifest_error("at least one faction is required".into());
+    }
+
+    let faction_ids: Vec<&str> = manifest.factions.iter().map(|f| f.id.as_str()).collect();
+    let mut seen = HashSet::new();
+    let mut rivals = HashMap::new();
+    let mut factions = Vec::new();
+    for (i, faction) in manifest.factions.iter().enumerate() {
+        let at = format!("factions[{}]", i);
+        if !valid_id(&faction.id) {
+            manifest_error(format!("{}.id '{}' is not a valid id", at, faction.id));
+        } else if !seen.insert(faction.id.as_str()) {
+            manifest_error(format!("{}.id '{}' is used twice", at, faction.id));
+        }
+
+        let mut color = |field: &str, hex: &str| {
+            parse_color(hex).unwrap_or_else(|| {
+                manifest_error(format!("{}.{} '{}' is not a hex color", at, field, hex));
+                Color::WHITE
+            })
+        };
+        let primary_color = color("primary_color", &faction.primary_color);
+        let secondary_color = color("secondary_color", &faction.secondary_color);
+        let accent_color = color("accent_color", &faction.accent_color);
+
+        let rival = match &faction.rival {
+            Some(rival) if !faction_ids.contains(&rival.as_str()) => {
+                manifest_error(format!("{}.rival '{}' is not a faction", at, rival));
+                None
+            }
+            Some(rival) => Some(rival.clone()),
+            None => faction_ids
+                .iter()
+                .cycle()
+                .skip(i + 1)
+                .take(faction_ids.len() - 1)
+                .find(|id| **id != faction.id)
+                .map(|id| id.to_string()),
+        };
+        match rival {
+            Some(rival) => {
+                rivals.insert(faction.id.clone(), rival);
+            }
+            None if faction_ids.len() == 1 => {
+                manifest_error(format!(
+                    "{} has no rival; add a second faction to fight",
+                    at
+                ));
+            }
+            None => {}
+        }
+
+        factions.push(FactionInfo {
+            id: leak(faction.id.clone()),
+            name: leak(faction.name.clone()),
+            primary_color,
+            secondary_color,
+            accent_color,
+            doctrine: faction.doctrine.iter().cloned().map(leak).collect(),
+            description: leak(faction.description.clone()),
+        });
+    }
+
+    // --- Ships ---
+    let mut ship_error = |message: String| errors.push(ModError::new(folder, SHIPS_FILE, message));
+    for key in ships.keys() {
+        if !faction_ids.contains(&key.as_str()) {
+            ship_error(format!("pool '{}' does not match a faction", key));
+        }
+    }
+
+    let mut pools = HashMap::new();
+    let mut enemy_tuning = HashMap::new();
+    for faction in &faction_ids {
+        let pool = ships.get(*faction).cloned().unwrap_or_default();
+        if pool.player_ships.is_empty() {
+            ship_error(format!("'{}' has no player_ships", faction));
+        }
+
+        let mut player_ships = Vec::new();
+        for (i, ship) in pool.player_ships.iter().enumerate() {
+            let at = format!("{}.player_ships[{}]", faction, i);
+            if ship.type_id == 0 {
+                ship_error(format!("{}.type_id must be an EVE type id", at));
+            }
+            for (field, value) in [
+                ("health", ship.health),
+                ("speed", ship.speed),
+                ("fire_rate", ship.fire_rate),
+                ("damage", ship.damage),
+            ] {
+                if !(value.is_finite() && value > 0.0) {
+                    ship_error(format!("{}.{} must be positive", at, field));
+                }
+            }
+            let mut requirements = Vec::new();
+            if let Some(mission) = ship.unlock_mission {
+                if mission == 0 || mission as usize > missions.len() {
+                    ship_error(format!(
+                        "{}.unlock_mission {} is not a mission (1-{})",
+                        at,
+                        mission,
+                        missions.len()
+                    ));
+                }
+                // Same keys the package campaign saves its progress under
+                requirements.push(UnlockRequirement::Stage {
+                    faction: format!("{}/{}", manifest.id, faction),
+                    enemy: rivals.get(*faction).cloned().unwrap_or_default(),
+                    stage: mission,
+                });
+            }
+            if let Some(id) = &ship.unlock_achievement {
+                if id.trim().is_empty() {
+                    ship_error(format!("{}.unlock_achievement is empty", at));
+                }
+                requirements.push(UnlockRequirement::Achievement(id.clone()));
+            }
+            if let Some(score) = ship.unlock_score {
+                requirements.push(UnlockRequirement::Score(score));
+            }
+            if let Some(cost) = ship.unlock_cost {
+                if cost == 0 {
+                    ship_error(format!("{}.unlock_cost must be positive", at));
+                }
+                requirements.push(UnlockRequirement::SkillPoints(cost));
+            }
+            player_ships.push(ModuleShip {
+                type_id: ship.type_id,
+                name: leak(ship.name.clone()),
+                class: leak(ship.class.clone()),
+                role: leak(ship.role.clone()),
+                health: ship.health,
+                speed: ship.speed,
+                fire_rate: ship.fire_rate,
+                damage: ship.damage,
+                unlimited_thrust: ship.unlimited_thrust,
+                requirements,
+            });
+        }
+
+        let is_enemy = rivals.values().any(|r| r == faction);
+        if is_enemy && pool.enemy_ships.iter().all(|s| s.spawn_weight == 0) {
+            ship_error(format!(
+                "'{}' is fought as a rival but has no enemy_ships with spawn_weight",
+                faction
+            ));
+        }
+
+        let mut enemy_ships = Vec::new();
+        for (i, ship) in pool.enemy_ships.iter().enumerate() {
+            let at = format!("{}.enemy_ships[{}]", faction, i);
+            if ship.type_id == 0 {
+                ship_error(format!("{}.type_id must be an EVE type id", at));
+            }
+            if ship.health.is_some_and(|h| !(h.is_finite() && h > 0.0)) {
+                ship_error(format!("{}.health must be positive", at));
+            }
+            if ship.speed.is_some_and(|s| !(s.is_finite() && s > 0.0)) {
+                ship_error(format!("{}.speed must be positive", at));
+            }
+            enemy_tuning.insert(
+                ship.type_id,
+                EnemyTuning {
+                    health: ship.health,
+                    speed: ship.speed,
+                    score: ship.score,
+                    behavior: ship
+                        .behavior
+                        .unwrap_or_else(|| WaveBehavior::for_class(&ship.class)),
+                },
+            );
+            enemy_ships.push(EnemyShipDef {
+                type_id: ship.type_id,
+                name: leak(ship.name.clone()),
+                class: leak(ship.class.clone()),
+                spawn_weight: ship.spawn_weight,
+            });
+        }
+
+        pools.insert(
+            faction.to_string(),
+            FactionShipPool {
+                player_ships,
+                enemy_ships,
+            },
+        );
+    }
+
+    // --- Missions ---
+    let mut mission_error =
+        |message: String| errors.push(ModError::new(folder, MISSIONS_FILE, message));
+    if missions.is_empty() {
+        mission_error("at least one mission is required".into());
+    }
+    for (i, mission) in missions.iter().enumerate() {
+        let at = format!("[{}] '{}'", i, mission.name);
+        if mission.waves.is_empty() && mission.script.is_none() {
+            mission_error(format!("{} has no waves", at));
+        }
+        if let Some(path) = &mission.script {
+            if !valid_script_path(path) {
+                mission_error(format!(
+                    "{}.script '{}' must be a .{} file inside the package",
+                    at, path, SCRIPT_EXTENSION
+                ));
+            }
+        }
+        for (w, wave) in mission.waves.iter().enumerate() {
+            if wave.count == 0 || wave.count > MAX_WAVE_SIZE {
+                mission_error(format!(
+                    "{}.waves[{}].count must be 1-{}",
+                    at, w, MAX_WAVE_SIZE
+                ));
+            }
+            if wave.ships.contains(&0) {
+                mission_error(format!("{}.waves[{}].ships contains type id 0", at, w));
+            }
+        }
+        for (o, objective) in mission.objectives.iter().enumerate() {
+            if let Err(e) = objective.objective() {
+                mission_error(format!("{}.objectives[{}]: {}", at, o, e));
+            }
+            if objective.kind == ObjectiveKindFile::Scripted && mission.script.is_none() {
+                mission_error(format!(
+                    "{}.objectives[{}] is scripted but the mission has no script",
+                    at, o
+                ));
+            }
+        }
+        let boss = &mission.boss;
+        if boss.type_id == 0 {
+            mission_error(format!("{}.boss.type_id must be an EVE type id", at));
+        }
+        if !(boss.health.is_finite() && boss.health > 0.0) {
+            mission_error(format!("{}.boss.health must be positive", at));
+        }
+        if boss.phases == 0 || boss.phases > MAX_BOSS_PHASES {
+            mission_error(format!("{}.boss.phases must be 1-{}", at, MAX_BOSS_PHASES));
+        }
+        if !(boss.scale.is_finite() && boss.scale > 0.0) {
+            mission_error(format!("{}.boss.scale must be positive", at));
+        }
+    }
+
+    // --- Scripts ---
+    let mut scripts = Vec::with_capacity(missions.len());
+    for mission in &missions {
+        let compiled = mission
+            .script
+            .as_ref()
+            .filter(|path| valid_script_path(path))
+            .and_then(|path| match script_sources.get(path) {
+                Some(source) => compile_script(source)
+                    .map_err(|e| errors.push(ModError::new(folder, path, e)))
+                    .ok(),
+                None => {
+                    errors.push(ModError::new(folder, path, "script not found"));
+                    None
+                }
+            });
+        scripts.push(compiled);
+    }
+
+    // --- Objectives ---
+    let objectives = missions
+        .iter()
+        .map(|mission| {
+            let mut list: Vec<Objective> = mission
+                .objectives
+                .iter()
+                .filter_map(|o| o.objective().ok())
+                .collect();
+            // Waves-then-boss missions always end on the boss
+            if mission.script.is_none() && list.iter().all(|o| o.optional) {
+                list.insert(0, Objective::primary(ObjectiveKind::DestroyBoss));
+            }
+            list
+        })
+        .collect();
+
+    // --- Dialogue ---
+    if dialogue.briefings.len() > missions.len() {
+        errors.push(ModError::new(
+            folder,
+            DIALOGUE_FILE,
+            format!(
+                "{} briefings for {} missions",
+                dialogue.briefings.len(),
+                missions.len()
+            ),
+        ));
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(ModPackage {
+        folder: folder.to_string(),
+        info: GameModuleInfo {
+            id: leak(manifest.id),
+            display_name: leak(manifest.display_name),
+            subtitle: leak(manifest.subtitle),
+            description: leak(manifest.description),
+            factions,
+        },
+        rivals,
+        pools,
+        enemy_tuning,
+        missions,
+        scripts,
+        objectives,
+        speaker: leak(if dialogue.speaker.trim().is_empty() {
+            "Fleet Command".to_string()
+        } else {
+            dialogue.speaker.clone()
+        }),
+        dialogue,
+    })
+}
+
+/// Load every package folder under `root`. Folders without a manifest are
+/// skipped; ids already taken by `reserved` or an earlier package are errors.
+pub fn load_packages(root: &Path, reserved: &[&str]) -> (Vec<ModPackage>, Vec<ModError>) {
+    let mut packages: Vec<ModPackage> = Vec::new();
+    let mut errors = Vec::new();
+
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return (packages, errors);
+    };
+    let mut dirs: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_dir() && p.join(MANIFEST_FILE).exists())
+        .collect();
+    dirs.sort();
+
+    for dir in dirs {
+        match load_package(&dir) {
+            Ok(package) => {
+                let id = package.id();
+                if reserved.contains(&id) || packages.iter().any(|p| p.id() == id) {
+                    errors.push(ModError::new(
+                        &package.folder,
+                        MANIFEST_FILE,
+                        format!("id '{}' is already used by another campaign", id),
+                    ));
+                } else {
+                    packages.push(package);
+                }
+            }
+            Err(mut package_errors) => errors.append(&mut package_errors),
+        }
+    }
+
+    (packages, errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r##"{
+        "id": "sansha_incursion",
+        "display_name": "Sansha's Nation",
+        "factions": [
+            { "id": "edencom", "name": "EDENCOM", "primary_color": "#3399e6",
+              "secondary_color": "#e6e6f2", "accent_color": "#4dccff" },
+            { "id": "sansha", "name": "Sansha's Nation", "primary_color": "#2a8a3a",
+              "secondary_color": "#101010", "accent_color": "#66ff66",
+              "doctrine": ["True Slaves"] }
+        ]
+    }"##;
+
+    const SHIPS: &str = r#"{
+        "edencom": {
+            "player_ships": [ { "type_id": 603, "name": "Merlin", "class": "Frigate",
+                "health": 100, "speed": 300, "fire_rate": 8, "damage": 10 } ],
+            "enemy_ships": [ { "type_id": 602, "name": "Kestrel", "class": "Frigate" } ]
+        },
+        "sansha": {
+            "player_ships": [ { "type_id": 17922, "name": "Succubus", "class": "Frigate",
+                "health": 110, "speed": 320, "fire_rate": 7, "damage": 12, "unlock_mission": 1 } ],
+            "enemy_ships": [ { "type_id": 17924, "name": "Phantasm", "class": "Cruiser",
+                "health": 220, "behavior": "homing" } ]
+        }
+    }"#;
+
+    const MISSIONS: &str = r#"[
+        { "name": "Incursion", "waves": [ { "count": 5 }, { "count": 8, "ships": [17924] } ],
+          "boss": { "type_id": 17918, "name": "Revenant", "health": 2000 } }
+    ]"#;
+
+    fn parse<T: for<'de> Deserialize<'de>>(text: &str) -> T {
+        serde_json::from_str(text).unwrap()
+    }
+
+    fn build(manifest: &str, ships: &str, missions: &str) -> Result<ModPackage, Vec<ModError>> {
+        build_package(
+            "sansha",
+            parse(manifest),
+            parse(ships),
+            parse(missions),
+            DialogueFile::default(),
+            &HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn valid_package_builds() {
+        let package = build(MANIFEST, SHIPS, MISSIONS).unwrap();
+        assert_eq!(package.id(), "sansha_incursion");
+        assert_eq!(package.rival_of("edencom"), Some("sansha"));
+        assert_eq!(package.rival_of("sansha"), Some("edencom"));
+        assert_eq!(
+            package.pools["sansha"].player_ships[0].requirements,
+            vec![UnlockRequirement::Stage {
+                faction: "sansha_incursion/sansha".into(),
+                enemy: "edencom".into(),
+                stage: 1,
+            }]
+        );
+        assert_eq!(package.enemy_tuning[&17924].behavior, WaveBehavior::Homing);
+        assert_eq!(package.enemy_tuning[&602].behavior, WaveBehavior::Zigzag);
+        assert!(package.type_ids().contains(&17918));
+    }
+
+    #[test]
+    fn errors_name_the_file_and_field() {
+        let manifest = MANIFEST.replace("#3399e6", "blue");
+        let missions = MISSIONS.replace("\"count\": 5", "\"count\": 0");
+        let errors = build(&manifest, SHIPS, &missions).unwrap_err();
+        let lines: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("sansha/manifest.json: factions[0].primary_color")));
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("sansha/missions.json: [0] 'Incursion'.waves[0].count")));
+    }
+
+    #[test]
+    fn rival_without_enemy_pool_is_rejected() {
+        let ships = SHIPS.replace(
+            r#""enemy_ships": [ { "type_id": 602, "name": "Kestrel", "class": "Frigate" } ]"#,
+            r#""enemy_ships": []"#,
+        );
+        let errors = build(MANIFEST, &ships, MISSIONS).unwrap_err();
+        assert!(errors[0].message.contains("'edencom' is fought as a rival"));
+    }
+
+    #[test]
+    fn scripted_missions_compile_and_report_errors() {
+        let missions = r#"[
+            { "name": "Hold", "script": "scripts/hold.rhai",
+              "boss": { "type_id": 17918, "name": "Revenant", "health": 2000 } },
+            { "name": "Escape", "script": "../escape.rhai", "waves": [ { "count": 3 } ],
+              "boss": { "type_id": 17918, "name": "Revenant", "health": 2000 } }
+        ]"#;
+        let mut sources = HashMap::new();
+        sources.insert(
+            "scripts/hold.rhai".to_string(),
+            "fn on_mission_start() { spawn_wave( }".to_string(),
+        );
+        let errors = build_package(
+            "sansha",
+            parse(MANIFEST),
+            parse(SHIPS),
+            parse(missions),
+            DialogueFile::default(),
+            &sources,
+        )
+        .unwrap_err();
+        assert!(errors.iter().any(|e| e.file == "scripts/hold.rhai"));
+        assert!(errors
+            .iter()
+            .any(|e| e.message.contains("'../escape.rhai' must be a .rhai file")));
+
+        sources.insert(
+            "scripts/hold.rhai".to_string(),
+            "fn on_mission_start() { spawn_wave(); }".to_string(),
+        );
+        let package = build_package(
+            "sansha",
+            parse(MANIFEST),
+            parse(SHIPS),
+            parse(&missions.replace("../escape.rhai", "scripts/hold.rhai")),
+            DialogueFile::default(),
+            &sources,
+        )
+        .unwrap();
+        assert!(package.script(0).is_some() && package.script(1).is_some());
+    }
+
+    #[test]
+    fn mission_objectives_are_built_and_validated() {
+        let package = build(MANIFEST, SHIPS, MISSIONS).unwrap();
+        assert_eq!(package.objectives(0).len(), 1);
+        assert_eq!(package.objectives(0)[0].kind, ObjectiveKind::DestroyBoss);
+
+        let missions = MISSIONS.replace(
+            r#""boss":"#,
+            r#""objectives": [
+                { "kind": "defend", "type_id": 12235, "label": "Keep the beacon up" },
+                { "kind": "collect", "count": 5, "optional": true, "skill_points": 4 }
+              ],
+              "boss":"#,
+        );
+        let package = build(MANIFEST, SHIPS, &missions).unwrap();
+        let objectives = package.objectives(0);
+        assert_eq!(objectives.len(), 2);
+        assert_eq!(objectives[0].label, "Keep the beacon up");
+        assert!(!objectives[0].optional);
+        assert_eq!(objectives[1].skill_points, 4);
+        assert!(package.type_ids().contains(&12235));
+
+        let missions = MISSIONS.replace(
+            r#""boss":"#,
+            r#""objectives": [ { "kind": "survive" }, { "kind": "scripted" } ], "boss":"#,
+        );
+        let errors = build(MANIFEST, SHIPS, &missions).unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.message.contains("objectives[0]: seconds is required")));
+        assert!(errors
+            .iter()
+            .any(|e| e.message.contains("objectives[1] is scripted")));
+    }
+
+    #[test]
+    fn unknown_fields_are_reported() {
+        let mut errors = Vec::new();
+        let parsed: Option<ManifestFile> = parse_json(
+            r#"{ "id": "x", "display_name": "X", "factions": [], "faction": [] }"#,
+            "x",
+            MANIFEST_FILE,
+            &mut errors,
+        );
+        assert!(parsed.is_none());
+        assert!(errors[0].message.contains("unknown field `faction`"));
+    }
+
+    #[test]
+    fn bundled_example_package_is_valid() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/mods");
+        let (packages, errors) = load_packages(&dir, &["elder_fleet"]);
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert_eq!(packages.len(), 1);
+        assert_eq!(
+            packages[0].missions.len(),
+            packages[0].dialogue.briefings.len()
+        );
+    }
+
+    #[test]
+    fn broken_package_does_not_block_others() {
+        let root = std::env::temp_dir().join(format!("eve_mods_{}", std::process::id()));
+        let good = root.join("a_good");
+        let bad = root.join("b_bad");
+        std::fs::create_dir_all(&good).unwrap();
+        std::fs::create_dir_all(&bad).unwrap();
+        for (file, text) in [
+            (MANIFEST_FILE, MANIFEST),
+            (SHIPS_FILE, SHIPS),
+            (MISSIONS_FILE, MISSIONS),
+        ] {
+            std::fs::write(good.join(file), text).unwrap();
+        }
+        std::fs::write(bad.join(MANIFEST_FILE), "{ not json").unwrap();
+
+        let (packages, errors) = load_packages(&root, &["elder_fleet"]);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(packages.len(), 1);
+        assert!(errors
+            .iter()
+            .any(|e| e.package == "b_bad" && e.file == MANIFEST_FILE));
+        assert!(errors
+            .iter()
+            .any(|e| e.package == "b_bad" && e.file == SHIPS_FILE));
+    }
+}
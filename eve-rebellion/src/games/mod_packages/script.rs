@@ -0,0 +1,391 @@
+//! Mission Scripts
+//!
+//! A package mission can hand its flow to a Rhai script instead of the
+//! fixed "waves, then boss" order. The mission names the script in
+//! `missions.json` (`"script": "scripts/hold.rhai"`) and the script defines
+//! any of these hooks:
+//!
+//! - `on_mission_start()`
+//! - `on_wave_complete(wave)`
+//! - `on_enemy_destroyed(name)`
+//! - `on_boss_defeated()`
+//! - `on_timer(name)`
+//! - `on_objective_complete(index)` and `on_objective_failed(index)`
+//!
+//! Hooks act on the game through a small API: `spawn_wave()` (the next wave
+//! from `missions.json`), `spawn_ships(count)` (drawn from the enemy
+//! faction's pool), `spawn_ships(type_id, count)`, `spawn_boss()`,
+//! `say(text)`, `set_objective(text)`, `complete_objective(index)`,
+//! `fail_objective(index)`, `start_timer(name, seconds)`,
+//! `cancel_timer(name)` and `end_mission(success)`. They can read `wave()`,
+//! `waves_total()`, `enemies_alive()`, `kills()`, `elapsed()` and
+//! `player_health()`. Anything a script needs to remember between hooks
+//! goes on `this`, an object map that lives for the whole mission.
+//!
+//! Scripts are sandboxed: no file access or `import`, no `eval`, and hard
+//! limits on operations, call depth and collection sizes so a runaway loop
+//! cannot stall a frame.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use bevy::prelude::*;
+use rhai::{CallFnOptions, Dynamic, Engine, Map, Scope, AST};
+
+use super::MAX_WAVE_SIZE;
+
+/// Operations a single hook call may run before it is aborted
+pub const MAX_SCRIPT_OPERATIONS: u64 = 100_000;
+/// Game commands a single hook call may queue; extras are dropped
+pub const MAX_SCRIPT_COMMANDS: usize = 64;
+const MAX_SCRIPT_CALL_LEVELS: usize = 32;
+const MAX_SCRIPT_STRING: usize = 1024;
+const MAX_SCRIPT_COLLECTION: usize = 256;
+
+/// Something a script asked the game to do
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptCommand {
+    /// Spawn the mission's next wave from `missions.json`
+    SpawnWave,
+    /// Ad hoc wave; no type id draws from the enemy faction's pool
+    SpawnShips {
+        type_id: Option<u32>,
+        count: u32,
+    },
+    SpawnBoss,
+    Say(String),
+    SetObjective(String),
+    /// Settle one of the mission's objectives, by index in `missions.json`
+    CompleteObjective(usize),
+    FailObjective(usize),
+    EndMission {
+        success: bool,
+    },
+}
+
+/// Read-only game state scripts can query, refreshed every frame
+#[derive(Debug, Clone, Default)]
+pub struct ScriptView {
+    /// Waves spawned so far (from `missions.json` or `spawn_ships`)
+    pub wave: u32,
+    /// Waves listed for the mission in `missions.json`
+    pub waves_total: u32,
+    pub enemies_alive: u32,
+    pub kills: u32,
+    /// Player hull/armor/shield total as a fraction of max (0.0-1.0)
+    pub player_health: f32,
+}
+
+/// Shared between the engine's registered functions and the runtime
+#[derive(Default)]
+struct ScriptFrame {
+    view: ScriptView,
+    elapsed: f32,
+    commands: Vec<ScriptCommand>,
+    timers: Vec<(String, f32)>,
+}
+
+fn lock(frame: &Mutex<ScriptFrame>) -> MutexGuard<'_, ScriptFrame> {
+    // A panic inside a registered function cannot leave the frame half
+    // written, so a poisoned lock is still safe to use
+    frame
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn push(frame: &Mutex<ScriptFrame>, command: ScriptCommand) {
+    let mut frame = lock(frame);
+    if frame.commands.len() < MAX_SCRIPT_COMMANDS {
+        frame.commands.push(command);
+    }
+}
+
+/// Engine with the sandbox limits and no game API; used to compile
+fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine
+        .set_max_operations(MAX_SCRIPT_OPERATIONS)
+        .set_max_call_levels(MAX_SCRIPT_CALL_LEVELS)
+        .set_max_expr_depths(64, 32)
+        .set_max_string_size(MAX_SCRIPT_STRING)
+        .set_max_array_size(MAX_SCRIPT_COLLECTION)
+        .set_max_map_size(MAX_SCRIPT_COLLECTION)
+        .set_module_resolver(rhai::module_resolvers::DummyModuleResolver::new())
+        .disable_symbol("eval");
+    engine.on_print(|text| info!("[mission script] {}", text));
+    engine.on_debug(|text, _, pos| info!("[mission script] {} {}", pos, text));
+    engine
+}
+
+/// Compile a script, returning the parse error as text
+pub fn compile_script(source: &str) -> Result<AST, String> {
+    sandboxed_engine()
+        .compile(source)
+        .map_err(|err| err.to_string())
+}
+
+fn register_api(engine: &mut Engine, frame: &Arc<Mutex<ScriptFrame>>) {
+    // --- Actions ---
+    let f = frame.clone();
+    engine.register_fn("spawn_wave", move || push(&f, ScriptCommand::SpawnWave));
+    let f = frame.clone();
+    engine.register_fn("spawn_ships", move |count: i64| {
+        spawn_ships(&f, None, count)
+    });
+    let f = frame.clone();
+    engine.register_fn("spawn_ships", move |type_id: i64, count: i64| {
+        if let Ok(type_id) = u32::try_from(type_id) {
+            if type_id != 0 {
+                spawn_ships(&f, Some(type_id), count);
+            }
+        }
+    });
+    let f = frame.clone();
+    engine.register_fn("spawn_boss", move || push(&f, ScriptCommand::SpawnBoss));
+    let f = frame.clone();
+    engine.register_fn("say", move |text: &str| {
+        push(&f, ScriptCommand::Say(text.to_string()))
+    });
+    let f = frame.clone();
+    engine.register_fn("set_objective", move |text: &str| {
+        push(&f, ScriptCommand::SetObjective(text.to_string()))
+    });
+    let f = frame.clone();
+    engine.register_fn("complete_objective", move |index: i64| {
+        if let Ok(index) = usize::try_from(index) {
+            push(&f, ScriptCommand::CompleteObjective(index))
+        }
+    });
+    let f = frame.clone();
+    engine.register_fn("fail_objective", move |index: i64| {
+        if let Ok(index) = usize::try_from(index) {
+            push(&f, ScriptCommand::FailObjective(index))
+        }
+    });
+    let f = frame.clone();
+    engine.register_fn("end_mission", move |success: bool| {
+        push(&f, ScriptCommand::EndMission { success })
+    });
+
+    // --- Timers (whole or fractional seconds) ---
+    let f = frame.clone();
+    engine.register_fn("start_timer", move |name: &str, seconds: f64| {
+        start_timer(&f, name, seconds as f32)
+    });
+    let f = frame.clone();
+    engine.register_fn("start_timer", move |name: &str, seconds: i64| {
+        start_timer(&f, name, seconds as f32)
+    });
+    let f = frame.clone();
+    engine.register_fn("cancel_timer", move |name: &str| {
+        lock(&f).timers.retain(|(timer, _)| timer != name);
+    });
+
+    // --- Queries ---
+    let f = frame.clone();
+    engine.register_fn("wave", move || lock(&f).view.wave as i64);
+    let f = frame.clone();
+    engine.register_fn("waves_total", move || lock(&f).view.waves_total as i64);
+    let f = frame.clone();
+    engine.register_fn("enemies_alive", move || lock(&f).view.enemies_alive as i64);
+    let f = frame.clone();
+    engine.register_fn("kills", move || lock(&f).view.kills as i64);
+    let f = frame.clone();
+    engine.register_fn("elapsed", move || lock(&f).elapsed as f64);
+    let f = frame.clone();
+    engine.register_fn("player_health", move || lock(&f).view.player_health as f64);
+}
+
+fn spawn_ships(frame: &Mutex<ScriptFrame>, type_id: Option<u32>, count: i64) {
+    let count = count.clamp(0, MAX_WAVE_SIZE as i64) as u32;
+    if count > 0 {
+        push(frame, ScriptCommand::SpawnShips { type_id, count });
+    }
+}
+
+/// Starting a timer that already runs restarts it
+fn start_timer(frame: &Mutex<ScriptFrame>, name: &str, seconds: f32) {
+    let mut frame = lock(frame);
+    frame.timers.retain(|(timer, _)| timer != name);
+    if frame.timers.len() < MAX_SCRIPT_COLLECTION {
+        frame.timers.push((name.to_string(), seconds.max(0.0)));
+    }
+}
+
+/// A script running for one mission
+pub struct MissionScript {
+    engine: Engine,
+    ast: AST,
+    frame: Arc<Mutex<ScriptFrame>>,
+    /// `this` in every hook
+    state: Dynamic,
+}
+
+impl MissionScript {
+    pub fn new(ast: AST) -> Self {
+        let frame = Arc::new(Mutex::new(ScriptFrame::default()));
+        let mut engine = sandboxed_engine();
+        register_api(&mut engine, &frame);
+        Self {
+            engine,
+            ast,
+            frame,
+            state: Dynamic::from_map(Map::new()),
+        }
+    }
+
+    pub fn set_view(&mut self, view: ScriptView) {
+        lock(&self.frame).view = view;
+    }
+
+    /// Does the script define `hook` taking `arity` arguments?
+    pub fn has_hook(&self, hook: &str, arity: usize) -> bool {
+        self.ast
+            .iter_functions()
+            .any(|f| f.name == hook && f.params.len() == arity)
+    }
+
+    /// Run a hook if the script defines it. Script errors are logged and
+    /// otherwise ignored so a broken hook never takes the game down.
+    pub fn call(&mut self, hook: &str, args: Vec<Dynamic>) {
+        if !self.has_hook(hook, args.len()) {
+            return;
+        }
+        let options = CallFnOptions::new()
+            .eval_ast(false)
+            .bind_this_ptr(&mut self.state);
+        if let Err(err) = self.engine.call_fn_with_options::<Dynamic>(
+            options,
+            &mut Scope::new(),
+            &self.ast,
+            hook,
+            args,
+        ) {
+            warn!("Mission script error in {}: {}", hook, err);
+        }
+    }
+
+    /// Advance the mission clock and fire `on_timer` for expired timers
+    pub fn tick(&mut self, delta: f32) {
+        let expired: Vec<String> = {
+            let mut frame = lock(&self.frame);
+            frame.elapsed += delta;
+            for (_, remaining) in frame.timers.iter_mut() {
+                *remaining -= delta;
+            }
+            let (expired, running) = std::mem::take(&mut frame.timers)
+                .into_iter()
+                .partition(|(_, remaining)| *remaining <= 0.0);
+            frame.timers = running;
+            expired
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>()
+        };
+        for name in expired {
+            self.call("on_timer", vec![name.into()]);
+        }
+    }
+
+    /// Commands queued by hooks since the last call
+    pub fn take_commands(&mut self) -> Vec<ScriptCommand> {
+        std::mem::take(&mut lock(&self.frame).commands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(source: &str) -> MissionScript {
+        MissionScript::new(compile_script(source).unwrap())
+    }
+
+    #[test]
+    fn hooks_queue_commands_and_keep_state_on_this() {
+        let mut script = load(
+            r#"
+            fn on_mission_start() {
+                this.waves = 0;
+                set_objective("Survive");
+                spawn_wave();
+            }
+            fn on_wave_complete(wave) {
+                this.waves += 1;
+                if this.waves >= 2 { complete_objective(1); fail_objective(-1); end_mission(true); } else { spawn_ships(602, 3); spawn_ships(99); }
+            }
+            "#,
+        );
+        script.call("on_mission_start", vec![]);
+        assert_eq!(
+            script.take_commands(),
+            vec![
+                ScriptCommand::SetObjective("Survive".into()),
+                ScriptCommand::SpawnWave
+            ]
+        );
+
+        script.call("on_wave_complete", vec![Dynamic::from(1_i64)]);
+        assert_eq!(
+            script.take_commands(),
+            vec![
+                ScriptCommand::SpawnShips {
+                    type_id: Some(602),
+                    count: 3
+                },
+                ScriptCommand::SpawnShips {
+                    type_id: None,
+                    count: MAX_WAVE_SIZE
+                }
+            ]
+        );
+        script.call("on_wave_complete", vec![Dynamic::from(2_i64)]);
+        assert_eq!(
+            script.take_commands(),
+            vec![
+                ScriptCommand::CompleteObjective(1),
+                ScriptCommand::EndMission { success: true }
+            ]
+        );
+    }
+
+    #[test]
+    fn timers_fire_once_and_can_be_cancelled() {
+        let mut script = load(
+            r#"
+            fn on_mission_start() { start_timer("hold", 1.5); start_timer("nag", 1); }
+            fn on_timer(name) { if name == "hold" { say("Held at " + elapsed()); cancel_timer("nag"); } }
+            "#,
+        );
+        script.call("on_mission_start", vec![]);
+        script.tick(1.0);
+        // "nag" fired with no matching branch
+        assert!(script.take_commands().is_empty());
+        script.tick(1.0);
+        assert_eq!(
+            script.take_commands(),
+            vec![ScriptCommand::Say("Held at 2.0".into())]
+        );
+        script.tick(5.0);
+        assert!(script.take_commands().is_empty());
+    }
+
+    #[test]
+    fn runaway_and_unsafe_scripts_are_stopped() {
+        let mut script = load("fn on_mission_start() { loop { spawn_wave(); } }");
+        script.call("on_mission_start", vec![]);
+        assert_eq!(script.take_commands().len(), MAX_SCRIPT_COMMANDS);
+
+        assert!(compile_script(r#"fn f() { eval("1") }"#).is_err());
+        let mut script = load(r#"fn on_mission_start() { import "os" as os; say("x"); }"#);
+        script.call("on_mission_start", vec![]);
+        assert!(script.take_commands().is_empty());
+    }
+
+    #[test]
+    fn missing_hooks_and_wrong_arity_are_skipped() {
+        let mut script = load("fn on_wave_complete() { spawn_boss(); }");
+        script.call("on_mission_start", vec![]);
+        script.call("on_wave_complete", vec![Dynamic::from(1_i64)]);
+        assert!(script.take_commands().is_empty());
+    }
+}
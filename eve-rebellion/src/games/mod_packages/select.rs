@@ -0,0 +1,381 @@
+//! Mod Package Faction Select
+//!
+//! One screen for picking a package faction and ship. Left/right switches
+//! faction, up/down switches ship; the screen is rebuilt on every change.
+
+use bevy::prelude::*;
+
+use super::{progress_key, ModCampaignState, ModPackage, ModPackages};
+use crate::core::{AchievementRegistry, GameState, NarrationEvent, SaveData, ShipUnlockRegistry};
+use crate::games::ActiveModule;
+use crate::systems::JoystickState;
+
+/// Root of the package select screen
+#[derive(Component)]
+pub struct ModSelectRoot;
+
+/// Current faction and ship on the select screen
+#[derive(Resource, Default, Debug)]
+pub struct ModSelection {
+    pub faction: usize,
+    pub ship: usize,
+}
+
+pub fn spawn_mod_select(
+    mut commands: Commands,
+    packages: Res<ModPackages>,
+    active: Res<ActiveModule>,
+    mut selection: ResMut<ModSelection>,
+    save_data: Res<SaveData>,
+    unlocks: Res<ShipUnlockRegistry>,
+    achievements: Res<AchievementRegistry>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    let Some(package) = packages.active(&active) else {
+        return;
+    };
+    *selection = ModSelection::default();
+    let locks = ShipLocks {
+        unlocks: &unlocks,
+        achievements: &achievements,
+        save_data: &save_data,
+    };
+    build_mod_select(&mut commands, package, &selection, &locks);
+    narration.send(NarrationEvent::queued(selection_narration(
+        package, &selection,
+    )));
+}
+
+/// What the select screen needs to show lock states
+struct ShipLocks<'a> {
+    unlocks: &'a ShipUnlockRegistry,
+    achievements: &'a AchievementRegistry,
+    save_data: &'a SaveData,
+}
+
+impl ShipLocks<'_> {
+    /// What still locks a ship, `None` once it can be flown
+    fn hint(&self, type_id: u32) -> Option<String> {
+        self.unlocks
+            .hint(type_id, self.save_data, self.achievements)
+    }
+}
+
+fn build_mod_select(
+    commands: &mut Commands,
+    package: &ModPackage,
+    selection: &ModSelection,
+    locks: &ShipLocks,
+) {
+    let factions = &package.info.factions;
+    let selected = &factions[selection.faction];
+    let ships = package
+        .pools
+        .get(selected.id)
+        .map(|p| p.player_ships.as_slice())
+        .unwrap_or_default();
+
+    commands
+        .spawn((
+            ModSelectRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.95)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(package.info.display_name.to_uppercase()),
+                TextFont {
+                    font_size: 44.0,
+                    ..default()
+                },
+                TextColor(selected.accent_color),
+            ));
+            if !package.info.subtitle.is_empty() {
+                parent.spawn((
+                    Text::new(package.info.subtitle),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                ));
+            }
+
+            // Faction cards
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(30.0),
+                    margin: UiRect::vertical(Val::Px(16.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (i, faction) in factions.iter().enumerate() {
+                        let is_selected = i == selection.faction;
+                        let color = faction.primary_color;
+                        row.spawn((
+                            Node {
+                                width: Val::Px(240.0),
+                                flex_direction: FlexDirection::Column,
+                                align_items: AlignItems::Center,
+                                padding: UiRect::all(Val::Px(16.0)),
+                                border: UiRect::all(Val::Px(if is_selected { 3.0 } else { 1.0 })),
+                                row_gap: Val::Px(6.0),
+                                ..default()
+                            },
+                            BackgroundColor(color.with_alpha(if is_selected {
+                                0.35
+                            } else {
+                                0.12
+                            })),
+                            BorderColor(if is_selected {
+                                faction.accent_color
+                            } else {
+                                color.with_alpha(0.5)
+                            }),
+                        ))
+                        .with_children(|card| {
+                            card.spawn((
+                                Text::new(faction.name),
+                                TextFont {
+                                    font_size: 24.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                            ));
+                            for doctrine in &faction.doctrine {
+                                card.spawn((
+                                    Text::new(*doctrine),
+                                    TextFont {
+                                        font_size: 13.0,
+                                        ..default()
+                                    },
+                                    TextColor(faction.accent_color),
+                                ));
+                            }
+                            card.spawn((
+                                Text::new(faction.description),
+                                TextFont {
+                                    font_size: 13.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                            ));
+                        });
+                    }
+                });
+
+            // Ships for the selected faction
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|list| {
+                    for (i, ship) in ships.iter().enumerate() {
+                        let lock = locks.hint(ship.type_id);
+                        let unlocked = lock.is_none();
+                        let marker = if i == selection.ship { "> " } else { "  " };
+                        let mut label = format!("{}{} - {}", marker, ship.name, ship.class);
+                        if !ship.role.is_empty() {
+                            label.push_str(&format!(" ({})", ship.role));
+                        }
+                        if let Some(lock) = &lock {
+                            label.push_str(&format!("  [{}]", lock));
+                        }
+                        let color = match (i == selection.ship, unlocked) {
+                            (_, false) => Color::srgb(0.35, 0.35, 0.35),
+                            (true, true) => selected.accent_color,
+                            (false, true) => Color::srgb(0.75, 0.75, 0.75),
+                        };
+                        list.spawn((
+                            Text::new(label),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(color),
+                        ));
+                    }
+                });
+
+            parent.spawn((
+                Text::new("← → Faction • ↑ ↓ Ship • A/ENTER Launch • B/ESC Back"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+                Node {
+                    margin: UiRect::top(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// Spoken summary of the current selection
+fn selection_narration(package: &ModPackage, selection: &ModSelection) -> String {
+    let faction = &package.info.factions[selection.faction];
+    let ship = package
+        .pools
+        .get(faction.id)
+        .and_then(|p| p.player_ships.get(selection.ship));
+    match ship {
+        Some(ship) => format!("{}. {}, {}", faction.name, ship.name, ship.class),
+        None => faction.name.to_string(),
+    }
+}
+
+pub fn mod_select_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    packages: Res<ModPackages>,
+    mut active: ResMut<ActiveModule>,
+    mut selection: ResMut<ModSelection>,
+    mut campaign: ResMut<ModCampaignState>,
+    mut save_data: ResMut<SaveData>,
+    unlocks: Res<ShipUnlockRegistry>,
+    achievements: Res<AchievementRegistry>,
+    roots: Query<Entity, With<ModSelectRoot>>,
+    mut narration: EventWriter<NarrationEvent>,
+    mut transitions: EventWriter<crate::ui::TransitionEvent>,
+) {
+    let Some(package) = packages.active(&active) else {
+        return;
+    };
+    let factions = &package.info.factions;
+    let ship_count = |faction: usize| {
+        package
+            .pools
+            .get(factions[faction].id)
+            .map_or(0, |p| p.player_ships.len())
+    };
+
+    let horizontal = if keyboard.just_pressed(KeyCode::ArrowLeft)
+        || keyboard.just_pressed(KeyCode::KeyA)
+        || joystick.dpad_just_left()
+    {
+        -1
+    } else if keyboard.just_pressed(KeyCode::ArrowRight)
+        || keyboard.just_pressed(KeyCode::KeyD)
+        || joystick.dpad_just_right()
+    {
+        1
+    } else {
+        0
+    };
+    let vertical = if keyboard.just_pressed(KeyCode::ArrowUp)
+        || keyboard.just_pressed(KeyCode::KeyW)
+        || joystick.dpad_just_up()
+    {
+        -1
+    } else if keyboard.just_pressed(KeyCode::ArrowDown)
+        || keyboard.just_pressed(KeyCode::KeyS)
+        || joystick.dpad_just_down()
+    {
+        1
+    } else {
+        0
+    };
+
+    if horizontal != 0 || vertical != 0 {
+        if horizontal != 0 {
+            selection.faction =
+                (selection.faction as i32 + horizontal).rem_euclid(factions.len() as i32) as usize;
+            selection.ship = 0;
+        }
+        let ships = ship_count(selection.faction).max(1);
+        selection.ship = (selection.ship as i32 + vertical).rem_euclid(ships as i32) as usize;
+
+        for root in roots.iter() {
+            commands.entity(root).despawn_recursive();
+        }
+        let locks = ShipLocks {
+            unlocks: &unlocks,
+            achievements: &achievements,
+            save_data: &save_data,
+        };
+        build_mod_select(&mut commands, package, &selection, &locks);
+        narration.send(NarrationEvent::new(selection_narration(
+            package, &selection,
+        )));
+    }
+
+    if keyboard.just_pressed(KeyCode::Space)
+        || keyboard.just_pressed(KeyCode::Enter)
+        || joystick.confirm()
+    {
+        let faction = factions[selection.faction].id;
+        let Some(ship) = package
+            .pools
+            .get(faction)
+            .and_then(|p| p.player_ships.get(selection.ship))
+        else {
+            return;
+        };
+        if let Some(lock) = unlocks.hint(ship.type_id, &save_data, &achievements) {
+            // Ships for sale are bought on the first confirm and launched on the next
+            let message = match unlocks.purchase(ship.type_id, &mut save_data) {
+                Ok(()) => {
+                    for root in roots.iter() {
+                        commands.entity(root).despawn_recursive();
+                    }
+                    let locks = ShipLocks {
+                        unlocks: &unlocks,
+                        achievements: &achievements,
+                        save_data: &save_data,
+                    };
+                    build_mod_select(&mut commands, package, &selection, &locks);
+                    format!("{} bought.", ship.name)
+                }
+                Err(_) => format!("{} is locked. {}.", ship.name, lock),
+            };
+            narration.send(NarrationEvent::new(message));
+            return;
+        }
+
+        let enemy = package.rival_of(faction).unwrap_or_default().to_string();
+        // Resume at the first mission not yet cleared; replay from the
+        // start once the whole campaign is done
+        let cleared = save_data.get_highest_stage(&progress_key(package, faction), &enemy) as usize;
+        let start = if cleared >= package.missions.len() {
+            0
+        } else {
+            cleared
+        };
+
+        active.set_faction(faction, &enemy);
+        campaign.begin(ship.type_id, start);
+        info!(
+            "Package {}: {} vs {} in {}, mission {}",
+            package.id(),
+            faction,
+            enemy,
+            ship.name,
+            start + 1
+        );
+        transitions.send(crate::ui::TransitionEvent::slow(GameState::Playing));
+    }
+
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        transitions.send(crate::ui::TransitionEvent::to(GameState::ModuleSelect));
+    }
+}
+
+pub fn despawn_mod_select(mut commands: Commands, roots: Query<Entity, With<ModSelectRoot>>) {
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+}
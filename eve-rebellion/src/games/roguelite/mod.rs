@@ -0,0 +1,558 @@
+//! Roguelite Sector Runs
+//!
+//! One ship, one life, one seeded sector. The sector screen (in place of
+//! stage select) picks the next jump and runs shops and events; combat,
+//! elite and boss nodes are flown here with the core enemy, boss and
+//! collectible systems. Modules and faction ammo found on the way last for
+//! the run, salvage earned buys permanent unlocks for the next one.
+
+use bevy::prelude::*;
+
+use crate::assets::{PowerupIconCache, ShipModelCache, ShipSpriteCache};
+use crate::core::*;
+use crate::entities::{
+    spawn_boss, spawn_collectible, spawn_damavik, spawn_enemy, spawn_vedmak, triglavian, Boss,
+    Enemy, EnemyBehavior, EnemyStats, Movement, Player, ShipStats, Weapon,
+};
+use crate::games::{
+    module_active, sourced_replacements, ContentSource, CoreSystem, GameModule, GameModuleInfo,
+    ModuleHudSet,
+};
+use crate::ui::{HudObjective, TransitionEvent};
+
+pub mod sector;
+
+pub use sector::*;
+
+/// Module id
+pub const MODULE_ID: &str = "roguelite";
+
+/// Pause before the first wave of an encounter and between waves
+const WAVE_DELAY: f32 = 2.0;
+/// Pause after the last kill before jumping back to the sector screen
+const EXIT_DELAY: f32 = 3.0;
+/// Chance a destroyed ship drops a credit canister
+const CREDIT_DROP_CHANCE: f32 = 0.35;
+/// Run credits per canister picked up
+const CREDITS_PER_PICKUP: u32 = 15;
+
+/// Roguelite module - seeded sector runs with in-run upgrades
+pub struct RogueliteModule;
+
+impl GameModule for RogueliteModule {
+    fn id(&self) -> &'static str {
+        MODULE_ID
+    }
+
+    fn info(&self) -> GameModuleInfo {
+        GameModuleInfo {
+            id: MODULE_ID,
+            display_name: "ROGUELITE",
+            subtitle: "Sector Runs",
+            description: "Seeded sectors, loot and a boss. Salvage buys permanent unlocks.",
+            factions: vec![], // Core faction select; enemies vary per node
+        }
+    }
+
+    fn wave_source(&self) -> ContentSource {
+        ContentSource::Module
+    }
+
+    fn boss_source(&self) -> ContentSource {
+        ContentSource::Module
+    }
+
+    fn replaces(&self, _world: &World) -> Vec<CoreSystem> {
+        let mut replaced = sourced_replacements(self);
+        replaced.extend([
+            CoreSystem::Spawning,
+            CoreSystem::StageSelect,
+            CoreSystem::ResultScreens,
+        ]);
+        replaced
+    }
+
+    fn build(&self, app: &mut App) {
+        if let Some(mut cache) = app.world_mut().get_resource_mut::<ShipSpriteCache>() {
+            cache.request([triglavian::DAMAVIK, triglavian::VEDMAK]);
+        }
+
+        app.init_resource::<RogueliteRun>()
+            .init_resource::<RogueliteEncounter>()
+            .init_resource::<SectorScreen>()
+            .add_systems(
+                OnEnter(GameState::StageSelect),
+                spawn_sector_screen.run_if(module_active(MODULE_ID)),
+            )
+            .add_systems(
+                Update,
+                sector_screen_input
+                    .run_if(in_state(GameState::StageSelect))
+                    .run_if(module_active(MODULE_ID)),
+            )
+            .add_systems(OnExit(GameState::StageSelect), despawn_sector_screen)
+            .add_systems(
+                Update,
+                redirect_ship_select
+                    .run_if(in_state(GameState::ShipSelect))
+                    .run_if(module_active(MODULE_ID)),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_run_loadout,
+                    apply_faction_ammo,
+                    run_encounter,
+                    collect_run_loot,
+                    check_encounter_clear,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(module_active(MODULE_ID)),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                end_run_on_death.run_if(module_active(MODULE_ID)),
+            )
+            .add_systems(
+                Update,
+                roguelite_hud
+                    .in_set(ModuleHudSet)
+                    .run_if(module_active(MODULE_ID)),
+            );
+    }
+}
+
+/// Progress through the encounter being flown
+#[derive(Resource, Default, Debug)]
+pub struct RogueliteEncounter {
+    /// Sector node this state belongs to
+    pub node: Option<usize>,
+    /// Waves to clear before the node is done (boss nodes: before the boss)
+    pub waves: u32,
+    pub spawned: u32,
+    pub wave_timer: f32,
+    pub boss_spawned: bool,
+    pub boss_defeated: bool,
+    /// Counts down to the jump out once the node is cleared
+    pub exit_timer: Option<f32>,
+}
+
+impl RogueliteEncounter {
+    fn begin(&mut self, node: usize, kind: SectorNodeKind, depth: u32) {
+        *self = Self {
+            node: Some(node),
+            waves: match kind {
+                SectorNodeKind::Combat => 2 + depth / 3,
+                SectorNodeKind::Elite => 2,
+                _ => 1,
+            },
+            wave_timer: WAVE_DELAY,
+            ..default()
+        };
+    }
+}
+
+/// Weapon damage before the faction ammo bonus
+#[derive(Component)]
+pub struct RunLoadout {
+    pub base_damage: f32,
+}
+
+/// Run modules, bulkheads, carried damage and faction ammo on the freshly
+/// spawned player (on top of the save's own upgrades)
+fn apply_run_loadout(
+    mut commands: Commands,
+    run: Res<RogueliteRun>,
+    mut players: Query<(Entity, &mut ShipStats, &mut Movement, &mut Weapon), Added<Player>>,
+) {
+    if !run.active {
+        return;
+    }
+    let bonuses = run_bonuses(&run.upgrades);
+
+    for (entity, mut stats, mut movement, mut weapon) in players.iter_mut() {
+        stats.max_shield += bonuses.shield_bonus;
+        stats.max_armor += bonuses.armor_bonus;
+        stats.max_capacitor += bonuses.capacitor_bonus;
+        stats.shield_recharge *= bonuses.shield_regen_mult;
+        if run.has(RunUpgrade::ReinforcedBulkheads) {
+            stats.max_hull *= 1.5;
+        }
+        stats.shield = stats.max_shield;
+        stats.capacitor = stats.max_capacitor;
+        // Shields come back between jumps; armor and hull do not
+        stats.armor = stats.max_armor * run.structure;
+        stats.hull = (stats.max_hull * run.structure).max(1.0);
+
+        movement.max_speed *= bonuses.speed_mult;
+        movement.acceleration *= bonuses.speed_mult;
+        weapon.fire_rate *= bonuses.fire_rate_mult;
+        weapon.damage *= bonuses.damage_mult;
+
+        if let Some(ammo) = run.upgrades.iter().find_map(|u| match u {
+            RunUpgrade::Ammo(ammo) => Some(*ammo),
+            _ => None,
+        }) {
+            // Run ammo loads whether or not the pilot trained for it
+            if !weapon.loadable_ammo.contains(&ammo) {
+                weapon.loadable_ammo.push(ammo);
+            }
+            weapon.ammo_type = ammo;
+        }
+
+        commands.entity(entity).insert(RunLoadout {
+            base_damage: weapon.damage,
+        });
+    }
+}
+
+/// Faction ammo hits harder while the matching charges are loaded
+fn apply_faction_ammo(
+    run: Res<RogueliteRun>,
+    mut players: Query<(&RunLoadout, &mut Weapon), With<Player>>,
+) {
+    for (loadout, mut weapon) in players.iter_mut() {
+        let bonus = if run.has_ammo(weapon.ammo_type) {
+            FACTION_AMMO_BONUS
+        } else {
+            1.0
+        };
+        weapon.damage = loadout.base_damage * bonus;
+    }
+}
+
+/// Spawn the current node's waves, then its boss
+fn run_encounter(
+    mut commands: Commands,
+    time: Res<Time>,
+    run: Res<RogueliteRun>,
+    session: Res<GameSession>,
+    mut encounter: ResMut<RogueliteEncounter>,
+    sprite_cache: Res<ShipSpriteCache>,
+    model_cache: Res<ShipModelCache>,
+    enemies: Query<(), With<Enemy>>,
+    bosses: Query<(), With<Boss>>,
+    mut wave_events: EventWriter<SpawnWaveEvent>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    let (Some(index), Some(node)) = (run.current, run.current_node()) else {
+        return;
+    };
+    if encounter.node != Some(index) {
+        encounter.begin(index, node.kind, node.depth);
+        narration.send(NarrationEvent::queued(format!(
+            "{} encounter, {} forces",
+            node.kind.name().to_lowercase(),
+            encounter_faction(node, &session).name()
+        )));
+    }
+
+    if encounter.exit_timer.is_some() || encounter.boss_spawned {
+        return;
+    }
+    if !enemies.is_empty() || !bosses.is_empty() {
+        return;
+    }
+
+    encounter.wave_timer -= time.delta_secs();
+    if encounter.wave_timer > 0.0 {
+        return;
+    }
+    encounter.wave_timer = WAVE_DELAY;
+
+    // Every wave of a node follows the seed
+    let mut rng = fastrand::Rng::with_seed(run.node_rng(index).u64(..) ^ encounter.spawned as u64);
+    let faction = encounter_faction(node, &session);
+
+    if encounter.spawned < encounter.waves {
+        let spawned = spawn_run_wave(
+            &mut commands,
+            &mut rng,
+            node,
+            faction,
+            encounter.spawned,
+            &sprite_cache,
+            &model_cache,
+        );
+        encounter.spawned += 1;
+        wave_events.send(SpawnWaveEvent {
+            wave_number: encounter.spawned,
+            enemy_count: spawned.len() as u32,
+            enemy_types: spawned,
+        });
+    } else if node.kind == SectorNodeKind::Boss {
+        // Any campaign boss of the sector's faction may hold the gate
+        let stage = rng.u32(5..=13);
+        if !spawn_boss(
+            &mut commands,
+            stage,
+            faction,
+            Some(&sprite_cache),
+            Some(&model_cache),
+        ) {
+            spawn_boss(
+                &mut commands,
+                1,
+                faction,
+                Some(&sprite_cache),
+                Some(&model_cache),
+            );
+        }
+        encounter.boss_spawned = true;
+    }
+}
+
+/// Who is fought at a node: never the player's own faction
+fn encounter_faction(node: &SectorNode, session: &GameSession) -> Faction {
+    if node.enemy_faction == session.player_faction {
+        session.enemy_faction
+    } else {
+        node.enemy_faction
+    }
+}
+
+/// Spawn one wave from the faction's ship table, returning the names spawned
+fn spawn_run_wave(
+    commands: &mut Commands,
+    rng: &mut fastrand::Rng,
+    node: &SectorNode,
+    faction: Faction,
+    wave: u32,
+    sprite_cache: &ShipSpriteCache,
+    model_cache: &ShipModelCache,
+) -> Vec<String> {
+    let ships = faction.enemy_ships();
+    let total_weight: u32 = ships.iter().map(|s| s.spawn_weight).sum();
+    if total_weight == 0 {
+        return Vec::new();
+    }
+
+    let elite = node.kind == SectorNodeKind::Elite;
+    let count = 4 + node.depth + wave + if elite { 2 } else { 0 };
+    // Later columns hit harder
+    let health_mult = 1.0 + node.depth as f32 * 0.1 + if elite { 0.5 } else { 0.0 };
+    let behaviors = [
+        EnemyBehavior::Linear,
+        EnemyBehavior::Zigzag,
+        EnemyBehavior::Weaver,
+        EnemyBehavior::Homing,
+        EnemyBehavior::Sniper,
+    ];
+
+    let spread = SCREEN_WIDTH * 0.8;
+    let spawn_y = SCREEN_HEIGHT / 2.0 + 50.0;
+    let mut names = Vec::new();
+
+    for i in 0..count {
+        let x = -spread / 2.0 + (i as f32 + 0.5) / count as f32 * spread + rng.f32() * 40.0 - 20.0;
+        let position = Vec2::new(x, spawn_y + rng.f32() * 100.0);
+
+        // Elite nodes mix in a Triglavian escort
+        if elite && i % 3 == 0 {
+            if rng.bool() {
+                spawn_vedmak(
+                    commands,
+                    position,
+                    sprite_cache.get(triglavian::VEDMAK),
+                    Some(model_cache),
+                );
+                names.push("Vedmak".to_string());
+            } else {
+                spawn_damavik(
+                    commands,
+                    position,
+                    sprite_cache.get(triglavian::DAMAVIK),
+                    Some(model_cache),
+                );
+                names.push("Damavik".to_string());
+            }
+            continue;
+        }
+
+        let roll = rng.u32(0..total_weight);
+        let mut cumulative = 0;
+        let mut ship = &ships[0];
+        for candidate in ships {
+            cumulative += candidate.spawn_weight;
+            if roll < cumulative {
+                ship = candidate;
+                break;
+            }
+        }
+
+        let entity = spawn_enemy(
+            commands,
+            ship.type_id,
+            position,
+            behaviors[rng.usize(..behaviors.len())],
+            sprite_cache.get(ship.type_id),
+            None,
+        );
+        commands
+            .entity(entity)
+            .queue(move |mut entity: EntityWorldMut| {
+                if let Some(mut stats) = entity.get_mut::<EnemyStats>() {
+                    stats.health *= health_mult;
+                    stats.max_health *= health_mult;
+                }
+            });
+        names.push(ship.name.to_string());
+    }
+
+    names
+}
+
+/// Kills drop credit canisters; picking them up pays run credits
+fn collect_run_loot(
+    mut commands: Commands,
+    mut run: ResMut<RogueliteRun>,
+    mut encounter: ResMut<RogueliteEncounter>,
+    icon_cache: Res<PowerupIconCache>,
+    mut destroyed: EventReader<EnemyDestroyedEvent>,
+    mut pickups: EventReader<CollectiblePickedUpEvent>,
+    mut module_boss_events: EventReader<crate::systems::boss::BossDefeatedEvent>,
+) {
+    for event in destroyed.read() {
+        run.kills += 1;
+        if !event.was_boss && fastrand::f32() < CREDIT_DROP_CHANCE {
+            spawn_collectible(
+                &mut commands,
+                event.position,
+                CollectibleType::Credits,
+                Some(&icon_cache),
+            );
+        }
+    }
+    for event in pickups.read() {
+        if event.collectible_type == CollectibleType::Credits {
+            run.add_credits(CREDITS_PER_PICKUP);
+        }
+    }
+    if module_boss_events.read().count() > 0 {
+        encounter.boss_defeated = true;
+    }
+}
+
+/// Settle the node once it is cleared: carry damage, drop loot, then jump
+/// back to the sector screen (or end the run after the boss)
+fn check_encounter_clear(
+    time: Res<Time>,
+    mut run: ResMut<RogueliteRun>,
+    mut encounter: ResMut<RogueliteEncounter>,
+    mut save_data: ResMut<SaveData>,
+    players: Query<&ShipStats, With<Player>>,
+    enemies: Query<(), With<Enemy>>,
+    bosses: Query<(), With<Boss>>,
+    mut narration: EventWriter<NarrationEvent>,
+    mut transitions: EventWriter<TransitionEvent>,
+) {
+    let Some(node) = run.current_node().cloned() else {
+        return;
+    };
+    if encounter.node != run.current {
+        return;
+    }
+
+    if let Some(timer) = encounter.exit_timer.as_mut() {
+        *timer -= time.delta_secs();
+        if *timer <= 0.0 {
+            encounter.exit_timer = None;
+            encounter.node = None;
+            run.clear_current();
+            if node.kind == SectorNodeKind::Boss {
+                let outcome = run.finish(true);
+                save_data.roguelite.record(&outcome);
+                info!(
+                    "Roguelite run {} won: +{} salvage",
+                    seed_code(outcome.seed),
+                    outcome.salvage
+                );
+            }
+            transitions.send(TransitionEvent::slow(GameState::StageSelect));
+        }
+        return;
+    }
+
+    let cleared = encounter.spawned >= encounter.waves
+        && enemies.is_empty()
+        && bosses.is_empty()
+        && (node.kind != SectorNodeKind::Boss || encounter.boss_defeated);
+    if !cleared {
+        return;
+    }
+    encounter.exit_timer = Some(EXIT_DELAY);
+
+    // Damage taken carries into the next fight
+    if let Ok(stats) = players.get_single() {
+        let max = stats.max_armor + stats.max_hull;
+        if max > 0.0 {
+            run.structure = ((stats.armor + stats.hull) / max).clamp(0.05, 1.0);
+        }
+    }
+
+    let mut lines = vec![format!("{} cleared", node.kind.name().to_lowercase())];
+    if node.kind == SectorNodeKind::Elite {
+        let drops = if save_data
+            .roguelite
+            .unlocks
+            .contains(&MetaUnlock::EliteSalvage)
+        {
+            2
+        } else {
+            1
+        };
+        let mut rng = run.node_rng(run.current.unwrap_or_default());
+        for upgrade in draw_run_upgrades(&mut rng, drops, &run.upgrades) {
+            run.add_upgrade(upgrade);
+            lines.push(format!("Recovered {}", upgrade.name()));
+        }
+    }
+    info!("Roguelite: {}", lines.join(", "));
+    narration.send(NarrationEvent::queued(lines.join(". ")));
+}
+
+/// Death ends the run; salvage is banked before the death screen
+fn end_run_on_death(
+    mut run: ResMut<RogueliteRun>,
+    mut encounter: ResMut<RogueliteEncounter>,
+    mut save_data: ResMut<SaveData>,
+) {
+    if !run.active {
+        return;
+    }
+    let outcome = run.finish(false);
+    *encounter = RogueliteEncounter::default();
+    save_data.roguelite.record(&outcome);
+    info!(
+        "Roguelite run {} lost at depth {}: +{} salvage",
+        seed_code(outcome.seed),
+        outcome.depth,
+        outcome.salvage
+    );
+}
+
+/// Ship select is only for the first jump of a run; retrying from the
+/// death screen goes back to the hangar
+fn redirect_ship_select(run: Res<RogueliteRun>, mut next_state: ResMut<NextState<GameState>>) {
+    if !run.active {
+        next_state.set(GameState::StageSelect);
+    }
+}
+
+fn roguelite_hud(run: Res<RogueliteRun>, mut objective: ResMut<HudObjective>) {
+    let Some(node) = run.current_node() else {
+        return;
+    };
+    let text = format!(
+        "SECTOR {} • {} {}/{} • {} CR",
+        seed_code(run.seed()),
+        node.kind.name(),
+        node.depth + 1,
+        SECTOR_DEPTH,
+        run.credits
+    );
+    if objective.0.as_deref() != Some(text.as_str()) {
+        objective.0 = Some(text);
+    }
+}
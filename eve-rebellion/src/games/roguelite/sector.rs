@@ -0,0 +1,684 @@
+//! Roguelite Sector Screen
+//!
+//! Stands in for stage select while the roguelite module is active. With no
+//! run going it is the hangar: banked salvage, permanent unlocks and the
+//! launch of a new run from a random, typed or `EVE_REBELLION_SEED` seed.
+//! During a run it shows the sector, picks the next jump and plays shops
+//! and events out in place. The screen is rebuilt on every change.
+
+use bevy::prelude::*;
+
+use super::RogueliteEncounter;
+use crate::core::*;
+use crate::systems::JoystickState;
+use crate::ui::TransitionEvent;
+
+/// Environment variable that fixes the seed of launched runs
+pub const SEED_ENV: &str = "EVE_REBELLION_SEED";
+/// Price of a shop repair
+const REPAIR_PRICE: u32 = 40;
+/// Share of armor and hull a shop repair restores
+const REPAIR_AMOUNT: f32 = 0.3;
+
+/// Root of the sector screen
+#[derive(Component)]
+pub struct SectorScreenRoot;
+
+/// What the sector screen is showing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SectorView {
+    /// Between runs: unlocks and launch
+    #[default]
+    Hangar,
+    Map,
+    Shop,
+    Event,
+}
+
+/// Sector screen cursor and seed entry
+#[derive(Resource, Default, Debug)]
+pub struct SectorScreen {
+    pub view: SectorView,
+    pub cursor: usize,
+    /// Hex digits typed in the hangar
+    pub seed_entry: String,
+}
+
+impl SectorScreen {
+    fn show(&mut self, view: SectorView) {
+        self.view = view;
+        self.cursor = 0;
+    }
+}
+
+/// Seed for a new run: typed code, then the environment, then random
+pub fn launch_seed(entry: &str) -> u32 {
+    parse_seed_code(entry)
+        .or_else(|| {
+            std::env::var(SEED_ENV)
+                .ok()
+                .and_then(|code| parse_seed_code(&code))
+        })
+        .unwrap_or_else(|| fastrand::u32(..))
+}
+
+/// Upgrades a shop node stocks
+fn shop_stock(run: &RogueliteRun, node: usize, save_data: &SaveData) -> Vec<RunUpgrade> {
+    let count = if save_data
+        .roguelite
+        .unlocks
+        .contains(&MetaUnlock::BrokerContacts)
+    {
+        SHOP_OFFERS + 1
+    } else {
+        SHOP_OFFERS
+    };
+    run.shop_offers(node, count)
+}
+
+/// Entries the cursor moves over in the current view
+fn entry_count(screen: &SectorScreen, run: &RogueliteRun, save_data: &SaveData) -> usize {
+    match (screen.view, run.current) {
+        (SectorView::Hangar, _) => 1 + MetaUnlock::all().len(),
+        (SectorView::Map, _) => run.choices().len(),
+        // Stock, repair, leave
+        (SectorView::Shop, Some(node)) => shop_stock(run, node, save_data).len() + 2,
+        (SectorView::Event, _) => 2,
+        _ => 0,
+    }
+}
+
+pub fn spawn_sector_screen(
+    mut commands: Commands,
+    mut screen: ResMut<SectorScreen>,
+    run: Res<RogueliteRun>,
+    save_data: Res<SaveData>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    screen.show(if run.active {
+        SectorView::Map
+    } else {
+        SectorView::Hangar
+    });
+    build_sector_screen(&mut commands, &screen, &run, &save_data);
+    narration.send(NarrationEvent::queued(screen_narration(
+        &screen, &run, &save_data,
+    )));
+}
+
+fn build_sector_screen(
+    commands: &mut Commands,
+    screen: &SectorScreen,
+    run: &RogueliteRun,
+    save_data: &SaveData,
+) {
+    commands
+        .spawn((
+            SectorScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(14.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.95)),
+        ))
+        .with_children(|parent| match screen.view {
+            SectorView::Hangar => build_hangar(parent, screen, run, save_data),
+            SectorView::Map => build_map(parent, screen, run),
+            SectorView::Shop => build_shop(parent, screen, run, save_data),
+            SectorView::Event => build_event(parent, screen, run),
+        });
+}
+
+fn spawn_line(parent: &mut ChildBuilder, text: impl Into<String>, size: f32, color: Color) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: size,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+/// One selectable row
+fn spawn_entry(parent: &mut ChildBuilder, text: String, selected: bool, enabled: bool) {
+    let color = match (selected, enabled) {
+        (true, true) => Color::srgb(1.0, 0.8, 0.3),
+        (true, false) => Color::srgb(0.6, 0.5, 0.3),
+        (false, true) => Color::srgb(0.8, 0.8, 0.8),
+        (false, false) => Color::srgb(0.4, 0.4, 0.4),
+    };
+    let marker = if selected { "> " } else { "  " };
+    spawn_line(parent, format!("{}{}", marker, text), 20.0, color);
+}
+
+fn spawn_hint(parent: &mut ChildBuilder, text: &str) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.4, 0.4, 0.4)),
+        Node {
+            margin: UiRect::top(Val::Px(20.0)),
+            ..default()
+        },
+    ));
+}
+
+/// Run status shared by the map, shop and event views
+fn spawn_run_status(parent: &mut ChildBuilder, run: &RogueliteRun) {
+    spawn_line(
+        parent,
+        format!("SECTOR {}", seed_code(run.seed())),
+        40.0,
+        Color::srgb(0.9, 0.6, 0.3),
+    );
+    spawn_line(
+        parent,
+        format!(
+            "Depth {}/{} • {} credits • Armor & hull {:.0}%",
+            run.depth(),
+            SECTOR_DEPTH,
+            run.credits,
+            run.structure * 100.0
+        ),
+        18.0,
+        Color::srgb(0.7, 0.7, 0.7),
+    );
+    let fitted = if run.upgrades.is_empty() {
+        "Nothing fitted yet".to_string()
+    } else {
+        run.upgrades
+            .iter()
+            .map(|u| u.name())
+            .collect::<Vec<_>>()
+            .join(" • ")
+    };
+    spawn_line(parent, fitted, 14.0, Color::srgb(0.5, 0.7, 0.9));
+}
+
+fn build_hangar(
+    parent: &mut ChildBuilder,
+    screen: &SectorScreen,
+    run: &RogueliteRun,
+    save_data: &SaveData,
+) {
+    let progress = &save_data.roguelite;
+
+    spawn_line(parent, "ROGUELITE HANGAR", 44.0, Color::srgb(0.9, 0.6, 0.3));
+    spawn_line(
+        parent,
+        format!(
+            "{} salvage • {} runs • {} won • best depth {}",
+            progress.salvage, progress.runs, progress.wins, progress.best_depth
+        ),
+        18.0,
+        Color::srgb(0.7, 0.7, 0.7),
+    );
+    if let Some(outcome) = run.outcome {
+        let (verdict, color) = if outcome.won {
+            ("BOSS DESTROYED", Color::srgb(0.5, 0.9, 0.5))
+        } else {
+            ("SHIP LOST", Color::srgb(0.9, 0.4, 0.3))
+        };
+        spawn_line(
+            parent,
+            format!(
+                "Last run {}: {} at depth {} • +{} salvage",
+                seed_code(outcome.seed),
+                verdict,
+                outcome.depth,
+                outcome.salvage
+            ),
+            16.0,
+            color,
+        );
+    }
+
+    let seed = if screen.seed_entry.is_empty() {
+        "random".to_string()
+    } else {
+        screen.seed_entry.clone()
+    };
+    spawn_entry(
+        parent,
+        format!("LAUNCH RUN (seed: {})", seed),
+        screen.cursor == 0,
+        true,
+    );
+
+    spawn_line(
+        parent,
+        "PERMANENT UNLOCKS",
+        16.0,
+        Color::srgb(0.6, 0.6, 0.6),
+    );
+    for (i, unlock) in MetaUnlock::all().iter().enumerate() {
+        let owned = progress.unlocks.contains(unlock);
+        let status = if owned {
+            "OWNED".to_string()
+        } else {
+            format!("{} salvage", unlock.cost())
+        };
+        spawn_entry(
+            parent,
+            format!("{} - {} [{}]", unlock.name(), unlock.description(), status),
+            screen.cursor == i + 1,
+            !owned && progress.salvage >= unlock.cost(),
+        );
+    }
+
+    spawn_hint(
+        parent,
+        "↑ ↓ Select • 0-9 A-F Type seed • BACKSPACE Clear • A/ENTER Confirm • B/ESC Back",
+    );
+}
+
+fn build_map(parent: &mut ChildBuilder, screen: &SectorScreen, run: &RogueliteRun) {
+    spawn_run_status(parent, run);
+
+    let choices = run.choices();
+    let selected = choices.get(screen.cursor).copied();
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(18.0),
+            margin: UiRect::vertical(Val::Px(16.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            for depth in 0..SECTOR_DEPTH {
+                row.spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                })
+                .with_children(|column| {
+                    for index in run.map.column(depth) {
+                        let node = &run.map.nodes[index];
+                        let (background, border) = if Some(index) == selected {
+                            (
+                                Color::srgba(1.0, 0.7, 0.2, 0.45),
+                                Color::srgb(1.0, 0.8, 0.3),
+                            )
+                        } else if choices.contains(&index) {
+                            (
+                                Color::srgba(1.0, 0.7, 0.2, 0.15),
+                                Color::srgb(0.8, 0.6, 0.3),
+                            )
+                        } else if run.position == Some(index) {
+                            (Color::srgba(0.3, 0.8, 1.0, 0.3), Color::srgb(0.3, 0.8, 1.0))
+                        } else if run.cleared.contains(&index) {
+                            (Color::srgba(0.3, 0.7, 0.3, 0.2), Color::srgb(0.3, 0.5, 0.3))
+                        } else {
+                            (Color::srgba(0.2, 0.2, 0.2, 0.3), Color::srgb(0.3, 0.3, 0.3))
+                        };
+                        column
+                            .spawn((
+                                Node {
+                                    width: Val::Px(64.0),
+                                    height: Val::Px(48.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(background),
+                                BorderColor(border),
+                            ))
+                            .with_children(|card| {
+                                spawn_line(card, node.kind.symbol(), 22.0, Color::WHITE);
+                            });
+                    }
+                });
+            }
+        });
+
+    if let Some(node) = selected.map(|i| &run.map.nodes[i]) {
+        spawn_line(parent, node.kind.name(), 26.0, Color::srgb(1.0, 0.8, 0.3));
+        let detail = if node.kind.is_fight() {
+            format!(
+                "{} forces • {} onward jumps",
+                node.enemy_faction.name(),
+                node.next.len()
+            )
+        } else {
+            format!("{} onward jumps", node.next.len())
+        };
+        spawn_line(parent, detail, 16.0, Color::srgb(0.7, 0.7, 0.7));
+    }
+
+    spawn_hint(
+        parent,
+        "← → Choose jump • A/ENTER Jump • B/ESC Leave (run is kept)",
+    );
+}
+
+fn build_shop(
+    parent: &mut ChildBuilder,
+    screen: &SectorScreen,
+    run: &RogueliteRun,
+    save_data: &SaveData,
+) {
+    let Some(node) = run.current else {
+        return;
+    };
+    spawn_run_status(parent, run);
+    spawn_line(parent, "STATION MARKET", 28.0, Color::srgb(1.0, 0.8, 0.3));
+
+    let stock = shop_stock(run, node, save_data);
+    for (i, upgrade) in stock.iter().enumerate() {
+        let owned = run.has(*upgrade);
+        let price = if owned {
+            "OWNED".to_string()
+        } else {
+            format!("{} CR", upgrade.price())
+        };
+        spawn_entry(
+            parent,
+            format!("{} - {} [{}]", upgrade.name(), upgrade.description(), price),
+            screen.cursor == i,
+            !owned && run.credits >= upgrade.price(),
+        );
+    }
+    spawn_entry(
+        parent,
+        format!(
+            "Repair {:.0}% armor & hull [{} CR]",
+            REPAIR_AMOUNT * 100.0,
+            REPAIR_PRICE
+        ),
+        screen.cursor == stock.len(),
+        run.credits >= REPAIR_PRICE && run.structure < 1.0,
+    );
+    spawn_entry(
+        parent,
+        "Undock".to_string(),
+        screen.cursor == stock.len() + 1,
+        true,
+    );
+
+    spawn_hint(parent, "↑ ↓ Select • A/ENTER Buy • B/ESC Undock");
+}
+
+fn build_event(parent: &mut ChildBuilder, screen: &SectorScreen, run: &RogueliteRun) {
+    let Some(node) = run.current else {
+        return;
+    };
+    let event = run.event_at(node);
+    spawn_run_status(parent, run);
+    spawn_line(parent, event.title(), 28.0, Color::srgb(1.0, 0.8, 0.3));
+    parent.spawn((
+        Text::new(event.description()),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.7, 0.7, 0.7)),
+        Node {
+            max_width: Val::Px(640.0),
+            ..default()
+        },
+    ));
+    for (i, (label, _)) in event.options().iter().enumerate() {
+        spawn_entry(parent, label.to_string(), screen.cursor == i, true);
+    }
+
+    spawn_hint(parent, "↑ ↓ Select • A/ENTER Choose");
+}
+
+/// Spoken summary of what the cursor is on
+fn screen_narration(screen: &SectorScreen, run: &RogueliteRun, save_data: &SaveData) -> String {
+    match screen.view {
+        SectorView::Hangar => match screen.cursor {
+            0 => format!(
+                "Roguelite hangar. {} salvage. Launch run",
+                save_data.roguelite.salvage
+            ),
+            i => MetaUnlock::all()
+                .get(i - 1)
+                .map(|u| format!("{}, {} salvage. {}", u.name(), u.cost(), u.description()))
+                .unwrap_or_default(),
+        },
+        SectorView::Map => match run.choices().get(screen.cursor) {
+            Some(&node) => {
+                let node = &run.map.nodes[node];
+                format!(
+                    "Jump {} of {}: {}, depth {}",
+                    screen.cursor + 1,
+                    run.choices().len(),
+                    node.kind.name().to_lowercase(),
+                    node.depth + 1
+                )
+            }
+            None => String::new(),
+        },
+        SectorView::Shop => {
+            let stock = run
+                .current
+                .map(|node| shop_stock(run, node, save_data))
+                .unwrap_or_default();
+            match stock.get(screen.cursor) {
+                Some(upgrade) => format!("{}, {} credits", upgrade.name(), upgrade.price()),
+                None if screen.cursor == stock.len() => {
+                    format!("Repair, {} credits", REPAIR_PRICE)
+                }
+                None => "Undock".to_string(),
+            }
+        }
+        SectorView::Event => run
+            .current
+            .map(|node| {
+                run.event_at(node).options()[screen.cursor.min(1)]
+                    .0
+                    .to_string()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+/// Hex digit typed this frame, for seed entry
+fn typed_hex(keyboard: &ButtonInput<KeyCode>) -> Option<char> {
+    const KEYS: [(KeyCode, char); 16] = [
+        (KeyCode::Digit0, '0'),
+        (KeyCode::Digit1, '1'),
+        (KeyCode::Digit2, '2'),
+        (KeyCode::Digit3, '3'),
+        (KeyCode::Digit4, '4'),
+        (KeyCode::Digit5, '5'),
+        (KeyCode::Digit6, '6'),
+        (KeyCode::Digit7, '7'),
+        (KeyCode::Digit8, '8'),
+        (KeyCode::Digit9, '9'),
+        (KeyCode::KeyA, 'A'),
+        (KeyCode::KeyB, 'B'),
+        (KeyCode::KeyC, 'C'),
+        (KeyCode::KeyD, 'D'),
+        (KeyCode::KeyE, 'E'),
+        (KeyCode::KeyF, 'F'),
+    ];
+    KEYS.iter()
+        .find(|(key, _)| keyboard.just_pressed(*key))
+        .map(|&(_, c)| c)
+}
+
+pub fn sector_screen_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut screen: ResMut<SectorScreen>,
+    mut run: ResMut<RogueliteRun>,
+    mut encounter: ResMut<RogueliteEncounter>,
+    mut save_data: ResMut<SaveData>,
+    roots: Query<Entity, With<SectorScreenRoot>>,
+    mut narration: EventWriter<NarrationEvent>,
+    mut transitions: EventWriter<TransitionEvent>,
+) {
+    let mut changed = false;
+
+    // Arrows only: letters type the seed in the hangar
+    let nav = if keyboard.just_pressed(KeyCode::ArrowUp)
+        || keyboard.just_pressed(KeyCode::ArrowLeft)
+        || joystick.dpad_just_up()
+        || joystick.dpad_just_left()
+    {
+        -1
+    } else if keyboard.just_pressed(KeyCode::ArrowDown)
+        || keyboard.just_pressed(KeyCode::ArrowRight)
+        || joystick.dpad_just_down()
+        || joystick.dpad_just_right()
+    {
+        1
+    } else {
+        0
+    };
+    let count = entry_count(&screen, &run, &save_data);
+    if nav != 0 && count > 0 {
+        screen.cursor = (screen.cursor as i32 + nav).rem_euclid(count as i32) as usize;
+        changed = true;
+    }
+
+    if screen.view == SectorView::Hangar {
+        if let Some(digit) = typed_hex(&keyboard) {
+            if screen.seed_entry.len() < 8 {
+                screen.seed_entry.push(digit);
+                changed = true;
+            }
+        }
+        if keyboard.just_pressed(KeyCode::Backspace) {
+            screen.seed_entry.clear();
+            changed = true;
+        }
+    }
+
+    let confirm = keyboard.just_pressed(KeyCode::Space)
+        || keyboard.just_pressed(KeyCode::Enter)
+        || joystick.confirm();
+    let back = keyboard.just_pressed(KeyCode::Escape) || joystick.back();
+
+    if confirm {
+        changed = true;
+        match screen.view {
+            SectorView::Hangar => {
+                if screen.cursor == 0 {
+                    let seed = launch_seed(&screen.seed_entry);
+                    run.start(seed, &save_data.roguelite.unlocks);
+                    *encounter = RogueliteEncounter::default();
+                    screen.seed_entry.clear();
+                    screen.show(SectorView::Map);
+                    info!("Roguelite run launched on sector {}", seed_code(seed));
+                    narration.send(NarrationEvent::new(format!(
+                        "Sector {} launched",
+                        seed_code(seed)
+                    )));
+                } else if let Some(&unlock) = MetaUnlock::all().get(screen.cursor - 1) {
+                    let line = if save_data.roguelite.purchase(unlock) {
+                        format!("{} unlocked", unlock.name())
+                    } else {
+                        format!("Cannot buy {}", unlock.name())
+                    };
+                    narration.send(NarrationEvent::new(line));
+                }
+            }
+            SectorView::Map => {
+                let Some(&node) = run.choices().get(screen.cursor) else {
+                    return;
+                };
+                if run.enter(node) {
+                    match run.map.nodes[node].kind {
+                        SectorNodeKind::Shop => screen.show(SectorView::Shop),
+                        SectorNodeKind::Event => screen.show(SectorView::Event),
+                        _ if !run.ship_chosen => {
+                            // First fight of the run: pick a hull
+                            run.ship_chosen = true;
+                            transitions.send(TransitionEvent::to(GameState::ShipSelect));
+                            return;
+                        }
+                        _ => {
+                            transitions.send(TransitionEvent::slow(GameState::Playing));
+                            return;
+                        }
+                    }
+                }
+            }
+            SectorView::Shop => {
+                let Some(node) = run.current else {
+                    return;
+                };
+                let stock = shop_stock(&run, node, &save_data);
+                if let Some(&upgrade) = stock.get(screen.cursor) {
+                    let line = if run.buy(upgrade) {
+                        format!("Bought {}", upgrade.name())
+                    } else {
+                        format!("Cannot buy {}", upgrade.name())
+                    };
+                    narration.send(NarrationEvent::new(line));
+                } else if screen.cursor == stock.len() {
+                    if run.credits >= REPAIR_PRICE && run.structure < 1.0 {
+                        run.credits -= REPAIR_PRICE;
+                        run.repair(REPAIR_AMOUNT);
+                        narration.send(NarrationEvent::new("Repaired"));
+                    }
+                } else {
+                    run.clear_current();
+                    screen.show(SectorView::Map);
+                }
+            }
+            SectorView::Event => {
+                let Some(node) = run.current else {
+                    return;
+                };
+                let (label, outcome) = run.event_at(node).options()[screen.cursor.min(1)];
+                let found = run.apply_event(node, outcome);
+                run.clear_current();
+                screen.show(SectorView::Map);
+                let line = match found {
+                    Some(upgrade) => format!("{}. Found {}", label, upgrade.name()),
+                    None => label.to_string(),
+                };
+                narration.send(NarrationEvent::new(line));
+            }
+        }
+    } else if back {
+        match screen.view {
+            SectorView::Shop => {
+                run.clear_current();
+                screen.show(SectorView::Map);
+                changed = true;
+            }
+            // Events have to be answered
+            SectorView::Event => {}
+            SectorView::Hangar | SectorView::Map => {
+                transitions.send(TransitionEvent::to(GameState::ModuleSelect));
+                return;
+            }
+        }
+    }
+
+    if changed {
+        for root in roots.iter() {
+            commands.entity(root).despawn_recursive();
+        }
+        build_sector_screen(&mut commands, &screen, &run, &save_data);
+        if nav != 0 {
+            narration.send(NarrationEvent::new(screen_narration(
+                &screen, &run, &save_data,
+            )));
+        }
+    }
+}
+
+pub fn despawn_sector_screen(mut commands: Commands, roots: Query<Entity, With<SectorScreenRoot>>) {
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+}
@@ -1,13 +1,19 @@
 //! Triglavian Invasion Campaign
 //!
-//! 9-mission campaign across contested systems.
+//! 9-mission campaign across contested systems, played on a star map with
+//! a branch at Kuharah/Otela and one side mission per side.
 
 use super::ships::*;
 use crate::assets::ShipSpriteCache;
-use crate::core::{GameState, LAYER_ENEMIES};
+use crate::core::{
+    CampaignMap, GameState, MapBriefing, MapNode, MapNodeLaunched, MissionObjectives, NodeReward,
+    Objective, ObjectiveKind, SaveData, ScoreEvent, Skill, LAYER_ENEMIES,
+};
 use crate::entities::boss::{Boss, BossAttack, BossData, BossMovement, BossState, MovementPattern};
 use crate::entities::Hitbox;
 use crate::entities::{spawn_damavik, spawn_enemy, spawn_vedmak, EnemyBehavior};
+use crate::games::ActiveModule;
+use crate::systems::JoystickState;
 use bevy::prelude::*;
 
 /// Campaign state resource
@@ -38,11 +44,12 @@ impl TriglavianCampaignState {
         self.mission_complete = false;
         self.boss_spawned = false;
 
-        // Waves per mission (increases with difficulty)
+        // Waves per mission (increases with difficulty); side missions are short
         self.waves_in_mission = match mission {
             0..=2 => 3,
             3..=5 => 4,
-            _ => 5,
+            6..=8 => 5,
+            _ => 3,
         };
     }
 }
@@ -54,78 +61,190 @@ impl TriglavianCampaignState {
 /// Mission information
 #[derive(Clone, Debug)]
 pub struct MissionInfo {
+    /// Also the campaign map node id
+    pub id: &'static str,
     pub name: &'static str,
     pub system: &'static str,
     pub description: &'static str,
     pub boss_type_id: u32,
     pub boss_name: &'static str,
+    pub bonus_objective: Option<&'static str>,
+    /// How the bonus objective is tracked
+    pub bonus: Option<ObjectiveKind>,
+}
+
+/// Stargates and the Stellar Transmuter have no ship render; the defend
+/// objective draws them as a placeholder
+const STRUCTURE: u32 = 0;
+
+impl MissionInfo {
+    /// Destroying the boss is the primary objective; the bonus is optional
+    pub fn objectives(&self) -> Vec<Objective> {
+        let mut objectives = vec![Objective::primary(ObjectiveKind::DestroyBoss)
+            .with_label(format!("Destroy the {}", self.boss_name))];
+        if let (Some(kind), Some(label)) = (self.bonus, self.bonus_objective) {
+            objectives.push(Objective::optional(kind).with_label(label));
+        }
+        objectives
+    }
+}
+
+/// Missions of the side the player flies for
+fn missions_for(active: &ActiveModule) -> Vec<MissionInfo> {
+    if active.player_faction.as_deref().unwrap_or("edencom") == "edencom" {
+        edencom_missions()
+    } else {
+        triglavian_missions()
+    }
+}
+
+/// Star map of the side the player flies for
+pub fn campaign_map_for(active: &ActiveModule) -> &'static CampaignMap {
+    if active.player_faction.as_deref().unwrap_or("edencom") == "edencom" {
+        &EDENCOM_MAP
+    } else {
+        &TRIGLAVIAN_MAP
+    }
+}
+
+fn briefing(missions: Vec<MissionInfo>, id: &str) -> Option<MapBriefing> {
+    missions
+        .into_iter()
+        .find(|m| m.id == id)
+        .map(|mission| MapBriefing {
+            name: mission.name,
+            description: mission.description,
+            objectives: mission.objectives(),
+        })
+}
+
+fn edencom_briefing(id: &str) -> Option<MapBriefing> {
+    briefing(edencom_missions(), id)
+}
+
+fn triglavian_briefing(id: &str) -> Option<MapBriefing> {
+    briefing(triglavian_missions(), id)
 }
 
 /// Get mission info for EDENCOM campaign
 pub fn edencom_missions() -> Vec<MissionInfo> {
     vec![
         MissionInfo {
+            id: "ec_m1_first_contact",
             name: "First Contact",
             system: "Niarja",
             description: "Triglavian scouts have been spotted. Intercept and eliminate.",
             boss_type_id: triglavian::VEDMAK,
             boss_name: "Raznaborg Vedmak",
+            bonus_objective: Some("Destroy 10 Damaviks"),
+            bonus: Some(ObjectiveKind::DestroyTargets {
+                type_id: Some(triglavian::DAMAVIK),
+                count: 10,
+            }),
         },
         MissionInfo {
+            id: "ec_m2_stellar_transmuter",
             name: "Stellar Transmuter",
             system: "Raravoss",
             description: "Prevent the Triglavians from deploying their stellar harvester.",
             boss_type_id: triglavian::DREKAVAC,
             boss_name: "Perun Drekavac",
+            bonus_objective: Some("Complete within 3 minutes"),
+            bonus: Some(ObjectiveKind::TimeLimit { seconds: 180.0 }),
         },
         MissionInfo {
+            id: "ec_m3_liminality_rising",
             name: "Liminality Rising",
             system: "Sakenta",
             description: "The system is approaching Final Liminality. Hold the line.",
             boss_type_id: triglavian::LESHAK,
             boss_name: "Veles Leshak",
+            bonus_objective: Some("Hold the beacon for 30s"),
+            bonus: Some(ObjectiveKind::StayInArea {
+                center: Vec2::new(0.0, -150.0),
+                radius: 120.0,
+                seconds: 30.0,
+            }),
         },
         MissionInfo {
+            id: "ec_m4_pochvens_edge",
             name: "Pochven's Edge",
             system: "Vale",
             description: "Systems are falling to Pochven. We must not lose Vale.",
             boss_type_id: triglavian::IKITURSA,
             boss_name: "Svarog Ikitursa",
+            bonus_objective: Some("No damage taken in phase 1"),
+            bonus: Some(ObjectiveKind::NoDamageInPhase { phase: 1 }),
         },
         MissionInfo {
+            id: "ec_m5_proving_ground",
             name: "Proving Ground",
             system: "Kuharah",
             description: "The Collective seeks to prove their might. Show them ours.",
             boss_type_id: triglavian::DREKAVAC,
             boss_name: "Proving Drekavac",
+            bonus_objective: Some("Destroy all in one chain"),
+            bonus: Some(ObjectiveKind::SingleChain),
         },
         MissionInfo {
+            id: "ec_m6_gate_defense",
             name: "Gate Defense",
             system: "Otela",
             description: "Defend the stargate from Triglavian assault.",
             boss_type_id: triglavian::LESHAK,
             boss_name: "Siege Leshak",
+            bonus_objective: Some("Keep the stargate intact"),
+            bonus: Some(ObjectiveKind::Defend {
+                type_id: STRUCTURE,
+                health: 600.0,
+            }),
         },
         MissionInfo {
+            id: "ec_m7_world_ark",
             name: "World Ark Assault",
             system: "Kino",
             description: "A World Ark has been detected. This is our chance.",
             boss_type_id: triglavian::XORDAZH,
             boss_name: "Xordazh World Ark",
+            bonus_objective: Some("Destroy 8 Vedmaks"),
+            bonus: Some(ObjectiveKind::DestroyTargets {
+                type_id: Some(triglavian::VEDMAK),
+                count: 8,
+            }),
         },
         MissionInfo {
+            id: "ec_m8_zoryas_domain",
             name: "Zorya's Domain",
             system: "Pochven Gate",
             description: "Push into Pochven and strike at the heart of the invasion.",
             boss_type_id: triglavian::LESHAK,
             boss_name: "Zorya's Champion",
+            bonus_objective: Some("Complete within 4 minutes"),
+            bonus: Some(ObjectiveKind::TimeLimit { seconds: 240.0 }),
         },
         MissionInfo {
+            id: "ec_m9_final_proving",
             name: "The Final Proving",
             system: "Triglavian Stronghold",
             description: "End the invasion. Victory or death.",
             boss_type_id: triglavian::XORDAZH,
             boss_name: "Zorya Triglav",
+            bonus_objective: Some("No damage taken"),
+            bonus: Some(ObjectiveKind::NoDamage),
+        },
+        MissionInfo {
+            id: "ec_s1_ahtila_evacuation",
+            name: "Ahtila Evacuation",
+            system: "Ahtila",
+            description: "Civilians are fleeing the liminal system. Get the transports out.",
+            boss_type_id: triglavian::VEDMAK,
+            boss_name: "Pursuit Vedmak",
+            bonus_objective: Some("Protect the evacuation transports"),
+            bonus: Some(ObjectiveKind::Escort {
+                type_id: 648, // Badger
+                health: 300.0,
+                speed: 18.0,
+            }),
         },
     ]
 }
@@ -134,71 +253,323 @@ pub fn edencom_missions() -> Vec<MissionInfo> {
 pub fn triglavian_missions() -> Vec<MissionInfo> {
     vec![
         MissionInfo {
+            id: "tc_m1_glory",
             name: "Glory to the Collective",
             system: "Niarja",
             description: "Prove yourself worthy by destroying EDENCOM scouts.",
             boss_type_id: edencom::SKYBREAKER,
             boss_name: "EDENCOM Vanguard",
+            bonus_objective: Some("Destroy 10 Skybreakers"),
+            bonus: Some(ObjectiveKind::DestroyTargets {
+                type_id: Some(edencom::SKYBREAKER),
+                count: 10,
+            }),
         },
         MissionInfo {
+            id: "tc_m2_stellar_manipulation",
             name: "Stellar Manipulation",
             system: "Raravoss",
             description: "Protect the Stellar Transmuter from EDENCOM interference.",
             boss_type_id: edencom::THUNDERCHILD,
             boss_name: "EDENCOM Commander",
+            bonus_objective: Some("Keep the Stellar Transmuter intact"),
+            bonus: Some(ObjectiveKind::Defend {
+                type_id: STRUCTURE,
+                health: 500.0,
+            }),
         },
         MissionInfo {
+            id: "tc_m3_embrace_liminality",
             name: "Embrace Liminality",
             system: "Sakenta",
             description: "Push the system toward Final Liminality.",
             boss_type_id: edencom::STORMBRINGER,
             boss_name: "EDENCOM Battlegroup",
+            bonus_objective: Some("Hold the conduit for 30s"),
+            bonus: Some(ObjectiveKind::StayInArea {
+                center: Vec2::new(0.0, -150.0),
+                radius: 120.0,
+                seconds: 30.0,
+            }),
         },
         MissionInfo {
+            id: "tc_m4_claim_for_pochven",
             name: "Claim for Pochven",
             system: "Vale",
             description: "Vale will join Pochven. Eliminate all resistance.",
             boss_type_id: edencom::THUNDERCHILD,
             boss_name: "Imperial Thunderchild",
+            bonus_objective: Some("Destroy all in one chain"),
+            bonus: Some(ObjectiveKind::SingleChain),
         },
         MissionInfo {
+            id: "tc_m5_flow_of_vyraj",
             name: "The Flow of Vyraj",
             system: "Kuharah",
             description: "Demonstrate superiority in the Proving.",
             boss_type_id: edencom::STORMBRINGER,
             boss_name: "CONCORD Stormbringer",
+            bonus_objective: Some("No damage taken"),
+            bonus: Some(ObjectiveKind::NoDamage),
         },
         MissionInfo {
+            id: "tc_m6_gate_seizure",
             name: "Gate Seizure",
             system: "Otela",
             description: "Capture the stargate for Collective use.",
             boss_type_id: edencom::APOCALYPSE,
             boss_name: "Amarr Dreadnought",
+            bonus_objective: Some("Hold the gate for 40s"),
+            bonus: Some(ObjectiveKind::StayInArea {
+                center: Vec2::new(0.0, 100.0),
+                radius: 110.0,
+                seconds: 40.0,
+            }),
         },
         MissionInfo {
+            id: "tc_m7_empires_fall",
             name: "Empire's Fall",
             system: "Kino",
             description: "Break the empire fleet defending this system.",
             boss_type_id: edencom::RAVEN,
             boss_name: "Caldari Fleet Commander",
+            bonus_objective: Some("Complete within 4 minutes"),
+            bonus: Some(ObjectiveKind::TimeLimit { seconds: 240.0 }),
         },
         MissionInfo {
+            id: "tc_m8_weaving_pochven",
             name: "Weaving Pochven",
             system: "New Eden Core",
             description: "The wormhole network must grow. Destroy all obstacles.",
             boss_type_id: edencom::MEGATHRON,
             boss_name: "Gallente Titan Escort",
+            bonus_objective: Some("Destroy 40 enemies"),
+            bonus: Some(ObjectiveKind::DestroyTargets {
+                type_id: None,
+                count: 40,
+            }),
         },
         MissionInfo {
+            id: "tc_m9_totality",
             name: "Totality",
             system: "Jita",
             description: "All of New Eden shall join Pochven.",
             boss_type_id: edencom::STORMBRINGER,
             boss_name: "EDENCOM Supreme Commander",
+            bonus_objective: Some("No damage taken in phase 1"),
+            bonus: Some(ObjectiveKind::NoDamageInPhase { phase: 1 }),
+        },
+        MissionInfo {
+            id: "tc_s1_tunudan_harvest",
+            name: "Tunudan Harvest",
+            system: "Tunudan",
+            description: "Gather the system's stellar matter before EDENCOM can respond.",
+            boss_type_id: edencom::SKYBREAKER,
+            boss_name: "EDENCOM Picket",
+            bonus_objective: Some("Complete within 3 minutes"),
+            bonus: Some(ObjectiveKind::TimeLimit { seconds: 180.0 }),
         },
     ]
 }
 
+/// EDENCOM: hold Kuharah's Proving or the Otela gate on the way to the
+/// World Ark; the Ahtila evacuation trains shield handling on the side
+pub static EDENCOM_MAP: CampaignMap = CampaignMap {
+    finale: "ec_m9_final_proving",
+    briefing: edencom_briefing,
+    nodes: &[
+        MapNode {
+            mission: "ec_m1_first_contact",
+            system: "Niarja",
+            position: Vec2::new(-320.0, 0.0),
+            side: false,
+            requires: &[],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "ec_m2_stellar_transmuter",
+            system: "Raravoss",
+            position: Vec2::new(-240.0, 70.0),
+            side: false,
+            requires: &["ec_m1_first_contact"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "ec_m3_liminality_rising",
+            system: "Sakenta",
+            position: Vec2::new(-160.0, 0.0),
+            side: false,
+            requires: &["ec_m2_stellar_transmuter"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "ec_s1_ahtila_evacuation",
+            system: "Ahtila",
+            position: Vec2::new(-160.0, -110.0),
+            side: true,
+            requires: &["ec_m3_liminality_rising"],
+            closes: &[],
+            reward: Some(NodeReward::Skill(Skill::ShieldOperation)),
+        },
+        MapNode {
+            mission: "ec_m4_pochvens_edge",
+            system: "Vale",
+            position: Vec2::new(-80.0, 70.0),
+            side: false,
+            requires: &["ec_m3_liminality_rising"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "ec_m5_proving_ground",
+            system: "Kuharah",
+            position: Vec2::new(10.0, 140.0),
+            side: false,
+            requires: &["ec_m4_pochvens_edge"],
+            closes: &["ec_m6_gate_defense"],
+            reward: None,
+        },
+        MapNode {
+            mission: "ec_m6_gate_defense",
+            system: "Otela",
+            position: Vec2::new(10.0, 0.0),
+            side: false,
+            requires: &["ec_m4_pochvens_edge"],
+            closes: &["ec_m5_proving_ground"],
+            reward: None,
+        },
+        MapNode {
+            mission: "ec_m7_world_ark",
+            system: "Kino",
+            position: Vec2::new(100.0, 70.0),
+            side: false,
+            requires: &["ec_m5_proving_ground", "ec_m6_gate_defense"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "ec_m8_zoryas_domain",
+            system: "Pochven Gate",
+            position: Vec2::new(180.0, 0.0),
+            side: false,
+            requires: &["ec_m7_world_ark"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "ec_m9_final_proving",
+            system: "Triglavian Stronghold",
+            position: Vec2::new(270.0, 60.0),
+            side: false,
+            requires: &["ec_m8_zoryas_domain"],
+            closes: &[],
+            reward: None,
+        },
+    ],
+};
+
+/// Triglavian Collective: prove in Kuharah or seize the Otela gate on the
+/// way to Kino; the Tunudan harvest pays skill points on the side
+pub static TRIGLAVIAN_MAP: CampaignMap = CampaignMap {
+    finale: "tc_m9_totality",
+    briefing: triglavian_briefing,
+    nodes: &[
+        MapNode {
+            mission: "tc_m1_glory",
+            system: "Niarja",
+            position: Vec2::new(-320.0, 0.0),
+            side: false,
+            requires: &[],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "tc_m2_stellar_manipulation",
+            system: "Raravoss",
+            position: Vec2::new(-240.0, 70.0),
+            side: false,
+            requires: &["tc_m1_glory"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "tc_m3_embrace_liminality",
+            system: "Sakenta",
+            position: Vec2::new(-160.0, 0.0),
+            side: false,
+            requires: &["tc_m2_stellar_manipulation"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "tc_s1_tunudan_harvest",
+            system: "Tunudan",
+            position: Vec2::new(-160.0, -110.0),
+            side: true,
+            requires: &["tc_m3_embrace_liminality"],
+            closes: &[],
+            reward: Some(NodeReward::SkillPoints(10)),
+        },
+        MapNode {
+            mission: "tc_m4_claim_for_pochven",
+            system: "Vale",
+            position: Vec2::new(-80.0, 70.0),
+            side: false,
+            requires: &["tc_m3_embrace_liminality"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "tc_m5_flow_of_vyraj",
+            system: "Kuharah",
+            position: Vec2::new(10.0, 140.0),
+            side: false,
+            requires: &["tc_m4_claim_for_pochven"],
+            closes: &["tc_m6_gate_seizure"],
+            reward: None,
+        },
+        MapNode {
+            mission: "tc_m6_gate_seizure",
+            system: "Otela",
+            position: Vec2::new(10.0, 0.0),
+            side: false,
+            requires: &["tc_m4_claim_for_pochven"],
+            closes: &["tc_m5_flow_of_vyraj"],
+            reward: None,
+        },
+        MapNode {
+            mission: "tc_m7_empires_fall",
+            system: "Kino",
+            position: Vec2::new(100.0, 70.0),
+            side: false,
+            requires: &["tc_m5_flow_of_vyraj", "tc_m6_gate_seizure"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "tc_m8_weaving_pochven",
+            system: "New Eden Core",
+            position: Vec2::new(180.0, 0.0),
+            side: false,
+            requires: &["tc_m7_empires_fall"],
+            closes: &[],
+            reward: None,
+        },
+        MapNode {
+            mission: "tc_m9_totality",
+            system: "Jita",
+            position: Vec2::new(270.0, 60.0),
+            side: false,
+            requires: &["tc_m8_weaving_pochven"],
+            closes: &[],
+            reward: None,
+        },
+    ],
+};
+
 // =============================================================================
 // CAMPAIGN SYSTEMS
 // =============================================================================
@@ -206,19 +577,14 @@ pub fn triglavian_missions() -> Vec<MissionInfo> {
 /// Start a Triglavian campaign mission
 pub fn start_trig_mission(
     mut state: ResMut<TriglavianCampaignState>,
+    mut objectives: ResMut<MissionObjectives>,
     active: Res<crate::games::ActiveModule>,
 ) {
     let mission = state.current_mission;
     state.start_mission(mission);
 
-    let faction = active.player_faction.as_deref().unwrap_or("edencom");
-    let missions = if faction == "edencom" {
-        edencom_missions()
-    } else {
-        triglavian_missions()
-    };
-
-    if let Some(info) = missions.get(mission as usize) {
+    if let Some(info) = missions_for(&active).get(mission as usize) {
+        objectives.set(info.objectives());
         info!(
             "Starting mission {}: {} - {}",
             mission + 1,
@@ -517,19 +883,135 @@ pub fn update_trig_boss() {
 /// Check if boss is defeated
 pub fn check_trig_boss_defeated(
     mut state: ResMut<TriglavianCampaignState>,
+    mut save_data: ResMut<SaveData>,
+    active: Res<ActiveModule>,
     mut next_state: ResMut<NextState<GameState>>,
     bosses: Query<Entity, With<crate::entities::boss::Boss>>,
+    escorts: Query<(), With<crate::entities::Enemy>>,
+    mut objectives: ResMut<MissionObjectives>,
+    mut score_events: EventWriter<ScoreEvent>,
 ) {
     if bosses.is_empty() && state.boss_spawned {
         state.mission_complete = true;
-        state.current_mission += 1;
+
+        // Settle objectives and pay out their rewards
+        if objectives.rewards.is_none() {
+            objectives.on_boss_defeated(escorts.iter().count());
+            let rewards = objectives.finish(true);
+            score_events.send(ScoreEvent::objective(rewards.score));
+            save_data.add_skill_points(rewards.skill_points);
+        }
+
+        // Save progress; later hulls unlock from the story missions' stages
+        let map = campaign_map_for(&active);
+        let mut finished = false;
+        if let (Some(faction), Some(enemy)) = (&active.player_faction, &active.enemy_faction) {
+            if let Some(info) = missions_for(&active).get(state.current_mission as usize) {
+                map.clear_node(&mut save_data, faction, enemy, info.id);
+                if map.node(info.id).is_some_and(|node| !node.side) {
+                    let stage = state.current_mission + 1;
+                    save_data.complete_stage(faction, enemy, stage, stage);
+                }
+            }
+            finished = save_data
+                .faction_progress(faction, enemy)
+                .is_some_and(|p| map.is_complete(p));
+        }
 
         // Check for campaign complete
-        let total_missions = 9;
-        if state.current_mission >= total_missions {
+        if finished {
             next_state.set(GameState::Victory);
         } else {
             next_state.set(GameState::StageComplete);
         }
     }
 }
+
+/// Pick up the mission launched from the campaign map
+pub fn launch_trig_map_node(
+    mut launched: EventReader<MapNodeLaunched>,
+    mut state: ResMut<TriglavianCampaignState>,
+    active: Res<ActiveModule>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for event in launched.read() {
+        if let Some(index) = missions_for(&active)
+            .iter()
+            .position(|m| m.id == event.mission)
+        {
+            state.current_mission = index as u32;
+            next_state.set(GameState::Playing);
+        }
+    }
+}
+
+/// Back to the star map once the result has been seen
+pub fn trig_stage_complete_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard.just_pressed(KeyCode::Space)
+        || keyboard.just_pressed(KeyCode::Enter)
+        || joystick.confirm()
+    {
+        next_state.set(GameState::StageSelect);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FactionProgress, NodeStatus};
+
+    #[test]
+    fn map_nodes_resolve_to_missions() {
+        for (map, missions) in [
+            (&EDENCOM_MAP, edencom_missions()),
+            (&TRIGLAVIAN_MAP, triglavian_missions()),
+        ] {
+            assert_eq!(map.nodes.len(), missions.len());
+            for node in map.nodes {
+                assert!((map.briefing)(node.mission).is_some(), "{}", node.mission);
+                for id in node.requires.iter().chain(node.closes) {
+                    assert!(map.node(id).is_some(), "{}", id);
+                }
+            }
+            for mission in &missions {
+                assert!(map.node(mission.id).is_some(), "{}", mission.id);
+            }
+            assert_eq!(missions[8].id, map.finale);
+        }
+    }
+
+    #[test]
+    fn the_proving_closes_the_gate() {
+        let map = &EDENCOM_MAP;
+        let index = |id| map.nodes.iter().position(|n| n.mission == id).unwrap();
+        let mut progress = FactionProgress::default();
+        for mission in &edencom_missions()[..4] {
+            progress.completed_nodes.insert(mission.id.to_string());
+        }
+        let side = index("ec_s1_ahtila_evacuation");
+        assert_eq!(map.status(side, &progress), NodeStatus::Available);
+
+        progress.choices.insert("ec_m5_proving_ground".to_string());
+        let gate = index("ec_m6_gate_defense");
+        assert_eq!(map.status(gate, &progress), NodeStatus::Closed);
+
+        progress
+            .completed_nodes
+            .insert("ec_m5_proving_ground".to_string());
+        let ark = index("ec_m7_world_ark");
+        assert_eq!(map.status(ark, &progress), NodeStatus::Available);
+    }
+
+    #[test]
+    fn side_missions_are_short() {
+        let mut state = TriglavianCampaignState::default();
+        state.start_mission(8);
+        assert_eq!(state.waves_in_mission, 5);
+        state.start_mission(9);
+        assert_eq!(state.waves_in_mission, 3);
+    }
+}
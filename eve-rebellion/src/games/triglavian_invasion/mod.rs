@@ -3,8 +3,14 @@
 //! EDENCOM vs Triglavian Collective - defend New Eden or embrace Pochven.
 //! Set during the Triglavian invasion of YC122.
 
-use super::{ActiveModule, FactionInfo, GameModuleInfo, ModuleRegistry};
-use crate::core::GameState;
+use super::{
+    sourced_replacements, ActiveModule, ContentSource, CoreSystem, FactionInfo, GameModule,
+    GameModuleInfo,
+};
+use crate::core::{
+    AchievementDef, AchievementScope, AchievementSet, AchievementTier, CampaignMap, GameState,
+    ShipUnlock, STAT_KILLS,
+};
 use bevy::prelude::*;
 
 pub mod campaign;
@@ -13,14 +19,50 @@ pub mod ships;
 pub use campaign::*;
 pub use ships::*;
 
-/// Triglavian Invasion module plugin
-pub struct TriglavianInvasionPlugin;
+/// Module id
+pub const MODULE_ID: &str = "triglavian_invasion";
 
-impl Plugin for TriglavianInvasionPlugin {
-    fn build(&self, app: &mut App) {
-        // Register module
-        app.add_systems(Startup, register_module);
+/// Triglavian Invasion module - own campaign, bosses and faction select
+pub struct TriglavianInvasionModule;
+
+impl GameModule for TriglavianInvasionModule {
+    fn id(&self) -> &'static str {
+        MODULE_ID
+    }
+
+    fn info(&self) -> GameModuleInfo {
+        module_info()
+    }
+
+    fn achievements(&self) -> &'static [AchievementSet] {
+        ACHIEVEMENTS
+    }
+
+    fn campaign_map(&self, active: &ActiveModule) -> Option<&'static CampaignMap> {
+        Some(campaign_map_for(active))
+    }
+
+    fn ship_unlocks(&self) -> Vec<ShipUnlock> {
+        let mut ships = edencom_player_ships();
+        ships.extend(triglavian_player_ships());
+        ships
+    }
+
+    fn wave_source(&self) -> ContentSource {
+        ContentSource::Module
+    }
+
+    fn boss_source(&self) -> ContentSource {
+        ContentSource::Module
+    }
+
+    fn replaces(&self, _world: &World) -> Vec<CoreSystem> {
+        let mut replaced = sourced_replacements(self);
+        replaced.extend([CoreSystem::FactionSelect, CoreSystem::ResultScreens]);
+        replaced
+    }
 
+    fn build(&self, app: &mut App) {
         // Initialize resources
         app.init_resource::<TriglavianShips>();
         app.init_resource::<TriglavianCampaignState>();
@@ -43,6 +85,18 @@ impl Plugin for TriglavianInvasionPlugin {
 
         // Campaign systems
         app.add_systems(
+            Update,
+            launch_trig_map_node
+                .run_if(in_state(GameState::StageSelect))
+                .run_if(is_triglavian_invasion),
+        )
+        .add_systems(
+            Update,
+            trig_stage_complete_input
+                .run_if(in_state(GameState::StageComplete))
+                .run_if(is_triglavian_invasion),
+        )
+        .add_systems(
             OnEnter(GameState::Playing),
             start_trig_mission.run_if(is_triglavian_invasion),
         )
@@ -72,15 +126,72 @@ impl Plugin for TriglavianInvasionPlugin {
     }
 }
 
+/// EDENCOM and Triglavian campaign achievements
+static ACHIEVEMENTS: &[AchievementSet] = &[AchievementSet {
+    name: "Triglavian Invasion",
+    achievements: &[
+        AchievementDef::new(
+            "TrigEdencomDefender",
+            "Stellar Defender",
+            "Complete 3 missions for EDENCOM",
+            "missions_completed/edencom",
+            3,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .factions(&["edencom"]),
+        AchievementDef::new(
+            "TrigProving",
+            "Proven in the Flow",
+            "Complete 3 missions for the Triglavian Collective",
+            "missions_completed/triglavian",
+            3,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .factions(&["triglavian"]),
+        AchievementDef::new(
+            "TrigCullTheWeak",
+            "Cull the Weak",
+            "Destroy 150 enemies in a single Triglavian Invasion run",
+            STAT_KILLS,
+            150,
+            AchievementScope::Run,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Silver),
+        AchievementDef::new(
+            "TrigInvasionVeteran",
+            "Invasion Veteran",
+            "Complete 9 Triglavian Invasion missions",
+            "missions_completed/triglavian_invasion",
+            9,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Silver),
+        AchievementDef::new(
+            "TrigFlowOfVyraj",
+            "The Flow of Vyraj",
+            "Win the Triglavian Invasion campaign",
+            "victories/triglavian_invasion",
+            1,
+            AchievementScope::Lifetime,
+        )
+        .modules(&[MODULE_ID])
+        .tier(AchievementTier::Gold),
+    ],
+}];
+
 /// Check if Triglavian Invasion module is active
 fn is_triglavian_invasion(active: Res<ActiveModule>) -> bool {
-    active.module_id.as_deref() == Some("triglavian_invasion")
+    active.is(MODULE_ID)
 }
 
-/// Register the Triglavian Invasion module
-fn register_module(mut registry: ResMut<ModuleRegistry>) {
-    registry.register(GameModuleInfo {
-        id: "triglavian_invasion",
+/// Registry entry for the Triglavian Invasion module
+fn module_info() -> GameModuleInfo {
+    GameModuleInfo {
+        id: MODULE_ID,
         display_name: "Triglavian Invasion",
         subtitle: "YC122 - The Flow of Vyraj",
         description: "Defend New Eden from the Triglavian Collective, or embrace the Flow and fight for Pochven.",
@@ -104,7 +215,7 @@ fn register_module(mut registry: ResMut<ModuleRegistry>) {
                 description: "Ancient Jove descendants from Abyssal Deadspace, seeking to claim systems for Pochven.",
             },
         ],
-    });
+    }
 }
 
 // =============================================================================
@@ -287,13 +398,13 @@ fn faction_select_input(
     // EDENCOM (left)
     if keys.just_pressed(KeyCode::KeyA) || keys.just_pressed(KeyCode::ArrowLeft) {
         active.set_faction("edencom", "triglavian");
-        next_state.set(GameState::Playing);
+        next_state.set(GameState::StageSelect);
     }
 
     // Triglavian (right)
     if keys.just_pressed(KeyCode::KeyD) || keys.just_pressed(KeyCode::ArrowRight) {
         active.set_faction("triglavian", "edencom");
-        next_state.set(GameState::Playing);
+        next_state.set(GameState::StageSelect);
     }
 
     // Back to menu
@@ -2,6 +2,7 @@
 //!
 //! Ship type IDs from EVE Online for both factions.
 
+use crate::core::{ShipUnlock, UnlockRequirement};
 use bevy::prelude::*;
 
 /// Ship pool for Triglavian Invasion module
@@ -240,51 +241,44 @@ pub fn edencom_spawn_weights() -> Vec<(u32, u32)> {
 // =============================================================================
 
 /// Player ship progression for EDENCOM
-pub fn edencom_player_ships() -> Vec<PlayerShip> {
+pub fn edencom_player_ships() -> Vec<ShipUnlock> {
     vec![
-        PlayerShip {
-            type_id: edencom::SKYBREAKER,
-            name: "Skybreaker",
-            unlock_stage: 0, // Starting ship
-        },
-        PlayerShip {
-            type_id: edencom::THUNDERCHILD,
-            name: "Thunderchild",
-            unlock_stage: 3,
-        },
-        PlayerShip {
-            type_id: edencom::STORMBRINGER,
-            name: "Stormbringer",
-            unlock_stage: 6,
-        },
+        ShipUnlock::new(edencom::SKYBREAKER, "Skybreaker", Vec::new()), // Starting ship
+        ShipUnlock::new(
+            edencom::THUNDERCHILD,
+            "Thunderchild",
+            vec![cleared("edencom", "triglavian", 3)],
+        ),
+        ShipUnlock::new(
+            edencom::STORMBRINGER,
+            "Stormbringer",
+            vec![cleared("edencom", "triglavian", 6)],
+        ),
     ]
 }
 
 /// Player ship progression for Triglavian
-pub fn triglavian_player_ships() -> Vec<PlayerShip> {
+pub fn triglavian_player_ships() -> Vec<ShipUnlock> {
     vec![
-        PlayerShip {
-            type_id: triglavian::DAMAVIK,
-            name: "Damavik",
-            unlock_stage: 0, // Starting ship
-        },
-        PlayerShip {
-            type_id: triglavian::VEDMAK,
-            name: "Vedmak",
-            unlock_stage: 3,
-        },
-        PlayerShip {
-            type_id: triglavian::LESHAK,
-            name: "Leshak",
-            unlock_stage: 6,
-        },
+        ShipUnlock::new(triglavian::DAMAVIK, "Damavik", Vec::new()), // Starting ship
+        ShipUnlock::new(
+            triglavian::VEDMAK,
+            "Vedmak",
+            vec![cleared("triglavian", "edencom", 3)],
+        ),
+        ShipUnlock::new(
+            triglavian::LESHAK,
+            "Leshak",
+            vec![cleared("triglavian", "edencom", 6)],
+        ),
     ]
 }
 
-/// Player ship definition
-#[derive(Clone, Debug)]
-pub struct PlayerShip {
-    pub type_id: u32,
-    pub name: &'static str,
-    pub unlock_stage: u32,
+/// Missions cleared on one side of the invasion
+fn cleared(faction: &str, enemy: &str, stage: u32) -> UnlockRequirement {
+    UnlockRequirement::Stage {
+        faction: faction.to_string(),
+        enemy: enemy.to_string(),
+        stage,
+    }
 }
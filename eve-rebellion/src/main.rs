@@ -23,10 +23,10 @@ mod ui;
 
 use assets::AssetsPlugin;
 use core::{
-    AchievementPlugin, ActCompleteEvent, AudioSettings, BossSpawnEvent, CampaignState,
-    CurrentStage, Difficulty, EndlessMode, GameEventsPlugin, GameProgress, GameSession, GameState,
-    InputConfig, MissionCompleteEvent, MissionStartEvent, SaltMinerSystem, SavePlugin, ScoreSystem,
-    SelectedShip, ShipUnlocks, WaveCompleteEvent,
+    AccessibilityPlugin, AchievementPlugin, ActCompleteEvent, AudioSettings, BossSpawnEvent,
+    CampaignState, CurrentStage, Difficulty, EndlessMode, GameEventsPlugin, GameProgress, GameRng,
+    GameSession, GameState, InputConfig, MissionCompleteEvent, MissionStartEvent, SaltMinerSystem,
+    SavePlugin, ScoreSystem, SelectedShip, ShipUnlockPlugin, WaveCompleteEvent,
 };
 use entities::EntitiesPlugin;
 use games::GameModulesPlugin;
@@ -61,10 +61,10 @@ fn main() {
         .init_resource::<Difficulty>()
         .init_resource::<SelectedShip>()
         .init_resource::<CurrentStage>()
-        .init_resource::<ShipUnlocks>()
         .init_resource::<CampaignState>()
         .init_resource::<GameSession>()
         .init_resource::<EndlessMode>()
+        .init_resource::<GameRng>()
         // Campaign events
         .add_event::<MissionStartEvent>()
         .add_event::<MissionCompleteEvent>()
@@ -74,7 +74,9 @@ fn main() {
         // Game plugins
         .add_plugins((
             SavePlugin,
+            AccessibilityPlugin,
             AchievementPlugin,
+            ShipUnlockPlugin,
             AssetsPlugin,
             GameEventsPlugin,
             EntitiesPlugin,
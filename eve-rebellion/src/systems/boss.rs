@@ -13,7 +13,6 @@ use crate::entities::{
     get_phase_threshold, spawn_boss, Boss, BossAttack, BossData, BossMovement, BossState,
     MovementPattern,
 };
-use crate::systems::ComboHeatSystem;
 use bevy::prelude::*;
 
 /// Boss system plugin
@@ -612,6 +611,7 @@ fn boss_phase_check(
     mut encounter: ResMut<BossEncounter>,
     mut screen_shake: ResMut<ScreenShake>,
     mut explosion_events: EventWriter<ExplosionEvent>,
+    mut phase_events: EventWriter<BossPhaseChangeEvent>,
 ) {
     for (transform, mut data, mut attack, mut state, mut movement) in boss_query.iter_mut() {
         if *state != BossState::Battle {
@@ -673,6 +673,11 @@ fn boss_phase_check(
                 data.current_phase = next_phase;
                 *state = BossState::PhaseTransition;
                 encounter.phase_timer = 1.0;
+                phase_events.send(BossPhaseChangeEvent {
+                    boss_name: data.name.clone(),
+                    phase: next_phase,
+                    total_phases: data.total_phases,
+                });
 
                 // Update attack pattern based on phase
                 attack.pattern = get_phase_pattern(data.id, next_phase);
@@ -895,8 +900,7 @@ fn boss_damage(
         (Entity, &Transform, &ProjectileDamage),
         With<crate::entities::PlayerProjectile>,
     >,
-    mut score: ResMut<ScoreSystem>,
-    mut heat_system: ResMut<ComboHeatSystem>,
+    mut score_events: EventWriter<ScoreEvent>,
     mut encounter: ResMut<BossEncounter>,
     mut defeated_events: EventWriter<BossDefeatedEvent>,
     mut explosion_events: EventWriter<ExplosionEvent>,
@@ -926,15 +930,11 @@ fn boss_damage(
                     *state = BossState::Defeated;
                     encounter.defeat_timer = 3.0;
 
-                    // Add score
-                    let mult = heat_system.on_kill();
-                    let final_score = (data.score_value as f32 * mult) as u64;
-                    score.score += final_score;
-                    heat_system.souls_liberated += data.liberation_value;
+                    score_events.send(ScoreEvent::boss(data.score_value));
 
                     defeated_events.send(BossDefeatedEvent {
                         boss_name: data.name.clone(),
-                        score: final_score,
+                        score: data.score_value,
                         liberation_value: data.liberation_value,
                     });
 
@@ -946,8 +946,8 @@ fn boss_damage(
 
                     info!("BOSS DEFEATED: {}", data.name);
                     info!(
-                        "+{} score, +{} souls liberated",
-                        final_score, data.liberation_value
+                        "+{} base score, +{} souls liberated",
+                        data.score_value, data.liberation_value
                     );
 
                     // Massive screen shake
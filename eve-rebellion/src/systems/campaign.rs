@@ -5,9 +5,10 @@
 use crate::assets::{ShipModelCache, ShipSpriteCache};
 use crate::core::events::BossDefeatedEvent;
 use crate::core::*;
-use crate::entities::{spawn_boss, spawn_enemy, Boss, BossData, BossState, Enemy, EnemyBehavior};
-use crate::games::ActiveModule;
-use bevy::ecs::schedule::common_conditions::not;
+use crate::entities::{
+    spawn_boss, spawn_enemy, Boss, BossData, BossState, Enemy, EnemyBehavior, Formation,
+};
+use crate::games::{core_enabled, ActiveModule, CoreSystem, GameModules};
 use bevy::prelude::*;
 
 /// Campaign system plugin
@@ -15,13 +16,10 @@ pub struct CampaignPlugin;
 
 impl Plugin for CampaignPlugin {
     fn build(&self, app: &mut App) {
-        // These systems run only when NOT in Caldari/Gallente or Abyssal modules
-        // (those modules have their own campaign/spawning systems)
+        // Modules with their own wave or boss flow switch these off via `GameModule::replaces`
         app.add_systems(
             OnEnter(GameState::Playing),
-            start_mission
-                .run_if(not(is_cg_module))
-                .run_if(not(is_abyssal_module)),
+            start_mission.run_if(core_enabled(CoreSystem::Campaign)),
         )
         .add_systems(
             Update,
@@ -29,54 +27,44 @@ impl Plugin for CampaignPlugin {
                 update_mission_timer,
                 check_wave_complete,
                 spawn_next_wave,
-                update_boss_behavior,
-                check_boss_defeated,
                 check_mission_complete,
             )
                 .run_if(in_state(GameState::Playing))
-                .run_if(not(is_cg_module))
-                .run_if(not(is_abyssal_module)),
+                .run_if(core_enabled(CoreSystem::Campaign)),
+        )
+        .add_systems(
+            Update,
+            (update_boss_behavior, check_boss_defeated)
+                .run_if(in_state(GameState::Playing))
+                .run_if(core_enabled(CoreSystem::Bosses)),
         )
         .add_systems(
             OnEnter(GameState::BossIntro),
-            spawn_mission_boss
-                .run_if(not(is_cg_module))
-                .run_if(not(is_abyssal_module)),
+            spawn_mission_boss.run_if(core_enabled(CoreSystem::Bosses)),
         )
         .add_systems(
             Update,
             boss_intro_sequence
                 .run_if(in_state(GameState::BossIntro))
-                .run_if(not(is_cg_module))
-                .run_if(not(is_abyssal_module)),
+                .run_if(core_enabled(CoreSystem::Bosses)),
         )
         .add_systems(
             OnEnter(GameState::BossFight),
-            start_boss_fight
-                .run_if(not(is_cg_module))
-                .run_if(not(is_abyssal_module)),
+            start_boss_fight.run_if(core_enabled(CoreSystem::Bosses)),
         );
     }
 }
 
-/// Run condition: is Caldari/Gallente module active?
-fn is_cg_module(active_module: Res<ActiveModule>) -> bool {
-    active_module.is_caldari_gallente()
-}
-
-/// Run condition: is Abyssal Depths module active?
-fn is_abyssal_module(abyssal: Option<Res<crate::games::abyssal_depths::AbyssalState>>) -> bool {
-    abyssal.map(|a| a.active).unwrap_or(false)
-}
-
 /// Start mission when entering Playing state
 fn start_mission(
     mut campaign: ResMut<CampaignState>,
+    mut objectives: ResMut<MissionObjectives>,
     mut mission_events: EventWriter<MissionStartEvent>,
 ) {
     campaign.start_mission();
 
     if let Some(mission) = campaign.current_mission() {
+        objectives.set(mission.objectives());
         info!(
             "Starting Mission {}: {} - {}",
             campaign.mission_number(),
@@ -134,6 +122,8 @@ fn spawn_next_wave(
     model_cache: Res<ShipModelCache>,
     difficulty: Res<Difficulty>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut wave_events: EventWriter<SpawnWaveEvent>,
+    mut rng: ResMut<GameRng>,
 ) {
     // Only spawn if no enemies remain
     if enemy_query.iter().count() > 0 || boss_query.iter().count() > 0 {
@@ -160,10 +150,17 @@ fn spawn_next_wave(
     let count = (base_count as f32 * spawn_mult) as usize;
 
     info!("Spawning wave {} with {} enemies", wave, count);
-
-    // Use faction-appropriate enemies from session
+    wave_events.send(SpawnWaveEvent {
+        wave_number: wave,
+        enemy_count: count as u32,
+        enemy_types: Vec::new(),
+    });
+
+    // Use faction-appropriate enemies from session; the wave flies as one
+    // formation
+    let mut leader = None;
     for i in 0..count {
-        let enemy_def = session.random_enemy();
+        let enemy_def = session.random_enemy(&mut rng.0);
         let type_id = enemy_def.type_id;
         let x = (i as f32 - count as f32 / 2.0) * 80.0;
         let y = SCREEN_HEIGHT / 2.0 + 50.0 + (i as f32 * 20.0);
@@ -171,7 +168,7 @@ fn spawn_next_wave(
         // Get sprite from cache if available
         let sprite_handle = sprite_cache.get(type_id);
 
-        spawn_enemy(
+        let entity = spawn_enemy(
             &mut commands,
             type_id,
             Vec2::new(x, y),
@@ -179,6 +176,10 @@ fn spawn_next_wave(
             sprite_handle,
             Some(&model_cache),
         );
+        commands.entity(entity).insert(Formation {
+            leader: *leader.get_or_insert(entity),
+            size: count as u32,
+        });
     }
 
     campaign.current_wave += 1;
@@ -363,12 +364,16 @@ fn update_boss_behavior(
 fn check_boss_defeated(
     mut commands: Commands,
     mut campaign: ResMut<CampaignState>,
-    mut score: ResMut<ScoreSystem>,
-    mut ship_unlocks: ResMut<ShipUnlocks>,
+    mut score_events: EventWriter<ScoreEvent>,
     mut save_data: ResMut<crate::core::SaveData>,
+    mut objectives: ResMut<MissionObjectives>,
     session: Res<crate::core::GameSession>,
+    modules: Res<GameModules>,
+    active_module: Res<ActiveModule>,
     boss_query: Query<(Entity, &Transform, &BossData), With<Boss>>,
+    escorts: Query<(), (With<Enemy>, Without<Boss>)>,
     mut boss_events: EventWriter<BossDefeatedEvent>,
+    mut record_events: EventWriter<RecordScoreEvent>,
     mut act_events: EventWriter<ActCompleteEvent>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
@@ -376,8 +381,7 @@ fn check_boss_defeated(
         if data.health <= 0.0 {
             info!("Boss defeated: {}", data.title);
 
-            // Add score
-            score.add_score(data.score_value);
+            score_events.send(ScoreEvent::boss(data.score_value));
 
             // Spawn massive liberation pod burst for boss defeat
             let pos = transform.translation.truncate();
@@ -388,6 +392,13 @@ fn check_boss_defeated(
             campaign.boss_defeated = true;
             campaign.primary_complete = true;
 
+            // Settle objectives and pay out their rewards
+            objectives.on_boss_defeated(escorts.iter().count());
+            let rewards = objectives.finish(true);
+            score_events.send(ScoreEvent::objective(rewards.score));
+            save_data.add_skill_points(rewards.skill_points);
+            campaign.bonus_complete = objectives.optionals_complete();
+
             // Send event
             boss_events.send(BossDefeatedEvent {
                 boss_type: data.name.clone(),
@@ -396,25 +407,28 @@ fn check_boss_defeated(
             });
 
             // Save progress
-            let stage = campaign.mission_index as u32 + 1;
-            save_data.complete_stage(
-                session.player_faction.short_name(),
-                session.enemy_faction.short_name(),
-                stage,
-                campaign.mission_index as u32,
-            );
-            save_data.record_score(
-                session.player_faction.short_name(),
-                session.enemy_faction.short_name(),
-                score.score,
-                stage,
-            );
-
-            // Check for act completion and ship unlocks
+            let faction = session.player_faction.short_name();
+            let enemy = session.enemy_faction.short_name();
+            let stage = campaign.mission_number() as u32;
+            let side_mission = campaign.side_mission.is_some();
+            if !side_mission {
+                save_data.complete_stage(faction, enemy, stage, campaign.mission_index as u32);
+            }
+            record_events.send(RecordScoreEvent::new(faction, enemy, stage));
+
+            // Campaign map node; its reward is paid on the first clear
+            let map = modules
+                .active(&active_module)
+                .and_then(|m| m.campaign_map(&active_module));
+            if let (Some(map), Some(mission)) = (map, campaign.current_mission()) {
+                map.clear_node(&mut save_data, faction, enemy, mission.id);
+            }
+
+            // Check for act completion; ship unlocks follow from the saved
+            // progress (see `ShipUnlockRegistry`)
             let missions = campaign.act.missions();
-            if campaign.mission_index + 1 >= missions.len() {
+            if !side_mission && campaign.mission_index + 1 >= missions.len() {
                 let completed_act = campaign.act;
-                ship_unlocks.complete_act(completed_act.number());
                 act_events.send(ActCompleteEvent { act: completed_act });
                 info!("Act {} complete!", completed_act.number());
             }
@@ -0,0 +1,127 @@
+//! Challenge System
+//!
+//! Runs a daily or weekly challenge once the challenge screen has launched
+//! it: keeps [`DifficultySettings`] in step with the challenge, seeds the
+//! run, spends the pilot's ranked attempt, holds Salt Miner open when the
+//! challenge asks for it and ends the run once its Endless segment is
+//! cleared.
+
+use bevy::prelude::*;
+
+use super::spawning::WaveManager;
+use crate::core::*;
+use crate::entities::Enemy;
+
+/// Challenge plugin
+pub struct ChallengePlugin;
+
+impl Plugin for ChallengePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DifficultySettings>()
+            .init_resource::<ChallengeRun>()
+            .add_systems(
+                Update,
+                (
+                    sync_difficulty_settings.run_if(resource_changed::<ChallengeRun>),
+                    start_challenge_run,
+                    finish_challenge_run,
+                ),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), clear_challenge_run)
+            .add_systems(OnEnter(GameState::ModuleSelect), clear_challenge_run)
+            .add_systems(
+                Update,
+                (hold_salt_miner, end_challenge_segment)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// Difficulty settings are the defaults plus the challenge's modifiers
+fn sync_difficulty_settings(run: Res<ChallengeRun>, mut settings: ResMut<DifficultySettings>) {
+    *settings = DifficultySettings::default();
+    if let Some(challenge) = &run.challenge {
+        challenge.apply(&mut settings);
+    }
+}
+
+/// Seed the run and spend the attempt when a challenge mission starts
+fn start_challenge_run(
+    mut transitions: EventReader<StateTransitionEvent<GameState>>,
+    run: Res<ChallengeRun>,
+    mut rng: ResMut<GameRng>,
+    mut save_data: ResMut<SaveData>,
+) {
+    for transition in transitions.read() {
+        let (Some(exited), Some(entered)) = (transition.exited, transition.entered) else {
+            continue;
+        };
+        if exited.in_mission() || !entered.in_mission() {
+            continue;
+        }
+        let Some(challenge) = &run.challenge else {
+            continue;
+        };
+        rng.reseed(challenge.seed as u64);
+        if run.ranked {
+            save_data.challenge_attempts.insert(challenge.key());
+        }
+        info!(
+            "{} challenge: {}",
+            challenge.title(),
+            challenge
+                .modifiers
+                .iter()
+                .map(|m| m.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+}
+
+/// A challenge is one run; retries go through the challenge screen
+fn finish_challenge_run(
+    mut finished: EventReader<RunFinishedEvent>,
+    mut run: ResMut<ChallengeRun>,
+) {
+    if finished.read().count() > 0 && run.challenge.is_some() {
+        *run = ChallengeRun::default();
+    }
+}
+
+/// Backing out to the menus leaves the challenge
+fn clear_challenge_run(mut run: ResMut<ChallengeRun>) {
+    if run.challenge.is_some() {
+        *run = ChallengeRun::default();
+    }
+}
+
+/// Keep Salt Miner running for the whole mission
+fn hold_salt_miner(settings: Res<DifficultySettings>, mut salt_miner: ResMut<SaltMinerSystem>) {
+    if settings.player.salt_miner_always {
+        salt_miner.is_active = true;
+        salt_miner.timer = salt_miner.duration;
+    }
+}
+
+/// Clearing the segment's last wave completes the challenge
+fn end_challenge_segment(
+    run: Res<ChallengeRun>,
+    endless: Res<EndlessMode>,
+    manager: Res<WaveManager>,
+    enemies: Query<(), With<Enemy>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(challenge) = &run.challenge else {
+        return;
+    };
+    if endless.wave >= challenge.period.segment_waves()
+        && manager.in_delay
+        && !manager.boss_active
+        && !manager.mini_boss_active
+        && enemies.is_empty()
+    {
+        info!("{} challenge cleared", challenge.title());
+        next_state.set(GameState::Victory);
+    }
+}
@@ -18,6 +18,8 @@ const GRID_HEIGHT: usize = 16; // 700 / 50 + padding
 pub struct SpatialGrid {
     /// Grid cells containing enemy entity indices
     enemy_cells: Vec<Vec<(Entity, Vec2)>>,
+    /// Grid cells containing enemy projectiles
+    projectile_cells: Vec<Vec<(Entity, Vec2)>>,
 }
 
 impl SpatialGrid {
@@ -26,6 +28,9 @@ impl SpatialGrid {
             enemy_cells: (0..GRID_WIDTH * GRID_HEIGHT)
                 .map(|_| Vec::with_capacity(8))
                 .collect(),
+            projectile_cells: (0..GRID_WIDTH * GRID_HEIGHT)
+                .map(|_| Vec::with_capacity(16))
+                .collect(),
         }
     }
 
@@ -33,6 +38,9 @@ impl SpatialGrid {
         for cell in &mut self.enemy_cells {
             cell.clear();
         }
+        for cell in &mut self.projectile_cells {
+            cell.clear();
+        }
     }
 
     #[inline]
@@ -75,6 +83,35 @@ impl SpatialGrid {
             .into_iter()
             .flat_map(move |idx| self.enemy_cells[idx].iter())
     }
+
+    pub fn insert_projectile(&mut self, entity: Entity, pos: Vec2) {
+        if let Some(idx) = Self::pos_to_cell(pos) {
+            self.projectile_cells[idx].push((entity, pos));
+        }
+    }
+
+    /// Get enemy projectiles in every cell within `radius` of `pos`
+    pub fn get_nearby_projectiles(
+        &self,
+        pos: Vec2,
+        radius: f32,
+    ) -> impl Iterator<Item = &(Entity, Vec2)> {
+        let min_x = ((pos.x - radius + SCREEN_WIDTH / 2.0) / CELL_SIZE).floor() as i32;
+        let max_x = ((pos.x + radius + SCREEN_WIDTH / 2.0) / CELL_SIZE).floor() as i32;
+        let min_y = ((pos.y - radius + SCREEN_HEIGHT / 2.0) / CELL_SIZE).floor() as i32;
+        let max_y = ((pos.y + radius + SCREEN_HEIGHT / 2.0) / CELL_SIZE).floor() as i32;
+
+        let mut indices = Vec::new();
+        for ny in min_y.max(0)..=max_y.min(GRID_HEIGHT as i32 - 1) {
+            for nx in min_x.max(0)..=max_x.min(GRID_WIDTH as i32 - 1) {
+                indices.push((ny * GRID_WIDTH as i32 + nx) as usize);
+            }
+        }
+
+        indices
+            .into_iter()
+            .flat_map(move |idx| self.projectile_cells[idx].iter())
+    }
 }
 
 /// Collision plugin
@@ -95,27 +132,48 @@ impl Plugin for CollisionPlugin {
     }
 }
 
-/// Update spatial grid with current enemy positions
+/// Update spatial grid with current enemy and enemy projectile positions
 fn update_spatial_grid(
     mut grid: ResMut<SpatialGrid>,
     enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    projectile_query: Query<(Entity, &Transform), With<EnemyProjectile>>,
 ) {
     grid.clear();
     for (entity, transform) in enemy_query.iter() {
         grid.insert_enemy(entity, transform.translation.truncate());
     }
+    for (entity, transform) in projectile_query.iter() {
+        grid.insert_projectile(entity, transform.translation.truncate());
+    }
 }
 
 /// Player projectiles hitting enemies (optimized with spatial grid)
 fn player_projectile_enemy_collision(
     mut commands: Commands,
     grid: Res<SpatialGrid>,
-    projectile_query: Query<(Entity, &Transform, &ProjectileDamage), With<PlayerProjectile>>,
-    mut enemy_query: Query<(&mut EnemyStats, Option<&Sprite>), With<Enemy>>,
+    projectile_query: Query<
+        (
+            Entity,
+            &Transform,
+            &ProjectileDamage,
+            Option<&SeekingProjectile>,
+        ),
+        With<PlayerProjectile>,
+    >,
+    mut enemy_query: Query<
+        (
+            &mut EnemyStats,
+            Option<&EnemyResists>,
+            Option<&Sprite>,
+            Option<&Formation>,
+        ),
+        With<Enemy>,
+    >,
     player_query: Query<(&Transform, &ShipStats), With<Player>>,
-    mut score: ResMut<ScoreSystem>,
-    mut salt_miner: ResMut<SaltMinerSystem>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut kill_shot_events: EventWriter<KillShotEvent>,
     mut destroy_events: EventWriter<EnemyDestroyedEvent>,
+    mut hit_events: EventWriter<EnemyHitEvent>,
     mut explosion_events: EventWriter<ExplosionEvent>,
     mut dialogue_events: EventWriter<super::DialogueEvent>,
     mut screen_shake: ResMut<super::effects::ScreenShake>,
@@ -138,7 +196,7 @@ fn player_projectile_enemy_collision(
     // Collision radius squared for faster distance checks
     const COLLISION_RADIUS_SQ: f32 = 25.0 * 25.0;
 
-    for (proj_entity, proj_transform, proj_damage) in projectile_query.iter() {
+    for (proj_entity, proj_transform, proj_damage, seeking) in projectile_query.iter() {
         let proj_pos = proj_transform.translation.truncate();
 
         // Only check enemies in nearby grid cells (O(1) average instead of O(n))
@@ -148,7 +206,9 @@ fn player_projectile_enemy_collision(
             // Use squared distance to avoid sqrt
             if dist_sq < COLLISION_RADIUS_SQ {
                 // Get mutable enemy stats
-                let Ok((mut enemy_stats, sprite)) = enemy_query.get_mut(enemy_entity) else {
+                let Ok((mut enemy_stats, resists, sprite, formation)) =
+                    enemy_query.get_mut(enemy_entity)
+                else {
                     continue;
                 };
 
@@ -160,10 +220,31 @@ fn player_projectile_enemy_collision(
                     1.0
                 };
 
-                // Apply ammo type multiplier (use armor mult since most enemies are armored)
-                let ammo_mult = proj_damage.ammo_type.armor_mult();
+                // Resists and ammo of the layer being hit (enemies without a
+                // profile are treated as armor)
+                let layer = resists.map(|r| r.layer(&enemy_stats));
+                let (layer_mult, effectiveness) = match (resists, layer) {
+                    (Some(resists), Some(layer)) => {
+                        let mult = resists
+                            .profile
+                            .relative_multiplier(layer, proj_damage.damage_type)
+                            * proj_damage.ammo_type.layer_mult(layer);
+                        (mult, DamageEffectiveness::from_multiplier(mult))
+                    }
+                    _ => (
+                        proj_damage.ammo_type.armor_mult(),
+                        DamageEffectiveness::Normal,
+                    ),
+                };
 
-                let final_damage = proj_damage.damage * crit_mult * ammo_mult;
+                let final_damage = proj_damage.damage * crit_mult * layer_mult;
+                hit_events.send(EnemyHitEvent {
+                    position: enemy_pos,
+                    damage: final_damage,
+                    damage_type: proj_damage.damage_type,
+                    layer,
+                    crit: is_crit,
+                });
 
                 // Apply damage
                 enemy_stats.health -= final_damage;
@@ -191,6 +272,7 @@ fn player_projectile_enemy_collision(
                     enemy_pos,
                     final_damage,
                     is_crit,
+                    effectiveness,
                 );
 
                 // Despawn projectile
@@ -201,23 +283,19 @@ fn player_projectile_enemy_collision(
                     // Calculate distance from player to enemy for salt miner
                     let player_distance = (player_pos - enemy_pos).length();
 
-                    // Update score (with salt miner multiplier)
-                    let base_score = enemy_stats.score_value;
-                    let final_score = (base_score as f32 * salt_miner.score_mult()) as u64;
-                    score.on_kill(final_score);
-
-                    // Fill salt miner meter based on proximity (closer = more meter)
-                    let meter_gained = salt_miner.on_kill_at_distance(player_distance);
-                    if meter_gained > 0.0 && salt_miner.can_activate() {
-                        info!(
-                            "SALT MINER READY! Press B to activate! (meter: {:.0}%)",
-                            salt_miner.meter
-                        );
-                    }
+                    // Score and salt miner meter go through the scoring rules
+                    score_events.send(ScoreEvent::kill(enemy_stats.score_value, player_distance));
+                    kill_shot_events.send(KillShotEvent {
+                        position: enemy_pos,
+                        distance: player_distance,
+                        seeking: seeking.is_some(),
+                        formation: formation.copied(),
+                    });
 
                     // Send events
                     destroy_events.send(EnemyDestroyedEvent {
                         position: enemy_pos,
+                        type_id: enemy_stats.type_id,
                         enemy_type: enemy_stats.name.clone(),
                         score_value: enemy_stats.score_value,
                         was_boss: enemy_stats.is_boss,
@@ -267,10 +345,13 @@ fn player_projectile_enemy_collision(
     }
 }
 
-/// Enemy projectiles hitting player
+/// Enemy projectiles hitting or grazing the player (only those near the
+/// player, from the spatial grid)
 fn enemy_projectile_player_collision(
     mut commands: Commands,
-    projectile_query: Query<(Entity, &Transform, &ProjectileDamage), With<EnemyProjectile>>,
+    grid: Res<SpatialGrid>,
+    projectile_query: Query<(&ProjectileDamage, Has<Grazed>), With<EnemyProjectile>>,
+    grazing_query: Query<(Entity, &Transform), (With<EnemyProjectile>, With<Grazing>)>,
     mut player_query: Query<
         (
             Entity,
@@ -285,6 +366,7 @@ fn enemy_projectile_player_collision(
     >,
     mut score: ResMut<ScoreSystem>,
     mut damage_events: EventWriter<PlayerDamagedEvent>,
+    mut graze_events: EventWriter<GrazeEvent>,
     mut damage_layer_events: EventWriter<DamageLayerEvent>,
     mut dialogue_events: EventWriter<super::DialogueEvent>,
     mut rumble_events: EventWriter<super::RumbleRequest>,
@@ -304,11 +386,30 @@ fn enemy_projectile_player_collision(
 
     let player_pos = player_transform.translation.truncate();
     let hit_radius_sq = (hitbox.radius + 4.0) * (hitbox.radius + 4.0);
+    let graze_radius = hitbox.radius + GRAZE_MARGIN;
 
-    for (proj_entity, proj_transform, proj_damage) in projectile_query.iter() {
+    // Near miss: counts once, when a bullet that entered the margin leaves it unhit
+    for (proj_entity, proj_transform) in grazing_query.iter() {
         let proj_pos = proj_transform.translation.truncate();
+        if proj_pos.distance_squared(player_pos) >= graze_radius * graze_radius {
+            commands
+                .entity(proj_entity)
+                .remove::<Grazing>()
+                .insert(Grazed);
+            graze_events.send(GrazeEvent { position: proj_pos });
+        }
+    }
+
+    for &(proj_entity, proj_pos) in grid.get_nearby_projectiles(player_pos, graze_radius) {
+        let Ok((proj_damage, grazed)) = projectile_query.get(proj_entity) else {
+            continue;
+        };
         let dist_sq = (proj_pos - player_pos).length_squared();
 
+        if dist_sq < graze_radius * graze_radius && !grazed {
+            commands.entity(proj_entity).insert(Grazing);
+        }
+
         if dist_sq < hit_radius_sq {
             // Despawn projectile regardless
             commands.entity(proj_entity).despawn_recursive();
@@ -402,3 +503,31 @@ fn enemy_projectile_player_collision(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearby_projectiles_come_from_cells_in_range() {
+        let mut grid = SpatialGrid::new();
+        let near = Entity::from_raw(1);
+        let far = Entity::from_raw(2);
+        grid.insert_projectile(near, Vec2::new(30.0, -200.0));
+        grid.insert_projectile(far, Vec2::new(300.0, 200.0));
+
+        let found: Vec<_> = grid
+            .get_nearby_projectiles(Vec2::new(0.0, -200.0), 40.0)
+            .map(|(entity, _)| *entity)
+            .collect();
+        assert_eq!(found, vec![near]);
+
+        // Off-grid positions are dropped, and lookups at the edge stay in bounds
+        grid.insert_projectile(far, Vec2::new(2000.0, 0.0));
+        assert_eq!(
+            grid.get_nearby_projectiles(Vec2::new(-400.0, -350.0), 40.0)
+                .count(),
+            0
+        );
+    }
+}
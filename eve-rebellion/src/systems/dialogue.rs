@@ -2,12 +2,12 @@
 //!
 //! Handles faction-specific dialogue display during gameplay.
 //! - Elder Fleet: "Tribal Elder" speaker (Minmatar vs Amarr)
-//! - Caldari/Gallente: "Fleet Command" speaker
+//! - Other modules: their `GameModule::dialogue` provider (e.g. CG "Fleet Command")
 
 #![allow(dead_code)]
 
 use crate::core::*;
-use crate::games::ActiveModule;
+use crate::games::{ActiveModule, DialogueProvider, GameModules};
 use bevy::prelude::*;
 
 /// Dialogue plugin
@@ -205,25 +205,28 @@ fn handle_dialogue_events(
     mut events: EventReader<DialogueEvent>,
     mut dialogue: ResMut<DialogueSystem>,
     active_module: Res<ActiveModule>,
+    modules: Res<GameModules>,
 ) {
-    for event in events.read() {
-        let is_cg = active_module.is_caldari_gallente();
+    if events.is_empty() {
+        return;
+    }
 
+    let provider = modules
+        .active(&active_module)
+        .and_then(|m| m.dialogue())
+        .unwrap_or_else(|| DialogueProvider {
+            speaker: "Tribal Elder",
+            text: std::sync::Arc::new(get_dialogue_text),
+        });
+
+    for event in events.read() {
         let text = if let Some(custom) = &event.custom_text {
             custom.clone()
-        } else if is_cg {
-            get_cg_dialogue_text(&event.trigger)
-        } else {
-            get_dialogue_text(&event.trigger)
-        };
-
-        let speaker = if is_cg {
-            "Fleet Command"
         } else {
-            "Tribal Elder"
+            (provider.text)(&event.trigger)
         };
 
-        dialogue.show_with_speaker(text, event.duration, event.priority, speaker);
+        dialogue.show_with_speaker(text, event.duration, event.priority, provider.speaker);
     }
 }
 
@@ -237,7 +240,9 @@ fn update_dialogue_timer(time: Res<Time>, mut dialogue: ResMut<DialogueSystem>)
 
             // Process queue (highest priority first)
             if !dialogue.queue.is_empty() {
-                dialogue.queue.sort_by(|a, b| b.2.cmp(&a.2));
+                dialogue
+                    .queue
+                    .sort_by_key(|entry| std::cmp::Reverse(entry.2));
                 if let Some((text, duration, priority)) = dialogue.queue.pop() {
                     dialogue.show(text, duration, priority);
                 }
@@ -370,7 +375,7 @@ pub fn check_liberation_milestone(old_count: u32, new_count: u32) -> Option<u32>
 // ============================================================================
 
 /// Get CG dialogue text for a trigger
-fn get_cg_dialogue_text(trigger: &DialogueTrigger) -> String {
+pub fn get_cg_dialogue_text(trigger: &DialogueTrigger) -> String {
     match trigger {
         DialogueTrigger::StageBriefing(stage) => get_cg_stage_briefing(*stage),
         DialogueTrigger::BossIntro(name) => format!("Enemy commander {} engaged!", name),
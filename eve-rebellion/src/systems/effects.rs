@@ -720,11 +720,22 @@ impl Default for DamageNumber {
     }
 }
 
-/// Spawn a floating damage number at position
-pub fn spawn_damage_number(commands: &mut Commands, position: Vec2, damage: f32, is_crit: bool) {
+/// Spawn a floating damage number at position, colored by how well the
+/// damage type and ammo matched the layer it hit
+pub fn spawn_damage_number(
+    commands: &mut Commands,
+    position: Vec2,
+    damage: f32,
+    is_crit: bool,
+    effectiveness: DamageEffectiveness,
+) {
     let text = format!("{:.0}", damage);
     let (color, size) = if is_crit {
         (Color::srgb(1.0, 0.9, 0.2), 18.0) // Yellow, larger for crits
+    } else if effectiveness == DamageEffectiveness::Effective {
+        (Color::srgb(0.3, 0.9, 1.0), 16.0) // Cyan for hits into a resist hole
+    } else if effectiveness == DamageEffectiveness::Resisted {
+        (Color::srgb(0.55, 0.55, 0.6), 12.0) // Gray, smaller for resisted hits
     } else if damage >= 20.0 {
         (Color::srgb(1.0, 0.5, 0.2), 16.0) // Orange for heavy hits
     } else {
@@ -815,6 +826,7 @@ impl HitFlash {
 fn update_hit_flash(
     mut commands: Commands,
     time: Res<Time>,
+    accessibility: Res<AccessibilitySettings>,
     mut query: Query<(Entity, &mut Sprite, &mut HitFlash)>,
 ) {
     let dt = time.delta_secs();
@@ -823,8 +835,11 @@ fn update_hit_flash(
         flash.timer -= dt;
 
         if flash.timer > 0.0 {
-            // Lerp from white to original color
-            let progress = 1.0 - (flash.timer / flash.duration);
+            // Lerp from white to original color (starting partway with reduced flashing)
+            let mut progress = 1.0 - (flash.timer / flash.duration);
+            if accessibility.reduce_flashing {
+                progress = progress.max(1.0 - REDUCED_HIT_FLASH_STRENGTH);
+            }
             let white = Color::WHITE;
             let original = flash.original_color;
 
@@ -849,7 +864,7 @@ fn update_hit_flash(
 // =============================================================================
 
 /// Screen-wide flash effect for big explosions
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct ScreenFlash {
     /// Current flash intensity (0.0 - 1.0)
     pub intensity: f32,
@@ -857,21 +872,47 @@ pub struct ScreenFlash {
     pub color: Color,
     /// Fade speed
     pub fade_speed: f32,
+    /// Intensity cap (lowered by the reduced flashing option)
+    pub max_intensity: f32,
+    /// Minimum seconds between flashes (raised by the reduced flashing option)
+    pub min_interval: f32,
+    /// Seconds since the last flash was triggered
+    pub since_last: f32,
+}
+
+impl Default for ScreenFlash {
+    fn default() -> Self {
+        Self {
+            intensity: 0.0,
+            color: Color::WHITE,
+            fade_speed: 0.0,
+            max_intensity: 1.0,
+            min_interval: 0.0,
+            since_last: f32::MAX,
+        }
+    }
 }
 
 impl ScreenFlash {
     /// Trigger a white screen flash
     pub fn white(&mut self, intensity: f32) {
-        self.intensity = intensity.min(1.0);
-        self.color = Color::WHITE;
-        self.fade_speed = 4.0;
+        self.colored(Color::WHITE, intensity);
     }
 
-    /// Trigger a colored screen flash
+    /// Trigger a colored screen flash (dropped if it comes too soon after the last one)
     pub fn colored(&mut self, color: Color, intensity: f32) {
-        self.intensity = intensity.min(1.0);
+        if self.since_last < self.min_interval {
+            return;
+        }
+        self.intensity = intensity.min(self.max_intensity);
         self.color = color;
         self.fade_speed = 4.0;
+        self.since_last = 0.0;
+    }
+
+    /// Advance the flash rate limiter
+    pub fn tick(&mut self, dt: f32) {
+        self.since_last += dt;
     }
 
     /// Trigger flash for massive explosion (boss kill)
@@ -904,6 +945,7 @@ fn update_screen_flash(
     mut overlay_query: Query<(Entity, &mut Sprite), With<ScreenFlashOverlay>>,
 ) {
     let dt = time.delta_secs();
+    flash.tick(dt);
 
     if flash.intensity > 0.0 {
         // Fade out
@@ -944,11 +986,16 @@ pub struct SaltMinerTintOverlay;
 fn update_salt_miner_tint(
     mut commands: Commands,
     salt_miner: Res<SaltMinerSystem>,
+    accessibility: Res<AccessibilitySettings>,
     mut overlay_query: Query<(Entity, &mut Sprite), With<SaltMinerTintOverlay>>,
 ) {
     if salt_miner.is_active {
-        // Pulse the tint based on remaining time
-        let pulse = (salt_miner.timer * 8.0).sin().abs() * 0.1;
+        // Pulse the tint based on remaining time (steady with reduced flashing)
+        let pulse = if accessibility.reduce_flashing {
+            0.0
+        } else {
+            (salt_miner.timer * 8.0).sin().abs() * 0.1
+        };
         let alpha = 0.15 + pulse;
 
         if let Ok((_, mut sprite)) = overlay_query.get_single_mut() {
@@ -2034,6 +2081,7 @@ fn handle_damage_layer_events(
     spark_query: Query<&ArmorSpark>,
     ripple_query: Query<&ShieldRipple>,
     mut screen_shake: ResMut<ScreenShake>,
+    palette: Res<Palette>,
 ) {
     let current_sparks = spark_query.iter().count();
     let current_ripples = ripple_query.iter().count();
@@ -2043,7 +2091,12 @@ fn handle_damage_layer_events(
             DamageLayer::Shield => {
                 // Cap shield ripples to prevent lag during sustained fire
                 if current_ripples < MAX_SHIELD_RIPPLES {
-                    spawn_shield_ripple(&mut commands, event.position, event.direction);
+                    spawn_shield_ripple(
+                        &mut commands,
+                        event.position,
+                        event.direction,
+                        palette.shield,
+                    );
                 }
             }
             DamageLayer::Armor => {
@@ -2053,11 +2106,12 @@ fn handle_damage_layer_events(
                         event.position,
                         event.direction,
                         event.damage,
+                        palette.armor,
                     );
                 }
             }
             DamageLayer::Hull => {
-                spawn_hull_fire(&mut commands, event.position, event.damage);
+                spawn_hull_fire(&mut commands, event.position, event.damage, palette.hull);
                 // Hull damage causes stronger screen shake
                 screen_shake.trigger(6.0, 0.15);
             }
@@ -2066,7 +2120,7 @@ fn handle_damage_layer_events(
 }
 
 /// Spawn a shield impact ripple effect
-fn spawn_shield_ripple(commands: &mut Commands, position: Vec2, direction: Vec2) {
+fn spawn_shield_ripple(commands: &mut Commands, position: Vec2, direction: Vec2, color: Color) {
     let angle = direction.y.atan2(direction.x);
     let lifetime = 0.4;
 
@@ -2079,7 +2133,7 @@ fn spawn_shield_ripple(commands: &mut Commands, position: Vec2, direction: Vec2)
             angle,
         },
         Sprite {
-            color: Color::srgba(0.3, 0.7, 1.0, 0.8), // Blue shield color
+            color: color.with_alpha(0.8), // Shield color
             custom_size: Some(Vec2::splat(10.0)),
             ..default()
         },
@@ -2095,7 +2149,7 @@ fn spawn_shield_ripple(commands: &mut Commands, position: Vec2, direction: Vec2)
             angle,
         },
         Sprite {
-            color: Color::srgba(0.5, 0.8, 1.0, 0.6),
+            color: lighten(color, 0.3).with_alpha(0.6),
             custom_size: Some(Vec2::splat(8.0)),
             ..default()
         },
@@ -2117,7 +2171,7 @@ fn spawn_shield_ripple(commands: &mut Commands, position: Vec2, direction: Vec2)
                 angle: particle_angle,
             },
             Sprite {
-                color: Color::srgba(0.4, 0.8, 1.0, 0.7),
+                color: lighten(color, 0.15).with_alpha(0.7),
                 custom_size: Some(Vec2::splat(6.0)),
                 ..default()
             },
@@ -2162,7 +2216,13 @@ fn update_shield_ripples(
 }
 
 /// Spawn armor spark particles
-fn spawn_armor_sparks(commands: &mut Commands, position: Vec2, direction: Vec2, damage: f32) {
+fn spawn_armor_sparks(
+    commands: &mut Commands,
+    position: Vec2,
+    direction: Vec2,
+    damage: f32,
+    color: Color,
+) {
     let spark_count = (damage / 5.0).clamp(3.0, 12.0) as u32;
     let base_angle = direction.y.atan2(direction.x);
 
@@ -2182,7 +2242,7 @@ fn spawn_armor_sparks(commands: &mut Commands, position: Vec2, direction: Vec2,
                 max_lifetime: lifetime,
             },
             Sprite {
-                color: Color::srgba(1.0, 0.7, 0.3, 1.0), // Orange/gold
+                color, // Armor color (orange/gold by default)
                 custom_size: Some(Vec2::new(4.0, 2.0)),
                 ..default()
             },
@@ -2230,7 +2290,7 @@ fn update_armor_sparks(
 }
 
 /// Spawn hull fire and smoke particles
-fn spawn_hull_fire(commands: &mut Commands, position: Vec2, damage: f32) {
+fn spawn_hull_fire(commands: &mut Commands, position: Vec2, damage: f32, fire_color: Color) {
     let particle_count = (damage / 3.0).clamp(4.0, 15.0) as u32;
 
     for i in 0..particle_count {
@@ -2252,7 +2312,7 @@ fn spawn_hull_fire(commands: &mut Commands, position: Vec2, damage: f32) {
         let color = if is_smoke {
             Color::srgba(0.2, 0.2, 0.2, 0.7) // Dark smoke
         } else {
-            Color::srgba(1.0, 0.4, 0.1, 0.9) // Orange fire
+            fire_color.with_alpha(0.9) // Hull fire
         };
 
         let size = if is_smoke {
@@ -0,0 +1,270 @@
+//! Ghost System
+//!
+//! Records every mission as a [`GhostRecording`] and, when a ghost was
+//! picked on the leaderboards screen for the mode being flown, replays it as
+//! a translucent ship that nothing collides with. Splits are taken at each
+//! wave and boss phase and compared with the ghost's.
+
+use bevy::prelude::*;
+
+use super::leaderboards::{track_leaderboard_run, LeaderboardRun};
+use crate::assets::ShipSpriteCache;
+use crate::core::*;
+use crate::entities::{get_ship_rotation_correction, Player};
+
+/// Ghost plugin
+pub struct GhostPlugin;
+
+impl Plugin for GhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GhostRecorder>()
+            .init_resource::<GhostRace>()
+            .add_systems(
+                Update,
+                (start_ghost_run, end_ghost_run).after(track_leaderboard_run),
+            )
+            .add_systems(
+                Update,
+                (record_ghost, record_splits, move_ghost)
+                    .chain()
+                    .after(start_ghost_run)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// Recording of the mission in progress
+#[derive(Resource, Default, Debug)]
+pub struct GhostRecorder {
+    pub recording: GhostRecording,
+    /// Seconds flown, not counting pauses
+    pub clock: f32,
+    wave: u32,
+}
+
+/// Run picked to race
+#[derive(Clone, Debug, PartialEq)]
+pub struct GhostTarget {
+    pub mode: LeaderboardMode,
+    pub pilot: String,
+    pub score: u64,
+    /// Recording id (`LeaderboardEntry::replay`)
+    pub id: String,
+}
+
+/// Latest split against the ghost; negative time is ahead
+#[derive(Clone, Debug, PartialEq)]
+pub struct SplitDelta {
+    pub label: String,
+    pub time: f32,
+    pub score: i64,
+    /// When it was taken, on the recorder clock
+    pub at: f32,
+}
+
+/// The ghost being raced
+#[derive(Resource, Default, Debug)]
+pub struct GhostRace {
+    /// Raced whenever its mode is flown
+    pub target: Option<GhostTarget>,
+    /// Loaded for the mission in progress
+    pub ghost: Option<GhostRecording>,
+    pub last_split: Option<SplitDelta>,
+}
+
+impl GhostRace {
+    /// Live score minus the ghost's at the same time
+    pub fn score_delta(&self, clock: f32, score: u64) -> Option<i64> {
+        let ghost = self.ghost.as_ref()?;
+        Some(score as i64 - ghost.score_at(clock) as i64)
+    }
+
+    /// Time taken to reach the live score minus the ghost's; `None` once
+    /// the live score is past anything the ghost reached
+    pub fn time_delta(&self, clock: f32, score: u64) -> Option<f32> {
+        let ghost = self.ghost.as_ref()?;
+        ghost.time_to_score(score).map(|time| clock - time)
+    }
+}
+
+/// Translucent ship replaying the ghost
+#[derive(Component)]
+pub struct GhostShip;
+
+/// Opacity of the ghost ship
+const GHOST_ALPHA: f32 = 0.35;
+
+/// Reset the recorder and load the ghost when a mission starts
+fn start_ghost_run(
+    mut commands: Commands,
+    mut transitions: EventReader<StateTransitionEvent<GameState>>,
+    run: Res<LeaderboardRun>,
+    campaign: Res<CampaignState>,
+    endless: Res<EndlessMode>,
+    sprite_cache: Res<ShipSpriteCache>,
+    mut recorder: ResMut<GhostRecorder>,
+    mut race: ResMut<GhostRace>,
+) {
+    for transition in transitions.read() {
+        let (Some(exited), Some(entered)) = (transition.exited, transition.entered) else {
+            continue;
+        };
+        if exited.in_mission() || !entered.in_mission() {
+            continue;
+        }
+
+        *recorder = GhostRecorder {
+            recording: GhostRecording::new(run.ship_type_id),
+            clock: 0.0,
+            wave: current_wave(&campaign, &endless),
+        };
+        race.last_split = None;
+        race.ghost = race
+            .target
+            .as_ref()
+            .filter(|target| run.mode.as_ref() == Some(&target.mode))
+            .and_then(|target| GhostRecording::load(&target.id));
+
+        if let (Some(ghost), Some(target)) = (&race.ghost, &race.target) {
+            info!("Racing ghost of {} ({})", target.pilot, target.score);
+            spawn_ghost_ship(&mut commands, &sprite_cache, ghost.ship_type_id);
+        }
+    }
+}
+
+fn spawn_ghost_ship(commands: &mut Commands, sprite_cache: &ShipSpriteCache, type_id: u32) {
+    let size = Faction::all()
+        .iter()
+        .flat_map(|faction| faction.player_ships())
+        .find(|ship| ship.type_id == type_id)
+        .map_or(64.0, |ship| ship.class.sprite_size())
+        * PLAYER_SIZE_BONUS;
+    let sprite = match sprite_cache.get(type_id) {
+        Some(image) => Sprite {
+            image,
+            color: Color::WHITE.with_alpha(GHOST_ALPHA),
+            custom_size: Some(Vec2::splat(size)),
+            ..default()
+        },
+        None => Sprite {
+            color: Color::srgba(0.6, 0.8, 1.0, GHOST_ALPHA),
+            custom_size: Some(Vec2::new(size * 0.85, size)),
+            ..default()
+        },
+    };
+    commands.spawn((
+        GhostShip,
+        sprite,
+        Transform::from_xyz(0.0, -250.0, LAYER_PLAYER - 0.5)
+            .with_rotation(Quat::from_rotation_z(get_ship_rotation_correction(type_id))),
+        Visibility::Hidden,
+    ));
+}
+
+/// Remove the ghost ship once the mission is over
+fn end_ghost_run(
+    mut commands: Commands,
+    mut transitions: EventReader<StateTransitionEvent<GameState>>,
+    mut race: ResMut<GhostRace>,
+    ghosts: Query<Entity, With<GhostShip>>,
+) {
+    for transition in transitions.read() {
+        let (Some(exited), Some(entered)) = (transition.exited, transition.entered) else {
+            continue;
+        };
+        if exited.in_mission() && !entered.in_mission() {
+            race.ghost = None;
+            for entity in ghosts.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
+/// Wave counter of the mode being flown
+pub fn current_wave(campaign: &CampaignState, endless: &EndlessMode) -> u32 {
+    if endless.active {
+        endless.wave
+    } else {
+        campaign.current_wave
+    }
+}
+
+/// Sample the player; the last position is held while there is no ship
+fn record_ghost(
+    time: Res<Time>,
+    score: Res<ScoreSystem>,
+    player: Query<&Transform, With<Player>>,
+    mut recorder: ResMut<GhostRecorder>,
+) {
+    recorder.clock += time.delta_secs();
+    while recorder.recording.next_sample_time() <= recorder.clock {
+        let position = player
+            .get_single()
+            .map(|transform| transform.translation.truncate())
+            .ok()
+            .or_else(|| recorder.recording.frames.last().map(GhostFrame::position))
+            .unwrap_or(Vec2::new(0.0, -250.0));
+        recorder
+            .recording
+            .frames
+            .push(GhostFrame::new(position, score.score));
+    }
+}
+
+/// Split at each new wave and boss phase
+fn record_splits(
+    campaign: Res<CampaignState>,
+    endless: Res<EndlessMode>,
+    score: Res<ScoreSystem>,
+    mut phases: EventReader<BossPhaseChangeEvent>,
+    mut recorder: ResMut<GhostRecorder>,
+    mut race: ResMut<GhostRace>,
+) {
+    let mut labels = Vec::new();
+    let wave = current_wave(&campaign, &endless);
+    if wave != recorder.wave {
+        recorder.wave = wave;
+        labels.push(format!("Wave {}", wave));
+    }
+    labels.extend(
+        phases
+            .read()
+            .map(|phase| format!("Boss phase {}", phase.phase)),
+    );
+
+    for label in labels {
+        let clock = recorder.clock;
+        if let Some(split) = race.ghost.as_ref().and_then(|ghost| ghost.split(&label)) {
+            race.last_split = Some(SplitDelta {
+                time: clock - split.time,
+                score: score.score as i64 - split.score as i64,
+                label: label.clone(),
+                at: clock,
+            });
+        }
+        recorder.recording.push_split(label, clock, score.score);
+    }
+}
+
+/// Put the ghost ship where the recording was at this time
+fn move_ghost(
+    recorder: Res<GhostRecorder>,
+    race: Res<GhostRace>,
+    mut ghosts: Query<(&mut Transform, &mut Visibility), With<GhostShip>>,
+) {
+    let position = race
+        .ghost
+        .as_ref()
+        .and_then(|ghost| ghost.position_at(recorder.clock));
+    for (mut transform, mut visibility) in ghosts.iter_mut() {
+        match position {
+            Some(position) => {
+                transform.translation.x = position.x;
+                transform.translation.y = position.y;
+                *visibility = Visibility::Visible;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
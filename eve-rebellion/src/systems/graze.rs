@@ -0,0 +1,93 @@
+//! Graze System
+//!
+//! Pays out near misses: every enemy bullet that passes inside the graze
+//! margin without hitting (see [`GrazeEvent`]) charges the capacitor and
+//! the Salt Miner meter, scores points and throws a spark with a soft tick.
+
+use bevy::prelude::*;
+
+use super::effects::ArmorSpark;
+use crate::core::*;
+use crate::entities::{Player, ShipStats};
+
+/// Graze plugin
+pub struct GrazePlugin;
+
+impl Plugin for GrazePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            reward_grazes.run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+        );
+    }
+}
+
+/// Sparks thrown per graze
+const GRAZE_SPARKS: u32 = 3;
+
+/// Graze sparks alive at once, so bullet curtains don't flood the screen
+const MAX_GRAZE_SPARKS: usize = 60;
+
+fn reward_grazes(
+    mut commands: Commands,
+    mut grazes: EventReader<GrazeEvent>,
+    mut player: Query<(&Transform, &mut ShipStats), With<Player>>,
+    sparks: Query<(), With<ArmorSpark>>,
+    mut salt_miner: ResMut<SaltMinerSystem>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+) {
+    let Ok((transform, mut stats)) = player.get_single_mut() else {
+        grazes.clear();
+        return;
+    };
+    let player_pos = transform.translation.truncate();
+    let mut spark_count = sparks.iter().count();
+
+    for graze in grazes.read() {
+        let charge = stats.max_capacitor * CAP_CHARGE_PER_GRAZE;
+        stats.capacitor = (stats.capacitor + charge).min(stats.max_capacitor);
+
+        if salt_miner.on_graze() > 0.0 && salt_miner.can_activate() {
+            info!("SALT MINER READY! Press B to activate! (filled by graze)");
+        }
+        score_events.send(ScoreEvent::graze(POINTS_PER_GRAZE));
+        sound_events.send(
+            PlaySoundEvent::new(SoundType::Graze)
+                .with_volume(0.35)
+                .at(graze.position),
+        );
+
+        if spark_count < MAX_GRAZE_SPARKS {
+            spawn_graze_sparks(&mut commands, graze.position, graze.position - player_pos);
+            spark_count += GRAZE_SPARKS as usize;
+        }
+    }
+}
+
+/// Small pale sparks flicking off the hull, away from the player
+fn spawn_graze_sparks(commands: &mut Commands, position: Vec2, away: Vec2) {
+    let base_angle = away.y.atan2(away.x);
+
+    for i in 0..GRAZE_SPARKS {
+        let spread = (i as f32 / (GRAZE_SPARKS - 1) as f32 - 0.5) * 0.8;
+        let angle = base_angle + spread + (fastrand::f32() - 0.5) * 0.2;
+        let speed = 60.0 + fastrand::f32() * 60.0;
+        let lifetime = 0.15 + fastrand::f32() * 0.1;
+
+        commands.spawn((
+            ArmorSpark {
+                velocity: Vec2::new(angle.cos() * speed, angle.sin() * speed),
+                lifetime,
+                max_lifetime: lifetime,
+            },
+            Sprite {
+                color: Color::srgb(0.75, 0.9, 1.0),
+                custom_size: Some(Vec2::new(3.0, 1.5)),
+                ..default()
+            },
+            Transform::from_xyz(position.x, position.y, LAYER_EFFECTS + 0.5)
+                .with_rotation(Quat::from_rotation_z(angle)),
+        ));
+    }
+}
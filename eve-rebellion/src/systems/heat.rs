@@ -1,22 +1,12 @@
-//! EVE Rebellion Enhanced Scoring System
+//! Weapon Heat
 //!
-//! Adds to existing SaltMinerSystem:
-//! - Combo: Consecutive kill chains with timer
-//! - Heat: Weapon overheating for bonus multiplier
+//! Firing builds heat; running hot raises the `Heat` score rule's
+//! multiplier, overheating slows the guns until they cool to half.
 
 #![allow(dead_code)]
 
 use bevy::prelude::*;
 
-/// Combo timeout in seconds
-pub const COMBO_TIMEOUT: f32 = 2.0;
-
-/// Combo bonus thresholds
-pub const COMBO_TIER_1: u32 = 5; // 1.2x
-pub const COMBO_TIER_2: u32 = 10; // 1.5x
-pub const COMBO_TIER_3: u32 = 20; // 2.0x
-pub const COMBO_TIER_4: u32 = 50; // 3.0x
-
 /// Heat level classification
 /// Heat system matches Python EVE Rebellion:
 /// - Overheat at 100, exit at 50
@@ -73,66 +63,35 @@ impl HeatLevel {
     }
 }
 
-/// Combo and Heat tracking (works with existing SaltMinerSystem)
-/// Heat values match Python EVE Rebellion
+/// Weapon heat (values match Python EVE Rebellion)
 #[derive(Resource, Debug)]
-pub struct ComboHeatSystem {
-    // === Combo System ===
-    pub combo_count: u32,
-    pub combo_timer: f32,
-    pub max_combo: u32,
-
-    // === Heat System (matches Python game) ===
+pub struct HeatSystem {
     pub heat: f32,
     pub heat_per_shot: f32,   // 2.0 per shot
     pub heat_decay_rate: f32, // ~72/second (1.2/frame at 60fps)
     pub heat_level: HeatLevel,
-
-    // === Stats ===
-    pub total_kills: u32,
-    pub souls_liberated: u32,
 }
 
-impl Default for ComboHeatSystem {
+impl Default for HeatSystem {
     fn default() -> Self {
         Self {
-            combo_count: 0,
-            combo_timer: 0.0,
-            max_combo: 0,
             heat: 0.0,
             heat_per_shot: 2.0,    // Python: 2.0 per shot
             heat_decay_rate: 72.0, // Python: 1.2/frame * 60fps
             heat_level: HeatLevel::Cool,
-            total_kills: 0,
-            souls_liberated: 0,
         }
     }
 }
 
-impl ComboHeatSystem {
+impl HeatSystem {
     /// Reset for new stage
     pub fn reset(&mut self) {
-        self.combo_count = 0;
-        self.combo_timer = 0.0;
         self.heat = 0.0;
         self.heat_level = HeatLevel::Cool;
-        self.total_kills = 0;
-        self.souls_liberated = 0;
     }
 
     /// Update per frame
     pub fn update(&mut self, dt: f32) {
-        // Update combo timer
-        if self.combo_count > 0 {
-            self.combo_timer -= dt;
-            if self.combo_timer <= 0.0 {
-                if self.combo_count > self.max_combo {
-                    self.max_combo = self.combo_count;
-                }
-                self.combo_count = 0;
-            }
-        }
-
         // Decay heat
         let was_overheated = self.heat_level == HeatLevel::Overheated;
         if self.heat > 0.0 {
@@ -142,7 +101,7 @@ impl ComboHeatSystem {
         self.heat_level = HeatLevel::from_heat(self.heat, was_overheated);
     }
 
-    /// Called when player fires weapon - returns true if can fire
+    /// Called when player fires weapon
     /// Note: firing is NEVER blocked, just slowed when overheated
     pub fn on_fire(&mut self) {
         let was_overheated = self.heat_level == HeatLevel::Overheated;
@@ -155,54 +114,16 @@ impl ComboHeatSystem {
         self.heat_level.fire_rate_mult()
     }
 
+    /// Score multiplier of the current heat level
+    pub fn score_mult(&self) -> f32 {
+        self.heat_level.score_multiplier()
+    }
+
     /// Check if currently overheated
     pub fn is_overheated(&self) -> bool {
         self.heat_level == HeatLevel::Overheated
     }
 
-    /// Called when enemy is killed - returns score multiplier
-    pub fn on_kill(&mut self) -> f32 {
-        self.total_kills += 1;
-        self.combo_count += 1;
-        self.combo_timer = COMBO_TIMEOUT;
-
-        self.combo_multiplier() * self.heat_level.score_multiplier()
-    }
-
-    /// Get current combo multiplier
-    pub fn combo_multiplier(&self) -> f32 {
-        if self.combo_count >= COMBO_TIER_4 {
-            3.0
-        } else if self.combo_count >= COMBO_TIER_3 {
-            2.0
-        } else if self.combo_count >= COMBO_TIER_2 {
-            1.5
-        } else if self.combo_count >= COMBO_TIER_1 {
-            1.2
-        } else {
-            1.0
-        }
-    }
-
-    /// Get combo tier name
-    pub fn combo_tier_name(&self) -> Option<&'static str> {
-        if self.combo_count >= 100 {
-            Some("GODLIKE!")
-        } else if self.combo_count >= COMBO_TIER_4 {
-            Some("UNSTOPPABLE!")
-        } else if self.combo_count >= 30 {
-            Some("RAMPAGE!")
-        } else if self.combo_count >= COMBO_TIER_3 {
-            Some("DOMINATING!")
-        } else if self.combo_count >= COMBO_TIER_2 {
-            Some("KILLING SPREE!")
-        } else if self.combo_count >= COMBO_TIER_1 {
-            Some("COMBO!")
-        } else {
-            None
-        }
-    }
-
     /// Reduce heat (from nanite powerup)
     pub fn reduce_heat(&mut self, amount: f32) {
         let was_overheated = self.heat_level == HeatLevel::Overheated;
@@ -214,29 +135,6 @@ impl ComboHeatSystem {
     pub fn heat_percent(&self) -> f32 {
         self.heat / 100.0
     }
-
-    /// Get combo timer percentage remaining (0.0 - 1.0)
-    pub fn combo_timer_percent(&self) -> f32 {
-        if self.combo_count > 0 {
-            self.combo_timer / COMBO_TIMEOUT
-        } else {
-            0.0
-        }
-    }
-}
-
-/// Plugin to register combo/heat system
-pub struct ScoringSystemPlugin;
-
-impl Plugin for ScoringSystemPlugin {
-    fn build(&self, app: &mut App) {
-        app.init_resource::<ComboHeatSystem>()
-            .add_systems(Update, update_combo_heat_system);
-    }
-}
-
-fn update_combo_heat_system(time: Res<Time>, mut system: ResMut<ComboHeatSystem>) {
-    system.update(time.delta_secs());
 }
 
 #[cfg(test)]
@@ -303,12 +201,11 @@ mod tests {
         assert_eq!(HeatLevel::Overheated.fire_rate_mult(), 0.7); // 30% slower
     }
 
-    // ==================== ComboHeatSystem Tests ====================
+    // ==================== HeatSystem Tests ====================
 
     #[test]
-    fn combo_heat_default_values() {
-        let c = ComboHeatSystem::default();
-        assert_eq!(c.combo_count, 0);
+    fn heat_system_default_values() {
+        let c = HeatSystem::default();
         assert_eq!(c.heat, 0.0);
         assert_eq!(c.heat_per_shot, 2.0);
         assert_eq!(c.heat_decay_rate, 72.0);
@@ -316,92 +213,8 @@ mod tests {
     }
 
     #[test]
-    fn combo_heat_on_kill_increments_combo() {
-        let mut c = ComboHeatSystem::default();
-        let mult = c.on_kill();
-        assert_eq!(c.combo_count, 1);
-        assert_eq!(c.combo_timer, COMBO_TIMEOUT);
-        assert_eq!(mult, 1.0); // Below tier 1 threshold
-    }
-
-    #[test]
-    fn combo_heat_combo_timer_decay() {
-        let mut c = ComboHeatSystem::default();
-        c.on_kill();
-        assert_eq!(c.combo_count, 1);
-
-        c.update(2.1); // Exceed COMBO_TIMEOUT
-        assert_eq!(c.combo_count, 0);
-    }
-
-    #[test]
-    fn combo_heat_max_combo_tracking() {
-        let mut c = ComboHeatSystem::default();
-        for _ in 0..10 {
-            c.on_kill();
-        }
-        assert_eq!(c.combo_count, 10);
-        assert_eq!(c.max_combo, 0); // Not updated until combo ends
-
-        c.update(2.1); // Let combo expire
-        assert_eq!(c.max_combo, 10);
-        assert_eq!(c.combo_count, 0);
-    }
-
-    #[test]
-    fn combo_heat_combo_multiplier_tiers() {
-        let mut c = ComboHeatSystem::default();
-
-        // Below tier 1
-        c.combo_count = 4;
-        assert_eq!(c.combo_multiplier(), 1.0);
-
-        // Tier 1: 5+
-        c.combo_count = 5;
-        assert_eq!(c.combo_multiplier(), 1.2);
-
-        // Tier 2: 10+
-        c.combo_count = 10;
-        assert_eq!(c.combo_multiplier(), 1.5);
-
-        // Tier 3: 20+
-        c.combo_count = 20;
-        assert_eq!(c.combo_multiplier(), 2.0);
-
-        // Tier 4: 50+
-        c.combo_count = 50;
-        assert_eq!(c.combo_multiplier(), 3.0);
-    }
-
-    #[test]
-    fn combo_heat_tier_names() {
-        let mut c = ComboHeatSystem::default();
-
-        c.combo_count = 4;
-        assert_eq!(c.combo_tier_name(), None);
-
-        c.combo_count = 5;
-        assert_eq!(c.combo_tier_name(), Some("COMBO!"));
-
-        c.combo_count = 10;
-        assert_eq!(c.combo_tier_name(), Some("KILLING SPREE!"));
-
-        c.combo_count = 20;
-        assert_eq!(c.combo_tier_name(), Some("DOMINATING!"));
-
-        c.combo_count = 30;
-        assert_eq!(c.combo_tier_name(), Some("RAMPAGE!"));
-
-        c.combo_count = 50;
-        assert_eq!(c.combo_tier_name(), Some("UNSTOPPABLE!"));
-
-        c.combo_count = 100;
-        assert_eq!(c.combo_tier_name(), Some("GODLIKE!"));
-    }
-
-    #[test]
-    fn combo_heat_on_fire_adds_heat() {
-        let mut c = ComboHeatSystem::default();
+    fn heat_system_on_fire_adds_heat() {
+        let mut c = HeatSystem::default();
         c.on_fire();
         assert_eq!(c.heat, 2.0);
 
@@ -410,8 +223,8 @@ mod tests {
     }
 
     #[test]
-    fn combo_heat_heat_caps_at_100() {
-        let mut c = ComboHeatSystem::default();
+    fn heat_system_heat_caps_at_100() {
+        let mut c = HeatSystem::default();
         for _ in 0..100 {
             c.on_fire();
         }
@@ -420,8 +233,8 @@ mod tests {
     }
 
     #[test]
-    fn combo_heat_heat_decay() {
-        let mut c = ComboHeatSystem::default();
+    fn heat_system_heat_decay() {
+        let mut c = HeatSystem::default();
         c.heat = 50.0;
 
         c.update(0.5); // Decay 36 units (72 * 0.5)
@@ -429,8 +242,8 @@ mod tests {
     }
 
     #[test]
-    fn combo_heat_heat_does_not_go_negative() {
-        let mut c = ComboHeatSystem::default();
+    fn heat_system_heat_does_not_go_negative() {
+        let mut c = HeatSystem::default();
         c.heat = 10.0;
 
         c.update(1.0); // Would decay 72 units, but capped at 0
@@ -438,8 +251,8 @@ mod tests {
     }
 
     #[test]
-    fn combo_heat_hysteresis_in_update() {
-        let mut c = ComboHeatSystem::default();
+    fn heat_system_hysteresis_in_update() {
+        let mut c = HeatSystem::default();
 
         // Heat up to overheated
         for _ in 0..50 {
@@ -460,8 +273,8 @@ mod tests {
     }
 
     #[test]
-    fn combo_heat_fire_rate_when_overheated() {
-        let mut c = ComboHeatSystem::default();
+    fn heat_system_fire_rate_when_overheated() {
+        let mut c = HeatSystem::default();
         assert_eq!(c.fire_rate_mult(), 1.0);
 
         // Overheat
@@ -473,8 +286,8 @@ mod tests {
     }
 
     #[test]
-    fn combo_heat_reduce_heat() {
-        let mut c = ComboHeatSystem::default();
+    fn heat_system_reduce_heat() {
+        let mut c = HeatSystem::default();
         c.heat = 80.0;
         c.heat_level = HeatLevel::Hot;
 
@@ -484,8 +297,8 @@ mod tests {
     }
 
     #[test]
-    fn combo_heat_reduce_heat_not_negative() {
-        let mut c = ComboHeatSystem::default();
+    fn heat_system_reduce_heat_not_negative() {
+        let mut c = HeatSystem::default();
         c.heat = 20.0;
 
         c.reduce_heat(50.0);
@@ -493,46 +306,20 @@ mod tests {
     }
 
     #[test]
-    fn combo_heat_percentages() {
-        let mut c = ComboHeatSystem::default();
+    fn heat_system_percentage() {
+        let mut c = HeatSystem::default();
         c.heat = 50.0;
         assert_eq!(c.heat_percent(), 0.5);
-
-        c.combo_count = 5;
-        c.combo_timer = 1.0;
-        assert_eq!(c.combo_timer_percent(), 0.5); // 1.0 / 2.0
-    }
-
-    #[test]
-    fn combo_heat_on_kill_returns_combined_multiplier() {
-        let mut c = ComboHeatSystem::default();
-        c.heat = 80.0; // Hot = 1.5x
-        c.heat_level = HeatLevel::from_heat(c.heat, false);
-
-        // Get to tier 1 combo (5 kills)
-        for _ in 0..4 {
-            c.on_kill();
-        }
-        let mult = c.on_kill(); // 5th kill
-
-        // combo_multiplier (1.2) × heat_multiplier (1.5) = 1.8
-        assert!((mult - 1.8).abs() < 0.01);
     }
 
     #[test]
-    fn combo_heat_reset() {
-        let mut c = ComboHeatSystem::default();
-        c.combo_count = 10;
+    fn heat_system_reset() {
+        let mut c = HeatSystem::default();
         c.heat = 80.0;
-        c.total_kills = 100;
-        c.souls_liberated = 50;
 
         c.reset();
 
-        assert_eq!(c.combo_count, 0);
         assert_eq!(c.heat, 0.0);
-        assert_eq!(c.total_kills, 0);
-        assert_eq!(c.souls_liberated, 0);
         assert_eq!(c.heat_level, HeatLevel::Cool);
     }
 }
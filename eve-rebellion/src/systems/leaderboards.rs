@@ -0,0 +1,387 @@
+//! Leaderboard System
+//!
+//! Works out which board a mission belongs to when it starts (the mode
+//! flags are cleared again by the result screens), and when the run
+//! finishes submits it to the local [`Leaderboards`], keeping the ghost
+//! recordings of each board's best runs. Player input is logged through
+//! the mission; when a shared leaderboard is configured the run and its
+//! input log are sent there on a background thread, and the leaderboard
+//! screen fetches the shared boards the same way.
+
+use bevy::prelude::*;
+use std::sync::{Arc, Mutex};
+
+use super::ghost::GhostRecorder;
+use crate::core::*;
+use crate::games::caldari_gallente::{LastStandState, ShiigeruNightmare};
+use crate::games::{abyssal_depths, roguelite, ActiveModule};
+use crate::systems::JoystickState;
+
+/// Leaderboard plugin
+pub struct LeaderboardPlugin;
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Leaderboards>()
+            .init_resource::<LeaderboardRun>()
+            .init_resource::<InputLog>()
+            .init_resource::<SharedBoards>()
+            .add_systems(Startup, load_leaderboards)
+            .add_systems(
+                Update,
+                (track_leaderboard_run, record_leaderboard_entry).chain(),
+            )
+            .add_systems(
+                Update,
+                record_input
+                    .after(track_leaderboard_run)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// Board and seed of the run in progress
+#[derive(Resource, Default, Debug)]
+pub struct LeaderboardRun {
+    pub mode: Option<LeaderboardMode>,
+    pub difficulty: Difficulty,
+    pub seed: Option<u32>,
+    pub ship_type_id: u32,
+}
+
+/// Load the boards; survival bests come from them, as the modes themselves
+/// only keep bests in memory
+fn load_leaderboards(
+    mut commands: Commands,
+    mut endless: ResMut<EndlessMode>,
+    nightmare: Option<ResMut<ShiigeruNightmare>>,
+) {
+    let boards = Leaderboards::load();
+    (endless.best_wave, endless.best_time) = boards.best_survival(&LeaderboardMode::Endless);
+    if let Some(mut nightmare) = nightmare {
+        (nightmare.best_wave, nightmare.best_time) =
+            boards.best_survival(&LeaderboardMode::Nightmare);
+    }
+    commands.insert_resource(boards);
+}
+
+/// Resolve the board when a mission starts
+pub fn track_leaderboard_run(
+    mut transitions: EventReader<StateTransitionEvent<GameState>>,
+    mut run: ResMut<LeaderboardRun>,
+    mut input_log: ResMut<InputLog>,
+    difficulty: Res<Difficulty>,
+    session: Res<GameSession>,
+    campaign: Res<CampaignState>,
+    active: Res<ActiveModule>,
+    endless: Res<EndlessMode>,
+    nightmare: Option<Res<ShiigeruNightmare>>,
+    last_stand: Option<Res<LastStandState>>,
+    sector: Option<Res<RogueliteRun>>,
+    challenge: Res<ChallengeRun>,
+) {
+    for transition in transitions.read() {
+        let (Some(exited), Some(entered)) = (transition.exited, transition.entered) else {
+            continue;
+        };
+        if exited.in_mission() || !entered.in_mission() {
+            continue;
+        }
+
+        let module = active.module_id.as_deref().unwrap_or_default();
+        let sector_seed = sector
+            .as_ref()
+            .filter(|run| run.active && module == roguelite::MODULE_ID)
+            .map(|run| run.seed());
+        let mode = if let Some(current) = &challenge.challenge {
+            // Practice attempts are not ranked anywhere
+            challenge.ranked.then(|| LeaderboardMode::Challenge {
+                title: current.title(),
+            })
+        } else if nightmare.as_ref().is_some_and(|n| n.active) {
+            Some(LeaderboardMode::Nightmare)
+        } else if last_stand.as_ref().is_some_and(|l| l.active) {
+            Some(LeaderboardMode::LastStand)
+        } else if endless.active {
+            Some(LeaderboardMode::Endless)
+        } else if module == abyssal_depths::MODULE_ID {
+            Some(LeaderboardMode::Abyssal)
+        } else if sector_seed.is_some() {
+            Some(LeaderboardMode::Sector)
+        } else {
+            Some(LeaderboardMode::Mission {
+                module: module.to_string(),
+                mission: campaign.current_mission_name().to_string(),
+            })
+        };
+        *run = LeaderboardRun {
+            mode,
+            difficulty: *difficulty,
+            seed: challenge.challenge.as_ref().map(|c| c.seed).or(sector_seed),
+            ship_type_id: session.selected_ship().type_id,
+        };
+        input_log.clear();
+    }
+}
+
+/// Log this frame's input. Frame time is real time, so the log length is
+/// the run time divided by the game speed.
+fn record_input(
+    time: Res<Time<Real>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut input_log: ResMut<InputLog>,
+) {
+    let keys = LOGGED_KEYS
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| keys.pressed(**key))
+        .fold(0, |bits, (i, _)| bits | 1 << i);
+    let buttons = joystick
+        .buttons
+        .iter()
+        .enumerate()
+        .filter(|(_, held)| **held)
+        .fold(0, |bits, (i, _)| bits | 1 << i);
+    input_log.push(InputFrame {
+        dt_ms: time.delta().as_millis().min(u16::MAX as u128) as u16,
+        keys,
+        buttons,
+        sticks: [
+            quantize_axis(joystick.left_x),
+            quantize_axis(joystick.left_y),
+            quantize_axis(joystick.right_x),
+            quantize_axis(joystick.right_y),
+        ],
+        triggers: [
+            quantize_trigger(joystick.left_trigger),
+            quantize_trigger(joystick.right_trigger),
+        ],
+    });
+}
+
+/// Submit finished runs to their board
+fn record_leaderboard_entry(
+    mut finished: EventReader<RunFinishedEvent>,
+    mut run: ResMut<LeaderboardRun>,
+    mut boards: ResMut<Leaderboards>,
+    profiles: Res<PilotProfiles>,
+    endless: Res<EndlessMode>,
+    nightmare: Option<Res<ShiigeruNightmare>>,
+    save_data: Res<SaveData>,
+    accessibility: Res<AccessibilitySettings>,
+    input_log: Res<InputLog>,
+    recorder: Res<GhostRecorder>,
+) {
+    for event in finished.read() {
+        let Some(mode) = run.mode.take() else {
+            continue;
+        };
+        let summary = &event.summary;
+        let ranked = match summary.outcome {
+            RunResult::Completed => true,
+            RunResult::Failed => mode.survival(),
+            RunResult::Left => false,
+        };
+        if !ranked || summary.score == 0 {
+            continue;
+        }
+
+        let wave = match mode {
+            LeaderboardMode::Endless | LeaderboardMode::Challenge { .. } => endless.wave,
+            LeaderboardMode::Nightmare => nightmare.as_ref().map_or(0, |n| n.wave),
+            _ => 0,
+        };
+        let mut entry = LeaderboardEntry {
+            pilot: profiles
+                .active_profile()
+                .map_or_else(|| "Pilot".to_string(), |p| p.name.clone()),
+            ship_type_id: run.ship_type_id,
+            ship: summary.ship.clone(),
+            score: summary.score,
+            time: summary.duration,
+            wave,
+            grade: StyleGrade::from_multiplier(summary.peak_multiplier)
+                .as_str()
+                .to_string(),
+            timestamp: summary.timestamp,
+            seed: run.seed,
+            replay: None,
+            input_hash: None,
+            has_input_log: false,
+        };
+        if let Some(backend) = backend_for(&save_data.settings.leaderboard_server) {
+            let submission = ScoreSubmission {
+                mode: mode.clone(),
+                entry: entry.clone(),
+                settings: RunSettings {
+                    difficulty: run.difficulty,
+                    game_speed: accessibility.game_speed,
+                    fire_mode: accessibility.fire_mode,
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                input_hash: input_log.hash(),
+            };
+            share_run(backend, submission, input_log.clone());
+        }
+
+        // Ghosts stay local; the shared copy goes without one
+        let replay = ghost_id(&entry);
+        entry.replay = Some(replay.clone());
+        let name = mode.name();
+        if let Some(rank) = boards.submit(mode, run.difficulty, entry) {
+            info!(
+                "Leaderboard: {} rank {} ({})",
+                name,
+                rank + 1,
+                summary.score
+            );
+            for id in boards.prune_replays(GHOSTS_PER_BOARD) {
+                if id != replay {
+                    remove_ghost(&id);
+                }
+            }
+            if rank < GHOSTS_PER_BOARD {
+                recorder.recording.save(&replay);
+            }
+            boards.save();
+        }
+    }
+}
+
+/// Upload the input log, then the score, off the main thread
+fn share_run(backend: Box<dyn LeaderboardBackend>, submission: ScoreSubmission, log: InputLog) {
+    std::thread::spawn(move || {
+        let result = backend
+            .upload_input_log(&log)
+            .and_then(|_| backend.submit(&submission));
+        match result {
+            Ok(Some(rank)) => info!(
+                "Shared leaderboard {}: {} rank {}",
+                backend.describe(),
+                submission.mode.name(),
+                rank + 1
+            ),
+            Ok(None) => info!(
+                "Shared leaderboard {}: {} not ranked",
+                backend.describe(),
+                submission.mode.name()
+            ),
+            Err(e) => warn!("Shared leaderboard {}: {}", backend.describe(), e),
+        }
+    });
+}
+
+/// Shared boards as last fetched for the leaderboard screen. Fetches run on
+/// a background thread; [`SharedBoards::poll`] picks up the answer.
+#[derive(Resource, Default)]
+pub struct SharedBoards {
+    /// Boards of the last successful fetch
+    pub boards: Leaderboards,
+    /// Where they came from, or what went wrong
+    pub status: String,
+    pending: Option<Arc<Mutex<Option<SharedReply>>>>,
+}
+
+/// Answer of a background fetch
+enum SharedReply {
+    Boards {
+        source: String,
+        result: Result<Leaderboards, BackendError>,
+    },
+    InputLog {
+        pilot: String,
+        hash: String,
+        result: Result<Option<InputLog>, BackendError>,
+    },
+}
+
+impl SharedBoards {
+    /// Fetch every board from the configured location
+    pub fn fetch(&mut self, location: &str) {
+        self.request(location, |backend| SharedReply::Boards {
+            source: backend.describe(),
+            result: backend.fetch(),
+        });
+    }
+
+    /// Fetch the input log of a shared entry and check it against its hash
+    pub fn fetch_input_log(&mut self, location: &str, entry: &LeaderboardEntry) {
+        let Some(hash) = entry.input_hash.clone() else {
+            self.status = format!("No input log for {}", entry.pilot);
+            return;
+        };
+        let pilot = entry.pilot.clone();
+        self.request(location, move |backend| {
+            let result = backend.fetch_input_log(&hash);
+            SharedReply::InputLog {
+                pilot,
+                hash,
+                result,
+            }
+        });
+    }
+
+    fn request(
+        &mut self,
+        location: &str,
+        call: impl FnOnce(&dyn LeaderboardBackend) -> SharedReply + Send + 'static,
+    ) {
+        if self.pending.is_some() {
+            return;
+        }
+        let Some(backend) = backend_for(location) else {
+            self.status = "No shared leaderboard set in Options".to_string();
+            return;
+        };
+        self.status = format!("Fetching from {}...", backend.describe());
+        let slot = Arc::new(Mutex::new(None));
+        self.pending = Some(Arc::clone(&slot));
+        std::thread::spawn(move || {
+            let reply = call(backend.as_ref());
+            if let Ok(mut slot) = slot.lock() {
+                *slot = Some(reply);
+            }
+        });
+    }
+
+    /// Take the answer of a finished fetch; true when one came in
+    pub fn poll(&mut self) -> bool {
+        let Some(reply) = self
+            .pending
+            .as_ref()
+            .and_then(|slot| slot.lock().ok()?.take())
+        else {
+            return false;
+        };
+        self.pending = None;
+        self.status = match reply {
+            SharedReply::Boards { source, result } => match result {
+                Ok(boards) => {
+                    self.boards = boards;
+                    format!("Shared: {}", source)
+                }
+                Err(e) => {
+                    warn!("Shared leaderboard {}: {}", source, e);
+                    format!("{}: {}", source, e)
+                }
+            },
+            SharedReply::InputLog {
+                pilot,
+                hash,
+                result,
+            } => match result {
+                Ok(Some(log)) if log.hash() == hash => format!(
+                    "Input log of {}: {} frames, {:.0}s of input",
+                    pilot,
+                    log.frames.len(),
+                    log.duration_ms() as f32 / 1000.0
+                ),
+                Ok(Some(_)) => format!("Input log of {} does not match its hash", pilot),
+                Ok(None) => format!("Input log of {} is not stored", pilot),
+                Err(e) => format!("Input log of {}: {}", pilot, e),
+            },
+        };
+        true
+    }
+}
@@ -0,0 +1,136 @@
+//! Medal System
+//!
+//! Judges kills, grazes and waves for style medals (see
+//! [`crate::core::medals`]), pays them out through the scoring rules and
+//! pops each one up where it was earned.
+
+use bevy::prelude::*;
+
+use super::effects::DamageNumber;
+use super::ghost::current_wave;
+use super::ManeuverState;
+use crate::core::*;
+use crate::entities::Player;
+
+/// Medal plugin
+pub struct MedalPlugin;
+
+impl Plugin for MedalPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MedalEvent>()
+            .add_event::<KillShotEvent>()
+            .add_event::<GrazeEvent>()
+            .init_resource::<StyleTracker>()
+            .add_systems(Update, reset_style_tracker)
+            .add_systems(
+                Update,
+                (judge_kills, judge_grazes, judge_waves, pay_medals)
+                    .chain()
+                    .after(reset_style_tracker)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// Seconds a medal popup stays up
+const MEDAL_POPUP_TIME: f32 = 1.2;
+
+/// Forget the last mission's kills when a new one starts
+fn reset_style_tracker(
+    mut transitions: EventReader<StateTransitionEvent<GameState>>,
+    mut tracker: ResMut<StyleTracker>,
+) {
+    for transition in transitions.read() {
+        let (Some(exited), Some(entered)) = (transition.exited, transition.entered) else {
+            continue;
+        };
+        if !exited.in_mission() && entered.in_mission() {
+            tracker.reset();
+        }
+    }
+}
+
+fn judge_kills(
+    time: Res<Time>,
+    mut kills: EventReader<KillShotEvent>,
+    player: Query<&ManeuverState, With<Player>>,
+    mut tracker: ResMut<StyleTracker>,
+    mut medals: EventWriter<MedalEvent>,
+) {
+    let rolling = player
+        .get_single()
+        .is_ok_and(|maneuver| maneuver.invincible);
+    for kill in kills.read() {
+        for medal in tracker.judge_kill(kill, time.elapsed_secs(), rolling) {
+            medals.send(MedalEvent {
+                medal,
+                position: kill.position,
+            });
+        }
+    }
+}
+
+fn judge_grazes(mut grazes: EventReader<GrazeEvent>, mut medals: EventWriter<MedalEvent>) {
+    for graze in grazes.read() {
+        medals.send(MedalEvent {
+            medal: Medal::Graze,
+            position: graze.position,
+        });
+    }
+}
+
+/// No-miss check whenever the next wave comes in
+fn judge_waves(
+    campaign: Res<CampaignState>,
+    endless: Res<EndlessMode>,
+    stats: Res<RunStats>,
+    player: Query<&Transform, With<Player>>,
+    mut tracker: ResMut<StyleTracker>,
+    mut medals: EventWriter<MedalEvent>,
+) {
+    let wave = current_wave(&campaign, &endless);
+    if tracker.judge_wave(wave, stats.shots_fired, stats.shots_hit) {
+        let position = player
+            .get_single()
+            .map_or(Vec2::ZERO, |transform| transform.translation.truncate());
+        medals.send(MedalEvent {
+            medal: Medal::NoMissWave,
+            position,
+        });
+    }
+}
+
+/// Style points, score bonus and popup for each medal
+fn pay_medals(
+    mut commands: Commands,
+    mut medals: EventReader<MedalEvent>,
+    mut score: ResMut<ScoreSystem>,
+    mut score_events: EventWriter<ScoreEvent>,
+) {
+    for event in medals.read() {
+        let medal = event.medal;
+        score.style_points += medal.style_points();
+        if medal.bonus() > 0 {
+            score_events.send(ScoreEvent::medal(medal.bonus()));
+        }
+
+        commands.spawn((
+            DamageNumber {
+                velocity: Vec2::new(0.0, 50.0),
+                lifetime: MEDAL_POPUP_TIME,
+                max_lifetime: MEDAL_POPUP_TIME,
+            },
+            Text2d::new(medal.name()),
+            TextFont {
+                font_size: if medal == Medal::Graze { 11.0 } else { 16.0 },
+                ..default()
+            },
+            TextColor(medal.color()),
+            Transform::from_xyz(
+                event.position.x,
+                event.position.y + 36.0,
+                LAYER_EFFECTS + 6.0,
+            ),
+        ));
+    }
+}
@@ -0,0 +1,548 @@
+//! Audio Mixer
+//!
+//! Every sound effect is requested through `PlaySoundEvent` and mixed here:
+//! - Sub-buses (weapons, explosions, UI, warnings) under the SFX volume
+//! - Stereo panning from the emitter's screen x position
+//! - Distance attenuation (from the player) and priority-based crowd attenuation
+//! - Per-sound-type voice limits with voice stealing
+//! - Ducking of SFX and music under dialogue and boss intros
+
+#![allow(dead_code)]
+
+use bevy::audio::{AudioSinkPlayback, PlaybackMode, Volume};
+use bevy::prelude::*;
+
+use crate::core::*;
+use crate::systems::audio::{SoundAssets, SoundSettings};
+use crate::systems::dialogue::DialogueSystem;
+use crate::systems::music::MusicTrack;
+use crate::systems::sound_pack::SoundPack;
+
+/// Hard cap on simultaneous sound effect voices
+pub const MAX_VOICES: usize = 24;
+
+/// Active voices allowed before low-priority sounds start getting quieter
+pub const CROWD_HEADROOM: usize = 8;
+
+/// Distance (pixels) at which sounds reach their minimum distance gain
+pub const MAX_AUDIBLE_DISTANCE: f32 = 900.0;
+
+/// Quietest a sound gets from distance alone
+pub const MIN_DISTANCE_GAIN: f32 = 0.35;
+
+/// How far sounds can pan from center (0 = mono, 1 = hard left/right)
+pub const PAN_WIDTH: f32 = 0.8;
+
+/// Gain applied to ducked buses while dialogue or a boss intro is up
+pub const DUCK_LEVEL: f32 = 0.4;
+
+/// Duck attack/release speeds (gain units per second)
+const DUCK_ATTACK_RATE: f32 = 4.0;
+const DUCK_RELEASE_RATE: f32 = 1.5;
+
+/// Distance between the listener's ears (spatial audio units)
+const EAR_GAP: f32 = 1.0;
+
+/// Mixer plugin
+pub struct MixerPlugin;
+
+impl Plugin for MixerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AudioMixer>()
+            .add_systems(Startup, spawn_audio_listener)
+            .add_systems(
+                Update,
+                (
+                    prune_finished_voices,
+                    update_ducking,
+                    mix_sound_events,
+                    apply_music_ducking,
+                )
+                    .chain()
+                    .in_set(AudioMixSet),
+            );
+    }
+}
+
+/// System set for the mixer - sound producers run before it
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AudioMixSet;
+
+/// Mixer sub-buses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioBus {
+    Weapons,
+    Explosions,
+    Ui,
+    Warnings,
+    /// Impacts, pickups, abilities and game events (SFX volume only)
+    Gameplay,
+}
+
+impl AudioBus {
+    /// Sub-bus volume from settings
+    pub fn volume(&self, settings: &SoundSettings) -> f32 {
+        match self {
+            AudioBus::Weapons => settings.weapons_volume,
+            AudioBus::Explosions => settings.explosions_volume,
+            AudioBus::Ui => settings.ui_volume,
+            AudioBus::Warnings => settings.warnings_volume,
+            AudioBus::Gameplay => 1.0,
+        }
+    }
+
+    /// Whether this bus is ducked under dialogue (UI and alarms stay audible)
+    pub fn ducks(&self) -> bool {
+        matches!(
+            self,
+            AudioBus::Weapons | AudioBus::Explosions | AudioBus::Gameplay
+        )
+    }
+}
+
+/// Voice priority - higher priorities steal from lower ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SoundPriority {
+    Low,
+    Normal,
+    High,
+    Critical,
+}
+
+impl SoundPriority {
+    /// Gain lost per voice above the crowd headroom
+    fn crowd_falloff(&self) -> f32 {
+        match self {
+            SoundPriority::Low => 0.08,
+            SoundPriority::Normal => 0.04,
+            SoundPriority::High | SoundPriority::Critical => 0.0,
+        }
+    }
+}
+
+impl SoundType {
+    /// Which mixer bus this sound plays on
+    pub fn bus(&self) -> AudioBus {
+        match self {
+            SoundType::Autocannon
+            | SoundType::Artillery
+            | SoundType::Laser
+            | SoundType::Missile => AudioBus::Weapons,
+            SoundType::SmallExplosion | SoundType::MediumExplosion | SoundType::LargeExplosion => {
+                AudioBus::Explosions
+            }
+            SoundType::MenuSelect | SoundType::MenuConfirm | SoundType::MenuBack => AudioBus::Ui,
+            SoundType::Warning
+            | SoundType::ShieldWarning
+            | SoundType::ArmorWarning
+            | SoundType::HullWarning
+            | SoundType::BossSpawn
+            | SoundType::BossPhase => AudioBus::Warnings,
+            _ => AudioBus::Gameplay,
+        }
+    }
+
+    /// Voice priority
+    pub fn priority(&self) -> SoundPriority {
+        match self {
+            SoundType::Autocannon
+            | SoundType::Artillery
+            | SoundType::Laser
+            | SoundType::Missile
+            | SoundType::SmallExplosion
+            | SoundType::Graze => SoundPriority::Low,
+            SoundType::Warning
+            | SoundType::ShieldWarning
+            | SoundType::ArmorWarning
+            | SoundType::HullWarning
+            | SoundType::BossSpawn
+            | SoundType::Victory
+            | SoundType::GameOver => SoundPriority::Critical,
+            SoundType::LargeExplosion
+            | SoundType::WaveComplete
+            | SoundType::WaveStart
+            | SoundType::BossPhase
+            | SoundType::MenuSelect
+            | SoundType::MenuConfirm
+            | SoundType::MenuBack => SoundPriority::High,
+            _ => SoundPriority::Normal,
+        }
+    }
+
+    /// Maximum simultaneous voices of this sound
+    pub fn voice_limit(&self) -> usize {
+        match self {
+            SoundType::Autocannon | SoundType::Artillery | SoundType::Laser => 4,
+            SoundType::Missile => 3,
+            SoundType::SmallExplosion => 6,
+            SoundType::MediumExplosion => 4,
+            SoundType::LargeExplosion => 2,
+            SoundType::ShieldHit | SoundType::ArmorHit | SoundType::HullHit => 3,
+            _ => 2,
+        }
+    }
+}
+
+/// A playing sound effect tracked by the mixer
+#[derive(Debug, Clone)]
+pub struct Voice {
+    pub entity: Entity,
+    pub sound: SoundType,
+    pub priority: SoundPriority,
+    pub started: f32,
+}
+
+/// What to do with a new voice request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiceDecision {
+    Play,
+    /// Stop this voice to make room
+    Steal(Entity),
+    Reject,
+}
+
+/// Marker for sound effect entities spawned by the mixer
+#[derive(Component)]
+pub struct MixerVoice;
+
+/// Mixer state: active voices and current duck level
+#[derive(Resource, Debug)]
+pub struct AudioMixer {
+    pub voices: Vec<Voice>,
+    /// Current duck gain (1.0 = not ducked)
+    pub duck: f32,
+}
+
+impl Default for AudioMixer {
+    fn default() -> Self {
+        Self {
+            voices: Vec::new(),
+            duck: 1.0,
+        }
+    }
+}
+
+impl AudioMixer {
+    /// Number of playing voices of a sound type
+    pub fn count(&self, sound: SoundType) -> usize {
+        self.voices.iter().filter(|v| v.sound == sound).count()
+    }
+
+    /// Decide whether a new sound can play, and which voice it steals
+    pub fn decide(&self, sound: SoundType, priority: SoundPriority) -> VoiceDecision {
+        // Per-type limit: the oldest voice of the same sound makes way
+        if self.count(sound) >= sound.voice_limit() {
+            return self
+                .voices
+                .iter()
+                .filter(|v| v.sound == sound)
+                .min_by(|a, b| a.started.total_cmp(&b.started))
+                .map_or(VoiceDecision::Reject, |v| VoiceDecision::Steal(v.entity));
+        }
+
+        // Global limit: steal the lowest-priority, oldest voice if we outrank it
+        if self.voices.len() >= MAX_VOICES {
+            let victim = self.voices.iter().min_by(|a, b| {
+                a.priority
+                    .cmp(&b.priority)
+                    .then(a.started.total_cmp(&b.started))
+            });
+            return match victim {
+                Some(v) if v.priority <= priority => VoiceDecision::Steal(v.entity),
+                _ => VoiceDecision::Reject,
+            };
+        }
+
+        VoiceDecision::Play
+    }
+
+    pub fn add_voice(&mut self, entity: Entity, sound: SoundType, started: f32) {
+        self.voices.push(Voice {
+            entity,
+            sound,
+            priority: sound.priority(),
+            started,
+        });
+    }
+
+    pub fn remove_voice(&mut self, entity: Entity) {
+        self.voices.retain(|v| v.entity != entity);
+    }
+}
+
+/// Stereo pan (-1 left .. 1 right) from a world x position
+pub fn pan_from_x(x: f32) -> f32 {
+    (x / (SCREEN_WIDTH / 2.0)).clamp(-1.0, 1.0) * PAN_WIDTH
+}
+
+/// Gain from distance between emitter and player
+pub fn distance_attenuation(distance: f32) -> f32 {
+    (1.0 - distance / MAX_AUDIBLE_DISTANCE).clamp(MIN_DISTANCE_GAIN, 1.0)
+}
+
+/// Gain for a sound given how many voices are already playing
+pub fn crowd_attenuation(active_voices: usize, priority: SoundPriority) -> f32 {
+    let excess = active_voices.saturating_sub(CROWD_HEADROOM) as f32;
+    1.0 / (1.0 + excess * priority.crowd_falloff())
+}
+
+/// Emitter offset from the listener for a given pan.
+///
+/// Emitters stay between the ears so rodio applies no distance falloff. Rodio 0.19's
+/// spatial source boosts the ear *farther* from the emitter, so the offset is mirrored.
+fn emitter_translation(pan: f32) -> Vec3 {
+    Vec3::new(-pan * EAR_GAP / 2.0, 0.0, 0.0)
+}
+
+/// Spawn the spatial listener used for stereo panning
+fn spawn_audio_listener(mut commands: Commands) {
+    commands.spawn((SpatialListener::new(EAR_GAP), Transform::default()));
+}
+
+/// Forget voices whose entities have finished playing
+fn prune_finished_voices(mut mixer: ResMut<AudioMixer>, voices: Query<(), With<MixerVoice>>) {
+    mixer.voices.retain(|v| voices.contains(v.entity));
+}
+
+/// Ease the duck level toward its target
+fn update_ducking(
+    time: Res<Time>,
+    dialogue: Res<DialogueSystem>,
+    game_state: Res<State<GameState>>,
+    mut mixer: ResMut<AudioMixer>,
+) {
+    let should_duck = dialogue.is_active() || *game_state.get() == GameState::BossIntro;
+    let target = if should_duck { DUCK_LEVEL } else { 1.0 };
+
+    let dt = time.delta_secs();
+    mixer.duck = if mixer.duck > target {
+        (mixer.duck - DUCK_ATTACK_RATE * dt).max(target)
+    } else {
+        (mixer.duck + DUCK_RELEASE_RATE * dt).min(target)
+    };
+}
+
+/// Play queued sound effects through the mixer
+fn mix_sound_events(
+    mut commands: Commands,
+    mut sound_events: EventReader<PlaySoundEvent>,
+    sounds: Res<SoundAssets>,
+    sound_pack: Res<SoundPack>,
+    settings: Res<SoundSettings>,
+    mut mixer: ResMut<AudioMixer>,
+    player_query: Query<&Transform, With<crate::entities::Player>>,
+    time: Res<Time>,
+) {
+    if !settings.enabled {
+        sound_events.clear();
+        return;
+    }
+
+    let listener_pos = player_query
+        .get_single()
+        .ok()
+        .map(|t| t.translation.truncate());
+    let now = time.elapsed_secs();
+
+    for event in sound_events.read() {
+        let Some(source) = sound_pack
+            .sound(event.sound)
+            .or_else(|| sounds.get(event.sound))
+        else {
+            continue;
+        };
+        let tuning = sound_pack.sound_tuning(event.sound);
+
+        let priority = event.sound.priority();
+        match mixer.decide(event.sound, priority) {
+            VoiceDecision::Play => {}
+            VoiceDecision::Steal(victim) => {
+                commands.entity(victim).despawn();
+                mixer.remove_voice(victim);
+            }
+            VoiceDecision::Reject => continue,
+        }
+
+        let bus = event.sound.bus();
+        let mut volume = event.volume
+            * tuning.volume
+            * bus.volume(&settings)
+            * settings.sfx_volume
+            * settings.master_volume
+            * crowd_attenuation(mixer.voices.len(), priority);
+        if bus.ducks() {
+            volume *= mixer.duck;
+        }
+
+        let pan = match event.position {
+            Some(pos) => {
+                if let Some(listener) = listener_pos {
+                    volume *= distance_attenuation(pos.distance(listener));
+                }
+                pan_from_x(pos.x)
+            }
+            None => 0.0,
+        };
+
+        let entity = commands
+            .spawn((
+                MixerVoice,
+                AudioPlayer(source),
+                PlaybackSettings {
+                    mode: PlaybackMode::Despawn,
+                    volume: Volume::new(volume),
+                    speed: event.speed * tuning.pitch(fastrand::f32()),
+                    spatial: true,
+                    ..default()
+                },
+                Transform::from_translation(emitter_translation(pan)),
+            ))
+            .id();
+        mixer.add_voice(entity, event.sound, now);
+    }
+}
+
+/// Keep music volume in sync with settings and the duck level
+fn apply_music_ducking(
+    settings: Res<SoundSettings>,
+    mixer: Res<AudioMixer>,
+    tracks: Query<(&MusicTrack, &AudioSink)>,
+) {
+    for (track, sink) in tracks.iter() {
+        sink.set_volume(
+            settings.music_volume * settings.master_volume * track.base_volume * mixer.duck,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn voice(id: u32, sound: SoundType, started: f32) -> Voice {
+        Voice {
+            entity: Entity::from_raw(id),
+            sound,
+            priority: sound.priority(),
+            started,
+        }
+    }
+
+    // ==================== Voice Limit Tests ====================
+
+    #[test]
+    fn decide_plays_when_room() {
+        let mixer = AudioMixer::default();
+        assert_eq!(
+            mixer.decide(SoundType::Autocannon, SoundPriority::Low),
+            VoiceDecision::Play
+        );
+    }
+
+    #[test]
+    fn decide_steals_oldest_of_same_type_at_limit() {
+        let mut mixer = AudioMixer::default();
+        for i in 0..SoundType::Autocannon.voice_limit() as u32 {
+            mixer
+                .voices
+                .push(voice(i, SoundType::Autocannon, 1.0 + i as f32));
+        }
+        assert_eq!(
+            mixer.decide(SoundType::Autocannon, SoundPriority::Low),
+            VoiceDecision::Steal(Entity::from_raw(0))
+        );
+        // Other sound types are unaffected by the autocannon limit
+        assert_eq!(
+            mixer.decide(SoundType::Missile, SoundPriority::Low),
+            VoiceDecision::Play
+        );
+    }
+
+    #[test]
+    fn decide_global_limit_steals_lower_priority() {
+        let mut mixer = AudioMixer::default();
+        for i in 0..MAX_VOICES as u32 {
+            // Spread across types so no per-type limit is hit
+            let sound = if i == 5 {
+                SoundType::SmallExplosion
+            } else {
+                SoundType::AbilityShield
+            };
+            mixer.voices.push(Voice {
+                entity: Entity::from_raw(i),
+                sound,
+                priority: sound.priority(),
+                started: i as f32,
+            });
+        }
+        assert_eq!(
+            mixer.decide(SoundType::HullWarning, SoundPriority::Critical),
+            VoiceDecision::Steal(Entity::from_raw(5))
+        );
+    }
+
+    #[test]
+    fn decide_global_limit_rejects_when_outranked() {
+        let mut mixer = AudioMixer::default();
+        for i in 0..MAX_VOICES as u32 {
+            mixer.voices.push(Voice {
+                entity: Entity::from_raw(i),
+                sound: SoundType::BossSpawn,
+                priority: SoundPriority::Critical,
+                started: i as f32,
+            });
+        }
+        assert_eq!(
+            mixer.decide(SoundType::Autocannon, SoundPriority::Low),
+            VoiceDecision::Reject
+        );
+    }
+
+    #[test]
+    fn remove_voice_drops_entity() {
+        let mut mixer = AudioMixer::default();
+        mixer.add_voice(Entity::from_raw(1), SoundType::Laser, 0.0);
+        mixer.add_voice(Entity::from_raw(2), SoundType::Laser, 0.1);
+        mixer.remove_voice(Entity::from_raw(1));
+        assert_eq!(mixer.count(SoundType::Laser), 1);
+    }
+
+    // ==================== Gain & Pan Tests ====================
+
+    #[test]
+    fn pan_follows_x_and_is_clamped() {
+        assert_eq!(pan_from_x(0.0), 0.0);
+        assert!(pan_from_x(-200.0) < 0.0);
+        assert!(pan_from_x(200.0) > 0.0);
+        assert_eq!(pan_from_x(10_000.0), PAN_WIDTH);
+        assert_eq!(pan_from_x(-10_000.0), -PAN_WIDTH);
+    }
+
+    #[test]
+    fn distance_attenuation_falls_off_to_floor() {
+        assert_eq!(distance_attenuation(0.0), 1.0);
+        assert!(distance_attenuation(300.0) < 1.0);
+        assert_eq!(distance_attenuation(5_000.0), MIN_DISTANCE_GAIN);
+    }
+
+    #[test]
+    fn crowd_attenuation_spares_high_priority() {
+        assert_eq!(crowd_attenuation(4, SoundPriority::Low), 1.0);
+        assert!(crowd_attenuation(20, SoundPriority::Low) < 1.0);
+        assert!(
+            crowd_attenuation(20, SoundPriority::Low)
+                < crowd_attenuation(20, SoundPriority::Normal)
+        );
+        assert_eq!(crowd_attenuation(20, SoundPriority::Critical), 1.0);
+    }
+
+    #[test]
+    fn buses_route_as_expected() {
+        assert_eq!(SoundType::Autocannon.bus(), AudioBus::Weapons);
+        assert_eq!(SoundType::LargeExplosion.bus(), AudioBus::Explosions);
+        assert_eq!(SoundType::MenuConfirm.bus(), AudioBus::Ui);
+        assert_eq!(SoundType::HullWarning.bus(), AudioBus::Warnings);
+        assert!(AudioBus::Weapons.ducks());
+        assert!(!AudioBus::Warnings.ducks());
+    }
+}
@@ -1,33 +1,52 @@
 //! Game Systems
 //!
-//! Core gameplay systems: collision, spawning, scoring, effects, input, dialogue, audio.
+//! Core gameplay systems: collision, spawning, scoring, weapon heat, effects, input, dialogue, audio, mixing, synthesis, sound packs, mission objectives, run stats, leaderboards, challenges, ghosts, style medals, grazing.
 
 pub mod ability;
 pub mod audio;
 pub mod boss;
 pub mod campaign;
+pub mod challenges;
 pub mod collision;
 pub mod dialogue;
 pub mod effects;
+pub mod ghost;
+pub mod graze;
+pub mod heat;
 pub mod joystick;
+pub mod leaderboards;
 pub mod maneuvers;
+pub mod medals;
+pub mod mixer;
 pub mod music;
+pub mod objectives;
+pub mod run_stats;
 pub mod scoring;
-pub mod scoring_v2;
+pub mod sound_pack;
 pub mod spawning;
+pub mod synth;
 
 pub use ability::*;
 pub use audio::*;
 pub use boss::*;
 pub use campaign::CampaignPlugin;
+pub use challenges::*;
 pub use collision::*;
 pub use dialogue::*;
 pub use effects::*;
+pub use ghost::*;
+pub use graze::*;
+pub use heat::*;
 pub use joystick::*;
+pub use leaderboards::*;
 pub use maneuvers::*;
+pub use medals::*;
+pub use mixer::*;
 pub use music::*;
+pub use objectives::*;
+pub use run_stats::*;
 pub use scoring::*;
-pub use scoring_v2::*;
+pub use sound_pack::*;
 pub use spawning::*;
 
 use bevy::prelude::*;
@@ -44,16 +63,26 @@ impl Plugin for SystemsPlugin {
             CollisionPlugin,
             SpawningPlugin,
             ScoringPlugin,
-            ScoringSystemPlugin,
             EffectsPlugin,
             JoystickPlugin,
             BossPlugin,
             DialoguePlugin,
             AudioPlugin,
+            MixerPlugin,
             MusicPlugin,
+            SoundPackPlugin,
             ManeuverPlugin,
             CampaignPlugin,
         ))
+        .add_plugins((
+            ObjectivesPlugin,
+            RunStatsPlugin,
+            LeaderboardPlugin,
+            ChallengePlugin,
+            GhostPlugin,
+            MedalPlugin,
+            GrazePlugin,
+        ))
         // Pause system - ESC during gameplay triggers pause
         .add_systems(
             Update,
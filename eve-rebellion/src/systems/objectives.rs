@@ -0,0 +1,511 @@
+//! Objectives System
+//!
+//! Advances [`MissionObjectives`] from game events, spawns the convoys,
+//! structures, cargo pods and zones that escort, defend, destroy-cargo and
+//! stay-in-area objectives need, and announces completed or failed
+//! objectives. Runs during boss fights too, since some module campaigns
+//! fight their bosses outside `Playing`.
+
+use crate::assets::ShipSpriteCache;
+use crate::core::*;
+use crate::entities::{
+    Boss, BossData, Enemy, EnemyProjectile, Player, PlayerProjectile, ProjectileDamage,
+};
+use bevy::prelude::*;
+
+/// Radius within which enemy fire hits a convoy or structure
+const PROTECTED_HIT_RADIUS: f32 = 28.0;
+/// Where a defended structure sits
+const DEFEND_POSITION: Vec2 = Vec2::new(0.0, -60.0);
+/// Cargo pods: hit radius, hull, drift speed and spacing as they come in
+const CARGO_HIT_RADIUS: f32 = 16.0;
+const CARGO_HEALTH: f32 = 30.0;
+const CARGO_SPEED: f32 = 30.0;
+const CARGO_SPACING: f32 = 160.0;
+
+/// Objectives plugin
+pub struct ObjectivesPlugin;
+
+impl Plugin for ObjectivesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MissionObjectives>()
+            .add_event::<ObjectiveEvent>()
+            .add_systems(OnEnter(GameState::ModuleSelect), clear_objectives)
+            .add_systems(
+                Update,
+                (
+                    spawn_objective_markers,
+                    update_protected_targets,
+                    update_cargo_pods,
+                    update_objective_zones,
+                    track_objectives,
+                    announce_objective_changes,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+
+        // Markers outlive pausing; they go once the mission is left
+        for state in [
+            GameState::MainMenu,
+            GameState::ModuleSelect,
+            GameState::StageComplete,
+            GameState::GameOver,
+            GameState::Victory,
+        ] {
+            app.add_systems(OnEnter(state), despawn_objective_markers);
+        }
+    }
+}
+
+/// Entity belonging to one objective of the current mission
+#[derive(Component)]
+pub struct ObjectiveMarker {
+    pub index: usize,
+    /// [`MissionObjectives::generation`] it was spawned for
+    pub generation: u32,
+}
+
+/// Convoy or structure the enemy shoots at
+#[derive(Component)]
+pub struct ProtectedTarget {
+    pub health: f32,
+    pub max_health: f32,
+}
+
+/// Convoy heading for the top of the screen
+#[derive(Component)]
+pub struct ConvoyMovement {
+    pub speed: f32,
+}
+
+/// Health bar fill of a protected target
+#[derive(Component)]
+pub struct ObjectiveHealthBar;
+
+/// Cargo pod the player has to shoot down before it drifts off screen
+#[derive(Component)]
+pub struct CargoPod {
+    pub health: f32,
+}
+
+/// Area the player has to hold
+#[derive(Component)]
+pub struct ObjectiveZone {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+fn clear_objectives(mut objectives: ResMut<MissionObjectives>) {
+    objectives.clear();
+}
+
+/// Spawn what active objectives need on screen; drop leftovers from an
+/// earlier mission
+fn spawn_objective_markers(
+    mut commands: Commands,
+    objectives: Res<MissionObjectives>,
+    markers: Query<(Entity, &ObjectiveMarker)>,
+    sprite_cache: Res<ShipSpriteCache>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if !objectives.is_changed() {
+        return;
+    }
+
+    let mut spawned = Vec::new();
+    for (entity, marker) in markers.iter() {
+        if marker.generation == objectives.generation {
+            spawned.push(marker.index);
+        } else {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    for (index, objective) in objectives.objectives.iter().enumerate() {
+        if !objective.is_active() || spawned.contains(&index) {
+            continue;
+        }
+        let marker = ObjectiveMarker {
+            index,
+            generation: objectives.generation,
+        };
+
+        match objective.kind {
+            ObjectiveKind::Escort {
+                type_id,
+                health,
+                speed,
+            } => {
+                let position = Vec2::new(0.0, -SCREEN_HEIGHT / 2.0 - 30.0);
+                let entity = spawn_protected(
+                    &mut commands,
+                    marker,
+                    sprite_cache.get(type_id),
+                    position,
+                    48.0,
+                    health,
+                );
+                commands.entity(entity).insert(ConvoyMovement { speed });
+            }
+            ObjectiveKind::Defend { type_id, health } => {
+                spawn_protected(
+                    &mut commands,
+                    marker,
+                    sprite_cache.get(type_id),
+                    DEFEND_POSITION,
+                    72.0,
+                    health,
+                );
+            }
+            ObjectiveKind::DestroyCargo { count } => {
+                for i in 0..count {
+                    let x = ((i * 7) % 5) as f32 * 120.0 - 240.0;
+                    let y = SCREEN_HEIGHT / 2.0 + 30.0 + i as f32 * CARGO_SPACING;
+                    commands.spawn((
+                        ObjectiveMarker {
+                            index,
+                            generation: objectives.generation,
+                        },
+                        CargoPod {
+                            health: CARGO_HEALTH,
+                        },
+                        Sprite {
+                            color: Color::srgb(0.75, 0.6, 0.35),
+                            custom_size: Some(Vec2::new(18.0, 14.0)),
+                            ..default()
+                        },
+                        Transform::from_xyz(x, y, LAYER_ENEMIES),
+                    ));
+                }
+            }
+            ObjectiveKind::StayInArea { center, radius, .. } => {
+                commands.spawn((
+                    marker,
+                    ObjectiveZone { center, radius },
+                    Mesh2d(meshes.add(Circle::new(radius))),
+                    MeshMaterial2d(
+                        materials.add(ColorMaterial::from_color(Color::srgba(0.3, 0.8, 1.0, 0.1))),
+                    ),
+                    Transform::from_xyz(center.x, center.y, LAYER_HAZARDS),
+                ));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn spawn_protected(
+    commands: &mut Commands,
+    marker: ObjectiveMarker,
+    sprite: Option<Handle<Image>>,
+    position: Vec2,
+    size: f32,
+    health: f32,
+) -> Entity {
+    let sprite = match sprite {
+        Some(image) => Sprite {
+            image,
+            custom_size: Some(Vec2::splat(size)),
+            ..default()
+        },
+        None => Sprite {
+            color: Color::srgb(0.4, 0.8, 1.0),
+            custom_size: Some(Vec2::new(size * 0.6, size)),
+            ..default()
+        },
+    };
+
+    commands
+        .spawn((
+            marker,
+            ProtectedTarget {
+                health,
+                max_health: health,
+            },
+            sprite,
+            Transform::from_xyz(position.x, position.y, LAYER_ENEMIES),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Sprite {
+                    color: Color::srgba(0.0, 0.0, 0.0, 0.6),
+                    custom_size: Some(Vec2::new(size, 4.0)),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, size / 2.0 + 8.0, 0.1),
+            ));
+            parent.spawn((
+                ObjectiveHealthBar,
+                Sprite {
+                    color: Color::srgb(0.3, 1.0, 0.3),
+                    custom_size: Some(Vec2::new(size, 4.0)),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, size / 2.0 + 8.0, 0.2),
+            ));
+        })
+        .id()
+}
+
+/// Move convoys, apply enemy fire and report health to the objectives
+fn update_protected_targets(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut objectives: ResMut<MissionObjectives>,
+    mut targets: Query<(
+        Entity,
+        &ObjectiveMarker,
+        &mut ProtectedTarget,
+        &mut Transform,
+        &Children,
+        Option<&ConvoyMovement>,
+    )>,
+    projectiles: Query<
+        (Entity, &Transform, &ProjectileDamage),
+        (With<EnemyProjectile>, Without<ProtectedTarget>),
+    >,
+    mut bars: Query<
+        (&mut Sprite, &mut Transform),
+        (
+            With<ObjectiveHealthBar>,
+            Without<ProtectedTarget>,
+            Without<EnemyProjectile>,
+        ),
+    >,
+    mut explosions: EventWriter<ExplosionEvent>,
+) {
+    let dt = time.delta_secs();
+    let mut absorbed = Vec::new();
+
+    for (entity, marker, mut target, mut transform, children, convoy) in targets.iter_mut() {
+        if marker.generation != objectives.generation {
+            continue;
+        }
+        let position = transform.translation.truncate();
+
+        let health_before = target.health;
+        for (projectile, projectile_transform, damage) in projectiles.iter() {
+            if absorbed.contains(&projectile) {
+                continue;
+            }
+            if projectile_transform
+                .translation
+                .truncate()
+                .distance(position)
+                < PROTECTED_HIT_RADIUS
+            {
+                target.health -= damage.damage;
+                absorbed.push(projectile);
+                commands.entity(projectile).despawn();
+            }
+        }
+
+        if target.health != health_before {
+            let fraction = (target.health / target.max_health).max(0.0);
+            objectives.on_protected_health(marker.index, fraction);
+            for &child in children.iter() {
+                if let Ok((mut sprite, mut bar_transform)) = bars.get_mut(child) {
+                    bar_transform.scale.x = fraction;
+                    sprite.color = if fraction > 0.3 {
+                        Color::srgb(0.3, 1.0, 0.3)
+                    } else {
+                        Color::srgb(1.0, 0.3, 0.2)
+                    };
+                }
+            }
+
+            if target.health <= 0.0 {
+                explosions.send(ExplosionEvent {
+                    position,
+                    size: ExplosionSize::Large,
+                    color: Color::srgb(1.0, 0.6, 0.2),
+                });
+                commands.entity(entity).despawn_recursive();
+                continue;
+            }
+        }
+
+        if let Some(convoy) = convoy {
+            transform.translation.y += convoy.speed * dt;
+            if transform.translation.y > SCREEN_HEIGHT / 2.0 + 30.0 {
+                objectives.on_escort_arrived(marker.index);
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
+/// Drift cargo pods down, let the player's fire break them open and fail
+/// the objective when one gets away
+fn update_cargo_pods(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut objectives: ResMut<MissionObjectives>,
+    mut pods: Query<(Entity, &ObjectiveMarker, &mut CargoPod, &mut Transform)>,
+    projectiles: Query<
+        (Entity, &Transform, &ProjectileDamage),
+        (With<PlayerProjectile>, Without<CargoPod>),
+    >,
+    mut explosions: EventWriter<ExplosionEvent>,
+) {
+    let dt = time.delta_secs();
+    let mut absorbed = Vec::new();
+
+    for (entity, marker, mut pod, mut transform) in pods.iter_mut() {
+        if marker.generation != objectives.generation {
+            continue;
+        }
+        transform.translation.y -= CARGO_SPEED * dt;
+        let position = transform.translation.truncate();
+
+        for (projectile, projectile_transform, damage) in projectiles.iter() {
+            if absorbed.contains(&projectile) {
+                continue;
+            }
+            if projectile_transform
+                .translation
+                .truncate()
+                .distance(position)
+                < CARGO_HIT_RADIUS
+            {
+                pod.health -= damage.damage;
+                absorbed.push(projectile);
+                commands.entity(projectile).despawn();
+            }
+        }
+
+        if pod.health <= 0.0 {
+            explosions.send(ExplosionEvent {
+                position,
+                size: ExplosionSize::Small,
+                color: Color::srgb(1.0, 0.7, 0.3),
+            });
+            objectives.on_cargo_destroyed(marker.index);
+            commands.entity(entity).despawn_recursive();
+        } else if position.y < -SCREEN_HEIGHT / 2.0 - 20.0 {
+            objectives.on_cargo_escaped(marker.index);
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Brighten zones while the player is inside; remove them once settled
+fn update_objective_zones(
+    mut commands: Commands,
+    objectives: Res<MissionObjectives>,
+    zones: Query<(
+        Entity,
+        &ObjectiveMarker,
+        &ObjectiveZone,
+        &MeshMaterial2d<ColorMaterial>,
+    )>,
+    player: Query<&Transform, With<Player>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let player_position = player.get_single().ok().map(|t| t.translation.truncate());
+
+    for (entity, marker, zone, material) in zones.iter() {
+        let active = marker.generation == objectives.generation
+            && objectives
+                .objectives
+                .get(marker.index)
+                .is_some_and(|o| o.is_active());
+        if !active {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let inside = player_position.is_some_and(|p| p.distance(zone.center) <= zone.radius);
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.color = Color::srgba(0.3, 0.8, 1.0, if inside { 0.25 } else { 0.1 });
+        }
+    }
+}
+
+/// Feed game events into the objectives
+fn track_objectives(
+    time: Res<Time>,
+    score: Res<ScoreSystem>,
+    mut objectives: ResMut<MissionObjectives>,
+    player: Query<&Transform, With<Player>>,
+    bosses: Query<&BossData, With<Boss>>,
+    escorts: Query<(), (With<Enemy>, Without<Boss>)>,
+    mut boss_events: EventReader<BossDefeatedEvent>,
+    mut module_boss_events: EventReader<crate::systems::boss::BossDefeatedEvent>,
+    mut pickups: EventReader<CollectiblePickedUpEvent>,
+    mut kills: EventReader<EnemyDestroyedEvent>,
+    mut damage: EventReader<PlayerDamagedEvent>,
+    mut wingmen_lost: EventReader<WingmanLostEvent>,
+) {
+    // Nothing to track, or the mission already paid out
+    if objectives.is_empty() || objectives.rewards.is_some() {
+        boss_events.clear();
+        module_boss_events.clear();
+        pickups.clear();
+        kills.clear();
+        damage.clear();
+        wingmen_lost.clear();
+        return;
+    }
+
+    if boss_events.read().count() + module_boss_events.read().count() > 0 {
+        objectives.on_boss_defeated(escorts.iter().count());
+    }
+    objectives.on_boss_phase(bosses.iter().next().map(|b| b.current_phase));
+    let pods = pickups
+        .read()
+        .filter(|e| e.collectible_type == CollectibleType::LiberationPod)
+        .count();
+    if pods > 0 {
+        objectives.on_pods_collected(pods as u32);
+    }
+    for kill in kills.read().filter(|e| !e.was_boss) {
+        objectives.on_enemy_destroyed(kill.type_id);
+    }
+    if damage.read().count() > 0 {
+        objectives.on_player_damaged();
+    }
+    if wingmen_lost.read().count() > 0 {
+        objectives.on_ally_lost();
+    }
+    objectives.on_chain(score.chain, !escorts.is_empty());
+
+    let player_position = player.get_single().ok().map(|t| t.translation.truncate());
+    objectives.tick(time.delta_secs(), player_position);
+}
+
+/// Send an event and a spoken line for every settled objective
+fn announce_objective_changes(
+    mut objectives: ResMut<MissionObjectives>,
+    mut events: EventWriter<ObjectiveEvent>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    for index in objectives.take_changes() {
+        let objective = &objectives.objectives[index];
+        let verdict = match objective.status {
+            ObjectiveStatus::Complete => "complete",
+            ObjectiveStatus::Failed => "failed",
+            ObjectiveStatus::Active => continue,
+        };
+        info!("Objective {}: {}", verdict, objective.label);
+        narration.send(NarrationEvent::queued(format!(
+            "Objective {}: {}",
+            verdict, objective.label
+        )));
+        events.send(ObjectiveEvent {
+            index,
+            status: objective.status,
+        });
+    }
+}
+
+fn despawn_objective_markers(
+    mut commands: Commands,
+    markers: Query<Entity, With<ObjectiveMarker>>,
+) {
+    for entity in markers.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
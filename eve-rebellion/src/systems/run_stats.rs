@@ -0,0 +1,120 @@
+//! Run Stats System
+//!
+//! Starts a [`RunStats`] collection when a mission begins, feeds it from
+//! gameplay events, and on leaving the mission finishes it and appends the
+//! summary to the active pilot's run history.
+
+use bevy::prelude::*;
+
+use crate::core::*;
+use crate::systems::ability::AbilityActivatedEvent;
+
+/// Run stats plugin
+pub struct RunStatsPlugin;
+
+impl Plugin for RunStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunStats>()
+            .add_event::<RunFinishedEvent>()
+            .add_systems(Update, track_run_lifecycle)
+            .add_systems(
+                Update,
+                collect_run_stats
+                    .after(track_run_lifecycle)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// Start a run on entering a mission, finish it on leaving.
+///
+/// Pausing and boss intros leave and re-enter `Playing`, so only
+/// transitions into or out of the mission states as a whole count.
+/// Finished runs are announced with a [`RunFinishedEvent`].
+fn track_run_lifecycle(
+    mut transitions: EventReader<StateTransitionEvent<GameState>>,
+    mut stats: ResMut<RunStats>,
+    score: Res<ScoreSystem>,
+    session: Res<GameSession>,
+    campaign: Res<CampaignState>,
+    profiles: Res<PilotProfiles>,
+    mut finished_events: EventWriter<RunFinishedEvent>,
+) {
+    for transition in transitions.read() {
+        let (Some(exited), Some(entered)) = (transition.exited, transition.entered) else {
+            continue;
+        };
+        let was_in = exited.in_mission();
+        let now_in = entered.in_mission();
+
+        if !was_in && now_in {
+            stats.start();
+        } else if was_in && !now_in && stats.active {
+            let outcome = match entered {
+                GameState::StageComplete | GameState::Victory => RunResult::Completed,
+                GameState::GameOver => RunResult::Failed,
+                _ => RunResult::Left,
+            };
+            let context = RunContext {
+                mission: campaign.current_mission_name().to_string(),
+                faction: session.player_faction.name().to_string(),
+                ship: session.selected_ship().name.to_string(),
+                score: score.score,
+            };
+            let summary = stats.finish(outcome, context);
+            if let Some(id) = profiles.active.as_deref() {
+                append_log(RUN_HISTORY_LOG, id, &summary);
+            }
+            finished_events.send(RunFinishedEvent {
+                summary,
+                victory: entered == GameState::Victory,
+            });
+        }
+    }
+}
+
+/// Feed the active run from gameplay events
+fn collect_run_stats(
+    time: Res<Time>,
+    mut stats: ResMut<RunStats>,
+    score: Res<ScoreSystem>,
+    mut fire_events: EventReader<PlayerFireEvent>,
+    mut hit_events: EventReader<EnemyHitEvent>,
+    mut destroyed_events: EventReader<EnemyDestroyedEvent>,
+    mut damaged_events: EventReader<PlayerDamagedEvent>,
+    mut layer_events: EventReader<DamageLayerEvent>,
+    mut pickup_events: EventReader<CollectiblePickedUpEvent>,
+    mut ability_events: EventReader<AbilityActivatedEvent>,
+    mut medal_events: EventReader<MedalEvent>,
+) {
+    if !stats.active {
+        return;
+    }
+    stats.tick(time.delta_secs());
+
+    for event in fire_events.read() {
+        stats.record_shots(event.burst_count);
+    }
+    for event in hit_events.read() {
+        stats.record_hit(event.damage, event.damage_type, event.layer, event.crit);
+    }
+    for event in destroyed_events.read() {
+        stats.record_kill(&event.enemy_type, event.was_boss);
+    }
+    for event in damaged_events.read() {
+        stats.record_damage_taken(event.damage, event.damage_type);
+    }
+    for event in layer_events.read() {
+        stats.record_layer_damage(event.layer, event.damage);
+    }
+    for event in pickup_events.read() {
+        stats.record_pickup(&format!("{:?}", event.collectible_type));
+    }
+    for event in ability_events.read() {
+        stats.record_ability(event.ability_type.name());
+    }
+    for event in medal_events.read() {
+        stats.record_medal(event.medal.name());
+    }
+    stats.record_combo(score.chain, score.multiplier);
+}
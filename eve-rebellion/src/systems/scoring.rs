@@ -1,8 +1,12 @@
 //! Scoring System
 //!
-//! Handles score, multipliers, chain combos, and salt miner meter.
+//! Handles score, multipliers, chain combos, weapon heat and salt miner
+//! meter. Points arrive as [`ScoreEvent`]s and are run through the active
+//! module's score rules by [`award_scores`].
 
+use super::HeatSystem;
 use crate::core::*;
+use crate::games::{ActiveModule, GameModules};
 use bevy::prelude::*;
 
 /// Scoring plugin
@@ -10,13 +14,78 @@ pub struct ScoringPlugin;
 
 impl Plugin for ScoringPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (update_score_system, update_salt_miner_system).run_if(in_state(GameState::Playing)),
-        );
+        app.add_event::<ScoreEvent>()
+            .add_event::<RecordScoreEvent>()
+            .init_resource::<HeatSystem>()
+            .add_systems(
+                Update,
+                (update_score_system, update_salt_miner_system)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, update_heat_system)
+            // After every Update sender, so awards land the frame they're earned
+            .add_systems(PostUpdate, (award_scores, record_scores).chain());
     }
 }
 
+/// Run each score event through the active module's rules
+pub fn award_scores(
+    mut events: EventReader<ScoreEvent>,
+    modules: Option<Res<GameModules>>,
+    active: Option<Res<ActiveModule>>,
+    difficulty: Res<Difficulty>,
+    heat: Res<HeatSystem>,
+    mut score: ResMut<ScoreSystem>,
+    mut salt_miner: ResMut<SaltMinerSystem>,
+) {
+    let rules = modules
+        .as_deref()
+        .zip(active.as_deref())
+        .and_then(|(modules, active)| modules.active(active))
+        .map_or(DEFAULT_SCORE_RULES, |module| module.score_rules());
+
+    for event in events.read() {
+        if event.source.is_kill() {
+            score.register_kill();
+        }
+        let state = ScoreState {
+            chain: score.chain,
+            chain_multiplier: score.multiplier,
+            heat_multiplier: heat.score_mult(),
+            salt_miner_multiplier: salt_miner.score_mult(),
+            difficulty_multiplier: difficulty.score_mult(),
+        };
+        score.award(ScoreBreakdown::new(event.base, event.source, rules, &state));
+
+        // Salt miner meter fills from proximity kills
+        if let Some(distance) = event.distance {
+            let meter_gained = salt_miner.on_kill_at_distance(distance);
+            if meter_gained > 0.0 && salt_miner.can_activate() {
+                info!(
+                    "SALT MINER READY! Press B to activate! (meter: {:.0}%)",
+                    salt_miner.meter
+                );
+            }
+        }
+    }
+}
+
+/// Save stage bests after the awards that finished the stage
+fn record_scores(
+    mut events: EventReader<RecordScoreEvent>,
+    score: Res<ScoreSystem>,
+    mut save_data: ResMut<SaveData>,
+) {
+    for event in events.read() {
+        save_data.record_score(&event.faction, &event.enemy, score.score, event.stage);
+    }
+}
+
+/// Cool the guns
+fn update_heat_system(time: Res<Time>, mut heat: ResMut<HeatSystem>) {
+    heat.update(time.delta_secs());
+}
+
 /// Update score chain timer
 fn update_score_system(time: Res<Time>, mut score: ResMut<ScoreSystem>) {
     score.update(time.delta_secs());
@@ -54,6 +123,3 @@ fn update_salt_miner_system(
         ));
     }
 }
-
-// Salt miner meter fills from proximity kills
-// See collision.rs: player_projectile_enemy_collision
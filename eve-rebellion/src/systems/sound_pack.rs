@@ -0,0 +1,367 @@
+//! Sound Packs
+//!
+//! Optional recorded audio that replaces the procedural sounds.
+//! Looks for `assets/sounds/<sound_type>.{wav,ogg}` and `assets/music/<context>.ogg`
+//! under the same asset root Bevy uses (`BEVY_ASSET_ROOT`, else next to the
+//! executable) at startup; anything missing falls back to the generated source.
+//! An optional `assets/sounds/manifest.json` tunes per-sound volume and pitch variation:
+//!
+//! ```json
+//! {
+//!     "name": "Tranquility Recordings",
+//!     "sounds": { "autocannon": { "volume": 0.8, "pitch_variation": 0.05 } },
+//!     "music": { "boss": { "volume": 1.2 } }
+//! }
+//! ```
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::core::*;
+use crate::systems::music::MusicType;
+
+/// Sound pack plugin
+pub struct SoundPackPlugin;
+
+impl Plugin for SoundPackPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SoundPack>();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Startup, load_sound_pack);
+    }
+}
+
+/// Per-sound tuning from the pack manifest
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct SoundTuning {
+    /// Gain multiplier applied on top of the mixer
+    pub volume: f32,
+    /// Random playback speed spread, e.g. 0.05 = +/-5%
+    pub pitch_variation: f32,
+}
+
+impl Default for SoundTuning {
+    fn default() -> Self {
+        Self {
+            volume: 1.0,
+            pitch_variation: 0.0,
+        }
+    }
+}
+
+impl SoundTuning {
+    /// Playback speed multiplier for a random roll in [0, 1)
+    pub fn pitch(&self, roll: f32) -> f32 {
+        1.0 + (roll * 2.0 - 1.0) * self.pitch_variation
+    }
+}
+
+/// Contents of `assets/sounds/manifest.json`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SoundPackManifest {
+    pub name: String,
+    /// Keyed by sound file stem (see [`SoundType::file_stem`])
+    pub sounds: HashMap<String, SoundTuning>,
+    /// Keyed by music context (see [`MusicType::file_stem`])
+    pub music: HashMap<String, SoundTuning>,
+}
+
+/// Loaded sound pack overrides
+#[derive(Resource, Default)]
+pub struct SoundPack {
+    pub name: Option<String>,
+    pub sounds: HashMap<SoundType, Handle<AudioSource>>,
+    pub music: HashMap<MusicType, Handle<AudioSource>>,
+    pub sound_tuning: HashMap<SoundType, SoundTuning>,
+    pub music_tuning: HashMap<MusicType, SoundTuning>,
+}
+
+impl SoundPack {
+    /// Recorded override for a sound, if the pack provides one
+    pub fn sound(&self, sound: SoundType) -> Option<Handle<AudioSource>> {
+        self.sounds.get(&sound).cloned()
+    }
+
+    /// Recorded override for a music context, if the pack provides one
+    pub fn music(&self, music: MusicType) -> Option<Handle<AudioSource>> {
+        self.music.get(&music).cloned()
+    }
+
+    pub fn sound_tuning(&self, sound: SoundType) -> SoundTuning {
+        self.sound_tuning.get(&sound).copied().unwrap_or_default()
+    }
+
+    pub fn music_tuning(&self, music: MusicType) -> SoundTuning {
+        self.music_tuning.get(&music).copied().unwrap_or_default()
+    }
+
+    /// Apply manifest tuning, warning about keys that match nothing
+    pub fn apply_manifest(&mut self, manifest: SoundPackManifest) {
+        if !manifest.name.is_empty() {
+            self.name = Some(manifest.name);
+        }
+        for (key, tuning) in manifest.sounds {
+            match SoundType::from_file_stem(&key) {
+                Some(sound) => {
+                    self.sound_tuning.insert(sound, tuning);
+                }
+                None => warn!("Sound pack manifest: unknown sound '{}'", key),
+            }
+        }
+        for (key, tuning) in manifest.music {
+            match MusicType::from_file_stem(&key) {
+                Some(music) => {
+                    self.music_tuning.insert(music, tuning);
+                }
+                None => warn!("Sound pack manifest: unknown music context '{}'", key),
+            }
+        }
+    }
+}
+
+impl SoundType {
+    pub const ALL: [SoundType; 39] = [
+        SoundType::Autocannon,
+        SoundType::Artillery,
+        SoundType::Laser,
+        SoundType::Missile,
+        SoundType::ShieldHit,
+        SoundType::ArmorHit,
+        SoundType::HullHit,
+        SoundType::SmallExplosion,
+        SoundType::MediumExplosion,
+        SoundType::LargeExplosion,
+        SoundType::MenuSelect,
+        SoundType::MenuConfirm,
+        SoundType::MenuBack,
+        SoundType::PowerUp,
+        SoundType::Liberation,
+        SoundType::SaltMinerActivate,
+        SoundType::Graze,
+        SoundType::Warning,
+        SoundType::Victory,
+        SoundType::GameOver,
+        SoundType::PowerupOverdrive,
+        SoundType::PowerupDamage,
+        SoundType::PowerupInvuln,
+        SoundType::PowerupHealth,
+        SoundType::ShieldWarning,
+        SoundType::ArmorWarning,
+        SoundType::HullWarning,
+        SoundType::WaveComplete,
+        SoundType::BossSpawn,
+        SoundType::WaveStart,
+        SoundType::BossPhase,
+        SoundType::AbilitySpeed,
+        SoundType::AbilityShield,
+        SoundType::AbilityArmor,
+        SoundType::AbilityWeapon,
+        SoundType::AbilityDrone,
+        SoundType::AbilityDebuff,
+        SoundType::AbilityDamage,
+        SoundType::AbilityReady,
+    ];
+
+    /// File name (without extension) used for sound pack overrides
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            SoundType::Autocannon => "autocannon",
+            SoundType::Artillery => "artillery",
+            SoundType::Laser => "laser",
+            SoundType::Missile => "missile",
+            SoundType::ShieldHit => "shield_hit",
+            SoundType::ArmorHit => "armor_hit",
+            SoundType::HullHit => "hull_hit",
+            SoundType::SmallExplosion => "small_explosion",
+            SoundType::MediumExplosion => "medium_explosion",
+            SoundType::LargeExplosion => "large_explosion",
+            SoundType::MenuSelect => "menu_select",
+            SoundType::MenuConfirm => "menu_confirm",
+            SoundType::MenuBack => "menu_back",
+            SoundType::PowerUp => "power_up",
+            SoundType::Liberation => "liberation",
+            SoundType::SaltMinerActivate => "salt_miner_activate",
+            SoundType::Graze => "graze",
+            SoundType::Warning => "warning",
+            SoundType::Victory => "victory",
+            SoundType::GameOver => "game_over",
+            SoundType::PowerupOverdrive => "powerup_overdrive",
+            SoundType::PowerupDamage => "powerup_damage",
+            SoundType::PowerupInvuln => "powerup_invuln",
+            SoundType::PowerupHealth => "powerup_health",
+            SoundType::ShieldWarning => "shield_warning",
+            SoundType::ArmorWarning => "armor_warning",
+            SoundType::HullWarning => "hull_warning",
+            SoundType::WaveComplete => "wave_complete",
+            SoundType::BossSpawn => "boss_spawn",
+            SoundType::WaveStart => "wave_start",
+            SoundType::BossPhase => "boss_phase",
+            SoundType::AbilitySpeed => "ability_speed",
+            SoundType::AbilityShield => "ability_shield",
+            SoundType::AbilityArmor => "ability_armor",
+            SoundType::AbilityWeapon => "ability_weapon",
+            SoundType::AbilityDrone => "ability_drone",
+            SoundType::AbilityDebuff => "ability_debuff",
+            SoundType::AbilityDamage => "ability_damage",
+            SoundType::AbilityReady => "ability_ready",
+        }
+    }
+
+    pub fn from_file_stem(stem: &str) -> Option<SoundType> {
+        SoundType::ALL.into_iter().find(|s| s.file_stem() == stem)
+    }
+}
+
+impl MusicType {
+    pub const ALL: [MusicType; 3] = [MusicType::Menu, MusicType::Gameplay, MusicType::Boss];
+
+    /// File name (without extension) used for sound pack overrides
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            MusicType::None => "none",
+            MusicType::Menu => "menu",
+            MusicType::Gameplay => "gameplay",
+            MusicType::Boss => "boss",
+        }
+    }
+
+    pub fn from_file_stem(stem: &str) -> Option<MusicType> {
+        MusicType::ALL.into_iter().find(|m| m.file_stem() == stem)
+    }
+}
+
+/// Directory holding `assets/`, resolved the way Bevy's file asset reader does:
+/// `BEVY_ASSET_ROOT`, then `CARGO_MANIFEST_DIR` under `cargo run`, then the
+/// executable's directory. Falls back to the working directory.
+#[cfg(not(target_arch = "wasm32"))]
+fn asset_root() -> std::path::PathBuf {
+    use std::path::PathBuf;
+
+    std::env::var_os("BEVY_ASSET_ROOT")
+        .or_else(|| std::env::var_os("CARGO_MANIFEST_DIR"))
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::current_exe()
+                .ok()
+                .and_then(|exe| exe.parent().map(PathBuf::from))
+        })
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Scan the assets directory for recorded overrides and the manifest
+#[cfg(not(target_arch = "wasm32"))]
+fn load_sound_pack(mut pack: ResMut<SoundPack>, mut audio_sources: ResMut<Assets<AudioSource>>) {
+    use std::path::Path;
+
+    let assets_dir = asset_root().join("assets");
+    let sounds_dir = assets_dir.join("sounds");
+    let music_dir = assets_dir.join("music");
+
+    let mut load = |path: &Path| -> Option<Handle<AudioSource>> {
+        let bytes = std::fs::read(path).ok()?;
+        info!("Sound pack override: {:?}", path);
+        Some(audio_sources.add(AudioSource {
+            bytes: std::sync::Arc::from(bytes.into_boxed_slice()),
+        }))
+    };
+
+    for sound in SoundType::ALL {
+        let handle = ["wav", "ogg"]
+            .iter()
+            .find_map(|ext| load(&sounds_dir.join(format!("{}.{}", sound.file_stem(), ext))));
+        if let Some(handle) = handle {
+            pack.sounds.insert(sound, handle);
+        }
+    }
+
+    for music in MusicType::ALL {
+        if let Some(handle) = load(&music_dir.join(format!("{}.ogg", music.file_stem()))) {
+            pack.music.insert(music, handle);
+        }
+    }
+
+    let manifest_path = sounds_dir.join("manifest.json");
+    if let Ok(json) = std::fs::read_to_string(&manifest_path) {
+        match serde_json::from_str::<SoundPackManifest>(&json) {
+            Ok(manifest) => pack.apply_manifest(manifest),
+            Err(e) => warn!("Failed to parse {:?}: {}", manifest_path, e),
+        }
+    }
+
+    if !pack.sounds.is_empty() || !pack.music.is_empty() {
+        info!(
+            "Sound pack '{}': {} sounds, {} music tracks",
+            pack.name.as_deref().unwrap_or("unnamed"),
+            pack.sounds.len(),
+            pack.music.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sound_file_stems_round_trip() {
+        for sound in SoundType::ALL {
+            assert_eq!(SoundType::from_file_stem(sound.file_stem()), Some(sound));
+        }
+        for music in MusicType::ALL {
+            assert_eq!(MusicType::from_file_stem(music.file_stem()), Some(music));
+        }
+        assert_eq!(SoundType::from_file_stem("nope"), None);
+        assert_eq!(MusicType::from_file_stem("none"), None);
+    }
+
+    #[test]
+    fn manifest_fills_defaults() {
+        let manifest: SoundPackManifest = serde_json::from_str(
+            r#"{ "sounds": { "laser": { "volume": 0.5 } }, "music": { "boss": { "pitch_variation": 0.1 } } }"#,
+        )
+        .unwrap();
+        let mut pack = SoundPack::default();
+        pack.apply_manifest(manifest);
+
+        assert!(pack.name.is_none());
+        assert_eq!(pack.sound_tuning(SoundType::Laser).volume, 0.5);
+        assert_eq!(pack.sound_tuning(SoundType::Laser).pitch_variation, 0.0);
+        assert_eq!(pack.music_tuning(MusicType::Boss).volume, 1.0);
+        assert_eq!(pack.music_tuning(MusicType::Boss).pitch_variation, 0.1);
+        assert_eq!(
+            pack.sound_tuning(SoundType::Autocannon),
+            SoundTuning::default()
+        );
+    }
+
+    #[test]
+    fn unknown_manifest_keys_are_ignored() {
+        let manifest: SoundPackManifest = serde_json::from_str(
+            r#"{ "name": "Test", "sounds": { "railgun": { "volume": 2.0 } } }"#,
+        )
+        .unwrap();
+        let mut pack = SoundPack::default();
+        pack.apply_manifest(manifest);
+
+        assert_eq!(pack.name.as_deref(), Some("Test"));
+        assert!(pack.sound_tuning.is_empty());
+    }
+
+    #[test]
+    fn pitch_variation_spans_both_directions() {
+        let tuning = SoundTuning {
+            volume: 1.0,
+            pitch_variation: 0.1,
+        };
+        assert!((tuning.pitch(0.0) - 0.9).abs() < 1e-6);
+        assert!((tuning.pitch(0.5) - 1.0).abs() < 1e-6);
+        assert!(tuning.pitch(0.999) < 1.1);
+        assert_eq!(SoundTuning::default().pitch(0.9), 1.0);
+    }
+}
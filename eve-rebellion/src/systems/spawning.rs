@@ -8,9 +8,9 @@ use crate::assets::ShipModelCache;
 use crate::core::*;
 use crate::entities::{
     spawn_enemy, spawn_kamikaze, spawn_sniper, spawn_spawner_enemy, spawn_tank, spawn_weaver,
-    EnemyBehavior,
+    EnemyBehavior, Formation,
 };
-use crate::games::caldari_gallente::LastStandState;
+use crate::games::{core_enabled, CoreSystem};
 use bevy::prelude::*;
 
 /// Spawning plugin
@@ -22,30 +22,18 @@ impl Plugin for SpawningPlugin {
             .add_systems(
                 OnEnter(GameState::Playing),
                 (reset_wave_manager, spawn_enemy_carrier)
-                    .run_if(not_last_stand)
-                    .run_if(not_abyssal),
+                    .run_if(core_enabled(CoreSystem::Spawning)),
             )
             .add_systems(OnExit(GameState::Playing), cleanup_carrier)
             .add_systems(
                 Update,
                 (wave_spawning, handle_spawn_events, animate_carrier)
                     .run_if(in_state(GameState::Playing))
-                    .run_if(not_last_stand)
-                    .run_if(not_abyssal),
+                    .run_if(core_enabled(CoreSystem::Spawning)),
             );
     }
 }
 
-/// Run condition: Last Stand mode is NOT active
-fn not_last_stand(last_stand: Option<Res<LastStandState>>) -> bool {
-    last_stand.map(|ls| !ls.active).unwrap_or(true)
-}
-
-/// Run condition: Abyssal Depths is NOT active
-fn not_abyssal(abyssal: Option<Res<crate::games::abyssal_depths::AbyssalState>>) -> bool {
-    abyssal.map(|a| !a.active).unwrap_or(true)
-}
-
 /// Marker component for the enemy carrier in background
 #[derive(Component)]
 pub struct EnemyCarrier {
@@ -183,6 +171,8 @@ pub struct WaveManager {
     pub endless_mode: bool,
     /// Mini-boss spawning (for endless mode)
     pub mini_boss_active: bool,
+    /// First enemy of the current wave's formation, once spawned
+    pub formation_leader: Option<Entity>,
 }
 
 impl Default for WaveManager {
@@ -200,6 +190,7 @@ impl Default for WaveManager {
             stage_complete: false,
             endless_mode: false,
             mini_boss_active: false,
+            formation_leader: None,
         }
     }
 }
@@ -254,7 +245,7 @@ fn wave_spawning(
     mut manager: ResMut<WaveManager>,
     mut endless: ResMut<crate::core::EndlessMode>,
     mut next_state: ResMut<NextState<GameState>>,
-    _stage: Res<CurrentStage>,
+    difficulty: Res<DifficultySettings>,
     session: Res<crate::core::GameSession>,
     enemy_query: Query<Entity, With<crate::entities::Enemy>>,
     boss_query: Query<Entity, With<crate::entities::Boss>>,
@@ -263,8 +254,8 @@ fn wave_spawning(
     mut boss_spawn_events: EventWriter<super::boss::BossSpawnEvent>,
     mut boss_defeated_events: EventReader<super::boss::BossDefeatedEvent>,
     mut dialogue_events: EventWriter<DialogueEvent>,
-    sprite_cache: Res<crate::assets::ShipSpriteCache>,
-    model_cache: Res<ShipModelCache>,
+    (sprite_cache, model_cache): (Res<crate::assets::ShipSpriteCache>, Res<ShipModelCache>),
+    mut rng: ResMut<crate::core::GameRng>,
 ) {
     // Get carrier position for spawning enemies
     let carrier_pos = carrier_query
@@ -397,6 +388,7 @@ fn wave_spawning(
             // Setup new wave
             let wave_def = get_wave_definition(manager.current_stage, manager.wave);
             manager.enemies_remaining = wave_def.enemy_count;
+            manager.formation_leader = None;
             manager.spawn_interval = 0.5 + 0.3 / (manager.wave as f32).sqrt();
 
             wave_events.send(SpawnWaveEvent {
@@ -434,18 +426,22 @@ fn wave_spawning(
             let wave_def = get_wave_definition(manager.current_stage, manager.wave);
 
             // Get random enemy from enemy faction using GameSession
-            let enemy_def = session.random_enemy();
+            let enemy_def = session.random_enemy(&mut rng.0);
             let type_id = enemy_def.type_id;
 
-            // Pick behavior based on stage progression
-            let behavior_idx = fastrand::usize(..wave_def.behaviors.len());
-            let behavior = wave_def.behaviors[behavior_idx];
+            // Pick behavior based on stage progression, unless a challenge
+            // limits waves to a few behaviors
+            let behaviors = difficulty
+                .enemy
+                .wave_behaviors
+                .unwrap_or(&wave_def.behaviors);
+            let behavior = behaviors[rng.0.usize(..behaviors.len())];
 
             // Spawn position based on pattern - enemies launch from carrier
             let pos = match wave_def.spawn_pattern {
                 SpawnPattern::Single | SpawnPattern::Random => {
                     // Spawn near carrier with random spread
-                    let x = carrier_pos.x + fastrand::f32() * 200.0 - 100.0;
+                    let x = carrier_pos.x + rng.0.f32() * 200.0 - 100.0;
                     Vec2::new(x, carrier_pos.y - 50.0)
                 }
                 SpawnPattern::Line => {
@@ -474,8 +470,8 @@ fn wave_spawning(
                 }
                 SpawnPattern::Swarm => {
                     // Swarm bursting from carrier bay
-                    let x = carrier_pos.x + fastrand::f32() * 300.0 - 150.0;
-                    let y = carrier_pos.y - 20.0 - fastrand::f32() * 60.0;
+                    let x = carrier_pos.x + rng.0.f32() * 300.0 - 150.0;
+                    let y = carrier_pos.y - 20.0 - rng.0.f32() * 60.0;
                     Vec2::new(x, y)
                 }
             };
@@ -483,32 +479,40 @@ fn wave_spawning(
             let sprite = sprite_cache.get(type_id);
 
             // Use specialized spawn functions for special enemy types
-            match behavior {
+            let entity = match behavior {
                 EnemyBehavior::Kamikaze => {
-                    spawn_kamikaze(&mut commands, pos, sprite, Some(&model_cache));
+                    spawn_kamikaze(&mut commands, pos, sprite, Some(&model_cache))
                 }
                 EnemyBehavior::Weaver => {
-                    spawn_weaver(&mut commands, pos, sprite, Some(&model_cache));
+                    spawn_weaver(&mut commands, pos, sprite, Some(&model_cache))
                 }
                 EnemyBehavior::Sniper => {
-                    spawn_sniper(&mut commands, pos, sprite, Some(&model_cache));
+                    spawn_sniper(&mut commands, pos, sprite, Some(&model_cache))
                 }
                 EnemyBehavior::Spawner => {
-                    spawn_spawner_enemy(&mut commands, pos, sprite, Some(&model_cache));
-                }
-                EnemyBehavior::Tank => {
-                    spawn_tank(&mut commands, pos, sprite, Some(&model_cache));
-                }
-                _ => {
-                    spawn_enemy(
-                        &mut commands,
-                        type_id,
-                        pos,
-                        behavior,
-                        sprite,
-                        Some(&model_cache),
-                    );
+                    spawn_spawner_enemy(&mut commands, pos, sprite, Some(&model_cache))
                 }
+                EnemyBehavior::Tank => spawn_tank(&mut commands, pos, sprite, Some(&model_cache)),
+                _ => spawn_enemy(
+                    &mut commands,
+                    type_id,
+                    pos,
+                    behavior,
+                    sprite,
+                    Some(&model_cache),
+                ),
+            };
+
+            // Patterned waves fly as one formation
+            if matches!(
+                wave_def.spawn_pattern,
+                SpawnPattern::Line | SpawnPattern::VFormation | SpawnPattern::Circle
+            ) {
+                let leader = *manager.formation_leader.get_or_insert(entity);
+                commands.entity(entity).insert(Formation {
+                    leader,
+                    size: wave_def.enemy_count,
+                });
             }
             manager.enemies_remaining -= 1;
         }
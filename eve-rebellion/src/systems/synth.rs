@@ -0,0 +1,1605 @@
+//! Sound Synthesis
+//!
+//! Pure sample generators for every procedural sound effect and music track.
+//! Each generator is `fn(params, seed) -> Vec<f32>`: mono samples in [-1, 1]
+//! at [`SAMPLE_RATE`]. [`SynthParams`] shifts a preset's pitch, length and
+//! level; the seed drives the noise and a small detune, so the same seed
+//! always renders the same buffer. Nothing here touches Bevy, which keeps the
+//! generators usable from tests, offline tools and any playback backend.
+
+use std::f32::consts::PI;
+
+/// Sample rate of every generated buffer
+pub const SAMPLE_RATE: u32 = 44100;
+
+/// How a generator's preset is rendered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SynthParams {
+    /// Frequency multiplier (2.0 is an octave up)
+    pub pitch: f32,
+    /// Length multiplier; note timings stay put, so a stretched preset
+    /// rings out longer and a shortened one is cut off
+    pub duration: f32,
+    /// Output gain before the final clamp
+    pub volume: f32,
+    /// Largest seeded pitch deviation (0.02 is ±2%)
+    pub detune: f32,
+}
+
+impl Default for SynthParams {
+    fn default() -> Self {
+        Self {
+            pitch: 1.0,
+            duration: 1.0,
+            volume: 1.0,
+            detune: 0.02,
+        }
+    }
+}
+
+impl SynthParams {
+    /// These params with the pitch and length scaled further
+    pub fn scaled(self, pitch: f32, duration: f32) -> Self {
+        Self {
+            pitch: self.pitch * pitch,
+            duration: self.duration * duration,
+            ..self
+        }
+    }
+
+    /// Pitch multiplier with the seeded detune applied
+    fn detuned(&self, rng: &mut fastrand::Rng) -> f32 {
+        self.pitch * (1.0 + (rng.f32() * 2.0 - 1.0) * self.detune)
+    }
+}
+
+/// Every sound the synthesizer can render
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SynthSound {
+    Autocannon,
+    Laser,
+    Missile,
+    ExplosionSmall,
+    ExplosionMedium,
+    ExplosionLarge,
+    Pickup,
+    ShieldHit,
+    ArmorHit,
+    HullHit,
+    ShieldWarning,
+    ArmorWarning,
+    HullWarning,
+    WaveComplete,
+    BossSpawn,
+    WaveStart,
+    BossPhase,
+    PowerupOverdrive,
+    PowerupDamage,
+    PowerupInvuln,
+    PowerupHealth,
+    MenuSelect,
+    MenuConfirm,
+    AbilitySpeed,
+    AbilityShield,
+    AbilityArmor,
+    AbilityWeapon,
+    AbilityDrone,
+    AbilityDebuff,
+    AbilityDamage,
+    AbilityReady,
+    Graze,
+    MenuAmbient,
+    GameplayAmbient,
+    BossAmbient,
+    VictorySting,
+    DefeatSting,
+}
+
+impl SynthSound {
+    /// Every sound in render order (the main game only renders on demand)
+    #[allow(dead_code)]
+    pub const ALL: [SynthSound; 37] = [
+        SynthSound::Autocannon,
+        SynthSound::Laser,
+        SynthSound::Missile,
+        SynthSound::ExplosionSmall,
+        SynthSound::ExplosionMedium,
+        SynthSound::ExplosionLarge,
+        SynthSound::Pickup,
+        SynthSound::ShieldHit,
+        SynthSound::ArmorHit,
+        SynthSound::HullHit,
+        SynthSound::ShieldWarning,
+        SynthSound::ArmorWarning,
+        SynthSound::HullWarning,
+        SynthSound::WaveComplete,
+        SynthSound::BossSpawn,
+        SynthSound::WaveStart,
+        SynthSound::BossPhase,
+        SynthSound::PowerupOverdrive,
+        SynthSound::PowerupDamage,
+        SynthSound::PowerupInvuln,
+        SynthSound::PowerupHealth,
+        SynthSound::MenuSelect,
+        SynthSound::MenuConfirm,
+        SynthSound::AbilitySpeed,
+        SynthSound::AbilityShield,
+        SynthSound::AbilityArmor,
+        SynthSound::AbilityWeapon,
+        SynthSound::AbilityDrone,
+        SynthSound::AbilityDebuff,
+        SynthSound::AbilityDamage,
+        SynthSound::AbilityReady,
+        SynthSound::Graze,
+        SynthSound::MenuAmbient,
+        SynthSound::GameplayAmbient,
+        SynthSound::BossAmbient,
+        SynthSound::VictorySting,
+        SynthSound::DefeatSting,
+    ];
+
+    /// File-friendly name, used when rendering to disk
+    #[allow(dead_code)]
+    pub fn name(self) -> &'static str {
+        match self {
+            SynthSound::Autocannon => "autocannon",
+            SynthSound::Laser => "laser",
+            SynthSound::Missile => "missile",
+            SynthSound::ExplosionSmall => "explosion_small",
+            SynthSound::ExplosionMedium => "explosion_medium",
+            SynthSound::ExplosionLarge => "explosion_large",
+            SynthSound::Pickup => "pickup",
+            SynthSound::ShieldHit => "shield_hit",
+            SynthSound::ArmorHit => "armor_hit",
+            SynthSound::HullHit => "hull_hit",
+            SynthSound::ShieldWarning => "shield_warning",
+            SynthSound::ArmorWarning => "armor_warning",
+            SynthSound::HullWarning => "hull_warning",
+            SynthSound::WaveComplete => "wave_complete",
+            SynthSound::BossSpawn => "boss_spawn",
+            SynthSound::WaveStart => "wave_start",
+            SynthSound::BossPhase => "boss_phase",
+            SynthSound::PowerupOverdrive => "powerup_overdrive",
+            SynthSound::PowerupDamage => "powerup_damage",
+            SynthSound::PowerupInvuln => "powerup_invuln",
+            SynthSound::PowerupHealth => "powerup_health",
+            SynthSound::MenuSelect => "menu_select",
+            SynthSound::MenuConfirm => "menu_confirm",
+            SynthSound::AbilitySpeed => "ability_speed",
+            SynthSound::AbilityShield => "ability_shield",
+            SynthSound::AbilityArmor => "ability_armor",
+            SynthSound::AbilityWeapon => "ability_weapon",
+            SynthSound::AbilityDrone => "ability_drone",
+            SynthSound::AbilityDebuff => "ability_debuff",
+            SynthSound::AbilityDamage => "ability_damage",
+            SynthSound::AbilityReady => "ability_ready",
+            SynthSound::Graze => "graze",
+            SynthSound::MenuAmbient => "menu_ambient",
+            SynthSound::GameplayAmbient => "gameplay_ambient",
+            SynthSound::BossAmbient => "boss_ambient",
+            SynthSound::VictorySting => "victory_sting",
+            SynthSound::DefeatSting => "defeat_sting",
+        }
+    }
+
+    /// Length of the rendered buffer in seconds
+    #[allow(dead_code)]
+    pub fn duration(self) -> f32 {
+        match self {
+            SynthSound::Autocannon => 0.12,
+            SynthSound::Laser => 0.15,
+            SynthSound::Missile => 0.2,
+            SynthSound::ExplosionSmall => 0.15,
+            SynthSound::ExplosionMedium => 0.25,
+            SynthSound::ExplosionLarge => 0.4,
+            SynthSound::Pickup => 0.1,
+            SynthSound::ShieldHit => 0.08,
+            SynthSound::ArmorHit => 0.1,
+            SynthSound::HullHit => 0.12,
+            SynthSound::ShieldWarning => 0.6,
+            SynthSound::ArmorWarning => 0.5,
+            SynthSound::HullWarning => 0.8,
+            SynthSound::WaveComplete => 0.5,
+            SynthSound::BossSpawn => 0.8,
+            SynthSound::WaveStart => 0.35,
+            SynthSound::BossPhase => 0.6,
+            SynthSound::PowerupOverdrive => 0.3,
+            SynthSound::PowerupDamage => 0.25,
+            SynthSound::PowerupInvuln => 0.35,
+            SynthSound::PowerupHealth => 0.2,
+            SynthSound::MenuSelect => 0.05,
+            SynthSound::MenuConfirm => 0.1,
+            SynthSound::AbilitySpeed => 0.4,
+            SynthSound::AbilityShield => 0.35,
+            SynthSound::AbilityArmor => 0.3,
+            SynthSound::AbilityWeapon => 0.25,
+            SynthSound::AbilityDrone => 0.4,
+            SynthSound::AbilityDebuff => 0.35,
+            SynthSound::AbilityDamage => 0.3,
+            SynthSound::AbilityReady => 0.2,
+            SynthSound::Graze => 0.06,
+            SynthSound::MenuAmbient => 30.0,
+            SynthSound::GameplayAmbient => 20.0,
+            SynthSound::BossAmbient => 15.0,
+            SynthSound::VictorySting => 3.0,
+            SynthSound::DefeatSting => 2.5,
+        }
+    }
+
+    /// Render this sound with the given noise seed
+    pub fn render(self, seed: u64) -> Vec<f32> {
+        self.render_with(SynthParams::default(), seed)
+    }
+
+    /// Render this sound with custom pitch, length and level
+    pub fn render_with(self, params: SynthParams, seed: u64) -> Vec<f32> {
+        match self {
+            SynthSound::Autocannon => autocannon(params, seed),
+            SynthSound::Laser => laser(params, seed),
+            SynthSound::Missile => missile(params, seed),
+            // Smaller blasts are shorter and higher
+            SynthSound::ExplosionSmall => explosion(params.scaled(1.5, 0.6), seed),
+            SynthSound::ExplosionMedium => explosion(params, seed),
+            SynthSound::ExplosionLarge => explosion(params.scaled(0.6, 1.6), seed),
+            SynthSound::Pickup => pickup(params, seed),
+            SynthSound::ShieldHit => shield_hit(params, seed),
+            SynthSound::ArmorHit => armor_hit(params, seed),
+            SynthSound::HullHit => hull_hit(params, seed),
+            SynthSound::ShieldWarning => shield_warning(params, seed),
+            SynthSound::ArmorWarning => armor_warning(params, seed),
+            SynthSound::HullWarning => hull_warning(params, seed),
+            SynthSound::WaveComplete => wave_complete(params, seed),
+            SynthSound::BossSpawn => boss_spawn(params, seed),
+            SynthSound::WaveStart => wave_start(params, seed),
+            SynthSound::BossPhase => boss_phase(params, seed),
+            SynthSound::PowerupOverdrive => powerup_overdrive(params, seed),
+            SynthSound::PowerupDamage => powerup_damage(params, seed),
+            SynthSound::PowerupInvuln => powerup_invuln(params, seed),
+            SynthSound::PowerupHealth => powerup_health(params, seed),
+            SynthSound::MenuSelect => menu_select(params, seed),
+            SynthSound::MenuConfirm => menu_confirm(params, seed),
+            SynthSound::AbilitySpeed => ability_speed(params, seed),
+            SynthSound::AbilityShield => ability_shield(params, seed),
+            SynthSound::AbilityArmor => ability_armor(params, seed),
+            SynthSound::AbilityWeapon => ability_weapon(params, seed),
+            SynthSound::AbilityDrone => ability_drone(params, seed),
+            SynthSound::AbilityDebuff => ability_debuff(params, seed),
+            SynthSound::AbilityDamage => ability_damage(params, seed),
+            SynthSound::AbilityReady => ability_ready(params, seed),
+            SynthSound::Graze => graze(params, seed),
+            SynthSound::MenuAmbient => menu_ambient(params, seed),
+            SynthSound::GameplayAmbient => gameplay_ambient(params, seed),
+            SynthSound::BossAmbient => boss_ambient(params, seed),
+            SynthSound::VictorySting => victory_sting(params, seed),
+            SynthSound::DefeatSting => defeat_sting(params, seed),
+        }
+    }
+}
+
+/// Encode samples as a 16-bit mono WAV file in memory
+pub fn encode_wav(samples: &[f32], sample_rate: u32) -> Result<Vec<u8>, hound::Error> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    {
+        let mut writer = hound::WavWriter::new(&mut buffer, spec)?;
+        for &sample in samples {
+            writer.write_sample((sample * 32767.0) as i16)?;
+        }
+        writer.finalize()?;
+    }
+    Ok(buffer.into_inner())
+}
+
+// =============================================================================
+// SOUND EFFECTS
+// =============================================================================
+
+/// Generate autocannon sound - deep industrial thump
+pub fn autocannon(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.12 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Deep bass thump
+        let bass = (2.0 * PI * pitch * 80.0 * t).sin() * 0.5;
+
+        // Mid punch
+        let mid = (2.0 * PI * pitch * 200.0 * t).sin() * (-t * 50.0).exp() * 0.4;
+
+        // High crack
+        let crack = (2.0 * PI * pitch * 600.0 * t).sin() * (-t * 80.0).exp() * 0.3;
+
+        // Noise burst
+        let noise = (rng.f32() * 2.0 - 1.0) * (-t * 40.0).exp() * 0.2;
+
+        // Envelope
+        let env = (-t * 15.0).exp();
+
+        let sample = ((bass + mid + crack + noise) * env * 0.8 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate laser sound - high-pitched zap
+pub fn laser(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.15 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Descending frequency
+        let freq = 1200.0 - t * 3000.0;
+        let wave = (2.0 * PI * pitch * freq * t).sin();
+
+        // Add harmonics
+        let harm = (2.0 * PI * pitch * freq * 2.0 * t).sin() * 0.3;
+
+        // Envelope
+        let env = (-t * 20.0).exp();
+
+        let sample = ((wave + harm) * env * 0.6 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate explosion sound - a medium blast; pitch and length make the
+/// small and large ones
+pub fn explosion(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.25 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Low rumble
+        let rumble = (2.0 * PI * pitch * 200.0 * t).sin() * 0.5;
+
+        // Noise
+        let noise = (rng.f32() * 2.0 - 1.0) * 0.6;
+
+        // Crackle (filtered noise bursts)
+        let crackle = if rng.f32() < 0.1 {
+            rng.f32() * 2.0 - 1.0
+        } else {
+            0.0
+        } * (-t * 5.0).exp()
+            * 0.3;
+
+        // Envelope - quick attack, slow decay
+        let env = (1.0 - (-t * 30.0).exp()) * (-t * 4.0).exp();
+
+        let sample = ((rumble + noise + crackle) * env * 0.7 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate pickup sound - happy blip
+pub fn pickup(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.1 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Rising frequency
+        let freq = 400.0 + t * 2000.0;
+        let wave = (2.0 * PI * pitch * freq * t).sin();
+
+        // Envelope
+        let env = (1.0 - t / duration) * (1.0 - (-t * 50.0).exp());
+
+        let sample = (wave * env * 0.5 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate shield hit sound - electric crackle
+pub fn shield_hit(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.08 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // High frequency buzz
+        let buzz = (2.0 * PI * pitch * 800.0 * t).sin() * 0.4;
+
+        // Electric crackle
+        let crackle = (rng.f32() * 2.0 - 1.0) * 0.5;
+
+        let env = (-t * 30.0).exp();
+
+        let sample = ((buzz + crackle) * env * 0.5 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate armor hit sound - metallic clang
+pub fn armor_hit(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.1 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Metallic frequencies
+        let f1 = (2.0 * PI * pitch * 300.0 * t).sin() * 0.5;
+        let f2 = (2.0 * PI * pitch * 450.0 * t).sin() * 0.3;
+        let f3 = (2.0 * PI * pitch * 180.0 * t).sin() * 0.4;
+
+        let env = (-t * 25.0).exp();
+
+        let sample = ((f1 + f2 + f3) * env * 0.6 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate hull hit sound - deep crunch
+pub fn hull_hit(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.12 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Low crunch
+        let crunch = (2.0 * PI * pitch * 100.0 * t).sin() * 0.6;
+
+        // Noise
+        let noise = (rng.f32() * 2.0 - 1.0) * 0.4;
+
+        let env = (-t * 20.0).exp();
+
+        let sample = ((crunch + noise) * env * 0.6 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate shield warning - high-pitched triple beep (EVE style)
+pub fn shield_warning(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.6 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Three beeps
+        let beep_duration = 0.12;
+        let gap = 0.08;
+        let cycle = beep_duration + gap;
+
+        let beep_num = (t / cycle).floor() as i32;
+        let beep_t = t - (beep_num as f32 * cycle);
+
+        let sample = if beep_num < 3 && beep_t < beep_duration {
+            let freq = 1200.0; // High pitched
+            let wave = (2.0 * PI * pitch * freq * beep_t).sin();
+            let env = (1.0 - (beep_t / beep_duration)).powf(0.5);
+            wave * env * 0.6
+        } else {
+            0.0
+        };
+
+        samples.push((sample * params.volume).clamp(-1.0, 1.0));
+    }
+
+    samples
+}
+
+/// Generate armor warning - mid-tone double beep with urgency (EVE style)
+pub fn armor_warning(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.5 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Two longer beeps
+        let beep_duration = 0.15;
+        let gap = 0.1;
+        let cycle = beep_duration + gap;
+
+        let beep_num = (t / cycle).floor() as i32;
+        let beep_t = t - (beep_num as f32 * cycle);
+
+        let sample = if beep_num < 2 && beep_t < beep_duration {
+            let freq = 800.0; // Mid tone
+            let wave = (2.0 * PI * pitch * freq * beep_t).sin();
+            // Add slight harmonic for urgency
+            let harm = (2.0 * PI * pitch * freq * 1.5 * beep_t).sin() * 0.3;
+            let env = (1.0 - (beep_t / beep_duration)).powf(0.3);
+            (wave + harm) * env * 0.7
+        } else {
+            0.0
+        };
+
+        samples.push((sample * params.volume).clamp(-1.0, 1.0));
+    }
+
+    samples
+}
+
+/// Generate hull warning - low urgent alarm (EVE style critical warning)
+pub fn hull_warning(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.8 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Continuous warbling alarm
+        let base_freq = 400.0;
+        // Frequency modulation for urgency
+        let mod_freq = 8.0; // 8 Hz wobble
+        let freq = base_freq + 100.0 * (2.0 * PI * pitch * mod_freq * t).sin();
+
+        let wave = (2.0 * PI * pitch * freq * t).sin();
+        // Add harmonics for harshness
+        let harm1 = (2.0 * PI * pitch * freq * 2.0 * t).sin() * 0.4;
+        let harm2 = (2.0 * PI * pitch * freq * 3.0 * t).sin() * 0.2;
+
+        // Envelope with attack
+        let env = (1.0 - (-t * 20.0).exp()) * (1.0 - (t / duration).powf(2.0));
+
+        let sample = (wave + harm1 + harm2) * env * 0.65;
+        samples.push((sample * params.volume).clamp(-1.0, 1.0));
+    }
+
+    samples
+}
+
+/// Generate missile launch sound - whooshing rocket
+pub fn missile(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.2 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Whoosh noise
+        let noise = (rng.f32() * 2.0 - 1.0) * 0.5;
+
+        // Rising frequency for ignition
+        let freq = 150.0 + t * 400.0;
+        let rumble = (2.0 * PI * pitch * freq * t).sin() * 0.4;
+
+        // High hiss
+        let hiss = (2.0 * PI * pitch * 2000.0 * t).sin() * 0.15 * (-t * 20.0).exp();
+
+        let env = (1.0 - (-t * 30.0).exp()) * (-t * 8.0).exp();
+
+        let sample = ((noise + rumble + hiss) * env * 0.7 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate wave complete sound - triumphant ascending chime
+pub fn wave_complete(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.5 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Three ascending notes
+        let note1 = if t < 0.15 {
+            (2.0 * PI * pitch * 523.25 * t).sin() * (1.0 - t / 0.15).powf(0.5) // C5
+        } else {
+            0.0
+        };
+
+        let note2 = if (0.12..0.3).contains(&t) {
+            let nt = t - 0.12;
+            (2.0 * PI * pitch * 659.25 * t).sin() * (1.0 - nt / 0.18).powf(0.5) // E5
+        } else {
+            0.0
+        };
+
+        let note3 = if t >= 0.25 {
+            let nt = t - 0.25;
+            (2.0 * PI * pitch * 783.99 * t).sin() * (-nt * 6.0).exp() // G5
+        } else {
+            0.0
+        };
+
+        let sample = ((note1 + note2 + note3) * 0.5 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate boss spawn sound - dramatic low impact
+pub fn boss_spawn(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.8 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Deep impact
+        let bass = (2.0 * PI * pitch * 60.0 * t).sin() * 0.6;
+
+        // Ominous drone
+        let drone = (2.0 * PI * pitch * 100.0 * t).sin() * 0.3;
+        let drone2 = (2.0 * PI * pitch * 150.0 * t).sin() * 0.2;
+
+        // Metallic ring
+        let ring = (2.0 * PI * pitch * 300.0 * t).sin() * (-t * 4.0).exp() * 0.3;
+
+        // Rumble
+        let rumble = (rng.f32() * 2.0 - 1.0) * 0.2 * (-t * 3.0).exp();
+
+        let env = (1.0 - (-t * 10.0).exp()) * (-t * 2.5).exp();
+
+        let sample =
+            ((bass + drone + drone2 + ring + rumble) * env * 0.7 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate wave start sound - two rising radar pings
+pub fn wave_start(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.35 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // A4 then D5, each a short decaying ping
+        let ping1 = if t < 0.15 {
+            (2.0 * PI * pitch * 440.0 * t).sin() * (-t * 18.0).exp()
+        } else {
+            0.0
+        };
+
+        let ping2 = if t >= 0.15 {
+            let nt = t - 0.15;
+            (2.0 * PI * pitch * 587.33 * t).sin() * (-nt * 14.0).exp()
+        } else {
+            0.0
+        };
+
+        let attack = (t / 0.004).min(1.0);
+        let sample = ((ping1 + ping2) * attack * 0.5 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate boss phase change sound - descending horn over a low thud
+pub fn boss_phase(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.6 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+        let progress = t / duration;
+
+        // Horn sweeping down a fifth
+        let freq = 220.0 - 73.0 * progress;
+        let horn = (2.0 * PI * pitch * freq * t).sin() * 0.5
+            + (2.0 * PI * pitch * freq * 2.0 * t).sin() * 0.2;
+
+        // Thud at the start
+        let thud = (2.0 * PI * pitch * 55.0 * t).sin() * (-t * 8.0).exp() * 0.5;
+        let grit = (rng.f32() * 2.0 - 1.0) * 0.1 * (-t * 10.0).exp();
+
+        let env = (1.0 - (-t * 30.0).exp()) * (1.0 - progress).powf(0.7);
+
+        let sample = ((horn + thud + grit) * env * 0.7 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate overdrive powerup sound - engine rev
+pub fn powerup_overdrive(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.3 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Rising engine freq
+        let freq = 200.0 + t * 600.0;
+        let engine = (2.0 * PI * pitch * freq * t).sin() * 0.5;
+
+        // Turbo whoosh
+        let whoosh = (rng.f32() * 2.0 - 1.0) * 0.3 * (t * 4.0).min(1.0);
+
+        let env = (1.0 - (-t * 20.0).exp()) * (1.0 - (t / duration).powf(2.0));
+
+        let sample = ((engine + whoosh) * env * 0.6 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate damage boost powerup sound - power surge
+pub fn powerup_damage(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.25 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Power charge
+        let charge = (2.0 * PI * pitch * (400.0 + t * 800.0) * t).sin() * 0.5;
+
+        // Electric crackle
+        let crackle = if rng.f32() < 0.15 {
+            (rng.f32() * 2.0 - 1.0) * 0.4
+        } else {
+            0.0
+        };
+
+        let env = (1.0 - (-t * 30.0).exp()) * (-t * 6.0).exp();
+
+        let sample = ((charge + crackle) * env * 0.6 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate invulnerability powerup sound - shield activation
+pub fn powerup_invuln(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.35 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Shield hum
+        let hum = (2.0 * PI * pitch * 300.0 * t).sin() * 0.3;
+
+        // Shimmer
+        let shimmer = (2.0 * PI * pitch * 1200.0 * t).sin() * 0.2 * (t * 8.0).sin().abs();
+
+        // Bass impact
+        let bass = (2.0 * PI * pitch * 80.0 * t).sin() * 0.4 * (-t * 15.0).exp();
+
+        let env = (1.0 - (-t * 20.0).exp()) * (1.0 - (t / duration).powf(3.0));
+
+        let sample = ((hum + shimmer + bass) * env * 0.6 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate health restore powerup sound - healing chime
+pub fn powerup_health(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.2 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Gentle ascending tone
+        let freq = 600.0 + t * 400.0;
+        let tone = (2.0 * PI * pitch * freq * t).sin() * 0.4;
+
+        // Soft shimmer
+        let shimmer = (2.0 * PI * pitch * freq * 2.0 * t).sin() * 0.15;
+
+        let env = (1.0 - (-t * 30.0).exp()) * (-t * 8.0).exp();
+
+        let sample = ((tone + shimmer) * env * 0.5 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate menu navigation sound - soft blip
+pub fn menu_select(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.05 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        let wave = (2.0 * PI * pitch * 800.0 * t).sin();
+        let env = (-t * 60.0).exp();
+
+        let sample = (wave * env * 0.4 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate menu confirm sound - satisfying click
+pub fn menu_confirm(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.1 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        let wave1 = (2.0 * PI * pitch * 600.0 * t).sin() * 0.4;
+        let wave2 = (2.0 * PI * pitch * 900.0 * t).sin() * 0.3;
+
+        let env = (-t * 30.0).exp();
+
+        let sample = ((wave1 + wave2) * env * 0.5 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate speed ability sound - engine boost whoosh
+pub fn ability_speed(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.4 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Rising engine frequency
+        let freq = 150.0 + t * 800.0;
+        let engine = (2.0 * PI * pitch * freq * t).sin() * 0.4;
+
+        // Turbo whoosh (filtered noise)
+        let whoosh = (rng.f32() * 2.0 - 1.0) * 0.35 * (t * 5.0).min(1.0);
+
+        // High overtone
+        let high = (2.0 * PI * pitch * (freq * 2.5) * t).sin() * 0.15 * (t * 8.0).min(1.0);
+
+        let env = (1.0 - (-t * 15.0).exp()) * (1.0 - (t / duration).powf(1.5));
+
+        let sample = ((engine + whoosh + high) * env * 0.7 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate shield ability sound - energy bubble activation
+pub fn ability_shield(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.35 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Shield activation sweep
+        let freq = 800.0 - t * 400.0;
+        let sweep = (2.0 * PI * pitch * freq * t).sin() * 0.4;
+
+        // Shimmer
+        let shimmer =
+            (2.0 * PI * pitch * 2400.0 * t).sin() * 0.2 * (1.0 + (PI * 20.0 * t).sin() * 0.5);
+
+        // Bubble pop at start
+        let pop = (2.0 * PI * pitch * 300.0 * t).sin() * (-t * 60.0).exp() * 0.3;
+
+        let env = (1.0 - (-t * 25.0).exp()) * (-t * 4.0).exp();
+
+        let sample = ((sweep + shimmer + pop) * env * 0.6 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate armor ability sound - metallic clang/hardening
+pub fn ability_armor(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.3 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Metallic clang
+        let clang = (2.0 * PI * pitch * 400.0 * t).sin() * 0.3 * (-t * 20.0).exp();
+
+        // Harmonic overtones (metallic)
+        let harm1 = (2.0 * PI * pitch * 800.0 * t).sin() * 0.2 * (-t * 25.0).exp();
+        let harm2 = (2.0 * PI * pitch * 1200.0 * t).sin() * 0.15 * (-t * 30.0).exp();
+
+        // Low rumble for weight
+        let rumble = (2.0 * PI * pitch * 80.0 * t).sin() * 0.25 * (-t * 10.0).exp();
+
+        let sample = ((clang + harm1 + harm2 + rumble) * 0.7 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate weapon ability sound - charging burst
+pub fn ability_weapon(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.25 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Rapid charge up
+        let freq = 200.0 + t * 1200.0;
+        let charge = (2.0 * PI * pitch * freq * t).sin() * 0.4;
+
+        // Burst
+        let burst = if t > 0.15 {
+            (2.0 * PI * pitch * 500.0 * t).sin() * 0.5 * (-(t - 0.15) * 40.0).exp()
+        } else {
+            0.0
+        };
+
+        // Crackle
+        let crackle = if rng.f32() < 0.1 {
+            (rng.f32() * 2.0 - 1.0) * 0.3
+        } else {
+            0.0
+        };
+
+        let env = 1.0 - (-t * 40.0).exp();
+
+        let sample = ((charge + burst + crackle) * env * 0.6 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate drone ability sound - mechanical launch
+pub fn ability_drone(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.4 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Mechanical hum
+        let hum = (2.0 * PI * pitch * 120.0 * t).sin() * 0.3;
+
+        // Drone whine (rising)
+        let freq = 400.0 + t * 300.0;
+        let whine = (2.0 * PI * pitch * freq * t).sin() * 0.25;
+
+        // Launch click
+        let click = (2.0 * PI * pitch * 1000.0 * t).sin() * (-t * 100.0).exp() * 0.4;
+
+        // Propeller flutter
+        let flutter = (2.0 * PI * pitch * 60.0 * t).sin() * 0.15 * (t * 4.0).min(1.0);
+
+        let env = (1.0 - (-t * 20.0).exp()) * (1.0 - (t / duration).powf(2.0));
+
+        let sample = ((hum + whine + click + flutter) * env * 0.6 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate debuff ability sound - disrupting pulse
+pub fn ability_debuff(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.35 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Warping frequency
+        let warp = (2.0 * PI * pitch * (300.0 + 200.0 * (PI * 15.0 * t).sin()) * t).sin() * 0.4;
+
+        // Disruptor pulse
+        let pulse = (2.0 * PI * pitch * 100.0 * t).sin() * 0.3 * (1.0 + (PI * 8.0 * t).sin() * 0.5);
+
+        // Static
+        let static_noise = (rng.f32() * 2.0 - 1.0) * 0.15;
+
+        let env = (1.0 - (-t * 20.0).exp()) * (-t * 5.0).exp();
+
+        let sample = ((warp + pulse + static_noise) * env * 0.6 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate damage ability sound - power surge
+pub fn ability_damage(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 0.3 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Power charge
+        let charge = (2.0 * PI * pitch * (500.0 + t * 600.0) * t).sin() * 0.4;
+
+        // Impact hit
+        let impact = (2.0 * PI * pitch * 150.0 * t).sin() * (-t * 30.0).exp() * 0.5;
+
+        // Crackle
+        let crackle = if rng.f32() < 0.12 {
+            (rng.f32() * 2.0 - 1.0) * 0.35
+        } else {
+            0.0
+        };
+
+        let env = (1.0 - (-t * 35.0).exp()) * (-t * 6.0).exp();
+
+        let sample = ((charge + impact + crackle) * env * 0.7 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate ability ready sound - soft double tick
+pub fn ability_ready(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 0.2 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Two quick ticks a fourth apart
+        let (freq, nt) = if t < 0.08 {
+            (987.77, t) // B5
+        } else {
+            (1318.51, t - 0.08) // E6
+        };
+
+        let env = (nt / 0.003).min(1.0) * (-nt * 30.0).exp();
+        let sample =
+            ((2.0 * PI * pitch * freq * t).sin() * env * 0.45 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate graze sound - soft high shimmer, quiet enough to repeat fast
+pub fn graze(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let duration = 0.06 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Falling whistle with a breath of noise
+        let freq = 2600.0 - t * 8000.0;
+        let tone = (2.0 * PI * pitch * freq * t).sin();
+        let noise = rng.f32() * 2.0 - 1.0;
+
+        let env = (t / 0.004).min(1.0) * (-t * 60.0).exp();
+        let sample = ((tone * 0.8 + noise * 0.2) * env * 0.3 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+// =============================================================================
+// MUSIC
+// =============================================================================
+
+/// Generate menu ambient - ethereal, space atmosphere (30 seconds loop)
+pub fn menu_ambient(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 30.0 * params.duration; // 30 second loop
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Deep drone pad (shifting between notes)
+        let drone_freq = 55.0 + 10.0 * (0.1 * t).sin(); // A1 with slow drift
+        let drone = (2.0 * PI * pitch * drone_freq * t).sin() * 0.15;
+
+        // Fifth above (creates space feeling)
+        let fifth_freq = drone_freq * 1.5;
+        let fifth = (2.0 * PI * pitch * fifth_freq * t).sin() * 0.08;
+
+        // Slow LFO modulated pad
+        let lfo = (2.0 * PI * pitch * 0.05 * t).sin(); // Very slow modulation
+        let pad_freq = 110.0 + lfo * 5.0;
+        let pad = (2.0 * PI * pitch * pad_freq * t).sin()
+            * 0.06
+            * (0.5 + 0.5 * (2.0 * PI * pitch * 0.03 * t).sin());
+
+        // Ethereal shimmer (high frequencies)
+        let shimmer_freq = 880.0 + 220.0 * (0.07 * t).sin();
+        let shimmer = (2.0 * PI * pitch * shimmer_freq * t).sin()
+            * 0.02
+            * (0.5 + 0.5 * (2.0 * PI * pitch * 0.02 * t).sin());
+
+        // Occasional distant "star" twinkles
+        let twinkle = if (t * 0.3).fract() < 0.01 {
+            let tw_freq = 1200.0 + 400.0 * ((t * 7.0).sin());
+            (2.0 * PI * pitch * tw_freq * t).sin() * 0.03 * (-(t * 0.3).fract() * 100.0).exp()
+        } else {
+            0.0
+        };
+
+        // Mix with subtle fade in/out for seamless loop
+        let loop_env = if t < 2.0 {
+            t / 2.0
+        } else if t > duration - 2.0 {
+            (duration - t) / 2.0
+        } else {
+            1.0
+        };
+
+        let sample = ((drone + fifth + pad + shimmer + twinkle) * loop_env * 0.8 * params.volume)
+            .clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate gameplay ambient - tense, driving (20 seconds loop)
+pub fn gameplay_ambient(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 20.0 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    let bpm = 120.0;
+    let beat_duration = 60.0 / bpm;
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+        let beat = (t / beat_duration).floor();
+        let beat_phase = (t / beat_duration).fract();
+
+        // Driving bass pulse (on the beat)
+        let bass_freq = if beat as i32 % 4 == 0 { 55.0 } else { 41.25 }; // A1 / E1
+        let bass_env = (-beat_phase * 8.0).exp();
+        let bass = (2.0 * PI * pitch * bass_freq * t).sin() * bass_env * 0.2;
+
+        // Sub-bass rumble
+        let sub = (2.0 * PI * pitch * 30.0 * t).sin() * 0.1;
+
+        // Pulsing synth (offbeat)
+        let synth_freq = 110.0;
+        let synth_env = if beat_phase > 0.5 {
+            (-(beat_phase - 0.5) * 10.0).exp()
+        } else {
+            0.0
+        };
+        let synth = (2.0 * PI * pitch * synth_freq * t).sin() * synth_env * 0.08;
+
+        // High tension string-like pad
+        let tension_freq = 220.0 * (1.0 + 0.01 * (t * 0.5).sin()); // Slight detune for tension
+        let tension = (2.0 * PI * pitch * tension_freq * t).sin() * 0.05;
+
+        // Rhythmic hi-hat-like noise
+        let hihat_phase = (t * 4.0 / beat_duration).fract();
+        let hihat = if hihat_phase < 0.1 {
+            (rng.f32() * 2.0 - 1.0) * (-hihat_phase * 50.0).exp() * 0.03
+        } else {
+            0.0
+        };
+
+        // Loop envelope
+        let loop_env = if t < 1.0 {
+            t
+        } else if t > duration - 1.0 {
+            duration - t
+        } else {
+            1.0
+        };
+
+        let sample = ((bass + sub + synth + tension + hihat) * loop_env * 0.9 * params.volume)
+            .clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate boss ambient - intense, urgent (15 seconds loop)
+pub fn boss_ambient(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let mut rng = fastrand::Rng::with_seed(seed);
+    let pitch = params.detuned(&mut rng);
+    let duration = 15.0 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    let bpm = 140.0; // Faster tempo
+    let beat_duration = 60.0 / bpm;
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+        let beat = (t / beat_duration).floor();
+        let beat_phase = (t / beat_duration).fract();
+
+        // Heavy bass hit every beat
+        let bass_freq = 36.7; // D1 - ominous
+        let bass_env = (-beat_phase * 12.0).exp();
+        let bass = (2.0 * PI * pitch * bass_freq * t).sin() * bass_env * 0.25;
+
+        // Distorted bass overtones
+        let dist = (2.0 * PI * pitch * bass_freq * 2.0 * t).sin() * bass_env * 0.1;
+
+        // Urgent alarm-like synth (tritone for tension)
+        let alarm_freq = if beat as i32 % 2 == 0 { 293.66 } else { 415.3 }; // D4 / Ab4 tritone
+        let alarm_env = (-beat_phase * 6.0).exp();
+        let alarm = (2.0 * PI * pitch * alarm_freq * t).sin() * alarm_env * 0.06;
+
+        // Rapid hi-hats
+        let hh_phase = (t * 8.0 / beat_duration).fract();
+        let hihat = (rng.f32() * 2.0 - 1.0) * (-hh_phase * 40.0).exp() * 0.04;
+
+        // Tension riser (pitch goes up over time, resets at loop)
+        let riser_freq = 200.0 + (t / duration) * 400.0;
+        let riser = (2.0 * PI * pitch * riser_freq * t).sin() * 0.03;
+
+        // Loop envelope
+        let loop_env = if t < 0.5 {
+            t * 2.0
+        } else if t > duration - 0.5 {
+            (duration - t) * 2.0
+        } else {
+            1.0
+        };
+
+        let sample =
+            ((bass + dist + alarm + hihat + riser) * loop_env * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate victory sting - triumphant, short
+pub fn victory_sting(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 3.0 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Rising arpeggio: C - E - G - C (major chord)
+        let note = if t < 0.3 {
+            261.63 // C4
+        } else if t < 0.6 {
+            329.63 // E4
+        } else if t < 0.9 {
+            392.0 // G4
+        } else {
+            523.25 // C5 (hold through end)
+        };
+
+        let note_t = t % 0.3;
+        let note_env = if t < 1.2 {
+            (1.0 - note_t / 0.3).powf(0.3)
+        } else {
+            (-(t - 1.2) * 1.5).exp()
+        };
+
+        let melody = (2.0 * PI * pitch * note * t).sin() * note_env * 0.3;
+
+        // Harmony pad
+        let pad = (2.0 * PI * pitch * 130.81 * t).sin() * 0.1 // C3
+            + (2.0 * PI * pitch * 164.81 * t).sin() * 0.08 // E3
+            + (2.0 * PI * pitch * 196.0 * t).sin() * 0.08; // G3
+
+        let pad_env = (-(t - 1.0).max(0.0) * 0.5).exp();
+
+        // Shimmer
+        let shimmer =
+            (2.0 * PI * pitch * 1046.5 * t).sin() * 0.02 * (-(t - 0.5).max(0.0) * 2.0).exp();
+
+        let sample = ((melody + pad * pad_env + shimmer) * 0.8 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+/// Generate defeat sting - somber, short
+pub fn defeat_sting(params: SynthParams, seed: u64) -> Vec<f32> {
+    let sample_rate = SAMPLE_RATE;
+    let pitch = params.detuned(&mut fastrand::Rng::with_seed(seed));
+    let duration = 2.5 * params.duration;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Descending minor: E - D - C - B (sad descent)
+        let note = if t < 0.4 {
+            329.63 // E4
+        } else if t < 0.8 {
+            293.66 // D4
+        } else if t < 1.2 {
+            261.63 // C4
+        } else {
+            246.94 // B3
+        };
+
+        let note_env = (-(t % 0.4) * 3.0).exp() * (-t * 0.8).exp();
+        let melody = (2.0 * PI * pitch * note * t).sin() * note_env * 0.25;
+
+        // Minor pad (Am)
+        let pad = (2.0 * PI * pitch * 110.0 * t).sin() * 0.1 // A2
+            + (2.0 * PI * pitch * 130.81 * t).sin() * 0.08 // C3
+            + (2.0 * PI * pitch * 164.81 * t).sin() * 0.08; // E3
+
+        let pad_env = (-t * 0.5).exp();
+
+        // Low rumble
+        let rumble = (2.0 * PI * pitch * 55.0 * t).sin() * 0.08 * (-t * 0.3).exp();
+
+        let sample = ((melody + pad * pad_env + rumble) * 0.8 * params.volume).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peak(samples: &[f32]) -> f32 {
+        samples.iter().fold(0.0f32, |m, s| m.max(s.abs()))
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn every_sound_has_expected_length() {
+        for sound in SynthSound::ALL {
+            let samples = sound.render(1);
+            let expected = (SAMPLE_RATE as f32 * sound.duration()) as usize;
+            assert_eq!(samples.len(), expected, "{}", sound.name());
+        }
+    }
+
+    #[test]
+    fn every_sound_is_finite_and_in_range() {
+        for sound in SynthSound::ALL {
+            for (i, s) in sound.render(7).iter().enumerate() {
+                assert!(s.is_finite(), "{} sample {} is {}", sound.name(), i, s);
+                assert!(s.abs() <= 1.0, "{} sample {} is {}", sound.name(), i, s);
+            }
+        }
+    }
+
+    #[test]
+    fn every_sound_is_audible_without_clipping() {
+        for sound in SynthSound::ALL {
+            let samples = sound.render(42);
+            let peak = peak(&samples);
+            let rms = rms(&samples);
+            assert!(
+                peak > 0.05,
+                "{} is near-silent (peak {})",
+                sound.name(),
+                peak
+            );
+            assert!(peak < 1.0, "{} clips (peak {})", sound.name(), peak);
+            assert!(rms > 0.005, "{} is too quiet (rms {})", sound.name(), rms);
+            assert!(rms < 0.5, "{} is too hot (rms {})", sound.name(), rms);
+        }
+    }
+
+    #[test]
+    fn same_seed_renders_same_buffer() {
+        for sound in SynthSound::ALL {
+            assert_eq!(sound.render(3), sound.render(3), "{}", sound.name());
+        }
+    }
+
+    #[test]
+    fn noise_follows_seed() {
+        let params = SynthParams::default();
+        assert_ne!(autocannon(params, 1), autocannon(params, 2));
+        assert_ne!(explosion(params, 1), explosion(params, 2));
+        // Tonal sounds are detuned by the seed
+        assert_ne!(laser(params, 1), laser(params, 2));
+        let exact = SynthParams {
+            detune: 0.0,
+            ..params
+        };
+        assert_eq!(laser(exact, 1), laser(exact, 2));
+    }
+
+    #[test]
+    fn params_shape_the_render() {
+        let base = SynthParams {
+            detune: 0.0,
+            ..SynthParams::default()
+        };
+        let sound = SynthSound::MenuConfirm;
+        let normal = sound.render_with(base, 5);
+
+        let long = sound.render_with(base.scaled(1.0, 2.0), 5);
+        assert_eq!(
+            long.len(),
+            (SAMPLE_RATE as f32 * sound.duration() * 2.0) as usize
+        );
+
+        let quiet = sound.render_with(
+            SynthParams {
+                volume: 0.5,
+                ..base
+            },
+            5,
+        );
+        assert!((peak(&quiet) - peak(&normal) * 0.5).abs() < 1e-4);
+
+        // An octave up crosses zero about twice as often
+        let crossings = |samples: &[f32]| {
+            samples
+                .windows(2)
+                .filter(|w| (w[0] < 0.0) != (w[1] < 0.0))
+                .count() as f32
+        };
+        let high = sound.render_with(base.scaled(2.0, 1.0), 5);
+        let ratio = crossings(&high) / crossings(&normal);
+        assert!((1.8..2.2).contains(&ratio), "ratio {}", ratio);
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let names: std::collections::HashSet<_> =
+            SynthSound::ALL.iter().map(|s| s.name()).collect();
+        assert_eq!(names.len(), SynthSound::ALL.len());
+    }
+
+    #[test]
+    fn wav_round_trips() {
+        let samples = pickup(SynthParams::default(), 0);
+        let wav = encode_wav(&samples, SAMPLE_RATE).unwrap();
+        let reader = hound::WavReader::new(std::io::Cursor::new(wav)).unwrap();
+        assert_eq!(reader.spec().sample_rate, SAMPLE_RATE);
+        assert_eq!(reader.len() as usize, samples.len());
+    }
+}
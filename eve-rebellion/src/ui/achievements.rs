@@ -0,0 +1,382 @@
+//! Achievements Browser
+//!
+//! Reached from the main menu. One tab per achievement set (general, then
+//! each module's), listing every achievement with its scope and a progress
+//! bar of the best value reached. Hidden achievements show as "???" until
+//! unlocked. Left/right or the bumpers switch sets, up/down scrolls; the
+//! screen is rebuilt on every change.
+
+use crate::core::*;
+use crate::systems::JoystickState;
+use bevy::prelude::*;
+
+use super::TransitionEvent;
+
+/// Achievements browser plugin
+pub struct AchievementsBrowserPlugin;
+
+impl Plugin for AchievementsBrowserPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AchievementBrowser>()
+            .add_systems(OnEnter(GameState::Achievements), spawn_achievements_screen)
+            .add_systems(
+                Update,
+                achievements_input.run_if(in_state(GameState::Achievements)),
+            )
+            .add_systems(OnExit(GameState::Achievements), despawn_achievements_screen);
+    }
+}
+
+/// Root of the achievements browser
+#[derive(Component)]
+pub struct AchievementsRoot;
+
+/// Selected set and row
+#[derive(Resource, Default, Debug)]
+pub struct AchievementBrowser {
+    pub set: usize,
+    pub cursor: usize,
+}
+
+/// Rows visible at once
+const VISIBLE_ROWS: usize = 8;
+
+/// First row of the scrolled window that keeps the cursor in view
+fn window_start(cursor: usize, len: usize) -> usize {
+    cursor
+        .saturating_sub(VISIBLE_ROWS / 2)
+        .min(len.saturating_sub(VISIBLE_ROWS))
+}
+
+fn spawn_achievements_screen(
+    mut commands: Commands,
+    mut browser: ResMut<AchievementBrowser>,
+    registry: Res<AchievementRegistry>,
+    save_data: Res<SaveData>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    browser.cursor = 0;
+    browser.set = browser.set.min(registry.sets.len().saturating_sub(1));
+    build_achievements_screen(&mut commands, &browser, &registry, &save_data);
+    narration.send(NarrationEvent::queued(row_narration(
+        &browser, &registry, &save_data,
+    )));
+}
+
+fn build_achievements_screen(
+    commands: &mut Commands,
+    browser: &AchievementBrowser,
+    registry: &AchievementRegistry,
+    save_data: &SaveData,
+) {
+    let accent = Color::srgb(1.0, 0.85, 0.2);
+    let (unlocked, total) = save_data.achievement_progress(registry);
+    let set = registry.sets[browser.set];
+
+    commands
+        .spawn((
+            AchievementsRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.95)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("ACHIEVEMENTS"),
+                TextFont {
+                    font_size: 42.0,
+                    ..default()
+                },
+                TextColor(accent),
+            ));
+            parent.spawn((
+                Text::new(format!("{} / {} unlocked", unlocked, total)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+
+            // Set tabs
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(16.0),
+                    ..default()
+                })
+                .with_children(|tabs| {
+                    for (index, tab) in registry.sets.iter().enumerate() {
+                        let selected = index == browser.set;
+                        tabs.spawn((
+                            Text::new(tab.name.to_uppercase()),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(if selected {
+                                accent
+                            } else {
+                                Color::srgb(0.4, 0.4, 0.4)
+                            }),
+                        ));
+                    }
+                });
+
+            let start = window_start(browser.cursor, set.achievements.len());
+            for (index, def) in set
+                .achievements
+                .iter()
+                .enumerate()
+                .skip(start)
+                .take(VISIBLE_ROWS)
+            {
+                spawn_achievement_row(parent, def, save_data, index == browser.cursor);
+            }
+
+            parent.spawn((
+                Text::new("←→/LB RB Set • ↑↓ Scroll • ESC Back"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+            ));
+        });
+}
+
+fn spawn_achievement_row(
+    parent: &mut ChildBuilder,
+    def: &AchievementDef,
+    save_data: &SaveData,
+    selected: bool,
+) {
+    let unlocked = save_data.has_achievement(def.id);
+    let concealed = def.hidden && !unlocked;
+    let progress = best_progress(def, &save_data.achievement_stats);
+    let fraction = progress as f32 / def.threshold as f32;
+    let (name, description) = if concealed {
+        ("???", "Hidden achievement")
+    } else {
+        (def.name, def.description)
+    };
+    let name_color = if unlocked {
+        def.color()
+    } else {
+        Color::srgb(0.6, 0.6, 0.6)
+    };
+
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(620.0),
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(6.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BorderColor(if selected {
+                Color::srgb(1.0, 0.85, 0.2)
+            } else {
+                Color::srgb(0.2, 0.2, 0.2)
+            }),
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.05, 0.8)),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Text::new(format!(
+                    "{}{}  [{}]",
+                    if unlocked { "✓ " } else { "" },
+                    name,
+                    def.scope.name()
+                )),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(name_color),
+            ));
+            row.spawn((
+                Text::new(description),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+            if concealed {
+                return;
+            }
+            row.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|line| {
+                line.spawn((
+                    Node {
+                        width: Val::Px(420.0),
+                        height: Val::Px(6.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.1)),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width: Val::Percent(if unlocked { 100.0 } else { fraction * 100.0 }),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(if unlocked {
+                            def.color()
+                        } else {
+                            Color::srgb(0.3, 0.8, 1.0)
+                        }),
+                    ));
+                });
+                line.spawn((
+                    Text::new(if unlocked {
+                        "UNLOCKED".to_string()
+                    } else {
+                        format!("{}/{}", progress, def.threshold)
+                    }),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                ));
+            });
+        });
+}
+
+/// Spoken description of the selected achievement
+fn row_narration(
+    browser: &AchievementBrowser,
+    registry: &AchievementRegistry,
+    save_data: &SaveData,
+) -> String {
+    let set = registry.sets[browser.set];
+    let Some(def) = set.achievements.get(browser.cursor) else {
+        return format!("{}, empty", set.name);
+    };
+    let unlocked = save_data.has_achievement(def.id);
+    if def.hidden && !unlocked {
+        return format!("{}. Hidden achievement", set.name);
+    }
+    let status = if unlocked {
+        "unlocked".to_string()
+    } else {
+        format!(
+            "{} of {}",
+            best_progress(def, &save_data.achievement_stats),
+            def.threshold
+        )
+    };
+    format!(
+        "{}. {}, {}. {}",
+        set.name, def.name, def.description, status
+    )
+}
+
+fn achievements_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut browser: ResMut<AchievementBrowser>,
+    registry: Res<AchievementRegistry>,
+    save_data: Res<SaveData>,
+    roots: Query<Entity, With<AchievementsRoot>>,
+    mut narration: EventWriter<NarrationEvent>,
+    mut transitions: EventWriter<TransitionEvent>,
+) {
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        transitions.send(TransitionEvent::quick(GameState::MainMenu));
+        return;
+    }
+
+    let sets = registry.sets.len();
+    let rows = registry.sets[browser.set].achievements.len();
+    let mut changed = false;
+
+    if keyboard.just_pressed(KeyCode::ArrowRight)
+        || keyboard.just_pressed(KeyCode::KeyD)
+        || joystick.dpad_just_right()
+        || joystick.right_bumper()
+    {
+        browser.set = (browser.set + 1) % sets;
+        browser.cursor = 0;
+        changed = true;
+    }
+    if keyboard.just_pressed(KeyCode::ArrowLeft)
+        || keyboard.just_pressed(KeyCode::KeyA)
+        || joystick.dpad_just_left()
+        || joystick.left_bumper()
+    {
+        browser.set = (browser.set + sets - 1) % sets;
+        browser.cursor = 0;
+        changed = true;
+    }
+    if rows > 0
+        && (keyboard.just_pressed(KeyCode::ArrowDown)
+            || keyboard.just_pressed(KeyCode::KeyS)
+            || joystick.dpad_just_down())
+    {
+        browser.cursor = (browser.cursor + 1) % rows;
+        changed = true;
+    }
+    if rows > 0
+        && (keyboard.just_pressed(KeyCode::ArrowUp)
+            || keyboard.just_pressed(KeyCode::KeyW)
+            || joystick.dpad_just_up())
+    {
+        browser.cursor = (browser.cursor + rows - 1) % rows;
+        changed = true;
+    }
+
+    if changed {
+        for root in roots.iter() {
+            commands.entity(root).despawn_recursive();
+        }
+        build_achievements_screen(&mut commands, &browser, &registry, &save_data);
+        narration.send(NarrationEvent::new(row_narration(
+            &browser, &registry, &save_data,
+        )));
+    }
+}
+
+fn despawn_achievements_screen(
+    mut commands: Commands,
+    roots: Query<Entity, With<AchievementsRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_keeps_cursor_visible() {
+        assert_eq!(window_start(0, 30), 0);
+        assert_eq!(window_start(10, 30), 6);
+        assert_eq!(window_start(29, 30), 22);
+        assert_eq!(window_start(3, 5), 0);
+        for cursor in 0..30 {
+            let start = window_start(cursor, 30);
+            assert!(cursor >= start && cursor < start + VISIBLE_ROWS);
+        }
+    }
+}
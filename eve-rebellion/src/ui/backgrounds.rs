@@ -20,10 +20,18 @@ impl Plugin for BackgroundPlugin {
             .init_resource::<BackgroundShipSpawnTimer>()
             .add_systems(Startup, load_backgrounds)
             .add_systems(OnEnter(GameState::Loading), spawn_title_background)
+            .add_systems(OnEnter(GameState::ProfileSelect), spawn_title_background)
             .add_systems(OnEnter(GameState::MainMenu), spawn_title_background)
+            .add_systems(OnEnter(GameState::Achievements), spawn_title_background)
+            .add_systems(OnEnter(GameState::Leaderboards), spawn_title_background)
+            .add_systems(OnEnter(GameState::Challenges), spawn_title_background)
             .add_systems(OnEnter(GameState::DifficultySelect), spawn_title_background)
             .add_systems(OnEnter(GameState::ShipSelect), spawn_title_background)
+            .add_systems(OnExit(GameState::ProfileSelect), despawn_menu_background)
             .add_systems(OnExit(GameState::MainMenu), despawn_menu_background)
+            .add_systems(OnExit(GameState::Achievements), despawn_menu_background)
+            .add_systems(OnExit(GameState::Leaderboards), despawn_menu_background)
+            .add_systems(OnExit(GameState::Challenges), despawn_menu_background)
             .add_systems(OnExit(GameState::DifficultySelect), despawn_menu_background)
             .add_systems(OnExit(GameState::ShipSelect), despawn_menu_background)
             .add_systems(OnExit(GameState::Loading), despawn_menu_background)
@@ -0,0 +1,461 @@
+//! Campaign Map
+//!
+//! Star map stage select for modules with a [`CampaignMap`]. Missions are
+//! systems joined by jump lines; the player moves between them with the
+//! arrow keys, launches available ones and commits to branch choices.
+//! Core missions go on to ship select; any other node is handed to its
+//! module with [`MapNodeLaunched`].
+
+use crate::core::*;
+use crate::games::{campaign_map_active, core_enabled, ActiveModule, CoreSystem, GameModules};
+use crate::systems::JoystickState;
+use bevy::prelude::*;
+
+const MAP_NAV_COOLDOWN: f32 = 0.15;
+const LAYER_MAP: f32 = 50.0;
+
+/// Campaign map plugin
+pub struct CampaignMapPlugin;
+
+impl Plugin for CampaignMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CampaignMapCursor>()
+            .add_event::<MapNodeLaunched>()
+            .add_systems(
+                OnEnter(GameState::StageSelect),
+                spawn_campaign_map
+                    .run_if(core_enabled(CoreSystem::StageSelect))
+                    .run_if(campaign_map_active),
+            )
+            .add_systems(
+                Update,
+                (campaign_map_input, update_campaign_map)
+                    .chain()
+                    .run_if(in_state(GameState::StageSelect))
+                    .run_if(core_enabled(CoreSystem::StageSelect))
+                    .run_if(campaign_map_active),
+            )
+            .add_systems(OnExit(GameState::StageSelect), despawn_campaign_map);
+    }
+}
+
+/// Selected node and pending branch commitment
+#[derive(Resource, Default)]
+pub struct CampaignMapCursor {
+    pub index: usize,
+    /// Confirm was pressed once on a branch node; the next press commits
+    pub confirm_pending: bool,
+}
+
+#[derive(Component)]
+struct CampaignMapRoot;
+
+#[derive(Component)]
+struct MapNodeMarker {
+    index: usize,
+}
+
+#[derive(Component)]
+struct MapSelectionRing;
+
+#[derive(Component)]
+struct MapDetailsText;
+
+fn status_color(status: NodeStatus, side: bool) -> Color {
+    match status {
+        NodeStatus::Completed => Color::srgb(0.3, 0.9, 0.4),
+        NodeStatus::Available if side => Color::srgb(0.4, 0.8, 1.0),
+        NodeStatus::Available => Color::srgb(1.0, 0.75, 0.3),
+        NodeStatus::Locked => Color::srgb(0.35, 0.35, 0.35),
+        NodeStatus::Closed => Color::srgb(0.5, 0.15, 0.15),
+    }
+}
+
+/// Progress of the current faction pair (empty before the first clear)
+fn current_progress(
+    save_data: &SaveData,
+    session: &GameSession,
+    active_module: &ActiveModule,
+) -> FactionProgress {
+    let (faction, enemy) = active_module.progress_factions(session);
+    save_data
+        .faction_progress(faction, enemy)
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn spawn_campaign_map(
+    mut commands: Commands,
+    modules: Res<GameModules>,
+    active_module: Res<ActiveModule>,
+    session: Res<GameSession>,
+    save_data: Res<SaveData>,
+    mut cursor: ResMut<CampaignMapCursor>,
+) {
+    let Some(map) = modules
+        .active(&active_module)
+        .and_then(|m| m.campaign_map(&active_module))
+    else {
+        return;
+    };
+    let progress = current_progress(&save_data, &session, &active_module);
+    let statuses: Vec<NodeStatus> = (0..map.nodes.len())
+        .map(|i| map.status(i, &progress))
+        .collect();
+
+    cursor.index = map.start_index(&progress);
+    cursor.confirm_pending = false;
+
+    // Backdrop
+    commands.spawn((
+        CampaignMapRoot,
+        Sprite {
+            color: Color::srgba(0.0, 0.0, 0.02, 0.85),
+            custom_size: Some(Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, LAYER_MAP),
+    ));
+
+    // Jump lines
+    for (from, to) in jump_lines(map) {
+        let a = map.nodes[from].position;
+        let b = map.nodes[to].position;
+        let offset = b - a;
+        let travelled = statuses[from] == NodeStatus::Completed
+            && matches!(statuses[to], NodeStatus::Available | NodeStatus::Completed);
+        let color = if statuses[to] == NodeStatus::Closed {
+            Color::srgba(0.5, 0.15, 0.15, 0.4)
+        } else if travelled {
+            Color::srgba(0.8, 0.7, 0.4, 0.7)
+        } else {
+            Color::srgba(0.4, 0.4, 0.5, 0.35)
+        };
+        let midpoint = (a + b) / 2.0;
+        commands.spawn((
+            CampaignMapRoot,
+            Sprite {
+                color,
+                custom_size: Some(Vec2::new(offset.length(), 2.0)),
+                ..default()
+            },
+            Transform::from_xyz(midpoint.x, midpoint.y, LAYER_MAP + 1.0)
+                .with_rotation(Quat::from_rotation_z(offset.to_angle())),
+        ));
+    }
+
+    // Selection ring, moved by update_campaign_map
+    commands.spawn((
+        CampaignMapRoot,
+        MapSelectionRing,
+        Sprite {
+            color: Color::srgba(1.0, 1.0, 1.0, 0.35),
+            custom_size: Some(Vec2::splat(26.0)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, LAYER_MAP + 2.0),
+    ));
+
+    // Systems
+    for (index, node) in map.nodes.iter().enumerate() {
+        let color = status_color(statuses[index], node.side);
+        let (size, rotation) = if node.side {
+            (10.0, Quat::from_rotation_z(std::f32::consts::FRAC_PI_4))
+        } else {
+            (14.0, Quat::IDENTITY)
+        };
+        commands.spawn((
+            CampaignMapRoot,
+            MapNodeMarker { index },
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(size)),
+                ..default()
+            },
+            Transform::from_xyz(node.position.x, node.position.y, LAYER_MAP + 3.0)
+                .with_rotation(rotation),
+        ));
+        commands.spawn((
+            CampaignMapRoot,
+            Text2d::new(node.system),
+            TextFont {
+                font_size: 11.0,
+                ..default()
+            },
+            TextColor(color.with_alpha(0.8)),
+            Transform::from_xyz(node.position.x, node.position.y - 18.0, LAYER_MAP + 3.0),
+        ));
+    }
+
+    // Title and details panel
+    let cleared = progress.completed_nodes.len();
+    commands
+        .spawn((
+            CampaignMapRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                padding: UiRect::all(Val::Px(20.0)),
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|header| {
+                    header.spawn((
+                        Text::new("CAMPAIGN MAP"),
+                        TextFont {
+                            font_size: 36.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.7, 0.3)),
+                    ));
+                    header.spawn((
+                        Text::new(format!(
+                            "Systems cleared: {} / {}",
+                            cleared,
+                            map.nodes.len()
+                        )),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                    ));
+                });
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(560.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(6.0),
+                        padding: UiRect::all(Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.9)),
+                    BorderColor(Color::srgb(0.3, 0.3, 0.35)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        MapDetailsText,
+                        Text::new(""),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                    ));
+                    panel.spawn((
+                        Text::new("← → ↑ ↓ Navigate • A/ENTER Launch • B/ESC Back"),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.4, 0.4, 0.4)),
+                    ));
+                });
+        });
+}
+
+/// Direction pressed this frame (edge triggered), in map space
+fn map_nav_direction(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> Vec2 {
+    let mut direction = Vec2::ZERO;
+    if keyboard.any_just_pressed([KeyCode::ArrowUp, KeyCode::KeyW]) || joystick.dpad_just_up() {
+        direction.y += 1.0;
+    }
+    if keyboard.any_just_pressed([KeyCode::ArrowDown, KeyCode::KeyS]) || joystick.dpad_just_down() {
+        direction.y -= 1.0;
+    }
+    if keyboard.any_just_pressed([KeyCode::ArrowLeft, KeyCode::KeyA]) || joystick.dpad_just_left() {
+        direction.x -= 1.0;
+    }
+    if keyboard.any_just_pressed([KeyCode::ArrowRight, KeyCode::KeyD]) || joystick.dpad_just_right()
+    {
+        direction.x += 1.0;
+    }
+    direction
+}
+
+fn campaign_map_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    time: Res<Time>,
+    modules: Res<GameModules>,
+    active_module: Res<ActiveModule>,
+    session: Res<GameSession>,
+    mut save_data: ResMut<SaveData>,
+    mut campaign: ResMut<CampaignState>,
+    mut cursor: ResMut<CampaignMapCursor>,
+    mut cooldown: Local<f32>,
+    mut launched: EventWriter<MapNodeLaunched>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(map) = modules
+        .active(&active_module)
+        .and_then(|m| m.campaign_map(&active_module))
+    else {
+        return;
+    };
+    *cooldown -= time.delta_secs();
+
+    let direction = map_nav_direction(&keyboard, &joystick);
+    if direction != Vec2::ZERO && *cooldown <= 0.0 {
+        if let Some(next) = map.neighbor(cursor.index, direction) {
+            cursor.index = next;
+            cursor.confirm_pending = false;
+            *cooldown = MAP_NAV_COOLDOWN;
+        }
+    }
+
+    if keyboard.any_just_pressed([KeyCode::Space, KeyCode::Enter]) || joystick.confirm() {
+        let progress = current_progress(&save_data, &session, &active_module);
+        let node = &map.nodes[cursor.index];
+        match map.status(cursor.index, &progress) {
+            NodeStatus::Available | NodeStatus::Completed => {
+                let commits = map.needs_commitment(cursor.index, &progress);
+                if commits && !cursor.confirm_pending {
+                    cursor.confirm_pending = true;
+                } else {
+                    if commits {
+                        let (faction, enemy) = active_module.progress_factions(&session);
+                        save_data.choose_node(faction, enemy, node.mission);
+                    }
+                    info!("Launching {} ({})", node.mission, node.system);
+                    if campaign.select_mission(node.mission, node.requires.first().copied()) {
+                        next_state.set(GameState::ShipSelect);
+                    } else {
+                        launched.send(MapNodeLaunched {
+                            mission: node.mission,
+                        });
+                    }
+                }
+            }
+            NodeStatus::Locked | NodeStatus::Closed => {}
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        next_state.set(GameState::FactionSelect);
+    }
+}
+
+/// Details for the selected node
+fn node_details(
+    map: &CampaignMap,
+    index: usize,
+    progress: &FactionProgress,
+    pending: bool,
+) -> String {
+    let node = &map.nodes[index];
+    let Some(mission) = (map.briefing)(node.mission) else {
+        return node.system.to_string();
+    };
+
+    let kind = if node.side { " (side mission)" } else { "" };
+    let mut lines = vec![
+        format!("{} - {}{}", node.system, mission.name, kind),
+        mission.description.to_string(),
+    ];
+    for objective in mission.objectives {
+        let tag = if objective.optional {
+            "Bonus"
+        } else {
+            "Objective"
+        };
+        lines.push(format!("{}: {}", tag, objective.label));
+    }
+    if let Some(reward) = node.reward {
+        lines.push(format!("Reward: {}", reward.describe()));
+    }
+
+    let system_names = |ids: &[&str]| {
+        ids.iter()
+            .filter_map(|id| map.node(id))
+            .map(|n| n.system)
+            .collect::<Vec<_>>()
+            .join(" / ")
+    };
+    match map.status(index, progress) {
+        NodeStatus::Locked => lines.push(format!(
+            "LOCKED - reach {} first",
+            system_names(node.requires)
+        )),
+        NodeStatus::Closed => lines.push("CLOSED - your fleet took another route".to_string()),
+        NodeStatus::Completed => lines.push("CLEARED - launch to replay".to_string()),
+        NodeStatus::Available if pending => lines.push(format!(
+            "Confirm again to commit. {} will be closed for good.",
+            system_names(node.closes)
+        )),
+        NodeStatus::Available if map.needs_commitment(index, progress) => lines.push(format!(
+            "Route choice: launching closes {}",
+            system_names(node.closes)
+        )),
+        NodeStatus::Available => {}
+    }
+    lines.join("\n")
+}
+
+fn update_campaign_map(
+    modules: Res<GameModules>,
+    active_module: Res<ActiveModule>,
+    session: Res<GameSession>,
+    save_data: Res<SaveData>,
+    cursor: Res<CampaignMapCursor>,
+    mut ring: Query<&mut Transform, With<MapSelectionRing>>,
+    mut nodes: Query<(&MapNodeMarker, &mut Sprite)>,
+    mut details: Query<&mut Text, With<MapDetailsText>>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    if !cursor.is_changed() {
+        return;
+    }
+    let Some(map) = modules
+        .active(&active_module)
+        .and_then(|m| m.campaign_map(&active_module))
+    else {
+        return;
+    };
+    let Some(node) = map.nodes.get(cursor.index) else {
+        return;
+    };
+    let progress = current_progress(&save_data, &session, &active_module);
+
+    for mut transform in ring.iter_mut() {
+        transform.translation.x = node.position.x;
+        transform.translation.y = node.position.y;
+    }
+    for (marker, mut sprite) in nodes.iter_mut() {
+        let base = status_color(
+            map.status(marker.index, &progress),
+            map.nodes[marker.index].side,
+        );
+        sprite.color = if marker.index == cursor.index {
+            base.lighter(0.2)
+        } else {
+            base
+        };
+    }
+
+    let text = node_details(map, cursor.index, &progress, cursor.confirm_pending);
+    for mut details in details.iter_mut() {
+        **details = text.clone();
+    }
+    narration.send(NarrationEvent::new(text.replace('\n', ". ")));
+}
+
+fn despawn_campaign_map(mut commands: Commands, roots: Query<Entity, With<CampaignMapRoot>>) {
+    for entity in roots.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -6,6 +6,7 @@
 //! - Speed display at bottom center
 //! - Percentage readouts on left
 //! - Heat status indicators
+//! - Glow on the capacitor rings as grazes charge them
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
@@ -13,7 +14,7 @@ use std::f32::consts::PI;
 
 use crate::core::*;
 use crate::entities::{Movement, Player, ShipStats};
-use crate::systems::ComboHeatSystem;
+use crate::systems::HeatSystem;
 
 /// Capacitor wheel plugin
 pub struct CapacitorWheelPlugin;
@@ -36,6 +37,8 @@ pub struct CapacitorAnimation {
     pub rotation: f32,
     pub pulse: f32,
     pub pulse_direction: f32,
+    /// Graze glow (1.0 on a graze, fades to 0)
+    pub graze_flash: f32,
 }
 
 impl Default for CapacitorAnimation {
@@ -44,14 +47,26 @@ impl Default for CapacitorAnimation {
             rotation: 0.0,
             pulse: 1.0,
             pulse_direction: 1.0,
+            graze_flash: 0.0,
         }
     }
 }
 
 /// Update capacitor animation
-fn update_capacitor_animation(time: Res<Time>, mut anim: ResMut<CapacitorAnimation>) {
+fn update_capacitor_animation(
+    time: Res<Time>,
+    mut grazes: EventReader<GrazeEvent>,
+    mut anim: ResMut<CapacitorAnimation>,
+) {
     let dt = time.delta_secs();
 
+    // Grazes charge the capacitor; light the rings up as they do
+    if grazes.read().count() > 0 {
+        anim.graze_flash = 1.0;
+    } else {
+        anim.graze_flash = (anim.graze_flash - dt * 4.0).max(0.0);
+    }
+
     // Very slow rotation for capacitor glow effect
     anim.rotation += dt * 0.15;
     if anim.rotation > PI * 2.0 {
@@ -73,7 +88,7 @@ fn update_capacitor_animation(time: Res<Time>, mut anim: ResMut<CapacitorAnimati
 fn draw_capacitor_wheel(
     mut egui_ctx: EguiContexts,
     player_query: Query<(&ShipStats, Option<&Movement>), With<Player>>,
-    heat_system: Res<ComboHeatSystem>,
+    heat_system: Res<HeatSystem>,
     anim: Res<CapacitorAnimation>,
     windows: Query<&Window>,
 ) {
@@ -198,6 +213,21 @@ fn draw_capacitor_wheel(
                 anim.pulse,
             );
 
+            // === GRAZE CHARGE GLOW ===
+            if anim.graze_flash > 0.0 {
+                let glow_color = egui::Color32::from_rgba_unmultiplied(
+                    190,
+                    230,
+                    255,
+                    (anim.graze_flash * 160.0) as u8,
+                );
+                painter.circle_stroke(
+                    center,
+                    cap_outer_radius + 1.0,
+                    egui::Stroke::new(1.5, glow_color),
+                );
+            }
+
             // === INNER SPEED DISPLAY CIRCLE ===
             painter.circle_filled(
                 center,
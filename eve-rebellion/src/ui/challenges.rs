@@ -0,0 +1,325 @@
+//! Challenges Screen
+//!
+//! Reached from the campaign select. Shows today's daily and this week's
+//! weekly challenge with their modifiers, whether the ranked attempt is
+//! still available and the best score on the challenge's board. Launching
+//! one sets up an Endless run of Minmatar against Amarr on Newbro.
+
+use crate::core::*;
+use crate::games::abyssal_depths::AbyssalState;
+use crate::games::{elder_fleet, ActiveModule};
+use crate::systems::JoystickState;
+use bevy::prelude::*;
+
+use super::TransitionEvent;
+
+/// Challenges screen plugin
+pub struct ChallengeScreenPlugin;
+
+impl Plugin for ChallengeScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChallengeBrowser>()
+            .add_systems(OnEnter(GameState::Challenges), spawn_challenges_screen)
+            .add_systems(
+                Update,
+                challenges_input.run_if(in_state(GameState::Challenges)),
+            )
+            .add_systems(OnExit(GameState::Challenges), despawn_challenges_screen);
+    }
+}
+
+/// Root of the challenges screen
+#[derive(Component)]
+pub struct ChallengesRoot;
+
+/// Selected challenge period
+#[derive(Resource, Default, Debug)]
+pub struct ChallengeBrowser {
+    pub cursor: usize,
+}
+
+/// Top entry on a challenge's board
+fn best_entry<'a>(boards: &'a Leaderboards, challenge: &Challenge) -> Option<&'a LeaderboardEntry> {
+    let mode = LeaderboardMode::Challenge {
+        title: challenge.title(),
+    };
+    boards
+        .board(&mode, Difficulty::Newbro)
+        .and_then(|board| board.entries.first())
+}
+
+fn ship_name(type_id: u32) -> &'static str {
+    Faction::Minmatar
+        .player_ships()
+        .iter()
+        .find(|ship| ship.type_id == type_id)
+        .map_or("Unknown", |ship| ship.name)
+}
+
+fn spawn_challenges_screen(
+    mut commands: Commands,
+    browser: Res<ChallengeBrowser>,
+    save_data: Res<SaveData>,
+    boards: Res<Leaderboards>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    let text = build_challenges_screen(&mut commands, &browser, &save_data, &boards);
+    narration.send(NarrationEvent::queued(text));
+}
+
+/// Build the screen; returns its narration
+fn build_challenges_screen(
+    commands: &mut Commands,
+    browser: &ChallengeBrowser,
+    save_data: &SaveData,
+    boards: &Leaderboards,
+) -> String {
+    let accent = Color::srgb(1.0, 0.85, 0.2);
+    let challenges = ChallengePeriod::ALL.map(Challenge::current);
+    let mut narration = String::new();
+
+    commands
+        .spawn((
+            ChallengesRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.95)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("CHALLENGES"),
+                TextFont {
+                    font_size: 42.0,
+                    ..default()
+                },
+                TextColor(accent),
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(30.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (index, challenge) in challenges.iter().enumerate() {
+                        let selected = index == browser.cursor;
+                        let attempted = save_data.challenge_attempts.contains(&challenge.key());
+                        let best = best_entry(boards, challenge);
+
+                        let mut lines = vec![format!(
+                            "Clear {} Endless waves",
+                            challenge.period.segment_waves()
+                        )];
+                        lines.extend(
+                            challenge
+                                .modifiers
+                                .iter()
+                                .map(|m| format!("• {}: {}", m.name(), m.description())),
+                        );
+                        if let Some(type_id) = challenge.ship_type_id {
+                            lines.push(format!("Ship: {}", ship_name(type_id)));
+                        }
+                        let status = if attempted {
+                            "Attempt used • practice only".to_string()
+                        } else {
+                            "Ranked attempt available".to_string()
+                        };
+                        let best_line = best.map_or_else(
+                            || "No scores yet".to_string(),
+                            |e| format!("Best: {} by {}", e.score, e.pilot),
+                        );
+
+                        if selected {
+                            narration = format!(
+                                "{}. {}. {}. {}",
+                                challenge.title(),
+                                lines.join(". "),
+                                status,
+                                best_line
+                            );
+                        }
+
+                        row.spawn((
+                            Node {
+                                width: Val::Px(380.0),
+                                flex_direction: FlexDirection::Column,
+                                padding: UiRect::all(Val::Px(16.0)),
+                                border: UiRect::all(Val::Px(3.0)),
+                                row_gap: Val::Px(8.0),
+                                ..default()
+                            },
+                            BackgroundColor(accent.with_alpha(if selected { 0.15 } else { 0.05 })),
+                            BorderColor(if selected {
+                                accent
+                            } else {
+                                Color::srgb(0.25, 0.25, 0.25)
+                            }),
+                        ))
+                        .with_children(|card| {
+                            card.spawn((
+                                Text::new(challenge.period.name()),
+                                TextFont {
+                                    font_size: 26.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                            ));
+                            card.spawn((
+                                Text::new(format!(
+                                    "{} • Seed {}",
+                                    challenge.date(),
+                                    seed_code(challenge.seed)
+                                )),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(accent),
+                            ));
+                            for line in &lines {
+                                card.spawn((
+                                    Text::new(line.clone()),
+                                    TextFont {
+                                        font_size: 15.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                                ));
+                            }
+                            card.spawn((
+                                Text::new(status),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(if attempted {
+                                    Color::srgb(0.6, 0.6, 0.6)
+                                } else {
+                                    Color::srgb(0.4, 0.9, 0.4)
+                                }),
+                            ));
+                            card.spawn((
+                                Text::new(best_line),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                            ));
+                        });
+                    }
+                });
+
+            parent.spawn((
+                Text::new("←→ Select • ENTER Launch • ESC Back"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+            ));
+        });
+
+    narration
+}
+
+fn challenges_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut browser: ResMut<ChallengeBrowser>,
+    save_data: Res<SaveData>,
+    boards: Res<Leaderboards>,
+    mut challenge_run: ResMut<ChallengeRun>,
+    mut active_module: ResMut<ActiveModule>,
+    mut endless: ResMut<EndlessMode>,
+    mut abyssal: ResMut<AbyssalState>,
+    mut difficulty: ResMut<Difficulty>,
+    mut session: ResMut<GameSession>,
+    roots: Query<Entity, With<ChallengesRoot>>,
+    mut narration: EventWriter<NarrationEvent>,
+    mut transitions: EventWriter<TransitionEvent>,
+) {
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        transitions.send(TransitionEvent::quick(GameState::ModuleSelect));
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Enter)
+        || keyboard.just_pressed(KeyCode::Space)
+        || joystick.confirm()
+    {
+        let challenge = Challenge::current(ChallengePeriod::ALL[browser.cursor]);
+        challenge_run.ranked = !save_data.challenge_attempts.contains(&challenge.key());
+
+        active_module.set_module(elder_fleet::MODULE_ID);
+        endless.active = true;
+        abyssal.active = false;
+        *difficulty = Difficulty::Newbro;
+        *session = GameSession::new(Faction::Minmatar, Faction::Amarr);
+        info!(
+            "Launching {} challenge ({})",
+            challenge.title(),
+            if challenge_run.ranked {
+                "ranked"
+            } else {
+                "practice"
+            }
+        );
+
+        match challenge.ship_type_id {
+            Some(type_id) => {
+                session.selected_ship_index = session
+                    .player_ships()
+                    .iter()
+                    .position(|ship| ship.type_id == type_id)
+                    .unwrap_or(0);
+                transitions.send(TransitionEvent::slow(GameState::Playing));
+            }
+            None => {
+                transitions.send(TransitionEvent::to(GameState::ShipSelect));
+            }
+        }
+        challenge_run.challenge = Some(challenge);
+        return;
+    }
+
+    let step: i32 = if keyboard.just_pressed(KeyCode::ArrowRight)
+        || keyboard.just_pressed(KeyCode::KeyD)
+        || joystick.dpad_just_right()
+        || joystick.right_bumper()
+    {
+        1
+    } else if keyboard.just_pressed(KeyCode::ArrowLeft)
+        || keyboard.just_pressed(KeyCode::KeyA)
+        || joystick.dpad_just_left()
+        || joystick.left_bumper()
+    {
+        -1
+    } else {
+        0
+    };
+    if step != 0 {
+        let count = ChallengePeriod::ALL.len() as i32;
+        browser.cursor = (browser.cursor as i32 + step).rem_euclid(count) as usize;
+        for root in roots.iter() {
+            commands.entity(root).despawn_recursive();
+        }
+        let text = build_challenges_screen(&mut commands, &browser, &save_data, &boards);
+        narration.send(NarrationEvent::new(text));
+    }
+}
+
+fn despawn_challenges_screen(mut commands: Commands, roots: Query<Entity, With<ChallengesRoot>>) {
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+}
@@ -0,0 +1,412 @@
+//! Debrief Screen
+//!
+//! After a mission ends (stage complete, game over or victory) TAB/Y opens
+//! a debrief of the run just flown over the result screen: accuracy and
+//! combo peaks, damage dealt and taken per damage type and layer, kills per
+//! enemy type, ability uses and a timeline of the fight.
+
+use crate::core::*;
+use crate::systems::JoystickState;
+use bevy::prelude::*;
+
+/// Debrief plugin
+pub struct DebriefPlugin;
+
+impl Plugin for DebriefPlugin {
+    fn build(&self, app: &mut App) {
+        for state in [
+            GameState::StageComplete,
+            GameState::GameOver,
+            GameState::Victory,
+        ] {
+            app.add_systems(OnEnter(state), spawn_debrief_hint)
+                .add_systems(Update, debrief_input.run_if(in_state(state)))
+                .add_systems(OnExit(state), despawn_debrief);
+        }
+    }
+}
+
+/// "Debrief" prompt on result screens
+#[derive(Component)]
+pub struct DebriefHint;
+
+/// Root of the debrief overlay
+#[derive(Component)]
+pub struct DebriefRoot;
+
+/// Bars shown in the timeline chart
+const TIMELINE_COLUMNS: usize = 24;
+/// Rows shown in the kills chart
+const MAX_KILL_ROWS: usize = 6;
+
+const DAMAGE_TYPE_COLORS: [Color; 4] = [
+    Color::srgb(0.4, 0.6, 1.0),
+    Color::srgb(1.0, 0.5, 0.2),
+    Color::srgb(0.7, 0.7, 0.7),
+    Color::srgb(1.0, 0.8, 0.2),
+];
+const LAYER_COLORS: [Color; 3] = [
+    Color::srgb(0.3, 0.7, 1.0),
+    Color::srgb(1.0, 0.6, 0.2),
+    Color::srgb(0.9, 0.3, 0.3),
+];
+const LAYER_NAMES: [&str; 3] = ["Shield", "Armor", "Hull"];
+
+/// The run is summarized in `Update` right after this, so the hint does not
+/// wait for the summary; the overlay opens once it exists.
+fn spawn_debrief_hint(mut commands: Commands) {
+    commands.spawn((
+        DebriefHint,
+        Text::new("TAB/Y: Debrief"),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgba(0.8, 0.8, 0.8, 0.8)),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            bottom: Val::Px(20.0),
+            ..default()
+        },
+        GlobalZIndex(50),
+    ));
+}
+
+fn debrief_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    stats: Res<RunStats>,
+    roots: Query<Entity, With<DebriefRoot>>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    if !(keyboard.just_pressed(KeyCode::Tab) || joystick.y_button()) {
+        return;
+    }
+    if !roots.is_empty() {
+        for root in roots.iter() {
+            commands.entity(root).despawn_recursive();
+        }
+        narration.send(NarrationEvent::new("Debrief closed"));
+        return;
+    }
+    let Some(summary) = stats.last_summary.as_ref() else {
+        return;
+    };
+    build_debrief(&mut commands, summary);
+    narration.send(NarrationEvent::new(debrief_narration(summary)));
+}
+
+fn build_debrief(commands: &mut Commands, summary: &RunSummary) {
+    let minutes = (summary.duration / 60.0) as u32;
+    let seconds = (summary.duration % 60.0) as u32;
+
+    commands
+        .spawn((
+            DebriefRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.95)),
+            GlobalZIndex(100),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!(
+                    "DEBRIEF - {} ({})",
+                    summary.mission.to_uppercase(),
+                    summary.outcome.name()
+                )),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.8, 0.4)),
+            ));
+            parent.spawn((
+                Text::new(format!(
+                    "{}:{:02}   Accuracy {:.0}% ({}/{} hits, {} crits)   Peak chain {} (x{:.1})   Bosses {}",
+                    minutes,
+                    seconds,
+                    summary.accuracy * 100.0,
+                    summary.shots_hit,
+                    summary.shots_fired,
+                    summary.crits,
+                    summary.peak_chain,
+                    summary.peak_multiplier,
+                    summary.bosses_killed,
+                )),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(40.0),
+                    ..default()
+                })
+                .with_children(|columns| {
+                    columns
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(6.0),
+                            ..default()
+                        })
+                        .with_children(|column| {
+                            let dealt: Vec<_> = DamageType::all()
+                                .iter()
+                                .map(|t| {
+                                    (
+                                        t.short_name().to_string(),
+                                        summary.damage_dealt[t.index()],
+                                        DAMAGE_TYPE_COLORS[t.index()],
+                                    )
+                                })
+                                .collect();
+                            spawn_bar_chart(column, "DAMAGE DEALT", &dealt);
+
+                            let taken: Vec<_> = DamageType::all()
+                                .iter()
+                                .map(|t| {
+                                    (
+                                        t.short_name().to_string(),
+                                        summary.damage_taken[t.index()],
+                                        DAMAGE_TYPE_COLORS[t.index()],
+                                    )
+                                })
+                                .collect();
+                            spawn_bar_chart(column, "DAMAGE TAKEN", &taken);
+
+                            let layers: Vec<_> = (0..3)
+                                .map(|i| {
+                                    (
+                                        LAYER_NAMES[i].to_string(),
+                                        summary.damage_taken_by_layer[i],
+                                        LAYER_COLORS[i],
+                                    )
+                                })
+                                .collect();
+                            spawn_bar_chart(column, "ABSORBED BY LAYER", &layers);
+                        });
+
+                    columns
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(6.0),
+                            ..default()
+                        })
+                        .with_children(|column| {
+                            let mut kills: Vec<_> = summary.kills.iter().collect();
+                            kills.sort_by(|a, b| b.1.cmp(a.1));
+                            let kills: Vec<_> = kills
+                                .into_iter()
+                                .take(MAX_KILL_ROWS)
+                                .map(|(name, count)| {
+                                    (name.clone(), *count as f32, Color::srgb(0.9, 0.3, 0.3))
+                                })
+                                .collect();
+                            spawn_bar_chart(
+                                column,
+                                &format!("KILLS ({})", summary.total_kills()),
+                                &kills,
+                            );
+
+                            let abilities: Vec<_> = summary
+                                .ability_uses
+                                .iter()
+                                .map(|(name, count)| {
+                                    (name.clone(), *count as f32, Color::srgb(0.4, 1.0, 0.6))
+                                })
+                                .collect();
+                            spawn_bar_chart(column, "ABILITIES", &abilities);
+
+                            let medals: Vec<_> = summary
+                                .medals
+                                .iter()
+                                .map(|(name, count)| {
+                                    (name.clone(), *count as f32, Color::srgb(1.0, 0.85, 0.2))
+                                })
+                                .collect();
+                            spawn_bar_chart(column, "MEDALS", &medals);
+                        });
+                });
+
+            spawn_timeline(parent, summary);
+
+            parent.spawn((
+                Text::new("TAB/Y: Close"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.7, 0.7, 0.7, 0.8)),
+            ));
+        });
+}
+
+/// Titled horizontal bar chart, bars scaled to the largest value
+fn spawn_bar_chart(parent: &mut ChildBuilder, title: &str, rows: &[(String, f32, Color)]) {
+    let max = rows.iter().map(|(_, value, _)| *value).fold(0.0, f32::max);
+
+    parent.spawn((
+        Text::new(title),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.9, 0.8, 0.4)),
+    ));
+    if rows.is_empty() {
+        parent.spawn((
+            Text::new("-"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+        ));
+        return;
+    }
+
+    for (label, value, color) in rows {
+        let fraction = if max > 0.0 { value / max } else { 0.0 };
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new(format!("{:<12}", label)),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    Node {
+                        width: Val::Px(110.0),
+                        ..default()
+                    },
+                ));
+                row.spawn((
+                    Node {
+                        width: Val::Px(160.0),
+                        height: Val::Px(8.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.1)),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width: Val::Percent(fraction * 100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(*color),
+                    ));
+                });
+                row.spawn((
+                    Text::new(format!("{:.0}", value)),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                ));
+            });
+    }
+}
+
+/// Column chart of damage dealt (and taken, stacked on top) over time
+fn spawn_timeline(parent: &mut ChildBuilder, summary: &RunSummary) {
+    let timeline = summary.compact_timeline(TIMELINE_COLUMNS);
+    let max = timeline
+        .iter()
+        .map(|b| b.damage_dealt + b.damage_taken)
+        .fold(0.0, f32::max);
+
+    parent.spawn((
+        Text::new("TIMELINE (dealt / taken)"),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.9, 0.8, 0.4)),
+    ));
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(480.0),
+                height: Val::Px(80.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::FlexEnd,
+                column_gap: Val::Px(2.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.05)),
+        ))
+        .with_children(|chart| {
+            for bucket in &timeline {
+                let scale = |value: f32| if max > 0.0 { value / max * 100.0 } else { 0.0 };
+                chart
+                    .spawn(Node {
+                        flex_grow: 1.0,
+                        height: Val::Percent(100.0),
+                        flex_direction: FlexDirection::ColumnReverse,
+                        ..default()
+                    })
+                    .with_children(|column| {
+                        column.spawn((
+                            Node {
+                                height: Val::Percent(scale(bucket.damage_dealt)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.3, 0.8, 1.0)),
+                        ));
+                        column.spawn((
+                            Node {
+                                height: Val::Percent(scale(bucket.damage_taken)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(1.0, 0.4, 0.3)),
+                        ));
+                    });
+            }
+        });
+}
+
+/// Spoken summary of the debrief
+fn debrief_narration(summary: &RunSummary) -> String {
+    format!(
+        "Debrief. {}. Accuracy {:.0} percent. {} kills. Damage dealt {:.0}, taken {:.0}. Peak chain {}.",
+        summary.outcome.name().to_lowercase(),
+        summary.accuracy * 100.0,
+        summary.total_kills(),
+        summary.total_dealt(),
+        summary.total_taken(),
+        summary.peak_chain
+    )
+}
+
+fn despawn_debrief(
+    mut commands: Commands,
+    roots: Query<Entity, Or<(With<DebriefRoot>, With<DebriefHint>)>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+}
@@ -0,0 +1,532 @@
+//! Fitting Screen
+//!
+//! Reached from ship select with Y/F. Lists the selected hull's high, mid
+//! and low slots next to its powergrid and CPU budget and the stats the
+//! fitting produces, with the change from the unfitted hull. Up/down picks
+//! a slot, left/right cycles the modules that fit it and the pilot is
+//! trained for; every valid change is saved to the ship's fitting right
+//! away.
+
+use crate::core::*;
+use crate::systems::JoystickState;
+use bevy::prelude::*;
+
+use super::TransitionEvent;
+
+/// Fitting screen plugin
+pub struct FittingPlugin;
+
+impl Plugin for FittingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FittingCursor>()
+            .add_systems(OnEnter(GameState::Fitting), spawn_fitting_screen)
+            .add_systems(Update, fitting_input.run_if(in_state(GameState::Fitting)))
+            .add_systems(OnExit(GameState::Fitting), despawn_fitting_screen);
+    }
+}
+
+/// Root of the fitting screen
+#[derive(Component)]
+pub struct FittingRoot;
+
+/// Selected slot, counted across high, mid and low slots
+#[derive(Resource, Default, Debug)]
+pub struct FittingCursor {
+    pub slot: usize,
+}
+
+/// Slot kind and index of a flat slot number
+fn slot_at(layout: &SlotLayout, slot: usize) -> Option<(SlotKind, usize)> {
+    let mut offset = 0;
+    for kind in SlotKind::all() {
+        let count = layout.count(kind);
+        if slot < offset + count {
+            return Some((kind, slot - offset));
+        }
+        offset += count;
+    }
+    None
+}
+
+fn slot_total(layout: &SlotLayout) -> usize {
+    layout.high + layout.mid + layout.low
+}
+
+/// Next module (or empty) for a slot in `direction`, skipping modules the
+/// hull cannot power or the pilot has not trained for
+fn cycle_module(
+    fitting: &ShipFitting,
+    layout: &SlotLayout,
+    skills: &SkillSheet,
+    kind: SlotKind,
+    index: usize,
+    direction: i32,
+) -> Option<ShipModule> {
+    let mut options: Vec<Option<ShipModule>> = vec![None];
+    options.extend(
+        ShipModule::for_slot(kind)
+            .into_iter()
+            .filter(|&m| skills.can_fit(m))
+            .map(Some),
+    );
+    let current = options
+        .iter()
+        .position(|&m| m == fitting.get(kind, index))
+        .unwrap_or(0);
+
+    for step in 1..options.len() as i32 {
+        let candidate =
+            options[(current as i32 + step * direction).rem_euclid(options.len() as i32) as usize];
+        let mut trial = fitting.clone();
+        trial.set(kind, index, candidate);
+        if trial.check(layout).is_ok() {
+            return candidate;
+        }
+    }
+    fitting.get(kind, index)
+}
+
+fn spawn_fitting_screen(
+    mut commands: Commands,
+    mut cursor: ResMut<FittingCursor>,
+    session: Res<GameSession>,
+    save_data: Res<SaveData>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    cursor.slot = 0;
+    let ship = session.selected_ship();
+    let fitting = save_data.fitting(ship.type_id);
+    build_fitting_screen(&mut commands, &session, ship, &fitting, &cursor);
+    narration.send(NarrationEvent::queued(slot_narration(
+        ship, &fitting, &cursor,
+    )));
+}
+
+fn build_fitting_screen(
+    commands: &mut Commands,
+    session: &GameSession,
+    ship: &ShipDef,
+    fitting: &ShipFitting,
+    cursor: &FittingCursor,
+) {
+    let layout = SlotLayout::for_ship(ship);
+    let base = FittedStats::base(ship);
+    let fitted = fitting.stats(ship);
+    let faction_color = session.player_faction.primary_color();
+    let ability = fitting
+        .ability()
+        .unwrap_or_else(|| crate::systems::AbilityType::from_special(ship.special));
+
+    commands
+        .spawn((
+            FittingRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.95)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("FITTING - {}", ship.name.to_uppercase())),
+                TextFont {
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(faction_color),
+            ));
+            parent.spawn((
+                Text::new(format!("{} • {}", ship.class.name(), ship.role)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+
+            // Budgets
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(40.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (label, used, available) in [
+                        ("POWERGRID", fitting.powergrid_used(), layout.powergrid),
+                        ("CPU", fitting.cpu_used(), layout.cpu),
+                    ] {
+                        spawn_budget(row, label, used, available);
+                    }
+                });
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(60.0),
+                    ..default()
+                })
+                .with_children(|columns| {
+                    // Slots
+                    columns
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(4.0),
+                            min_width: Val::Px(420.0),
+                            ..default()
+                        })
+                        .with_children(|list| {
+                            let mut slot = 0;
+                            for kind in SlotKind::all() {
+                                list.spawn((
+                                    Text::new(format!("{} SLOTS", kind.name())),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                                    Node {
+                                        margin: UiRect::top(Val::Px(8.0)),
+                                        ..default()
+                                    },
+                                ));
+                                for index in 0..layout.count(kind) {
+                                    let selected = slot == cursor.slot;
+                                    let module = fitting.get(kind, index);
+                                    let label = match module {
+                                        Some(module) => format!(
+                                            "{}{}  ({:.0} MW, {:.0} tf)",
+                                            if selected { "> " } else { "  " },
+                                            module.name(),
+                                            module.powergrid(),
+                                            module.cpu()
+                                        ),
+                                        None => {
+                                            format!("{}[empty]", if selected { "> " } else { "  " })
+                                        }
+                                    };
+                                    let color = match (selected, module.is_some()) {
+                                        (true, _) => Color::srgb(1.0, 0.8, 0.3),
+                                        (false, true) => Color::srgb(0.85, 0.85, 0.85),
+                                        (false, false) => Color::srgb(0.4, 0.4, 0.4),
+                                    };
+                                    list.spawn((
+                                        Text::new(label),
+                                        TextFont {
+                                            font_size: 18.0,
+                                            ..default()
+                                        },
+                                        TextColor(color),
+                                    ));
+                                    slot += 1;
+                                }
+                            }
+
+                            // What the selected module does
+                            let description = slot_at(&layout, cursor.slot)
+                                .and_then(|(kind, index)| fitting.get(kind, index))
+                                .map(|m| m.description())
+                                .unwrap_or("←→ to fit a module");
+                            list.spawn((
+                                Text::new(description),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(faction_color),
+                                Node {
+                                    margin: UiRect::top(Val::Px(12.0)),
+                                    ..default()
+                                },
+                            ));
+                        });
+
+                    // Stats with deltas
+                    columns
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(6.0),
+                            min_width: Val::Px(300.0),
+                            ..default()
+                        })
+                        .with_children(|stats| {
+                            stats.spawn((
+                                Text::new("FITTED STATS"),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                            ));
+                            for ((label, before), (_, after)) in
+                                base.rows().into_iter().zip(fitted.rows())
+                            {
+                                spawn_stat_row(stats, label, before, after);
+                            }
+                            stats.spawn((
+                                Text::new(format!("Ability: {}", ability.name())),
+                                TextFont {
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(faction_color),
+                                Node {
+                                    margin: UiRect::top(Val::Px(8.0)),
+                                    ..default()
+                                },
+                            ));
+                        });
+                });
+
+            parent.spawn((
+                Text::new("↑↓ Slot • ←→ Module • X/BACKSPACE Unfit • A/ENTER Launch • B/ESC Back"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+                Node {
+                    margin: UiRect::top(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn spawn_budget(parent: &mut ChildBuilder, label: &str, used: f32, available: f32) {
+    let fraction = if available > 0.0 {
+        (used / available).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let color = if fraction > 0.9 {
+        Color::srgb(1.0, 0.5, 0.2)
+    } else {
+        Color::srgb(0.3, 0.8, 1.0)
+    };
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|budget| {
+            budget.spawn((
+                Text::new(format!("{} {:.0}/{:.0}", label, used, available)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+            budget
+                .spawn((
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(8.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.1)),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width: Val::Percent(fraction * 100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(color),
+                    ));
+                });
+        });
+}
+
+fn spawn_stat_row(parent: &mut ChildBuilder, label: &str, before: f32, after: f32) {
+    let delta = after - before;
+    let (delta_text, color) = if delta.abs() < 0.05 {
+        (String::new(), Color::srgb(0.8, 0.8, 0.8))
+    } else if delta > 0.0 {
+        (format!("  (+{:.1})", delta), Color::srgb(0.4, 1.0, 0.4))
+    } else {
+        (format!("  ({:.1})", delta), Color::srgb(1.0, 0.4, 0.3))
+    };
+
+    parent.spawn((
+        Text::new(format!("{:<14}{:>7.1}{}", label, after, delta_text)),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
+
+/// Spoken description of the selected slot
+fn slot_narration(ship: &ShipDef, fitting: &ShipFitting, cursor: &FittingCursor) -> String {
+    let layout = SlotLayout::for_ship(ship);
+    let Some((kind, index)) = slot_at(&layout, cursor.slot) else {
+        return String::new();
+    };
+    let module = fitting
+        .get(kind, index)
+        .map(|m| format!("{}, {}", m.name(), m.description()))
+        .unwrap_or_else(|| "empty".to_string());
+    format!(
+        "{} slot {}: {}. Powergrid {:.0} of {:.0}, CPU {:.0} of {:.0}",
+        kind.name().to_lowercase(),
+        index + 1,
+        module,
+        fitting.powergrid_used(),
+        layout.powergrid,
+        fitting.cpu_used(),
+        layout.cpu
+    )
+}
+
+fn fitting_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut cursor: ResMut<FittingCursor>,
+    session: Res<GameSession>,
+    mut save_data: ResMut<SaveData>,
+    roots: Query<Entity, With<FittingRoot>>,
+    mut narration: EventWriter<NarrationEvent>,
+    mut transitions: EventWriter<TransitionEvent>,
+) {
+    let ship = session.selected_ship();
+    let layout = SlotLayout::for_ship(ship);
+    let mut fitting = save_data.fitting(ship.type_id);
+    let total = slot_total(&layout);
+
+    let vertical = if keyboard.just_pressed(KeyCode::ArrowUp)
+        || keyboard.just_pressed(KeyCode::KeyW)
+        || joystick.dpad_just_up()
+    {
+        -1
+    } else if keyboard.just_pressed(KeyCode::ArrowDown)
+        || keyboard.just_pressed(KeyCode::KeyS)
+        || joystick.dpad_just_down()
+    {
+        1
+    } else {
+        0
+    };
+    let horizontal = if keyboard.just_pressed(KeyCode::ArrowLeft)
+        || keyboard.just_pressed(KeyCode::KeyA)
+        || joystick.dpad_just_left()
+    {
+        -1
+    } else if keyboard.just_pressed(KeyCode::ArrowRight)
+        || keyboard.just_pressed(KeyCode::KeyD)
+        || joystick.dpad_just_right()
+    {
+        1
+    } else {
+        0
+    };
+    let unfit = keyboard.just_pressed(KeyCode::Backspace)
+        || keyboard.just_pressed(KeyCode::Delete)
+        || joystick.x_button();
+
+    let mut changed = false;
+    if vertical != 0 && total > 0 {
+        cursor.slot = (cursor.slot as i32 + vertical).rem_euclid(total as i32) as usize;
+        changed = true;
+    }
+    if let Some((kind, index)) = slot_at(&layout, cursor.slot) {
+        let module = if unfit {
+            Some(None)
+        } else if horizontal != 0 {
+            Some(cycle_module(
+                &fitting,
+                &layout,
+                &save_data.skills,
+                kind,
+                index,
+                horizontal,
+            ))
+        } else {
+            None
+        };
+        if let Some(module) = module {
+            if module != fitting.get(kind, index) {
+                fitting.set(kind, index, module);
+                save_data.set_fitting(ship.type_id, fitting.clone());
+            }
+            changed = true;
+        }
+    }
+
+    if changed {
+        for root in roots.iter() {
+            commands.entity(root).despawn_recursive();
+        }
+        build_fitting_screen(&mut commands, &session, ship, &fitting, &cursor);
+        narration.send(NarrationEvent::new(slot_narration(ship, &fitting, &cursor)));
+    }
+
+    if keyboard.just_pressed(KeyCode::Space)
+        || keyboard.just_pressed(KeyCode::Enter)
+        || joystick.confirm()
+    {
+        info!(
+            "Launching {} with {} modules fitted",
+            ship.name,
+            fitting.modules().count()
+        );
+        transitions.send(TransitionEvent::slow(GameState::Playing));
+    }
+
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        transitions.send(TransitionEvent::quick(GameState::ShipSelect));
+    }
+}
+
+fn despawn_fitting_screen(mut commands: Commands, roots: Query<Entity, With<FittingRoot>>) {
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycling_skips_modules_that_do_not_fit() {
+        let ship = *MINMATAR_SHIPS
+            .iter()
+            .find(|s| s.class == ShipClass::Frigate)
+            .unwrap();
+        let layout = SlotLayout::for_ship(&ship);
+        let mut fitting = ShipFitting::default();
+        fitting.set(SlotKind::Low, 0, Some(ShipModule::ArmorPlate));
+        fitting.set(SlotKind::Low, 1, Some(ShipModule::ArmorPlate));
+
+        // 24 of 30 MW used: artillery no longer fits
+        fitting.set(SlotKind::High, 0, Some(ShipModule::Autocannon));
+        let untrained = SkillSheet::default();
+        let next = cycle_module(&fitting, &layout, &untrained, SlotKind::High, 0, 1);
+        assert_eq!(next, Some(ShipModule::RocketLauncher));
+        let previous = cycle_module(&fitting, &layout, &untrained, SlotKind::High, 0, -1);
+        assert_eq!(previous, None);
+
+        // The Drone Link Augmentor needs the Drones skill
+        fitting.set(SlotKind::High, 0, Some(ShipModule::RocketLauncher));
+        let next = cycle_module(&fitting, &layout, &untrained, SlotKind::High, 0, 1);
+        assert_eq!(next, None);
+        let mut drones = SkillSheet::default();
+        drones.grant(Skill::Drones);
+        let next = cycle_module(&fitting, &layout, &drones, SlotKind::High, 0, 1);
+        assert_eq!(next, Some(ShipModule::DroneLinkAugmentor));
+        assert_eq!(slot_at(&layout, layout.high), Some((SlotKind::Mid, 0)));
+        assert_eq!(slot_at(&layout, slot_total(&layout)), None);
+    }
+}
@@ -0,0 +1,158 @@
+//! Ghost HUD
+//!
+//! While racing a ghost: its pilot, the live score and time delta against
+//! it, and the latest wave or boss phase split for a few seconds.
+
+use crate::core::*;
+use crate::games::{core_enabled, CoreSystem};
+use crate::systems::{GhostRace, GhostRecorder};
+use bevy::prelude::*;
+
+/// Ghost HUD plugin
+pub struct GhostHudPlugin;
+
+impl Plugin for GhostHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_ghost_hud, update_ghost_hud)
+                .chain()
+                .run_if(in_state(GameState::Playing))
+                .run_if(core_enabled(CoreSystem::Hud)),
+        )
+        .add_systems(OnExit(GameState::Playing), despawn_ghost_hud);
+    }
+}
+
+/// Root of the ghost panel
+#[derive(Component)]
+pub struct GhostHudRoot;
+
+/// Live delta text
+#[derive(Component)]
+pub struct GhostDeltaText;
+
+/// Split text
+#[derive(Component)]
+pub struct GhostSplitText;
+
+/// Seconds a split stays up
+const SPLIT_DISPLAY_TIME: f32 = 4.0;
+
+const AHEAD_COLOR: Color = Color::srgb(0.4, 0.9, 0.4);
+const BEHIND_COLOR: Color = Color::srgb(1.0, 0.4, 0.3);
+
+/// The ghost loads after the HUD is built, so the panel follows it
+fn spawn_ghost_hud(
+    mut commands: Commands,
+    race: Res<GhostRace>,
+    roots: Query<(), With<GhostHudRoot>>,
+) {
+    let (Some(target), Some(_)) = (&race.target, &race.ghost) else {
+        return;
+    };
+    if !roots.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            GhostHudRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(140.0),
+                right: Val::Px(20.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexEnd,
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("GHOST: {} ({})", target.pilot, target.score)),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(0.6, 0.8, 1.0, 0.8)),
+            ));
+            parent.spawn((
+                GhostDeltaText,
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            parent.spawn((
+                GhostSplitText,
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// `+1.2s` / `-0.8s`; negative is ahead
+fn format_time_delta(seconds: f32) -> String {
+    format!("{:+.1}s", seconds)
+}
+
+fn update_ghost_hud(
+    race: Res<GhostRace>,
+    recorder: Res<GhostRecorder>,
+    score: Res<ScoreSystem>,
+    mut deltas: Query<(&mut Text, &mut TextColor), (With<GhostDeltaText>, Without<GhostSplitText>)>,
+    mut splits: Query<(&mut Text, &mut TextColor), (With<GhostSplitText>, Without<GhostDeltaText>)>,
+) {
+    let clock = recorder.clock;
+    let Some(score_delta) = race.score_delta(clock, score.score) else {
+        return;
+    };
+
+    for (mut text, mut color) in deltas.iter_mut() {
+        let time = race
+            .time_delta(clock, score.score)
+            .map_or_else(|| "PAST GHOST".to_string(), format_time_delta);
+        **text = format!("{:+}  {}", score_delta, time);
+        *color = TextColor(if score_delta >= 0 {
+            AHEAD_COLOR
+        } else {
+            BEHIND_COLOR
+        });
+    }
+
+    for (mut text, mut color) in splits.iter_mut() {
+        match race
+            .last_split
+            .as_ref()
+            .filter(|split| clock - split.at < SPLIT_DISPLAY_TIME)
+        {
+            Some(split) => {
+                **text = format!(
+                    "{}  {}  {:+}",
+                    split.label,
+                    format_time_delta(split.time),
+                    split.score
+                );
+                *color = TextColor(if split.time <= 0.0 {
+                    AHEAD_COLOR
+                } else {
+                    BEHIND_COLOR
+                });
+            }
+            None => text.clear(),
+        }
+    }
+}
+
+fn despawn_ghost_hud(mut commands: Commands, roots: Query<Entity, With<GhostHudRoot>>) {
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+}
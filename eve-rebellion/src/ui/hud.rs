@@ -9,7 +9,8 @@ use crate::core::*;
 use crate::entities::{
     Boss, BossData, BossState, Drone, DroneStats, Player, PowerupEffects, Wingman, WingmanTracker,
 };
-use crate::systems::{Ability, AbilityType, ComboHeatSystem, DialogueSystem};
+use crate::games::{core_enabled, CoreSystem};
+use crate::systems::{Ability, AbilityType, DialogueSystem, HeatSystem};
 use bevy::prelude::*;
 
 /// HUD plugin
@@ -17,43 +18,38 @@ pub struct HudPlugin;
 
 impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            OnEnter(GameState::Playing),
-            spawn_hud.run_if(not_last_stand),
-        )
-        .add_systems(
-            Update,
-            (
-                update_score_display,
-                update_salt_miner_meter,
-                update_combo_display,
-                update_heat_display,
-                update_combo_kills,
-                update_combo_timer_bar,
-                update_powerup_indicators,
-                update_buff_expiration_warnings,
-                update_wave_display,
-                update_mission_display,
-                update_boss_health_bar,
-                update_dialogue_display,
-                update_wingman_gauge,
-                update_drone_status,
-                update_ability_indicator,
-                update_ammo_display,
-                update_achievement_popup,
+        app.init_resource::<HudObjective>()
+            .add_systems(
+                OnEnter(GameState::Playing),
+                spawn_hud.run_if(core_enabled(CoreSystem::Hud)),
             )
-                .run_if(in_state(GameState::Playing))
-                .run_if(not_last_stand),
-        )
-        .add_systems(OnExit(GameState::Playing), despawn_hud);
-    }
-}
-
-/// Run condition: NOT in Last Stand mode
-fn not_last_stand(last_stand: Option<Res<crate::games::caldari_gallente::LastStandState>>) -> bool {
-    match last_stand {
-        Some(ls) => !ls.active,
-        None => true,
+            .add_systems(OnEnter(GameState::ModuleSelect), clear_hud_objective)
+            .add_systems(
+                Update,
+                (
+                    update_score_display,
+                    update_salt_miner_meter,
+                    update_combo_display,
+                    update_heat_display,
+                    update_combo_kills,
+                    update_combo_timer_bar,
+                    update_score_breakdown,
+                    update_powerup_indicators,
+                    update_buff_expiration_warnings,
+                    update_wave_display,
+                    update_mission_display,
+                    update_boss_health_bar,
+                    update_dialogue_display,
+                    update_wingman_gauge,
+                    update_drone_status,
+                    update_ability_indicator,
+                    update_ammo_display,
+                    update_achievement_popup,
+                )
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(core_enabled(CoreSystem::Hud)),
+            )
+            .add_systems(OnExit(GameState::Playing), despawn_hud);
     }
 }
 
@@ -93,6 +89,13 @@ pub struct ComboTimerContainer;
 #[derive(Component)]
 pub struct ComboTimerBar;
 
+/// Latest award worked out ("100 × chain 1.50 = 150")
+#[derive(Component)]
+pub struct ScoreBreakdownText;
+
+/// Seconds an award's breakdown stays up
+const BREAKDOWN_DISPLAY_TIME: f32 = 2.0;
+
 /// Wave display text
 #[derive(Component)]
 pub struct WaveText;
@@ -105,6 +108,11 @@ pub struct MissionNameText;
 #[derive(Component)]
 pub struct ObjectiveText;
 
+/// Objective line set by module logic such as mission scripts. Shown in
+/// place of the campaign objective while set.
+#[derive(Resource, Default, Debug)]
+pub struct HudObjective(pub Option<String>);
+
 /// Souls liberated text
 #[derive(Component)]
 pub struct SoulsText;
@@ -367,6 +375,15 @@ fn spawn_hud(mut commands: Commands) {
                                     BorderRadius::all(Val::Px(2.0)),
                                 ));
                             });
+                        center.spawn((
+                            ScoreBreakdownText,
+                            Text::new(""),
+                            TextFont {
+                                font_size: 13.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(1.0, 0.9, 0.6)),
+                        ));
                     });
 
                     // Right: Multiplier and Grade
@@ -994,6 +1011,7 @@ fn update_score_display(score: Res<ScoreSystem>, mut query: Query<&mut Text, Wit
 
 fn update_combo_display(
     score: Res<ScoreSystem>,
+    palette: Res<Palette>,
     mut combo_query: Query<(&mut Text, &mut TextColor), (With<ComboText>, Without<GradeText>)>,
     mut grade_query: Query<(&mut Text, &mut TextColor), (With<GradeText>, Without<ComboText>)>,
 ) {
@@ -1014,7 +1032,7 @@ fn update_combo_display(
     for (mut text, mut text_color) in grade_query.iter_mut() {
         let grade = score.get_grade();
         **text = grade.as_str().to_string();
-        text_color.0 = grade.color();
+        text_color.0 = palette.grade(grade);
     }
 }
 
@@ -1038,26 +1056,24 @@ fn update_salt_miner_meter(
 
 /// Update heat display bar
 fn update_heat_display(
-    heat_system: Res<ComboHeatSystem>,
+    heat_system: Res<HeatSystem>,
+    palette: Res<Palette>,
     mut query: Query<(&mut Node, &mut BackgroundColor), With<HeatBar>>,
 ) {
     for (mut node, mut bg) in query.iter_mut() {
         node.width = Val::Percent(heat_system.heat);
         // Color changes with heat level
-        bg.0 = heat_system.heat_level.color();
+        bg.0 = palette.heat(heat_system.heat_level);
     }
 }
 
 /// Update combo kills display
-fn update_combo_kills(
-    heat_system: Res<ComboHeatSystem>,
-    mut query: Query<&mut Text, With<ComboKillsText>>,
-) {
+fn update_combo_kills(score: Res<ScoreSystem>, mut query: Query<&mut Text, With<ComboKillsText>>) {
     for mut text in query.iter_mut() {
-        if let Some(tier_name) = heat_system.combo_tier_name() {
-            **text = format!("{} x{}", tier_name, heat_system.combo_count);
-        } else if heat_system.combo_count > 0 {
-            **text = format!("{}x", heat_system.combo_count);
+        if let Some(tier_name) = score.combo_tier_name() {
+            **text = format!("{} x{}", tier_name, score.chain);
+        } else if score.chain > 0 {
+            **text = format!("{}x", score.chain);
         } else {
             **text = String::new();
         }
@@ -1066,15 +1082,15 @@ fn update_combo_kills(
 
 /// Update combo timer bar (shows time remaining to keep combo)
 fn update_combo_timer_bar(
-    heat_system: Res<ComboHeatSystem>,
+    score: Res<ScoreSystem>,
     mut container_query: Query<&mut Node, With<ComboTimerContainer>>,
     mut fill_query: Query<
         (&mut Node, &mut BackgroundColor),
         (With<ComboTimerBar>, Without<ComboTimerContainer>),
     >,
 ) {
-    let has_combo = heat_system.combo_count > 0;
-    let timer_percent = heat_system.combo_timer_percent();
+    let has_combo = score.chain > 0;
+    let timer_percent = score.chain_timer_percent();
 
     // Show/hide container
     for mut node in container_query.iter_mut() {
@@ -1100,6 +1116,28 @@ fn update_combo_timer_bar(
     }
 }
 
+/// Show how the latest award was multiplied, fading out
+fn update_score_breakdown(
+    score: Res<ScoreSystem>,
+    mut query: Query<(&mut Text, &mut TextColor), With<ScoreBreakdownText>>,
+) {
+    let shown = score
+        .last_award
+        .as_ref()
+        .filter(|_| score.last_award_age < BREAKDOWN_DISPLAY_TIME);
+    for (mut text, mut color) in query.iter_mut() {
+        match shown {
+            Some(award) => {
+                **text = award.describe();
+                color.0 = color
+                    .0
+                    .with_alpha(1.0 - score.last_award_age / BREAKDOWN_DISPLAY_TIME);
+            }
+            None => text.clear(),
+        }
+    }
+}
+
 /// Update wave display (with stage info)
 fn update_wave_display(campaign: Res<CampaignState>, mut query: Query<&mut Text, With<WaveText>>) {
     for mut text in query.iter_mut() {
@@ -1119,10 +1157,16 @@ fn update_wave_display(campaign: Res<CampaignState>, mut query: Query<&mut Text,
     }
 }
 
+fn clear_hud_objective(mut objective: ResMut<HudObjective>) {
+    objective.0 = None;
+}
+
 /// Update mission info display
 fn update_mission_display(
     campaign: Res<CampaignState>,
     score: Res<ScoreSystem>,
+    hud_objective: Res<HudObjective>,
+    mission_objectives: Res<MissionObjectives>,
     mut mission_query: Query<
         &mut Text,
         (
@@ -1164,7 +1208,21 @@ fn update_mission_display(
 
     // Update objective
     for (mut text, mut color) in objective_query.iter_mut() {
-        if let Some(mission) = campaign.current_mission() {
+        if let Some(objective) = &hud_objective.0 {
+            **text = format!("◯ {}", objective);
+            if !mission_objectives.is_empty() {
+                text.push('\n');
+                text.push_str(&mission_objectives.hud_text());
+            }
+            color.0 = Color::srgb(0.5, 0.8, 0.5);
+        } else if !mission_objectives.is_empty() {
+            **text = mission_objectives.hud_text();
+            color.0 = if mission_objectives.primaries_complete() {
+                Color::srgb(0.3, 1.0, 0.3) // Bright green when complete
+            } else {
+                Color::srgb(0.5, 0.8, 0.5) // Dim green when incomplete
+            };
+        } else if let Some(mission) = campaign.current_mission() {
             if campaign.primary_complete {
                 **text = format!("✓ {}", mission.primary_objective);
                 color.0 = Color::srgb(0.3, 1.0, 0.3); // Bright green when complete
@@ -1684,7 +1742,7 @@ fn spawn_ammo_indicator(parent: &mut ChildBuilder) {
             ));
             // Key hint
             row.spawn((
-                Text::new("[1-5/Q/E]"),
+                Text::new("[1-7/Q/E]"),
                 TextFont {
                     font_size: 9.0,
                     ..default()
@@ -1697,6 +1755,7 @@ fn spawn_ammo_indicator(parent: &mut ChildBuilder) {
 /// Update ammo type display based on player's current ammo
 fn update_ammo_display(
     player_query: Query<&crate::entities::Weapon, With<Player>>,
+    palette: Res<Palette>,
     mut text_query: Query<(&mut Text, &mut TextColor), With<AmmoTypeText>>,
 ) {
     let Ok(weapon) = player_query.get_single() else {
@@ -1710,7 +1769,7 @@ fn update_ammo_display(
 
     for (mut text, mut color) in text_query.iter_mut() {
         **text = weapon.ammo_type.name().to_string();
-        color.0 = weapon.ammo_type.color();
+        color.0 = palette.ammo(weapon.ammo_type);
     }
 }
 
@@ -1747,11 +1806,11 @@ fn update_achievement_popup(
 
         // Update popup content
         if let Ok((mut name_text, mut name_color)) = name_query.get_single_mut() {
-            **name_text = achievement.name().to_string();
+            **name_text = achievement.name.to_string();
             name_color.0 = achievement.color();
         }
         if let Ok(mut desc_text) = desc_query.get_single_mut() {
-            **desc_text = achievement.description().to_string();
+            **desc_text = achievement.description.to_string();
         }
 
         // Show popup
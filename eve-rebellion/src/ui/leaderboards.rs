@@ -0,0 +1,512 @@
+//! Leaderboards Screen
+//!
+//! Reached from the main menu. Shows the local top runs with a filter row
+//! for mode, difficulty, ship and seed: up/down picks a filter, left/right
+//! (or the bumpers) changes it. Entries recorded with a replay are marked,
+//! and G (or Y) races the best of them as a ghost next time its mode is
+//! flown. Tab (or X) switches to the shared boards set in the options,
+//! fetched in the background; R fetches them again and I fetches the input
+//! log of the best shown run.
+
+use crate::core::*;
+use crate::systems::{GhostRace, GhostTarget, JoystickState, SharedBoards};
+use bevy::prelude::*;
+
+use super::TransitionEvent;
+
+/// Leaderboards screen plugin
+pub struct LeaderboardScreenPlugin;
+
+impl Plugin for LeaderboardScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LeaderboardBrowser>()
+            .add_systems(OnEnter(GameState::Leaderboards), spawn_leaderboards_screen)
+            .add_systems(
+                Update,
+                leaderboards_input.run_if(in_state(GameState::Leaderboards)),
+            )
+            .add_systems(OnExit(GameState::Leaderboards), despawn_leaderboards_screen);
+    }
+}
+
+/// Root of the leaderboards screen
+#[derive(Component)]
+pub struct LeaderboardsRoot;
+
+/// Focused filter and the chosen option of each (0 = all)
+#[derive(Resource, Default, Debug)]
+pub struct LeaderboardBrowser {
+    pub focus: usize,
+    pub choices: [usize; FILTER_COUNT],
+    /// Showing the shared boards instead of the local ones
+    pub shared: bool,
+}
+
+const FILTER_COUNT: usize = 4;
+const FILTER_NAMES: [&str; FILTER_COUNT] = ["MODE", "DIFFICULTY", "SHIP", "SEED"];
+
+/// One choice of a filter
+enum FilterValue {
+    All,
+    Mode(LeaderboardMode),
+    Difficulty(Difficulty),
+    Ship(u32),
+    Seed(u32),
+}
+
+/// Options of each filter with their labels, drawn from what is on the boards
+fn filter_options(boards: &Leaderboards) -> [Vec<(String, FilterValue)>; FILTER_COUNT] {
+    let all = || vec![("All".to_string(), FilterValue::All)];
+
+    let mut modes = all();
+    modes.extend(
+        boards
+            .modes()
+            .into_iter()
+            .map(|mode| (mode.name(), FilterValue::Mode(mode))),
+    );
+
+    let mut difficulties = all();
+    difficulties.extend(
+        Difficulty::all()
+            .iter()
+            .map(|d| (d.name().to_string(), FilterValue::Difficulty(*d))),
+    );
+
+    let entries = || boards.boards.iter().flat_map(|b| b.entries.iter());
+    let mut ships: Vec<(u32, String)> = entries()
+        .map(|e| (e.ship_type_id, e.ship.clone()))
+        .collect();
+    ships.sort();
+    ships.dedup_by_key(|(id, _)| *id);
+    let mut ship_options = all();
+    ship_options.extend(
+        ships
+            .into_iter()
+            .map(|(id, name)| (name, FilterValue::Ship(id))),
+    );
+
+    let mut seeds: Vec<u32> = entries().filter_map(|e| e.seed).collect();
+    seeds.sort();
+    seeds.dedup();
+    let mut seed_options = all();
+    seed_options.extend(
+        seeds
+            .into_iter()
+            .map(|seed| (seed_code(seed), FilterValue::Seed(seed))),
+    );
+
+    [modes, difficulties, ship_options, seed_options]
+}
+
+/// Filter for the browser's choices, clamping them to the options
+fn current_filter(
+    browser: &mut LeaderboardBrowser,
+    options: &[Vec<(String, FilterValue)>; FILTER_COUNT],
+) -> LeaderboardFilter {
+    let mut filter = LeaderboardFilter::default();
+    for (choice, options) in browser.choices.iter_mut().zip(options) {
+        *choice = (*choice).min(options.len() - 1);
+        match &options[*choice].1 {
+            FilterValue::All => {}
+            FilterValue::Mode(mode) => filter.mode = Some(mode.clone()),
+            FilterValue::Difficulty(difficulty) => filter.difficulty = Some(*difficulty),
+            FilterValue::Ship(id) => filter.ship_type_id = Some(*id),
+            FilterValue::Seed(seed) => filter.seed = Some(*seed),
+        }
+    }
+    filter
+}
+
+fn spawn_leaderboards_screen(
+    mut commands: Commands,
+    mut browser: ResMut<LeaderboardBrowser>,
+    boards: Res<Leaderboards>,
+    mut shared: ResMut<SharedBoards>,
+    save_data: Res<SaveData>,
+    race: Res<GhostRace>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    browser.focus = 0;
+    if browser.shared {
+        shared.fetch(&save_data.settings.leaderboard_server);
+    }
+    let text = build_leaderboards_screen(&mut commands, &mut browser, &boards, &shared, &race);
+    narration.send(NarrationEvent::queued(text));
+}
+
+/// Build the screen; returns its narration
+fn build_leaderboards_screen(
+    commands: &mut Commands,
+    browser: &mut LeaderboardBrowser,
+    local: &Leaderboards,
+    shared: &SharedBoards,
+    race: &GhostRace,
+) -> String {
+    let accent = Color::srgb(1.0, 0.85, 0.2);
+    let boards = if browser.shared {
+        &shared.boards
+    } else {
+        local
+    };
+    let options = filter_options(boards);
+    let filter = current_filter(browser, &options);
+    let entries = boards.query(&filter);
+
+    commands
+        .spawn((
+            LeaderboardsRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.95)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(if browser.shared {
+                    "SHARED LEADERBOARDS"
+                } else {
+                    "LEADERBOARDS"
+                }),
+                TextFont {
+                    font_size: 42.0,
+                    ..default()
+                },
+                TextColor(accent),
+            ));
+            if browser.shared {
+                parent.spawn((
+                    Text::new(shared.status.clone()),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.8, 1.0)),
+                ));
+            }
+
+            // Filters
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(12.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (index, name) in FILTER_NAMES.iter().enumerate() {
+                        let focused = index == browser.focus;
+                        row.spawn((
+                            Node {
+                                padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BorderColor(if focused {
+                                accent
+                            } else {
+                                Color::srgb(0.2, 0.2, 0.2)
+                            }),
+                        ))
+                        .with_child((
+                            Text::new(format!(
+                                "{}: {}",
+                                name, options[index][browser.choices[index]].0
+                            )),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(if focused {
+                                accent
+                            } else {
+                                Color::srgb(0.6, 0.6, 0.6)
+                            }),
+                        ));
+                    }
+                });
+
+            spawn_entry_row(
+                parent,
+                [
+                    "#", "PILOT", "SHIP", "SCORE", "TIME", "GRADE", "DATE", "SEED",
+                ]
+                .map(str::to_string),
+                Color::srgb(0.6, 0.6, 0.6),
+            );
+            if entries.is_empty() {
+                parent.spawn((
+                    Text::new("No runs recorded yet"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                ));
+            }
+            for (rank, (difficulty, entry)) in entries.iter().enumerate() {
+                let time = if entry.wave > 0 {
+                    format!("{} (W{})", format_time(entry.time), entry.wave)
+                } else {
+                    format_time(entry.time)
+                };
+                let pilot = if filter.difficulty.is_none() {
+                    format!("{} [{}]", entry.pilot, difficulty.name())
+                } else {
+                    entry.pilot.clone()
+                };
+                let seed = entry.seed.map(seed_code).unwrap_or_else(|| "-".to_string());
+                spawn_entry_row(
+                    parent,
+                    [
+                        format!(
+                            "{}{}{}",
+                            rank + 1,
+                            if entry.replay.is_some() { " ▶" } else { "" },
+                            if entry.has_input_log { " ✓" } else { "" }
+                        ),
+                        pilot,
+                        entry.ship.clone(),
+                        entry.score.to_string(),
+                        time,
+                        entry.grade.clone(),
+                        format_date(entry.timestamp),
+                        seed,
+                    ],
+                    if rank == 0 {
+                        accent
+                    } else {
+                        Color::srgb(0.85, 0.85, 0.85)
+                    },
+                );
+            }
+
+            if let Some(target) = &race.target {
+                parent.spawn((
+                    Text::new(format!(
+                        "Ghost: {} ({}) on {}",
+                        target.pilot,
+                        target.score,
+                        target.mode.name()
+                    )),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.8, 1.0)),
+                ));
+            }
+
+            parent.spawn((
+                Text::new(if browser.shared {
+                    "↑↓ Filter • ←→/LB RB Change • ✓ Input log stored • R Refresh • I Input log • TAB/X Local • ESC Back"
+                } else {
+                    "↑↓ Filter • ←→/LB RB Change • ▶ Replay saved • G/Y Race ghost • TAB/X Shared • ESC Back"
+                }),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+            ));
+        });
+
+    let focused = &options[browser.focus][browser.choices[browser.focus]];
+    let top = entries.first().map_or_else(
+        || "no runs".to_string(),
+        |(_, e)| format!("top score {} by {}", e.score, e.pilot),
+    );
+    format!(
+        "{}{} {}. {} entries, {}",
+        if browser.shared { "Shared. " } else { "" },
+        FILTER_NAMES[browser.focus].to_lowercase(),
+        focused.0,
+        entries.len(),
+        top
+    )
+}
+
+/// Column widths of the entry table
+const COLUMN_WIDTHS: [f32; 8] = [40.0, 200.0, 110.0, 100.0, 100.0, 60.0, 100.0, 100.0];
+
+fn spawn_entry_row(parent: &mut ChildBuilder, cells: [String; 8], color: Color) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            ..default()
+        })
+        .with_children(|row| {
+            for (cell, width) in cells.into_iter().zip(COLUMN_WIDTHS) {
+                row.spawn((
+                    Text::new(cell),
+                    TextFont {
+                        font_size: 15.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                    Node {
+                        width: Val::Px(width),
+                        ..default()
+                    },
+                ));
+            }
+        });
+}
+
+/// `m:ss`
+fn format_time(seconds: f32) -> String {
+    let seconds = seconds.max(0.0) as u32;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+fn leaderboards_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut browser: ResMut<LeaderboardBrowser>,
+    local: Res<Leaderboards>,
+    mut shared: ResMut<SharedBoards>,
+    save_data: Res<SaveData>,
+    mut race: ResMut<GhostRace>,
+    roots: Query<Entity, With<LeaderboardsRoot>>,
+    mut narration: EventWriter<NarrationEvent>,
+    mut transitions: EventWriter<TransitionEvent>,
+) {
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        transitions.send(TransitionEvent::quick(GameState::MainMenu));
+        return;
+    }
+
+    // A background fetch came back
+    let mut changed = shared.poll();
+    let mut status_text = changed.then(|| shared.status.clone());
+
+    let location = &save_data.settings.leaderboard_server;
+    if keyboard.just_pressed(KeyCode::Tab) || joystick.x_button() {
+        browser.shared = !browser.shared;
+        if browser.shared {
+            shared.fetch(location);
+        }
+        changed = true;
+    }
+    if browser.shared && keyboard.just_pressed(KeyCode::KeyR) {
+        shared.fetch(location);
+        status_text = Some(shared.status.clone());
+        changed = true;
+    }
+    if browser.shared && keyboard.just_pressed(KeyCode::KeyI) {
+        let filter = current_filter(&mut browser, &filter_options(&shared.boards));
+        let top = shared
+            .boards
+            .query(&filter)
+            .first()
+            .map(|(_, e)| (*e).clone());
+        match top {
+            Some(entry) => shared.fetch_input_log(location, &entry),
+            None => shared.status = "No run to fetch an input log for".to_string(),
+        }
+        status_text = Some(shared.status.clone());
+        changed = true;
+    }
+    let boards = if browser.shared {
+        &shared.boards
+    } else {
+        &*local
+    };
+
+    if keyboard.just_pressed(KeyCode::ArrowDown)
+        || keyboard.just_pressed(KeyCode::KeyS)
+        || joystick.dpad_just_down()
+    {
+        browser.focus = (browser.focus + 1) % FILTER_COUNT;
+        changed = true;
+    }
+    if keyboard.just_pressed(KeyCode::ArrowUp)
+        || keyboard.just_pressed(KeyCode::KeyW)
+        || joystick.dpad_just_up()
+    {
+        browser.focus = (browser.focus + FILTER_COUNT - 1) % FILTER_COUNT;
+        changed = true;
+    }
+
+    let step: i32 = if keyboard.just_pressed(KeyCode::ArrowRight)
+        || keyboard.just_pressed(KeyCode::KeyD)
+        || joystick.dpad_just_right()
+        || joystick.right_bumper()
+    {
+        1
+    } else if keyboard.just_pressed(KeyCode::ArrowLeft)
+        || keyboard.just_pressed(KeyCode::KeyA)
+        || joystick.dpad_just_left()
+        || joystick.left_bumper()
+    {
+        -1
+    } else {
+        0
+    };
+    if step != 0 {
+        let count = filter_options(boards)[browser.focus].len() as i32;
+        let focus = browser.focus;
+        browser.choices[focus] = (browser.choices[focus] as i32 + step).rem_euclid(count) as usize;
+        changed = true;
+    }
+
+    let mut ghost_text = None;
+    if keyboard.just_pressed(KeyCode::KeyG) || joystick.y_button() {
+        let target = ghost_target(&mut browser, boards);
+        ghost_text = Some(match &target {
+            None => "No replay to race".to_string(),
+            Some(target) if race.target.as_ref() == Some(target) => {
+                race.target = None;
+                "Ghost cleared".to_string()
+            }
+            Some(target) => {
+                let text = format!("Racing ghost of {} on {}", target.pilot, target.mode.name());
+                race.target = Some(target.clone());
+                text
+            }
+        });
+        changed = true;
+    }
+
+    if changed {
+        for root in roots.iter() {
+            commands.entity(root).despawn_recursive();
+        }
+        let text = build_leaderboards_screen(&mut commands, &mut browser, &local, &shared, &race);
+        narration.send(NarrationEvent::new(
+            ghost_text.or(status_text).unwrap_or(text),
+        ));
+    }
+}
+
+/// Best shown entry with a replay, as a ghost to race
+fn ghost_target(browser: &mut LeaderboardBrowser, boards: &Leaderboards) -> Option<GhostTarget> {
+    let filter = current_filter(browser, &filter_options(boards));
+    let entries = boards.query(&filter);
+    let (_, entry) = entries.iter().find(|(_, e)| e.replay.is_some())?;
+    let board = boards
+        .boards
+        .iter()
+        .find(|b| b.entries.iter().any(|e| e.replay == entry.replay))?;
+    Some(GhostTarget {
+        mode: board.mode.clone(),
+        pilot: entry.pilot.clone(),
+        score: entry.score,
+        id: entry.replay.clone()?,
+    })
+}
+
+fn despawn_leaderboards_screen(
+    mut commands: Commands,
+    roots: Query<Entity, With<LeaderboardsRoot>>,
+) {
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+}
@@ -7,9 +7,15 @@
 
 use crate::core::*;
 use crate::entities::boss::get_boss_for_stage;
-use crate::games::ActiveModule;
+use crate::games::mod_packages::{ModLoadErrors, ModPackages};
+use crate::games::{
+    campaign_map_active, core_enabled, ActiveModule, CoreSystem, GameModuleInfo, GameModules,
+    ModuleRegistry,
+};
 use crate::systems::JoystickState;
+use crate::ui::narration::collect_text;
 use crate::ui::TransitionEvent;
+use bevy::input::keyboard::{Key, KeyboardInput};
 use bevy::prelude::*;
 
 /// Menu plugin
@@ -29,7 +35,11 @@ impl Plugin for MenuPlugin {
             .add_systems(OnEnter(GameState::MainMenu), spawn_main_menu)
             .add_systems(
                 Update,
-                (main_menu_input, update_menu_selection::<MainMenuRoot>)
+                (
+                    main_menu_input,
+                    update_menu_selection::<MainMenuRoot>,
+                    narrate_menu_selection,
+                )
                     .run_if(in_state(GameState::MainMenu)),
             )
             .add_systems(OnExit(GameState::MainMenu), despawn_menu::<MainMenuRoot>)
@@ -40,6 +50,7 @@ impl Plugin for MenuPlugin {
                 (
                     module_select_input,
                     update_menu_selection::<ModuleSelectRoot>,
+                    narrate_menu_selection,
                 )
                     .run_if(in_state(GameState::ModuleSelect)),
             )
@@ -54,20 +65,20 @@ impl Plugin for MenuPlugin {
                 options_menu_input.run_if(in_state(GameState::Options)),
             )
             .add_systems(OnExit(GameState::Options), despawn_menu::<OptionsMenuRoot>)
-            // Faction Select (unified 4-faction) - only for Elder Fleet module
+            // Faction Select (unified 4-faction) - unless the module has its own
             .add_systems(
                 OnEnter(GameState::FactionSelect),
-                spawn_faction_select.run_if(is_elder_fleet),
+                spawn_faction_select.run_if(core_enabled(CoreSystem::FactionSelect)),
             )
             .add_systems(
                 Update,
-                faction_select_input
+                (faction_select_input, narrate_menu_selection)
                     .run_if(in_state(GameState::FactionSelect))
-                    .run_if(is_elder_fleet),
+                    .run_if(core_enabled(CoreSystem::FactionSelect)),
             )
             .add_systems(
                 OnExit(GameState::FactionSelect),
-                despawn_menu::<FactionSelectRoot>.run_if(is_elder_fleet),
+                despawn_menu::<FactionSelectRoot>.run_if(core_enabled(CoreSystem::FactionSelect)),
             )
             // Difficulty Select
             .add_systems(OnEnter(GameState::DifficultySelect), spawn_difficulty_menu)
@@ -76,6 +87,7 @@ impl Plugin for MenuPlugin {
                 (
                     difficulty_menu_input,
                     update_menu_selection::<DifficultyMenuRoot>,
+                    narrate_menu_selection,
                 )
                     .run_if(in_state(GameState::DifficultySelect)),
             )
@@ -83,12 +95,23 @@ impl Plugin for MenuPlugin {
                 OnExit(GameState::DifficultySelect),
                 despawn_menu::<DifficultyMenuRoot>,
             )
-            // Stage Select
-            .add_systems(OnEnter(GameState::StageSelect), spawn_stage_select)
+            // Stage Select (modules with a campaign map use the star map instead)
+            .add_systems(
+                OnEnter(GameState::StageSelect),
+                spawn_stage_select
+                    .run_if(core_enabled(CoreSystem::StageSelect))
+                    .run_if(not(campaign_map_active)),
+            )
             .add_systems(
                 Update,
-                (stage_select_input, update_menu_selection::<StageSelectRoot>)
-                    .run_if(in_state(GameState::StageSelect)),
+                (
+                    stage_select_input,
+                    update_menu_selection::<StageSelectRoot>,
+                    narrate_menu_selection,
+                )
+                    .run_if(in_state(GameState::StageSelect))
+                    .run_if(core_enabled(CoreSystem::StageSelect))
+                    .run_if(not(campaign_map_active)),
             )
             .add_systems(
                 OnExit(GameState::StageSelect),
@@ -102,21 +125,11 @@ impl Plugin for MenuPlugin {
                     ship_menu_input,
                     update_menu_selection::<ShipMenuRoot>,
                     update_ship_detail_panel,
+                    (narrate_menu_selection, narrate_ship_details).chain(),
                 )
                     .run_if(in_state(GameState::ShipSelect)),
             )
             .add_systems(OnExit(GameState::ShipSelect), despawn_menu::<ShipMenuRoot>)
-            // Upgrade Shop
-            .add_systems(OnEnter(GameState::UpgradeShop), spawn_upgrade_shop)
-            .add_systems(
-                Update,
-                (upgrade_shop_input, update_upgrade_shop_selection)
-                    .run_if(in_state(GameState::UpgradeShop)),
-            )
-            .add_systems(
-                OnExit(GameState::UpgradeShop),
-                despawn_menu::<UpgradeShopRoot>,
-            )
             // Pause Menu
             .add_systems(OnEnter(GameState::Paused), spawn_pause_menu)
             .add_systems(Update, pause_menu_input.run_if(in_state(GameState::Paused)))
@@ -129,37 +142,37 @@ impl Plugin for MenuPlugin {
                     .run_if(in_state(GameState::GameOver)),
             )
             .add_systems(OnExit(GameState::GameOver), despawn_death_screen)
-            // Boss Intro (Elder Fleet only - CG has its own)
+            // Boss Intro (modules with their own screens replace ResultScreens)
             .add_systems(
                 OnEnter(GameState::BossIntro),
-                spawn_boss_intro.run_if(is_elder_fleet),
+                spawn_boss_intro.run_if(core_enabled(CoreSystem::ResultScreens)),
             )
             .add_systems(
                 Update,
                 boss_intro_update
                     .run_if(in_state(GameState::BossIntro))
-                    .run_if(is_elder_fleet),
+                    .run_if(core_enabled(CoreSystem::ResultScreens)),
             )
             .add_systems(OnExit(GameState::BossIntro), despawn_menu::<BossIntroRoot>)
-            // Stage Complete (Elder Fleet only - CG has its own)
+            // Stage Complete
             .add_systems(
                 OnEnter(GameState::StageComplete),
-                spawn_stage_complete.run_if(is_elder_fleet),
+                spawn_stage_complete.run_if(core_enabled(CoreSystem::ResultScreens)),
             )
             .add_systems(
                 Update,
                 stage_complete_input
                     .run_if(in_state(GameState::StageComplete))
-                    .run_if(is_elder_fleet),
+                    .run_if(core_enabled(CoreSystem::ResultScreens)),
             )
             .add_systems(
                 OnExit(GameState::StageComplete),
-                despawn_menu::<StageCompleteRoot>.run_if(is_elder_fleet),
+                despawn_menu::<StageCompleteRoot>.run_if(core_enabled(CoreSystem::ResultScreens)),
             )
-            // Victory (Elder Fleet only - CG has its own)
+            // Victory
             .add_systems(
                 OnEnter(GameState::Victory),
-                spawn_victory_screen.run_if(is_elder_fleet),
+                spawn_victory_screen.run_if(core_enabled(CoreSystem::ResultScreens)),
             )
             .add_systems(
                 Update,
@@ -169,13 +182,13 @@ impl Plugin for MenuPlugin {
                     update_victory_buttons,
                 )
                     .run_if(in_state(GameState::Victory))
-                    .run_if(is_elder_fleet),
+                    .run_if(core_enabled(CoreSystem::ResultScreens)),
             )
             .add_systems(
                 OnExit(GameState::Victory),
-                despawn_victory_screen.run_if(is_elder_fleet),
+                despawn_victory_screen.run_if(core_enabled(CoreSystem::ResultScreens)),
             )
-            // Endless Mode Announcements (Elder Fleet only)
+            // Endless Mode Announcements
             .add_systems(
                 Update,
                 (
@@ -184,7 +197,7 @@ impl Plugin for MenuPlugin {
                     update_endless_announcements,
                 )
                     .run_if(in_state(GameState::Playing))
-                    .run_if(is_elder_fleet),
+                    .run_if(core_enabled(CoreSystem::Hud)),
             )
             // Init menu selection resource
             .init_resource::<MenuSelection>();
@@ -263,14 +276,6 @@ struct BossIntroDialogue {
     timer: f32,
 }
 
-#[derive(Component)]
-struct UpgradeShopRoot;
-
-#[derive(Component)]
-struct UpgradeItem {
-    upgrade: crate::core::Upgrade,
-}
-
 #[derive(Component)]
 struct StageCompleteRoot;
 
@@ -399,7 +404,7 @@ fn loading_progress(
 ) {
     *timer += time.delta_secs();
     if *timer > 1.0 {
-        next_state.set(GameState::MainMenu);
+        next_state.set(GameState::ProfileSelect);
     }
 }
 
@@ -411,9 +416,10 @@ fn spawn_main_menu(
     mut commands: Commands,
     mut selection: ResMut<MenuSelection>,
     save_data: Res<SaveData>,
+    profiles: Res<PilotProfiles>,
 ) {
     selection.index = 0;
-    selection.total = 4; // PLAY, UPGRADES, OPTIONS, QUIT
+    selection.total = 7; // PLAY, SKILLS, OPTIONS, ACHIEVEMENTS, LEADERBOARDS, SWITCH PILOT, QUIT
 
     // Get best high score across all faction pairs
     let best_score = save_data
@@ -457,6 +463,17 @@ fn spawn_main_menu(
                 TextColor(Color::srgb(0.6, 0.4, 0.2)), // Bronze/copper
             ));
 
+            if let Some(pilot) = profiles.active_profile() {
+                parent.spawn((
+                    Text::new(format!("PILOT: {}", pilot.name)),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                ));
+            }
+
             // Spacer
             parent.spawn(Node {
                 height: Val::Px(40.0),
@@ -465,9 +482,12 @@ fn spawn_main_menu(
 
             // Menu buttons
             spawn_menu_item(parent, "PLAY", 0);
-            spawn_menu_item(parent, "UPGRADES", 1);
+            spawn_menu_item(parent, "SKILLS", 1);
             spawn_menu_item(parent, "OPTIONS", 2);
-            spawn_menu_item(parent, "QUIT", 3);
+            spawn_menu_item(parent, "ACHIEVEMENTS", 3);
+            spawn_menu_item(parent, "LEADERBOARDS", 4);
+            spawn_menu_item(parent, "SWITCH PILOT", 5);
+            spawn_menu_item(parent, "QUIT", 6);
 
             // High score display
             if best_score > 0 {
@@ -566,14 +586,26 @@ fn main_menu_input(
                 transitions.send(TransitionEvent::to(GameState::ModuleSelect));
             }
             1 => {
-                // UPGRADES - go to upgrade shop
-                transitions.send(TransitionEvent::to(GameState::UpgradeShop));
+                // SKILLS - go to the skill tree
+                transitions.send(TransitionEvent::to(GameState::SkillTree));
             }
             2 => {
                 // OPTIONS - go to options menu
                 transitions.send(TransitionEvent::to(GameState::Options));
             }
             3 => {
+                // ACHIEVEMENTS - browse achievements and progress
+                transitions.send(TransitionEvent::to(GameState::Achievements));
+            }
+            4 => {
+                // LEADERBOARDS - local top runs
+                transitions.send(TransitionEvent::to(GameState::Leaderboards));
+            }
+            5 => {
+                // SWITCH PILOT - back to pilot select
+                transitions.send(TransitionEvent::to(GameState::ProfileSelect));
+            }
+            6 => {
                 exit.send(AppExit::Success);
             }
             _ => {}
@@ -590,14 +622,42 @@ fn main_menu_input(
 // Module Select
 // ============================================================================
 
-/// Run condition: is the active module Elder Fleet (default)?
-fn is_elder_fleet(active_module: Res<ActiveModule>) -> bool {
-    active_module.is_elder_fleet()
+/// Built-in cards: Elder Fleet, Caldari vs Gallente, Abyssal Depths, Endless,
+/// Roguelite, Challenges. Mod package cards follow.
+const BUILTIN_MODULE_CARDS: usize = 6;
+
+/// Most package load errors listed on screen; the rest are in the log
+const MAX_MOD_ERRORS_SHOWN: usize = 6;
+
+/// Registry entries that come from mod packages, in card order
+fn package_modules<'a>(
+    registry: &'a ModuleRegistry,
+    packages: &'a ModPackages,
+) -> impl Iterator<Item = &'a GameModuleInfo> {
+    registry
+        .modules
+        .iter()
+        .filter(|info| packages.get(info.id).is_some())
 }
 
-fn spawn_module_select(mut commands: Commands, mut selection: ResMut<MenuSelection>) {
+/// Card color for a package: its first faction's primary color
+fn package_card_color(info: &GameModuleInfo) -> Color {
+    info.factions
+        .first()
+        .map(|f| f.primary_color)
+        .unwrap_or(Color::srgb(0.5, 0.5, 0.5))
+}
+
+fn spawn_module_select(
+    mut commands: Commands,
+    mut selection: ResMut<MenuSelection>,
+    registry: Res<ModuleRegistry>,
+    packages: Res<ModPackages>,
+    load_errors: Res<ModLoadErrors>,
+) {
+    let mods: Vec<&GameModuleInfo> = package_modules(&registry, &packages).collect();
     selection.index = 0;
-    selection.total = 4; // Elder Fleet, Caldari vs Gallente, Abyssal Depths, Endless
+    selection.total = BUILTIN_MODULE_CARDS + mods.len();
 
     commands
         .spawn((
@@ -633,7 +693,11 @@ fn spawn_module_select(mut commands: Commands, mut selection: ResMut<MenuSelecti
             parent
                 .spawn(Node {
                     flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    justify_content: JustifyContent::Center,
                     column_gap: Val::Px(30.0),
+                    row_gap: Val::Px(20.0),
+                    max_width: Val::Px(1300.0),
                     ..default()
                 })
                 .with_children(|row| {
@@ -680,8 +744,103 @@ fn spawn_module_select(mut commands: Commands, mut selection: ResMut<MenuSelecti
                         Color::srgb(0.7, 0.2, 0.2), // Red for danger
                         "∞",
                     );
+
+                    // Roguelite card
+                    spawn_module_card(
+                        row,
+                        4,
+                        "ROGUELITE",
+                        "Sector Runs",
+                        "Seeded sectors, loot and a boss.\nSalvage buys permanent unlocks.",
+                        Color::srgb(0.2, 0.6, 0.5), // Salvage teal
+                        "⬡",
+                    );
+
+                    // Challenges card
+                    spawn_module_card(
+                        row,
+                        5,
+                        "CHALLENGES",
+                        "Daily & Weekly",
+                        "Same seed and modifiers for everyone.\nOne ranked attempt each.",
+                        Color::srgb(0.85, 0.7, 0.2), // Challenge gold
+                        "★",
+                    );
                 });
 
+            // Community campaigns from mod packages
+            if !mods.is_empty() {
+                parent.spawn((
+                    Text::new("COMMUNITY CAMPAIGNS"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                ));
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::Center,
+                        column_gap: Val::Px(20.0),
+                        row_gap: Val::Px(10.0),
+                        max_width: Val::Px(1200.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        for (i, info) in mods.iter().enumerate() {
+                            spawn_package_card(row, BUILTIN_MODULE_CARDS + i, info);
+                        }
+                    });
+            }
+
+            // Packages that failed to load
+            if !load_errors.errors.is_empty() {
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(2.0),
+                        max_width: Val::Px(1000.0),
+                        ..default()
+                    })
+                    .with_children(|list| {
+                        list.spawn((
+                            Text::new(format!("{} MOD PACKAGE ERROR(S)", load_errors.errors.len())),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(1.0, 0.5, 0.2)),
+                        ));
+                        for error in load_errors.errors.iter().take(MAX_MOD_ERRORS_SHOWN) {
+                            list.spawn((
+                                Text::new(error.to_string()),
+                                TextFont {
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.8, 0.5, 0.4)),
+                            ));
+                        }
+                        let hidden = load_errors
+                            .errors
+                            .len()
+                            .saturating_sub(MAX_MOD_ERRORS_SHOWN);
+                        if hidden > 0 {
+                            list.spawn((
+                                Text::new(format!("...and {} more (see log)", hidden)),
+                                TextFont {
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                            ));
+                        }
+                    });
+            }
+
             parent.spawn(Node {
                 height: Val::Px(30.0),
                 ..default()
@@ -786,6 +945,61 @@ fn spawn_module_card(
         });
 }
 
+/// Compact card for a mod package campaign
+fn spawn_package_card(parent: &mut ChildBuilder, index: usize, info: &GameModuleInfo) {
+    let color = package_card_color(info);
+    let factions = info
+        .factions
+        .iter()
+        .map(|f| f.name)
+        .collect::<Vec<_>>()
+        .join(" vs ");
+
+    parent
+        .spawn((
+            MenuItem { index },
+            Node {
+                width: Val::Px(280.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::all(Val::Px(3.0)),
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(color.with_alpha(0.2)),
+            BorderColor(color.with_alpha(0.5)),
+        ))
+        .with_children(|card| {
+            card.spawn((
+                Text::new(info.display_name.to_uppercase()),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            if !info.subtitle.is_empty() {
+                card.spawn((
+                    Text::new(info.subtitle),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            }
+            card.spawn((
+                Text::new(factions),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+        });
+}
+
 fn module_select_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
@@ -793,10 +1007,14 @@ fn module_select_input(
     mut active_module: ResMut<ActiveModule>,
     mut endless: ResMut<crate::core::EndlessMode>,
     mut abyssal: ResMut<crate::games::abyssal_depths::AbyssalState>,
+    registry: Res<ModuleRegistry>,
+    packages: Res<ModPackages>,
     time: Res<Time>,
     mut transitions: EventWriter<TransitionEvent>,
     mut cards: Query<(&MenuItem, &mut BackgroundColor, &mut BorderColor)>,
 ) {
+    let mods: Vec<&GameModuleInfo> = package_modules(&registry, &packages).collect();
+
     selection.cooldown -= time.delta_secs();
 
     // Navigation
@@ -808,12 +1026,15 @@ fn module_select_input(
     }
 
     // Update card highlights
-    let colors = [
-        Color::srgb(0.8, 0.5, 0.2), // Elder Fleet orange
-        Color::srgb(0.2, 0.4, 0.7), // Caldari blue
-        Color::srgb(0.6, 0.2, 0.6), // Abyssal purple
-        Color::srgb(0.7, 0.2, 0.2), // Endless red
+    let mut colors = vec![
+        Color::srgb(0.8, 0.5, 0.2),  // Elder Fleet orange
+        Color::srgb(0.2, 0.4, 0.7),  // Caldari blue
+        Color::srgb(0.6, 0.2, 0.6),  // Abyssal purple
+        Color::srgb(0.7, 0.2, 0.2),  // Endless red
+        Color::srgb(0.2, 0.6, 0.5),  // Roguelite teal
+        Color::srgb(0.85, 0.7, 0.2), // Challenges gold
     ];
+    colors.extend(mods.iter().copied().map(package_card_color));
 
     for (item, mut bg, mut border) in cards.iter_mut() {
         let color = colors.get(item.index).copied().unwrap_or(colors[0]);
@@ -833,7 +1054,7 @@ fn module_select_input(
         match selection.index {
             0 => {
                 // Elder Fleet
-                active_module.set_module("elder_fleet");
+                active_module.set_module(crate::games::elder_fleet::MODULE_ID);
                 endless.active = false;
                 abyssal.active = false;
                 info!("Selected Elder Fleet campaign");
@@ -841,7 +1062,7 @@ fn module_select_input(
             }
             1 => {
                 // Caldari vs Gallente
-                active_module.set_module("caldari_gallente");
+                active_module.set_module(crate::games::caldari_gallente::MODULE_ID);
                 endless.active = false;
                 abyssal.active = false;
                 info!("Selected Caldari vs Gallente campaign");
@@ -849,7 +1070,7 @@ fn module_select_input(
             }
             2 => {
                 // Abyssal Depths
-                active_module.set_module("abyssal_depths");
+                active_module.set_module(crate::games::abyssal_depths::MODULE_ID);
                 endless.active = false;
                 abyssal.active = true; // Set BEFORE entering Playing state
                 info!("Selected ABYSSAL DEPTHS!");
@@ -858,13 +1079,34 @@ fn module_select_input(
             }
             3 => {
                 // Endless Mode
-                active_module.set_module("elder_fleet"); // Use Elder Fleet enemies
+                active_module.set_module(crate::games::elder_fleet::MODULE_ID); // Use Elder Fleet enemies
                 endless.active = true;
                 abyssal.active = false;
                 info!("Selected ENDLESS MODE!");
                 transitions.send(TransitionEvent::to(GameState::FactionSelect));
             }
-            _ => {}
+            4 => {
+                // Roguelite sector runs
+                active_module.set_module(crate::games::roguelite::MODULE_ID);
+                endless.active = false;
+                abyssal.active = false;
+                info!("Selected ROGUELITE sector runs");
+                transitions.send(TransitionEvent::to(GameState::FactionSelect));
+            }
+            5 => {
+                // Daily and weekly challenges pick their own setup
+                transitions.send(TransitionEvent::to(GameState::Challenges));
+            }
+            index => {
+                // Mod package campaign
+                if let Some(info) = mods.get(index - BUILTIN_MODULE_CARDS) {
+                    active_module.set_module(info.id);
+                    endless.active = false;
+                    abyssal.active = false;
+                    info!("Selected mod package {}", info.display_name);
+                    transitions.send(TransitionEvent::to(GameState::FactionSelect));
+                }
+            }
         }
     }
 
@@ -896,19 +1138,276 @@ enum VolumeSetting {
     Master,
     Music,
     Sfx,
+    Weapons,
+    Explosions,
+    Ui,
+    Warnings,
+}
+
+impl VolumeSetting {
+    /// All settings in menu order
+    const ALL: [VolumeSetting; 7] = [
+        VolumeSetting::Master,
+        VolumeSetting::Music,
+        VolumeSetting::Sfx,
+        VolumeSetting::Weapons,
+        VolumeSetting::Explosions,
+        VolumeSetting::Ui,
+        VolumeSetting::Warnings,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            VolumeSetting::Master => "Master Volume",
+            VolumeSetting::Music => "Music Volume",
+            VolumeSetting::Sfx => "SFX Volume",
+            VolumeSetting::Weapons => "  Weapons",
+            VolumeSetting::Explosions => "  Explosions",
+            VolumeSetting::Ui => "  Interface",
+            VolumeSetting::Warnings => "  Warnings",
+        }
+    }
+
+    fn value(&self, settings: &crate::systems::audio::SoundSettings) -> f32 {
+        match self {
+            VolumeSetting::Master => settings.master_volume,
+            VolumeSetting::Music => settings.music_volume,
+            VolumeSetting::Sfx => settings.sfx_volume,
+            VolumeSetting::Weapons => settings.weapons_volume,
+            VolumeSetting::Explosions => settings.explosions_volume,
+            VolumeSetting::Ui => settings.ui_volume,
+            VolumeSetting::Warnings => settings.warnings_volume,
+        }
+    }
+
+    fn value_mut<'a>(&self, settings: &'a mut crate::systems::audio::SoundSettings) -> &'a mut f32 {
+        match self {
+            VolumeSetting::Master => &mut settings.master_volume,
+            VolumeSetting::Music => &mut settings.music_volume,
+            VolumeSetting::Sfx => &mut settings.sfx_volume,
+            VolumeSetting::Weapons => &mut settings.weapons_volume,
+            VolumeSetting::Explosions => &mut settings.explosions_volume,
+            VolumeSetting::Ui => &mut settings.ui_volume,
+            VolumeSetting::Warnings => &mut settings.warnings_volume,
+        }
+    }
+
+    /// Sound played after adjusting a mixer sub-bus so the change can be heard
+    fn preview_sound(&self) -> Option<SoundType> {
+        match self {
+            VolumeSetting::Weapons => Some(SoundType::Autocannon),
+            VolumeSetting::Explosions => Some(SoundType::MediumExplosion),
+            VolumeSetting::Ui => Some(SoundType::MenuSelect),
+            VolumeSetting::Warnings => Some(SoundType::ShieldWarning),
+            _ => None,
+        }
+    }
+}
+
+/// Options menu pages (switch with Tab or the bumpers)
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+enum OptionsPage {
+    #[default]
+    Audio,
+    Accessibility,
+    Online,
+}
+
+impl OptionsPage {
+    fn next(&self) -> Self {
+        match self {
+            OptionsPage::Audio => OptionsPage::Accessibility,
+            OptionsPage::Accessibility => OptionsPage::Online,
+            OptionsPage::Online => OptionsPage::Audio,
+        }
+    }
+
+    fn row_count(&self) -> usize {
+        match self {
+            OptionsPage::Audio => VolumeSetting::ALL.len(),
+            OptionsPage::Accessibility => AccessSetting::ALL.len(),
+            OptionsPage::Online => 1,
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            OptionsPage::Audio => "Audio",
+            OptionsPage::Accessibility => "Accessibility",
+            OptionsPage::Online => "Online",
+        }
+    }
+
+    /// Narration line for a row: its label and current value
+    fn row_narration(
+        &self,
+        row: usize,
+        sound_settings: &crate::systems::audio::SoundSettings,
+        accessibility: &AccessibilitySettings,
+        settings: &GameSettings,
+    ) -> String {
+        match self {
+            OptionsPage::Audio => {
+                let setting = VolumeSetting::ALL[row.min(VolumeSetting::ALL.len() - 1)];
+                format!(
+                    "{}, {}%",
+                    setting.label().trim(),
+                    (setting.value(sound_settings) * 100.0) as i32
+                )
+            }
+            OptionsPage::Accessibility => {
+                let setting = AccessSetting::ALL[row.min(AccessSetting::ALL.len() - 1)];
+                format!("{}, {}", setting.label(), setting.value_text(accessibility))
+            }
+            OptionsPage::Online => format!(
+                "Leaderboard server, {}. Press Enter to type",
+                server_text(&settings.leaderboard_server)
+            ),
+        }
+    }
+}
+
+/// Value shown for the leaderboard server setting
+fn server_text(server: &str) -> &str {
+    if server.is_empty() {
+        "Off (local only)"
+    } else {
+        server
+    }
+}
+
+/// Longest leaderboard server location that can be typed
+const MAX_SERVER_LEN: usize = 120;
+
+/// Value text of the leaderboard server row
+#[derive(Component)]
+struct ServerValue;
+
+#[derive(Component)]
+struct AccessRow {
+    setting: AccessSetting,
+}
+
+#[derive(Component)]
+struct AccessBar {
+    setting: AccessSetting,
+}
+
+#[derive(Component)]
+struct AccessValue {
+    setting: AccessSetting,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AccessSetting {
+    Colorblind,
+    ReduceFlashing,
+    TextScale,
+    FireMode,
+    GameSpeed,
+    Narration,
+    AudioCues,
+}
+
+impl AccessSetting {
+    /// All settings in menu order
+    const ALL: [AccessSetting; 7] = [
+        AccessSetting::Colorblind,
+        AccessSetting::ReduceFlashing,
+        AccessSetting::TextScale,
+        AccessSetting::FireMode,
+        AccessSetting::GameSpeed,
+        AccessSetting::Narration,
+        AccessSetting::AudioCues,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            AccessSetting::Colorblind => "Colorblind Mode",
+            AccessSetting::ReduceFlashing => "Reduce Flashing",
+            AccessSetting::TextScale => "Text Size",
+            AccessSetting::FireMode => "Fire Mode",
+            AccessSetting::GameSpeed => "Game Speed",
+            AccessSetting::Narration => "Narration",
+            AccessSetting::AudioCues => "HUD Audio Cues",
+        }
+    }
+
+    fn value_text(&self, settings: &AccessibilitySettings) -> String {
+        match self {
+            AccessSetting::Colorblind => settings.colorblind_mode.label().to_string(),
+            AccessSetting::ReduceFlashing => if settings.reduce_flashing {
+                "On"
+            } else {
+                "Off"
+            }
+            .to_string(),
+            AccessSetting::TextScale => format!("{}%", (settings.text_scale * 100.0).round()),
+            AccessSetting::FireMode => settings.fire_mode.label().to_string(),
+            AccessSetting::GameSpeed => format!("{}%", (settings.game_speed * 100.0).round()),
+            AccessSetting::Narration => settings.narration.label().to_string(),
+            AccessSetting::AudioCues => if settings.audio_cues { "On" } else { "Off" }.to_string(),
+        }
+    }
+
+    /// Bar fill (0-1) for slider settings, None for choices
+    fn bar_fill(&self, settings: &AccessibilitySettings) -> Option<f32> {
+        match self {
+            AccessSetting::TextScale => {
+                Some((settings.text_scale - TEXT_SCALE_MIN) / (TEXT_SCALE_MAX - TEXT_SCALE_MIN))
+            }
+            AccessSetting::GameSpeed => {
+                Some((settings.game_speed - GAME_SPEED_MIN) / (GAME_SPEED_MAX - GAME_SPEED_MIN))
+            }
+            _ => None,
+        }
+    }
+
+    fn adjust(&self, settings: &mut AccessibilitySettings, dir: i32) {
+        match self {
+            AccessSetting::Colorblind => {
+                settings.colorblind_mode = settings.colorblind_mode.cycle(dir);
+            }
+            AccessSetting::ReduceFlashing => settings.reduce_flashing = !settings.reduce_flashing,
+            AccessSetting::TextScale => {
+                settings.text_scale =
+                    (settings.text_scale + dir as f32 * 0.05).clamp(TEXT_SCALE_MIN, TEXT_SCALE_MAX);
+            }
+            AccessSetting::FireMode => settings.fire_mode = settings.fire_mode.cycle(dir),
+            AccessSetting::GameSpeed => {
+                settings.game_speed =
+                    (settings.game_speed + dir as f32 * 0.05).clamp(GAME_SPEED_MIN, GAME_SPEED_MAX);
+            }
+            AccessSetting::Narration => settings.narration = settings.narration.cycle(dir),
+            AccessSetting::AudioCues => settings.audio_cues = !settings.audio_cues,
+        }
+    }
+
+    /// Cooldown before the next adjustment while the key is held
+    fn repeat_delay(&self) -> f32 {
+        match self {
+            AccessSetting::TextScale | AccessSetting::GameSpeed => 0.08,
+            _ => 0.25,
+        }
+    }
 }
 
 #[derive(Resource)]
 struct OptionsMenuState {
+    page: OptionsPage,
     selected: usize,
     cooldown: f32,
+    /// Leaderboard server being typed
+    editing: Option<String>,
 }
 
 impl Default for OptionsMenuState {
     fn default() -> Self {
         Self {
+            page: OptionsPage::Audio,
             selected: 0,
             cooldown: 0.0,
+            editing: None,
         }
     }
 }
@@ -916,9 +1415,33 @@ impl Default for OptionsMenuState {
 fn spawn_options_menu(
     mut commands: Commands,
     sound_settings: Res<crate::systems::audio::SoundSettings>,
+    accessibility: Res<AccessibilitySettings>,
+    save_data: Res<SaveData>,
+    mut narration: EventWriter<NarrationEvent>,
 ) {
-    commands.init_resource::<OptionsMenuState>();
+    commands.insert_resource(OptionsMenuState::default());
+    build_options_menu(
+        &mut commands,
+        OptionsPage::Audio,
+        &sound_settings,
+        &accessibility,
+        &save_data.settings,
+    );
+    narration.send(NarrationEvent::queued(OptionsPage::Audio.row_narration(
+        0,
+        &sound_settings,
+        &accessibility,
+        &save_data.settings,
+    )));
+}
 
+fn build_options_menu(
+    commands: &mut Commands,
+    page: OptionsPage,
+    sound_settings: &crate::systems::audio::SoundSettings,
+    accessibility: &AccessibilitySettings,
+    settings: &GameSettings,
+) {
     // Root container
     commands
         .spawn((
@@ -929,7 +1452,7 @@ fn spawn_options_menu(
                 flex_direction: FlexDirection::Column,
                 align_items: AlignItems::Center,
                 justify_content: JustifyContent::Center,
-                row_gap: Val::Px(20.0),
+                row_gap: Val::Px(6.0),
                 ..default()
             },
             BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.95)),
@@ -944,58 +1467,66 @@ fn spawn_options_menu(
                 },
                 TextColor(Color::srgb(0.9, 0.9, 0.9)),
                 Node {
-                    margin: UiRect::bottom(Val::Px(40.0)),
+                    margin: UiRect::bottom(Val::Px(20.0)),
                     ..default()
                 },
             ));
 
-            // Audio section header
-            parent.spawn((
-                Text::new("AUDIO"),
-                TextFont {
-                    font_size: 24.0,
-                    ..default()
-                },
-                TextColor(Color::srgb(0.6, 0.6, 0.7)),
-                Node {
-                    margin: UiRect::bottom(Val::Px(20.0)),
+            // Page tabs - current page highlighted
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(30.0),
+                    margin: UiRect::bottom(Val::Px(10.0)),
                     ..default()
-                },
-            ));
+                })
+                .with_children(|tabs| {
+                    for (tab, title) in [
+                        (OptionsPage::Audio, "AUDIO"),
+                        (OptionsPage::Accessibility, "ACCESSIBILITY"),
+                        (OptionsPage::Online, "ONLINE"),
+                    ] {
+                        tabs.spawn((
+                            Text::new(title),
+                            TextFont {
+                                font_size: 24.0,
+                                ..default()
+                            },
+                            TextColor(if tab == page {
+                                Color::srgb(0.4, 0.7, 1.0)
+                            } else {
+                                Color::srgb(0.4, 0.4, 0.5)
+                            }),
+                        ));
+                    }
+                });
 
-            // Volume sliders
-            spawn_volume_row(
-                parent,
-                "Master Volume",
-                VolumeSetting::Master,
-                sound_settings.master_volume,
-                0,
-            );
-            spawn_volume_row(
-                parent,
-                "Music Volume",
-                VolumeSetting::Music,
-                sound_settings.music_volume,
-                1,
-            );
-            spawn_volume_row(
-                parent,
-                "SFX Volume",
-                VolumeSetting::Sfx,
-                sound_settings.sfx_volume,
-                2,
-            );
+            match page {
+                OptionsPage::Audio => {
+                    // Volume sliders (master/music/sfx, then SFX sub-buses)
+                    for (index, setting) in VolumeSetting::ALL.iter().enumerate() {
+                        let value = setting.value(sound_settings);
+                        spawn_volume_row(parent, setting.label(), *setting, value, index);
+                    }
+                }
+                OptionsPage::Accessibility => {
+                    for (index, setting) in AccessSetting::ALL.iter().enumerate() {
+                        spawn_access_row(parent, *setting, accessibility, index);
+                    }
+                }
+                OptionsPage::Online => spawn_server_row(parent, &settings.leaderboard_server),
+            }
 
             // Back instruction
             parent.spawn((
-                Text::new("[ESC] Back   [←/→] Adjust   [↑/↓] Select"),
+                Text::new("[ESC] Back   [←/→] Adjust   [↑/↓] Select   [TAB] Page"),
                 TextFont {
                     font_size: 16.0,
                     ..default()
                 },
                 TextColor(Color::srgb(0.5, 0.5, 0.5)),
                 Node {
-                    margin: UiRect::top(Val::Px(40.0)),
+                    margin: UiRect::top(Val::Px(20.0)),
                     ..default()
                 },
             ));
@@ -1016,8 +1547,7 @@ fn spawn_volume_row(
                 flex_direction: FlexDirection::Row,
                 align_items: AlignItems::Center,
                 justify_content: JustifyContent::SpaceBetween,
-                padding: UiRect::all(Val::Px(10.0)),
-                margin: UiRect::bottom(Val::Px(10.0)),
+                padding: UiRect::all(Val::Px(8.0)),
                 border: UiRect::all(Val::Px(2.0)),
                 ..default()
             },
@@ -1085,88 +1615,356 @@ fn spawn_volume_row(
         });
 }
 
+fn spawn_access_row(
+    parent: &mut ChildBuilder,
+    setting: AccessSetting,
+    accessibility: &AccessibilitySettings,
+    index: usize,
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(400.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.15, 0.8)),
+            BorderColor(if index == 0 {
+                Color::srgb(0.4, 0.6, 0.8)
+            } else {
+                Color::srgba(0.3, 0.3, 0.4, 0.5)
+            }),
+            AccessRow { setting },
+        ))
+        .with_children(|row| {
+            // Label
+            row.spawn((
+                Text::new(setting.label()),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+
+            // Value (+ bar for sliders)
+            row.spawn((Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                ..default()
+            },))
+                .with_children(|value_row| {
+                    if let Some(fill) = setting.bar_fill(accessibility) {
+                        value_row
+                            .spawn((
+                                Node {
+                                    width: Val::Px(100.0),
+                                    height: Val::Px(12.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                            ))
+                            .with_children(|bar_bg| {
+                                bar_bg.spawn((
+                                    AccessBar { setting },
+                                    Node {
+                                        width: Val::Percent(fill * 100.0),
+                                        height: Val::Percent(100.0),
+                                        ..default()
+                                    },
+                                    BackgroundColor(Color::srgb(0.3, 0.6, 0.9)),
+                                ));
+                            });
+                    }
+
+                    value_row.spawn((
+                        AccessValue { setting },
+                        Text::new(setting.value_text(accessibility)),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                    ));
+                });
+        });
+}
+
+/// Leaderboard server row: a folder or `http(s)://` address, typed in place
+fn spawn_server_row(parent: &mut ChildBuilder, server: &str) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(560.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.15, 0.8)),
+            BorderColor(Color::srgb(0.4, 0.6, 0.8)),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Text::new("Leaderboard Server"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+            row.spawn((
+                ServerValue,
+                Text::new(server_text(server)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+
+    parent.spawn((
+        Text::new("[ENTER] Type an http(s):// server or a shared folder; blank for local only"),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.5, 0.5, 0.5)),
+    ));
+}
+
 fn options_menu_input(
+    mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
     time: Res<Time>,
     mut state: ResMut<OptionsMenuState>,
-    mut sound_settings: ResMut<crate::systems::audio::SoundSettings>,
+    (mut sound_settings, mut accessibility, mut save_data): (
+        ResMut<crate::systems::audio::SoundSettings>,
+        ResMut<AccessibilitySettings>,
+        ResMut<SaveData>,
+    ),
+    mut typed: EventReader<KeyboardInput>,
     mut next_state: ResMut<NextState<GameState>>,
-    mut sliders: Query<(&VolumeSlider, &mut BorderColor), Without<VolumeLabel>>,
-    mut bars: Query<(&VolumeSlider, &mut Node), (Without<VolumeLabel>, Without<BorderColor>)>,
-    mut labels: Query<(&VolumeLabel, &mut Text)>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+    mut narration: EventWriter<NarrationEvent>,
+    roots: Query<Entity, With<OptionsMenuRoot>>,
+    mut rows: Query<
+        (Option<&VolumeSlider>, Option<&AccessRow>, &mut BorderColor),
+        Or<(With<VolumeSlider>, With<AccessRow>)>,
+    >,
+    mut bars: Query<
+        (Option<&VolumeSlider>, Option<&AccessBar>, &mut Node),
+        (
+            Or<(With<VolumeSlider>, With<AccessBar>)>,
+            Without<BorderColor>,
+        ),
+    >,
+    mut labels: Query<
+        (
+            Option<&VolumeLabel>,
+            Option<&AccessValue>,
+            Option<&ServerValue>,
+            &mut Text,
+        ),
+        Or<(With<VolumeLabel>, With<AccessValue>, With<ServerValue>)>,
+    >,
 ) {
     let dt = time.delta_secs();
     state.cooldown = (state.cooldown - dt).max(0.0);
 
-    // Navigation (up/down)
+    // Typing the leaderboard server: Enter keeps it, Esc drops the edit
+    if let Some(mut text) = state.editing.take() {
+        for event in typed.read() {
+            if !event.state.is_pressed() {
+                continue;
+            }
+            match &event.logical_key {
+                Key::Character(chars) if text.chars().count() < MAX_SERVER_LEN => {
+                    text.extend(chars.chars().filter(|c| !c.is_control()));
+                }
+                Key::Space if text.chars().count() < MAX_SERVER_LEN => text.push(' '),
+                Key::Backspace => {
+                    text.pop();
+                }
+                _ => {}
+            }
+        }
+
+        let shown = if keyboard.just_pressed(KeyCode::Enter) {
+            save_data.settings.leaderboard_server = text.trim().to_string();
+            let server = server_text(&save_data.settings.leaderboard_server);
+            narration.send(NarrationEvent::new(format!(
+                "Leaderboard server, {}",
+                server
+            )));
+            sound_events.send(PlaySoundEvent::new(SoundType::MenuSelect));
+            state.cooldown = 0.25;
+            server.to_string()
+        } else if keyboard.just_pressed(KeyCode::Escape) {
+            narration.send(NarrationEvent::new("Edit cancelled"));
+            state.cooldown = 0.25;
+            server_text(&save_data.settings.leaderboard_server).to_string()
+        } else {
+            let shown = format!("{}_", text);
+            state.editing = Some(text);
+            shown
+        };
+        for (_, _, server, mut label) in labels.iter_mut() {
+            if server.is_some() && **label != shown {
+                **label = shown.clone();
+            }
+        }
+        return;
+    }
+    typed.clear();
+
+    // Switch page (rebuilds the menu)
+    if keyboard.just_pressed(KeyCode::Tab) || joystick.left_bumper() || joystick.right_bumper() {
+        if state.cooldown <= 0.0 {
+            state.page = state.page.next();
+            state.selected = 0;
+            state.cooldown = 0.25;
+            for entity in roots.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            build_options_menu(
+                &mut commands,
+                state.page,
+                &sound_settings,
+                &accessibility,
+                &save_data.settings,
+            );
+            sound_events.send(PlaySoundEvent::new(SoundType::MenuSelect));
+            narration.send(NarrationEvent::new(format!(
+                "{} options. {}",
+                state.page.title(),
+                state
+                    .page
+                    .row_narration(0, &sound_settings, &accessibility, &save_data.settings)
+            )));
+        }
+        return;
+    }
+
+    let row_count = state.page.row_count();
+
+    // Navigation (up/down only - left/right adjusts)
     if state.cooldown <= 0.0 {
-        let nav = get_nav_input(&keyboard, &joystick);
+        let nav = get_vertical_nav_input(&keyboard, &joystick);
         if nav != 0 {
-            state.selected = (state.selected as i32 + nav).rem_euclid(3) as usize;
+            state.selected = (state.selected as i32 + nav).rem_euclid(row_count as i32) as usize;
             state.cooldown = 0.15;
+            narration.send(NarrationEvent::new(state.page.row_narration(
+                state.selected,
+                &sound_settings,
+                &accessibility,
+                &save_data.settings,
+            )));
+        }
+
+        // Start typing the leaderboard server (controllers can't type)
+        if state.page == OptionsPage::Online && keyboard.just_pressed(KeyCode::Enter) {
+            state.editing = Some(save_data.settings.leaderboard_server.clone());
+            narration.send(NarrationEvent::new(
+                "Type the leaderboard server, Enter to keep, Escape to cancel",
+            ));
+            return;
         }
 
-        // Adjust volume (left/right)
-        let adjust = if keyboard.pressed(KeyCode::ArrowLeft) || joystick.dpad_x < 0 {
-            -0.05
-        } else if keyboard.pressed(KeyCode::ArrowRight) || joystick.dpad_x > 0 {
-            0.05
+        // Adjust (left/right)
+        let dir = if keyboard.pressed(KeyCode::ArrowLeft) || joystick.dpad_x < 0 {
+            -1
+        } else if keyboard.pressed(KeyCode::ArrowRight)
+            || joystick.dpad_x > 0
+            || (is_confirm(&keyboard, &joystick) && state.page == OptionsPage::Accessibility)
+        {
+            1
         } else {
-            0.0
+            0
         };
 
-        if adjust != 0.0 {
-            let current_setting = match state.selected {
-                0 => VolumeSetting::Master,
-                1 => VolumeSetting::Music,
-                2 => VolumeSetting::Sfx,
-                _ => VolumeSetting::Master,
-            };
+        if dir != 0 {
+            match state.page {
+                OptionsPage::Audio => {
+                    let current_setting = VolumeSetting::ALL[state.selected.min(row_count - 1)];
 
-            // Update the setting
-            let new_value = match current_setting {
-                VolumeSetting::Master => {
-                    sound_settings.master_volume =
-                        (sound_settings.master_volume + adjust).clamp(0.0, 1.0);
-                    sound_settings.master_volume
-                }
-                VolumeSetting::Music => {
-                    sound_settings.music_volume =
-                        (sound_settings.music_volume + adjust).clamp(0.0, 1.0);
-                    sound_settings.music_volume
-                }
-                VolumeSetting::Sfx => {
-                    sound_settings.sfx_volume =
-                        (sound_settings.sfx_volume + adjust).clamp(0.0, 1.0);
-                    sound_settings.sfx_volume
-                }
-            };
+                    // Update the setting
+                    let value = current_setting.value_mut(&mut sound_settings);
+                    *value = (*value + dir as f32 * 0.05).clamp(0.0, 1.0);
+                    let new_value = *value;
+
+                    // Update bar width
+                    for (slider, _, mut node) in bars.iter_mut() {
+                        if slider.is_some_and(|s| s.setting == current_setting) {
+                            node.width = Val::Percent(new_value * 100.0);
+                        }
+                    }
+
+                    // Update label
+                    for (label, _, _, mut text) in labels.iter_mut() {
+                        if label.is_some_and(|l| l.setting == current_setting) {
+                            **text = format!("{}%", (new_value * 100.0) as i32);
+                        }
+                    }
 
-            // Update bar width
-            for (slider, mut node) in bars.iter_mut() {
-                if slider.setting == current_setting {
-                    node.width = Val::Percent(new_value * 100.0);
+                    narration.send(NarrationEvent::new(format!(
+                        "{}%",
+                        (new_value * 100.0) as i32
+                    )));
+
+                    // Let the player hear the bus they're adjusting
+                    if let Some(sound) = current_setting.preview_sound() {
+                        sound_events.send(PlaySoundEvent::new(sound).with_volume(0.7));
+                    }
+
+                    state.cooldown = 0.08;
                 }
-            }
+                OptionsPage::Accessibility => {
+                    let current_setting = AccessSetting::ALL[state.selected.min(row_count - 1)];
+                    current_setting.adjust(&mut accessibility, dir);
+
+                    if let Some(fill) = current_setting.bar_fill(&accessibility) {
+                        for (_, bar, mut node) in bars.iter_mut() {
+                            if bar.is_some_and(|b| b.setting == current_setting) {
+                                node.width = Val::Percent(fill * 100.0);
+                            }
+                        }
+                    }
+                    for (_, value, _, mut text) in labels.iter_mut() {
+                        if value.is_some_and(|v| v.setting == current_setting) {
+                            **text = current_setting.value_text(&accessibility);
+                        }
+                    }
 
-            // Update label
-            for (label, mut text) in labels.iter_mut() {
-                if label.setting == current_setting {
-                    **text = format!("{}%", (new_value * 100.0) as i32);
+                    sound_events.send(PlaySoundEvent::new(SoundType::MenuSelect));
+                    narration.send(NarrationEvent::new(
+                        current_setting.value_text(&accessibility),
+                    ));
+                    state.cooldown = current_setting.repeat_delay();
                 }
+                OptionsPage::Online => {}
             }
-
-            state.cooldown = 0.08;
         }
     }
 
     // Update selection highlighting
-    for (slider, mut border) in sliders.iter_mut() {
-        let is_selected = match slider.setting {
-            VolumeSetting::Master => state.selected == 0,
-            VolumeSetting::Music => state.selected == 1,
-            VolumeSetting::Sfx => state.selected == 2,
+    for (slider, row, mut border) in rows.iter_mut() {
+        let is_selected = match state.page {
+            OptionsPage::Audio => {
+                slider.is_some_and(|s| VolumeSetting::ALL.get(state.selected) == Some(&s.setting))
+            }
+            OptionsPage::Accessibility => {
+                row.is_some_and(|r| AccessSetting::ALL.get(state.selected) == Some(&r.setting))
+            }
+            OptionsPage::Online => false,
         };
         *border = if is_selected {
             BorderColor(Color::srgb(0.4, 0.6, 0.8))
@@ -1674,6 +2472,9 @@ fn difficulty_menu_input(
     mut selection: ResMut<MenuSelection>,
     mut difficulty: ResMut<Difficulty>,
     time: Res<Time>,
+    endless: Res<crate::core::EndlessMode>,
+    modules: Res<GameModules>,
+    active_module: Res<ActiveModule>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     selection.cooldown -= time.delta_secs();
@@ -1692,7 +2493,15 @@ fn difficulty_menu_input(
             difficulty.name(),
             difficulty.tagline()
         );
-        next_state.set(GameState::ShipSelect);
+        // A module that picks its difficulty first plays the star map next
+        let map = modules
+            .active(&active_module)
+            .and_then(|m| m.campaign_map(&active_module));
+        if map.is_some() && !endless.active {
+            next_state.set(GameState::StageSelect);
+        } else {
+            next_state.set(GameState::ShipSelect);
+        }
     }
 
     if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
@@ -2151,6 +2960,7 @@ fn stage_select_input(
 
             campaign.act = act;
             campaign.mission_index = mission_idx;
+            campaign.side_mission = None;
 
             info!(
                 "Selected Stage {} (Act {:?}, Mission {})",
@@ -2206,13 +3016,17 @@ fn spawn_ship_menu(
     difficulty: Res<Difficulty>,
     session: Res<GameSession>,
     save_data: Res<crate::core::SaveData>,
+    unlocks: Res<ShipUnlockRegistry>,
+    achievements: Res<AchievementRegistry>,
 ) {
     let ships = session.player_ships();
     let faction = session.player_faction;
-    let enemy = session.enemy_faction;
     let faction_color = faction.primary_color();
 
-    selection.index = 0;
+    // Coming back from the fitting screen keeps the ship highlighted
+    selection.index = session
+        .selected_ship_index
+        .min(ships.len().saturating_sub(1));
     selection.total = ships.len();
 
     // Calculate stat ranges for normalization
@@ -2276,6 +3090,7 @@ fn spawn_ship_menu(
                     spawn_ship_detail_panel(
                         content,
                         &ships[0],
+                        &ResistProfile::for_faction(session.player_faction),
                         faction_color,
                         max_speed,
                         max_damage,
@@ -2292,20 +3107,15 @@ fn spawn_ship_menu(
                         })
                         .with_children(|list| {
                             for (i, ship) in ships.iter().enumerate() {
-                                let is_unlocked = save_data.is_ship_unlocked(
-                                    ship.type_id,
-                                    ship.unlock_stage,
-                                    faction.short_name(),
-                                    enemy.short_name(),
-                                );
-                                spawn_ship_list_item(list, ship, i, is_unlocked, faction_color);
+                                let lock = unlocks.hint(ship.type_id, &save_data, &achievements);
+                                spawn_ship_list_item(list, ship, i, lock, faction_color);
                             }
                         });
                 });
 
             // Navigation hint
             parent.spawn((
-                Text::new("↑↓ Navigate • A/ENTER Select • B/ESC Back"),
+                Text::new("↑↓ Navigate • A/ENTER Select • Y/F Fitting • B/ESC Back"),
                 TextFont {
                     font_size: 12.0,
                     ..default()
@@ -2319,6 +3129,7 @@ fn spawn_ship_menu(
 fn spawn_ship_detail_panel(
     parent: &mut ChildBuilder,
     ship: &ShipDef,
+    resists: &ResistProfile,
     faction_color: Color,
     max_speed: f32,
     max_damage: f32,
@@ -2439,6 +3250,9 @@ fn spawn_ship_detail_panel(
                     );
                 });
 
+            // Resist profile (faction doctrine, same for every hull)
+            spawn_resist_table(panel, resists);
+
             // Divider
             panel.spawn((
                 Node {
@@ -2479,6 +3293,109 @@ fn spawn_ship_detail_panel(
         });
 }
 
+/// Spawn the shield/armor/hull resist table
+fn spawn_resist_table(parent: &mut ChildBuilder, resists: &ResistProfile) {
+    let cell = |text: String, color: Color, width: f32| {
+        (
+            Text::new(text),
+            TextFont {
+                font_size: 11.0,
+                ..default()
+            },
+            TextColor(color),
+            Node {
+                width: Val::Px(width),
+                ..default()
+            },
+        )
+    };
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(3.0),
+            ..default()
+        })
+        .with_children(|table| {
+            table
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn(cell("RESISTS".into(), Color::srgb(0.5, 0.5, 0.5), 80.0));
+                    for damage_type in DamageType::all() {
+                        row.spawn(cell(
+                            damage_type.short_name().into(),
+                            Color::srgb(0.5, 0.5, 0.5),
+                            50.0,
+                        ));
+                    }
+                });
+
+            for (label, layer, color) in [
+                ("SHIELD", DamageLayer::Shield, Color::srgb(0.3, 0.6, 0.9)),
+                ("ARMOR", DamageLayer::Armor, Color::srgb(0.9, 0.6, 0.3)),
+                ("HULL", DamageLayer::Hull, Color::srgb(0.6, 0.6, 0.6)),
+            ] {
+                table
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn(cell(label.into(), color, 80.0));
+                        for &resist in resists.layer(layer) {
+                            // Brighter the harder the resist, dim for holes
+                            let value = 0.4 + resist * 0.6;
+                            row.spawn(cell(
+                                format!("{:.0}%", resist * 100.0),
+                                Color::srgb(value, value, value),
+                                50.0,
+                            ));
+                        }
+                    });
+            }
+        });
+}
+
+/// Spoken resist table: the strongest and weakest resist of each layer
+fn resist_narration(resists: &ResistProfile) -> String {
+    [
+        ("Shield", DamageLayer::Shield),
+        ("armor", DamageLayer::Armor),
+        ("hull", DamageLayer::Hull),
+    ]
+    .iter()
+    .map(|&(label, layer)| {
+        let by_resist = |a: &DamageType, b: &DamageType| {
+            resists
+                .resist(layer, *a)
+                .total_cmp(&resists.resist(layer, *b))
+        };
+        let types = DamageType::all();
+        let weakest = types.iter().min_by(|a, b| by_resist(a, b)).unwrap();
+        let strongest = types.iter().max_by(|a, b| by_resist(a, b)).unwrap();
+        if by_resist(weakest, strongest).is_eq() {
+            return format!(
+                "{} {:.0}% against everything",
+                label,
+                resists.resist(layer, *weakest) * 100.0
+            );
+        }
+        format!(
+            "{} weakest to {:?} at {:.0}%, strongest against {:?} at {:.0}%",
+            label,
+            weakest,
+            resists.resist(layer, *weakest) * 100.0,
+            strongest,
+            resists.resist(layer, *strongest) * 100.0
+        )
+    })
+    .collect::<Vec<_>>()
+    .join(". ")
+}
+
 /// Spawn a stat bar with label and fill
 fn spawn_stat_bar(
     parent: &mut ChildBuilder,
@@ -2554,9 +3471,10 @@ fn spawn_ship_list_item(
     parent: &mut ChildBuilder,
     ship: &ShipDef,
     index: usize,
-    is_unlocked: bool,
+    lock: Option<String>,
     faction_color: Color,
 ) {
+    let is_unlocked = lock.is_none();
     let name_color = if is_unlocked {
         faction_color
     } else {
@@ -2645,9 +3563,9 @@ fn spawn_ship_list_item(
                         TextColor(Color::srgb(0.4, 0.6, 0.4)),
                     ));
                 });
-            } else {
+            } else if let Some(lock) = lock {
                 btn.spawn((
-                    Text::new(format!("Stage {}", ship.unlock_stage)),
+                    Text::new(lock),
                     TextFont {
                         font_size: 10.0,
                         ..default()
@@ -2711,12 +3629,6 @@ fn update_ship_detail_panel(
 
     let ship = &ships[selection.index];
 
-    // Calculate stat ranges for normalization
-    let max_speed = ships.iter().map(|s| s.speed).fold(0.0_f32, f32::max);
-    let max_damage = ships.iter().map(|s| s.damage).fold(0.0_f32, f32::max);
-    let max_health = ships.iter().map(|s| s.health).fold(0.0_f32, f32::max);
-    let max_fire_rate = ships.iter().map(|s| s.fire_rate).fold(0.0_f32, f32::max);
-
     // Update text fields
     for mut text in name_query.iter_mut() {
         **text = ship.name.to_string();
@@ -2733,14 +3645,69 @@ fn update_ship_detail_panel(
 
     // Update stat bars
     for (stat_fill, mut node) in stat_bars.iter_mut() {
-        let (value, max) = match stat_fill.0 {
-            StatType::Speed => (ship.speed, max_speed),
-            StatType::Damage => (ship.damage, max_damage),
-            StatType::Health => (ship.health, max_health),
-            StatType::FireRate => (ship.fire_rate, max_fire_rate),
-        };
-        let percent = (value / max * 100.0).clamp(0.0, 100.0);
-        node.width = Val::Percent(percent);
+        node.width = Val::Percent(ship_stat_percent(ship, ships, stat_fill.0));
+    }
+}
+
+/// A ship stat as a percentage of the best ship in the roster (stat bar fill)
+fn ship_stat_percent(ship: &ShipDef, ships: &[ShipDef], stat: StatType) -> f32 {
+    let value = |s: &ShipDef| match stat {
+        StatType::Speed => s.speed,
+        StatType::Damage => s.damage,
+        StatType::Health => s.health,
+        StatType::FireRate => s.fire_rate,
+    };
+    let max = ships.iter().map(value).fold(0.0_f32, f32::max);
+    if max <= 0.0 {
+        return 0.0;
+    }
+    (value(ship) / max * 100.0).clamp(0.0, 100.0)
+}
+
+/// Spoken summary of the detail panel: role, special and stat bars
+fn ship_detail_narration(ship: &ShipDef, ships: &[ShipDef]) -> String {
+    let stats = [
+        ("Speed", StatType::Speed),
+        ("Damage", StatType::Damage),
+        ("Health", StatType::Health),
+        ("Fire rate", StatType::FireRate),
+    ]
+    .iter()
+    .map(|(label, stat)| {
+        format!(
+            "{} {}%",
+            label,
+            ship_stat_percent(ship, ships, *stat).round()
+        )
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+    format!("{}. Special: {}. {}", ship.role, ship.special, stats)
+}
+
+/// Narrate the detail panel after the selected ship card
+fn narrate_ship_details(
+    selection: Res<MenuSelection>,
+    session: Res<GameSession>,
+    items: Query<(Entity, &MenuItem), With<ShipMenuRoot>>,
+    mut last: Local<Option<Entity>>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    let Some((entity, _)) = items.iter().find(|(_, item)| item.index == selection.index) else {
+        return;
+    };
+    if *last == Some(entity) {
+        return;
+    }
+    *last = Some(entity);
+
+    let ships = session.player_ships();
+    if let Some(ship) = ships.get(selection.index) {
+        narration.send(NarrationEvent::queued(format!(
+            "{}. {}",
+            ship_detail_narration(ship, ships),
+            resist_narration(&ResistProfile::for_faction(session.player_faction))
+        )));
     }
 }
 
@@ -2751,7 +3718,8 @@ fn ship_menu_input(
     mut session: ResMut<GameSession>,
     time: Res<Time>,
     mut transitions: EventWriter<TransitionEvent>,
-    save_data: Res<crate::core::SaveData>,
+    mut save_data: ResMut<crate::core::SaveData>,
+    unlocks: Res<ShipUnlockRegistry>,
 ) {
     selection.cooldown -= time.delta_secs();
 
@@ -2763,28 +3731,28 @@ fn ship_menu_input(
     }
 
     let ships = session.player_ships();
-    let faction = session.player_faction;
-    let enemy = session.enemy_faction;
 
-    if is_confirm(&keyboard, &joystick) && selection.index < ships.len() {
+    let fit = keyboard.just_pressed(KeyCode::KeyF) || joystick.y_button();
+    if (is_confirm(&keyboard, &joystick) || fit) && selection.index < ships.len() {
         let ship = &ships[selection.index];
-        let is_unlocked = save_data.is_ship_unlocked(
-            ship.type_id,
-            ship.unlock_stage,
-            faction.short_name(),
-            enemy.short_name(),
-        );
+        // Ships for sale are bought on confirm
+        let is_unlocked = unlocks.is_unlocked(ship.type_id, &save_data)
+            || match unlocks.purchase(ship.type_id, &mut save_data) {
+                Ok(()) => true,
+                Err(err) => {
+                    info!("Ship {} is locked: {}", ship.name, err);
+                    false
+                }
+            };
 
-        if is_unlocked {
+        if is_unlocked && fit {
+            session.selected_ship_index = selection.index;
+            transitions.send(TransitionEvent::to(GameState::Fitting));
+        } else if is_unlocked {
             session.selected_ship_index = selection.index;
             info!("Selected ship: {} ({})", ship.name, ship.class.name());
             // Slow transition into gameplay
             transitions.send(TransitionEvent::slow(GameState::Playing));
-        } else {
-            info!(
-                "Ship {} is locked - complete Stage {} to unlock",
-                ship.name, ship.unlock_stage
-            );
         }
     }
 
@@ -3933,6 +4901,8 @@ fn spawn_stage_complete(
     campaign: Res<CampaignState>,
     score: Res<ScoreSystem>,
     session: Res<GameSession>,
+    objectives: Res<MissionObjectives>,
+    unlocks: Res<ShipUnlockRegistry>,
 ) {
     let mission_name = campaign
         .current_mission()
@@ -3949,10 +4919,13 @@ fn spawn_stage_complete(
 
     // Check if any ships were unlocked by completing this stage
     let completed_stage = campaign.stage_number();
-    let ships = session.player_ships();
-    let unlocked_ships: Vec<&str> = ships
+    let faction = session.player_faction.short_name();
+    let enemy = session.enemy_faction.short_name();
+    let unlocked_ships: Vec<&str> = session
+        .player_ships()
         .iter()
-        .filter(|s| s.unlock_stage == completed_stage)
+        .filter_map(|s| unlocks.get(s.type_id))
+        .filter(|s| s.cleared_by(faction, enemy, completed_stage))
         .map(|s| s.name)
         .collect();
 
@@ -4023,8 +4996,44 @@ fn spawn_stage_complete(
                 TextColor(Color::srgb(0.6, 0.6, 0.6)),
             ));
 
-            // Bonus objective
-            if !bonus_text.is_empty() {
+            // Objectives and their rewards
+            if !objectives.is_empty() {
+                parent.spawn(Node {
+                    height: Val::Px(10.0),
+                    ..default()
+                });
+
+                for objective in &objectives.objectives {
+                    let color = match (objective.status, objective.optional) {
+                        (ObjectiveStatus::Complete, true) => Color::srgb(1.0, 0.85, 0.2), // Gold
+                        (ObjectiveStatus::Complete, false) => Color::srgb(0.3, 1.0, 0.3),
+                        _ => Color::srgb(0.5, 0.5, 0.5), // Gray
+                    };
+                    parent.spawn((
+                        Text::new(objective.display_line()),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(color),
+                    ));
+                }
+
+                if let Some(rewards) = objectives.rewards.filter(|r| r.completed > 0) {
+                    parent.spawn((
+                        Text::new(format!(
+                            "Objective bonus: +{} score • +{} SP",
+                            rewards.score, rewards.skill_points
+                        )),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.4, 0.8, 1.0)),
+                    ));
+                }
+            } else if !bonus_text.is_empty() {
+                // Bonus objective
                 parent.spawn(Node {
                     height: Val::Px(10.0),
                     ..default()
@@ -4110,14 +5119,35 @@ fn stage_complete_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
     mut campaign: ResMut<CampaignState>,
+    session: Res<GameSession>,
+    save_data: Res<SaveData>,
+    modules: Res<GameModules>,
+    active_module: Res<ActiveModule>,
     mut transitions: EventWriter<TransitionEvent>,
 ) {
     if keyboard.just_pressed(KeyCode::Space)
         || keyboard.just_pressed(KeyCode::Enter)
         || joystick.confirm()
     {
-        // Advance to next mission
-        if campaign.complete_mission() {
+        let map = modules
+            .active(&active_module)
+            .and_then(|m| m.campaign_map(&active_module));
+        if let Some(map) = map {
+            // Back to the star map to pick the next jump
+            campaign.complete_mission();
+            let finished = save_data
+                .faction_progress(
+                    session.player_faction.short_name(),
+                    session.enemy_faction.short_name(),
+                )
+                .is_some_and(|p| map.is_complete(p));
+            if finished {
+                transitions.send(TransitionEvent::slow(GameState::Victory));
+            } else {
+                transitions.send(TransitionEvent::to(GameState::StageSelect));
+            }
+        } else if campaign.complete_mission() {
+            // Advance to next mission
             // More missions available
             transitions.send(TransitionEvent::to(GameState::Playing));
         } else {
@@ -4829,6 +5859,44 @@ fn update_menu_selection<T: Component>(
     }
 }
 
+/// Narrate the selected menu item whenever the selection moves or a menu opens
+fn narrate_menu_selection(
+    selection: Res<MenuSelection>,
+    items: Query<(Entity, &MenuItem)>,
+    children: Query<&Children>,
+    texts: Query<&Text>,
+    mut last: Local<Option<Entity>>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    let Some((entity, _)) = items.iter().find(|(_, item)| item.index == selection.index) else {
+        return;
+    };
+    if *last == Some(entity) {
+        return;
+    }
+    // Moving within the same menu cuts off the previous item; a freshly
+    // opened menu waits for the screen name to finish
+    let interrupt = last.is_some_and(|prev| items.contains(prev));
+    *last = Some(entity);
+
+    let mut lines = Vec::new();
+    collect_text(entity, &children, &texts, &mut lines);
+    if lines.is_empty() {
+        return;
+    }
+    let text = format!(
+        "{}. {} of {}",
+        lines.join(", "),
+        selection.index + 1,
+        selection.total.max(selection.index + 1)
+    );
+    narration.send(if interrupt {
+        NarrationEvent::new(text)
+    } else {
+        NarrationEvent::queued(text)
+    });
+}
+
 fn get_nav_input(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> i32 {
     let mut nav = 0;
 
@@ -4868,6 +5936,34 @@ fn get_nav_input(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> i
     nav
 }
 
+/// Up/down only navigation, for menus where left/right adjusts a value
+fn get_vertical_nav_input(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> i32 {
+    let mut nav = 0;
+
+    if keyboard.just_pressed(KeyCode::ArrowUp) || keyboard.just_pressed(KeyCode::KeyW) {
+        nav = -1;
+    }
+    if keyboard.just_pressed(KeyCode::ArrowDown) || keyboard.just_pressed(KeyCode::KeyS) {
+        nav = 1;
+    }
+    if joystick.dpad_just_up() {
+        nav = -1;
+    }
+    if joystick.dpad_just_down() {
+        nav = 1;
+    }
+
+    // Analog stick (held state - menu cooldown prevents rapid repeat)
+    if joystick.left_y < -0.5 {
+        nav = -1;
+    }
+    if joystick.left_y > 0.5 {
+        nav = 1;
+    }
+
+    nav
+}
+
 fn is_confirm(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> bool {
     keyboard.just_pressed(KeyCode::Space)
         || keyboard.just_pressed(KeyCode::Enter)
@@ -4879,289 +5975,3 @@ fn despawn_menu<T: Component>(mut commands: Commands, query: Query<Entity, With<
         commands.entity(entity).despawn_recursive();
     }
 }
-
-// ============================================================================
-// Upgrade Shop
-// ============================================================================
-
-fn spawn_upgrade_shop(
-    mut commands: Commands,
-    mut selection: ResMut<MenuSelection>,
-    save_data: Res<SaveData>,
-) {
-    use crate::core::Upgrade;
-
-    let upgrades = Upgrade::all();
-    selection.index = 0;
-    selection.total = upgrades.len();
-
-    commands
-        .spawn((
-            UpgradeShopRoot,
-            Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                flex_direction: FlexDirection::Column,
-                padding: UiRect::all(Val::Px(40.0)),
-                ..default()
-            },
-            BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.95)),
-        ))
-        .with_children(|parent| {
-            // Header row with title and SP
-            parent
-                .spawn(Node {
-                    width: Val::Percent(100.0),
-                    justify_content: JustifyContent::SpaceBetween,
-                    align_items: AlignItems::Center,
-                    margin: UiRect::bottom(Val::Px(20.0)),
-                    ..default()
-                })
-                .with_children(|header| {
-                    // Title
-                    header.spawn((
-                        Text::new("SKILL UPGRADES"),
-                        TextFont {
-                            font_size: 36.0,
-                            ..default()
-                        },
-                        TextColor(Color::srgb(0.9, 0.7, 0.3)),
-                    ));
-
-                    // SP display
-                    header
-                        .spawn((
-                            Node {
-                                padding: UiRect::new(
-                                    Val::Px(15.0),
-                                    Val::Px(15.0),
-                                    Val::Px(8.0),
-                                    Val::Px(8.0),
-                                ),
-                                border: UiRect::all(Val::Px(1.0)),
-                                ..default()
-                            },
-                            BorderColor(Color::srgb(0.5, 0.3, 0.7)),
-                            BackgroundColor(Color::srgba(0.2, 0.1, 0.3, 0.8)),
-                        ))
-                        .with_children(|sp_box| {
-                            sp_box.spawn((
-                                Text::new(format!("SP: {}", save_data.skill_points)),
-                                TextFont {
-                                    font_size: 24.0,
-                                    ..default()
-                                },
-                                TextColor(Color::srgb(0.9, 0.7, 1.0)),
-                            ));
-                        });
-                });
-
-            // Upgrade list container
-            parent
-                .spawn((
-                    Node {
-                        width: Val::Percent(100.0),
-                        height: Val::Percent(70.0),
-                        flex_direction: FlexDirection::Column,
-                        overflow: Overflow::clip_y(),
-                        row_gap: Val::Px(8.0),
-                        ..default()
-                    },
-                    BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.5)),
-                ))
-                .with_children(|list| {
-                    for (i, &upgrade) in upgrades.iter().enumerate() {
-                        let purchased = save_data.has_upgrade(upgrade);
-                        let can_buy = save_data.can_purchase_upgrade(upgrade);
-                        let locked = if let Some(req) = upgrade.requires() {
-                            !save_data.has_upgrade(req)
-                        } else {
-                            false
-                        };
-
-                        let (name_color, status_text, status_color) = if purchased {
-                            (
-                                Color::srgb(0.5, 0.5, 0.5),
-                                "PURCHASED".to_string(),
-                                Color::srgb(0.4, 0.6, 0.4),
-                            )
-                        } else if locked {
-                            (
-                                Color::srgb(0.4, 0.4, 0.4),
-                                format!("Requires: {}", upgrade.requires().unwrap().name()),
-                                Color::srgb(0.6, 0.4, 0.4),
-                            )
-                        } else if can_buy {
-                            (
-                                Color::WHITE,
-                                format!("Cost: {} SP", upgrade.cost()),
-                                Color::srgb(0.4, 0.8, 0.4),
-                            )
-                        } else {
-                            (
-                                Color::srgb(0.7, 0.7, 0.7),
-                                format!("Cost: {} SP", upgrade.cost()),
-                                Color::srgb(0.8, 0.5, 0.5),
-                            )
-                        };
-
-                        list.spawn((
-                            UpgradeItem { upgrade },
-                            MenuItem { index: i },
-                            Node {
-                                width: Val::Percent(100.0),
-                                height: Val::Px(50.0),
-                                justify_content: JustifyContent::SpaceBetween,
-                                align_items: AlignItems::Center,
-                                padding: UiRect::horizontal(Val::Px(15.0)),
-                                border: UiRect::all(Val::Px(2.0)),
-                                ..default()
-                            },
-                            BackgroundColor(Color::srgba(0.1, 0.1, 0.15, 0.8)),
-                            BorderColor(Color::srgb(0.2, 0.2, 0.3)),
-                        ))
-                        .with_children(|row| {
-                            // Upgrade name
-                            row.spawn((
-                                Text::new(upgrade.name()),
-                                TextFont {
-                                    font_size: 20.0,
-                                    ..default()
-                                },
-                                TextColor(name_color),
-                            ));
-
-                            // Status/Cost
-                            row.spawn((
-                                Text::new(status_text),
-                                TextFont {
-                                    font_size: 18.0,
-                                    ..default()
-                                },
-                                TextColor(status_color),
-                            ));
-                        });
-                    }
-                });
-
-            // Description panel (bottom)
-            parent
-                .spawn((
-                    Node {
-                        width: Val::Percent(100.0),
-                        min_height: Val::Px(80.0),
-                        margin: UiRect::top(Val::Px(20.0)),
-                        padding: UiRect::all(Val::Px(15.0)),
-                        border: UiRect::all(Val::Px(1.0)),
-                        flex_direction: FlexDirection::Column,
-                        ..default()
-                    },
-                    BorderColor(Color::srgb(0.3, 0.3, 0.4)),
-                    BackgroundColor(Color::srgba(0.08, 0.08, 0.12, 0.9)),
-                    UpgradeDescriptionPanel,
-                ))
-                .with_children(|desc_panel| {
-                    let first_upgrade = upgrades.first().copied().unwrap_or(Upgrade::ShieldBoost1);
-                    desc_panel.spawn((
-                        Text::new(first_upgrade.description()),
-                        TextFont {
-                            font_size: 18.0,
-                            ..default()
-                        },
-                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
-                        UpgradeDescriptionText,
-                    ));
-                });
-
-            // Controls hint
-            parent.spawn((
-                Text::new("↑/↓ Navigate  •  Enter/Space Purchase  •  Esc Back"),
-                TextFont {
-                    font_size: 16.0,
-                    ..default()
-                },
-                TextColor(Color::srgb(0.5, 0.5, 0.5)),
-                Node {
-                    margin: UiRect::top(Val::Px(15.0)),
-                    ..default()
-                },
-            ));
-        });
-}
-
-#[derive(Component)]
-struct UpgradeDescriptionPanel;
-
-#[derive(Component)]
-struct UpgradeDescriptionText;
-
-fn update_upgrade_shop_selection(
-    selection: Res<MenuSelection>,
-    mut item_query: Query<
-        (
-            &MenuItem,
-            &UpgradeItem,
-            &mut BorderColor,
-            &mut BackgroundColor,
-        ),
-        With<UpgradeItem>,
-    >,
-    mut desc_query: Query<&mut Text, With<UpgradeDescriptionText>>,
-) {
-    for (item, upgrade_item, mut border, mut bg) in item_query.iter_mut() {
-        if item.index == selection.index {
-            border.0 = Color::srgb(0.7, 0.5, 0.9); // Purple highlight
-            bg.0 = Color::srgba(0.2, 0.15, 0.25, 0.95);
-
-            // Update description
-            if let Ok(mut desc_text) = desc_query.get_single_mut() {
-                desc_text.0 = upgrade_item.upgrade.description().to_string();
-            }
-        } else {
-            border.0 = Color::srgb(0.2, 0.2, 0.3);
-            bg.0 = Color::srgba(0.1, 0.1, 0.15, 0.8);
-        }
-    }
-}
-
-fn upgrade_shop_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
-    joystick: Res<JoystickState>,
-    mut selection: ResMut<MenuSelection>,
-    mut save_data: ResMut<SaveData>,
-    mut next_state: ResMut<NextState<GameState>>,
-    item_query: Query<(&MenuItem, &UpgradeItem)>,
-    mut commands: Commands,
-    sp_query: Query<Entity, With<UpgradeShopRoot>>,
-) {
-    // Navigation
-    let nav = get_nav_input(&keyboard, &joystick);
-    if nav != 0 && selection.total > 0 {
-        let new_index = (selection.index as i32 + nav).rem_euclid(selection.total as i32) as usize;
-        selection.index = new_index;
-    }
-
-    // Purchase
-    if is_confirm(&keyboard, &joystick) {
-        for (item, upgrade_item) in item_query.iter() {
-            if item.index == selection.index {
-                if save_data.purchase_upgrade(upgrade_item.upgrade) {
-                    info!("Purchased upgrade: {}", upgrade_item.upgrade.name());
-                    // Respawn the shop to update the UI
-                    for entity in sp_query.iter() {
-                        commands.entity(entity).despawn_recursive();
-                    }
-                    // Force re-spawn on next frame by temporarily going to a different state
-                    // Actually, let's just respawn inline
-                    next_state.set(GameState::UpgradeShop);
-                }
-                break;
-            }
-        }
-    }
-
-    // Back
-    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
-        next_state.set(GameState::MainMenu);
-    }
-}
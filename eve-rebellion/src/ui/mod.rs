@@ -2,17 +2,39 @@
 //!
 //! HUD, menus, and visual feedback.
 
+pub mod achievements;
 pub mod backgrounds;
+pub mod campaign_map;
 pub mod capacitor;
+pub mod challenges;
+pub mod debrief;
+pub mod fitting;
+pub mod ghost;
 pub mod hud;
+pub mod leaderboards;
 pub mod menu;
+pub mod narration;
+pub mod profiles;
+pub mod skill_tree;
 pub mod transitions;
+pub mod unlock_toast;
 
+pub use achievements::*;
 pub use backgrounds::*;
+pub use campaign_map::*;
 pub use capacitor::*;
+pub use challenges::*;
+pub use debrief::*;
+pub use fitting::*;
+pub use ghost::*;
 pub use hud::*;
+pub use leaderboards::*;
 pub use menu::*;
+pub use narration::*;
+pub use profiles::*;
+pub use skill_tree::*;
 pub use transitions::*;
+pub use unlock_toast::*;
 
 use bevy::prelude::*;
 
@@ -26,7 +48,18 @@ impl Plugin for UiPlugin {
             MenuPlugin,
             CapacitorWheelPlugin,
             BackgroundPlugin,
+            CampaignMapPlugin,
+            FittingPlugin,
             TransitionPlugin,
-        ));
+            NarrationPlugin,
+            ProfileSelectPlugin,
+            DebriefPlugin,
+            AchievementsBrowserPlugin,
+            LeaderboardScreenPlugin,
+            ChallengeScreenPlugin,
+            GhostHudPlugin,
+            UnlockToastPlugin,
+        ))
+        .add_plugins(SkillTreePlugin);
     }
 }
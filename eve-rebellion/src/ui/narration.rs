@@ -0,0 +1,306 @@
+//! Narration
+//!
+//! Text descriptions of the current screen, the selected menu item and
+//! HUD events for players using screen readers. Anything can send a
+//! `NarrationEvent`; with narration enabled the lines are written to stdout
+//! with a `[narration]` prefix so external readers can follow along, and in
+//! Speech mode they are also passed to the platform text-to-speech command.
+//!
+//! The speech command can be overridden with `EVE_REBELLION_TTS`, e.g.
+//! `EVE_REBELLION_TTS="espeak-ng -s 200"`. A `{text}` placeholder marks where
+//! the line goes; without one it is appended as the last argument.
+
+use bevy::prelude::*;
+
+use crate::core::accessibility::{AccessibilitySettings, NarrationMode};
+use crate::core::*;
+
+/// Environment variable overriding the text-to-speech command
+#[cfg(not(target_arch = "wasm32"))]
+pub const TTS_ENV_VAR: &str = "EVE_REBELLION_TTS";
+
+/// Narration plugin
+pub struct NarrationPlugin;
+
+impl Plugin for NarrationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Narrator>()
+            .add_systems(Update, (announce_screen, output_narration).chain());
+    }
+}
+
+/// Most lines kept waiting for speech; older ones are dropped so the
+/// voice never lags far behind the screen
+pub const MAX_PENDING_LINES: usize = 4;
+
+/// Speech output state
+#[derive(Resource, Default)]
+pub struct Narrator {
+    /// Lines waiting for the speech command to become free
+    pub pending: Vec<String>,
+    /// The TTS command failed to start; stay text-only until restart
+    pub speech_unavailable: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    speaking: Option<std::process::Child>,
+}
+
+impl Narrator {
+    /// Queue a line for speech. An interrupting line replaces the backlog.
+    pub fn enqueue(&mut self, text: &str, interrupt: bool) {
+        if interrupt {
+            self.pending.clear();
+        }
+        if self.pending.last().map(String::as_str) != Some(text) {
+            self.pending.push(text.to_string());
+        }
+        if self.pending.len() > MAX_PENDING_LINES {
+            let excess = self.pending.len() - MAX_PENDING_LINES;
+            self.pending.drain(..excess);
+        }
+    }
+
+    /// Everything queued, joined into one utterance
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn take_utterance(&mut self) -> Option<String> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        Some(self.pending.drain(..).collect::<Vec<_>>().join(". "))
+    }
+}
+
+/// Spoken name of each screen
+pub fn screen_name(state: GameState) -> Option<&'static str> {
+    match state {
+        GameState::Loading => None,
+        GameState::ProfileSelect => Some("Select pilot"),
+        GameState::MainMenu => Some("Main menu"),
+        GameState::Options => Some("Options"),
+        GameState::Achievements => Some("Achievements"),
+        GameState::Leaderboards => Some("Leaderboards"),
+        GameState::ModuleSelect => Some("Select campaign"),
+        GameState::Challenges => Some("Challenges"),
+        GameState::FactionSelect => Some("Select faction"),
+        GameState::StageSelect => Some("Select stage"),
+        GameState::DifficultySelect => Some("Select difficulty"),
+        GameState::ShipSelect => Some("Select ship"),
+        GameState::Fitting => Some("Ship fitting"),
+        GameState::Playing => Some("Playing"),
+        GameState::SkillTree => Some("Skill tree"),
+        GameState::BossIntro => Some("Boss approaching"),
+        GameState::BossFight => Some("Boss fight"),
+        GameState::StageComplete => Some("Stage complete"),
+        GameState::GameOver => Some("Game over"),
+        GameState::Victory => Some("Victory"),
+        GameState::Paused => Some("Paused"),
+    }
+}
+
+/// Gather the visible text under an entity, depth first, skipping blanks
+pub fn collect_text(
+    entity: Entity,
+    children: &Query<&Children>,
+    texts: &Query<&Text>,
+    out: &mut Vec<String>,
+) {
+    if let Ok(text) = texts.get(entity) {
+        let line = text.0.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !line.is_empty() {
+            out.push(line);
+        }
+    }
+    if let Ok(kids) = children.get(entity) {
+        for &child in kids.iter() {
+            collect_text(child, children, texts, out);
+        }
+    }
+}
+
+/// Build the program and arguments for speaking `text`.
+/// `template` is a whitespace-separated command line; `{text}` is replaced
+/// with the line, otherwise the line is appended as the final argument.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn tts_command(template: &str, text: &str) -> Option<(String, Vec<String>)> {
+    let mut parts = template.split_whitespace();
+    let program = parts.next()?.to_string();
+    let mut args: Vec<String> = Vec::new();
+    let mut placed = false;
+    for part in parts {
+        if part.contains("{text}") {
+            args.push(part.replace("{text}", text));
+            placed = true;
+        } else {
+            args.push(part.to_string());
+        }
+    }
+    if !placed {
+        args.push(text.to_string());
+    }
+    Some((program, args))
+}
+
+/// Default text-to-speech command for this platform
+#[cfg(not(target_arch = "wasm32"))]
+fn default_tts_command(text: &str) -> (String, Vec<String>) {
+    if cfg!(target_os = "macos") {
+        ("say".into(), vec![text.into()])
+    } else if cfg!(target_os = "windows") {
+        let script = format!(
+            "Add-Type -AssemblyName System.Speech; \
+             (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak('{}')",
+            text.replace('\'', "''")
+        );
+        (
+            "powershell".into(),
+            vec!["-NoProfile".into(), "-Command".into(), script],
+        )
+    } else {
+        ("espeak".into(), vec![text.into()])
+    }
+}
+
+/// Announce the screen name whenever the game state changes
+fn announce_screen(state: Res<State<GameState>>, mut narration: EventWriter<NarrationEvent>) {
+    if !state.is_changed() {
+        return;
+    }
+    if let Some(name) = screen_name(*state.get()) {
+        narration.send(NarrationEvent::new(name));
+    }
+}
+
+/// Write narration to stdout and, in Speech mode, feed the speech queue
+fn output_narration(
+    mut events: EventReader<NarrationEvent>,
+    access: Res<AccessibilitySettings>,
+    mut narrator: ResMut<Narrator>,
+) {
+    if access.narration == NarrationMode::Off {
+        events.clear();
+        narrator.pending.clear();
+        return;
+    }
+
+    let speech = access.narration == NarrationMode::Speech && !narrator.speech_unavailable;
+    let mut interrupt = false;
+    for event in events.read() {
+        if event.text.is_empty() {
+            continue;
+        }
+        println!("[narration] {}", event.text);
+        if speech {
+            interrupt |= event.interrupt;
+            narrator.enqueue(&event.text, event.interrupt);
+        }
+    }
+
+    if speech {
+        speak_pending(&mut narrator, interrupt);
+    }
+}
+
+/// Start speaking the queued lines once the previous utterance has finished
+#[cfg(not(target_arch = "wasm32"))]
+fn speak_pending(narrator: &mut Narrator, interrupt: bool) {
+    if let Some(child) = narrator.speaking.as_mut() {
+        match child.try_wait() {
+            Ok(None) if interrupt => {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+            // Still talking; queued lines wait their turn
+            Ok(None) => return,
+            _ => {}
+        }
+        narrator.speaking = None;
+    }
+
+    let Some(line) = narrator.take_utterance() else {
+        return;
+    };
+
+    let (program, args) = std::env::var(TTS_ENV_VAR)
+        .ok()
+        .and_then(|template| tts_command(&template, &line))
+        .unwrap_or_else(|| default_tts_command(&line));
+
+    match std::process::Command::new(&program)
+        .args(&args)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+    {
+        Ok(child) => narrator.speaking = Some(child),
+        Err(e) => {
+            warn!(
+                "Narration: could not start '{}' ({}); set {} to a TTS command. Falling back to text.",
+                program, e, TTS_ENV_VAR
+            );
+            narrator.speech_unavailable = true;
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn speak_pending(narrator: &mut Narrator, _interrupt: bool) {
+    narrator.pending.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tts_command_appends_text_by_default() {
+        let (program, args) = tts_command("espeak-ng -s 200", "Main menu").unwrap();
+        assert_eq!(program, "espeak-ng");
+        assert_eq!(args, vec!["-s", "200", "Main menu"]);
+    }
+
+    #[test]
+    fn tts_command_fills_placeholder() {
+        let (program, args) = tts_command("say -v Alex {text} -r 250", "Play").unwrap();
+        assert_eq!(program, "say");
+        assert_eq!(args, vec!["-v", "Alex", "Play", "-r", "250"]);
+    }
+
+    #[test]
+    fn empty_tts_template_is_rejected() {
+        assert!(tts_command("   ", "Play").is_none());
+    }
+
+    #[test]
+    fn interrupting_line_replaces_backlog() {
+        let mut narrator = Narrator::default();
+        narrator.enqueue("Main menu", true);
+        narrator.enqueue("Play. 1 of 4", false);
+        assert_eq!(
+            narrator.take_utterance().as_deref(),
+            Some("Main menu. Play. 1 of 4")
+        );
+
+        narrator.enqueue("Play. 1 of 4", false);
+        narrator.enqueue("Upgrades. 2 of 4", true);
+        assert_eq!(
+            narrator.take_utterance().as_deref(),
+            Some("Upgrades. 2 of 4")
+        );
+        assert!(narrator.take_utterance().is_none());
+    }
+
+    #[test]
+    fn backlog_is_bounded() {
+        let mut narrator = Narrator::default();
+        for wave in 0..10 {
+            narrator.enqueue(&format!("Wave {}", wave), false);
+        }
+        assert_eq!(narrator.pending.len(), MAX_PENDING_LINES);
+        assert_eq!(narrator.pending[0], "Wave 6");
+    }
+
+    #[test]
+    fn menus_have_screen_names() {
+        assert_eq!(screen_name(GameState::Loading), None);
+        assert_eq!(screen_name(GameState::ShipSelect), Some("Select ship"));
+    }
+}
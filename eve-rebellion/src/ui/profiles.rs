@@ -0,0 +1,397 @@
+//! Pilot Select Screen
+//!
+//! Shown after loading and from the main menu's SWITCH PILOT. Lists the
+//! pilot profiles with the last one flown selected, and creates, renames,
+//! copies and deletes them. The last row toggles shared settings. Names are
+//! typed on the keyboard; a controller gets "Pilot N" names. The screen is
+//! rebuilt on every change.
+
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use crate::core::*;
+use crate::systems::JoystickState;
+
+use super::TransitionEvent;
+
+/// Pilot select plugin
+pub struct ProfileSelectPlugin;
+
+impl Plugin for ProfileSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProfileScreen>()
+            .add_systems(OnEnter(GameState::ProfileSelect), spawn_profile_screen)
+            .add_systems(
+                Update,
+                profile_screen_input.run_if(in_state(GameState::ProfileSelect)),
+            )
+            .add_systems(OnExit(GameState::ProfileSelect), despawn_profile_screen);
+    }
+}
+
+/// Root of the pilot select screen
+#[derive(Component)]
+pub struct ProfileScreenRoot;
+
+/// What the pilot screen is doing
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ProfileMode {
+    #[default]
+    Browse,
+    /// Typing a name for a new pilot (None) or a rename
+    Naming {
+        target: Option<String>,
+        text: String,
+    },
+    /// Waiting for a second press to delete
+    ConfirmDelete(String),
+}
+
+/// Pilot screen cursor and mode
+#[derive(Resource, Default, Debug)]
+pub struct ProfileScreen {
+    pub cursor: usize,
+    pub mode: ProfileMode,
+}
+
+/// Rows: each pilot, then NEW PILOT, then the shared settings toggle
+fn row_count(profiles: &PilotProfiles) -> usize {
+    profiles.profiles.len() + 2
+}
+
+fn spawn_profile_screen(
+    mut commands: Commands,
+    mut screen: ResMut<ProfileScreen>,
+    profiles: Res<PilotProfiles>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    screen.mode = ProfileMode::Browse;
+    screen.cursor = profiles
+        .active
+        .as_deref()
+        .and_then(|id| profiles.profiles.iter().position(|p| p.id == id))
+        .unwrap_or(0);
+    build_profile_screen(&mut commands, &screen, &profiles);
+    narration.send(NarrationEvent::queued(row_narration(&screen, &profiles)));
+}
+
+fn build_profile_screen(commands: &mut Commands, screen: &ProfileScreen, profiles: &PilotProfiles) {
+    let accent = Color::srgb(0.8, 0.5, 0.2);
+
+    commands
+        .spawn((
+            ProfileScreenRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("SELECT PILOT"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(accent),
+            ));
+            parent.spawn(Node {
+                height: Val::Px(20.0),
+                ..default()
+            });
+
+            for (index, profile) in profiles.profiles.iter().enumerate() {
+                let active = profiles.active.as_deref() == Some(profile.id.as_str());
+                let label = match &screen.mode {
+                    ProfileMode::Naming {
+                        target: Some(id),
+                        text,
+                    } if *id == profile.id => format!("{}_", text),
+                    ProfileMode::ConfirmDelete(id) if *id == profile.id => {
+                        format!("DELETE {}? (press again)", profile.name)
+                    }
+                    _ if active => format!("{}  (last flown)", profile.name),
+                    _ => profile.name.clone(),
+                };
+                spawn_row(parent, label, index == screen.cursor, accent);
+            }
+
+            let new_label = match &screen.mode {
+                ProfileMode::Naming { target: None, text } => format!("NEW PILOT: {}_", text),
+                _ => "+ NEW PILOT".to_string(),
+            };
+            spawn_row(
+                parent,
+                new_label,
+                screen.cursor == profiles.profiles.len(),
+                accent,
+            );
+            spawn_row(
+                parent,
+                format!(
+                    "SHARED SETTINGS: {}",
+                    if profiles.share_settings { "ON" } else { "OFF" }
+                ),
+                screen.cursor == profiles.profiles.len() + 1,
+                accent,
+            );
+
+            parent.spawn(Node {
+                height: Val::Px(20.0),
+                ..default()
+            });
+            let hint = match screen.mode {
+                ProfileMode::Naming { .. } => "Type a name • ENTER Confirm • ESC Cancel",
+                ProfileMode::ConfirmDelete(_) => "DEL/X Delete • ESC/B Cancel",
+                ProfileMode::Browse => {
+                    "↑↓ Navigate • A/ENTER Fly • Y/R Rename • C Copy • X/DEL Delete • ESC Back"
+                }
+            };
+            parent.spawn((
+                Text::new(hint),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+            ));
+        });
+}
+
+fn spawn_row(parent: &mut ChildBuilder, label: String, selected: bool, accent: Color) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(420.0),
+                padding: UiRect::axes(Val::Px(20.0), Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BorderColor(if selected {
+                accent
+            } else {
+                Color::srgb(0.2, 0.2, 0.2)
+            }),
+            BackgroundColor(if selected {
+                Color::srgba(0.2, 0.12, 0.05, 0.9)
+            } else {
+                Color::srgba(0.05, 0.05, 0.05, 0.8)
+            }),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(if selected {
+                    Color::WHITE
+                } else {
+                    Color::srgb(0.6, 0.6, 0.6)
+                }),
+            ));
+        });
+}
+
+/// Spoken description of the selected row
+fn row_narration(screen: &ProfileScreen, profiles: &PilotProfiles) -> String {
+    match &screen.mode {
+        ProfileMode::Naming { text, .. } => format!("Pilot name: {}", text),
+        ProfileMode::ConfirmDelete(id) => format!(
+            "Delete {}? Press delete again to confirm",
+            profiles.get(id).map(|p| p.name.as_str()).unwrap_or("pilot")
+        ),
+        ProfileMode::Browse => match profiles.profiles.get(screen.cursor) {
+            Some(profile) => profile.name.clone(),
+            None if screen.cursor == profiles.profiles.len() => "New pilot".to_string(),
+            None => format!(
+                "Shared settings {}",
+                if profiles.share_settings { "on" } else { "off" }
+            ),
+        },
+    }
+}
+
+fn profile_screen_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut typed: EventReader<KeyboardInput>,
+    mut screen: ResMut<ProfileScreen>,
+    mut profiles: ResMut<PilotProfiles>,
+    mut save_data: ResMut<SaveData>,
+    roots: Query<Entity, With<ProfileScreenRoot>>,
+    mut narration: EventWriter<NarrationEvent>,
+    mut transitions: EventWriter<TransitionEvent>,
+) {
+    let confirm = keyboard.just_pressed(KeyCode::Enter) || joystick.confirm();
+    let cancel = keyboard.just_pressed(KeyCode::Escape) || joystick.back();
+    let mut changed = false;
+
+    match screen.mode.clone() {
+        ProfileMode::Naming { target, mut text } => {
+            for event in typed.read() {
+                if !event.state.is_pressed() {
+                    continue;
+                }
+                match &event.logical_key {
+                    Key::Character(chars) if text.chars().count() < MAX_PILOT_NAME => {
+                        text.extend(chars.chars().filter(|c| !c.is_control()));
+                    }
+                    Key::Space if text.chars().count() < MAX_PILOT_NAME => text.push(' '),
+                    Key::Backspace => {
+                        text.pop();
+                    }
+                    _ => {}
+                }
+            }
+
+            if confirm {
+                match &target {
+                    Some(id) => {
+                        profiles.rename(id, &text);
+                    }
+                    None => {
+                        profiles.create(&text);
+                        screen.cursor = profiles.profiles.len() - 1;
+                    }
+                }
+                profiles.save();
+                screen.mode = ProfileMode::Browse;
+            } else if cancel {
+                screen.mode = ProfileMode::Browse;
+            } else {
+                let naming = ProfileMode::Naming { target, text };
+                if screen.mode == naming {
+                    return;
+                }
+                screen.mode = naming;
+            }
+            changed = true;
+        }
+        ProfileMode::ConfirmDelete(id) => {
+            typed.clear();
+            if keyboard.just_pressed(KeyCode::Delete)
+                || keyboard.just_pressed(KeyCode::KeyX)
+                || joystick.x_button()
+            {
+                profiles.remove(&id);
+                delete_profile(&id);
+                profiles.save();
+                if profiles.active.is_none() {
+                    *save_data = SaveData::default();
+                }
+                screen.cursor = screen.cursor.min(row_count(&profiles) - 1);
+                screen.mode = ProfileMode::Browse;
+                changed = true;
+            } else if cancel || confirm {
+                screen.mode = ProfileMode::Browse;
+                changed = true;
+            }
+        }
+        ProfileMode::Browse => {
+            typed.clear();
+            let rows = row_count(&profiles);
+            let selected = profiles.profiles.get(screen.cursor).cloned();
+
+            if keyboard.just_pressed(KeyCode::ArrowUp)
+                || keyboard.just_pressed(KeyCode::KeyW)
+                || joystick.dpad_just_up()
+            {
+                screen.cursor = (screen.cursor + rows - 1) % rows;
+                changed = true;
+            } else if keyboard.just_pressed(KeyCode::ArrowDown)
+                || keyboard.just_pressed(KeyCode::KeyS)
+                || joystick.dpad_just_down()
+            {
+                screen.cursor = (screen.cursor + 1) % rows;
+                changed = true;
+            } else if confirm {
+                match selected {
+                    Some(profile) => {
+                        *save_data = profiles.load_save(&profile.id);
+                        profiles.active = Some(profile.id.clone());
+                        profiles.save();
+                        info!("Flying as {}", profile.name);
+                        transitions.send(TransitionEvent::to(GameState::MainMenu));
+                        return;
+                    }
+                    None if screen.cursor == profiles.profiles.len() => {
+                        // Controllers can't type, so start them on the default name
+                        if joystick.confirm() {
+                            profiles.create("");
+                            profiles.save();
+                            screen.cursor = profiles.profiles.len() - 1;
+                        } else {
+                            screen.mode = ProfileMode::Naming {
+                                target: None,
+                                text: String::new(),
+                            };
+                        }
+                    }
+                    None => {
+                        profiles.share_settings = !profiles.share_settings;
+                        if profiles.share_settings {
+                            profiles.shared_settings = save_data.settings.clone();
+                        }
+                        profiles.save();
+                    }
+                }
+                changed = true;
+            } else if let Some(profile) = selected {
+                if keyboard.just_pressed(KeyCode::KeyR) || joystick.y_button() {
+                    screen.mode = ProfileMode::Naming {
+                        target: Some(profile.id.clone()),
+                        text: profile.name.clone(),
+                    };
+                    changed = true;
+                } else if keyboard.just_pressed(KeyCode::KeyC) {
+                    if let Some(copy) = profiles.duplicate(&profile.id) {
+                        // The active pilot's latest data is in memory
+                        if profiles.active.as_deref() == Some(profile.id.as_str()) {
+                            save_profile(&copy, &save_data);
+                        } else {
+                            copy_profile(&profile.id, &copy);
+                        }
+                        profiles.save();
+                        screen.cursor = profiles.profiles.len() - 1;
+                        changed = true;
+                    }
+                } else if keyboard.just_pressed(KeyCode::Delete)
+                    || keyboard.just_pressed(KeyCode::KeyX)
+                    || joystick.x_button()
+                {
+                    screen.mode = ProfileMode::ConfirmDelete(profile.id.clone());
+                    changed = true;
+                }
+            }
+
+            // Back to the menu only with a pilot loaded
+            if cancel && profiles.active_profile().is_some() {
+                transitions.send(TransitionEvent::to(GameState::MainMenu));
+            }
+        }
+    }
+
+    if changed {
+        for root in roots.iter() {
+            commands.entity(root).despawn_recursive();
+        }
+        build_profile_screen(&mut commands, &screen, &profiles);
+        narration.send(NarrationEvent::new(row_narration(&screen, &profiles)));
+    }
+}
+
+fn despawn_profile_screen(mut commands: Commands, roots: Query<Entity, With<ProfileScreenRoot>>) {
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+}
@@ -0,0 +1,536 @@
+//! Skill Tree Screen
+//!
+//! Reached from the main menu. Shows every skill as a node in its
+//! category's column, linked to the skill it needs, with the trained rank
+//! and the pilot's Skill Points. Left/right picks a category, up/down a
+//! skill; confirm trains the next rank and R (or X) twice refunds every
+//! trained rank.
+
+use crate::core::*;
+use crate::systems::JoystickState;
+use bevy::prelude::*;
+
+use super::TransitionEvent;
+
+/// Skill tree screen plugin
+pub struct SkillTreePlugin;
+
+impl Plugin for SkillTreePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SkillTreeCursor>()
+            .add_systems(OnEnter(GameState::SkillTree), spawn_skill_tree)
+            .add_systems(
+                Update,
+                skill_tree_input.run_if(in_state(GameState::SkillTree)),
+            )
+            .add_systems(OnExit(GameState::SkillTree), despawn_skill_tree);
+    }
+}
+
+/// Root of the skill tree screen
+#[derive(Component)]
+pub struct SkillTreeRoot;
+
+/// Selected node, and whether a respec waits for confirmation
+#[derive(Resource, Default)]
+pub struct SkillTreeCursor {
+    pub column: usize,
+    pub row: usize,
+    pub confirm_respec: bool,
+}
+
+impl SkillTreeCursor {
+    pub fn skill(&self) -> Option<Skill> {
+        SkillCategory::all()[self.column].skills().nth(self.row)
+    }
+}
+
+const ACCENT: Color = Color::srgb(0.9, 0.7, 0.3);
+const TRAINED: Color = Color::srgb(0.4, 0.8, 0.5);
+const LOCKED: Color = Color::srgb(0.35, 0.35, 0.4);
+
+/// Vertical distance between nodes in a column
+const ROW_HEIGHT: f32 = 58.0;
+const NODE_HEIGHT: f32 = 46.0;
+/// Space above the first node for the category name
+const COLUMN_HEADER: f32 = 22.0;
+/// Space left of the nodes for prerequisite links
+const LINK_GUTTER: f32 = 14.0;
+
+fn spawn_skill_tree(
+    mut commands: Commands,
+    mut cursor: ResMut<SkillTreeCursor>,
+    save_data: Res<SaveData>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    cursor.confirm_respec = false;
+    build_skill_tree(&mut commands, &save_data, &cursor);
+    narration.send(NarrationEvent::queued(skill_narration(&save_data, &cursor)));
+}
+
+fn build_skill_tree(commands: &mut Commands, save_data: &SaveData, cursor: &SkillTreeCursor) {
+    let sheet = &save_data.skills;
+    let selected = cursor.skill();
+
+    commands
+        .spawn((
+            SkillTreeRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(20.0)),
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.95)),
+        ))
+        .with_children(|parent| {
+            // Title and SP
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|header| {
+                    header.spawn((
+                        Text::new("SKILL TREE"),
+                        TextFont {
+                            font_size: 32.0,
+                            ..default()
+                        },
+                        TextColor(ACCENT),
+                    ));
+                    header
+                        .spawn((
+                            Node {
+                                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                                border: UiRect::all(Val::Px(1.0)),
+                                ..default()
+                            },
+                            BorderColor(Color::srgb(0.5, 0.3, 0.7)),
+                            BackgroundColor(Color::srgba(0.2, 0.1, 0.3, 0.8)),
+                        ))
+                        .with_children(|sp_box| {
+                            sp_box.spawn((
+                                Text::new(format!("SP: {}", save_data.skill_points)),
+                                TextFont {
+                                    font_size: 20.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.9, 0.7, 1.0)),
+                            ));
+                        });
+                });
+
+            // Node graph, one column per category
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(COLUMN_HEADER + ROW_HEIGHT * 7.0),
+                    ..default()
+                })
+                .with_children(|graph| {
+                    for category in SkillCategory::all() {
+                        spawn_column(graph, category, sheet, selected);
+                    }
+                });
+
+            if let Some(skill) = selected {
+                spawn_detail_panel(parent, skill, save_data, cursor);
+            }
+
+            parent.spawn((
+                Text::new(format!(
+                    "←/→ Category  •  ↑/↓ Skill  •  Enter Train  •  R Respec ({} SP)  •  Esc Back",
+                    sheet.spent
+                )),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            ));
+        });
+}
+
+fn spawn_column(
+    graph: &mut ChildBuilder,
+    category: SkillCategory,
+    sheet: &SkillSheet,
+    selected: Option<Skill>,
+) {
+    let skills: Vec<Skill> = category.skills().collect();
+    let center = |row: usize| COLUMN_HEADER + row as f32 * ROW_HEIGHT + NODE_HEIGHT / 2.0;
+
+    graph
+        .spawn(Node {
+            width: Val::Percent(100.0 / 6.0),
+            height: Val::Percent(100.0),
+            ..default()
+        })
+        .with_children(|column| {
+            column.spawn((
+                Text::new(category.name().to_uppercase()),
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(ACCENT),
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(LINK_GUTTER),
+                    top: Val::Px(0.0),
+                    ..default()
+                },
+            ));
+
+            // Links: a trunk in the gutter per prerequisite, with stubs into
+            // both nodes; deeper prerequisites get their own lane
+            for (row, skill) in skills.iter().enumerate() {
+                let Some((required, rank)) = skill.def().requires else {
+                    continue;
+                };
+                let Some(parent_row) = skills.iter().position(|&s| s == required) else {
+                    continue;
+                };
+                let lane = 3.0 + 5.0 * requirement_depth(required) as f32;
+                let color = if sheet.rank(required) >= rank {
+                    TRAINED
+                } else {
+                    LOCKED
+                };
+                let top = center(parent_row);
+                let bottom = center(row);
+                for (left, top, width, height) in [
+                    (lane, top, 2.0, bottom - top + 2.0),
+                    (lane, top, LINK_GUTTER - lane, 2.0),
+                    (lane, bottom, LINK_GUTTER - lane, 2.0),
+                ] {
+                    column.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(left),
+                            top: Val::Px(top),
+                            width: Val::Px(width),
+                            height: Val::Px(height),
+                            ..default()
+                        },
+                        BackgroundColor(color),
+                    ));
+                }
+            }
+
+            for (row, &skill) in skills.iter().enumerate() {
+                spawn_node(
+                    column,
+                    skill,
+                    sheet,
+                    Some(skill) == selected,
+                    COLUMN_HEADER + row as f32 * ROW_HEIGHT,
+                );
+            }
+        });
+}
+
+/// How many prerequisites deep a skill is
+fn requirement_depth(skill: Skill) -> usize {
+    skill
+        .def()
+        .requires
+        .map_or(0, |(required, _)| 1 + requirement_depth(required))
+}
+
+fn spawn_node(
+    column: &mut ChildBuilder,
+    skill: Skill,
+    sheet: &SkillSheet,
+    selected: bool,
+    top: f32,
+) {
+    let def = skill.def();
+    let rank = sheet.rank(skill);
+    let trainable = sheet.next_rank_cost(skill).is_ok();
+    let color = if rank >= def.max_rank {
+        TRAINED
+    } else if trainable {
+        Color::WHITE
+    } else {
+        LOCKED
+    };
+    let border = if selected {
+        ACCENT
+    } else {
+        color.with_alpha(0.5)
+    };
+    let pips: String = (0..def.max_rank)
+        .map(|i| if i < rank { '■' } else { '□' })
+        .collect();
+    let tag = match def.scope {
+        SkillScope::All => String::new(),
+        scope => format!(" {}", scope.name()),
+    };
+
+    column
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(LINK_GUTTER),
+                right: Val::Px(4.0),
+                top: Val::Px(top),
+                height: Val::Px(NODE_HEIGHT),
+                padding: UiRect::axes(Val::Px(5.0), Val::Px(3.0)),
+                border: UiRect::all(Val::Px(if selected { 2.0 } else { 1.0 })),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::SpaceBetween,
+                ..default()
+            },
+            BorderColor(border),
+            BackgroundColor(if selected {
+                Color::srgba(0.2, 0.15, 0.25, 0.95)
+            } else {
+                Color::srgba(0.08, 0.08, 0.12, 0.9)
+            }),
+        ))
+        .with_children(|node| {
+            node.spawn((
+                Text::new(def.name),
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(color),
+            ));
+            node.spawn((
+                Text::new(format!("{}{}", pips, tag)),
+                TextFont {
+                    font_size: 9.0,
+                    ..default()
+                },
+                TextColor(color.with_alpha(0.8)),
+            ));
+        });
+}
+
+fn spawn_detail_panel(
+    parent: &mut ChildBuilder,
+    skill: Skill,
+    save_data: &SaveData,
+    cursor: &SkillTreeCursor,
+) {
+    let def = skill.def();
+    let sheet = &save_data.skills;
+    let rank = sheet.rank(skill);
+    let (status, status_color) = if cursor.confirm_respec {
+        (
+            format!(
+                "Respec refunds {} SP. Press R again to confirm.",
+                sheet.spent
+            ),
+            Color::srgb(1.0, 0.5, 0.4),
+        )
+    } else {
+        match sheet.next_rank_cost(skill) {
+            Ok(cost) if cost <= save_data.skill_points => (
+                format!("Train rank {}: {} SP", skill_rank_name(rank + 1), cost),
+                TRAINED,
+            ),
+            Ok(cost) => (
+                SkillError::NotEnoughSkillPoints {
+                    cost,
+                    available: save_data.skill_points,
+                }
+                .to_string(),
+                Color::srgb(0.8, 0.5, 0.5),
+            ),
+            Err(error) => (error.to_string(), Color::srgb(0.6, 0.6, 0.6)),
+        }
+    };
+    let mut effects = vec![format!("{}: {}", def.scope.name(), def.description)];
+    if def.effect != SkillEffect::None {
+        effects.push(def.effect.describe());
+    }
+    for (unlock_rank, unlock) in def.unlocks {
+        effects.push(format!(
+            "Rank {}: {}",
+            skill_rank_name(*unlock_rank),
+            unlock.name()
+        ));
+    }
+
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BorderColor(Color::srgb(0.3, 0.3, 0.4)),
+            BackgroundColor(Color::srgba(0.08, 0.08, 0.12, 0.9)),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(format!(
+                    "{}  {} / {}",
+                    def.name,
+                    skill_rank_name(rank),
+                    skill_rank_name(def.max_rank)
+                )),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            panel.spawn((
+                Text::new(effects.join("  •  ")),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+            panel.spawn((
+                Text::new(status),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(status_color),
+            ));
+        });
+}
+
+fn skill_narration(save_data: &SaveData, cursor: &SkillTreeCursor) -> String {
+    let Some(skill) = cursor.skill() else {
+        return String::new();
+    };
+    let def = skill.def();
+    let sheet = &save_data.skills;
+    let next = match sheet.next_rank_cost(skill) {
+        Ok(cost) => format!("Next rank {} SP", cost),
+        Err(error) => error.to_string(),
+    };
+    format!(
+        "{}, {}, rank {} of {}. {}. {}. {}",
+        def.category.name(),
+        def.name,
+        sheet.rank(skill),
+        def.max_rank,
+        def.scope.name(),
+        def.effect.describe(),
+        next
+    )
+}
+
+fn skill_tree_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut cursor: ResMut<SkillTreeCursor>,
+    mut save_data: ResMut<SaveData>,
+    roots: Query<Entity, With<SkillTreeRoot>>,
+    mut narration: EventWriter<NarrationEvent>,
+    mut transitions: EventWriter<TransitionEvent>,
+) {
+    let vertical = if keyboard.just_pressed(KeyCode::ArrowUp)
+        || keyboard.just_pressed(KeyCode::KeyW)
+        || joystick.dpad_just_up()
+    {
+        -1
+    } else if keyboard.just_pressed(KeyCode::ArrowDown)
+        || keyboard.just_pressed(KeyCode::KeyS)
+        || joystick.dpad_just_down()
+    {
+        1
+    } else {
+        0
+    };
+    let horizontal = if keyboard.just_pressed(KeyCode::ArrowLeft)
+        || keyboard.just_pressed(KeyCode::KeyA)
+        || joystick.dpad_just_left()
+    {
+        -1
+    } else if keyboard.just_pressed(KeyCode::ArrowRight)
+        || keyboard.just_pressed(KeyCode::KeyD)
+        || joystick.dpad_just_right()
+    {
+        1
+    } else {
+        0
+    };
+    let confirm = keyboard.just_pressed(KeyCode::Space)
+        || keyboard.just_pressed(KeyCode::Enter)
+        || joystick.confirm();
+    let respec = keyboard.just_pressed(KeyCode::KeyR) || joystick.x_button();
+    let back = keyboard.just_pressed(KeyCode::Escape) || joystick.back();
+
+    let mut message = None;
+    let mut changed = false;
+
+    if cursor.confirm_respec && (respec || confirm) {
+        let refund = save_data.respec_skills();
+        info!("Respec refunded {} SP", refund);
+        cursor.confirm_respec = false;
+        message = Some(format!("Skills reset, {} SP refunded", refund));
+        changed = true;
+    } else if cursor.confirm_respec && (back || vertical != 0 || horizontal != 0) {
+        cursor.confirm_respec = false;
+        changed = true;
+    } else if respec {
+        if save_data.skills.spent > 0 {
+            cursor.confirm_respec = true;
+            message = Some(format!(
+                "Respec refunds {} SP. Press again to confirm",
+                save_data.skills.spent
+            ));
+        } else {
+            message = Some("No trained skills to refund".to_string());
+        }
+        changed = true;
+    } else if back {
+        transitions.send(TransitionEvent::to(GameState::MainMenu));
+        return;
+    } else if horizontal != 0 || vertical != 0 {
+        let columns = SkillCategory::all().len() as i32;
+        cursor.column = (cursor.column as i32 + horizontal).rem_euclid(columns) as usize;
+        let rows = SkillCategory::all()[cursor.column].skills().count();
+        cursor.row = if vertical != 0 {
+            (cursor.row as i32 + vertical).rem_euclid(rows as i32) as usize
+        } else {
+            cursor.row.min(rows - 1)
+        };
+        changed = true;
+    } else if confirm {
+        if let Some(skill) = cursor.skill() {
+            message = Some(match save_data.train_skill(skill) {
+                Ok(rank) => {
+                    info!("Trained {} to rank {}", skill.name(), rank);
+                    format!("{} trained to rank {}", skill.name(), rank)
+                }
+                Err(error) => error.to_string(),
+            });
+            changed = true;
+        }
+    }
+
+    if changed {
+        for root in roots.iter() {
+            commands.entity(root).despawn_recursive();
+        }
+        build_skill_tree(&mut commands, &save_data, &cursor);
+        let text = message.unwrap_or_else(|| skill_narration(&save_data, &cursor));
+        narration.send(NarrationEvent::new(text));
+    }
+}
+
+fn despawn_skill_tree(mut commands: Commands, roots: Query<Entity, With<SkillTreeRoot>>) {
+    for root in roots.iter() {
+        commands.entity(root).despawn_recursive();
+    }
+}
@@ -0,0 +1,138 @@
+//! Ship Unlock Toast
+//!
+//! One banner for every newly unlocked ship, whichever module or screen it
+//! was earned on: the ship's render and name slide in at the top of the
+//! screen, one unlock at a time.
+
+use std::collections::VecDeque;
+
+use crate::assets::ShipSpriteCache;
+use crate::core::*;
+use bevy::prelude::*;
+
+/// Unlock toast plugin
+pub struct UnlockToastPlugin;
+
+impl Plugin for UnlockToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UnlockToastQueue>()
+            .add_systems(Update, (queue_unlock_toasts, update_unlock_toast).chain());
+    }
+}
+
+/// Seconds each toast stays up
+const TOAST_TIME: f32 = 3.5;
+
+/// Seconds to slide in and fade out
+const TOAST_FADE: f32 = 0.3;
+
+const TOAST_COLOR: Color = Color::srgb(0.4, 0.85, 1.0);
+
+/// Unlocks waiting for their toast
+#[derive(Resource, Default)]
+pub struct UnlockToastQueue {
+    pending: VecDeque<ShipUnlockedEvent>,
+}
+
+/// The toast on screen, with its remaining time
+#[derive(Component)]
+pub struct UnlockToast {
+    timer: f32,
+}
+
+fn queue_unlock_toasts(
+    mut events: EventReader<ShipUnlockedEvent>,
+    mut queue: ResMut<UnlockToastQueue>,
+    mut sprites: ResMut<ShipSpriteCache>,
+    mut narration: EventWriter<NarrationEvent>,
+) {
+    for event in events.read() {
+        sprites.request([event.type_id]);
+        narration.send(NarrationEvent::queued(format!(
+            "New ship unlocked: {}",
+            event.name
+        )));
+        queue.pending.push_back(event.clone());
+    }
+}
+
+fn update_unlock_toast(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut queue: ResMut<UnlockToastQueue>,
+    sprites: Res<ShipSpriteCache>,
+    mut toasts: Query<(Entity, &mut UnlockToast, &mut Node, &mut BackgroundColor)>,
+) {
+    if let Ok((entity, mut toast, mut node, mut background)) = toasts.get_single_mut() {
+        toast.timer -= time.delta_secs();
+        if toast.timer <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            return;
+        }
+        let shown = TOAST_TIME - toast.timer;
+        let slide = (shown / TOAST_FADE).min(1.0);
+        node.top = Val::Px(-60.0 + 80.0 * slide);
+        let fade = (toast.timer / TOAST_FADE).min(1.0);
+        background.0 = Color::srgba(0.02, 0.06, 0.1, 0.92 * fade);
+        return;
+    }
+
+    let Some(unlock) = queue.pending.pop_front() else {
+        return;
+    };
+    commands
+        .spawn((
+            UnlockToast { timer: TOAST_TIME },
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(-60.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-160.0)),
+                width: Val::Px(320.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                column_gap: Val::Px(12.0),
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.06, 0.1, 0.92)),
+            BorderColor(TOAST_COLOR),
+            BorderRadius::all(Val::Px(6.0)),
+            GlobalZIndex(150),
+        ))
+        .with_children(|toast| {
+            if let Some(image) = sprites.get(unlock.type_id) {
+                toast.spawn((
+                    ImageNode::new(image),
+                    Node {
+                        width: Val::Px(40.0),
+                        height: Val::Px(40.0),
+                        ..default()
+                    },
+                ));
+            }
+            toast
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                })
+                .with_children(|text| {
+                    text.spawn((
+                        Text::new("NEW SHIP UNLOCKED"),
+                        TextFont {
+                            font_size: 11.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.7, 0.8)),
+                    ));
+                    text.spawn((
+                        Text::new(unlock.name),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(TOAST_COLOR),
+                    ));
+                });
+        });
+}